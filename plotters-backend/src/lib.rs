@@ -246,6 +246,9 @@ pub trait DrawingBackend: Sized {
             VPos::Bottom => -height,
         };
         let trans = style.transform();
+        if let FontTransform::RotateAngle(angle) = trans {
+            return draw_rotated_text(self, text, style, layout, (dx, dy), pos, angle);
+        }
         let (w, h) = self.get_size();
         let drawing_result = style.draw(text, (0, 0), |x, y, color| {
             let (x, y) = trans.transform(x + dx - min_x, y + dy - min_y);
@@ -327,3 +330,62 @@ pub trait DrawingBackend: Sized {
         Ok(())
     }
 }
+
+/// Draw the text rotated by any angle. Mapping each glyph pixel forward to the rounded rotated
+/// position leaves holes and doubled pixels, so the glyphs are rendered unrotated first, and
+/// each pixel covered by the rotated text is mapped back into them.
+fn draw_rotated_text<DB: DrawingBackend, TStyle: BackendTextStyle>(
+    back: &mut DB,
+    text: &str,
+    style: &TStyle,
+    ((min_x, min_y), (max_x, max_y)): ((i32, i32), (i32, i32)),
+    (dx, dy): BackendCoord,
+    pos: BackendCoord,
+    angle: f32,
+) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+    let (width, height) = ((max_x - min_x).max(0), (max_y - min_y).max(0));
+    let mut glyphs = vec![None; (width * height) as usize];
+    let drawing_result = style.draw(text, (0, 0), |x, y, color| {
+        let (x, y) = (x - min_x, y - min_y);
+        if x >= 0 && x < width && y >= 0 && y < height {
+            glyphs[(x + y * width) as usize] = Some(color);
+        }
+        Ok::<(), DrawingErrorKind<DB::ErrorType>>(())
+    });
+    if let Err(font_error) = drawing_result {
+        return Err(DrawingErrorKind::FontError(Box::new(font_error)));
+    }
+
+    let (sin, cos) = f64::from(angle).to_radians().sin_cos();
+    let (left, top) = (f64::from(dx), f64::from(dy));
+    let (right, bottom) = (left + f64::from(width), top + f64::from(height));
+    let corners = [(left, top), (right, top), (left, bottom), (right, bottom)];
+    let (mut x0, mut y0, mut x1, mut y1) = (f64::MAX, f64::MAX, f64::MIN, f64::MIN);
+    for (x, y) in corners.iter() {
+        let (x, y) = (x * cos - y * sin, x * sin + y * cos);
+        x0 = x0.min(x);
+        y0 = y0.min(y);
+        x1 = x1.max(x);
+        y1 = y1.max(y);
+    }
+
+    let (w, h) = back.get_size();
+    for y in (y0.floor() as i32)..=(y1.ceil() as i32) {
+        for x in (x0.floor() as i32)..=(x1.ceil() as i32) {
+            let (px, py) = (pos.0 + x, pos.1 + y);
+            if px < 0 || px >= w as i32 || py < 0 || py >= h as i32 {
+                continue;
+            }
+            let (x, y) = (f64::from(x), f64::from(y));
+            let u = (x * cos + y * sin - left).round() as i32;
+            let v = (y * cos - x * sin - top).round() as i32;
+            if u < 0 || u >= width || v < 0 || v >= height {
+                continue;
+            }
+            if let Some(color) = glyphs[(u + v * width) as usize] {
+                back.draw_pixel((px, py), color)?;
+            }
+        }
+    }
+    Ok(())
+}
@@ -112,6 +112,7 @@ pub mod text_anchor {
 
 /// Specifying text transformations
 #[derive(Clone)]
+#[non_exhaustive]
 pub enum FontTransform {
     /// Nothing to transform
     None,
@@ -121,6 +122,8 @@ pub enum FontTransform {
     Rotate180,
     /// Rotating the text 270 degree clockwise
     Rotate270,
+    /// Rotating the text by the given angle in degrees clockwise
+    RotateAngle(f32),
 }
 
 impl FontTransform {
@@ -135,6 +138,14 @@ impl FontTransform {
             FontTransform::Rotate90 => (-y, x),
             FontTransform::Rotate180 => (-x, -y),
             FontTransform::Rotate270 => (y, -x),
+            FontTransform::RotateAngle(angle) => {
+                let (sin, cos) = f64::from(*angle).to_radians().sin_cos();
+                let (x, y) = (f64::from(x), f64::from(y));
+                (
+                    (x * cos - y * sin).round() as i32,
+                    (x * sin + y * cos).round() as i32,
+                )
+            }
         }
     }
 }
@@ -387,6 +387,75 @@ fn test_bitmap_blit() {
     }
 }
 
+#[cfg(test)]
+#[test]
+fn test_draw_text_rotate_angle() {
+    use plotters::prelude::*;
+    use plotters_backend::{BackendTextStyle, FontFamily, FontTransform};
+
+    // A text style rendering every text as a solid 20x6 block
+    struct BlockTextStyle(FontTransform);
+
+    impl BackendTextStyle for BlockTextStyle {
+        type FontError = std::convert::Infallible;
+
+        fn transform(&self) -> FontTransform {
+            self.0.clone()
+        }
+
+        fn family(&self) -> FontFamily<'_> {
+            FontFamily::SansSerif
+        }
+
+        fn layout_box(&self, _: &str) -> Result<((i32, i32), (i32, i32)), Self::FontError> {
+            Ok(((0, 0), (20, 6)))
+        }
+
+        fn draw<E, DrawFunc: FnMut(i32, i32, BackendColor) -> Result<(), E>>(
+            &self,
+            _: &str,
+            (x0, y0): BackendCoord,
+            mut draw: DrawFunc,
+        ) -> Result<Result<(), E>, Self::FontError> {
+            for y in 0..6 {
+                for x in 0..20 {
+                    if let Err(e) = draw(x0 + x, y0 + y, self.color()) {
+                        return Ok(Err(e));
+                    }
+                }
+            }
+            Ok(Ok(()))
+        }
+    }
+
+    let mut buffer = vec![255; 60 * 60 * 3];
+
+    {
+        let mut back = BitMapBackend::with_buffer(&mut buffer, (60, 60));
+        back.draw_text(
+            "x",
+            &BlockTextStyle(FontTransform::RotateAngle(45.0)),
+            (30, 30),
+        )
+        .unwrap();
+    }
+
+    let (sin, cos) = 45f64.to_radians().sin_cos();
+    for y in 0..60 {
+        for x in 0..60 {
+            let (dx, dy) = (f64::from(x - 30), f64::from(y - 30));
+            let (u, v) = (dx * cos + dy * sin, dy * cos - dx * sin);
+            let painted = buffer[(y * 60 + x) as usize * 3] == 0;
+            if u > 0.5 && u < 18.5 && v > 0.5 && v < 4.5 {
+                assert!(painted, "hole at ({}, {})", x, y);
+            }
+            if painted {
+                assert!(u > -1.0 && u < 21.0 && v > -1.0 && v < 7.0);
+            }
+        }
+    }
+}
+
 #[cfg(all(not(target_arch = "wasm32"), feature = "image"))]
 #[cfg(test)]
 mod test {
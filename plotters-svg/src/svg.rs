@@ -571,6 +571,11 @@ impl<'a> DrawingBackend for SVGBackend<'a> {
                     .write_key("transform")
                     .write_value(("rotate(270, ", x0, ", ", y0, ')'));
             }
+            FontTransform::RotateAngle(angle) => {
+                attrwriter
+                    .write_key("transform")
+                    .write_value(("rotate(", angle, ", ", x0, ", ", y0, ')'));
+            }
             _ => {}
         }
         attrwriter.finish_without_closing();
@@ -1,14 +1,28 @@
 use super::context::ChartContext;
 
 use crate::coord::cartesian::{Cartesian2d, Cartesian3d};
+use crate::coord::geo::{GeoCoord, GeoProjection, Latitude, Longitude};
+use crate::coord::polar::PolarCoord;
 use crate::coord::ranged1d::AsRangedCoord;
-use crate::coord::Shift;
+use crate::coord::ternary::TernaryCoord;
+use crate::coord::{nice_range, Shift};
+use crate::data::fitting_range_with_margin;
 
 use crate::drawing::{DrawingArea, DrawingAreaErrorKind};
 use crate::style::{IntoTextStyle, SizeDesc, TextStyle};
 
 use plotters_backend::DrawingBackend;
 
+use num_traits::Float;
+
+use std::ops::Range;
+
+/// The desired number of the tick intervals used by [`ChartBuilder::build_ranged_nice`]
+const NICE_RANGE_TICKS: usize = 10;
+
+/// The default margin of the ranges built by [`ChartBuilder::build_from_data`]
+const DEFAULT_DATA_MARGIN: f64 = 0.05;
+
 /**
 Specifies one of the four label positions around the figure.
 
@@ -35,7 +49,7 @@ The result is a chart with a spacious X label area and a narrow Y label area:
 
 [`ChartBuilder::set_left_and_bottom_label_area_size()`]
 */
-#[derive(Copy, Clone)]
+#[derive(Copy, Clone, Debug, PartialEq)]
 pub enum LabelAreaPosition {
     /// Top of the figure
     Top = 0,
@@ -61,6 +75,8 @@ pub struct ChartBuilder<'a, 'b, DB: DrawingBackend> {
     root_area: &'a DrawingArea<DB, Shift>,
     title: Option<(String, TextStyle<'b>)>,
     margin: [u32; 4],
+    data_margin: f64,
+    nice_data_range: bool,
 }
 
 impl<'a, 'b, DB: DrawingBackend> ChartBuilder<'a, 'b, DB> {
@@ -94,6 +110,8 @@ impl<'a, 'b, DB: DrawingBackend> ChartBuilder<'a, 'b, DB> {
             title: None,
             margin: [0; 4],
             overlap_plotting_area: [false; 4],
+            data_margin: DEFAULT_DATA_MARGIN,
+            nice_data_range: false,
         }
     }
 
@@ -280,6 +298,31 @@ impl<'a, 'b, DB: DrawingBackend> ChartBuilder<'a, 'b, DB> {
         self
     }
 
+    /**
+    Sets the margin added to both sides of the ranges built by [`ChartBuilder::build_from_data()`].
+
+    - `margin`: The margin as a fraction of the span of the data, 0.05 by default
+
+    See [`ChartBuilder::build_from_data()`] for more information and examples.
+    */
+    pub fn data_margin(&mut self, margin: f64) -> &mut Self {
+        self.data_margin = margin.max(0.0);
+        self
+    }
+
+    /**
+    Sets if the ranges built by [`ChartBuilder::build_from_data()`] are expanded to round
+    numbers after the margin is added, see [`crate::coord::nice_range`] for details.
+
+    - `value`: If the ranges should be expanded to round numbers, false by default
+
+    See [`ChartBuilder::build_from_data()`] for more information and examples.
+    */
+    pub fn nice_data_range(&mut self, value: bool) -> &mut Self {
+        self.nice_data_range = value;
+        self
+    }
+
     /// This function has been renamed to [`ChartBuilder::build_cartesian_2d()`] and is to be removed in the future.
     #[allow(clippy::type_complexity)]
     #[deprecated(
@@ -436,9 +479,222 @@ impl<'a, 'b, DB: DrawingBackend> ChartBuilder<'a, 'b, DB> {
                 actual_drawing_area_pos[2] + title_dx + self.margin[2] as i32,
                 actual_drawing_area_pos[0] + title_dy + self.margin[0] as i32,
             ),
+            deferred_layer: None,
+            axis_offset: 0,
+            fixed_layout: false,
+            layout_only: false,
         })
     }
 
+    /**
+    Builds two charts with 2D Cartesian coordinate systems stacked vertically and sharing the X
+    axis, the upper one for the prices and the lower one for the volumes, as in the usual layout
+    of the financial charts.
+
+    - `x_spec`: Specifies the X axis range shared by both charts
+    - `price_spec`: Specifies the Y axis range of the upper chart
+    - `volume_spec`: Specifies the Y axis range of the lower chart
+    - `volume_ratio`: The fraction of the height for the lower chart, between `0.0` and `1.0`
+    - Returns: The `ChartContext` objects of the upper and the lower charts.
+
+    Both charts take the left and the right label areas and margins of the builder, so their
+    plotting areas are aligned horizontally. The caption, the top label area and the top margin
+    only apply to the upper chart, and the bottom label area and the bottom margin only apply to
+    the lower chart, which means the X labels are drawn below the volumes.
+
+    ```rust
+    use plotters::prelude::*;
+
+    let drawing_area = SVGBackend::new("price_volume.svg", (400, 300)).into_drawing_area();
+    drawing_area.fill(&WHITE).unwrap();
+    let (mut price, mut volume) = ChartBuilder::on(&drawing_area)
+        .margin(10)
+        .x_label_area_size(20)
+        .y_label_area_size(40)
+        .build_price_volume(0..4, 10.0..14.0, 0.0..500.0, 0.3)
+        .unwrap();
+    price.configure_mesh().draw().unwrap();
+    volume.configure_mesh().y_labels(3).draw().unwrap();
+    let data = [(0, 11.0, 12.5, 10.5, 12.0, 300.0), (1, 12.0, 13.0, 11.0, 11.5, 450.0)];
+    price
+        .draw_series(data.iter().map(|&(x, o, h, l, c, _)| {
+            CandleStick::new(x, o, h, l, c, GREEN.filled(), RED.filled(), 15)
+        }))
+        .unwrap();
+    volume
+        .draw_series(data.iter().map(|&(x, _, _, _, _, v)| {
+            Rectangle::new([(x, 0.0), (x + 1, v)], BLUE.mix(0.5).filled())
+        }))
+        .unwrap();
+    ```
+    */
+    #[allow(clippy::type_complexity)]
+    pub fn build_price_volume<'c, X, Y, V>(
+        &mut self,
+        x_spec: X,
+        price_spec: Y,
+        volume_spec: V,
+        volume_ratio: f64,
+    ) -> Result<
+        (
+            ChartContext<'c, DB, Cartesian2d<X::CoordDescType, Y::CoordDescType>>,
+            ChartContext<'c, DB, Cartesian2d<X::CoordDescType, V::CoordDescType>>,
+        ),
+        DrawingAreaErrorKind<DB::ErrorType>,
+    >
+    where
+        X: AsRangedCoord + Clone,
+        Y: AsRangedCoord,
+        V: AsRangedCoord,
+    {
+        let [top, bottom, left, right] = self.margin;
+        let (_, h) = self.root_area.dim_in_pixel();
+        let title_height = match &self.title {
+            Some((title, style)) => self.root_area.title_height(title, style)?,
+            None => 0,
+        };
+        // The label areas overlapping the plotting area don't take its space
+        let label_height = |idx: usize| {
+            if self.overlap_plotting_area[idx] {
+                0
+            } else {
+                self.label_area_size[idx]
+            }
+        };
+        let (top_labels, bottom_labels) = (label_height(0), label_height(1));
+        let plotting_height = f64::from(h)
+            - f64::from(top + bottom + title_height)
+            - f64::from(top_labels + bottom_labels);
+        let volume_height = plotting_height.max(0.0) * volume_ratio.clamp(0.0, 1.0);
+        let split = f64::from(h) - f64::from(bottom + bottom_labels) - volume_height;
+        let (upper, lower) = self.root_area.split_vertically(split.round() as i32);
+
+        let label_area_size = self.label_area_size;
+        let mut price = ChartBuilder {
+            label_area_size: [
+                label_area_size[0],
+                0,
+                label_area_size[2],
+                label_area_size[3],
+            ],
+            overlap_plotting_area: self.overlap_plotting_area,
+            root_area: &upper,
+            title: self.title.clone(),
+            margin: [top, 0, left, right],
+            data_margin: self.data_margin,
+            nice_data_range: self.nice_data_range,
+        };
+        let mut volume = ChartBuilder {
+            label_area_size: [
+                0,
+                label_area_size[1],
+                label_area_size[2],
+                label_area_size[3],
+            ],
+            overlap_plotting_area: self.overlap_plotting_area,
+            root_area: &lower,
+            title: None,
+            margin: [0, bottom, left, right],
+            data_margin: self.data_margin,
+            nice_data_range: self.nice_data_range,
+        };
+
+        Ok((
+            price.build_cartesian_2d(x_spec.clone(), price_spec)?,
+            volume.build_cartesian_2d(x_spec, volume_spec)?,
+        ))
+    }
+
+    /**
+    Builds a chart with a 2D Cartesian coordinate system, whose ranges are expanded to round
+    numbers so that the ticks land on clean values, for example, `0.0..47.3` becomes `0.0..50.0`.
+    See [`crate::coord::nice_range`] for details.
+
+    - `x_range`: The raw X axis range, usually the bounds of the data, either `f32` or `f64`
+    - `y_range`: The raw Y axis range, usually the bounds of the data
+    - Returns: A `ChartContext` object, ready to visualize data.
+    */
+    #[allow(clippy::type_complexity)]
+    pub fn build_ranged_nice<'c, X: Float, Y: Float>(
+        &mut self,
+        x_range: Range<X>,
+        y_range: Range<Y>,
+    ) -> Result<
+        ChartContext<
+            'c,
+            DB,
+            Cartesian2d<
+                <Range<X> as AsRangedCoord>::CoordDescType,
+                <Range<Y> as AsRangedCoord>::CoordDescType,
+            >,
+        >,
+        DrawingAreaErrorKind<DB::ErrorType>,
+    >
+    where
+        Range<X>: AsRangedCoord<Value = X>,
+        Range<Y>: AsRangedCoord<Value = Y>,
+    {
+        self.build_cartesian_2d(
+            nice_range(x_range, NICE_RANGE_TICKS),
+            nice_range(y_range, NICE_RANGE_TICKS),
+        )
+    }
+
+    /**
+    Builds a chart with a 2D Cartesian coordinate system, whose ranges fit the given data. The
+    ranges are the bounds of the data, with the margin set by [`ChartBuilder::data_margin()`] on
+    both sides, and are expanded to round numbers if [`ChartBuilder::nice_data_range()`] is set.
+    The values that are not finite are ignored.
+
+    - `x_iter`: The X values of the data, either `f32` or `f64`
+    - `y_iter`: The Y values of the data
+    - Returns: A `ChartContext` object, ready to visualize data.
+
+    # Example
+
+    ```
+    use plotters::prelude::*;
+    let data = [(1.0, 3.2), (2.5, -0.4), (4.0, 2.7)];
+    let drawing_area = SVGBackend::new("build_from_data.svg", (300, 200)).into_drawing_area();
+    drawing_area.fill(&WHITE).unwrap();
+    let mut chart = ChartBuilder::on(&drawing_area)
+        .set_left_and_bottom_label_area_size(30)
+        .nice_data_range(true)
+        .build_from_data(data.iter().map(|p| p.0), data.iter().map(|p| p.1))
+        .unwrap();
+    chart.configure_mesh().draw().unwrap();
+    chart.draw_series(LineSeries::new(data.iter().copied(), &RED)).unwrap();
+    ```
+    */
+    #[allow(clippy::type_complexity)]
+    pub fn build_from_data<'c, X: Float, Y: Float>(
+        &mut self,
+        x_iter: impl IntoIterator<Item = X>,
+        y_iter: impl IntoIterator<Item = Y>,
+    ) -> Result<
+        ChartContext<
+            'c,
+            DB,
+            Cartesian2d<
+                <Range<X> as AsRangedCoord>::CoordDescType,
+                <Range<Y> as AsRangedCoord>::CoordDescType,
+            >,
+        >,
+        DrawingAreaErrorKind<DB::ErrorType>,
+    >
+    where
+        Range<X>: AsRangedCoord<Value = X>,
+        Range<Y>: AsRangedCoord<Value = Y>,
+    {
+        let x_range = fitting_range_with_margin(x_iter, self.data_margin);
+        let y_range = fitting_range_with_margin(y_iter, self.data_margin);
+        if self.nice_data_range {
+            self.build_ranged_nice(x_range, y_range)
+        } else {
+            self.build_cartesian_2d(x_range, y_range)
+        }
+    }
+
     /**
     Builds a chart with a 3D Cartesian coordinate system.
 
@@ -495,8 +751,164 @@ impl<'a, 'b, DB: DrawingBackend> ChartBuilder<'a, 'b, DB> {
                 title_dx + self.margin[2] as i32,
                 title_dy + self.margin[0] as i32,
             ),
+            deferred_layer: None,
+            axis_offset: 0,
+            fixed_layout: false,
+            layout_only: false,
         })
     }
+
+    /**
+    Builds a chart with a polar coordinate system.
+
+    - `angle_spec`: Specifies the angular axis range, which covers a full turn clockwise from the top
+    - `radius_spec`: Specifies the radial axis range
+    - Returns: A `ChartContext` object, ready to visualize data.
+
+    The circle is centered in the plotting area. The largest label area size set on the builder is
+    reserved around the circle for the angle labels.
+
+    See [`ChartBuilder::on()`] and [`ChartContext::configure_mesh()`] for more information and examples.
+    */
+    #[allow(clippy::type_complexity)]
+    pub fn build_polar<'c, A: AsRangedCoord, R: AsRangedCoord>(
+        &mut self,
+        angle_spec: A,
+        radius_spec: R,
+    ) -> Result<
+        ChartContext<'c, DB, PolarCoord<A::CoordDescType, R::CoordDescType>>,
+        DrawingAreaErrorKind<DB::ErrorType>,
+    > {
+        let (drawing_area, (title_dx, title_dy), (x_range, y_range)) = self.centered_plot_area()?;
+
+        Ok(ChartContext {
+            x_label_area: [None, None],
+            y_label_area: [None, None],
+            drawing_area: drawing_area.apply_coord_spec(PolarCoord::new(
+                angle_spec,
+                radius_spec,
+                (x_range, y_range),
+            )),
+            series_anno: vec![],
+            drawing_area_pos: (
+                title_dx + self.margin[2] as i32,
+                title_dy + self.margin[0] as i32,
+            ),
+            deferred_layer: None,
+            axis_offset: 0,
+            fixed_layout: false,
+            layout_only: false,
+        })
+    }
+
+    /**
+    Builds a chart with a geographic coordinate system, which is a 2D Cartesian coordinate
+    system of the longitude and the latitude in degrees, with the given map projection. The
+    mesh is drawn as the graticule. The plotting area isn't resized for the map, use
+    [GeoProjection::aspect_ratio](crate::coord::geo::GeoProjection::aspect_ratio) to choose
+    the size of the drawing area that keeps the shapes undistorted.
+
+    - `lon_range`: The range of the longitude in degrees, from the west to the east
+    - `lat_range`: The range of the latitude in degrees, from the south to the north
+    - `projection`: The map projection
+    - Returns: A `ChartContext` object, ready to visualize data.
+
+    ```rust
+    use plotters::prelude::*;
+
+    let drawing_area = SVGBackend::new("geo.svg", (400, 400)).into_drawing_area();
+    let mut chart = ChartBuilder::on(&drawing_area)
+        .x_label_area_size(30)
+        .y_label_area_size(40)
+        .build_geo(-180.0..180.0, -85.0..85.0, GeoProjection::WebMercator)
+        .unwrap();
+    chart.configure_mesh().draw().unwrap();
+    chart
+        .draw_series(LineSeries::new(vec![(13.4, 52.5), (-74.0, 40.7)], &RED))
+        .unwrap();
+    ```
+    */
+    pub fn build_geo<'c>(
+        &mut self,
+        lon_range: Range<f64>,
+        lat_range: Range<f64>,
+        projection: GeoProjection,
+    ) -> Result<ChartContext<'c, DB, GeoCoord>, DrawingAreaErrorKind<DB::ErrorType>> {
+        self.build_cartesian_2d(
+            Longitude::new(lon_range),
+            Latitude::new(lat_range, projection),
+        )
+    }
+
+    /**
+    Builds a chart with a ternary coordinate system for the three-component compositional data.
+
+    - Returns: A `ChartContext` object, ready to visualize data.
+
+    The triangle is centered in the plotting area. The largest label area size set on the builder
+    is reserved around the triangle for the labels.
+
+    See [`ChartBuilder::on()`] and [`ChartContext::configure_mesh()`] for more information and examples.
+    */
+    pub fn build_ternary<'c>(
+        &mut self,
+    ) -> Result<ChartContext<'c, DB, TernaryCoord>, DrawingAreaErrorKind<DB::ErrorType>> {
+        let (drawing_area, (title_dx, title_dy), pixel_range) = self.centered_plot_area()?;
+
+        Ok(ChartContext {
+            x_label_area: [None, None],
+            y_label_area: [None, None],
+            drawing_area: drawing_area.apply_coord_spec(TernaryCoord::new(pixel_range)),
+            series_anno: vec![],
+            drawing_area_pos: (
+                title_dx + self.margin[2] as i32,
+                title_dy + self.margin[0] as i32,
+            ),
+            deferred_layer: None,
+            axis_offset: 0,
+            fixed_layout: false,
+            layout_only: false,
+        })
+    }
+
+    /// Get the area for the charts without label areas, the title offset and the pixel range
+    /// left after reserving the largest label area size on all the sides
+    #[allow(clippy::type_complexity)]
+    fn centered_plot_area(
+        &mut self,
+    ) -> Result<
+        (DrawingArea<DB, Shift>, (i32, i32), (Range<i32>, Range<i32>)),
+        DrawingAreaErrorKind<DB::ErrorType>,
+    > {
+        let mut drawing_area = DrawingArea::clone(self.root_area);
+
+        if *self.margin.iter().max().unwrap_or(&0) > 0 {
+            drawing_area = drawing_area.margin(
+                self.margin[0] as i32,
+                self.margin[1] as i32,
+                self.margin[2] as i32,
+                self.margin[3] as i32,
+            );
+        }
+
+        let (title_dx, title_dy) = if let Some((ref title, ref style)) = self.title {
+            let (origin_dx, origin_dy) = drawing_area.get_base_pixel();
+            drawing_area = drawing_area.titled(title, style.clone())?;
+            let (current_dx, current_dy) = drawing_area.get_base_pixel();
+            (current_dx - origin_dx, current_dy - origin_dy)
+        } else {
+            (0, 0)
+        };
+
+        let label_size = *self.label_area_size.iter().max().unwrap_or(&0) as i32;
+        let (mut x_range, mut y_range) = drawing_area.get_pixel_range();
+        x_range = (x_range.start + label_size)
+            ..(x_range.end - label_size).max(x_range.start + label_size);
+        y_range = (y_range.start + label_size)
+            ..(y_range.end - label_size).max(y_range.start + label_size);
+
+        Ok((drawing_area, (title_dx, title_dy), (x_range, y_range)))
+    }
 }
 
 #[cfg(test)]
@@ -529,6 +941,96 @@ mod test {
         assert_eq!(chart.label_area_size[3], 200);
     }
 
+    #[test]
+    fn test_build_ranged_nice() {
+        let drawing_area = create_mocked_drawing_area(200, 200, |_| {});
+        let chart = ChartBuilder::on(&drawing_area)
+            .build_ranged_nice(0.0..47.3, -0.13..0.92)
+            .expect("Create chart");
+        assert_eq!(chart.as_coord_spec().get_x_range(), 0.0..50.0);
+        assert_eq!(chart.as_coord_spec().get_y_range(), -0.2..1.0);
+
+        let chart = ChartBuilder::on(&drawing_area)
+            .build_ranged_nice(0f32..47.3f32, 1013f32..1027f32)
+            .expect("Create chart");
+        assert_eq!(chart.as_coord_spec().get_x_range(), 0f32..50f32);
+        assert_eq!(chart.as_coord_spec().get_y_range(), 1012f32..1028f32);
+    }
+
+    #[test]
+    fn test_build_price_volume() {
+        let drawing_area = create_mocked_drawing_area(200, 200, |_| {});
+        let (price, volume) = ChartBuilder::on(&drawing_area)
+            .margin(10)
+            .x_label_area_size(20)
+            .y_label_area_size(30)
+            .build_price_volume(0..10, 0.0..100.0, 0.0..1000.0, 0.25)
+            .expect("Create charts");
+
+        let (price_x, price_y) = price.plotting_area().get_pixel_range();
+        let (volume_x, volume_y) = volume.plotting_area().get_pixel_range();
+        assert_eq!(price_x, 40..190);
+        assert_eq!(price_x, volume_x);
+        assert_eq!(price_y, 10..130);
+        assert_eq!(volume_y, 130..170);
+        assert_eq!(volume.as_coord_spec().get_x_range(), 0..10);
+        assert_eq!(volume.as_coord_spec().get_y_range(), 0.0..1000.0);
+
+        let (price, volume) = ChartBuilder::on(&drawing_area)
+            .caption("Prices", ("sans-serif", 20))
+            .x_label_area_size(20)
+            .build_price_volume(0..10, 0.0..100.0, 0.0..1000.0, 0.25)
+            .expect("Create charts");
+        let (_, price_y) = price.plotting_area().get_pixel_range();
+        let (_, volume_y) = volume.plotting_area().get_pixel_range();
+        assert!(price_y.start > 0);
+        assert_eq!(price_y.end, volume_y.start);
+        let total = volume_y.end - price_y.start;
+        assert!((4 * (volume_y.end - volume_y.start) - total).abs() <= 4);
+    }
+
+    #[test]
+    fn test_build_from_data() {
+        let drawing_area = create_mocked_drawing_area(200, 200, |_| {});
+        let xs = [1.0, 2.0, 3.0, f64::NAN];
+        let ys = [10f32, 30f32, 20f32];
+
+        let chart = ChartBuilder::on(&drawing_area)
+            .data_margin(0.25)
+            .build_from_data(xs.iter().copied(), ys.iter().copied())
+            .expect("Create chart");
+        assert_eq!(chart.as_coord_spec().get_x_range(), 0.5..3.5);
+        assert_eq!(chart.as_coord_spec().get_y_range(), 5f32..35f32);
+
+        let chart = ChartBuilder::on(&drawing_area)
+            .nice_data_range(true)
+            .build_from_data(xs.iter().copied(), ys.iter().copied())
+            .expect("Create chart");
+        assert_eq!(chart.as_coord_spec().get_x_range(), 0.75..3.25);
+        assert_eq!(chart.as_coord_spec().get_y_range(), 7.5f32..32.5f32);
+
+        let chart = ChartBuilder::on(&drawing_area)
+            .data_margin(0.0)
+            .build_from_data(std::iter::once(4.0), std::iter::empty::<f64>())
+            .expect("Create chart");
+        assert_eq!(chart.as_coord_spec().get_x_range(), 2.0..6.0);
+        assert_eq!(chart.as_coord_spec().get_y_range(), 0.0..1.0);
+    }
+
+    #[test]
+    fn test_build_geo() {
+        let drawing_area = create_mocked_drawing_area(200, 200, |_| {});
+        let chart = ChartBuilder::on(&drawing_area)
+            .build_geo(-180.0..180.0, -90.0..90.0, GeoProjection::WebMercator)
+            .expect("Create chart");
+        let coord = chart.as_coord_spec();
+        assert_eq!(coord.translate(&(0.0, 0.0)), (99, 100));
+        assert_eq!(coord.translate(&(-180.0, 90.0)), (0, 0));
+        assert!(
+            coord.translate(&(0.0, 60.0)).1 < 100 - 2 * (100 - coord.translate(&(0.0, 30.0)).1)
+        );
+    }
+
     #[test]
     fn test_margin_configure() {
         let drawing_area = create_mocked_drawing_area(200, 200, |_| {});
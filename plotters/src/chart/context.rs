@@ -1,4 +1,6 @@
 use std::borrow::Borrow;
+#[cfg(any(feature = "stacked_bar_series", feature = "grouped_bar_series"))]
+use std::ops::Add;
 
 use plotters_backend::{BackendCoord, DrawingBackend};
 
@@ -6,12 +8,58 @@ use crate::chart::{SeriesAnno, SeriesLabelStyle};
 use crate::coord::{CoordTranslate, ReverseCoordTranslate, Shift};
 use crate::drawing::{DrawingArea, DrawingAreaErrorKind};
 use crate::element::{CoordMapper, Drawable, PointCollection};
+#[cfg(feature = "grouped_bar_series")]
+use crate::series::GroupedBarSeries;
+#[cfg(feature = "stacked_bar_series")]
+use crate::series::StackedBarSeries;
+#[cfg(any(feature = "stacked_bar_series", feature = "grouped_bar_series"))]
+use crate::{coord::ranged1d::DiscreteRanged, series::HistogramType};
+#[cfg(any(
+    feature = "stacked_area_series",
+    feature = "stacked_bar_series",
+    feature = "grouped_bar_series"
+))]
+use crate::{element::Rectangle, style::ShapeStyle};
+#[cfg(feature = "stacked_area_series")]
+use {crate::element::Polygon, crate::series::StackedAreaSeries, num_traits::Float};
 
 pub(super) mod cartesian2d;
 pub(super) mod cartesian3d;
+pub(super) mod polar;
+pub(super) mod ternary;
+pub(super) mod transformed;
 
+pub(super) use cartesian2d::DeferredDrawFn;
 pub(super) use cartesian3d::Coord3D;
 
+type DeferredLayerDrawFn<'a, DB> =
+    dyn FnOnce() -> Result<(), DrawingAreaErrorKind<<DB as DrawingBackend>::ErrorType>> + 'a;
+
+/// A drawing routine which is drawn once when the chart context is dropped or converted, so it
+/// stays on top of all the series. Since it's drawn on drop, the drawing errors are ignored.
+pub(crate) struct DeferredLayer<'a, DB: DrawingBackend> {
+    draw_func: Option<Box<DeferredLayerDrawFn<'a, DB>>>,
+}
+
+impl<'a, DB: DrawingBackend> DeferredLayer<'a, DB> {
+    pub(crate) fn new<F>(draw_func: F) -> Self
+    where
+        F: FnOnce() -> Result<(), DrawingAreaErrorKind<DB::ErrorType>> + 'a,
+    {
+        Self {
+            draw_func: Some(Box::new(draw_func)),
+        }
+    }
+}
+
+impl<'a, DB: DrawingBackend> Drop for DeferredLayer<'a, DB> {
+    fn drop(&mut self) {
+        if let Some(draw_func) = self.draw_func.take() {
+            draw_func().ok();
+        }
+    }
+}
+
 /**
 The context of the chart. This is the core object of Plotters.
 
@@ -28,6 +76,14 @@ pub struct ChartContext<'a, DB: DrawingBackend, CT: CoordTranslate> {
     pub(crate) drawing_area: DrawingArea<DB, CT>,
     pub(crate) series_anno: Vec<SeriesAnno<'a, DB>>,
     pub(crate) drawing_area_pos: (i32, i32),
+    pub(crate) deferred_layer: Option<DeferredLayer<'a, DB>>,
+    /// The distance in pixels between the axes and the plotting area
+    pub(crate) axis_offset: i32,
+    /// If the plotting area is shared with another coordinate system, so that the label areas
+    /// can't grow into it
+    pub(crate) fixed_layout: bool,
+    /// If the axes and labels are only laid out without being drawn
+    pub(crate) layout_only: bool,
 }
 
 impl<'a, DB: DrawingBackend, CT: ReverseCoordTranslate> ChartContext<'a, DB, CT> {
@@ -131,6 +187,114 @@ impl<'a, DB: DrawingBackend, CT: CoordTranslate> ChartContext<'a, DB, CT> {
         self.draw_series_impl(series)?;
         Ok(self.alloc_series_anno())
     }
+
+    /**
+    Adds a series label which isn't attached to a drawn series, such as an entry of a size legend.
+
+    See [`crate::series::PointSeries`] and [`ChartContext::configure_series_labels()`] for more information and examples.
+    */
+    pub fn add_series_label<L: Into<String>>(&mut self, label: L) -> &mut SeriesAnno<'a, DB> {
+        self.alloc_series_anno().label(label)
+    }
+
+    /**
+    Draws a stacked bar series, and adds a series label with a filled box legend for each layer which
+    has a label.
+
+    See [`crate::series::StackedBarSeries`] for more information and examples.
+    */
+    #[cfg(feature = "stacked_bar_series")]
+    pub fn draw_stacked_series<BR, A, Tag, B, E>(
+        &mut self,
+        series: StackedBarSeries<BR, A, Tag>,
+    ) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>>
+    where
+        BR: DiscreteRanged + Clone,
+        A: Add<A, Output = A> + Default + Clone + PartialOrd,
+        Tag: HistogramType,
+        StackedBarSeries<BR, A, Tag>: Iterator<Item = E>,
+        B: CoordMapper,
+        for<'b> &'b E: PointCollection<'b, CT::From, B>,
+        E: Drawable<DB, B>,
+    {
+        let layers = series.layers();
+        self.draw_layered_series(series, layers)
+    }
+
+    /**
+    Draws a grouped bar series, and adds a series label with a filled box legend for each group which
+    has a label.
+
+    See [`crate::series::GroupedBarSeries`] for more information and examples.
+    */
+    #[cfg(feature = "grouped_bar_series")]
+    pub fn draw_grouped_series<BR, A, Tag, B, E>(
+        &mut self,
+        series: GroupedBarSeries<BR, A, Tag>,
+    ) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>>
+    where
+        BR: DiscreteRanged + Clone,
+        A: Add<A, Output = A> + Default + Clone,
+        Tag: HistogramType,
+        GroupedBarSeries<BR, A, Tag>: Iterator<Item = E>,
+        B: CoordMapper,
+        for<'b> &'b E: PointCollection<'b, CT::From, B>,
+        E: Drawable<DB, B>,
+    {
+        let layers = series.layers();
+        self.draw_layered_series(series, layers)
+    }
+
+    /**
+    Draws a stacked area series or a streamgraph series, and adds a series label with a filled box
+    legend for each layer which has a label.
+
+    See [`crate::series::StackedAreaSeries`] and [`crate::series::StreamgraphSeries`] for more
+    information and examples.
+    */
+    #[cfg(feature = "stacked_area_series")]
+    pub fn draw_stacked_area_series<X, Y, B, S>(
+        &mut self,
+        series: S,
+    ) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>>
+    where
+        S: Into<StackedAreaSeries<X, Y>>,
+        X: Clone,
+        Y: Float,
+        B: CoordMapper,
+        for<'b> &'b Polygon<(X, Y)>: PointCollection<'b, CT::From, B>,
+        Polygon<(X, Y)>: Drawable<DB, B>,
+    {
+        let series = series.into();
+        let layers = series.layers();
+        self.draw_layered_series(series, layers)
+    }
+
+    /// Draw the series, and add a series label for each of the labelled layers
+    #[cfg(any(
+        feature = "stacked_area_series",
+        feature = "stacked_bar_series",
+        feature = "grouped_bar_series"
+    ))]
+    fn draw_layered_series<B, E, S>(
+        &mut self,
+        series: S,
+        layers: Vec<(String, ShapeStyle)>,
+    ) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>>
+    where
+        B: CoordMapper,
+        for<'b> &'b E: PointCollection<'b, CT::From, B>,
+        E: Drawable<DB, B>,
+        S: IntoIterator<Item = E>,
+    {
+        self.draw_series_impl(series)?;
+        for (label, style) in layers.into_iter().filter(|(label, _)| !label.is_empty()) {
+            self.alloc_series_anno()
+                .label(label)
+                .legend(move |(x, y)| Rectangle::new([(x, y - 5), (x + 10, y + 5)], style));
+        }
+        Ok(())
+    }
 }
 
 #[cfg(test)]
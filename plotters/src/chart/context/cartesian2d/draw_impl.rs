@@ -1,51 +1,238 @@
 use std::ops::Range;
 
-use plotters_backend::DrawingBackend;
+use plotters_backend::{BackendCoord, DrawingBackend};
 
-use crate::chart::ChartContext;
+use crate::chart::mesh::{
+    rotate_label_style, AxisDescLayout, AxisDescOrientation, AxisDescPlacement, DrawnTick,
+    EdgeLabels, LabelOverlap, MeshLayout,
+};
+use crate::chart::{ChartContext, LabelAreaPosition};
 use crate::coord::{
     cartesian::{Cartesian2d, MeshLine},
     ranged1d::{KeyPointHint, Ranged},
     Shift,
 };
 use crate::drawing::{DrawingArea, DrawingAreaErrorKind};
-use crate::element::PathElement;
+use crate::element::{PathElement, Polygon, Rectangle};
 use crate::style::{
     text_anchor::{HPos, Pos, VPos},
-    FontTransform, ShapeStyle, TextStyle,
+    FontTransform, ShapeStyle, TextStyle, TRANSPARENT,
 };
 
+/// The line height of multi-line labels and axis descriptions, relative to the font size
+const LINE_HEIGHT: f64 = 1.25;
+
+/// A tick label: the pixel of the tick mark, the tier of the label and the text
+pub(crate) type TickLabel = (i32, usize, String);
+
+/// Get every other interval between the major positions, the last interval may be cut by the
+/// end of the given range
+fn band_ranges(mut major: Vec<i32>, range: Range<i32>) -> Vec<(i32, i32)> {
+    major.sort_unstable();
+    major.dedup();
+    let mut bands: Vec<_> = major
+        .windows(2)
+        .step_by(2)
+        .map(|pair| (pair[0], pair[1]))
+        .collect();
+    /* With an odd number of lines, the interval after the last line is also filled */
+    if major.len() % 2 == 1 {
+        if let Some(&last) = major.last() {
+            if range.end - last > 1 {
+                bands.push((last, range.end));
+            }
+        }
+    }
+    bands
+}
+
+/// Get the positions which divide the intervals between the major positions evenly. The
+/// intervals before the first and after the last major position are divided with the step of
+/// their neighbour, as long as the positions are inside the given range.
+fn division_positions(mut major: Vec<i32>, divisions: usize, range: Range<i32>) -> Vec<i32> {
+    major.sort_unstable();
+    major.dedup();
+    let mut result = vec![];
+    if divisions < 2 || major.len() < 2 {
+        return result;
+    }
+    let n = divisions as f64;
+    let at = |from: i32, step: f64, k: usize| from + (step * k as f64 / n).round() as i32;
+
+    let first_step = f64::from(major[1] - major[0]);
+    for k in (1..divisions).rev() {
+        let pos = at(major[0], -first_step, k);
+        if range.contains(&pos) {
+            result.push(pos);
+        }
+    }
+    for pair in major.windows(2) {
+        let step = f64::from(pair[1] - pair[0]);
+        result.extend((1..divisions).map(|k| at(pair[0], step, k)));
+    }
+    let last = major[major.len() - 1];
+    let last_step = f64::from(last - major[major.len() - 2]);
+    for k in 1..divisions {
+        let pos = at(last, last_step, k);
+        if range.contains(&pos) {
+            result.push(pos);
+        }
+    }
+    result
+}
+
+/// Get the sin and cos of the rotation angle of the label style, if the label is rotated
+fn label_rotation(style: &TextStyle) -> Option<(f64, f64)> {
+    match style.font.get_transform() {
+        FontTransform::None => None,
+        FontTransform::Rotate90 => Some(90.0),
+        FontTransform::Rotate180 => Some(180.0),
+        FontTransform::Rotate270 => Some(270.0),
+        FontTransform::RotateAngle(angle) => Some(f64::from(angle)),
+        _ => None,
+    }
+    .map(|angle: f64| angle.to_radians().sin_cos())
+}
+
+/// Get the distance between the lines of a multi-line text
+fn line_height(style: &TextStyle) -> i32 {
+    (style.font.get_size() * LINE_HEIGHT).round() as i32
+}
+
+/// Draw a text which may have multiple lines separated by `\n`. Each line is aligned to the
+/// anchor point on its own, and the lines are stacked in the direction of the text height, so
+/// that the whole block is below, around or above the anchor point as the vertical anchor says.
+fn draw_text_lines<DB: DrawingBackend>(
+    area: &DrawingArea<DB, Shift>,
+    text: &str,
+    style: &TextStyle,
+    (x, y): (i32, i32),
+) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>> {
+    let lines: Vec<_> = text.split('\n').collect();
+    let first = match style.pos.v_pos {
+        VPos::Top => 0.0,
+        VPos::Center => (1.0 - lines.len() as f64) / 2.0,
+        VPos::Bottom => 1.0 - lines.len() as f64,
+    };
+    /* The direction which the bottom of the text faces */
+    let (sin, cos) = label_rotation(style).unwrap_or((0.0, 1.0));
+    let line_height = f64::from(line_height(style));
+    for (idx, line) in lines.into_iter().enumerate() {
+        let dist = (first + idx as f64) * line_height;
+        /* Round the halves up, so that the lines around the anchor point stay evenly apart */
+        let (dx, dy) = (
+            (0.5 - sin * dist).floor() as i32,
+            (0.5 + cos * dist).floor() as i32,
+        );
+        area.draw_text(line, style, (x + dx, y + dy))?;
+    }
+    Ok(())
+}
+
+/// Get the end points of the axis line on a label area of the given size
+fn axis_line_ends(
+    (tw, th): (u32, u32),
+    orientation: (i16, i16),
+    inward_labels: bool,
+    axis_range: &Range<i32>,
+) -> ((i32, i32), (i32, i32)) {
+    let mut x0 = if orientation.0 > 0 { 0 } else { tw as i32 - 1 };
+    let mut y0 = if orientation.1 > 0 { 0 } else { th as i32 - 1 };
+    let mut x1 = if orientation.0 >= 0 { 0 } else { tw as i32 - 1 };
+    let mut y1 = if orientation.1 >= 0 { 0 } else { th as i32 - 1 };
+
+    if inward_labels {
+        if orientation.0 == 0 {
+            if y0 == 0 {
+                y0 = th as i32 - 1;
+                y1 = th as i32 - 1;
+            } else {
+                y0 = 0;
+                y1 = 0;
+            }
+        } else if x0 == 0 {
+            x0 = tw as i32 - 1;
+            x1 = tw as i32 - 1;
+        } else {
+            x0 = 0;
+            x1 = 0;
+        }
+    }
+
+    if orientation.0 == 0 {
+        x0 = axis_range.start;
+        x1 = axis_range.end;
+    } else {
+        y0 = axis_range.start;
+        y1 = axis_range.end;
+    }
+
+    ((x0, y0), (x1, y1))
+}
+
 impl<'a, DB: DrawingBackend, X: Ranged, Y: Ranged> ChartContext<'a, DB, Cartesian2d<X, Y>> {
     /// The actual function that draws the mesh lines.
     /// It also returns the label that suppose to be there.
     #[allow(clippy::type_complexity)]
-    fn draw_mesh_lines<FmtLabel, YH: KeyPointHint, XH: KeyPointHint>(
-        &mut self,
+    pub(crate) fn draw_mesh_lines<FmtLabel, YH: KeyPointHint, XH: KeyPointHint>(
+        &self,
         (r, c): (YH, XH),
+        (x_key_points, y_key_points): (Option<&[X::ValueType]>, Option<&[Y::ValueType]>),
         (x_mesh, y_mesh): (bool, bool),
         mesh_line_style: &ShapeStyle,
         mut fmt_label: FmtLabel,
-    ) -> Result<(Vec<(i32, String)>, Vec<(i32, String)>), DrawingAreaErrorKind<DB::ErrorType>>
+    ) -> Result<(Vec<TickLabel>, Vec<TickLabel>), DrawingAreaErrorKind<DB::ErrorType>>
     where
-        FmtLabel: FnMut(&X, &Y, &MeshLine<X, Y>) -> Option<String>,
+        FmtLabel: FnMut(
+            &X,
+            &Y,
+            &MeshLine<X, Y>,
+            usize,
+            (&[X::ValueType], &[Y::ValueType]),
+        ) -> Option<String>,
     {
         let mut x_labels = vec![];
         let mut y_labels = vec![];
         let xr = self.drawing_area.as_coord_spec().x_spec();
         let yr = self.drawing_area.as_coord_spec().y_spec();
-        self.drawing_area.draw_mesh(
+
+        /* If the key points are given explicitly, use them instead of the key point algorithm */
+        let (x_auto, y_auto);
+        let x_key_points = match x_key_points {
+            Some(key_points) => key_points,
+            None => {
+                x_auto = xr.key_points(c);
+                &x_auto[..]
+            }
+        };
+        let y_key_points = match y_key_points {
+            Some(key_points) => key_points,
+            None => {
+                y_auto = yr.key_points(r);
+                &y_auto[..]
+            }
+        };
+
+        /* The formatter also gets the index of the line and all the key points, so that the
+         * label is able to depend on its neighbours */
+        let (mut x_idx, mut y_idx) = (0, 0);
+        let key_points = (x_key_points, y_key_points);
+
+        self.drawing_area.draw_mesh_with_key_points(
             |b, l| {
                 let draw = match l {
-                    MeshLine::XMesh((x, _), _, _) => {
-                        if let Some(label_text) = fmt_label(xr, yr, &l) {
-                            x_labels.push((x, label_text));
+                    MeshLine::XMesh((x, _), _, value) => {
+                        if let Some(label_text) = fmt_label(xr, yr, &l, x_idx, key_points) {
+                            x_labels.push((x, xr.key_point_tier(value), label_text));
                         }
+                        x_idx += 1;
                         x_mesh
                     }
-                    MeshLine::YMesh((_, y), _, _) => {
-                        if let Some(label_text) = fmt_label(xr, yr, &l) {
-                            y_labels.push((y, label_text));
+                    MeshLine::YMesh((_, y), _, value) => {
+                        if let Some(label_text) = fmt_label(xr, yr, &l, y_idx, key_points) {
+                            y_labels.push((y, yr.key_point_tier(value), label_text));
                         }
+                        y_idx += 1;
                         y_mesh
                     }
                 };
@@ -55,21 +242,15 @@ impl<'a, DB: DrawingBackend, X: Ranged, Y: Ranged> ChartContext<'a, DB, Cartesia
                     Ok(())
                 }
             },
-            r,
-            c,
+            x_key_points,
+            y_key_points,
         )?;
         Ok((x_labels, y_labels))
     }
 
-    fn draw_axis(
-        &self,
-        area: &DrawingArea<DB, Shift>,
-        axis_style: Option<&ShapeStyle>,
-        orientation: (i16, i16),
-        inward_labels: bool,
-    ) -> Result<Range<i32>, DrawingAreaErrorKind<DB::ErrorType>> {
+    /// Get the pixel range of the axis, in the logic coordinate of the label area
+    fn local_axis_range(&self, orientation: (i16, i16)) -> Range<i32> {
         let (x0, y0) = self.drawing_area.get_base_pixel();
-        let (tw, th) = area.dim_in_pixel();
 
         let mut axis_range = if orientation.0 == 0 {
             self.drawing_area.get_x_axis_pixel_range()
@@ -87,42 +268,535 @@ impl<'a, DB: DrawingBackend, X: Ranged, Y: Ranged> ChartContext<'a, DB, Cartesia
             axis_range.end -= y0;
         }
 
+        axis_range
+    }
+
+    /// Get the part of the label area where the axis is drawn, which leaves the gap of the axis
+    /// offset between the axis and the plotting area
+    fn offset_axis_area(
+        &self,
+        area: &DrawingArea<DB, Shift>,
+        orientation: (i16, i16),
+    ) -> DrawingArea<DB, Shift> {
+        let d = self.axis_offset;
+        match orientation {
+            (0, dy) if dy > 0 => area.margin(d, 0, 0, 0),
+            (0, _) => area.margin(0, d, 0, 0),
+            (dx, _) if dx > 0 => area.margin(0, 0, d, 0),
+            _ => area.margin(0, 0, 0, d),
+        }
+    }
+
+    fn draw_axis(
+        &self,
+        area: &DrawingArea<DB, Shift>,
+        axis_style: Option<&ShapeStyle>,
+        orientation: (i16, i16),
+        inward_labels: bool,
+    ) -> Result<Range<i32>, DrawingAreaErrorKind<DB::ErrorType>> {
+        let (x0, y0) = self.drawing_area.get_base_pixel();
+        let axis_range = self.local_axis_range(orientation);
+
+        let mut breaks = if orientation.0 == 0 {
+            self.drawing_area.get_x_axis_breaks()
+        } else {
+            self.drawing_area.get_y_axis_breaks()
+        };
+        for gap in breaks.iter_mut() {
+            let base = if orientation.0 == 0 { x0 } else { y0 };
+            *gap = (gap.start - base)..(gap.end - base);
+        }
+        breaks.sort_by_key(|r| r.start);
+
+        let markers: Vec<i32> = if orientation.0 == 0 {
+            self.drawing_area
+                .get_x_axis_markers()
+                .into_iter()
+                .map(|p| p - x0)
+                .collect()
+        } else {
+            self.drawing_area
+                .get_y_axis_markers()
+                .into_iter()
+                .map(|p| p - y0)
+                .collect()
+        };
+
         if let Some(axis_style) = axis_style {
-            let mut x0 = if orientation.0 > 0 { 0 } else { tw as i32 - 1 };
-            let mut y0 = if orientation.1 > 0 { 0 } else { th as i32 - 1 };
-            let mut x1 = if orientation.0 >= 0 { 0 } else { tw as i32 - 1 };
-            let mut y1 = if orientation.1 >= 0 { 0 } else { th as i32 - 1 };
-
-            if inward_labels {
-                if orientation.0 == 0 {
-                    if y0 == 0 {
-                        y0 = th as i32 - 1;
-                        y1 = th as i32 - 1;
+            let ((x0, y0), (x1, y1)) =
+                axis_line_ends(area.dim_in_pixel(), orientation, inward_labels, &axis_range);
+
+            // Convert a position along the axis into the point on the axis line
+            let at = |p: i32| if orientation.0 == 0 { (p, y0) } else { (x0, p) };
+
+            let (mut from, to) = if orientation.0 == 0 {
+                (x0, x1)
+            } else {
+                (y0, y1)
+            };
+            for gap in breaks {
+                area.draw(&PathElement::new(
+                    vec![at(from), at(gap.start)],
+                    *axis_style,
+                ))?;
+                // The break marker is a short slanted line on both sides of the gap
+                for p in [gap.start, gap.end].iter() {
+                    let (px, py) = at(*p);
+                    let points = if orientation.0 == 0 {
+                        vec![(px - 3, py + 5), (px + 3, py - 5)]
                     } else {
-                        y0 = 0;
-                        y1 = 0;
-                    }
-                } else if x0 == 0 {
-                    x0 = tw as i32 - 1;
-                    x1 = tw as i32 - 1;
+                        vec![(px - 5, py + 3), (px + 5, py - 3)]
+                    };
+                    area.draw(&PathElement::new(points, *axis_style))?;
+                }
+                from = gap.end;
+            }
+            area.draw(&PathElement::new(vec![at(from), at(to)], *axis_style))?;
+
+            // The scale change marker is a pair of short lines across the axis line
+            for p in markers {
+                for offset in [-1, 1].iter() {
+                    let (px, py) = at(p + offset);
+                    let points = if orientation.0 == 0 {
+                        vec![(px, py - 4), (px, py + 4)]
+                    } else {
+                        vec![(px - 4, py), (px + 4, py)]
+                    };
+                    area.draw(&PathElement::new(points, *axis_style))?;
+                }
+            }
+        }
+
+        Ok(axis_range)
+    }
+
+    /// Draw a single tick mark at the backend coordinate `p` on the label area
+    fn draw_tick_mark(
+        &self,
+        area: &DrawingArea<DB, Shift>,
+        style: &ShapeStyle,
+        p: i32,
+        orientation: (i16, i16),
+        tick_size: i32,
+    ) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>> {
+        if tick_size == 0 {
+            return Ok(());
+        }
+
+        let (x0, y0) = self.drawing_area.get_base_pixel();
+        let (tw, th) = area.dim_in_pixel();
+        let xmax = tw as i32 - 1;
+        let ymax = th as i32 - 1;
+        let (kx0, ky0, kx1, ky1) = if tick_size > 0 {
+            match orientation {
+                (dx, dy) if dx > 0 && dy == 0 => (0, p - y0, tick_size, p - y0),
+                (dx, dy) if dx < 0 && dy == 0 => (xmax - tick_size, p - y0, xmax, p - y0),
+                (dx, dy) if dx == 0 && dy > 0 => (p - x0, 0, p - x0, tick_size),
+                (dx, dy) if dx == 0 && dy < 0 => (p - x0, ymax - tick_size, p - x0, ymax),
+                _ => panic!("Bug: Invalid orientation specification"),
+            }
+        } else {
+            match orientation {
+                (dx, dy) if dx > 0 && dy == 0 => (xmax, p - y0, xmax + tick_size, p - y0),
+                (dx, dy) if dx < 0 && dy == 0 => (0, p - y0, -tick_size, p - y0),
+                (dx, dy) if dx == 0 && dy > 0 => (p - x0, ymax, p - x0, ymax + tick_size),
+                (dx, dy) if dx == 0 && dy < 0 => (p - x0, 0, p - x0, -tick_size),
+                _ => panic!("Bug: Invalid orientation specification"),
+            }
+        };
+        let line = PathElement::new(vec![(kx0, ky0), (kx1, ky1)], *style);
+        area.draw(&line)
+    }
+
+    /// Draw the tick marks without labels and grid lines on the label area
+    fn draw_ticks_only(
+        &self,
+        area: Option<&DrawingArea<DB, Shift>>,
+        style: &ShapeStyle,
+        ticks: &[i32],
+        orientation: (i16, i16),
+        tick_size: i32,
+    ) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>> {
+        let area = if let Some(target) = area {
+            &self.offset_axis_area(target, orientation)
+        } else {
+            return Ok(());
+        };
+
+        let (x0, y0) = self.drawing_area.get_base_pixel();
+        let axis_range = self.draw_axis(area, None, orientation, tick_size < 0)?;
+
+        for p in ticks {
+            let rp = if orientation.0 == 0 { *p - x0 } else { *p - y0 };
+
+            if rp < axis_range.start.min(axis_range.end)
+                || axis_range.end.max(axis_range.start) < rp
+            {
+                continue;
+            }
+
+            self.draw_tick_mark(area, style, *p, orientation, tick_size)?;
+        }
+
+        Ok(())
+    }
+
+    /// Decide which labels should be drawn and how they should be rotated, so that the labels
+    /// don't overlap each other
+    fn fit_labels<'l, 's>(
+        &self,
+        labels: &'l [TickLabel],
+        label_style: &TextStyle<'s>,
+        orientation: (i16, i16),
+        overlap: LabelOverlap,
+    ) -> (Vec<&'l TickLabel>, TextStyle<'s>) {
+        let style = label_style.clone();
+
+        if overlap == LabelOverlap::Allow || labels.len() < 2 {
+            return (labels.iter().collect(), style);
+        }
+
+        let sizes: Vec<_> = labels
+            .iter()
+            .map(|(_, _, text)| {
+                let (w, h) = self.text_block_size(text, label_style).unwrap_or((0, 0));
+                (f64::from(w), f64::from(h))
+            })
+            .collect();
+
+        /* Check if the labels overlap when only every `stride`-th label is drawn */
+        let fits = |style: &TextStyle, stride: usize| {
+            let (sin, cos) = label_rotation(style).unwrap_or((0.0, 1.0));
+            /* The angle between the text and the axis */
+            let (sin, cos) = if orientation.0 == 0 {
+                (sin, cos)
+            } else {
+                (cos, sin)
+            };
+            let kept: Vec<_> = labels.iter().zip(sizes.iter()).step_by(stride).collect();
+            kept.windows(2).all(|pair| {
+                let ((pa, _, _), (wa, ha)) = pair[0];
+                let ((pb, _, _), (wb, hb)) = pair[1];
+                let (w, h) = ((wa + wb) / 2.0, ha.max(*hb));
+                /* Two rotated text boxes are apart if they are apart in the direction of either
+                 * the text or the text height */
+                let along = if cos.abs() > 1e-3 {
+                    w / cos.abs()
                 } else {
-                    x0 = 0;
-                    x1 = 0;
+                    f64::INFINITY
+                };
+                let across = if sin.abs() > 1e-3 {
+                    h / sin.abs()
+                } else {
+                    f64::INFINITY
+                };
+                f64::from((pa - pb).abs()) >= along.min(across) + h / 4.0
+            })
+        };
+
+        if fits(&style, 1) {
+            return (labels.iter().collect(), style);
+        }
+
+        let style = match overlap {
+            LabelOverlap::Rotate(angle) => {
+                let rotated = rotate_label_style(style, angle);
+                if fits(&rotated, 1) {
+                    return (labels.iter().collect(), rotated);
                 }
+                rotated
+            }
+            _ => style,
+        };
+
+        let mut stride = 2;
+        while stride < labels.len() && !fits(&style, stride) {
+            stride *= 2;
+        }
+
+        (labels.iter().step_by(stride).collect(), style)
+    }
+
+    /// Check if the label centered at its tick mark goes beyond the chart, which is made up by
+    /// the plotting area and the label areas. Returns the shift which moves the label back into
+    /// the chart, or `None` if the label is inside the chart.
+    fn fit_edge_label(
+        &self,
+        area: &DrawingArea<DB, Shift>,
+        text: &str,
+        style: &TextStyle,
+        (h_pos, v_pos): (HPos, VPos),
+        (x, y): (i32, i32),
+    ) -> Option<(i32, i32)> {
+        let (mut x_range, mut y_range) = self.drawing_area.get_pixel_range();
+        for label_area in self.x_label_area.iter().chain(self.y_label_area.iter()) {
+            if let Some((xr, yr)) = label_area.as_ref().map(|a| a.get_pixel_range()) {
+                x_range = x_range.start.min(xr.start)..x_range.end.max(xr.end);
+                y_range = y_range.start.min(yr.start)..y_range.end.max(yr.end);
             }
+        }
 
-            if orientation.0 == 0 {
-                x0 = axis_range.start;
-                x1 = axis_range.end;
+        let (w, h) = self.text_block_size(text, style)?;
+        let (w, h) = (w as i32, h as i32);
+        let (ax, ay) = area.get_base_pixel();
+        /* Only the labels centered at the tick marks can go beyond the ends of the axis */
+        let shift = |start: i32, size: i32, range: &Range<i32>| {
+            if start < range.start {
+                Some(range.start - start)
+            } else if start + size > range.end {
+                Some((range.end - start - size).max(range.start - start))
             } else {
-                y0 = axis_range.start;
-                y1 = axis_range.end;
+                None
+            }
+        };
+        match (h_pos, v_pos) {
+            (HPos::Center, VPos::Top) | (HPos::Center, VPos::Bottom) => {
+                shift(ax + x - w / 2, w, &x_range).map(|dx| (dx, 0))
+            }
+            (HPos::Left, VPos::Center) | (HPos::Right, VPos::Center) => {
+                shift(ay + y - h / 2, h, &y_range).map(|dy| (0, dy))
             }
+            _ => None,
+        }
+    }
 
-            area.draw(&PathElement::new(vec![(x0, y0), (x1, y1)], *axis_style))?;
+    /// Grow the label area at the given position to the given size, by taking the space of the
+    /// plotting area. The label areas overlapping the plotting area, and the plotting areas
+    /// shared with a secondary coordinate are kept as they are.
+    fn reserve_label_area(&mut self, pos: LabelAreaPosition, size: i32) {
+        let area = match pos {
+            LabelAreaPosition::Top => self.x_label_area[0].as_ref(),
+            LabelAreaPosition::Bottom => self.x_label_area[1].as_ref(),
+            LabelAreaPosition::Left => self.y_label_area[0].as_ref(),
+            LabelAreaPosition::Right => self.y_label_area[1].as_ref(),
+        };
+        if self.fixed_layout || area.is_none() || self.is_overlapping_drawing_area(area) {
+            return;
+        }
+        let vertical = matches!(pos, LabelAreaPosition::Top | LabelAreaPosition::Bottom);
+        let pick = |(w, h): (u32, u32)| if vertical { h as i32 } else { w as i32 };
+        let thickness = area.map_or(0, |area| pick(area.dim_in_pixel()));
+        /* Keep at least one pixel of the plotting area */
+        let delta = (size - thickness).min(pick(self.drawing_area.dim_in_pixel()) - 1);
+        if delta <= 0 {
+            return;
         }
 
-        Ok(axis_range)
+        /* The edges of the plotting area which moves, in the order of the margin */
+        let plot_edges = match pos {
+            LabelAreaPosition::Top => (delta, 0, 0, 0),
+            LabelAreaPosition::Bottom => (0, delta, 0, 0),
+            LabelAreaPosition::Left => (0, 0, delta, 0),
+            LabelAreaPosition::Right => (0, 0, 0, delta),
+        };
+        let (top, bottom, left, right) = plot_edges;
+        /* The label area grows towards the plotting area, and the label areas on the sides
+         * next to it follow the plotting area */
+        let (label_area, side_areas) = if vertical {
+            (&mut self.x_label_area[pos as usize], &mut self.y_label_area)
+        } else {
+            (
+                &mut self.y_label_area[pos as usize - 2],
+                &mut self.x_label_area,
+            )
+        };
+        if let Some(area) = label_area.as_mut() {
+            *area = area.margin(-bottom, -top, -right, -left);
+        }
+        for area in side_areas.iter_mut().flatten() {
+            *area = area.margin(top, bottom, left, right);
+        }
+
+        self.drawing_area.move_edges(plot_edges);
+        let mut pixel_range = self.drawing_area.get_pixel_range();
+        pixel_range.0.end -= 1;
+        pixel_range.1.end -= 1;
+        pixel_range.1 = pixel_range.1.end..pixel_range.1.start;
+        self.drawing_area
+            .as_coord_spec_mut()
+            .set_pixel_range(pixel_range);
+        self.drawing_area_pos.0 += left;
+        self.drawing_area_pos.1 += top;
+    }
+
+    /// Grow the label areas which are too small for the rotated labels, so that the rotated
+    /// labels aren't clipped. The space of a label is the bounding box of the rotated text.
+    /// This should be done before anything is drawn, since the plotting area shrinks.
+    /// - `shown_labels`: If the labels of each label area are drawn in the label area
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn reserve_rotated_labels<FmtLabel, YH: KeyPointHint, XH: KeyPointHint>(
+        &mut self,
+        (r, c): (YH, XH),
+        key_points: (Option<&[X::ValueType]>, Option<&[Y::ValueType]>),
+        fmt_label: FmtLabel,
+        (x_label_style, y_label_style): (&TextStyle, &TextStyle),
+        (x_tick_size, y_tick_size): ([i32; 2], [i32; 2]),
+        (x_label_padding, y_label_padding): (Option<i32>, Option<i32>),
+        (x_overlap, y_overlap): (LabelOverlap, LabelOverlap),
+        shown_labels: [bool; 4],
+    ) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>>
+    where
+        FmtLabel: FnMut(
+            &X,
+            &Y,
+            &MeshLine<X, Y>,
+            usize,
+            (&[X::ValueType], &[Y::ValueType]),
+        ) -> Option<String>,
+    {
+        let (x_labels, y_labels) = self.draw_mesh_lines(
+            (r, c),
+            key_points,
+            (false, false),
+            &TRANSPARENT.into(),
+            fmt_label,
+        )?;
+
+        let positions = [
+            LabelAreaPosition::Top,
+            LabelAreaPosition::Bottom,
+            LabelAreaPosition::Left,
+            LabelAreaPosition::Right,
+        ];
+        for (idx, pos) in positions.iter().enumerate() {
+            let (labels, style, tick_size, padding, overlap, orientation) = if idx < 2 {
+                let orientation = (0, -1 + idx as i16 * 2);
+                let tick_size = x_tick_size[idx];
+                (
+                    &x_labels,
+                    x_label_style,
+                    tick_size,
+                    x_label_padding,
+                    x_overlap,
+                    orientation,
+                )
+            } else {
+                let orientation = (-1 + (idx - 2) as i16 * 2, 0);
+                let tick_size = y_tick_size[idx - 2];
+                (
+                    &y_labels,
+                    y_label_style,
+                    tick_size,
+                    y_label_padding,
+                    y_overlap,
+                    orientation,
+                )
+            };
+            if !shown_labels[idx] || tick_size < 0 {
+                continue;
+            }
+
+            /* Only the labels on the innermost tier are rotated */
+            let inner_labels: Vec<_> = labels
+                .iter()
+                .filter(|(_, tier, _)| *tier == 0)
+                .cloned()
+                .collect();
+            let (labels, style) = self.fit_labels(&inner_labels, style, orientation, overlap);
+            if labels.is_empty() || label_rotation(&style).is_none() {
+                continue;
+            }
+            /* The far edge of each label box, measured from where the labels start */
+            let normal = (i32::from(orientation.0), i32::from(orientation.1));
+            let extent = labels
+                .iter()
+                .filter_map(|(_, _, text)| {
+                    let (shift, h_pos, v_pos) = self.rotated_label_anchor(text, &style, normal);
+                    let ((l, t), (r, b)) = self.label_rect(text, &style, (h_pos, v_pos))?;
+                    Some(
+                        shift
+                            + (-l * normal.0).max(r * normal.0)
+                            + (-t * normal.1).max(b * normal.1),
+                    )
+                })
+                .max()
+                .unwrap_or(0);
+            let label_dist = tick_size.abs() + padding.unwrap_or_else(|| tick_size.abs());
+            self.reserve_label_area(*pos, self.axis_offset + label_dist + extent);
+        }
+
+        Ok(())
+    }
+
+    /// Get the anchor of a rotated label and how much further than the label distance it is
+    /// put away from the axis, so that the rotated text is entirely outside of the axis
+    /// - `(nx, ny)`: The direction that points from the axis to the label
+    fn rotated_label_anchor(
+        &self,
+        text: &str,
+        style: &TextStyle,
+        (nx, ny): (i32, i32),
+    ) -> (i32, HPos, VPos) {
+        let (sin, cos) = label_rotation(style).unwrap_or((0.0, 1.0));
+        /* How much the text direction and the text height direction goes away from the axis
+         * after the rotation */
+        let along = cos * f64::from(nx) + sin * f64::from(ny);
+        let across = -sin * f64::from(nx) + cos * f64::from(ny);
+        if along.abs() < 1e-3 {
+            let v_pos = if across > 0.0 {
+                VPos::Top
+            } else {
+                VPos::Bottom
+            };
+            (0, HPos::Center, v_pos)
+        } else {
+            let h_pos = if along > 0.0 { HPos::Left } else { HPos::Right };
+            let (_, h) = self.text_block_size(text, style).unwrap_or((0, 0));
+            /* The text which is perpendicular to the axis doesn't need any shift */
+            let across = if across.abs() < 1e-3 { 0.0 } else { across };
+            let shift = (f64::from(h) / 2.0 * across.abs()).ceil() as i32;
+            (shift, h_pos, VPos::Center)
+        }
+    }
+
+    /// Estimate the size of a text which may have multiple lines separated by `\n`, without the
+    /// rotation of the style: the width of the widest line and the height of all the lines
+    fn text_block_size(&self, text: &str, style: &TextStyle) -> Option<(u32, u32)> {
+        let style = style.transform(FontTransform::None);
+        let line_height = line_height(&style) as u32;
+        let mut size = (0, 0);
+        for (idx, line) in text.split('\n').enumerate() {
+            let (w, h) = self.drawing_area.estimate_text_size(line, &style).ok()?;
+            size = (size.0.max(w), idx as u32 * line_height + h);
+        }
+        Some(size)
+    }
+
+    /// Get the bounding box of the label relative to its anchor point, taking the rotation of
+    /// the label into account
+    fn label_rect(
+        &self,
+        text: &str,
+        style: &TextStyle,
+        (h_pos, v_pos): (HPos, VPos),
+    ) -> Option<(BackendCoord, BackendCoord)> {
+        let transform = style.font.get_transform();
+        let (w, h) = self.text_block_size(text, style)?;
+        let (w, h) = (w as i32, h as i32);
+        let left = match h_pos {
+            HPos::Left => 0,
+            HPos::Center => -w / 2,
+            HPos::Right => -w,
+        };
+        let top = match v_pos {
+            VPos::Top => 0,
+            VPos::Center => -h / 2,
+            VPos::Bottom => -h,
+        };
+        let corners = [
+            (left, top),
+            (left + w, top),
+            (left, top + h),
+            (left + w, top + h),
+        ]
+        .iter()
+        .map(|&(x, y)| transform.transform(x, y))
+        .collect::<Vec<_>>();
+        let min_x = corners.iter().map(|c| c.0).min()?;
+        let max_x = corners.iter().map(|c| c.0).max()?;
+        let min_y = corners.iter().map(|c| c.1).min()?;
+        let max_y = corners.iter().map(|c| c.1).max()?;
+        Some(((min_x, min_y), (max_x, max_y)))
     }
 
     // TODO: consider make this function less complicated
@@ -132,39 +806,94 @@ impl<'a, DB: DrawingBackend, X: Ranged, Y: Ranged> ChartContext<'a, DB, Cartesia
         &self,
         area: Option<&DrawingArea<DB, Shift>>,
         axis_style: Option<&ShapeStyle>,
-        labels: &[(i32, String)],
+        labels: &[TickLabel],
         label_style: &TextStyle,
         label_offset: i32,
         orientation: (i16, i16),
-        axis_desc: Option<(&str, &TextStyle)>,
+        axis_desc: Option<(&str, &TextStyle, AxisDescLayout)>,
         tick_size: i32,
-    ) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>> {
+        label_padding: Option<i32>,
+        (label_overlap, edge_labels): (LabelOverlap, EdgeLabels),
+    ) -> Result<Vec<DrawnTick>, DrawingAreaErrorKind<DB::ErrorType>> {
         let area = if let Some(target) = area {
-            target
+            &self.offset_axis_area(target, orientation)
         } else {
-            return Ok(());
+            return Ok(vec![]);
         };
 
         let (x0, y0) = self.drawing_area.get_base_pixel();
         let (tw, th) = area.dim_in_pixel();
+        let position = match orientation {
+            (0, dy) if dy < 0 => LabelAreaPosition::Top,
+            (0, _) => LabelAreaPosition::Bottom,
+            (dx, _) if dx < 0 => LabelAreaPosition::Left,
+            _ => LabelAreaPosition::Right,
+        };
+        let mut drawn = vec![];
 
         /* This is the minimal distance from the axis to the box of the labels */
-        let label_dist = tick_size.abs() * 2;
+        let label_dist = tick_size.abs() + label_padding.unwrap_or_else(|| tick_size.abs());
 
         /* Draw the axis and get the axis range so that we can do further label
          * and tick mark drawing */
+        /* Only the positions of the labels are computed when the axes are just laid out */
+        let axis_style = axis_style.filter(|_| !self.layout_only);
         let axis_range = self.draw_axis(area, axis_style, orientation, tick_size < 0)?;
 
+        /* The labels on the outer tiers are put further away from the axis, one tier per level.
+         * Only the labels on the innermost tier are dropped or rotated if they overlap each
+         * other */
+        let (inner_labels, outer_labels): (Vec<_>, Vec<_>) =
+            labels.iter().cloned().partition(|(_, tier, _)| *tier == 0);
+        let (mut labels, label_style) =
+            self.fit_labels(&inner_labels, label_style, orientation, label_overlap);
+        labels.extend(outer_labels.iter());
+        let label_style = &label_style;
+
+        /* For rotated labels, we need the sin and cos of the angle to find out where the rotated
+         * text goes, so that we can put the text entirely outside of the axis */
+        let rotation = label_rotation(label_style);
+
+        /* The direction that points from the axis to labels */
+        let (nx, ny) = if tick_size >= 0 {
+            (orientation.0 as i32, orientation.1 as i32)
+        } else {
+            (-orientation.0 as i32, -orientation.1 as i32)
+        };
+
+        /* Each outer tier starts where the largest label of the tier next to it ends */
+        let mut tier_extents = vec![];
+        for (_, tier, text) in labels.iter().filter(|_| !outer_labels.is_empty()) {
+            let tier = *tier;
+            if tier_extents.len() <= tier {
+                tier_extents.resize(tier + 1, 0);
+            }
+            if let Some(((l, t), (r, b))) =
+                self.label_rect(text, label_style, (HPos::Center, VPos::Center))
+            {
+                let extent = if orientation.0 == 0 { b - t } else { r - l };
+                tier_extents[tier] = tier_extents[tier].max(extent);
+            }
+        }
+        let tier_padding = label_dist - tick_size.abs();
+        let tier_offsets: Vec<_> = tier_extents
+            .iter()
+            .scan(0, |offset, extent| {
+                let current = *offset;
+                *offset += extent + tier_padding;
+                Some(current)
+            })
+            .collect();
+
         /* To make the right label area looks nice, it's a little bit tricky, since for a that is
          * very long, we actually prefer left alignment instead of right alignment.
          * Otherwise, the right alignment looks better. So we estimate the max and min label width
          * So that we are able decide if we should apply right alignment for the text. */
         let label_width: Vec<_> = labels
             .iter()
-            .map(|(_, text)| {
-                if orientation.0 > 0 && orientation.1 == 0 && tick_size >= 0 {
-                    self.drawing_area
-                        .estimate_text_size(text, label_style)
+            .map(|(_, _, text)| {
+                if orientation.0 > 0 && orientation.1 == 0 && tick_size >= 0 && rotation.is_none() {
+                    self.text_block_size(text, label_style)
                         .map(|(w, _)| w)
                         .unwrap_or(0) as i32
                 } else {
@@ -184,7 +913,7 @@ impl<'a, DB: DrawingBackend, X: Ranged, Y: Ranged> ChartContext<'a, DB, Cartesia
         let right_align_width = (min_width * 2).min(max_width);
 
         /* Then we need to draw the tick mark and the label */
-        for ((p, t), w) in labels.iter().zip(label_width.into_iter()) {
+        for ((p, tier, t), w) in labels.iter().zip(label_width.into_iter()) {
             /* Make sure we are actually in the visible range */
             let rp = if orientation.0 == 0 { *p - x0 } else { *p - y0 };
 
@@ -194,7 +923,17 @@ impl<'a, DB: DrawingBackend, X: Ranged, Y: Ranged> ChartContext<'a, DB, Cartesia
                 continue;
             }
 
-            let (cx, cy, h_pos, v_pos) = if tick_size >= 0 {
+            let (cx, cy, h_pos, v_pos) = if rotation.is_some() {
+                let (px, py) = match (nx, ny) {
+                    (1, 0) => (label_dist, *p - y0),
+                    (-1, 0) => (tw as i32 - label_dist, *p - y0),
+                    (0, 1) => (*p - x0, label_dist),
+                    (0, -1) => (*p - x0, th as i32 - label_dist),
+                    _ => panic!("Bug: Invalid orientation specification"),
+                };
+                let (shift, h_pos, v_pos) = self.rotated_label_anchor(t, label_style, (nx, ny));
+                (px + nx * shift, py + ny * shift, h_pos, v_pos)
+            } else if tick_size >= 0 {
                 match orientation {
                     // Right
                     (dx, dy) if dx > 0 && dy == 0 => {
@@ -241,82 +980,129 @@ impl<'a, DB: DrawingBackend, X: Ranged, Y: Ranged> ChartContext<'a, DB, Cartesia
                 }
             };
 
-            let (text_x, text_y) = if orientation.0 == 0 {
+            let (mut text_x, mut text_y) = if orientation.0 == 0 {
                 (cx + label_offset, cy)
             } else {
                 (cx, cy + label_offset)
             };
+            if let Some(offset) = tier_offsets.get(*tier) {
+                text_x += nx * offset;
+                text_y += ny * offset;
+            }
 
-            let label_style = &label_style.pos(Pos::new(h_pos, v_pos));
-            area.draw_text(t, label_style, (text_x, text_y))?;
-
-            if tick_size != 0 {
-                if let Some(style) = axis_style {
-                    let xmax = tw as i32 - 1;
-                    let ymax = th as i32 - 1;
-                    let (kx0, ky0, kx1, ky1) = if tick_size > 0 {
-                        match orientation {
-                            (dx, dy) if dx > 0 && dy == 0 => (0, *p - y0, tick_size, *p - y0),
-                            (dx, dy) if dx < 0 && dy == 0 => {
-                                (xmax - tick_size, *p - y0, xmax, *p - y0)
-                            }
-                            (dx, dy) if dx == 0 && dy > 0 => (*p - x0, 0, *p - x0, tick_size),
-                            (dx, dy) if dx == 0 && dy < 0 => {
-                                (*p - x0, ymax - tick_size, *p - x0, ymax)
-                            }
-                            _ => panic!("Bug: Invalid orientation specification"),
-                        }
-                    } else {
-                        match orientation {
-                            (dx, dy) if dx > 0 && dy == 0 => {
-                                (xmax, *p - y0, xmax + tick_size, *p - y0)
-                            }
-                            (dx, dy) if dx < 0 && dy == 0 => (0, *p - y0, -tick_size, *p - y0),
-                            (dx, dy) if dx == 0 && dy > 0 => {
-                                (*p - x0, ymax, *p - x0, ymax + tick_size)
-                            }
-                            (dx, dy) if dx == 0 && dy < 0 => (*p - x0, 0, *p - x0, -tick_size),
-                            _ => panic!("Bug: Invalid orientation specification"),
-                        }
-                    };
-                    let line = PathElement::new(vec![(kx0, ky0), (kx1, ky1)], *style);
-                    area.draw(&line)?;
+            let mut draw_label = !t.is_empty();
+            if draw_label && edge_labels != EdgeLabels::Keep && rotation.is_none() {
+                match self.fit_edge_label(area, t, label_style, (h_pos, v_pos), (text_x, text_y)) {
+                    Some(_) if edge_labels == EdgeLabels::Drop => draw_label = false,
+                    Some((dx, dy)) => {
+                        text_x += dx;
+                        text_y += dy;
+                    }
+                    None => {}
+                }
+            }
+
+            let mut label_rect = None;
+            if draw_label {
+                let label_style = &label_style.pos(Pos::new(h_pos, v_pos));
+                if !self.layout_only {
+                    draw_text_lines(area, t, label_style, (text_x, text_y))?;
                 }
+                let (ax, ay) = area.get_base_pixel();
+                label_rect =
+                    self.label_rect(t, label_style, (h_pos, v_pos))
+                        .map(|((l, t), (r, b))| {
+                            (
+                                (ax + text_x + l, ay + text_y + t),
+                                (ax + text_x + r, ay + text_y + b),
+                            )
+                        });
+            }
+            drawn.push(DrawnTick {
+                position,
+                pixel: *p,
+                text: t.to_string(),
+                label_rect,
+            });
+
+            if let Some(style) = axis_style {
+                self.draw_tick_mark(area, style, *p, orientation, tick_size)?;
             }
         }
 
-        if let Some((text, style)) = axis_desc {
-            let actual_style = if orientation.0 == 0 {
-                style.clone()
-            } else if orientation.0 == -1 {
-                style.transform(FontTransform::Rotate270)
-            } else {
-                style.transform(FontTransform::Rotate90)
+        if let Some((text, style, layout)) = axis_desc.filter(|_| !self.layout_only) {
+            let (tw, th) = (tw as i32, th as i32);
+            let transform = match (orientation, layout.orientation) {
+                ((0, _), _) | (_, Some(AxisDescOrientation::Horizontal)) => FontTransform::None,
+                (_, Some(AxisDescOrientation::Rotate90)) => FontTransform::Rotate90,
+                (_, Some(AxisDescOrientation::Rotate270)) => FontTransform::Rotate270,
+                ((dx, _), None) if dx < 0 => FontTransform::Rotate270,
+                _ => FontTransform::Rotate90,
             };
+            let actual_style = style.transform(transform.clone());
 
-            let (x0, y0, h_pos, v_pos) = match orientation {
-                // Right
-                (dx, dy) if dx > 0 && dy == 0 => (tw, th / 2, HPos::Center, VPos::Top),
-                // Left
-                (dx, dy) if dx < 0 && dy == 0 => (0, th / 2, HPos::Center, VPos::Top),
-                // Bottom
-                (dx, dy) if dx == 0 && dy > 0 => (tw / 2, th, HPos::Center, VPos::Bottom),
-                // Top
-                (dx, dy) if dx == 0 && dy < 0 => (tw / 2, 0, HPos::Center, VPos::Top),
-                _ => panic!("Bug: Invalid orientation specification"),
+            /* The direction which the top of the text faces, and the reading direction */
+            let (up, read) = match transform {
+                FontTransform::Rotate90 => ((1, 0), (0, 1)),
+                FontTransform::Rotate270 => ((-1, 0), (0, -1)),
+                _ => ((0, -1), (1, 0)),
+            };
+            let outward = (i32::from(orientation.0), i32::from(orientation.1));
+
+            let (x0, y0, h_pos, v_pos) = if orientation.0 != 0
+                && matches!(transform, FontTransform::None)
+            {
+                /* A horizontal description of the Y axis is put above the top of the axis, and
+                 * clear of the half of the top-most label which is above the axis, which may have
+                 * multiple lines */
+                let extra_lines = labels
+                    .iter()
+                    .map(|(_, _, text)| text.matches('\n').count())
+                    .max()
+                    .unwrap_or(0) as i32;
+                let top = -((label_style.font.get_size() / 2.0).ceil() as i32
+                    + (extra_lines * line_height(label_style) + 1) / 2)
+                    - tick_size.abs();
+                match (layout.placement, orientation.0) {
+                    (AxisDescPlacement::Center, _) => (tw / 2, top, HPos::Center, VPos::Bottom),
+                    (AxisDescPlacement::End, dx) if dx < 0 => (tw, top, HPos::Right, VPos::Bottom),
+                    (AxisDescPlacement::End, _) => (0, top, HPos::Left, VPos::Bottom),
+                }
+            } else {
+                /* Otherwise the description is put on the outer edge of the label area, either
+                 * centered or at the right end of the X axis and the top end of the Y axis */
+                let (x0, y0) = match (orientation, layout.placement) {
+                    ((0, dy), AxisDescPlacement::Center) => (tw / 2, if dy > 0 { th } else { 0 }),
+                    ((0, dy), AxisDescPlacement::End) => (tw, if dy > 0 { th } else { 0 }),
+                    ((dx, _), AxisDescPlacement::Center) => (if dx > 0 { tw } else { 0 }, th / 2),
+                    ((dx, _), AxisDescPlacement::End) => (if dx > 0 { tw } else { 0 }, 0),
+                };
+                let axis_end = if orientation.0 == 0 { (1, 0) } else { (0, -1) };
+                let h_pos = match layout.placement {
+                    AxisDescPlacement::Center => HPos::Center,
+                    AxisDescPlacement::End if read == axis_end => HPos::Right,
+                    AxisDescPlacement::End => HPos::Left,
+                };
+                let v_pos = if up == outward {
+                    VPos::Top
+                } else {
+                    VPos::Bottom
+                };
+                (x0, y0, h_pos, v_pos)
             };
 
             let actual_style = &actual_style.pos(Pos::new(h_pos, v_pos));
-            area.draw_text(text, actual_style, (x0 as i32, y0 as i32))?;
+            draw_text_lines(area, text, actual_style, (x0, y0))?;
         }
 
-        Ok(())
+        Ok(drawn)
     }
 
     #[allow(clippy::too_many_arguments)]
     pub(crate) fn draw_mesh<FmtLabel, YH: KeyPointHint, XH: KeyPointHint>(
         &mut self,
         (r, c): (YH, XH),
+        key_points: (Option<&[X::ValueType]>, Option<&[Y::ValueType]>),
         mesh_line_style: &ShapeStyle,
         x_label_style: &TextStyle,
         y_label_style: &TextStyle,
@@ -331,39 +1117,913 @@ impl<'a, DB: DrawingBackend, X: Ranged, Y: Ranged> ChartContext<'a, DB, Cartesia
         axis_desc_style: &TextStyle,
         x_desc: Option<String>,
         y_desc: Option<String>,
+        desc_layout: (AxisDescLayout, AxisDescLayout),
         x_tick_size: [i32; 2],
         y_tick_size: [i32; 2],
-    ) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>>
+        (x_label_padding, y_label_padding): (Option<i32>, Option<i32>),
+        label_inset: (Option<i32>, Option<i32>),
+        x_label_fit: (LabelOverlap, EdgeLabels),
+        y_label_fit: (LabelOverlap, EdgeLabels),
+        hidden_labels: [bool; 4],
+    ) -> Result<MeshLayout, DrawingAreaErrorKind<DB::ErrorType>>
     where
-        FmtLabel: FnMut(&X, &Y, &MeshLine<X, Y>) -> Option<String>,
+        FmtLabel: FnMut(
+            &X,
+            &Y,
+            &MeshLine<X, Y>,
+            usize,
+            (&[X::ValueType], &[Y::ValueType]),
+        ) -> Option<String>,
     {
-        let (x_labels, y_labels) =
-            self.draw_mesh_lines((r, c), (x_mesh, y_mesh), mesh_line_style, fmt_label)?;
+        let (x_labels, y_labels) = self.draw_mesh_lines(
+            (r, c),
+            key_points,
+            (x_mesh, y_mesh),
+            mesh_line_style,
+            fmt_label,
+        )?;
+
+        self.draw_axes(
+            (&x_labels[..], &y_labels[..]),
+            x_label_style,
+            y_label_style,
+            x_label_offset,
+            y_label_offset,
+            x_axis,
+            y_axis,
+            axis_style,
+            axis_desc_style,
+            x_desc.as_deref(),
+            y_desc.as_deref(),
+            desc_layout,
+            x_tick_size,
+            y_tick_size,
+            (x_label_padding, y_label_padding),
+            label_inset,
+            x_label_fit,
+            y_label_fit,
+            hidden_labels,
+        )
+    }
+
+    /// Draw the offset text of the X axis at the bottom right corner and the one of the Y axis
+    /// at the top left corner of the plotting area
+    pub(crate) fn draw_offset_text(
+        &self,
+        (x_text, y_text): (Option<&str>, Option<&str>),
+        style: &TextStyle,
+    ) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>> {
+        let area = self.drawing_area.strip_coord_spec();
+        let (w, h) = area.dim_in_pixel();
+        let pad = (style.font.get_size() / 4.0).ceil() as i32;
+        if let Some(text) = x_text {
+            let style = style.pos(Pos::new(HPos::Right, VPos::Bottom));
+            area.draw_text(text, &style, (w as i32 - pad, h as i32 - pad))?;
+        }
+        if let Some(text) = y_text {
+            let style = style.pos(Pos::new(HPos::Left, VPos::Top));
+            area.draw_text(text, &style, (pad, pad))?;
+        }
+        Ok(())
+    }
 
+    /// Draw the axes, tick marks, labels and axis descriptions on all the label areas
+    /// - `label_inset`: The distances from the X and Y axes to the labels which are drawn inside
+    ///   the plotting area instead of the label areas
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn draw_axes(
+        &self,
+        (x_labels, y_labels): (&[TickLabel], &[TickLabel]),
+        x_label_style: &TextStyle,
+        y_label_style: &TextStyle,
+        x_label_offset: i32,
+        y_label_offset: i32,
+        x_axis: bool,
+        y_axis: bool,
+        axis_style: &ShapeStyle,
+        axis_desc_style: &TextStyle,
+        x_desc: Option<&str>,
+        y_desc: Option<&str>,
+        (x_desc_layout, y_desc_layout): (AxisDescLayout, AxisDescLayout),
+        x_tick_size: [i32; 2],
+        y_tick_size: [i32; 2],
+        (x_label_padding, y_label_padding): (Option<i32>, Option<i32>),
+        label_inset: (Option<i32>, Option<i32>),
+        x_label_fit: (LabelOverlap, EdgeLabels),
+        y_label_fit: (LabelOverlap, EdgeLabels),
+        hidden_labels: [bool; 4],
+    ) -> Result<MeshLayout, DrawingAreaErrorKind<DB::ErrorType>> {
+        // The hidden labels keep their tick marks, so only their text is dropped
+        let blank = |labels: &[TickLabel]| -> Vec<TickLabel> {
+            labels
+                .iter()
+                .map(|(p, tier, _)| (*p, *tier, String::new()))
+                .collect()
+        };
+        let (x_blank, y_blank) = (blank(x_labels), blank(y_labels));
+        let (x_inset, y_inset) = label_inset;
+        let plotting_area = self.drawing_area.strip_coord_spec();
+        let mut layout = MeshLayout::default();
         for idx in 0..2 {
-            self.draw_axis_and_labels(
+            let x_ticks = self.draw_axis_and_labels(
                 self.x_label_area[idx].as_ref(),
                 if x_axis { Some(axis_style) } else { None },
-                &x_labels[..],
+                if hidden_labels[idx] || x_inset.is_some() {
+                    &x_blank
+                } else {
+                    x_labels
+                },
                 x_label_style,
                 x_label_offset,
                 (0, -1 + idx as i16 * 2),
-                x_desc.as_ref().map(|desc| (&desc[..], axis_desc_style)),
+                x_desc.map(|desc| (desc, axis_desc_style, x_desc_layout)),
                 x_tick_size[idx],
+                x_label_padding,
+                x_label_fit,
             )?;
+            layout.ticks.extend(x_ticks);
 
-            self.draw_axis_and_labels(
+            let y_ticks = self.draw_axis_and_labels(
                 self.y_label_area[idx].as_ref(),
                 if y_axis { Some(axis_style) } else { None },
-                &y_labels[..],
+                if hidden_labels[2 + idx] || y_inset.is_some() {
+                    &y_blank
+                } else {
+                    y_labels
+                },
                 y_label_style,
                 y_label_offset,
                 (-1 + idx as i16 * 2, 0),
-                y_desc.as_ref().map(|desc| (&desc[..], axis_desc_style)),
+                y_desc.map(|desc| (desc, axis_desc_style, y_desc_layout)),
+                y_tick_size[idx],
+                y_label_padding,
+                y_label_fit,
+            )?;
+            layout.ticks.extend(y_ticks);
+
+            /* The inset labels are drawn on the plotting area as if the label area was overlapping
+             * it, while the axis and the tick marks stay in the label area */
+            if let (Some(inset), Some(_), false) =
+                (x_inset, self.x_label_area[idx].as_ref(), hidden_labels[idx])
+            {
+                let orientation = (0, -1 + idx as i16 * 2);
+                let x_ticks = self.draw_axis_and_labels(
+                    Some(&plotting_area),
+                    None,
+                    x_labels,
+                    x_label_style,
+                    x_label_offset,
+                    orientation,
+                    None,
+                    -inset.max(1),
+                    Some(0),
+                    x_label_fit,
+                )?;
+                layout.ticks.retain(|tick| tick.position as usize != idx);
+                layout.ticks.extend(x_ticks);
+            }
+            if let (Some(inset), Some(_), false) = (
+                y_inset,
+                self.y_label_area[idx].as_ref(),
+                hidden_labels[2 + idx],
+            ) {
+                let orientation = (-1 + idx as i16 * 2, 0);
+                let y_ticks = self.draw_axis_and_labels(
+                    Some(&plotting_area),
+                    None,
+                    y_labels,
+                    y_label_style,
+                    y_label_offset,
+                    orientation,
+                    None,
+                    -inset.max(1),
+                    Some(0),
+                    y_label_fit,
+                )?;
+                layout
+                    .ticks
+                    .retain(|tick| tick.position as usize != 2 + idx);
+                layout.ticks.extend(y_ticks);
+            }
+        }
+
+        Ok(layout)
+    }
+
+    /// Get the backend positions of the tick marks for the key points, without drawing anything
+    #[allow(clippy::type_complexity)]
+    fn tick_positions<YH: KeyPointHint, XH: KeyPointHint>(
+        &self,
+        (r, c): (YH, XH),
+        key_points: (Option<&[X::ValueType]>, Option<&[Y::ValueType]>),
+        (x_axis, y_axis): (bool, bool),
+    ) -> Result<(Vec<i32>, Vec<i32>), DrawingAreaErrorKind<DB::ErrorType>> {
+        let (x_ticks, y_ticks) = self.draw_mesh_lines(
+            (r, c),
+            key_points,
+            (false, false),
+            &TRANSPARENT.into(),
+            |_, _, m, _, _| match m {
+                MeshLine::XMesh(..) if x_axis => Some(String::new()),
+                MeshLine::YMesh(..) if y_axis => Some(String::new()),
+                _ => None,
+            },
+        )?;
+        Ok((
+            x_ticks.into_iter().map(|(p, _, _)| p).collect(),
+            y_ticks.into_iter().map(|(p, _, _)| p).collect(),
+        ))
+    }
+
+    /// Draw the fine grid lines which divide each interval between the coarse grid lines evenly
+    /// - `divisions`: The number of the divisions for X and Y, the axes with less than 2
+    ///   divisions don't get any line
+    pub(crate) fn draw_minor_division_lines<YH: KeyPointHint, XH: KeyPointHint>(
+        &self,
+        (r, c): (YH, XH),
+        key_points: (Option<&[X::ValueType]>, Option<&[Y::ValueType]>),
+        (x_div, y_div): (usize, usize),
+        style: &ShapeStyle,
+    ) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>> {
+        let (x_major, y_major) = self.tick_positions((r, c), key_points, (x_div > 1, y_div > 1))?;
+        let (x0, y0) = self.drawing_area.get_base_pixel();
+        let area = self.drawing_area.strip_coord_spec();
+        let (w, h) = area.dim_in_pixel();
+        let (w, h) = (w as i32, h as i32);
+
+        for x in division_positions(x_major, x_div, x0..x0 + w) {
+            area.draw(&PathElement::new(
+                vec![(x - x0, 0), (x - x0, h - 1)],
+                *style,
+            ))?;
+        }
+        for y in division_positions(y_major, y_div, y0..y0 + h) {
+            area.draw(&PathElement::new(
+                vec![(0, y - y0), (w - 1, y - y0)],
+                *style,
+            ))?;
+        }
+        Ok(())
+    }
+
+    /// Fill every other interval between the coarse grid lines, starting from the first full
+    /// interval. The X bands are vertical and the Y bands are horizontal.
+    pub(crate) fn draw_alternating_bands<YH: KeyPointHint, XH: KeyPointHint>(
+        &self,
+        (r, c): (YH, XH),
+        key_points: (Option<&[X::ValueType]>, Option<&[Y::ValueType]>),
+        (x_style, y_style): (Option<&ShapeStyle>, Option<&ShapeStyle>),
+    ) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>> {
+        let (x_major, y_major) =
+            self.tick_positions((r, c), key_points, (x_style.is_some(), y_style.is_some()))?;
+        let (x0, y0) = self.drawing_area.get_base_pixel();
+        let area = self.drawing_area.strip_coord_spec();
+        let (w, h) = area.dim_in_pixel();
+        let (w, h) = (w as i32, h as i32);
+
+        if let Some(style) = x_style {
+            for (a, b) in band_ranges(x_major, x0..x0 + w) {
+                area.draw(&Rectangle::new(
+                    [(a - x0, 0), (b - x0, h - 1)],
+                    style.filled(),
+                ))?;
+            }
+        }
+        if let Some(style) = y_style {
+            for (a, b) in band_ranges(y_major, y0..y0 + h) {
+                area.draw(&Rectangle::new(
+                    [(0, a - y0), (w - 1, b - y0)],
+                    style.filled(),
+                ))?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Draw the minor tick marks, which are tick marks without labels and grid lines
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn draw_minor_ticks<YH: KeyPointHint, XH: KeyPointHint>(
+        &self,
+        (r, c): (YH, XH),
+        tick_style: &ShapeStyle,
+        x_axis: bool,
+        y_axis: bool,
+        x_tick_size: [i32; 2],
+        y_tick_size: [i32; 2],
+    ) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>> {
+        let (x_ticks, y_ticks) = self.tick_positions((r, c), (None, None), (x_axis, y_axis))?;
+
+        for idx in 0..2 {
+            self.draw_ticks_only(
+                self.x_label_area[idx].as_ref(),
+                tick_style,
+                &x_ticks[..],
+                (0, -1 + idx as i16 * 2),
+                x_tick_size[idx],
+            )?;
+            self.draw_ticks_only(
+                self.y_label_area[idx].as_ref(),
+                tick_style,
+                &y_ticks[..],
+                (-1 + idx as i16 * 2, 0),
                 y_tick_size[idx],
             )?;
         }
 
         Ok(())
     }
+
+    /// Draw the arrowheads at the ends of the axes where the axis values are the largest
+    pub(crate) fn draw_axis_arrows(
+        &self,
+        style: &ShapeStyle,
+        size: i32,
+        (x_axis, y_axis): (bool, bool),
+        x_tick_size: [i32; 2],
+        y_tick_size: [i32; 2],
+    ) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>> {
+        let (x_end, y_end) = self.drawing_area.as_coord_spec().get_axis_end_pixels();
+        let (px, py) = self.drawing_area.get_base_pixel();
+        let plotting_area = self.drawing_area.strip_coord_spec();
+
+        for idx in 0..2 {
+            let axes = [
+                (
+                    x_axis,
+                    &self.x_label_area[idx],
+                    (0, -1 + idx as i16 * 2),
+                    x_tick_size[idx],
+                ),
+                (
+                    y_axis,
+                    &self.y_label_area[idx],
+                    (-1 + idx as i16 * 2, 0),
+                    y_tick_size[idx],
+                ),
+            ];
+            for (enabled, area, orientation, tick_size) in axes.iter() {
+                let area = match area {
+                    Some(area) if *enabled => self.offset_axis_area(area, *orientation),
+                    _ => continue,
+                };
+                let (p0, p1) = axis_line_ends(
+                    area.dim_in_pixel(),
+                    *orientation,
+                    *tick_size < 0,
+                    &self.local_axis_range(*orientation),
+                );
+
+                // The arrow points to the end of the axis which is closer to the end of the range
+                let (tip, from, end) = if orientation.0 == 0 {
+                    (p0.0, p1.0, x_end - px)
+                } else {
+                    (p0.1, p1.1, y_end - py)
+                };
+                let (tip, from) = if (tip - end).abs() <= (from - end).abs() {
+                    (tip, from)
+                } else {
+                    (from, tip)
+                };
+                let dir = if tip >= from { 1 } else { -1 };
+                let base = tip - dir * size;
+                let half = size / 2;
+
+                let points = if orientation.0 == 0 {
+                    let y = p0.1;
+                    vec![(tip, y), (base, y - half), (base, y + half)]
+                } else {
+                    let x = p0.0;
+                    vec![(x, tip), (x - half, base), (x + half, base)]
+                };
+
+                // The arrowhead crosses the edge of the label area, so the part over the plotting
+                // area is drawn on the plotting area, unless the axis is detached from it
+                let (ax, ay) = area.get_base_pixel();
+                let on_plotting_area: Vec<_> = points
+                    .iter()
+                    .map(|(x, y)| (x + ax - px, y + ay - py))
+                    .collect();
+                area.draw(&Polygon::new(points, style.filled()))?;
+                if self.axis_offset == 0 {
+                    plotting_area.draw(&Polygon::new(on_plotting_area, style.filled()))?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Get the positions of the tick marks mirrored on the top and right edges of the plotting
+    /// area. An edge with a label area already has its tick marks, so it gets no mirrored ticks.
+    #[allow(clippy::type_complexity)]
+    pub(crate) fn mirrored_tick_positions<YH: KeyPointHint, XH: KeyPointHint>(
+        &self,
+        (r, c): (YH, XH),
+        key_points: (Option<&[X::ValueType]>, Option<&[Y::ValueType]>),
+        x_axis: bool,
+        y_axis: bool,
+    ) -> Result<(Vec<i32>, Vec<i32>), DrawingAreaErrorKind<DB::ErrorType>> {
+        self.tick_positions(
+            (r, c),
+            key_points,
+            (
+                x_axis && self.x_label_area[0].is_none(),
+                y_axis && self.y_label_area[1].is_none(),
+            ),
+        )
+    }
+
+    /// Draw the mirrored tick marks on the top and right edges of the plotting area, pointing inward
+    pub(crate) fn draw_mirrored_ticks(
+        &self,
+        (x_ticks, y_ticks): (&[i32], &[i32]),
+        tick_style: &ShapeStyle,
+        x_tick_size: i32,
+        y_tick_size: i32,
+    ) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>> {
+        let area = self.drawing_area.strip_coord_spec();
+        let (x0, y0) = self.drawing_area.get_base_pixel();
+        let xmax = area.dim_in_pixel().0 as i32 - 1;
+        let (x_tick_size, y_tick_size) = (x_tick_size.abs(), y_tick_size.abs());
+
+        if x_tick_size > 0 {
+            for p in x_ticks {
+                let line = vec![(p - x0, 0), (p - x0, x_tick_size)];
+                area.draw(&PathElement::new(line, *tick_style))?;
+            }
+        }
+        if y_tick_size > 0 {
+            for p in y_ticks {
+                let line = vec![(xmax, p - y0), (xmax - y_tick_size, p - y0)];
+                area.draw(&PathElement::new(line, *tick_style))?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_rotated_labels() {
+        let draw = |angle| {
+            let (drawing_area, texts) = create_text_recording_area(200, 200);
+
+            let mut chart = ChartBuilder::on(&drawing_area)
+                .x_label_area_size(20)
+                .build_cartesian_2d(0..10, 0..10)
+                .expect("Create chart");
+
+            let layout = chart
+                .configure_mesh()
+                .x_labels(3)
+                .x_label_formatter(&|x| format!("label {}", x))
+                .x_label_rotation(angle)
+                .disable_y_axis()
+                .draw()
+                .expect("Draw mesh");
+            let plotting_area = chart.plotting_area().get_pixel_range();
+            (plotting_area, layout, texts.all())
+        };
+
+        // The label area grows to the bounding boxes of the rotated labels, the longest label
+        // ends at the bottom edge, and the labels start below the axis
+        let (plotting_area, layout, texts) = draw(45.0);
+        assert_eq!(plotting_area, (0..200, 0..156));
+        let rects: Vec<_> = layout.ticks.iter().map(|tick| tick.label_rect).collect();
+        assert_eq!(rects[0], Some(((-4, 167), (25, 196))));
+        assert_eq!(rects[2], Some(((195, 167), (229, 200))));
+        assert_eq!(texts[0], ("label 0".to_string(), (0, 170)));
+
+        // The vertical labels start right below the axis
+        let (plotting_area, layout, texts) = draw(90.0);
+        assert_eq!(plotting_area, (0..200, 0..152));
+        let rects: Vec<_> = layout.ticks.iter().map(|tick| tick.label_rect).collect();
+        assert_eq!(rects[0], Some(((-5, 162), (4, 194))));
+        assert_eq!(rects[2], Some(((194, 162), (203, 200))));
+        assert_eq!(texts[0], ("label 0".to_string(), (0, 162)));
+
+        // The labels which aren't rotated keep the label area
+        let (plotting_area, _, _) = draw(0.0);
+        assert_eq!(plotting_area, (0..200, 0..180));
+
+        // The plotting area shared with a secondary coordinate keeps its size
+        let drawing_area = create_mocked_drawing_area(200, 200, |_| {});
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .x_label_area_size(20)
+            .build_cartesian_2d(0..10, 0..10)
+            .expect("Create chart")
+            .set_secondary_coord(0..10, 0..10);
+        chart
+            .configure_mesh()
+            .x_label_rotation(90.0)
+            .draw()
+            .expect("Draw mesh");
+        assert_eq!(chart.plotting_area().get_pixel_range(), (0..200, 0..180));
+    }
+
+    #[test]
+    fn test_label_thinning() {
+        use crate::chart::LabelOverlap;
+        use std::cell::RefCell;
+        use std::rc::Rc;
+
+        /* Draw the labels which are too wide to fit in a 100 pixel wide axis, and get the
+         * rotation angle of each label which is drawn */
+        let draw_labels = |overlap, num_labels| {
+            let angles = Rc::new(RefCell::new(vec![]));
+            let angles_ref = angles.clone();
+            let drawing_area = create_mocked_drawing_area(100, 100, move |m| {
+                m.check_text_transform(move |transform, _| {
+                    angles_ref.borrow_mut().push(match transform {
+                        FontTransform::None => 0.0,
+                        FontTransform::Rotate90 => 90.0,
+                        FontTransform::Rotate180 => 180.0,
+                        FontTransform::Rotate270 => 270.0,
+                        FontTransform::RotateAngle(angle) => angle,
+                        _ => unreachable!(),
+                    })
+                });
+            });
+
+            let mut chart = ChartBuilder::on(&drawing_area)
+                .x_label_area_size(20)
+                .build_cartesian_2d(100000.0..200000.0, 0.0..1.0)
+                .expect("Create chart");
+
+            chart
+                .configure_mesh()
+                .disable_y_axis()
+                .x_labels(num_labels)
+                .x_label_overlap(overlap)
+                .draw()
+                .expect("Draw mesh");
+
+            drop(chart);
+            drop(drawing_area);
+            let angles = angles.borrow().clone();
+            angles
+        };
+
+        assert_eq!(draw_labels(LabelOverlap::Allow, 20), [0.0; 11]);
+        assert_eq!(draw_labels(LabelOverlap::Thin, 20), [0.0; 2]);
+        /* Even the rotated labels overlap, so every other label is dropped */
+        assert_eq!(draw_labels(LabelOverlap::Rotate(90.0), 20), [90.0; 6]);
+        assert_eq!(draw_labels(LabelOverlap::Rotate(45.0), 20), [45.0; 6]);
+        /* The rotated labels fit, so none of them is dropped */
+        assert_eq!(draw_labels(LabelOverlap::Thin, 6), [0.0; 2]);
+        assert_eq!(draw_labels(LabelOverlap::Rotate(90.0), 6), [90.0; 6]);
+    }
+
+    #[test]
+    fn test_edge_labels() {
+        use crate::chart::EdgeLabels;
+
+        let draw = |policy: EdgeLabels| {
+            let (drawing_area, labels) = create_text_recording_area(200, 200);
+
+            let mut chart = ChartBuilder::on(&drawing_area)
+                .x_label_area_size(20)
+                .build_cartesian_2d(0..1000, 0..10)
+                .expect("Create chart");
+
+            chart
+                .configure_mesh()
+                .x_ticks(vec![0, 500, 1000])
+                .edge_labels(policy)
+                .draw()
+                .expect("Draw mesh");
+
+            labels.all()
+        };
+
+        let find = |labels: &[(String, (i32, i32))], text: &str| {
+            labels.iter().find(|(t, _)| t == text).map(|(_, pos)| pos.0)
+        };
+
+        let kept = draw(EdgeLabels::Keep);
+        assert_eq!(find(&kept, "0"), Some(0));
+        assert_eq!(find(&kept, "1000"), Some(199));
+
+        let dropped = draw(EdgeLabels::Drop);
+        assert_eq!(find(&dropped, "0"), None);
+        assert_eq!(find(&dropped, "500"), find(&kept, "500"));
+        assert_eq!(find(&dropped, "1000"), None);
+
+        let nudged = draw(EdgeLabels::Nudge);
+        assert!(find(&nudged, "0").unwrap() > 0);
+        assert_eq!(find(&nudged, "500"), find(&kept, "500"));
+        assert!(find(&nudged, "1000").unwrap() < 199);
+    }
+
+    #[test]
+    fn test_label_padding() {
+        use std::cell::RefCell;
+        use std::rc::Rc;
+
+        let label_pos = |padding: Option<u32>| {
+            let pos = Rc::new(RefCell::new(None));
+            let pos_ref = pos.clone();
+            let drawing_area = create_mocked_drawing_area(200, 200, move |m| {
+                m.check_draw_text(move |_, _, _, p, text| {
+                    if text == "5" && pos_ref.borrow().is_none() {
+                        *pos_ref.borrow_mut() = Some(p);
+                    }
+                });
+            });
+
+            let mut chart = ChartBuilder::on(&drawing_area)
+                .x_label_area_size(40)
+                .build_cartesian_2d(0..10, 0..10)
+                .expect("Create chart");
+
+            let mut mesh = chart.configure_mesh();
+            mesh.x_ticks(vec![5]).disable_y_axis();
+            if let Some(padding) = padding {
+                mesh.x_label_padding(padding);
+            }
+            mesh.draw().expect("Draw mesh");
+
+            let pos = *pos.borrow();
+            pos.expect("Label drawn")
+        };
+
+        let default = label_pos(None);
+        assert_eq!(label_pos(Some(5)), default);
+        assert_eq!(label_pos(Some(15)).1, default.1 + 10);
+        assert_eq!(label_pos(Some(0)).1, default.1 - 5);
+    }
+
+    #[test]
+    fn test_multi_line_labels() {
+        let (drawing_area, texts) = create_text_recording_area(200, 200);
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .x_label_area_size(40)
+            .y_label_area_size(40)
+            .build_cartesian_2d(0..10, 0..10)
+            .expect("Create chart");
+
+        let layout = chart
+            .configure_mesh()
+            .disable_mesh()
+            .x_labels(3)
+            .y_labels(3)
+            .label_style(("sans-serif", 10))
+            .x_label_formatter(&|x| format!("{}\nmin", x))
+            .y_label_formatter(&|y| format!("{}\nkm", y))
+            .draw()
+            .expect("Draw mesh");
+
+        let texts = texts.all();
+        let pos_of = |text: &str, nth: usize| {
+            texts
+                .iter()
+                .filter(|(t, _)| t == text)
+                .nth(nth)
+                .expect("Drawn")
+                .1
+        };
+
+        // Each line of the Y labels is right aligned, and the lines are centered at the tick mark
+        assert_eq!(pos_of("0", 0), (30, 153));
+        assert_eq!(pos_of("km", 0), (30, 166));
+        // The lines of the X labels are centered at the tick mark and start below the axis
+        assert_eq!(pos_of("0", 1), (40, 170));
+        assert_eq!(pos_of("min", 0), (40, 183));
+
+        // The label boxes cover all the lines
+        let rects: Vec<_> = layout
+            .ticks_on(LabelAreaPosition::Bottom)
+            .map(|tick| tick.label_rect.expect("Drawn"))
+            .collect();
+        assert_eq!(rects[0], ((33, 170), (48, 191)));
+        let rects: Vec<_> = layout
+            .ticks_on(LabelAreaPosition::Left)
+            .map(|tick| tick.label_rect.expect("Drawn"))
+            .collect();
+        assert_eq!(rects[0], ((18, 149), (30, 170)));
+    }
+
+    #[test]
+    fn test_multi_line_desc() {
+        let (drawing_area, texts) = create_text_recording_area(200, 200);
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .x_label_area_size(40)
+            .y_label_area_size(40)
+            .build_cartesian_2d(0..10, 0..10)
+            .expect("Create chart");
+
+        chart
+            .configure_mesh()
+            .disable_axes()
+            .axis_desc_style(("sans-serif", 10))
+            .x_desc("Time\n(s)")
+            .y_desc("Speed\n(m/s)")
+            .draw()
+            .expect("Draw mesh");
+
+        let texts = texts.all();
+        let pos_of = |text: &str| texts.iter().find(|(t, _)| t == text).expect("Drawn").1;
+
+        // The X description ends at the bottom edge and the Y one starts at the left edge
+        assert_eq!(pos_of("(s)").1, 200);
+        assert_eq!(pos_of("Time").1, pos_of("(s)").1 - 13);
+        assert_eq!(pos_of("Speed").0, 0);
+        assert_eq!(pos_of("(m/s)").0, pos_of("Speed").0 + 13);
+    }
+
+    #[test]
+    fn test_desc_layout() {
+        use crate::chart::{AxisDescOrientation, AxisDescPlacement};
+
+        let (drawing_area, texts) = create_text_recording_area(200, 200);
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .margin_top(30)
+            .x_label_area_size(40)
+            .y_label_area_size(40)
+            .build_cartesian_2d(0..10, 0..10)
+            .expect("Create chart");
+
+        chart
+            .configure_mesh()
+            .disable_axes()
+            .x_desc("X")
+            .y_desc("Y")
+            .x_desc_placement(AxisDescPlacement::End)
+            .y_desc_orientation(AxisDescOrientation::Horizontal)
+            .y_desc_placement(AxisDescPlacement::End)
+            .draw()
+            .expect("Draw mesh");
+
+        let texts = texts.all();
+        let pos_of = |text: &str| texts.iter().find(|(t, _)| t == text).expect("Drawn").1;
+
+        assert_eq!(pos_of("X"), (200, 200));
+        // The horizontal Y description is right above the top of the Y axis
+        assert_eq!(pos_of("Y").0, 40);
+        assert!(pos_of("Y").1 < 30);
+    }
+
+    #[test]
+    fn test_two_tier_labels() {
+        let drawing_area = create_mocked_drawing_area(400, 200, |_| {});
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .x_label_area_size(50)
+            .build_cartesian_2d(
+                (10..12)
+                    .nested_coord(|_| (0..3).into_segmented())
+                    .two_tier_labels(),
+                0..10,
+            )
+            .expect("Create chart");
+
+        let layout = chart.configure_mesh().draw().expect("Draw mesh");
+        let label_top = |text: &str| {
+            let tick = layout
+                .ticks_on(LabelAreaPosition::Bottom)
+                .find(|tick| tick.text == text)
+                .expect("Label is drawn");
+            (tick.label_rect.unwrap().0).1
+        };
+
+        /* The category labels are drawn below the labels of the nested values */
+        assert_eq!(label_top("10"), label_top("11"));
+        assert!(label_top("10") > label_top("0") + 5);
+    }
+
+    #[test]
+    fn test_minor_ticks() {
+        let draw_ticks = |minor_ticks| {
+            count_mocked_calls(
+                200,
+                200,
+                |b| b.num_draw_path_call,
+                |drawing_area| {
+                    let mut chart = ChartBuilder::on(drawing_area)
+                        .x_label_area_size(20)
+                        .build_cartesian_2d(0..10, 0..10)
+                        .expect("Create chart");
+
+                    chart
+                        .configure_mesh()
+                        .disable_mesh()
+                        .disable_y_axis()
+                        .minor_ticks(minor_ticks)
+                        .draw()
+                        .expect("Draw mesh");
+                },
+            )
+        };
+
+        assert!(draw_ticks(0) < draw_ticks(5));
+    }
+
+    #[test]
+    fn test_mirror_ticks() {
+        let count_paths = |top_area, mirror| {
+            count_mocked_calls(
+                200,
+                200,
+                |b| b.num_draw_path_call,
+                |drawing_area| {
+                    let mut chart = ChartBuilder::on(drawing_area)
+                        .x_label_area_size(20)
+                        .y_label_area_size(20)
+                        .set_label_area_size(LabelAreaPosition::Top, top_area)
+                        .build_cartesian_2d(0..10, 0..10)
+                        .expect("Create chart");
+
+                    chart
+                        .configure_mesh()
+                        .disable_mesh()
+                        .x_ticks(vec![2, 4, 6])
+                        .y_ticks(vec![5])
+                        .mirror_ticks(mirror)
+                        .draw()
+                        .expect("Draw mesh");
+                },
+            )
+        };
+
+        assert_eq!(count_paths(0, true), count_paths(0, false) + 4);
+        assert_eq!(count_paths(20, true), count_paths(20, false) + 1);
+    }
+
+    #[test]
+    fn test_axis_arrows() {
+        use std::cell::RefCell;
+        use std::rc::Rc;
+
+        let arrows = Rc::new(RefCell::new(vec![]));
+        let arrows_ref = arrows.clone();
+        let drawing_area = create_mocked_drawing_area(200, 200, move |m| {
+            m.check_fill_polygon(move |_, path| arrows_ref.borrow_mut().push(path));
+        });
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .x_label_area_size(20)
+            .y_label_area_size(20)
+            .build_cartesian_2d(0..10, 0..10)
+            .expect("Create chart");
+
+        chart
+            .configure_mesh()
+            .axis_arrows(true)
+            .axis_arrow_size(10)
+            .draw()
+            .expect("Draw mesh");
+
+        let arrows = arrows.borrow();
+        assert_eq!(arrows.len(), 4);
+        // The Y arrow points to the top and the X arrow points to the right
+        assert!(arrows[0][1..].iter().all(|p| p.1 == arrows[0][0].1 + 10));
+        assert!(arrows[2][1..].iter().all(|p| p.0 == arrows[2][0].0 - 10));
+    }
+
+    #[test]
+    fn test_axis_offset() {
+        use std::cell::RefCell;
+        use std::rc::Rc;
+
+        let axis_y = |offset: i32| {
+            let lines = Rc::new(RefCell::new(vec![]));
+            let lines_ref = lines.clone();
+            let drawing_area = create_mocked_drawing_area(200, 200, move |m| {
+                m.check_draw_path(move |_, _, path| {
+                    // The X axis line is the only long horizontal path
+                    if path.len() == 2 && path[0].1 == path[1].1 && path[1].0 - path[0].0 > 100 {
+                        lines_ref.borrow_mut().push(path[0].1);
+                    }
+                });
+            });
+
+            let mut chart = ChartBuilder::on(&drawing_area)
+                .x_label_area_size(30)
+                .build_cartesian_2d(0..10, 0..10)
+                .expect("Create chart");
+
+            let layout = chart
+                .configure_mesh()
+                .disable_mesh()
+                .axis_offset(offset)
+                .draw()
+                .expect("Draw mesh");
+            let label_top = layout.ticks[0].label_rect.unwrap().0 .1;
+
+            let lines = lines.borrow().clone();
+            (lines, label_top)
+        };
+
+        let (lines, label_top) = axis_y(0);
+        let (offset_lines, offset_label_top) = axis_y(6);
+        assert_eq!(lines.len(), 1);
+        assert_eq!(offset_lines, vec![lines[0] + 6]);
+        assert_eq!(offset_label_top, label_top + 6);
+    }
 }
@@ -2,22 +2,37 @@ use std::ops::Range;
 
 use plotters_backend::{BackendCoord, DrawingBackend};
 
-use crate::chart::{ChartContext, DualCoordChartContext, MeshStyle};
+use crate::chart::{context::DeferredLayer, ChartContext, DualCoordChartContext, MeshStyle};
 use crate::coord::{
     cartesian::Cartesian2d,
     ranged1d::{AsRangedCoord, Ranged, ValueFormatter},
+    transformed::{AffineTransform, Transformed},
     Shift,
 };
-use crate::drawing::DrawingArea;
+use crate::drawing::{DrawingArea, DrawingAreaErrorKind};
 
 mod draw_impl;
 
+/// A drawing routine of a deferred layer, which draws on the chart it's configured on
+pub(crate) type DeferredDrawFn<'a, DB, X, Y> = dyn FnOnce(
+        &ChartContext<'a, DB, Cartesian2d<X, Y>>,
+    ) -> Result<(), DrawingAreaErrorKind<<DB as DrawingBackend>::ErrorType>>
+    + 'a;
+
 impl<'a, DB, XT, YT, X, Y> ChartContext<'a, DB, Cartesian2d<X, Y>>
 where
     DB: DrawingBackend,
     X: Ranged<ValueType = XT> + ValueFormatter<XT>,
     Y: Ranged<ValueType = YT> + ValueFormatter<YT>,
 {
+    /// Initialize a mesh configuration object and mesh drawing can be finalized by calling
+    /// the function `MeshStyle::draw`.
+    pub fn configure_mesh(&mut self) -> MeshStyle<'a, '_, X, Y, DB> {
+        MeshStyle::new(self)
+    }
+}
+
+impl<'a, DB: DrawingBackend, X: Ranged, Y: Ranged> ChartContext<'a, DB, Cartesian2d<X, Y>> {
     pub(crate) fn is_overlapping_drawing_area(
         &self,
         area: Option<&DrawingArea<DB, Shift>>,
@@ -39,14 +54,31 @@ where
         }
     }
 
-    /// Initialize a mesh configuration object and mesh drawing can be finalized by calling
-    /// the function `MeshStyle::draw`.
-    pub fn configure_mesh(&mut self) -> MeshStyle<'a, '_, X, Y, DB> {
-        MeshStyle::new(self)
+    /// Make the layer which is drawn on a copy of the chart context when the chart context is
+    /// dropped, so that it stays on top of all the series
+    pub(crate) fn defer_layer(
+        &self,
+        draw_func: Box<DeferredDrawFn<'a, DB, X, Y>>,
+    ) -> DeferredLayer<'a, DB>
+    where
+        DB: 'a,
+        X: Clone + 'a,
+        Y: Clone + 'a,
+    {
+        let chart = ChartContext {
+            x_label_area: self.x_label_area.clone(),
+            y_label_area: self.y_label_area.clone(),
+            drawing_area: self.drawing_area.clone(),
+            series_anno: vec![],
+            drawing_area_pos: self.drawing_area_pos,
+            deferred_layer: None,
+            axis_offset: self.axis_offset,
+            fixed_layout: self.fixed_layout,
+            layout_only: false,
+        };
+        DeferredLayer::new(move || draw_func(&chart))
     }
-}
 
-impl<'a, DB: DrawingBackend, X: Ranged, Y: Ranged> ChartContext<'a, DB, Cartesian2d<X, Y>> {
     /// Get the range of X axis
     pub fn x_range(&self) -> Range<X::ValueType> {
         self.drawing_area.get_x_range()
@@ -85,6 +117,92 @@ impl<'a, DB: DrawingBackend, X: Ranged, Y: Ranged> ChartContext<'a, DB, Cartesia
         let mut pixel_range = self.drawing_area.get_pixel_range();
         pixel_range.1 = pixel_range.1.end..pixel_range.1.start;
 
-        DualCoordChartContext::new(self, Cartesian2d::new(x_coord, y_coord, pixel_range))
+        DualCoordChartContext::new(
+            self,
+            Cartesian2d::new(x_coord, y_coord, pixel_range),
+            (true, true),
+        )
+    }
+
+    /// Convert this chart context into a dual axis chart context with an independent secondary X axis,
+    /// which shares the Y axis with the primary coordinate spec. The secondary X axis is drawn on the
+    /// top label area by [DualCoordChartContext::configure_secondary_axes](struct.DualCoordChartContext.html#method.configure_secondary_axes),
+    /// while the right label area is still used by the primary Y axis.
+    ///
+    /// - `x_coord`: The coordinate spec for the secondary X axis
+    /// - **returns** The newly created dual spec chart context
+    #[allow(clippy::type_complexity)]
+    pub fn set_secondary_x_coord<SX: AsRangedCoord>(
+        self,
+        x_coord: SX,
+    ) -> DualCoordChartContext<'a, DB, Cartesian2d<X, Y>, Cartesian2d<SX::CoordDescType, Y>>
+    where
+        Y: Clone,
+    {
+        let mut pixel_range = self.drawing_area.get_pixel_range();
+        pixel_range.1 = pixel_range.1.end..pixel_range.1.start;
+        let y_coord = self.drawing_area.as_coord_spec().y_spec().clone();
+
+        DualCoordChartContext::new(
+            self,
+            Cartesian2d::new(x_coord, y_coord, pixel_range),
+            (true, false),
+        )
+    }
+
+    /// Convert this chart context into the one on the affine transformed coordinate, which rotates,
+    /// shears or scales the whole plot. The transform is applied around the center of the plotting
+    /// area, and the mesh of the transformed chart is drawn by
+    /// [TransformedMeshStyle](struct.TransformedMeshStyle.html). The plotting area is expanded to
+    /// the bounding box of the transformed plotting area, so the elements near the transformed
+    /// edges are not cut off. The axes and labels kept on top of the series by
+    /// [MeshStyle::on_top](struct.MeshStyle.html#method.on_top) are still drawn without the transform.
+    ///
+    /// - `transform`: The transform of the plot, see [AffineTransform](../coord/transformed/struct.AffineTransform.html)
+    /// - **returns** The transformed chart context
+    pub fn transformed(
+        mut self,
+        transform: AffineTransform,
+    ) -> ChartContext<'a, DB, Transformed<Cartesian2d<X, Y>>>
+    where
+        DB: 'a,
+        X: Clone + 'a,
+        Y: Clone + 'a,
+    {
+        let (x_range, y_range) = self.drawing_area.get_pixel_range();
+        let pivot = (
+            f64::from(x_range.start + x_range.end) / 2.0,
+            f64::from(y_range.start + y_range.end) / 2.0,
+        );
+        let transform = transform.about(pivot);
+        let corners: Vec<_> = [
+            (x_range.start, y_range.start),
+            (x_range.end, y_range.start),
+            (x_range.start, y_range.end),
+            (x_range.end, y_range.end),
+        ]
+        .iter()
+        .map(|&corner| transform.apply_coord(corner))
+        .collect();
+        let min = |values: Vec<i32>| values.into_iter().min().unwrap_or(0);
+        let area = self.drawing_area.strip_coord_spec().margin(
+            min(corners.iter().map(|c| c.1 - y_range.start).collect()).min(0),
+            min(corners.iter().map(|c| y_range.end - c.1).collect()).min(0),
+            min(corners.iter().map(|c| c.0 - x_range.start).collect()).min(0),
+            min(corners.iter().map(|c| x_range.end - c.0).collect()).min(0),
+        );
+        let coord = Transformed::new(self.drawing_area.into_coord_spec(), transform);
+
+        ChartContext {
+            x_label_area: self.x_label_area,
+            y_label_area: self.y_label_area,
+            drawing_area: area.apply_coord_spec(coord),
+            series_anno: self.series_anno,
+            drawing_area_pos: self.drawing_area_pos,
+            deferred_layer: self.deferred_layer.take(),
+            axis_offset: self.axis_offset,
+            fixed_layout: self.fixed_layout,
+            layout_only: false,
+        }
     }
 }
@@ -0,0 +1,98 @@
+use plotters_backend::DrawingBackend;
+
+use crate::chart::ChartContext;
+use crate::coord::{polar::PolarCoord, ranged1d::Ranged};
+use crate::drawing::DrawingAreaErrorKind;
+use crate::element::{Circle, PathElement};
+use crate::style::{
+    text_anchor::{HPos, Pos, VPos},
+    ShapeStyle, TextStyle,
+};
+
+/// Pick the text anchor for a label placed outside of the circle in the given direction
+fn perimeter_label_pos(angle: f64) -> Pos {
+    let (sin, cos) = angle.sin_cos();
+    let h_pos = if sin > 0.3 {
+        HPos::Left
+    } else if sin < -0.3 {
+        HPos::Right
+    } else {
+        HPos::Center
+    };
+    let v_pos = if cos > 0.3 {
+        VPos::Bottom
+    } else if cos < -0.3 {
+        VPos::Top
+    } else {
+        VPos::Center
+    };
+    Pos::new(h_pos, v_pos)
+}
+
+impl<'a, DB, A, R> ChartContext<'a, DB, PolarCoord<A, R>>
+where
+    DB: DrawingBackend,
+    A: Ranged,
+    R: Ranged,
+{
+    /// Draw the rays at the given angles in radians and the circles with the given radius in pixels
+    pub(crate) fn draw_polar_mesh_lines(
+        &self,
+        (angles, radii): (&[f64], &[i32]),
+        style: &ShapeStyle,
+    ) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>> {
+        let coord = self.drawing_area.as_coord_spec();
+        let area = self.drawing_area.strip_coord_spec();
+        let (x0, y0) = area.get_base_pixel();
+        let to_area = |(x, y): (i32, i32)| (x - x0, y - y0);
+
+        let center = to_area(coord.get_center());
+        let outer = f64::from(coord.get_radius());
+
+        for &angle in angles {
+            area.draw(&PathElement::new(
+                vec![center, to_area(coord.point_at(angle, outer))],
+                *style,
+            ))?;
+        }
+
+        for &r in radii.iter().filter(|&&r| r > 0) {
+            area.draw(&Circle::new(center, r, *style))?;
+        }
+
+        Ok(())
+    }
+
+    /// Draw the outer circle, the angle labels along the perimeter and the radius labels along
+    /// the start ray
+    pub(crate) fn draw_polar_axes(
+        &self,
+        (angle_labels, radius_labels): (&[(f64, String)], &[(i32, String)]),
+        axis_style: &ShapeStyle,
+        label_style: &TextStyle,
+        label_padding: i32,
+    ) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>> {
+        let coord = self.drawing_area.as_coord_spec();
+        let area = self.drawing_area.strip_coord_spec();
+        let (x0, y0) = area.get_base_pixel();
+        let to_area = |(x, y): (i32, i32)| (x - x0, y - y0);
+
+        let center = to_area(coord.get_center());
+        let outer = coord.get_radius();
+
+        area.draw(&Circle::new(center, outer, *axis_style))?;
+
+        for (angle, text) in angle_labels {
+            let pos = to_area(coord.point_at(*angle, f64::from(outer + label_padding)));
+            area.draw_text(text, &label_style.pos(perimeter_label_pos(*angle)), pos)?;
+        }
+
+        let radius_style = label_style.pos(Pos::new(HPos::Left, VPos::Bottom));
+        for (r, text) in radius_labels {
+            let (x, y) = to_area(coord.point_at(0.0, f64::from(*r)));
+            area.draw_text(text, &radius_style, (x + label_padding / 2, y))?;
+        }
+
+        Ok(())
+    }
+}
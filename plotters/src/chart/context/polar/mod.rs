@@ -0,0 +1,84 @@
+use crate::chart::{ChartContext, PolarMeshStyle};
+use crate::coord::{polar::PolarCoord, ranged1d::Ranged};
+
+use plotters_backend::{BackendCoord, DrawingBackend};
+
+use std::ops::Range;
+
+mod draw_impl;
+
+impl<'a, DB, A, R> ChartContext<'a, DB, PolarCoord<A, R>>
+where
+    DB: DrawingBackend,
+    A: Ranged,
+    R: Ranged,
+{
+    /// Initialize a mesh configuration object for the polar coordinate, and the mesh drawing
+    /// can be finalized by calling the function `PolarMeshStyle::draw`.
+    pub fn configure_mesh(&mut self) -> PolarMeshStyle<'a, '_, A, R, DB> {
+        PolarMeshStyle::new(self)
+    }
+}
+
+impl<'a, DB: DrawingBackend, A: Ranged, R: Ranged> ChartContext<'a, DB, PolarCoord<A, R>> {
+    /// Get the range of the angular axis
+    pub fn angle_range(&self) -> Range<A::ValueType> {
+        self.drawing_area.as_coord_spec().get_angle_range()
+    }
+
+    /// Get the range of the radial axis
+    pub fn radius_range(&self) -> Range<R::ValueType> {
+        self.drawing_area.as_coord_spec().get_radius_range()
+    }
+
+    /// Maps the coordinate to the backend coordinate. This is typically used
+    /// with an interactive chart.
+    pub fn backend_coord(&self, coord: &(A::ValueType, R::ValueType)) -> BackendCoord {
+        self.drawing_area.map_coordinate(coord)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_polar_series() {
+        let drawing_area = create_mocked_drawing_area(300, 300, |m| {
+            m.check_draw_path(|c, _, path| {
+                assert_eq!(c, RED.to_rgba());
+                assert_eq!(path.len(), 4);
+            });
+            m.check_fill_polygon(|c, path| {
+                assert_eq!(c, BLUE.to_rgba());
+                assert!(path.len() > 4);
+            });
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_path_call, 1);
+                assert_eq!(b.num_fill_polygon_call, 1);
+            });
+        });
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_polar(0f64..360f64, 0f64..10f64)
+            .expect("Create chart");
+
+        assert_eq!(chart.angle_range(), 0.0..360.0);
+        assert_eq!(chart.backend_coord(&(90.0, 10.0)), (300, 150));
+
+        chart
+            .draw_series(LineSeries::new(
+                vec![(0.0, 5.0), (120.0, 8.0), (240.0, 6.0), (0.0, 5.0)],
+                &RED,
+            ))
+            .expect("Draw line");
+        let sector = chart.as_coord_spec().sector(0.0..45.0, 0.0..10.0);
+        chart
+            .draw_series(std::iter::once(Polygon::new(sector, BLUE.filled())))
+            .expect("Draw sector");
+
+        let coord = chart.into_coord_trans();
+        let (angle, radius) = coord((300, 150)).expect("Reverse translate");
+        assert!((angle - 90.0).abs() < 1e-3 && (radius - 10.0).abs() < 1e-3);
+    }
+}
@@ -0,0 +1,122 @@
+use plotters_backend::DrawingBackend;
+
+use crate::chart::ChartContext;
+use crate::coord::{ternary::TernaryCoord, CoordTranslate};
+use crate::drawing::DrawingAreaErrorKind;
+use crate::element::PathElement;
+use crate::style::{
+    text_anchor::{HPos, Pos, VPos},
+    ShapeStyle, TextStyle,
+};
+
+/// Get the compositions at the two ends of the grid line where the given component is `value`
+fn grid_line_ends(component: usize, value: f64) -> [(f64, f64, f64); 2] {
+    let rest = 1.0 - value;
+    match component {
+        0 => [(value, rest, 0.0), (value, 0.0, rest)],
+        1 => [(0.0, value, rest), (rest, value, 0.0)],
+        _ => [(rest, 0.0, value), (0.0, rest, value)],
+    }
+}
+
+/// Get the composition where the label of the given component is placed, together with the
+/// outward direction of that edge and the text anchor. The labels of each component are placed
+/// along the edge where the next component is zero, so they go around the triangle clockwise.
+fn label_placement(component: usize, value: f64) -> ((f64, f64, f64), (f64, f64), Pos) {
+    let rest = 1.0 - value;
+    let (cos, sin) = (3f64.sqrt() / 2.0, 0.5);
+    match component {
+        0 => (
+            (value, 0.0, rest),
+            (cos, -sin),
+            Pos::new(HPos::Left, VPos::Center),
+        ),
+        1 => (
+            (rest, value, 0.0),
+            (-cos, -sin),
+            Pos::new(HPos::Right, VPos::Center),
+        ),
+        _ => (
+            (0.0, rest, value),
+            (0.0, 1.0),
+            Pos::new(HPos::Center, VPos::Top),
+        ),
+    }
+}
+
+impl<'a, DB: DrawingBackend> ChartContext<'a, DB, TernaryCoord> {
+    /// Draw the grid lines of all the three components at the given values between 0 and 1
+    pub(crate) fn draw_ternary_mesh_lines(
+        &self,
+        values: &[f64],
+        style: &ShapeStyle,
+    ) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>> {
+        let coord = self.drawing_area.as_coord_spec();
+        let area = self.drawing_area.strip_coord_spec();
+        let (x0, y0) = area.get_base_pixel();
+        let to_area = |(x, y): (i32, i32)| (x - x0, y - y0);
+
+        for component in 0..3 {
+            for &value in values {
+                let [from, to] = grid_line_ends(component, value);
+                area.draw(&PathElement::new(
+                    vec![
+                        to_area(coord.translate(&from)),
+                        to_area(coord.translate(&to)),
+                    ],
+                    *style,
+                ))?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Draw the triangle, the labels of the three components along the edges and the axis
+    /// descriptions next to the vertices
+    pub(crate) fn draw_ternary_axes(
+        &self,
+        labels: &[(f64, String)],
+        descs: [Option<&str>; 3],
+        axis_style: &ShapeStyle,
+        (label_style, desc_style): (&TextStyle, &TextStyle),
+        label_padding: i32,
+    ) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>> {
+        let coord = self.drawing_area.as_coord_spec();
+        let area = self.drawing_area.strip_coord_spec();
+        let (x0, y0) = area.get_base_pixel();
+        let to_area = |(x, y): (i32, i32)| (x - x0, y - y0);
+
+        let [a, b, c] = coord.get_vertices();
+        area.draw(&PathElement::new(
+            vec![to_area(a), to_area(b), to_area(c), to_area(a)],
+            *axis_style,
+        ))?;
+
+        let padding = f64::from(label_padding);
+        for component in 0..3 {
+            for (value, text) in labels {
+                let (point, (dx, dy), pos) = label_placement(component, *value);
+                let (x, y) = to_area(coord.translate(&point));
+                let offset = ((dx * padding).round() as i32, (dy * padding).round() as i32);
+                area.draw_text(text, &label_style.pos(pos), (x + offset.0, y + offset.1))?;
+            }
+        }
+
+        /* The descriptions are moved away from the labels next to the vertices */
+        let shift = label_padding * 2 + label_style.font.get_size().ceil() as i32;
+        let desc_placements = [
+            (a, (0, -shift), Pos::new(HPos::Center, VPos::Bottom)),
+            (b, (0, shift), Pos::new(HPos::Center, VPos::Top)),
+            (c, (0, shift), Pos::new(HPos::Center, VPos::Top)),
+        ];
+        for (desc, (vertex, (dx, dy), pos)) in descs.iter().zip(desc_placements.iter()) {
+            if let Some(text) = desc {
+                let (x, y) = to_area(*vertex);
+                area.draw_text(text, &desc_style.pos(*pos), (x + dx, y + dy))?;
+            }
+        }
+
+        Ok(())
+    }
+}
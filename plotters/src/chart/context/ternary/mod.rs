@@ -0,0 +1,14 @@
+use crate::chart::{ChartContext, TernaryMeshStyle};
+use crate::coord::ternary::TernaryCoord;
+
+use plotters_backend::DrawingBackend;
+
+mod draw_impl;
+
+impl<'a, DB: DrawingBackend> ChartContext<'a, DB, TernaryCoord> {
+    /// Initialize a mesh configuration object for the ternary coordinate, and the mesh drawing
+    /// can be finalized by calling the function `TernaryMeshStyle::draw`.
+    pub fn configure_mesh(&mut self) -> TernaryMeshStyle<'a, '_, DB> {
+        TernaryMeshStyle::new(self)
+    }
+}
@@ -0,0 +1,112 @@
+use plotters_backend::{BackendCoord, DrawingBackend};
+
+use crate::chart::ChartContext;
+use crate::coord::{
+    cartesian::{Cartesian2d, MeshLine},
+    ranged1d::Ranged,
+    transformed::Transformed,
+};
+use crate::drawing::DrawingAreaErrorKind;
+use crate::element::PathElement;
+use crate::style::{
+    text_anchor::{HPos, Pos, VPos},
+    ShapeStyle, TextStyle,
+};
+
+/// Get the text anchor of the label which is put in the given direction from its tick
+fn label_pos((dx, dy): (f64, f64)) -> Pos {
+    if dy.abs() >= dx.abs() {
+        let v_pos = if dy > 0.0 { VPos::Top } else { VPos::Bottom };
+        Pos::new(HPos::Center, v_pos)
+    } else {
+        let h_pos = if dx > 0.0 { HPos::Left } else { HPos::Right };
+        Pos::new(h_pos, VPos::Center)
+    }
+}
+
+impl<'a, DB: DrawingBackend, X: Ranged, Y: Ranged>
+    ChartContext<'a, DB, Transformed<Cartesian2d<X, Y>>>
+{
+    /// Draw the transformed grid lines, the X and Y axes along the bottom and the left edges of
+    /// the plotting area, and the labels next to the ends of the grid lines on the axes
+    #[allow(clippy::type_complexity)]
+    pub(crate) fn draw_transformed_mesh(
+        &self,
+        (x_points, y_points): (&[X::ValueType], &[Y::ValueType]),
+        (x_grid_style, y_grid_style): (Option<&ShapeStyle>, Option<&ShapeStyle>),
+        axis_style: &ShapeStyle,
+        (x_label_style, y_label_style): (Option<&TextStyle>, Option<&TextStyle>),
+        (x_label_padding, y_label_padding): (i32, i32),
+        (format_x, format_y): (
+            &dyn Fn(&X::ValueType) -> String,
+            &dyn Fn(&Y::ValueType) -> String,
+        ),
+    ) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>> {
+        let coord = self.drawing_area.as_coord_spec();
+        let transform = coord.transform();
+        let area = self.drawing_area.strip_coord_spec();
+        let (x0, y0) = area.get_base_pixel();
+        let to_area = |(x, y): BackendCoord| (x - x0, y - y0);
+
+        /* The grid lines start from the axes, so the labels are placed next to their starts */
+        let mut labels = vec![];
+        coord
+            .inner()
+            .draw_mesh_with_key_points(x_points, y_points, |line| {
+                let (from, to, text, grid_style, label) = match line {
+                    MeshLine::XMesh(from, to, value) => (
+                        from,
+                        to,
+                        format_x(value),
+                        x_grid_style,
+                        x_label_style.map(|style| (style, x_label_padding)),
+                    ),
+                    MeshLine::YMesh(from, to, value) => (
+                        from,
+                        to,
+                        format_y(value),
+                        y_grid_style,
+                        y_label_style.map(|style| (style, y_label_padding)),
+                    ),
+                };
+                let (from, to) = (transform.apply_coord(from), transform.apply_coord(to));
+                if let Some(style) = grid_style {
+                    area.draw(&PathElement::new(vec![to_area(from), to_area(to)], *style))?;
+                }
+                if let Some((style, padding)) = label {
+                    labels.push((from, to, text, style, padding));
+                }
+                Ok(())
+            })?;
+
+        let (x_range, y_range) = (
+            coord.inner().get_x_axis_pixel_range(),
+            coord.inner().get_y_axis_pixel_range(),
+        );
+        let origin = transform.apply_coord((x_range.start, y_range.end));
+        let x_end = transform.apply_coord((x_range.end, y_range.end));
+        let y_end = transform.apply_coord((x_range.start, y_range.start));
+        area.draw(&PathElement::new(
+            vec![to_area(x_end), to_area(origin), to_area(y_end)],
+            *axis_style,
+        ))?;
+
+        for (from, to, text, label_style, padding) in labels {
+            let (dx, dy) = (f64::from(from.0 - to.0), f64::from(from.1 - to.1));
+            let len = dx.hypot(dy).max(1.0);
+            let (dx, dy) = (dx / len, dy / len);
+            let padding = f64::from(padding);
+            let (x, y) = to_area(from);
+            area.draw_text(
+                &text,
+                &label_style.pos(label_pos((dx, dy))),
+                (
+                    x + (dx * padding).round() as i32,
+                    y + (dy * padding).round() as i32,
+                ),
+            )?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,90 @@
+use crate::chart::{ChartContext, TransformedMeshStyle};
+use crate::coord::{
+    cartesian::Cartesian2d,
+    ranged1d::{Ranged, ValueFormatter},
+    transformed::Transformed,
+};
+
+use plotters_backend::{BackendCoord, DrawingBackend};
+
+mod draw_impl;
+
+impl<'a, DB, XT, YT, X, Y> ChartContext<'a, DB, Transformed<Cartesian2d<X, Y>>>
+where
+    DB: DrawingBackend,
+    X: Ranged<ValueType = XT> + ValueFormatter<XT>,
+    Y: Ranged<ValueType = YT> + ValueFormatter<YT>,
+{
+    /// Initialize a mesh configuration object for the transformed coordinate, and the mesh
+    /// drawing can be finalized by calling the function `TransformedMeshStyle::draw`.
+    pub fn configure_mesh(&mut self) -> TransformedMeshStyle<'a, '_, X, Y, DB> {
+        TransformedMeshStyle::new(self)
+    }
+}
+
+impl<'a, DB: DrawingBackend, X: Ranged, Y: Ranged>
+    ChartContext<'a, DB, Transformed<Cartesian2d<X, Y>>>
+{
+    /// Maps the coordinate to the backend coordinate. This is typically used
+    /// with an interactive chart.
+    pub fn backend_coord(&self, coord: &(X::ValueType, Y::ValueType)) -> BackendCoord {
+        self.drawing_area.map_coordinate(coord)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_transformed_chart() {
+        use std::cell::RefCell;
+        use std::rc::Rc;
+
+        let labels = Rc::new(RefCell::new(vec![]));
+        let labels_ref = labels.clone();
+        let drawing_area = create_mocked_drawing_area(300, 300, move |m| {
+            m.check_draw_text(move |_, _, _, pos, text| {
+                labels_ref.borrow_mut().push((text.to_string(), pos));
+            });
+            m.drop_check(|b| {
+                // The 3 grid lines of each axis, the axes and the series
+                assert_eq!(b.num_draw_path_call, 8);
+            });
+        });
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .margin(50)
+            .build_cartesian_2d(0..10, 0..10)
+            .expect("Create chart")
+            .transformed(AffineTransform::rotate(90.0));
+
+        /* The X axis points downwards after the rotation, and the Y axis points to the right */
+        let near =
+            |(x, y): (i32, i32), (ex, ey): (i32, i32)| (x - ex).abs() <= 1 && (y - ey).abs() <= 1;
+        assert!(near(chart.backend_coord(&(0, 0)), (50, 50)));
+        assert!(near(chart.backend_coord(&(10, 0)), (50, 250)));
+        assert!(near(chart.backend_coord(&(0, 10)), (250, 50)));
+        assert_eq!(chart.plotting_area().dim_in_pixel(), (200, 200));
+
+        chart
+            .configure_mesh()
+            .x_labels(3)
+            .y_labels(3)
+            .draw()
+            .expect("Draw mesh");
+        chart
+            .draw_series(std::iter::once(PathElement::new(
+                vec![(0, 0), (10, 10)],
+                RED,
+            )))
+            .expect("Drawing error");
+
+        /* The labels are placed outside of the rotated axes */
+        let labels = labels.borrow();
+        let x_label = labels.iter().find(|(t, _)| t == "10").unwrap();
+        assert!(x_label.1 .0 < 50 && (x_label.1 .1 - 250).abs() <= 1);
+        assert_eq!(labels.iter().filter(|(t, _)| t == "5").count(), 2);
+        assert_eq!(labels.len(), 6);
+    }
+}
@@ -20,6 +20,8 @@ use plotters_backend::{BackendCoord, DrawingBackend};
 /// This situation is quite common, for example, we with two different coordinate system.
 /// For instance this example <img src="https://plotters-rs.github.io/plotters-doc-data/twoscale.png"></img>
 /// This is done by attaching  a second coordinate system to ChartContext by method [ChartContext::set_secondary_coord](struct.ChartContext.html#method.set_secondary_coord).
+/// If only the X axis needs a second coordinate, for example wavelength on the bottom and energy on the top,
+/// use [ChartContext::set_secondary_x_coord](struct.ChartContext.html#method.set_secondary_x_coord) instead.
 /// For instance of dual coordinate charts, see [this example](https://github.com/plotters-rs/plotters/blob/master/examples/two-scales.rs#L15).
 /// Note: `DualCoordChartContext` is always deref to the chart context.
 /// - If you want to configure the secondary axis, method [DualCoordChartContext::configure_secondary_axes](struct.DualCoordChartContext.html#method.configure_secondary_axes)
@@ -103,7 +105,14 @@ impl<'b, DB: DrawingBackend, CT1: CoordTranslate + Clone, CT2: CoordTranslate +
 impl<'a, DB: DrawingBackend, CT1: CoordTranslate, CT2: CoordTranslate>
     DualCoordChartContext<'a, DB, CT1, CT2>
 {
-    pub(super) fn new(mut primary: ChartContext<'a, DB, CT1>, secondary_coord: CT2) -> Self {
+    /// Attach the secondary coordinate spec to the primary chart context
+    /// - `(x_axis, y_axis)`: If the top X label area and the right Y label area should be
+    ///   moved to the secondary chart context
+    pub(super) fn new(
+        mut primary: ChartContext<'a, DB, CT1>,
+        secondary_coord: CT2,
+        (x_axis, y_axis): (bool, bool),
+    ) -> Self {
         let secondary_drawing_area = primary
             .drawing_area
             .strip_coord_spec()
@@ -111,8 +120,13 @@ impl<'a, DB: DrawingBackend, CT1: CoordTranslate, CT2: CoordTranslate>
         let mut secondary_x_label_area = [None, None];
         let mut secondary_y_label_area = [None, None];
 
-        std::mem::swap(&mut primary.x_label_area[0], &mut secondary_x_label_area[0]);
-        std::mem::swap(&mut primary.y_label_area[1], &mut secondary_y_label_area[1]);
+        primary.fixed_layout = true;
+        if x_axis {
+            std::mem::swap(&mut primary.x_label_area[0], &mut secondary_x_label_area[0]);
+        }
+        if y_axis {
+            std::mem::swap(&mut primary.y_label_area[1], &mut secondary_y_label_area[1]);
+        }
 
         Self {
             primary,
@@ -122,6 +136,10 @@ impl<'a, DB: DrawingBackend, CT1: CoordTranslate, CT2: CoordTranslate>
                 drawing_area: secondary_drawing_area,
                 series_anno: vec![],
                 drawing_area_pos: (0, 0),
+                deferred_layer: None,
+                axis_offset: 0,
+                fixed_layout: true,
+                layout_only: false,
             },
         }
     }
@@ -240,3 +258,55 @@ impl<'a, DB: DrawingBackend, CT1: CoordTranslate, CT2: CoordTranslate> DerefMut
         self.borrow_mut()
     }
 }
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_secondary_x_coord() {
+        let (drawing_area, texts) = create_text_recording_area(200, 200);
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .x_label_area_size(20)
+            .y_label_area_size(20)
+            .set_label_area_size(LabelAreaPosition::Top, 20)
+            .set_label_area_size(LabelAreaPosition::Right, 20)
+            .build_cartesian_2d(0..10, 0..10)
+            .expect("Create chart")
+            .set_secondary_x_coord(100..200);
+
+        chart
+            .configure_mesh()
+            .x_ticks(vec![5])
+            .y_ticks(vec![5])
+            .draw()
+            .expect("Draw mesh");
+        chart
+            .configure_secondary_axes()
+            .x_ticks(vec![150])
+            .y_ticks(vec![5])
+            .draw()
+            .expect("Draw secondary axes");
+
+        let texts = texts.all();
+        let positions = |text: &str| {
+            texts
+                .iter()
+                .filter(|(t, _)| t == text)
+                .map(|(_, p)| *p)
+                .collect::<Vec<_>>()
+        };
+
+        // The secondary X labels are on the top, the primary ones are on the bottom
+        assert_eq!(positions("150").len(), 1);
+        assert!(positions("150")[0].1 < 20);
+        // The primary Y labels are drawn on both sides, the secondary Y axis is not drawn
+        assert_eq!(positions("5").len(), 3);
+        assert!(positions("5").iter().any(|p| p.1 > 180));
+        assert_eq!(
+            chart.secondary_plotting_area().map_coordinate(&(150, 5)).1,
+            chart.backend_coord(&(5, 5)).1
+        );
+    }
+}
@@ -1,146 +1,124 @@
 use std::marker::PhantomData;
+use std::ops::Range;
 
 use super::builder::LabelAreaPosition;
-use super::context::ChartContext;
-use crate::coord::cartesian::{Cartesian2d, MeshLine};
-use crate::coord::ranged1d::{BoldPoints, LightPoints, Ranged, ValueFormatter};
-use crate::drawing::DrawingAreaErrorKind;
-use crate::style::{
-    AsRelative, Color, FontDesc, FontFamily, FontStyle, IntoTextStyle, RGBColor, ShapeStyle,
-    SizeDesc, TextStyle,
+use super::context::{ChartContext, DeferredDrawFn, DeferredLayer};
+use crate::coord::cartesian::Cartesian2d;
+use crate::coord::ranged1d::{
+    DiscreteRanged, Ranged, SegmentValue, SegmentedCoord, ValueFormatter,
 };
+use crate::style::{AsRelative, IntoTextStyle, ShapeStyle, SizeDesc, TextStyle};
 
-use plotters_backend::DrawingBackend;
+use num_traits::{ToPrimitive, Zero};
+use plotters_backend::{BackendCoord, DrawingBackend};
 
-/// The style used to describe the mesh and axis for a secondary coordinate system.
-pub struct SecondaryMeshStyle<'a, 'b, X: Ranged, Y: Ranged, DB: DrawingBackend> {
-    style: MeshStyle<'a, 'b, X, Y, DB>,
-}
-
-impl<'a, 'b, XT, YT, X: Ranged<ValueType = XT>, Y: Ranged<ValueType = YT>, DB: DrawingBackend>
-    SecondaryMeshStyle<'a, 'b, X, Y, DB>
-where
-    X: ValueFormatter<XT>,
-    Y: ValueFormatter<YT>,
-{
-    pub(super) fn new(target: &'b mut ChartContext<'a, DB, Cartesian2d<X, Y>>) -> Self {
-        let mut style = target.configure_mesh();
-        style.draw_x_mesh = false;
-        style.draw_y_mesh = false;
-        Self { style }
-    }
-
-    /// Set the style definition for the axis
-    /// - `style`: The style for the axis
-    pub fn axis_style<T: Into<ShapeStyle>>(&mut self, style: T) -> &mut Self {
-        self.style.axis_style(style);
-        self
-    }
-
-    /// The offset of x labels. This is used when we want to place the label in the middle of
-    /// the grid. This is used to adjust label position for histograms, but since plotters 0.3, this
-    /// use case is deprecated, see [SegmentedCoord coord decorator](../coord/ranged1d/trait.IntoSegmentedCoord.html) for more details
-    /// - `value`: The offset in pixel
-    pub fn x_label_offset<S: SizeDesc>(&mut self, value: S) -> &mut Self {
-        self.style.x_label_offset(value);
-        self
-    }
-
-    /// The offset of y labels. This is used when we want to place the label in the middle of
-    /// the grid. This is used to adjust label position for histograms, but since plotters 0.3, this
-    /// use case is deprecated, see [SegmentedCoord coord decorator](../coord/ranged1d/trait.IntoSegmentedCoord.html) for more details
-    /// - `value`: The offset in pixel
-    pub fn y_label_offset<S: SizeDesc>(&mut self, value: S) -> &mut Self {
-        self.style.y_label_offset(value);
-        self
-    }
+mod draw;
+mod format;
+mod polar;
+mod secondary;
+mod ternary;
+mod transformed;
 
-    /// Set how many labels for the X axis at most
-    /// - `value`: The maximum desired number of labels in the X axis
-    pub fn x_labels(&mut self, value: usize) -> &mut Self {
-        self.style.x_labels(value);
-        self
-    }
+pub(crate) use draw::rotate_label_style;
+use format::{find_axis_offset, percentage_text, PercentageFormatter};
+pub use format::{Formatter, FormatterContext, LabelFormatterWithContext, LabelLocale};
+pub use polar::PolarMeshStyle;
+pub use secondary::SecondaryMeshStyle;
+pub use ternary::TernaryMeshStyle;
+pub use transformed::TransformedMeshStyle;
 
-    /// Set how many label for the Y axis at most
-    /// - `value`: The maximum desired number of labels in the Y axis
-    pub fn y_labels(&mut self, value: usize) -> &mut Self {
-        self.style.y_labels(value);
-        self
-    }
-
-    /// Set the formatter function for the X label text
-    /// - `fmt`: The formatter function
-    pub fn x_label_formatter(&mut self, fmt: &'b dyn Fn(&X::ValueType) -> String) -> &mut Self {
-        self.style.x_label_formatter(fmt);
-        self
-    }
+/// Describes what to do when the labels on an axis overlap each other
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum LabelOverlap {
+    /// Draw all the labels, even if they overlap
+    Allow,
+    /// Drop every other label until the remaining labels fit
+    Thin,
+    /// Rotate the labels by the given angle in degrees clockwise if they overlap,
+    /// and drop every other label if the rotated labels still overlap
+    Rotate(f32),
+}
 
-    /// Set the formatter function for the Y label text
-    /// - `fmt`: The formatter function
-    pub fn y_label_formatter(&mut self, fmt: &'b dyn Fn(&Y::ValueType) -> String) -> &mut Self {
-        self.style.y_label_formatter(fmt);
-        self
-    }
+/// Describes what to do with the labels at the ends of an axis which go beyond the chart, for
+/// example, when the chart has no margin or the chart is next to another one in a multi-plot
+/// layout. The chart is made up by the plotting area and the label areas.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum EdgeLabels {
+    /// Draw the labels as they are
+    Keep,
+    /// Drop the labels which go beyond the chart
+    Drop,
+    /// Move the labels along the axis until they are inside the chart
+    Nudge,
+}
 
-    /// Set the axis description's style. If not given, use label style instead.
-    /// - `style`: The text style that would be applied to descriptions
-    pub fn axis_desc_style<T: IntoTextStyle<'b>>(&mut self, style: T) -> &mut Self {
-        self.style
-            .axis_desc_style(style.into_text_style(&self.style.parent_size));
-        self
-    }
+/// A labeled tick mark drawn by [`MeshStyle::draw`], all the coordinates are backend pixels
+#[derive(Clone, Debug, PartialEq)]
+pub struct DrawnTick {
+    /// The label area where the tick mark is drawn
+    pub position: LabelAreaPosition,
+    /// The position of the tick mark along the axis, the X pixel for the X axes and the Y pixel
+    /// for the Y axes
+    pub pixel: i32,
+    /// The text of the label, which is empty if the label is hidden
+    pub text: String,
+    /// The upper left and lower right corners of the estimated bounding box of the label,
+    /// if the label is drawn
+    pub label_rect: Option<(BackendCoord, BackendCoord)>,
+}
 
-    /// Set the X axis's description
-    /// - `desc`: The description of the X axis
-    pub fn x_desc<T: Into<String>>(&mut self, desc: T) -> &mut Self {
-        self.style.x_desc(desc);
-        self
-    }
+/// The layout of the mesh returned by [`MeshStyle::draw`], which can be used to align other
+/// decorations or hit areas with the tick marks and labels.
+/// The minor tick marks and the mirrored tick marks are not included.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct MeshLayout {
+    /// All the labeled tick marks on all the label areas
+    pub ticks: Vec<DrawnTick>,
+}
 
-    /// Set the Y axis's description
-    /// - `desc`: The description of the Y axis
-    pub fn y_desc<T: Into<String>>(&mut self, desc: T) -> &mut Self {
-        self.style.y_desc(desc);
-        self
+impl MeshLayout {
+    /// Get the tick marks drawn on the given label area
+    pub fn ticks_on(&self, position: LabelAreaPosition) -> impl Iterator<Item = &DrawnTick> {
+        self.ticks
+            .iter()
+            .filter(move |tick| tick.position == position)
     }
+}
 
-    /// Draw the axes for the secondary coordinate system
-    pub fn draw(&mut self) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>> {
-        self.style.draw()
-    }
+/// The orientation of the description of a Y axis
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum AxisDescOrientation {
+    /// Rotate the description by 90 degrees clockwise, it reads from top to bottom
+    Rotate90,
+    /// Rotate the description by 270 degrees clockwise, it reads from bottom to top
+    Rotate270,
+    /// Keep the description horizontal and put it above the top of the axis
+    Horizontal,
+}
 
-    /// Set the label style for the secondary axis
-    pub fn label_style<T: IntoTextStyle<'b>>(&mut self, style: T) -> &mut Self {
-        self.style.label_style(style);
-        self
-    }
+/// Describes where an axis description is placed along the axis
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum AxisDescPlacement {
+    /// Center the description along the axis
+    Center,
+    /// Align the description to the end of the axis, which is the right end of an X axis and
+    /// the top end of a Y axis
+    End,
+}
 
-    /// Set all the tick marks to the same size
-    /// `value`: The new size
-    pub fn set_all_tick_mark_size<S: SizeDesc>(&mut self, value: S) -> &mut Self {
-        let size = value.in_pixels(&self.style.parent_size);
-        self.style.x_tick_size = [size, size];
-        self.style.y_tick_size = [size, size];
-        self
-    }
-    /// Sets the tick mark size for a given label area position.
-    /// `value`: The new size
-    pub fn set_tick_mark_size<S: SizeDesc>(
-        &mut self,
-        pos: LabelAreaPosition,
-        value: S,
-    ) -> &mut Self {
-        *match pos {
-            LabelAreaPosition::Top => &mut self.style.x_tick_size[0],
-            LabelAreaPosition::Bottom => &mut self.style.x_tick_size[1],
-            LabelAreaPosition::Left => &mut self.style.y_tick_size[0],
-            LabelAreaPosition::Right => &mut self.style.y_tick_size[1],
-        } = value.in_pixels(&self.style.parent_size);
-        self
-    }
+/// The layout of an axis description passed to the axis drawing code
+#[derive(Clone, Copy)]
+pub(crate) struct AxisDescLayout {
+    pub(crate) orientation: Option<AxisDescOrientation>,
+    pub(crate) placement: AxisDescPlacement,
 }
 
+/// Makes the deferred layer of the axes which are kept on top of the series
+type DeferLayerFn<'a, DB, X, Y> = fn(
+    &ChartContext<'a, DB, Cartesian2d<X, Y>>,
+    Box<DeferredDrawFn<'a, DB, X, Y>>,
+) -> DeferredLayer<'a, DB>;
+
 /// The struct that is used for tracking the configuration of a mesh of any chart
 pub struct MeshStyle<'a, 'b, X: Ranged, Y: Ranged, DB: DrawingBackend> {
     pub(super) parent_size: (u32, u32),
@@ -148,26 +126,69 @@ pub struct MeshStyle<'a, 'b, X: Ranged, Y: Ranged, DB: DrawingBackend> {
     pub(super) draw_y_mesh: bool,
     pub(super) draw_x_axis: bool,
     pub(super) draw_y_axis: bool,
+    pub(super) hidden_labels: [bool; 4],
     pub(super) x_label_offset: i32,
     pub(super) y_label_offset: i32,
     pub(super) x_light_lines_limit: usize,
     pub(super) y_light_lines_limit: usize,
     pub(super) n_x_labels: usize,
     pub(super) n_y_labels: usize,
+    pub(super) x_key_points: Option<Vec<X::ValueType>>,
+    pub(super) y_key_points: Option<Vec<Y::ValueType>>,
+    pub(super) x_grid_points: Option<Vec<X::ValueType>>,
+    pub(super) y_grid_points: Option<Vec<Y::ValueType>>,
     pub(super) axis_desc_style: Option<TextStyle<'b>>,
     pub(super) x_desc: Option<String>,
     pub(super) y_desc: Option<String>,
+    pub(super) y_desc_orientation: Option<AxisDescOrientation>,
+    pub(super) x_desc_placement: AxisDescPlacement,
+    pub(super) y_desc_placement: AxisDescPlacement,
     pub(super) bold_line_style: Option<ShapeStyle>,
     pub(super) light_line_style: Option<ShapeStyle>,
     pub(super) axis_style: Option<ShapeStyle>,
     pub(super) x_label_style: Option<TextStyle<'b>>,
     pub(super) y_label_style: Option<TextStyle<'b>>,
+    pub(super) x_label_rotation: f32,
+    pub(super) y_label_rotation: f32,
+    pub(super) x_label_overlap: LabelOverlap,
+    pub(super) y_label_overlap: LabelOverlap,
+    pub(super) x_edge_labels: EdgeLabels,
+    pub(super) y_edge_labels: EdgeLabels,
     pub(super) format_x: Option<&'b dyn Fn(&X::ValueType) -> String>,
     pub(super) format_y: Option<&'b dyn Fn(&Y::ValueType) -> String>,
+    pub(super) format_x_with_context: Option<LabelFormatterWithContext<'b, X::ValueType>>,
+    pub(super) format_y_with_context: Option<LabelFormatterWithContext<'b, Y::ValueType>>,
+    pub(super) x_percentage: Option<PercentageFormatter<X::ValueType>>,
+    pub(super) y_percentage: Option<PercentageFormatter<Y::ValueType>>,
+    pub(super) label_locale: Option<LabelLocale>,
+    pub(super) x_offset_text: Option<fn(&X::ValueType) -> Option<f64>>,
+    pub(super) y_offset_text: Option<fn(&Y::ValueType) -> Option<f64>>,
     pub(super) target: Option<&'b mut ChartContext<'a, DB, Cartesian2d<X, Y>>>,
     pub(super) _phantom_data: PhantomData<(X, Y)>,
     pub(super) x_tick_size: [i32; 2],
     pub(super) y_tick_size: [i32; 2],
+    pub(super) x_label_padding: Option<i32>,
+    pub(super) y_label_padding: Option<i32>,
+    pub(super) x_label_inset: Option<i32>,
+    pub(super) y_label_inset: Option<i32>,
+    pub(super) x_minor_divisions: Option<usize>,
+    pub(super) y_minor_divisions: Option<usize>,
+    pub(super) x_bands: Option<ShapeStyle>,
+    pub(super) y_bands: Option<ShapeStyle>,
+    pub(super) x_minor_ticks: usize,
+    pub(super) y_minor_ticks: usize,
+    pub(super) minor_tick_style: Option<ShapeStyle>,
+    pub(super) x_minor_tick_size: [i32; 2],
+    pub(super) y_minor_tick_size: [i32; 2],
+    pub(super) on_top: Option<DeferLayerFn<'a, DB, X, Y>>,
+    pub(super) mirror_ticks: bool,
+    pub(super) axis_arrows: bool,
+    pub(super) axis_offset: i32,
+    pub(super) axis_arrow_size: i32,
+    pub(super) axis_arrow_style: Option<ShapeStyle>,
+    pub(super) zero_line_style: Option<ShapeStyle>,
+    pub(super) x_zero: Vec<X::ValueType>,
+    pub(super) y_zero: Vec<Y::ValueType>,
 }
 
 impl<'a, 'b, X, Y, XT, YT, DB> MeshStyle<'a, 'b, X, Y, DB>
@@ -191,8 +212,20 @@ where
             }
         }
 
+        let mut style = Self::detached(chart.drawing_area.dim_in_pixel(), x_tick_size, y_tick_size);
+        style.target = Some(chart);
+        style
+    }
+
+    /// Create the mesh configuration which is not attached to a Cartesian chart, for the charts
+    /// which draw the mesh by themselves but share the configuration
+    pub(crate) fn detached(
+        parent_size: (u32, u32),
+        x_tick_size: [i32; 2],
+        y_tick_size: [i32; 2],
+    ) -> Self {
         MeshStyle {
-            parent_size: chart.drawing_area.dim_in_pixel(),
+            parent_size,
             axis_style: None,
             x_label_offset: 0,
             y_label_offset: 0,
@@ -200,23 +233,66 @@ where
             draw_y_mesh: true,
             draw_x_axis: true,
             draw_y_axis: true,
+            hidden_labels: [false; 4],
             x_light_lines_limit: 10,
             y_light_lines_limit: 10,
             n_x_labels: 11,
             n_y_labels: 11,
+            x_key_points: None,
+            x_grid_points: None,
+            y_grid_points: None,
+            y_key_points: None,
             bold_line_style: None,
             light_line_style: None,
             x_label_style: None,
             y_label_style: None,
+            x_label_rotation: 0.0,
+            y_label_rotation: 0.0,
+            x_label_overlap: LabelOverlap::Allow,
+            y_label_overlap: LabelOverlap::Allow,
+            x_edge_labels: EdgeLabels::Keep,
+            y_edge_labels: EdgeLabels::Keep,
             format_x: None,
             format_y: None,
-            target: Some(chart),
+            format_x_with_context: None,
+            format_y_with_context: None,
+            x_percentage: None,
+            y_percentage: None,
+            label_locale: None,
+            x_offset_text: None,
+            y_offset_text: None,
+            target: None,
             _phantom_data: PhantomData,
             x_desc: None,
             y_desc: None,
+            y_desc_orientation: None,
+            x_desc_placement: AxisDescPlacement::Center,
+            y_desc_placement: AxisDescPlacement::Center,
             axis_desc_style: None,
             x_tick_size,
             y_tick_size,
+            x_label_padding: None,
+            x_label_inset: None,
+            y_label_inset: None,
+            y_label_padding: None,
+            x_minor_divisions: None,
+            y_minor_divisions: None,
+            x_bands: None,
+            y_bands: None,
+            x_minor_ticks: 0,
+            y_minor_ticks: 0,
+            minor_tick_style: None,
+            x_minor_tick_size: [x_tick_size[0] / 2, x_tick_size[1] / 2],
+            y_minor_tick_size: [y_tick_size[0] / 2, y_tick_size[1] / 2],
+            on_top: None,
+            mirror_ticks: false,
+            axis_arrows: false,
+            axis_offset: 0,
+            axis_arrow_size: 10,
+            axis_arrow_style: None,
+            zero_line_style: None,
+            x_zero: vec![],
+            y_zero: vec![],
         }
     }
 }
@@ -255,6 +331,25 @@ where
         self
     }
 
+    /// Set the minor tick mark size on the axes. By default, the minor tick marks are half the
+    /// size of the major tick marks.
+    ///
+    /// - `pos`: The which label area we want to set
+    /// - `value`: The size specification
+    pub fn set_minor_tick_mark_size<S: SizeDesc>(
+        &mut self,
+        pos: LabelAreaPosition,
+        value: S,
+    ) -> &mut Self {
+        *match pos {
+            LabelAreaPosition::Top => &mut self.x_minor_tick_size[0],
+            LabelAreaPosition::Bottom => &mut self.x_minor_tick_size[1],
+            LabelAreaPosition::Left => &mut self.y_minor_tick_size[0],
+            LabelAreaPosition::Right => &mut self.y_minor_tick_size[1],
+        } = value.in_pixels(&self.parent_size);
+        self
+    }
+
     /// The offset of x labels. This is used when we want to place the label in the middle of
     /// the grid. This is used to adjust label position for histograms, but since plotters 0.3, this
     /// use case is deprecated, see [SegmentedCoord coord decorator](../coord/ranged1d/trait.IntoSegmentedCoord.html) for more details
@@ -273,6 +368,41 @@ where
         self
     }
 
+    /// Draw the x labels inside the plotting area instead of the label areas, at the given
+    /// distance from the axis. The axis and the tick marks stay in the label areas, so a small
+    /// label area is enough for the long labels, for example the category names of a ranking
+    /// chart. Since the labels are drawn with the mesh, this is usually combined with
+    /// [`MeshStyle::on_top()`] to keep them above the series.
+    /// - `value`: The distance between the axis and the labels
+    pub fn x_label_inset<S: SizeDesc>(&mut self, value: S) -> &mut Self {
+        self.x_label_inset = Some(value.in_pixels(&self.parent_size));
+        self
+    }
+
+    /// Draw the y labels inside the plotting area instead of the label areas, at the given
+    /// distance from the axis. See [`MeshStyle::x_label_inset()`] for more details.
+    /// - `value`: The distance between the axis and the labels
+    pub fn y_label_inset<S: SizeDesc>(&mut self, value: S) -> &mut Self {
+        self.y_label_inset = Some(value.in_pixels(&self.parent_size));
+        self
+    }
+
+    /// Set the distance between the x tick labels and the axis.
+    /// By default the distance is the same as the size of the tick marks.
+    /// - `value`: The padding size
+    pub fn x_label_padding<S: SizeDesc>(&mut self, value: S) -> &mut Self {
+        self.x_label_padding = Some(value.in_pixels(&self.parent_size));
+        self
+    }
+
+    /// Set the distance between the y tick labels and the axis.
+    /// By default the distance is the same as the size of the tick marks.
+    /// - `value`: The padding size
+    pub fn y_label_padding<S: SizeDesc>(&mut self, value: S) -> &mut Self {
+        self.y_label_padding = Some(value.in_pixels(&self.parent_size));
+        self
+    }
+
     /// Disable the mesh for the x axis.
     pub fn disable_x_mesh(&mut self) -> &mut Self {
         self.draw_x_mesh = false;
@@ -297,6 +427,30 @@ where
         self
     }
 
+    /// Hide the text of the X axis labels on both the top and the bottom, while the axis and
+    /// the tick marks are still drawn. This is useful when the labels are shown by another chart
+    /// which shares the same axis, in this case the X label area only needs to be large enough
+    /// for the tick marks. See [hide_labels](#method.hide_labels) to hide one side only.
+    pub fn hide_x_labels(&mut self) -> &mut Self {
+        self.hide_labels(LabelAreaPosition::Top)
+            .hide_labels(LabelAreaPosition::Bottom)
+    }
+
+    /// Hide the text of the Y axis labels on both the left and the right, while the axis and
+    /// the tick marks are still drawn. See [hide_x_labels](#method.hide_x_labels) for details.
+    pub fn hide_y_labels(&mut self) -> &mut Self {
+        self.hide_labels(LabelAreaPosition::Left)
+            .hide_labels(LabelAreaPosition::Right)
+    }
+
+    /// Hide the text of the labels in one label area, while the axis and the tick marks there are
+    /// still drawn, i.e. to keep the labels of a mirrored axis on one side only.
+    /// - `pos`: The label area whose labels are hidden
+    pub fn hide_labels(&mut self, pos: LabelAreaPosition) -> &mut Self {
+        self.hidden_labels[pos as usize] = true;
+        self
+    }
+
     /// Disable drawing all meshes
     pub fn disable_mesh(&mut self) -> &mut Self {
         self.disable_x_mesh().disable_y_mesh()
@@ -314,6 +468,15 @@ where
         self
     }
 
+    /// Detach the axes from the plotting area, so that the axis lines, tick marks and labels are
+    /// drawn the given distance outside of the plotting area, while the grid lines still span
+    /// the plotting area. Note that the label areas should be large enough for the gap.
+    /// - `value`: The distance between the axes and the plotting area
+    pub fn axis_offset<S: SizeDesc>(&mut self, value: S) -> &mut Self {
+        self.axis_offset = value.in_pixels(&self.parent_size).max(0);
+        self
+    }
+
     /// Set the maximum number of divisions for the minor grid
     /// - `value`: Maximum desired divisions between two consecutive X labels
     pub fn x_max_light_lines(&mut self, value: usize) -> &mut Self {
@@ -336,6 +499,84 @@ where
         self
     }
 
+    /// Divide each interval between two consecutive X grid lines into exactly the given number
+    /// of equal parts with the fine grid lines, for example, `5` gives 4 fine grid lines between
+    /// two coarse grid lines. This takes precedence over [`MeshStyle::x_max_light_lines`].
+    /// - `value`: The number of the divisions, 0 or 1 disables the fine grid lines
+    pub fn x_minor_divisions(&mut self, value: usize) -> &mut Self {
+        self.x_minor_divisions = Some(value);
+        self
+    }
+
+    /// Divide each interval between two consecutive Y grid lines into exactly the given number
+    /// of equal parts with the fine grid lines. See [`MeshStyle::x_minor_divisions`] for details.
+    /// - `value`: The number of the divisions, 0 or 1 disables the fine grid lines
+    pub fn y_minor_divisions(&mut self, value: usize) -> &mut Self {
+        self.y_minor_divisions = Some(value);
+        self
+    }
+
+    /// Divide each interval between two consecutive grid lines on both axes into exactly the
+    /// given number of equal parts with the fine grid lines
+    /// - `value`: The number of the divisions, 0 or 1 disables the fine grid lines
+    pub fn minor_divisions(&mut self, value: usize) -> &mut Self {
+        self.x_minor_divisions = Some(value);
+        self.y_minor_divisions = Some(value);
+        self
+    }
+
+    /// Fill every other interval between two consecutive X grid lines with the given style,
+    /// which gives the plotting area alternating vertical bands. When the mesh is drawn on top
+    /// of the series, a translucent style should be used to keep the series visible.
+    ///
+    /// The bands start from the first grid line, so the part of the plotting area before it is
+    /// never filled. With an odd number of grid lines, the part after the last line is filled as
+    /// the last band, and with an even number it's left empty like the part before the first.
+    /// - `style`: The fill style of the bands
+    pub fn x_bands<T: Into<ShapeStyle>>(&mut self, style: T) -> &mut Self {
+        self.x_bands = Some(style.into());
+        self
+    }
+
+    /// Fill every other interval between two consecutive Y grid lines with the given style,
+    /// which gives the plotting area alternating horizontal bands, starting from the top
+    /// grid line. See [`MeshStyle::x_bands`] for the bands at the ends.
+    /// - `style`: The fill style of the bands
+    pub fn y_bands<T: Into<ShapeStyle>>(&mut self, style: T) -> &mut Self {
+        self.y_bands = Some(style.into());
+        self
+    }
+
+    /// Draw minor tick marks between the major tick marks on the X axis. Unlike the fine grid,
+    /// minor tick marks only show up on the axis and don't draw any grid lines.
+    /// - `value`: Maximum desired divisions between two consecutive X labels, 0 disables the minor ticks
+    pub fn x_minor_ticks(&mut self, value: usize) -> &mut Self {
+        self.x_minor_ticks = value;
+        self
+    }
+
+    /// Draw minor tick marks between the major tick marks on the Y axis
+    /// - `value`: Maximum desired divisions between two consecutive Y labels, 0 disables the minor ticks
+    pub fn y_minor_ticks(&mut self, value: usize) -> &mut Self {
+        self.y_minor_ticks = value;
+        self
+    }
+
+    /// Draw minor tick marks between the major tick marks on both axes
+    /// - `value`: Maximum desired divisions between two consecutive labels, 0 disables the minor ticks
+    pub fn minor_ticks(&mut self, value: usize) -> &mut Self {
+        self.x_minor_ticks = value;
+        self.y_minor_ticks = value;
+        self
+    }
+
+    /// Set the style of the minor tick marks. If not given, use the axis style instead.
+    /// - `style`: The style for the minor tick marks
+    pub fn minor_tick_style<T: Into<ShapeStyle>>(&mut self, style: T) -> &mut Self {
+        self.minor_tick_style = Some(style.into());
+        self
+    }
+
     /// Set how many labels for the X axis at most
     /// - `value`: The maximum desired number of labels in the X axis
     pub fn x_labels(&mut self, value: usize) -> &mut Self {
@@ -350,6 +591,39 @@ where
         self
     }
 
+    /// Place the X labels, tick marks and the coarse grid lines exactly on the given values,
+    /// instead of the positions suggested by the coordinate.
+    /// The labels for the given values are formatted with the X label formatter as usual.
+    /// - `ticks`: The values where the ticks should be placed
+    pub fn x_ticks<I: IntoIterator<Item = X::ValueType>>(&mut self, ticks: I) -> &mut Self {
+        self.x_key_points = Some(ticks.into_iter().collect());
+        self
+    }
+
+    /// Place the Y labels, tick marks and the coarse grid lines exactly on the given values.
+    /// See [`MeshStyle::x_ticks`] for details.
+    /// - `ticks`: The values where the ticks should be placed
+    pub fn y_ticks<I: IntoIterator<Item = Y::ValueType>>(&mut self, ticks: I) -> &mut Self {
+        self.y_key_points = Some(ticks.into_iter().collect());
+        self
+    }
+
+    /// Draw the X grid lines exactly on the given values, independently from the labels and
+    /// tick marks. When this is set, the fine grid lines of the X axis are not drawn.
+    /// - `values`: The values where the grid lines should be drawn
+    pub fn x_grid_lines<I: IntoIterator<Item = X::ValueType>>(&mut self, values: I) -> &mut Self {
+        self.x_grid_points = Some(values.into_iter().collect());
+        self
+    }
+
+    /// Draw the Y grid lines exactly on the given values, independently from the labels and
+    /// tick marks. See [`MeshStyle::x_grid_lines`] for details.
+    /// - `values`: The values where the grid lines should be drawn
+    pub fn y_grid_lines<I: IntoIterator<Item = Y::ValueType>>(&mut self, values: I) -> &mut Self {
+        self.y_grid_points = Some(values.into_iter().collect());
+        self
+    }
+
     /// Set the style for the coarse grind grid
     /// - `style`: This is the coarse grind grid style
     pub fn bold_line_style<T: Into<ShapeStyle>>(&mut self, style: T) -> &mut Self {
@@ -364,6 +638,53 @@ where
         self
     }
 
+    /// Set if the tick marks should be mirrored on the top and right edges of the plotting area.
+    /// The mirrored tick marks have no labels, and use the sizes of the bottom X tick marks and
+    /// the left Y tick marks. An edge that already has a label area is left unchanged.
+    /// - `value`: If the tick marks should be mirrored
+    pub fn mirror_ticks(&mut self, value: bool) -> &mut Self {
+        self.mirror_ticks = value;
+        self
+    }
+
+    /// Set if the axes should be drawn with arrowheads at the ends where the values are the largest
+    /// - `value`: If the arrowheads should be drawn
+    pub fn axis_arrows(&mut self, value: bool) -> &mut Self {
+        self.axis_arrows = value;
+        self
+    }
+
+    /// Set the length of the axis arrowheads
+    /// - `size`: The length of the arrowheads
+    pub fn axis_arrow_size<S: SizeDesc>(&mut self, size: S) -> &mut Self {
+        self.axis_arrow_size = size.in_pixels(&self.parent_size);
+        self
+    }
+
+    /// Set the style of the axis arrowheads. If not given, use the axis style instead.
+    /// - `style`: The style for the arrowheads
+    pub fn axis_arrow_style<T: Into<ShapeStyle>>(&mut self, style: T) -> &mut Self {
+        self.axis_arrow_style = Some(style.into());
+        self
+    }
+
+    /// Emphasize the grid lines at X = 0 and Y = 0 with the given style.
+    /// Each line is only drawn when zero is inside the range of its axis.
+    /// - `style`: The style for the zero lines
+    pub fn zero_line_style<T: Into<ShapeStyle>>(&mut self, style: T) -> &mut Self
+    where
+        X::ValueType: Zero + PartialOrd,
+        Y::ValueType: Zero + PartialOrd,
+    {
+        if let Some(target) = self.target.as_ref() {
+            let coord = target.as_coord_spec();
+            self.x_zero = zero_in_range(coord.get_x_range()).into_iter().collect();
+            self.y_zero = zero_in_range(coord.get_y_range()).into_iter().collect();
+        }
+        self.zero_line_style = Some(style.into());
+        self
+    }
+
     /// Set the style of the label text
     /// - `style`: The text style that would be applied to the labels
     pub fn label_style<T: IntoTextStyle<'b>>(&mut self, style: T) -> &mut Self {
@@ -387,20 +708,213 @@ where
         self
     }
 
-    /// Set the formatter function for the X label text
+    /// Rotate the X label text, which is useful when the labels are too long to be placed
+    /// side by side. The labels are aligned so that the rotated text ends at the tick mark.
+    /// If the label area is too small for the bounding boxes of the rotated labels, it grows
+    /// and the plotting area shrinks, so the mesh should be drawn before the series. The label
+    /// areas of a chart with a secondary coordinate keep their sizes.
+    /// - `angle`: The clockwise rotation in degrees
+    pub fn x_label_rotation(&mut self, angle: f32) -> &mut Self {
+        self.x_label_rotation = angle;
+        self
+    }
+
+    /// Rotate the Y label text. See [`MeshStyle::x_label_rotation`] for details.
+    /// - `angle`: The clockwise rotation in degrees
+    pub fn y_label_rotation(&mut self, angle: f32) -> &mut Self {
+        self.y_label_rotation = angle;
+        self
+    }
+
+    /// Set how the X labels are handled when they overlap each other. The overlapping is detected
+    /// with the text size estimated by the drawing backend. By default, all the labels are drawn.
+    /// - `overlap`: What to do with the overlapping labels
+    pub fn x_label_overlap(&mut self, overlap: LabelOverlap) -> &mut Self {
+        self.x_label_overlap = overlap;
+        self
+    }
+
+    /// Set how the Y labels are handled when they overlap each other.
+    /// See [`MeshStyle::x_label_overlap`] for details.
+    /// - `overlap`: What to do with the overlapping labels
+    pub fn y_label_overlap(&mut self, overlap: LabelOverlap) -> &mut Self {
+        self.y_label_overlap = overlap;
+        self
+    }
+
+    /// Set how the labels on both axes are handled when they overlap each other
+    /// - `overlap`: What to do with the overlapping labels
+    pub fn label_overlap(&mut self, overlap: LabelOverlap) -> &mut Self {
+        self.x_label_overlap = overlap;
+        self.y_label_overlap = overlap;
+        self
+    }
+
+    /// Set what to do with the X labels which go beyond the left or right edge of the chart.
+    /// The text size is estimated by the drawing backend, and the rotated labels are kept as
+    /// they are. By default, all the labels are kept.
+    /// - `policy`: What to do with the labels at the edges
+    pub fn x_edge_labels(&mut self, policy: EdgeLabels) -> &mut Self {
+        self.x_edge_labels = policy;
+        self
+    }
+
+    /// Set what to do with the Y labels which go beyond the top or bottom edge of the chart.
+    /// See [`MeshStyle::x_edge_labels`] for details.
+    /// - `policy`: What to do with the labels at the edges
+    pub fn y_edge_labels(&mut self, policy: EdgeLabels) -> &mut Self {
+        self.y_edge_labels = policy;
+        self
+    }
+
+    /// Set what to do with the labels on both axes which go beyond the edges of the chart
+    /// - `policy`: What to do with the labels at the edges
+    pub fn edge_labels(&mut self, policy: EdgeLabels) -> &mut Self {
+        self.x_edge_labels = policy;
+        self.y_edge_labels = policy;
+        self
+    }
+
+    /// Set if the axes, tick marks and labels should be kept on top of the series.
+    ///
+    /// When enabled, the grid lines are drawn immediately, so the series drawn afterwards cover
+    /// them, while the axes, tick marks and labels are drawn once when the chart context is
+    /// dropped, so the chart context should be dropped before the drawing area is presented.
+    /// - `value`: If the axes should be on top of the series
+    pub fn on_top(&mut self, value: bool) -> &mut Self
+    where
+        DB: 'a,
+        X: Clone + 'a,
+        Y: Clone + 'a,
+    {
+        self.on_top = if value {
+            Some(ChartContext::defer_layer)
+        } else {
+            None
+        };
+        self
+    }
+
+    /// Set the formatter function for the X label text. The formatted labels stay on the tiers
+    /// given by [Ranged::key_point_tier](crate::coord::ranged1d::Ranged::key_point_tier).
+    /// A label may have multiple lines separated by `\n`.
     /// - `fmt`: The formatter function
     pub fn x_label_formatter(&mut self, fmt: &'b dyn Fn(&X::ValueType) -> String) -> &mut Self {
         self.format_x = Some(fmt);
         self
     }
 
-    /// Set the formatter function for the Y label text
+    /// Set the formatter function for the Y label text. A label may have multiple lines
+    /// separated by `\n`.
     /// - `fmt`: The formatter function
     pub fn y_label_formatter(&mut self, fmt: &'b dyn Fn(&Y::ValueType) -> String) -> &mut Self {
         self.format_y = Some(fmt);
         self
     }
 
+    /// Set the formatter function for the X label text, which also gets the context of the tick,
+    /// such as the index of the tick and the step between the ticks.
+    /// This takes precedence over the formatter set by [`MeshStyle::x_label_formatter`].
+    ///
+    /// ```rust
+    /// use plotters::prelude::*;
+    /// use plotters::chart::FormatterContext;
+    ///
+    /// let drawing_area = SVGBackend::new("formatter_context.svg", (300, 200)).into_drawing_area();
+    /// let mut chart = ChartBuilder::on(&drawing_area)
+    ///     .x_label_area_size(20)
+    ///     .build_cartesian_2d(0..100, 0..10)
+    ///     .unwrap();
+    ///
+    /// // Only the first label has the unit
+    /// let fmt = |x: &i32, ctx: &FormatterContext<i32>| {
+    ///     if ctx.index() == 0 {
+    ///         format!("{} ms", x)
+    ///     } else {
+    ///         format!("{}", x)
+    ///     }
+    /// };
+    /// chart
+    ///     .configure_mesh()
+    ///     .x_label_formatter_with_context(&fmt)
+    ///     .draw()
+    ///     .unwrap();
+    /// ```
+    /// - `fmt`: The formatter function
+    pub fn x_label_formatter_with_context(
+        &mut self,
+        fmt: LabelFormatterWithContext<'b, X::ValueType>,
+    ) -> &mut Self {
+        self.format_x_with_context = Some(fmt);
+        self
+    }
+
+    /// Set the formatter function for the Y label text, which also gets the context of the tick.
+    /// See [`MeshStyle::x_label_formatter_with_context`] for details.
+    /// - `fmt`: The formatter function
+    pub fn y_label_formatter_with_context(
+        &mut self,
+        fmt: LabelFormatterWithContext<'b, Y::ValueType>,
+    ) -> &mut Self {
+        self.format_y_with_context = Some(fmt);
+        self
+    }
+
+    /// Show the X labels as percentages, so that the ratio `0.25` is labeled as `25%`.
+    /// The formatter set by [`MeshStyle::x_label_formatter`] takes precedence over this.
+    /// - `precision`: The number of digits after the decimal point
+    pub fn x_label_as_percentage(&mut self, precision: usize) -> &mut Self
+    where
+        X::ValueType: ToPrimitive,
+    {
+        self.x_percentage = Some((percentage_text, precision));
+        self
+    }
+
+    /// Show the Y labels as percentages. See [`MeshStyle::x_label_as_percentage`] for details.
+    /// - `precision`: The number of digits after the decimal point
+    pub fn y_label_as_percentage(&mut self, precision: usize) -> &mut Self
+    where
+        Y::ValueType: ToPrimitive,
+    {
+        self.y_percentage = Some((percentage_text, precision));
+        self
+    }
+
+    /// Label the X axis relative to a common offset, and show the offset once at the bottom right
+    /// corner of the plotting area. For example, the ticks `1000000001, 1000000002, ...` are
+    /// labeled as `1, 2, ...` with the offset text `+1e9`. A power of ten is factored out in
+    /// the same way when the labels are very large or very small, such as `×1e-6`.
+    /// The offset is only used when the labels would be hard to read otherwise, and it doesn't
+    /// apply to the labels produced by a formatter function.
+    pub fn x_offset_text(&mut self) -> &mut Self
+    where
+        X::ValueType: ToPrimitive,
+    {
+        self.x_offset_text = Some(ToPrimitive::to_f64);
+        self
+    }
+
+    /// Label the Y axis relative to a common offset, and show the offset once at the top left
+    /// corner of the plotting area. See [`MeshStyle::x_offset_text`] for details.
+    pub fn y_offset_text(&mut self) -> &mut Self
+    where
+        Y::ValueType: ToPrimitive,
+    {
+        self.y_offset_text = Some(ToPrimitive::to_f64);
+        self
+    }
+
+    /// Use the number formatting convention of the given locale for the labels on both axes,
+    /// for example, `1234.5` is labeled as `1.234,5` with [`LabelLocale::german`].
+    /// This applies to the default labels and the percentage labels, but not to the labels
+    /// produced by a custom formatter function.
+    /// - `locale`: The locale to use
+    pub fn label_locale(&mut self, locale: LabelLocale) -> &mut Self {
+        self.label_locale = Some(locale);
+        self
+    }
+
     /// Set the axis description's style. If not given, use label style instead.
     /// - `style`: The text style that would be applied to descriptions
     pub fn axis_desc_style<T: IntoTextStyle<'b>>(&mut self, style: T) -> &mut Self {
@@ -409,125 +923,121 @@ where
     }
 
     /// Set the X axis's description
-    /// - `desc`: The description of the X axis
+    /// - `desc`: The description of the X axis, which may have multiple lines separated by `\n`
     pub fn x_desc<T: Into<String>>(&mut self, desc: T) -> &mut Self {
         self.x_desc = Some(desc.into());
         self
     }
 
     /// Set the Y axis's description
-    /// - `desc`: The description of the Y axis
+    /// - `desc`: The description of the Y axis, which may have multiple lines separated by `\n`
     pub fn y_desc<T: Into<String>>(&mut self, desc: T) -> &mut Self {
         self.y_desc = Some(desc.into());
         self
     }
 
-    /// Draw the configured mesh on the target plot
-    pub fn draw(&mut self) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>>
-    where
-        X: ValueFormatter<<X as Ranged>::ValueType>,
-        Y: ValueFormatter<<Y as Ranged>::ValueType>,
-    {
-        let target = self.target.take().unwrap();
-
-        let default_mesh_color_1 = RGBColor(0, 0, 0).mix(0.2);
-        let default_mesh_color_2 = RGBColor(0, 0, 0).mix(0.1);
-        let default_axis_color = RGBColor(0, 0, 0);
-        let default_label_font = FontDesc::new(
-            FontFamily::SansSerif,
-            f64::from((12i32).percent().max(12).in_pixels(&self.parent_size)),
-            FontStyle::Normal,
+    /// Set the orientation of the Y axis's description.
+    /// By default, the description on the left is rotated by 270 degrees and the one on the
+    /// right is rotated by 90 degrees.
+    /// - `orientation`: The orientation of the description
+    pub fn y_desc_orientation(&mut self, orientation: AxisDescOrientation) -> &mut Self {
+        self.y_desc_orientation = Some(orientation);
+        self
+    }
+
+    /// Set where the X axis's description is placed along the axis
+    /// - `placement`: The placement of the description
+    pub fn x_desc_placement(&mut self, placement: AxisDescPlacement) -> &mut Self {
+        self.x_desc_placement = placement;
+        self
+    }
+
+    /// Set where the Y axis's description is placed along the axis
+    /// - `placement`: The placement of the description
+    pub fn y_desc_placement(&mut self, placement: AxisDescPlacement) -> &mut Self {
+        self.y_desc_placement = placement;
+        self
+    }
+}
+
+impl<'a, 'b, D, Y, DB> MeshStyle<'a, 'b, SegmentedCoord<D>, Y, DB>
+where
+    D: DiscreteRanged,
+    Y: Ranged,
+    DB: DrawingBackend,
+{
+    /// Draw the X grid lines at the boundaries of the segments, while the labels and tick marks
+    /// stay at the centers of the segments. This is useful for the histograms.
+    pub fn x_grid_at_segment_boundaries(&mut self) -> &mut Self {
+        let values = segment_values(self.x_spec(), false);
+        self.x_grid_lines(values)
+    }
+
+    /// Put the X labels and tick marks at the boundaries of the segments, while the grid lines
+    /// are drawn at the centers of the segments.
+    pub fn x_labels_at_segment_boundaries(&mut self) -> &mut Self {
+        let (boundaries, centers) = (
+            segment_values(self.x_spec(), false),
+            segment_values(self.x_spec(), true),
         );
+        self.x_ticks(boundaries).x_grid_lines(centers)
+    }
+
+    fn x_spec(&self) -> &SegmentedCoord<D> {
+        self.target.as_ref().unwrap().as_coord_spec().x_spec()
+    }
+}
+
+impl<'a, 'b, X, D, DB> MeshStyle<'a, 'b, X, SegmentedCoord<D>, DB>
+where
+    X: Ranged,
+    D: DiscreteRanged,
+    DB: DrawingBackend,
+{
+    /// Draw the Y grid lines at the boundaries of the segments, while the labels and tick marks
+    /// stay at the centers of the segments.
+    pub fn y_grid_at_segment_boundaries(&mut self) -> &mut Self {
+        let values = segment_values(self.y_spec(), false);
+        self.y_grid_lines(values)
+    }
+
+    /// Put the Y labels and tick marks at the boundaries of the segments, while the grid lines
+    /// are drawn at the centers of the segments.
+    pub fn y_labels_at_segment_boundaries(&mut self) -> &mut Self {
+        let (boundaries, centers) = (
+            segment_values(self.y_spec(), false),
+            segment_values(self.y_spec(), true),
+        );
+        self.y_ticks(boundaries).y_grid_lines(centers)
+    }
+
+    fn y_spec(&self) -> &SegmentedCoord<D> {
+        self.target.as_ref().unwrap().as_coord_spec().y_spec()
+    }
+}
+
+/// Get either all the segment boundaries, including the last one, or all the segment centers
+fn segment_values<D: DiscreteRanged>(
+    spec: &SegmentedCoord<D>,
+    centers: bool,
+) -> Vec<SegmentValue<D::ValueType>> {
+    (0..spec.size())
+        .filter_map(|idx| match spec.from_index(idx)? {
+            SegmentValue::Exact(value) if centers => Some(SegmentValue::CenterOf(value)),
+            SegmentValue::Last if centers => None,
+            value => Some(value),
+        })
+        .collect()
+}
 
-        let bold_style = self
-            .bold_line_style
-            .unwrap_or_else(|| (&default_mesh_color_1).into());
-        let light_style = self
-            .light_line_style
-            .unwrap_or_else(|| (&default_mesh_color_2).into());
-        let axis_style = self
-            .axis_style
-            .unwrap_or_else(|| (&default_axis_color).into());
-
-        let x_label_style = self
-            .x_label_style
-            .clone()
-            .unwrap_or_else(|| default_label_font.clone().into());
-
-        let y_label_style = self
-            .y_label_style
-            .clone()
-            .unwrap_or_else(|| default_label_font.into());
-
-        let axis_desc_style = self
-            .axis_desc_style
-            .clone()
-            .unwrap_or_else(|| x_label_style.clone());
-
-        target.draw_mesh(
-            (
-                LightPoints::new(self.n_y_labels, self.n_y_labels * self.y_light_lines_limit),
-                LightPoints::new(self.n_x_labels, self.n_x_labels * self.x_light_lines_limit),
-            ),
-            &light_style,
-            &x_label_style,
-            &y_label_style,
-            |_, _, _| None,
-            self.draw_x_mesh,
-            self.draw_y_mesh,
-            self.x_label_offset,
-            self.y_label_offset,
-            false,
-            false,
-            &axis_style,
-            &axis_desc_style,
-            self.x_desc.clone(),
-            self.y_desc.clone(),
-            self.x_tick_size,
-            self.y_tick_size,
-        )?;
-
-        target.draw_mesh(
-            (BoldPoints(self.n_y_labels), BoldPoints(self.n_x_labels)),
-            &bold_style,
-            &x_label_style,
-            &y_label_style,
-            |xr, yr, m| match m {
-                MeshLine::XMesh(_, _, v) => {
-                    if self.draw_x_axis {
-                        if let Some(fmt_func) = self.format_x {
-                            Some(fmt_func(v))
-                        } else {
-                            Some(xr.format_ext(v))
-                        }
-                    } else {
-                        None
-                    }
-                }
-                MeshLine::YMesh(_, _, v) => {
-                    if self.draw_y_axis {
-                        if let Some(fmt_func) = self.format_y {
-                            Some(fmt_func(v))
-                        } else {
-                            Some(yr.format_ext(v))
-                        }
-                    } else {
-                        None
-                    }
-                }
-            },
-            self.draw_x_mesh,
-            self.draw_y_mesh,
-            self.x_label_offset,
-            self.y_label_offset,
-            self.draw_x_axis,
-            self.draw_y_axis,
-            &axis_style,
-            &axis_desc_style,
-            None,
-            None,
-            self.x_tick_size,
-            self.y_tick_size,
-        )
+/// Get the zero value if it's inside the given range
+fn zero_in_range<T: Zero + PartialOrd>(range: Range<T>) -> Option<T> {
+    let zero = T::zero();
+    let inside =
+        (range.start <= zero && zero <= range.end) || (range.end <= zero && zero <= range.start);
+    if inside {
+        Some(zero)
+    } else {
+        None
     }
 }
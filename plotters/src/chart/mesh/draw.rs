@@ -0,0 +1,884 @@
+use super::{
+    find_axis_offset, AxisDescLayout, EdgeLabels, FormatterContext, LabelOverlap, MeshLayout,
+    MeshStyle,
+};
+use crate::chart::ChartContext;
+use crate::coord::cartesian::{Cartesian2d, MeshLine};
+use crate::coord::ranged1d::{BoldPoints, LightPoints, Ranged, ValueFormatter};
+use crate::drawing::DrawingAreaErrorKind;
+use crate::style::{
+    text_anchor::Pos, AsRelative, Color, FontDesc, FontFamily, FontStyle, FontTransform, RGBColor,
+    ShapeStyle, SizeDesc, TextStyle,
+};
+
+use plotters_backend::{BackendColor, DrawingBackend};
+
+impl<'a, 'b, X, Y, DB> MeshStyle<'a, 'b, X, Y, DB>
+where
+    X: Ranged,
+    Y: Ranged,
+    DB: DrawingBackend,
+{
+    /// Draw the configured mesh on the target plot.
+    /// Returns the layout of the labeled tick marks which have been drawn.
+    pub fn draw(&mut self) -> Result<MeshLayout, DrawingAreaErrorKind<DB::ErrorType>>
+    where
+        X: ValueFormatter<<X as Ranged>::ValueType>,
+        Y: ValueFormatter<<Y as Ranged>::ValueType>,
+    {
+        let target = self.target.take().unwrap();
+        target.axis_offset = self.axis_offset;
+
+        let default_mesh_color_1 = RGBColor(0, 0, 0).mix(0.2);
+        let default_mesh_color_2 = RGBColor(0, 0, 0).mix(0.1);
+        let default_axis_color = RGBColor(0, 0, 0);
+        let default_label_font = FontDesc::new(
+            FontFamily::SansSerif,
+            f64::from((12i32).percent().max(12).in_pixels(&self.parent_size)),
+            FontStyle::Normal,
+        );
+
+        let bold_style = self
+            .bold_line_style
+            .unwrap_or_else(|| (&default_mesh_color_1).into());
+        let light_style = self
+            .light_line_style
+            .unwrap_or_else(|| (&default_mesh_color_2).into());
+        let axis_style = self
+            .axis_style
+            .unwrap_or_else(|| (&default_axis_color).into());
+        let minor_tick_style = self.minor_tick_style.unwrap_or(axis_style);
+        let arrow = if self.axis_arrows {
+            Some((
+                self.axis_arrow_style.unwrap_or(axis_style),
+                self.axis_arrow_size,
+            ))
+        } else {
+            None
+        };
+
+        let x_label_style = self
+            .x_label_style
+            .clone()
+            .unwrap_or_else(|| default_label_font.clone().into());
+
+        let y_label_style = self
+            .y_label_style
+            .clone()
+            .unwrap_or_else(|| default_label_font.into());
+
+        let axis_desc_style = self
+            .axis_desc_style
+            .clone()
+            .unwrap_or_else(|| x_label_style.clone());
+
+        let offset_text_style = x_label_style.clone();
+        let x_label_style = rotate_label_style(x_label_style, self.x_label_rotation);
+        let y_label_style = rotate_label_style(y_label_style, self.y_label_rotation);
+
+        let desc_layout = (
+            AxisDescLayout {
+                orientation: None,
+                placement: self.x_desc_placement,
+            },
+            AxisDescLayout {
+                orientation: self.y_desc_orientation,
+                placement: self.y_desc_placement,
+            },
+        );
+
+        let localize = |text: String| match self.label_locale {
+            Some(locale) => locale.localize(&text),
+            None => text,
+        };
+
+        let coord = target.as_coord_spec();
+        let x_offset = self.x_offset_text.and_then(|to_f64| {
+            let key_points = self.x_key_points.as_deref();
+            find_axis_offset(coord.x_spec(), key_points, self.n_x_labels, to_f64)
+        });
+        let y_offset = self.y_offset_text.and_then(|to_f64| {
+            let key_points = self.y_key_points.as_deref();
+            find_axis_offset(coord.y_spec(), key_points, self.n_y_labels, to_f64)
+        });
+        let offset_text = (
+            x_offset.map(|offset| offset.text()),
+            y_offset.map(|offset| offset.text()),
+        );
+
+        let fmt_label =
+            |xr: &X,
+             yr: &Y,
+             m: &MeshLine<X, Y>,
+             index: usize,
+             (x_ticks, y_ticks): (&[X::ValueType], &[Y::ValueType])| match m {
+                MeshLine::XMesh(_, _, v) => {
+                    if !self.draw_x_axis {
+                        None
+                    } else if let Some(fmt_func) = self.format_x_with_context {
+                        Some(fmt_func(v, &FormatterContext::new(x_ticks, index)))
+                    } else if let Some(fmt_func) = self.format_x {
+                        Some(fmt_func(v))
+                    } else if let Some((fmt_func, precision)) = self.x_percentage {
+                        Some(localize(fmt_func(v, precision)))
+                    } else if let (Some(offset), Some(value)) =
+                        (x_offset, self.x_offset_text.and_then(|to_f64| to_f64(v)))
+                    {
+                        Some(localize(offset.format(value)))
+                    } else {
+                        Some(localize(xr.format_ext(v)))
+                    }
+                }
+                MeshLine::YMesh(_, _, v) => {
+                    if !self.draw_y_axis {
+                        None
+                    } else if let Some(fmt_func) = self.format_y_with_context {
+                        Some(fmt_func(v, &FormatterContext::new(y_ticks, index)))
+                    } else if let Some(fmt_func) = self.format_y {
+                        Some(fmt_func(v))
+                    } else if let Some((fmt_func, precision)) = self.y_percentage {
+                        Some(localize(fmt_func(v, precision)))
+                    } else if let (Some(offset), Some(value)) =
+                        (y_offset, self.y_offset_text.and_then(|to_f64| to_f64(v)))
+                    {
+                        Some(localize(offset.format(value)))
+                    } else {
+                        Some(localize(yr.format_ext(v)))
+                    }
+                }
+            };
+
+        // The rotated labels may need larger label areas, which shrinks the plotting area, so
+        // the space is reserved before anything is drawn
+        let shown_labels = [
+            self.x_label_inset.is_none() && !self.hidden_labels[0],
+            self.x_label_inset.is_none() && !self.hidden_labels[1],
+            self.y_label_inset.is_none() && !self.hidden_labels[2],
+            self.y_label_inset.is_none() && !self.hidden_labels[3],
+        ];
+        target.reserve_rotated_labels(
+            (BoldPoints(self.n_y_labels), BoldPoints(self.n_x_labels)),
+            (self.x_key_points.as_deref(), self.y_key_points.as_deref()),
+            &fmt_label,
+            (&x_label_style, &y_label_style),
+            (self.x_tick_size, self.y_tick_size),
+            (self.x_label_padding, self.y_label_padding),
+            (self.x_label_overlap, self.y_label_overlap),
+            shown_labels,
+        )?;
+
+        // The axes with explicit grid lines don't get the grid lines from the key points
+        let x_mesh = self.draw_x_mesh && self.x_grid_points.is_none();
+        let y_mesh = self.draw_y_mesh && self.y_grid_points.is_none();
+        // The axes with exact minor divisions don't get the fine grid from the key points
+        let x_light_mesh = x_mesh && self.x_minor_divisions.is_none();
+        let y_light_mesh = y_mesh && self.y_minor_divisions.is_none();
+
+        // The mirrored tick mark sets, each with its positions, style and sizes
+        let mut mirrored_ticks = vec![];
+        if self.mirror_ticks {
+            let (x_ticks, y_ticks) = target.mirrored_tick_positions(
+                (BoldPoints(self.n_y_labels), BoldPoints(self.n_x_labels)),
+                (self.x_key_points.as_deref(), self.y_key_points.as_deref()),
+                self.draw_x_axis,
+                self.draw_y_axis,
+            )?;
+            mirrored_ticks.push((
+                x_ticks,
+                y_ticks,
+                axis_style,
+                self.x_tick_size[1],
+                self.y_tick_size[0],
+            ));
+            if self.x_minor_ticks > 0 || self.y_minor_ticks > 0 {
+                let (x_ticks, y_ticks) = target.mirrored_tick_positions(
+                    (
+                        LightPoints::new(self.n_y_labels, self.n_y_labels * self.y_minor_ticks),
+                        LightPoints::new(self.n_x_labels, self.n_x_labels * self.x_minor_ticks),
+                    ),
+                    (None, None),
+                    self.draw_x_axis && self.x_minor_ticks > 0,
+                    self.draw_y_axis && self.y_minor_ticks > 0,
+                )?;
+                mirrored_ticks.push((
+                    x_ticks,
+                    y_ticks,
+                    minor_tick_style,
+                    self.x_minor_tick_size[1],
+                    self.y_minor_tick_size[0],
+                ));
+            }
+        }
+
+        self.draw_bands(target)?;
+
+        if let Some(defer_layer) = self.on_top {
+            target.draw_mesh_lines(
+                (
+                    LightPoints::new(self.n_y_labels, self.n_y_labels * self.y_light_lines_limit),
+                    LightPoints::new(self.n_x_labels, self.n_x_labels * self.x_light_lines_limit),
+                ),
+                (None, None),
+                (x_light_mesh, y_light_mesh),
+                &light_style,
+                |_, _, _, _, _| None,
+            )?;
+            self.draw_minor_divisions(target, &light_style, (x_mesh, y_mesh))?;
+            self.draw_grid_lines(target, &bold_style)?;
+            let (x_labels, y_labels) = target.draw_mesh_lines(
+                (BoldPoints(self.n_y_labels), BoldPoints(self.n_x_labels)),
+                (self.x_key_points.as_deref(), self.y_key_points.as_deref()),
+                (x_mesh, y_mesh),
+                &bold_style,
+                fmt_label,
+            )?;
+            self.draw_zero_lines(target)?;
+
+            // The label styles may borrow the font name, so the deferred layer keeps its own copy
+            let x_label_style = OwnedTextStyle::new(&x_label_style);
+            let y_label_style = OwnedTextStyle::new(&y_label_style);
+            let axis_desc_style = OwnedTextStyle::new(&axis_desc_style);
+            let offset_text_style = OwnedTextStyle::new(&offset_text_style);
+            let (n_x_labels, n_y_labels) = (self.n_x_labels, self.n_y_labels);
+            let (x_minor_ticks, y_minor_ticks) = (self.x_minor_ticks, self.y_minor_ticks);
+            let (x_minor_tick_size, y_minor_tick_size) =
+                (self.x_minor_tick_size, self.y_minor_tick_size);
+            let (x_label_offset, y_label_offset) = (self.x_label_offset, self.y_label_offset);
+            let (draw_x_axis, draw_y_axis) = (self.draw_x_axis, self.draw_y_axis);
+            let (x_desc, y_desc) = (self.x_desc.clone(), self.y_desc.clone());
+            let (x_tick_size, y_tick_size) = (self.x_tick_size, self.y_tick_size);
+            let label_padding = (self.x_label_padding, self.y_label_padding);
+            let label_inset = (self.x_label_inset, self.y_label_inset);
+            let x_label_fit = (self.x_label_overlap, self.x_edge_labels);
+            let y_label_fit = (self.y_label_overlap, self.y_edge_labels);
+            let hidden_labels = self.hidden_labels;
+            let draw_axes = move |chart: &ChartContext<'a, DB, Cartesian2d<X, Y>>| {
+                chart.draw_axes(
+                    (&x_labels[..], &y_labels[..]),
+                    &x_label_style.as_text_style(),
+                    &y_label_style.as_text_style(),
+                    x_label_offset,
+                    y_label_offset,
+                    draw_x_axis,
+                    draw_y_axis,
+                    &axis_style,
+                    &axis_desc_style.as_text_style(),
+                    x_desc.as_deref(),
+                    y_desc.as_deref(),
+                    desc_layout,
+                    x_tick_size,
+                    y_tick_size,
+                    label_padding,
+                    label_inset,
+                    x_label_fit,
+                    y_label_fit,
+                    hidden_labels,
+                )
+            };
+            // The layout is known up front, while the layer is drawn once after all the series
+            target.layout_only = true;
+            let layout = draw_axes(target);
+            target.layout_only = false;
+
+            let layer = defer_layer(
+                target,
+                Box::new(move |chart: &ChartContext<'a, DB, Cartesian2d<X, Y>>| {
+                    if x_minor_ticks > 0 || y_minor_ticks > 0 {
+                        chart.draw_minor_ticks(
+                            (
+                                LightPoints::new(n_y_labels, n_y_labels * y_minor_ticks),
+                                LightPoints::new(n_x_labels, n_x_labels * x_minor_ticks),
+                            ),
+                            &minor_tick_style,
+                            draw_x_axis && x_minor_ticks > 0,
+                            draw_y_axis && y_minor_ticks > 0,
+                            x_minor_tick_size,
+                            y_minor_tick_size,
+                        )?;
+                    }
+                    for (x_ticks, y_ticks, style, x_size, y_size) in mirrored_ticks.iter() {
+                        chart.draw_mirrored_ticks((x_ticks, y_ticks), style, *x_size, *y_size)?;
+                    }
+                    if let Some((arrow_style, arrow_size)) = arrow {
+                        chart.draw_axis_arrows(
+                            &arrow_style,
+                            arrow_size,
+                            (draw_x_axis, draw_y_axis),
+                            x_tick_size,
+                            y_tick_size,
+                        )?;
+                    }
+                    draw_axes(chart)?;
+                    chart.draw_offset_text(
+                        (offset_text.0.as_deref(), offset_text.1.as_deref()),
+                        &offset_text_style.as_text_style(),
+                    )
+                }),
+            );
+            target.deferred_layer = Some(layer);
+            return layout;
+        }
+
+        target.draw_mesh(
+            (
+                LightPoints::new(self.n_y_labels, self.n_y_labels * self.y_light_lines_limit),
+                LightPoints::new(self.n_x_labels, self.n_x_labels * self.x_light_lines_limit),
+            ),
+            (None, None),
+            &light_style,
+            &x_label_style,
+            &y_label_style,
+            |_, _, _, _, _| None,
+            x_light_mesh,
+            y_light_mesh,
+            self.x_label_offset,
+            self.y_label_offset,
+            false,
+            false,
+            &axis_style,
+            &axis_desc_style,
+            self.x_desc.clone(),
+            self.y_desc.clone(),
+            desc_layout,
+            self.x_tick_size,
+            self.y_tick_size,
+            (self.x_label_padding, self.y_label_padding),
+            (None, None),
+            (LabelOverlap::Allow, EdgeLabels::Keep),
+            (LabelOverlap::Allow, EdgeLabels::Keep),
+            [false; 4],
+        )?;
+        self.draw_minor_divisions(target, &light_style, (x_mesh, y_mesh))?;
+
+        if self.x_minor_ticks > 0 || self.y_minor_ticks > 0 {
+            target.draw_minor_ticks(
+                (
+                    LightPoints::new(self.n_y_labels, self.n_y_labels * self.y_minor_ticks),
+                    LightPoints::new(self.n_x_labels, self.n_x_labels * self.x_minor_ticks),
+                ),
+                &minor_tick_style,
+                self.draw_x_axis && self.x_minor_ticks > 0,
+                self.draw_y_axis && self.y_minor_ticks > 0,
+                self.x_minor_tick_size,
+                self.y_minor_tick_size,
+            )?;
+        }
+
+        self.draw_grid_lines(target, &bold_style)?;
+
+        let layout = target.draw_mesh(
+            (BoldPoints(self.n_y_labels), BoldPoints(self.n_x_labels)),
+            (self.x_key_points.as_deref(), self.y_key_points.as_deref()),
+            &bold_style,
+            &x_label_style,
+            &y_label_style,
+            fmt_label,
+            x_mesh,
+            y_mesh,
+            self.x_label_offset,
+            self.y_label_offset,
+            self.draw_x_axis,
+            self.draw_y_axis,
+            &axis_style,
+            &axis_desc_style,
+            None,
+            None,
+            desc_layout,
+            self.x_tick_size,
+            self.y_tick_size,
+            (self.x_label_padding, self.y_label_padding),
+            (self.x_label_inset, self.y_label_inset),
+            (self.x_label_overlap, self.x_edge_labels),
+            (self.y_label_overlap, self.y_edge_labels),
+            self.hidden_labels,
+        )?;
+
+        for (x_ticks, y_ticks, style, x_size, y_size) in mirrored_ticks.iter() {
+            target.draw_mirrored_ticks((x_ticks, y_ticks), style, *x_size, *y_size)?;
+        }
+
+        if let Some((arrow_style, arrow_size)) = arrow {
+            target.draw_axis_arrows(
+                &arrow_style,
+                arrow_size,
+                (self.draw_x_axis, self.draw_y_axis),
+                self.x_tick_size,
+                self.y_tick_size,
+            )?;
+        }
+
+        target.draw_offset_text(
+            (offset_text.0.as_deref(), offset_text.1.as_deref()),
+            &offset_text_style,
+        )?;
+
+        self.draw_zero_lines(target)?;
+        Ok(layout)
+    }
+
+    /// Draw the alternating bands between the grid lines, if they are configured
+    fn draw_bands(
+        &self,
+        target: &ChartContext<'a, DB, Cartesian2d<X, Y>>,
+    ) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>> {
+        if self.x_bands.is_none() && self.y_bands.is_none() {
+            return Ok(());
+        }
+        target.draw_alternating_bands(
+            (BoldPoints(self.n_y_labels), BoldPoints(self.n_x_labels)),
+            (self.x_key_points.as_deref(), self.y_key_points.as_deref()),
+            (self.x_bands.as_ref(), self.y_bands.as_ref()),
+        )
+    }
+
+    /// Draw the fine grid lines with the exact divisions, if they are configured
+    fn draw_minor_divisions(
+        &self,
+        target: &ChartContext<'a, DB, Cartesian2d<X, Y>>,
+        style: &ShapeStyle,
+        (x_mesh, y_mesh): (bool, bool),
+    ) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>> {
+        let x_div = self.x_minor_divisions.filter(|_| x_mesh).unwrap_or(0);
+        let y_div = self.y_minor_divisions.filter(|_| y_mesh).unwrap_or(0);
+        if x_div < 2 && y_div < 2 {
+            return Ok(());
+        }
+        target.draw_minor_division_lines(
+            (BoldPoints(self.n_y_labels), BoldPoints(self.n_x_labels)),
+            (self.x_key_points.as_deref(), self.y_key_points.as_deref()),
+            (x_div, y_div),
+            style,
+        )
+    }
+
+    /// Draw the explicit grid lines, if they are configured
+    fn draw_grid_lines(
+        &self,
+        target: &ChartContext<'a, DB, Cartesian2d<X, Y>>,
+        style: &ShapeStyle,
+    ) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>> {
+        let (x_grid, y_grid) = (self.x_grid_points.as_deref(), self.y_grid_points.as_deref());
+        if x_grid.is_none() && y_grid.is_none() {
+            return Ok(());
+        }
+        target.draw_mesh_lines(
+            (BoldPoints(0), BoldPoints(0)),
+            (x_grid.or(Some(&[])), y_grid.or(Some(&[]))),
+            (self.draw_x_mesh, self.draw_y_mesh),
+            style,
+            |_, _, _, _, _| None,
+        )?;
+        Ok(())
+    }
+
+    /// Draw the emphasized zero lines, if they are configured
+    fn draw_zero_lines(
+        &self,
+        target: &ChartContext<'a, DB, Cartesian2d<X, Y>>,
+    ) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>> {
+        let style = match self.zero_line_style.as_ref() {
+            Some(style) => style,
+            None => return Ok(()),
+        };
+        target.draw_mesh_lines(
+            (BoldPoints(0), BoldPoints(0)),
+            (Some(&self.x_zero[..]), Some(&self.y_zero[..])),
+            (true, true),
+            style,
+            |_, _, _, _, _| None,
+        )?;
+        Ok(())
+    }
+}
+
+/// An owned copy of a text style
+struct OwnedTextStyle {
+    family: String,
+    size: f64,
+    style: FontStyle,
+    transform: FontTransform,
+    color: BackendColor,
+    pos: Pos,
+}
+
+impl OwnedTextStyle {
+    fn new(style: &TextStyle) -> Self {
+        Self {
+            family: style.font.get_name().to_string(),
+            size: style.font.get_size(),
+            style: style.font.get_style(),
+            transform: style.font.get_transform(),
+            color: style.color,
+            pos: style.pos,
+        }
+    }
+
+    fn as_text_style(&self) -> TextStyle<'_> {
+        TextStyle {
+            font: FontDesc::new(self.family.as_str().into(), self.size, self.style)
+                .transform(self.transform.clone()),
+            color: self.color,
+            pos: self.pos,
+        }
+    }
+}
+
+/// Apply the label rotation to the label style, a zero angle keeps the style untouched
+pub(crate) fn rotate_label_style(style: TextStyle, angle: f32) -> TextStyle {
+    let angle = angle.rem_euclid(360.0);
+    let trans = if angle == 0.0 {
+        return style;
+    } else if angle == 90.0 {
+        FontTransform::Rotate90
+    } else if angle == 180.0 {
+        FontTransform::Rotate180
+    } else if angle == 270.0 {
+        FontTransform::Rotate270
+    } else {
+        FontTransform::RotateAngle(angle)
+    };
+    style.transform(trans)
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_minor_divisions() {
+        use std::cell::RefCell;
+        use std::rc::Rc;
+
+        let lines = Rc::new(RefCell::new(vec![]));
+        let (lines_ref, path_lines_ref) = (lines.clone(), lines.clone());
+        let drawing_area = create_mocked_drawing_area(200, 200, move |m| {
+            m.check_draw_line(move |_, _, from, to| {
+                if from.0 == to.0 {
+                    lines_ref.borrow_mut().push(from.0);
+                }
+            });
+            m.check_draw_path(move |_, _, path| {
+                if path.len() == 2 && path[0].0 == path[1].0 {
+                    path_lines_ref.borrow_mut().push(path[0].0);
+                }
+            });
+        });
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .x_label_area_size(20)
+            .build_cartesian_2d(0..8, 0..10)
+            .expect("Create chart");
+
+        chart
+            .configure_mesh()
+            .disable_y_mesh()
+            .x_ticks(vec![0, 4, 8])
+            .x_minor_divisions(4)
+            .draw()
+            .expect("Draw mesh");
+
+        let mut lines = lines.borrow().clone();
+        lines.sort_unstable();
+        lines.dedup();
+        // The fine grid lines are interpolated in pixels, so they may be off by one pixel
+        assert_eq!(lines.len(), 9);
+        for (x, line) in (0..=8).zip(lines) {
+            assert!((chart.backend_coord(&(x, 0)).0 - line).abs() <= 1);
+        }
+    }
+
+    #[test]
+    fn test_segment_boundary_grid() {
+        use crate::coord::ranged1d::SegmentValue;
+        use std::cell::RefCell;
+        use std::rc::Rc;
+
+        let lines = Rc::new(RefCell::new(vec![]));
+        let lines_ref = lines.clone();
+        let drawing_area = create_mocked_drawing_area(200, 200, move |m| {
+            m.check_draw_line(move |_, _, from, to| {
+                // Only collect the vertical grid lines spanning the plotting area
+                if from.0 == to.0 && (from.1 - to.1).abs() > 100 {
+                    lines_ref.borrow_mut().push(from.0);
+                }
+            });
+        });
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .x_label_area_size(20)
+            .build_cartesian_2d((0..4).into_segmented(), 0..10)
+            .expect("Create chart");
+
+        chart
+            .configure_mesh()
+            .disable_y_mesh()
+            .x_grid_at_segment_boundaries()
+            .draw()
+            .expect("Draw mesh");
+
+        let expected: Vec<_> = (0..5)
+            .map(|x| chart.backend_coord(&(SegmentValue::Exact(x), 0)).0)
+            .chain(std::iter::once(
+                chart.backend_coord(&(SegmentValue::Last, 0)).0,
+            ))
+            .collect();
+        assert_eq!(*lines.borrow(), expected);
+    }
+
+    #[test]
+    fn test_alternating_bands() {
+        use std::cell::RefCell;
+        use std::rc::Rc;
+
+        // The bands start from the first grid line, so the part before it is never filled, and
+        // with an odd number of lines the part after the last line is filled
+        let cases: [(Vec<i32>, Vec<(i32, Option<i32>)>); 4] = [
+            (vec![0, 2, 4, 6, 8], vec![(0, Some(2)), (4, Some(6))]),
+            (vec![0, 2, 4, 6], vec![(0, Some(2)), (4, Some(6))]),
+            (vec![1, 3, 5, 7], vec![(1, Some(3)), (5, Some(7))]),
+            (vec![1, 3, 5], vec![(1, Some(3)), (5, None)]),
+        ];
+        for (ticks, expected) in cases.iter() {
+            let bands = Rc::new(RefCell::new(vec![]));
+            let bands_ref = bands.clone();
+            let drawing_area = create_mocked_drawing_area(200, 200, move |m| {
+                m.check_draw_rect(move |c, _, fill, from, to| {
+                    if c == RED.to_rgba() {
+                        assert!(fill);
+                        bands_ref.borrow_mut().push((from, to));
+                    }
+                });
+            });
+
+            let mut chart = ChartBuilder::on(&drawing_area)
+                .x_label_area_size(20)
+                .y_label_area_size(20)
+                .build_cartesian_2d(0..8, 0..10)
+                .expect("Create chart");
+
+            chart
+                .configure_mesh()
+                .x_ticks(ticks.clone())
+                .x_bands(RED)
+                .draw()
+                .expect("Draw mesh");
+
+            let bands = bands.borrow();
+            assert_eq!(bands.len(), expected.len());
+            for ((from, to), (start, end)) in bands.iter().zip(expected) {
+                assert_eq!(from.0, chart.backend_coord(&(*start, 0)).0);
+                let end = end.map_or(200, |end| chart.backend_coord(&(end, 0)).0);
+                assert_eq!(to.0, end);
+                assert_eq!((from.1, to.1), (0, 179));
+            }
+        }
+    }
+
+    #[test]
+    fn test_zero_line() {
+        let count_lines = |x_range: std::ops::Range<i32>, zero_line| {
+            count_mocked_calls(
+                200,
+                200,
+                |b| b.num_draw_line_call,
+                |drawing_area| {
+                    let mut chart = ChartBuilder::on(drawing_area)
+                        .build_cartesian_2d(x_range, -10..10)
+                        .expect("Create chart");
+
+                    let mut mesh = chart.configure_mesh();
+                    if zero_line {
+                        mesh.zero_line_style(BLACK);
+                    }
+                    mesh.draw().expect("Draw mesh");
+                },
+            )
+        };
+
+        assert_eq!(count_lines(-10..10, true), count_lines(-10..10, false) + 2);
+        assert_eq!(count_lines(1..10, true), count_lines(1..10, false) + 1);
+    }
+
+    #[test]
+    fn test_mesh_on_top() {
+        let count_text = |on_top, series| {
+            count_mocked_calls(
+                200,
+                200,
+                |b| b.num_draw_text_call,
+                |drawing_area| {
+                    let mut chart = ChartBuilder::on(drawing_area)
+                        .x_label_area_size(20)
+                        .y_label_area_size(20)
+                        .build_cartesian_2d(0..10, 0..10)
+                        .expect("Create chart");
+
+                    chart
+                        .configure_mesh()
+                        .on_top(on_top)
+                        .draw()
+                        .expect("Draw mesh");
+
+                    for _ in 0..series {
+                        chart
+                            .draw_series(std::iter::once(Circle::new((5, 5), 5, RED)))
+                            .expect("Draw series");
+                    }
+                },
+            )
+        };
+
+        let labels = count_text(false, 0);
+        assert!(labels > 0);
+        assert_eq!(count_text(false, 2), labels);
+        assert_eq!(count_text(true, 0), labels);
+        assert_eq!(count_text(true, 2), labels);
+    }
+
+    #[test]
+    fn test_mesh_on_top_transformed() {
+        use std::cell::RefCell;
+        use std::rc::Rc;
+
+        let events = Rc::new(RefCell::new(vec![]));
+        let (text_events, circle_events) = (events.clone(), events.clone());
+        let drawing_area = create_mocked_drawing_area(200, 200, move |m| {
+            m.check_draw_text(move |_, _, _, _, _| text_events.borrow_mut().push("label"));
+            m.check_draw_circle(move |_, _, _, _, _| circle_events.borrow_mut().push("series"));
+        });
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .x_label_area_size(20)
+            .y_label_area_size(20)
+            .build_cartesian_2d(0..10, 0..10)
+            .expect("Create chart");
+        chart
+            .configure_mesh()
+            .on_top(true)
+            .draw()
+            .expect("Draw mesh");
+
+        /* The axes configured on the untransformed chart are still drawn above the series */
+        let mut chart = chart.transformed(AffineTransform::rotate(30.0));
+        chart
+            .draw_series(std::iter::once(Circle::new((5, 5), 5, RED)))
+            .expect("Draw series");
+        /* The axes are drawn once the chart is dropped */
+        assert!(events.borrow().iter().all(|e| *e != "label"));
+        drop(chart);
+
+        let events = events.borrow();
+        assert_eq!(events.first(), Some(&"series"));
+        assert_eq!(events.last(), Some(&"label"));
+    }
+
+    #[test]
+    fn test_explicit_ticks() {
+        let (drawing_area, labels) = create_text_recording_area(200, 200);
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .x_label_area_size(20)
+            .build_cartesian_2d(0..10, 0..10)
+            .expect("Create chart");
+
+        chart
+            .configure_mesh()
+            .disable_y_axis()
+            .x_ticks([1, 3, 7])
+            .x_label_formatter(&|x| format!("#{}", x))
+            .draw()
+            .expect("Draw mesh");
+
+        assert_eq!(labels.texts(), ["#1", "#3", "#7"]);
+    }
+
+    #[test]
+    fn test_hide_labels() {
+        use crate::chart::{LabelAreaPosition, MeshStyle};
+        use crate::coord::types::RangedCoordi32;
+
+        type Mesh<'a, 'b> = MeshStyle<'a, 'b, RangedCoordi32, RangedCoordi32, MockedBackend>;
+        let count = |hide: &dyn Fn(&mut Mesh)| {
+            let read = |b: &MockedBackend| (b.num_draw_text_call, b.num_draw_line_call);
+            count_mocked_calls(200, 200, read, |drawing_area| {
+                // The labels are drawn on all the four sides
+                let mut chart = ChartBuilder::on(drawing_area)
+                    .set_all_label_area_size(20)
+                    .build_cartesian_2d(0..10, 0..10)
+                    .expect("Create chart");
+
+                let mut mesh = chart.configure_mesh();
+                mesh.disable_mesh().x_ticks(vec![2, 4, 6]).y_ticks(vec![5]);
+                hide(&mut mesh);
+                mesh.draw().expect("Draw mesh");
+            })
+        };
+
+        let (shown_text, shown_lines) = count(&|_| {});
+        let cases: [(&dyn Fn(&mut Mesh), u32); 4] = [
+            (
+                &|mesh| {
+                    mesh.hide_x_labels();
+                },
+                6,
+            ),
+            (
+                &|mesh| {
+                    mesh.hide_y_labels();
+                },
+                2,
+            ),
+            (
+                &|mesh| {
+                    mesh.hide_labels(LabelAreaPosition::Top);
+                },
+                3,
+            ),
+            (
+                &|mesh| {
+                    mesh.hide_labels(LabelAreaPosition::Right);
+                },
+                1,
+            ),
+        ];
+        for (hide, hidden) in cases.iter() {
+            let (hidden_text, hidden_lines) = count(*hide);
+            assert_eq!(hidden_text, shown_text - hidden);
+            assert_eq!(hidden_lines, shown_lines);
+        }
+    }
+
+    #[test]
+    fn test_mesh_layout() {
+        for &on_top in &[false, true] {
+            let drawing_area = create_mocked_drawing_area(200, 200, |_| {});
+            let mut chart = ChartBuilder::on(&drawing_area)
+                .x_label_area_size(20)
+                .y_label_area_size(20)
+                .build_cartesian_2d(0..10, 0..10)
+                .expect("Create chart");
+
+            let layout = chart
+                .configure_mesh()
+                .x_ticks(vec![0, 5, 10])
+                .y_ticks(vec![5])
+                .on_top(on_top)
+                .draw()
+                .expect("Draw mesh");
+
+            let x_ticks: Vec<_> = layout.ticks_on(LabelAreaPosition::Bottom).collect();
+            assert_eq!(x_ticks.len(), 3);
+            assert_eq!(layout.ticks_on(LabelAreaPosition::Left).count(), 1);
+            assert_eq!(layout.ticks_on(LabelAreaPosition::Top).count(), 0);
+
+            for (tick, x) in x_ticks.iter().zip(&[0, 5, 10]) {
+                assert_eq!(tick.pixel, chart.backend_coord(&(*x, 0)).0);
+                assert_eq!(tick.text, x.to_string());
+                let ((l, t), (r, b)) = tick.label_rect.expect("Label is drawn");
+                /* The label is centered below the tick mark, inside the label area */
+                assert!(l <= tick.pixel && tick.pixel <= r);
+                assert!(t >= 180 && b <= 200 && t < b);
+            }
+        }
+    }
+}
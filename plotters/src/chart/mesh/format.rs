@@ -0,0 +1,550 @@
+use std::ops::Sub;
+
+use crate::coord::ranged1d::{BoldPoints, Ranged};
+
+use num_traits::ToPrimitive;
+
+/**
+The ready-made label formatters, which can be used with [`MeshStyle::x_label_formatter`] and
+[`MeshStyle::y_label_formatter`] for any numeric axis.
+
+```rust
+use plotters::prelude::*;
+use plotters::chart::Formatter;
+
+let drawing_area = SVGBackend::new("formatter.svg", (300, 200)).into_drawing_area();
+let mut chart = ChartBuilder::on(&drawing_area)
+    .x_label_area_size(20)
+    .y_label_area_size(40)
+    .build_cartesian_2d(0.0..2e6, 0.0..1e-3)
+    .unwrap();
+
+let x_fmt = Formatter::si_prefix();
+let y_fmt = Formatter::scientific(1);
+chart
+    .configure_mesh()
+    .x_label_formatter(&x_fmt)
+    .y_label_formatter(&y_fmt)
+    .draw()
+    .unwrap();
+```
+
+[`MeshStyle::x_label_formatter`]: crate::chart::MeshStyle::x_label_formatter
+[`MeshStyle::y_label_formatter`]: crate::chart::MeshStyle::y_label_formatter
+*/
+pub struct Formatter;
+
+impl Formatter {
+    /// Format the value in the scientific notation, for example, `1500000` becomes `1.5e6`.
+    /// - `precision`: The maximum number of digits after the decimal point of the mantissa
+    pub fn scientific<T: ToPrimitive>(precision: usize) -> impl Fn(&T) -> String {
+        move |value| {
+            let value = value.to_f64().unwrap_or(f64::NAN);
+            if value == 0.0 || !value.is_finite() {
+                return format!("{}", value);
+            }
+            let text = format!("{:.*e}", precision, value);
+            match text.split_once('e') {
+                Some((mantissa, exp)) => format!("{}e{}", trim_fraction(mantissa), exp),
+                None => text,
+            }
+        }
+    }
+
+    /// Format the value with the SI prefixes, for example, `1500000` becomes `1.5M` and
+    /// `0.002` becomes `2m`.
+    pub fn si_prefix<T: ToPrimitive>() -> impl Fn(&T) -> String {
+        const PREFIXES: [&str; 17] = [
+            "y", "z", "a", "f", "p", "n", "µ", "m", "", "k", "M", "G", "T", "P", "E", "Z", "Y",
+        ];
+        move |value| {
+            let value = value.to_f64().unwrap_or(f64::NAN);
+            if value == 0.0 || !value.is_finite() {
+                return format!("{}", value);
+            }
+            let mut idx = ((value.abs().log10() / 3.0).floor() as i32).clamp(-8, 8);
+            let mut mantissa = value / 10f64.powi(idx * 3);
+            /* The mantissa may be rounded up to 1000, in this case we should use the next prefix */
+            if (mantissa.abs() * 100.0).round() >= 100_000.0 && idx < 8 {
+                idx += 1;
+                mantissa /= 1000.0;
+            }
+            let text = format!("{:.2}", mantissa);
+            format!("{}{}", trim_fraction(&text), PREFIXES[(idx + 8) as usize])
+        }
+    }
+
+    /// Format the ratio as a percentage, for example, `0.125` becomes `12.5%` with precision 1.
+    /// - `precision`: The number of digits after the decimal point
+    pub fn percentage<T: ToPrimitive>(precision: usize) -> impl Fn(&T) -> String {
+        move |value| percentage_text(value, precision)
+    }
+
+    /// Format the value with the separators of the given locale, for example, `1234.5` becomes
+    /// `1.234,50` with [`LabelLocale::german`] and precision 2.
+    /// - `locale`: The locale to use
+    /// - `precision`: The number of digits after the decimal point
+    pub fn localized<T: ToPrimitive>(
+        locale: LabelLocale,
+        precision: usize,
+    ) -> impl Fn(&T) -> String {
+        move |value| locale.format_number(value.to_f64().unwrap_or(f64::NAN), precision)
+    }
+}
+
+/**
+The number formatting convention of a locale, which is used by [`MeshStyle::label_locale`] and
+[`Formatter::localized`].
+
+```rust
+use plotters::chart::LabelLocale;
+
+assert_eq!(LabelLocale::german().format_number(1234.56, 2), "1.234,56");
+assert_eq!(LabelLocale::english().format_number(1234.56, 2), "1,234.56");
+assert_eq!(LabelLocale::german().localize("-1234.5%"), "-1.234,5%");
+```
+
+[`MeshStyle::label_locale`]: crate::chart::MeshStyle::label_locale
+*/
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct LabelLocale {
+    decimal_separator: char,
+    thousands_separator: Option<char>,
+}
+
+impl LabelLocale {
+    /// Create a locale with the given separators
+    /// - `decimal_separator`: The character between the integer and the fractional part
+    /// - `thousands_separator`: The character between the groups of three digits, if any
+    pub fn new(decimal_separator: char, thousands_separator: Option<char>) -> Self {
+        Self {
+            decimal_separator,
+            thousands_separator,
+        }
+    }
+
+    /// The English convention, for example, `1,234.56`
+    pub fn english() -> Self {
+        Self::new('.', Some(','))
+    }
+
+    /// The German convention, for example, `1.234,56`
+    pub fn german() -> Self {
+        Self::new(',', Some('.'))
+    }
+
+    /// The French convention, for example, `1 234,56` with a narrow no-break space
+    pub fn french() -> Self {
+        Self::new(',', Some('\u{202f}'))
+    }
+
+    /// Format the number with the given number of digits after the decimal point
+    pub fn format_number(&self, value: f64, precision: usize) -> String {
+        if !value.is_finite() {
+            return format!("{}", value);
+        }
+        self.localize(&format!("{:.*}", precision, value))
+    }
+
+    /// Rewrite a label produced by the default formatter with this locale's separators.
+    /// Only the labels that are a single plain number, optionally followed by a unit suffix
+    /// such as `%`, are changed; any other text, for example a date, is returned as it is.
+    pub fn localize(&self, text: &str) -> String {
+        let (sign, rest) = match text.strip_prefix('-') {
+            Some(rest) => ("-", rest),
+            None => ("", text),
+        };
+        let int_len = rest
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(rest.len());
+        let (int_part, rest) = rest.split_at(int_len);
+        let (frac_part, suffix) = match rest.strip_prefix('.') {
+            Some(frac) => {
+                let len = frac
+                    .find(|c: char| !c.is_ascii_digit())
+                    .unwrap_or(frac.len());
+                frac.split_at(len)
+            }
+            None => ("", rest),
+        };
+        if int_part.is_empty()
+            || (rest.starts_with('.') && frac_part.is_empty())
+            || suffix.contains(|c: char| c.is_ascii_digit() || c == '.')
+        {
+            return text.to_string();
+        }
+
+        let mut result = String::from(sign);
+        for (idx, digit) in int_part.chars().enumerate() {
+            if idx > 0 && (int_part.len() - idx) % 3 == 0 {
+                if let Some(sep) = self.thousands_separator {
+                    result.push(sep);
+                }
+            }
+            result.push(digit);
+        }
+        if !frac_part.is_empty() {
+            result.push(self.decimal_separator);
+            result.push_str(frac_part);
+        }
+        result.push_str(suffix);
+        result
+    }
+}
+
+/// The context of a tick label, which is passed to the formatter set by
+/// [`MeshStyle::x_label_formatter_with_context`] and [`MeshStyle::y_label_formatter_with_context`].
+///
+/// [`MeshStyle::x_label_formatter_with_context`]: crate::chart::MeshStyle::x_label_formatter_with_context
+/// [`MeshStyle::y_label_formatter_with_context`]: crate::chart::MeshStyle::y_label_formatter_with_context
+pub struct FormatterContext<'v, T> {
+    ticks: &'v [T],
+    index: usize,
+}
+
+impl<'v, T> FormatterContext<'v, T> {
+    pub(crate) fn new(ticks: &'v [T], index: usize) -> Self {
+        Self { ticks, index }
+    }
+
+    /// Get the index of the tick, starting from 0
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Get the total number of the ticks on the axis
+    pub fn count(&self) -> usize {
+        self.ticks.len()
+    }
+
+    /// Get the value of the previous tick, if the tick isn't the first one
+    pub fn previous(&self) -> Option<&'v T> {
+        self.index
+            .checked_sub(1)
+            .and_then(|idx| self.ticks.get(idx))
+    }
+
+    /// Get the value of the next tick, if the tick isn't the last one
+    pub fn next(&self) -> Option<&'v T> {
+        self.ticks.get(self.index + 1)
+    }
+
+    /// Get the step between this tick and the previous one, or between the first two ticks for
+    /// the first tick. Returns `None` if there's only one tick.
+    pub fn step<D>(&self) -> Option<D>
+    where
+        T: Clone + Sub<Output = D>,
+    {
+        let idx = self.index.max(1);
+        let (prev, this) = (self.ticks.get(idx - 1)?, self.ticks.get(idx)?);
+        Some(this.clone() - prev.clone())
+    }
+}
+
+/// The formatter function which gets the context of the tick
+pub type LabelFormatterWithContext<'b, T> = &'b dyn Fn(&T, &FormatterContext<T>) -> String;
+
+/// The percentage formatting function along with its precision
+pub(super) type PercentageFormatter<T> = (fn(&T, usize) -> String, usize);
+
+pub(super) fn percentage_text<T: ToPrimitive>(value: &T, precision: usize) -> String {
+    let value = value.to_f64().unwrap_or(f64::NAN) * 100.0;
+    format!("{:.*}%", precision, value)
+}
+
+/// The common offset and the power of ten factored out of the labels of an axis,
+/// see [`MeshStyle::x_offset_text`]
+///
+/// [`MeshStyle::x_offset_text`]: crate::chart::MeshStyle::x_offset_text
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(super) struct AxisOffset {
+    offset: f64,
+    exponent: i32,
+    precision: usize,
+}
+
+impl AxisOffset {
+    /// Find the offset for the given tick values, `None` if the ticks are readable as they are
+    pub(super) fn find(values: &[f64]) -> Option<Self> {
+        if values.is_empty() || values.iter().any(|v| !v.is_finite()) {
+            return None;
+        }
+        let lo = values.iter().copied().fold(f64::INFINITY, f64::min);
+        let hi = values.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+        let (span, magnitude) = (hi - lo, lo.abs().max(hi.abs()));
+
+        /* Only subtract an offset when the ticks share many leading digits */
+        let mut offset = 0.0;
+        if span > 0.0 && magnitude / span >= 1e4 {
+            let unit = 10f64.powi(span.log10().floor() as i32 + 1);
+            offset = (lo / unit).floor() * unit;
+        }
+
+        let rest = values
+            .iter()
+            .map(|v| (v - offset).abs())
+            .fold(0.0, f64::max);
+        let mut exponent = 0;
+        if rest > 0.0 {
+            let e = rest.log10().floor() as i32;
+            if !(-3..5).contains(&e) {
+                exponent = e;
+            }
+        }
+        if offset == 0.0 && exponent == 0 {
+            return None;
+        }
+
+        /* Use the least number of the fraction digits that shows all the ticks exactly */
+        let scale = 10f64.powi(exponent);
+        let precision = (0..15)
+            .find(|&p| {
+                let factor = 10f64.powi(p as i32);
+                values.iter().all(|v| {
+                    let scaled = (v - offset) / scale * factor;
+                    (scaled - scaled.round()).abs() < 1e-6
+                })
+            })
+            .unwrap_or(15);
+
+        Some(Self {
+            offset,
+            exponent,
+            precision,
+        })
+    }
+
+    /// Format a tick value relative to the offset
+    pub(super) fn format(&self, value: f64) -> String {
+        let scaled = (value - self.offset) / 10f64.powi(self.exponent);
+        let text = format!("{:.*}", self.precision, scaled);
+        /* Avoid the negative zero label */
+        match text.strip_prefix('-') {
+            Some(rest) if rest.chars().all(|c| c == '0' || c == '.') => rest.to_string(),
+            _ => text,
+        }
+    }
+
+    /// The text shown once at the corner of the plotting area, for example `×1e-6 +1e9`
+    pub(super) fn text(&self) -> String {
+        let mut parts = vec![];
+        if self.exponent != 0 {
+            parts.push(format!("×1e{}", self.exponent));
+        }
+        if self.offset != 0.0 {
+            let sign = if self.offset < 0.0 { '-' } else { '+' };
+            let value = self.offset.abs();
+            let text = if value >= 1e5 {
+                Formatter::scientific(15)(&value)
+            } else {
+                format!("{}", value)
+            };
+            parts.push(format!("{}{}", sign, text));
+        }
+        parts.join(" ")
+    }
+}
+
+/// Find the offset of an axis for the given key points, or the automatic key points
+pub(super) fn find_axis_offset<R: Ranged>(
+    spec: &R,
+    key_points: Option<&[R::ValueType]>,
+    n_labels: usize,
+    to_f64: fn(&R::ValueType) -> Option<f64>,
+) -> Option<AxisOffset> {
+    let auto;
+    let key_points = match key_points {
+        Some(key_points) => key_points,
+        None => {
+            auto = spec.key_points(BoldPoints(n_labels));
+            &auto[..]
+        }
+    };
+    let values: Vec<_> = key_points.iter().filter_map(to_f64).collect();
+    AxisOffset::find(&values)
+}
+
+/// Remove the trailing zeros of the fractional part of the formatted number
+fn trim_fraction(text: &str) -> &str {
+    if text.contains('.') {
+        text.trim_end_matches('0').trim_end_matches('.')
+    } else {
+        text
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_formatter() {
+        let sci = Formatter::scientific(2);
+        assert_eq!(sci(&1_500_000), "1.5e6");
+        assert_eq!(sci(&0), "0");
+        assert_eq!(Formatter::scientific(2)(&-0.00123), "-1.23e-3");
+
+        let si = Formatter::si_prefix();
+        assert_eq!(si(&1_500_000.0), "1.5M");
+        assert_eq!(si(&0.002), "2m");
+        assert_eq!(Formatter::si_prefix()(&-250), "-250");
+        assert_eq!(si(&999_999.0), "1M");
+        assert_eq!(si(&0.0), "0");
+
+        assert_eq!(Formatter::percentage(0)(&0.25), "25%");
+        assert_eq!(Formatter::percentage(1)(&0.125), "12.5%");
+        assert_eq!(Formatter::percentage(0)(&2u32), "200%");
+    }
+
+    #[test]
+    fn test_label_locale() {
+        let de = LabelLocale::german();
+        assert_eq!(de.localize("1234567.25"), "1.234.567,25");
+        assert_eq!(de.localize("-999"), "-999");
+        assert_eq!(de.localize("12.5%"), "12,5%");
+        assert_eq!(de.localize("2021-01-05"), "2021-01-05");
+        assert_eq!(de.localize("1.5e6"), "1.5e6");
+        assert_eq!(de.localize("abc"), "abc");
+        assert_eq!(LabelLocale::new(',', None).localize("1234.5"), "1234,5");
+        assert_eq!(Formatter::localized(de, 2)(&1234), "1.234,00");
+
+        use crate::prelude::*;
+
+        let (drawing_area, labels) = create_text_recording_area(200, 200);
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .x_label_area_size(20)
+            .build_cartesian_2d(0.0..2000.0, 0..10)
+            .expect("Create chart");
+
+        chart
+            .configure_mesh()
+            .x_ticks(vec![1500.5])
+            .label_locale(LabelLocale::german())
+            .draw()
+            .expect("Draw mesh");
+
+        assert!(labels.texts().contains(&"1.500,5".to_string()));
+    }
+
+    #[test]
+    fn test_axis_offset() {
+        let ticks: Vec<f64> = (1..10).map(|x| 1_000_000_000.0 + f64::from(x)).collect();
+        let offset = AxisOffset::find(&ticks).unwrap();
+        assert_eq!(offset.text(), "+1e9");
+        assert_eq!(offset.format(ticks[0]), "1");
+        assert_eq!(offset.format(ticks[8]), "9");
+
+        let small = AxisOffset::find(&[0.0, 2.5e-7, 5e-7]).unwrap();
+        assert_eq!(small.text(), "×1e-7");
+        assert_eq!(small.format(2.5e-7), "2.5");
+
+        let large = AxisOffset::find(&[1.0e6, 2.0e6]).unwrap();
+        assert_eq!(large.text(), "×1e6");
+        assert_eq!(large.format(2.0e6), "2");
+
+        assert_eq!(AxisOffset::find(&[0.0, 50.0, 100.0]), None);
+        assert_eq!(AxisOffset::find(&[]), None);
+
+        use crate::prelude::*;
+
+        let (drawing_area, labels) = create_text_recording_area(200, 200);
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .x_label_area_size(20)
+            .y_label_area_size(20)
+            .build_cartesian_2d(1_000_000_001i64..1_000_000_009, 0.0..1.0)
+            .expect("Create chart");
+
+        chart
+            .configure_mesh()
+            .x_offset_text()
+            .y_offset_text()
+            .draw()
+            .expect("Draw mesh");
+
+        let labels = labels.texts();
+        assert!(labels.contains(&"+1e9".to_string()));
+        assert!(labels.contains(&"5".to_string()));
+        assert!(labels.contains(&"0.5".to_string()));
+        assert!(!labels.iter().any(|text| text.starts_with("1000000")));
+    }
+
+    #[test]
+    fn test_label_as_percentage() {
+        use crate::prelude::*;
+
+        let (drawing_area, labels) = create_text_recording_area(200, 200);
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .x_label_area_size(20)
+            .y_label_area_size(20)
+            .build_cartesian_2d(0.0..1.0, 0f32..0.5)
+            .expect("Create chart");
+
+        chart
+            .configure_mesh()
+            .x_ticks(vec![0.0, 0.5, 1.0])
+            .y_ticks(vec![0.25])
+            .x_label_as_percentage(0)
+            .y_label_as_percentage(1)
+            .draw()
+            .expect("Draw mesh");
+
+        let labels = labels.texts();
+        for text in &["0%", "50%", "100%", "25.0%"] {
+            assert!(labels.contains(&text.to_string()));
+        }
+    }
+
+    #[test]
+    fn test_formatter_context() {
+        let ticks = [0, 5, 10];
+        let first = FormatterContext::new(&ticks, 0);
+        assert_eq!(first.index(), 0);
+        assert_eq!(first.count(), 3);
+        assert_eq!(first.previous(), None);
+        assert_eq!(first.next(), Some(&5));
+        assert_eq!(first.step(), Some(5));
+
+        let last = FormatterContext::new(&ticks, 2);
+        assert_eq!(last.previous(), Some(&5));
+        assert_eq!(last.next(), None);
+        assert_eq!(last.step(), Some(5));
+
+        assert_eq!(FormatterContext::new(&ticks[..1], 0).step::<i32>(), None);
+    }
+
+    #[test]
+    fn test_label_formatter_with_context() {
+        use crate::prelude::*;
+
+        let (drawing_area, labels) = create_text_recording_area(200, 200);
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .x_label_area_size(20)
+            .build_cartesian_2d(0..10, 0..10)
+            .expect("Create chart");
+
+        let fmt = |x: &i32, ctx: &FormatterContext<i32>| {
+            format!(
+                "{}:{}/{}+{}",
+                x,
+                ctx.index(),
+                ctx.count(),
+                ctx.step().unwrap_or(0)
+            )
+        };
+        chart
+            .configure_mesh()
+            .x_ticks(vec![2, 4, 8])
+            .x_label_formatter_with_context(&fmt)
+            .draw()
+            .expect("Draw mesh");
+
+        let labels = labels.texts();
+        assert!(labels.contains(&"2:0/3+2".to_string()));
+        assert!(labels.contains(&"4:1/3+2".to_string()));
+        assert!(labels.contains(&"8:2/3+4".to_string()));
+    }
+}
@@ -0,0 +1,359 @@
+use std::f64::consts::PI;
+
+use crate::chart::ChartContext;
+use crate::coord::polar::PolarCoord;
+use crate::coord::ranged1d::{BoldPoints, LightPoints, Ranged, ValueFormatter};
+use crate::drawing::DrawingAreaErrorKind;
+use crate::style::{
+    AsRelative, Color, FontDesc, FontFamily, FontStyle, IntoTextStyle, RGBColor, ShapeStyle,
+    SizeDesc, TextStyle,
+};
+
+use plotters_backend::DrawingBackend;
+
+/// The style used to describe the mesh of a polar coordinate system.
+///
+/// The mesh is composed by the concentric circles of the radial axis and the rays of the angular
+/// axis. The angle labels are placed along the perimeter, and the radius labels are placed along
+/// the ray at the start of the angular axis.
+///
+/// ```rust
+/// use plotters::prelude::*;
+///
+/// let drawing_area = SVGBackend::new("polar_mesh.svg", (300, 300)).into_drawing_area();
+/// let mut chart = ChartBuilder::on(&drawing_area)
+///     .margin(10)
+///     .set_all_label_area_size(20)
+///     .build_polar(0.0..360.0, 0.0..1.0)
+///     .unwrap();
+///
+/// chart
+///     .configure_mesh()
+///     .angle_labels(8)
+///     .radius_labels(4)
+///     .draw()
+///     .unwrap();
+/// ```
+pub struct PolarMeshStyle<'a, 'b, A: Ranged, R: Ranged, DB: DrawingBackend> {
+    pub(super) parent_size: (u32, u32),
+    pub(super) target: Option<&'b mut ChartContext<'a, DB, PolarCoord<A, R>>>,
+    pub(super) draw_angle_mesh: bool,
+    pub(super) draw_radius_mesh: bool,
+    pub(super) draw_angle_labels: bool,
+    pub(super) draw_radius_labels: bool,
+    pub(super) n_angle_labels: usize,
+    pub(super) n_radius_labels: usize,
+    pub(super) angle_light_lines_limit: usize,
+    pub(super) radius_light_lines_limit: usize,
+    pub(super) bold_line_style: Option<ShapeStyle>,
+    pub(super) light_line_style: Option<ShapeStyle>,
+    pub(super) axis_style: Option<ShapeStyle>,
+    pub(super) label_style: Option<TextStyle<'b>>,
+    pub(super) label_padding: i32,
+    pub(super) format_angle: Option<&'b dyn Fn(&A::ValueType) -> String>,
+    pub(super) format_radius: Option<&'b dyn Fn(&R::ValueType) -> String>,
+}
+
+impl<'a, 'b, A, R, DB> PolarMeshStyle<'a, 'b, A, R, DB>
+where
+    A: Ranged,
+    R: Ranged,
+    DB: DrawingBackend,
+{
+    pub(crate) fn new(chart: &'b mut ChartContext<'a, DB, PolarCoord<A, R>>) -> Self {
+        let base_tick_size = (5u32).percent().max(5).in_pixels(chart.plotting_area());
+
+        Self {
+            parent_size: chart.drawing_area.dim_in_pixel(),
+            target: Some(chart),
+            draw_angle_mesh: true,
+            draw_radius_mesh: true,
+            draw_angle_labels: true,
+            draw_radius_labels: true,
+            n_angle_labels: 8,
+            n_radius_labels: 5,
+            angle_light_lines_limit: 3,
+            radius_light_lines_limit: 4,
+            bold_line_style: None,
+            light_line_style: None,
+            axis_style: None,
+            label_style: None,
+            label_padding: base_tick_size,
+            format_angle: None,
+            format_radius: None,
+        }
+    }
+
+    /// Set how many labels on the angular axis at most
+    /// - `value`: The maximum desired number of the angle labels
+    pub fn angle_labels(&mut self, value: usize) -> &mut Self {
+        self.n_angle_labels = value;
+        self
+    }
+
+    /// Set how many labels on the radial axis at most
+    /// - `value`: The maximum desired number of the radius labels
+    pub fn radius_labels(&mut self, value: usize) -> &mut Self {
+        self.n_radius_labels = value;
+        self
+    }
+
+    /// Set the maximum number of the fine grid lines between two rays
+    /// - `value`: The maximum number of the fine grid lines
+    pub fn angle_max_light_lines(&mut self, value: usize) -> &mut Self {
+        self.angle_light_lines_limit = value;
+        self
+    }
+
+    /// Set the maximum number of the fine grid circles between two circles
+    /// - `value`: The maximum number of the fine grid circles
+    pub fn radius_max_light_lines(&mut self, value: usize) -> &mut Self {
+        self.radius_light_lines_limit = value;
+        self
+    }
+
+    /// Set the maximum number of the fine grid lines for both axes
+    /// - `value`: The maximum number of the fine grid lines
+    pub fn max_light_lines(&mut self, value: usize) -> &mut Self {
+        self.angle_light_lines_limit = value;
+        self.radius_light_lines_limit = value;
+        self
+    }
+
+    /// Disable the rays of the angular axis
+    pub fn disable_angle_mesh(&mut self) -> &mut Self {
+        self.draw_angle_mesh = false;
+        self
+    }
+
+    /// Disable the circles of the radial axis
+    pub fn disable_radius_mesh(&mut self) -> &mut Self {
+        self.draw_radius_mesh = false;
+        self
+    }
+
+    /// Disable all the grid lines
+    pub fn disable_mesh(&mut self) -> &mut Self {
+        self.disable_angle_mesh().disable_radius_mesh()
+    }
+
+    /// Hide the labels along the perimeter
+    pub fn hide_angle_labels(&mut self) -> &mut Self {
+        self.draw_angle_labels = false;
+        self
+    }
+
+    /// Hide the labels along the start ray
+    pub fn hide_radius_labels(&mut self) -> &mut Self {
+        self.draw_radius_labels = false;
+        self
+    }
+
+    /// Set the style for the coarse grid lines
+    /// - `style`: The coarse grid style
+    pub fn bold_line_style<T: Into<ShapeStyle>>(&mut self, style: T) -> &mut Self {
+        self.bold_line_style = Some(style.into());
+        self
+    }
+
+    /// Set the style for the fine grid lines
+    /// - `style`: The fine grid style
+    pub fn light_line_style<T: Into<ShapeStyle>>(&mut self, style: T) -> &mut Self {
+        self.light_line_style = Some(style.into());
+        self
+    }
+
+    /// Set the style of the outer circle
+    /// - `style`: The style of the outer circle
+    pub fn axis_style<T: Into<ShapeStyle>>(&mut self, style: T) -> &mut Self {
+        self.axis_style = Some(style.into());
+        self
+    }
+
+    /// Set the style of the labels
+    /// - `style`: The text style of the labels
+    pub fn label_style<T: IntoTextStyle<'b>>(&mut self, style: T) -> &mut Self {
+        self.label_style = Some(style.into_text_style(&self.parent_size));
+        self
+    }
+
+    /// Set the distance between the angle labels and the outer circle
+    /// - `value`: The distance
+    pub fn label_padding<S: SizeDesc>(&mut self, value: S) -> &mut Self {
+        self.label_padding = value.in_pixels(&self.parent_size);
+        self
+    }
+
+    /// Set the formatter function for the angle labels
+    /// - `fmt`: The formatter function
+    pub fn angle_label_formatter(&mut self, fmt: &'b dyn Fn(&A::ValueType) -> String) -> &mut Self {
+        self.format_angle = Some(fmt);
+        self
+    }
+
+    /// Set the formatter function for the radius labels
+    /// - `fmt`: The formatter function
+    pub fn radius_label_formatter(
+        &mut self,
+        fmt: &'b dyn Fn(&R::ValueType) -> String,
+    ) -> &mut Self {
+        self.format_radius = Some(fmt);
+        self
+    }
+
+    /// Draw the configured mesh on the target plot
+    pub fn draw(&mut self) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>>
+    where
+        A: ValueFormatter<<A as Ranged>::ValueType>,
+        R: ValueFormatter<<R as Ranged>::ValueType>,
+    {
+        let target = self.target.take().unwrap();
+
+        let default_mesh_color_1 = RGBColor(0, 0, 0).mix(0.2);
+        let default_mesh_color_2 = RGBColor(0, 0, 0).mix(0.1);
+        let default_axis_color = RGBColor(0, 0, 0);
+        let default_label_font = FontDesc::new(
+            FontFamily::SansSerif,
+            f64::from((12i32).percent().max(12).in_pixels(&self.parent_size)),
+            FontStyle::Normal,
+        );
+
+        let bold_style = self
+            .bold_line_style
+            .unwrap_or_else(|| (&default_mesh_color_1).into());
+        let light_style = self
+            .light_line_style
+            .unwrap_or_else(|| (&default_mesh_color_2).into());
+        let axis_style = self
+            .axis_style
+            .unwrap_or_else(|| (&default_axis_color).into());
+        let label_style = self
+            .label_style
+            .clone()
+            .unwrap_or_else(|| default_label_font.into());
+
+        let coord = target.as_coord_spec();
+        let (angle_spec, radius_spec) = (coord.angle_spec(), coord.radius_spec());
+
+        /* The end of the angular axis is the same ray as the start, so it's dropped */
+        let map_angles = |points: Vec<A::ValueType>| -> Vec<(f64, A::ValueType)> {
+            points
+                .into_iter()
+                .map(|v| (coord.map_angle(&v), v))
+                .filter(|(angle, _)| *angle < 2.0 * PI - 1e-6)
+                .collect()
+        };
+        let map_radii = |points: Vec<R::ValueType>| -> Vec<(i32, R::ValueType)> {
+            points
+                .into_iter()
+                .map(|v| (coord.map_radius(&v), v))
+                .collect()
+        };
+
+        let bold_angles = map_angles(angle_spec.key_points(BoldPoints(self.n_angle_labels)));
+        let bold_radii = map_radii(radius_spec.key_points(BoldPoints(self.n_radius_labels)));
+
+        let angle_labels: Vec<_> = if self.draw_angle_labels {
+            bold_angles
+                .iter()
+                .map(|(angle, v)| match self.format_angle {
+                    Some(fmt) => (*angle, fmt(v)),
+                    None => (*angle, angle_spec.format_ext(v)),
+                })
+                .collect()
+        } else {
+            vec![]
+        };
+        let radius_labels: Vec<_> = if self.draw_radius_labels {
+            bold_radii
+                .iter()
+                .map(|(r, v)| match self.format_radius {
+                    Some(fmt) => (*r, fmt(v)),
+                    None => (*r, radius_spec.format_ext(v)),
+                })
+                .collect()
+        } else {
+            vec![]
+        };
+
+        /* Only the positions are needed to draw the grid lines */
+        let angle_grid = |points: Vec<(f64, A::ValueType)>| -> Vec<f64> {
+            match self.draw_angle_mesh {
+                true => points.into_iter().map(|(angle, _)| angle).collect(),
+                false => vec![],
+            }
+        };
+        let radius_grid = |points: Vec<(i32, R::ValueType)>| -> Vec<i32> {
+            match self.draw_radius_mesh {
+                true => points.into_iter().map(|(r, _)| r).collect(),
+                false => vec![],
+            }
+        };
+
+        let light_angles = map_angles(angle_spec.key_points(LightPoints::new(
+            self.n_angle_labels,
+            self.n_angle_labels * self.angle_light_lines_limit,
+        )));
+        let light_radii = map_radii(radius_spec.key_points(LightPoints::new(
+            self.n_radius_labels,
+            self.n_radius_labels * self.radius_light_lines_limit,
+        )));
+
+        target.draw_polar_mesh_lines(
+            (&angle_grid(light_angles), &radius_grid(light_radii)),
+            &light_style,
+        )?;
+        target.draw_polar_mesh_lines(
+            (&angle_grid(bold_angles), &radius_grid(bold_radii)),
+            &bold_style,
+        )?;
+        target.draw_polar_axes(
+            (&angle_labels, &radius_labels),
+            &axis_style,
+            &label_style,
+            self.label_padding,
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_polar_mesh() {
+        use std::cell::RefCell;
+        use std::rc::Rc;
+
+        let labels = Rc::new(RefCell::new(vec![]));
+        let labels_ref = labels.clone();
+        let drawing_area = create_mocked_drawing_area(300, 300, move |m| {
+            m.check_draw_text(move |_, _, _, _, text| {
+                labels_ref.borrow_mut().push(text.to_string());
+            });
+            m.drop_check(|b| {
+                assert!(b.num_draw_circle_call > 0);
+                assert!(b.num_draw_path_call > 0);
+            });
+        });
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .set_all_label_area_size(20)
+            .build_polar(0.0..360.0, 0.0..1.0)
+            .expect("Create chart");
+
+        let fmt = |v: &f64| format!("{:.0}deg", v);
+        chart
+            .configure_mesh()
+            .angle_labels(4)
+            .radius_labels(2)
+            .angle_label_formatter(&fmt)
+            .draw()
+            .expect("Draw mesh");
+
+        let labels = labels.borrow();
+        for text in &["0deg", "100deg", "200deg", "300deg", "1.0"] {
+            assert!(labels.iter().any(|l| l == text));
+        }
+    }
+}
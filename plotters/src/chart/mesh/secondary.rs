@@ -0,0 +1,360 @@
+use super::{
+    AxisDescOrientation, AxisDescPlacement, EdgeLabels, LabelFormatterWithContext, LabelLocale,
+    LabelOverlap, MeshLayout, MeshStyle,
+};
+use crate::chart::{ChartContext, LabelAreaPosition};
+use crate::coord::cartesian::Cartesian2d;
+use crate::coord::ranged1d::{Ranged, ValueFormatter};
+use crate::drawing::DrawingAreaErrorKind;
+use crate::style::{IntoTextStyle, ShapeStyle, SizeDesc};
+
+use num_traits::{ToPrimitive, Zero};
+use plotters_backend::DrawingBackend;
+
+/// The style used to describe the mesh and axis for a secondary coordinate system.
+pub struct SecondaryMeshStyle<'a, 'b, X: Ranged, Y: Ranged, DB: DrawingBackend> {
+    style: MeshStyle<'a, 'b, X, Y, DB>,
+}
+
+impl<'a, 'b, XT, YT, X: Ranged<ValueType = XT>, Y: Ranged<ValueType = YT>, DB: DrawingBackend>
+    SecondaryMeshStyle<'a, 'b, X, Y, DB>
+where
+    X: ValueFormatter<XT>,
+    Y: ValueFormatter<YT>,
+{
+    pub(crate) fn new(target: &'b mut ChartContext<'a, DB, Cartesian2d<X, Y>>) -> Self {
+        let mut style = target.configure_mesh();
+        style.draw_x_mesh = false;
+        style.draw_y_mesh = false;
+        Self { style }
+    }
+
+    /// Set the style definition for the axis
+    /// - `style`: The style for the axis
+    pub fn axis_style<T: Into<ShapeStyle>>(&mut self, style: T) -> &mut Self {
+        self.style.axis_style(style);
+        self
+    }
+
+    /// Detach the secondary axes from the plotting area by the given distance
+    /// - `value`: The distance between the axes and the plotting area
+    pub fn axis_offset<S: SizeDesc>(&mut self, value: S) -> &mut Self {
+        self.style.axis_offset(value);
+        self
+    }
+
+    /// The offset of x labels. This is used when we want to place the label in the middle of
+    /// the grid. This is used to adjust label position for histograms, but since plotters 0.3, this
+    /// use case is deprecated, see [SegmentedCoord coord decorator](../coord/ranged1d/trait.IntoSegmentedCoord.html) for more details
+    /// - `value`: The offset in pixel
+    pub fn x_label_offset<S: SizeDesc>(&mut self, value: S) -> &mut Self {
+        self.style.x_label_offset(value);
+        self
+    }
+
+    /// The offset of y labels. This is used when we want to place the label in the middle of
+    /// the grid. This is used to adjust label position for histograms, but since plotters 0.3, this
+    /// use case is deprecated, see [SegmentedCoord coord decorator](../coord/ranged1d/trait.IntoSegmentedCoord.html) for more details
+    /// - `value`: The offset in pixel
+    pub fn y_label_offset<S: SizeDesc>(&mut self, value: S) -> &mut Self {
+        self.style.y_label_offset(value);
+        self
+    }
+
+    /// Draw the x labels inside the plotting area, at the given distance from the axis
+    /// - `value`: The distance between the axis and the labels
+    pub fn x_label_inset<S: SizeDesc>(&mut self, value: S) -> &mut Self {
+        self.style.x_label_inset(value);
+        self
+    }
+
+    /// Draw the y labels inside the plotting area, at the given distance from the axis
+    /// - `value`: The distance between the axis and the labels
+    pub fn y_label_inset<S: SizeDesc>(&mut self, value: S) -> &mut Self {
+        self.style.y_label_inset(value);
+        self
+    }
+
+    /// Set the distance between the x tick labels and the axis
+    /// - `value`: The padding size
+    pub fn x_label_padding<S: SizeDesc>(&mut self, value: S) -> &mut Self {
+        self.style.x_label_padding(value);
+        self
+    }
+
+    /// Set the distance between the y tick labels and the axis
+    /// - `value`: The padding size
+    pub fn y_label_padding<S: SizeDesc>(&mut self, value: S) -> &mut Self {
+        self.style.y_label_padding(value);
+        self
+    }
+
+    /// Set how many labels for the X axis at most
+    /// - `value`: The maximum desired number of labels in the X axis
+    pub fn x_labels(&mut self, value: usize) -> &mut Self {
+        self.style.x_labels(value);
+        self
+    }
+
+    /// Set how many label for the Y axis at most
+    /// - `value`: The maximum desired number of labels in the Y axis
+    pub fn y_labels(&mut self, value: usize) -> &mut Self {
+        self.style.y_labels(value);
+        self
+    }
+
+    /// Set the formatter function for the X label text
+    /// - `fmt`: The formatter function
+    pub fn x_label_formatter(&mut self, fmt: &'b dyn Fn(&X::ValueType) -> String) -> &mut Self {
+        self.style.x_label_formatter(fmt);
+        self
+    }
+
+    /// Set the formatter function for the Y label text
+    /// - `fmt`: The formatter function
+    pub fn y_label_formatter(&mut self, fmt: &'b dyn Fn(&Y::ValueType) -> String) -> &mut Self {
+        self.style.y_label_formatter(fmt);
+        self
+    }
+
+    /// Set the formatter function for the X label text, which also gets the context of the tick
+    /// - `fmt`: The formatter function
+    pub fn x_label_formatter_with_context(
+        &mut self,
+        fmt: LabelFormatterWithContext<'b, X::ValueType>,
+    ) -> &mut Self {
+        self.style.x_label_formatter_with_context(fmt);
+        self
+    }
+
+    /// Set the formatter function for the Y label text, which also gets the context of the tick
+    /// - `fmt`: The formatter function
+    pub fn y_label_formatter_with_context(
+        &mut self,
+        fmt: LabelFormatterWithContext<'b, Y::ValueType>,
+    ) -> &mut Self {
+        self.style.y_label_formatter_with_context(fmt);
+        self
+    }
+
+    /// Place the X labels and tick marks exactly on the given values
+    /// - `ticks`: The values where the ticks should be placed
+    pub fn x_ticks<I: IntoIterator<Item = X::ValueType>>(&mut self, ticks: I) -> &mut Self {
+        self.style.x_ticks(ticks);
+        self
+    }
+
+    /// Place the Y labels and tick marks exactly on the given values
+    /// - `ticks`: The values where the ticks should be placed
+    pub fn y_ticks<I: IntoIterator<Item = Y::ValueType>>(&mut self, ticks: I) -> &mut Self {
+        self.style.y_ticks(ticks);
+        self
+    }
+
+    /// Set how the labels on both axes are handled when they overlap each other
+    /// - `overlap`: What to do with the overlapping labels
+    pub fn label_overlap(&mut self, overlap: LabelOverlap) -> &mut Self {
+        self.style.label_overlap(overlap);
+        self
+    }
+
+    /// Set what to do with the labels on both axes which go beyond the edges of the chart
+    /// - `policy`: What to do with the labels at the edges
+    pub fn edge_labels(&mut self, policy: EdgeLabels) -> &mut Self {
+        self.style.edge_labels(policy);
+        self
+    }
+
+    /// Hide the text of the secondary X axis labels, but keep the tick marks
+    pub fn hide_x_labels(&mut self) -> &mut Self {
+        self.style.hide_x_labels();
+        self
+    }
+
+    /// Hide the text of the secondary Y axis labels, but keep the tick marks
+    pub fn hide_y_labels(&mut self) -> &mut Self {
+        self.style.hide_y_labels();
+        self
+    }
+
+    /// Hide the text of the secondary labels in one label area, but keep the tick marks
+    /// - `pos`: The label area whose labels are hidden
+    pub fn hide_labels(&mut self, pos: LabelAreaPosition) -> &mut Self {
+        self.style.hide_labels(pos);
+        self
+    }
+
+    /// Set if the axes, tick marks and labels should be kept on top of the series
+    /// - `value`: If the axes should be on top of the series
+    pub fn on_top(&mut self, value: bool) -> &mut Self
+    where
+        DB: 'a,
+        X: Clone + 'a,
+        Y: Clone + 'a,
+    {
+        self.style.on_top(value);
+        self
+    }
+
+    /// Label the X axis relative to a common offset, which is shown once at the plot corner
+    pub fn x_offset_text(&mut self) -> &mut Self
+    where
+        XT: ToPrimitive,
+    {
+        self.style.x_offset_text();
+        self
+    }
+
+    /// Label the Y axis relative to a common offset, which is shown once at the plot corner
+    pub fn y_offset_text(&mut self) -> &mut Self
+    where
+        YT: ToPrimitive,
+    {
+        self.style.y_offset_text();
+        self
+    }
+
+    /// Use the number formatting convention of the given locale for the labels
+    /// - `locale`: The locale to use
+    pub fn label_locale(&mut self, locale: LabelLocale) -> &mut Self {
+        self.style.label_locale(locale);
+        self
+    }
+
+    /// Show the X labels as percentages
+    /// - `precision`: The number of digits after the decimal point
+    pub fn x_label_as_percentage(&mut self, precision: usize) -> &mut Self
+    where
+        XT: ToPrimitive,
+    {
+        self.style.x_label_as_percentage(precision);
+        self
+    }
+
+    /// Show the Y labels as percentages
+    /// - `precision`: The number of digits after the decimal point
+    pub fn y_label_as_percentage(&mut self, precision: usize) -> &mut Self
+    where
+        YT: ToPrimitive,
+    {
+        self.style.y_label_as_percentage(precision);
+        self
+    }
+
+    /// Emphasize the grid lines at X = 0 and Y = 0 with the given style
+    /// - `style`: The style for the zero lines
+    pub fn zero_line_style<T: Into<ShapeStyle>>(&mut self, style: T) -> &mut Self
+    where
+        XT: Zero + PartialOrd,
+        YT: Zero + PartialOrd,
+    {
+        self.style.zero_line_style(style);
+        self
+    }
+
+    /// Set the rotation of the X label text
+    /// - `angle`: The clockwise rotation in degrees
+    pub fn x_label_rotation(&mut self, angle: f32) -> &mut Self {
+        self.style.x_label_rotation(angle);
+        self
+    }
+
+    /// Set the rotation of the Y label text
+    /// - `angle`: The clockwise rotation in degrees
+    pub fn y_label_rotation(&mut self, angle: f32) -> &mut Self {
+        self.style.y_label_rotation(angle);
+        self
+    }
+
+    /// Draw minor tick marks between the major tick marks on both axes
+    /// - `value`: Maximum desired divisions between two consecutive labels, 0 disables the minor ticks
+    pub fn minor_ticks(&mut self, value: usize) -> &mut Self {
+        self.style.minor_ticks(value);
+        self
+    }
+
+    /// Set the style of the minor tick marks
+    /// - `style`: The style for the minor tick marks
+    pub fn minor_tick_style<T: Into<ShapeStyle>>(&mut self, style: T) -> &mut Self {
+        self.style.minor_tick_style(style);
+        self
+    }
+
+    /// Set the axis description's style. If not given, use label style instead.
+    /// - `style`: The text style that would be applied to descriptions
+    pub fn axis_desc_style<T: IntoTextStyle<'b>>(&mut self, style: T) -> &mut Self {
+        self.style
+            .axis_desc_style(style.into_text_style(&self.style.parent_size));
+        self
+    }
+
+    /// Set the X axis's description
+    /// - `desc`: The description of the X axis
+    pub fn x_desc<T: Into<String>>(&mut self, desc: T) -> &mut Self {
+        self.style.x_desc(desc);
+        self
+    }
+
+    /// Set the Y axis's description
+    /// - `desc`: The description of the Y axis
+    pub fn y_desc<T: Into<String>>(&mut self, desc: T) -> &mut Self {
+        self.style.y_desc(desc);
+        self
+    }
+
+    /// Set the orientation of the secondary Y axis's description
+    /// - `orientation`: The orientation of the description
+    pub fn y_desc_orientation(&mut self, orientation: AxisDescOrientation) -> &mut Self {
+        self.style.y_desc_orientation(orientation);
+        self
+    }
+
+    /// Set where the secondary X axis's description is placed along the axis
+    /// - `placement`: The placement of the description
+    pub fn x_desc_placement(&mut self, placement: AxisDescPlacement) -> &mut Self {
+        self.style.x_desc_placement(placement);
+        self
+    }
+
+    /// Set where the secondary Y axis's description is placed along the axis
+    /// - `placement`: The placement of the description
+    pub fn y_desc_placement(&mut self, placement: AxisDescPlacement) -> &mut Self {
+        self.style.y_desc_placement(placement);
+        self
+    }
+
+    /// Draw the axes for the secondary coordinate system
+    pub fn draw(&mut self) -> Result<MeshLayout, DrawingAreaErrorKind<DB::ErrorType>> {
+        self.style.draw()
+    }
+
+    /// Set the label style for the secondary axis
+    pub fn label_style<T: IntoTextStyle<'b>>(&mut self, style: T) -> &mut Self {
+        self.style.label_style(style);
+        self
+    }
+
+    /// Set all the tick marks to the same size
+    /// `value`: The new size
+    pub fn set_all_tick_mark_size<S: SizeDesc>(&mut self, value: S) -> &mut Self {
+        let size = value.in_pixels(&self.style.parent_size);
+        self.style.x_tick_size = [size, size];
+        self.style.y_tick_size = [size, size];
+        self
+    }
+    /// Sets the tick mark size for a given label area position.
+    /// `value`: The new size
+    pub fn set_tick_mark_size<S: SizeDesc>(
+        &mut self,
+        pos: LabelAreaPosition,
+        value: S,
+    ) -> &mut Self {
+        *match pos {
+            LabelAreaPosition::Top => &mut self.style.x_tick_size[0],
+            LabelAreaPosition::Bottom => &mut self.style.x_tick_size[1],
+            LabelAreaPosition::Left => &mut self.style.y_tick_size[0],
+            LabelAreaPosition::Right => &mut self.style.y_tick_size[1],
+        } = value.in_pixels(&self.style.parent_size);
+        self
+    }
+}
@@ -0,0 +1,291 @@
+use crate::chart::ChartContext;
+use crate::coord::ranged1d::{BoldPoints, LightPoints, Ranged, ValueFormatter};
+use crate::coord::ternary::TernaryCoord;
+use crate::coord::types::RangedCoordf64;
+use crate::drawing::DrawingAreaErrorKind;
+use crate::style::{
+    AsRelative, Color, FontDesc, FontFamily, FontStyle, IntoTextStyle, RGBColor, ShapeStyle,
+    SizeDesc, TextStyle,
+};
+
+use plotters_backend::DrawingBackend;
+
+/// The style used to describe the mesh of a ternary coordinate system.
+///
+/// Each of the three components gets the grid lines parallel to the edge opposite to its vertex,
+/// and its labels along one of the other edges, so that the labels go around the triangle
+/// clockwise. The grid values are the fractions between 0 and 1.
+///
+/// ```rust
+/// use plotters::prelude::*;
+///
+/// let drawing_area = SVGBackend::new("ternary_mesh.svg", (300, 300)).into_drawing_area();
+/// let mut chart = ChartBuilder::on(&drawing_area)
+///     .margin(10)
+///     .set_all_label_area_size(30)
+///     .build_ternary()
+///     .unwrap();
+///
+/// chart
+///     .configure_mesh()
+///     .a_desc("Sand")
+///     .b_desc("Silt")
+///     .c_desc("Clay")
+///     .draw()
+///     .unwrap();
+/// ```
+pub struct TernaryMeshStyle<'a, 'b, DB: DrawingBackend> {
+    pub(super) parent_size: (u32, u32),
+    pub(super) target: Option<&'b mut ChartContext<'a, DB, TernaryCoord>>,
+    pub(super) draw_mesh: bool,
+    pub(super) draw_labels: bool,
+    pub(super) n_labels: usize,
+    pub(super) light_lines_limit: usize,
+    pub(super) bold_line_style: Option<ShapeStyle>,
+    pub(super) light_line_style: Option<ShapeStyle>,
+    pub(super) axis_style: Option<ShapeStyle>,
+    pub(super) label_style: Option<TextStyle<'b>>,
+    pub(super) axis_desc_style: Option<TextStyle<'b>>,
+    pub(super) label_padding: i32,
+    pub(super) format_label: Option<&'b dyn Fn(&f64) -> String>,
+    pub(super) descs: [Option<String>; 3],
+}
+
+impl<'a, 'b, DB: DrawingBackend> TernaryMeshStyle<'a, 'b, DB> {
+    pub(crate) fn new(chart: &'b mut ChartContext<'a, DB, TernaryCoord>) -> Self {
+        let base_tick_size = (5u32).percent().max(5).in_pixels(chart.plotting_area());
+
+        Self {
+            parent_size: chart.drawing_area.dim_in_pixel(),
+            target: Some(chart),
+            draw_mesh: true,
+            draw_labels: true,
+            n_labels: 6,
+            light_lines_limit: 2,
+            bold_line_style: None,
+            light_line_style: None,
+            axis_style: None,
+            label_style: None,
+            axis_desc_style: None,
+            label_padding: base_tick_size,
+            format_label: None,
+            descs: [None, None, None],
+        }
+    }
+
+    /// Set how many labels on each edge at most
+    /// - `value`: The maximum desired number of the labels
+    pub fn labels(&mut self, value: usize) -> &mut Self {
+        self.n_labels = value;
+        self
+    }
+
+    /// Set the maximum number of the fine grid lines between two coarse grid lines
+    /// - `value`: The maximum number of the fine grid lines
+    pub fn max_light_lines(&mut self, value: usize) -> &mut Self {
+        self.light_lines_limit = value;
+        self
+    }
+
+    /// Disable all the grid lines
+    pub fn disable_mesh(&mut self) -> &mut Self {
+        self.draw_mesh = false;
+        self
+    }
+
+    /// Hide the labels along the edges
+    pub fn hide_labels(&mut self) -> &mut Self {
+        self.draw_labels = false;
+        self
+    }
+
+    /// Set the style for the coarse grid lines
+    /// - `style`: The coarse grid style
+    pub fn bold_line_style<T: Into<ShapeStyle>>(&mut self, style: T) -> &mut Self {
+        self.bold_line_style = Some(style.into());
+        self
+    }
+
+    /// Set the style for the fine grid lines
+    /// - `style`: The fine grid style
+    pub fn light_line_style<T: Into<ShapeStyle>>(&mut self, style: T) -> &mut Self {
+        self.light_line_style = Some(style.into());
+        self
+    }
+
+    /// Set the style of the triangle
+    /// - `style`: The style of the triangle edges
+    pub fn axis_style<T: Into<ShapeStyle>>(&mut self, style: T) -> &mut Self {
+        self.axis_style = Some(style.into());
+        self
+    }
+
+    /// Set the style of the labels
+    /// - `style`: The text style of the labels
+    pub fn label_style<T: IntoTextStyle<'b>>(&mut self, style: T) -> &mut Self {
+        self.label_style = Some(style.into_text_style(&self.parent_size));
+        self
+    }
+
+    /// Set the style of the axis descriptions
+    /// - `style`: The text style of the axis descriptions
+    pub fn axis_desc_style<T: IntoTextStyle<'b>>(&mut self, style: T) -> &mut Self {
+        self.axis_desc_style = Some(style.into_text_style(&self.parent_size));
+        self
+    }
+
+    /// Set the distance between the labels and the edges
+    /// - `value`: The distance
+    pub fn label_padding<S: SizeDesc>(&mut self, value: S) -> &mut Self {
+        self.label_padding = value.in_pixels(&self.parent_size);
+        self
+    }
+
+    /// Set the formatter function for the labels
+    /// - `fmt`: The formatter function, which gets the fractions between 0 and 1
+    pub fn label_formatter(&mut self, fmt: &'b dyn Fn(&f64) -> String) -> &mut Self {
+        self.format_label = Some(fmt);
+        self
+    }
+
+    /// Set the description of the first component, which is drawn above the top vertex
+    /// - `desc`: The description
+    pub fn a_desc<T: Into<String>>(&mut self, desc: T) -> &mut Self {
+        self.descs[0] = Some(desc.into());
+        self
+    }
+
+    /// Set the description of the second component, which is drawn below the bottom left vertex
+    /// - `desc`: The description
+    pub fn b_desc<T: Into<String>>(&mut self, desc: T) -> &mut Self {
+        self.descs[1] = Some(desc.into());
+        self
+    }
+
+    /// Set the description of the third component, which is drawn below the bottom right vertex
+    /// - `desc`: The description
+    pub fn c_desc<T: Into<String>>(&mut self, desc: T) -> &mut Self {
+        self.descs[2] = Some(desc.into());
+        self
+    }
+
+    /// Draw the configured mesh on the target plot
+    pub fn draw(&mut self) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>> {
+        let target = self.target.take().unwrap();
+
+        let default_mesh_color_1 = RGBColor(0, 0, 0).mix(0.2);
+        let default_mesh_color_2 = RGBColor(0, 0, 0).mix(0.1);
+        let default_axis_color = RGBColor(0, 0, 0);
+        let default_label_font = FontDesc::new(
+            FontFamily::SansSerif,
+            f64::from((12i32).percent().max(12).in_pixels(&self.parent_size)),
+            FontStyle::Normal,
+        );
+
+        let bold_style = self
+            .bold_line_style
+            .unwrap_or_else(|| (&default_mesh_color_1).into());
+        let light_style = self
+            .light_line_style
+            .unwrap_or_else(|| (&default_mesh_color_2).into());
+        let axis_style = self
+            .axis_style
+            .unwrap_or_else(|| (&default_axis_color).into());
+        let label_style = self
+            .label_style
+            .clone()
+            .unwrap_or_else(|| default_label_font.into());
+        let axis_desc_style = self
+            .axis_desc_style
+            .clone()
+            .unwrap_or_else(|| label_style.clone());
+
+        let spec: RangedCoordf64 = (0.0..1.0).into();
+        let bold_values = spec.key_points(BoldPoints(self.n_labels));
+
+        let labels: Vec<_> = if self.draw_labels {
+            bold_values
+                .iter()
+                .map(|v| match self.format_label {
+                    Some(fmt) => (*v, fmt(v)),
+                    None => (*v, spec.format_ext(v)),
+                })
+                .collect()
+        } else {
+            vec![]
+        };
+
+        /* The grid lines at 0 and 1 fall on the edges and the vertices */
+        let grid = |values: Vec<f64>| -> Vec<f64> {
+            match self.draw_mesh {
+                true => values
+                    .into_iter()
+                    .filter(|v| *v > 1e-9 && *v < 1.0 - 1e-9)
+                    .collect(),
+                false => vec![],
+            }
+        };
+
+        let light_values = spec.key_points(LightPoints::new(
+            self.n_labels,
+            self.n_labels * self.light_lines_limit,
+        ));
+
+        target.draw_ternary_mesh_lines(&grid(light_values), &light_style)?;
+        target.draw_ternary_mesh_lines(&grid(bold_values), &bold_style)?;
+        target.draw_ternary_axes(
+            &labels,
+            [
+                self.descs[0].as_deref(),
+                self.descs[1].as_deref(),
+                self.descs[2].as_deref(),
+            ],
+            &axis_style,
+            (&label_style, &axis_desc_style),
+            self.label_padding,
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_ternary_mesh() {
+        use std::cell::RefCell;
+        use std::rc::Rc;
+
+        let labels = Rc::new(RefCell::new(vec![]));
+        let labels_ref = labels.clone();
+        let drawing_area = create_mocked_drawing_area(300, 300, move |m| {
+            m.check_draw_text(move |_, _, _, _, text| {
+                labels_ref.borrow_mut().push(text.to_string());
+            });
+            m.drop_check(|b| {
+                // The 4 coarse and the 9 fine grid lines of the three components, and the triangle
+                assert_eq!(b.num_draw_path_call, 40);
+            });
+        });
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .set_all_label_area_size(30)
+            .build_ternary()
+            .expect("Create chart");
+
+        chart
+            .configure_mesh()
+            .a_desc("Sand")
+            .b_desc("Silt")
+            .c_desc("Clay")
+            .draw()
+            .expect("Draw mesh");
+
+        let labels = labels.borrow();
+        for text in &["Sand", "Silt", "Clay"] {
+            assert_eq!(labels.iter().filter(|l| l == text).count(), 1);
+        }
+        assert_eq!(labels.iter().filter(|l| *l == "0.4").count(), 3);
+        assert_eq!(labels.len(), 3 + 3 * 6);
+    }
+}
@@ -0,0 +1,188 @@
+use super::MeshStyle;
+use crate::chart::{ChartContext, LabelAreaPosition};
+use crate::coord::cartesian::Cartesian2d;
+use crate::coord::ranged1d::{BoldPoints, Ranged, ValueFormatter};
+use crate::coord::transformed::Transformed;
+use crate::drawing::DrawingAreaErrorKind;
+use crate::style::{
+    AsRelative, Color, FontDesc, FontFamily, FontStyle, IntoTextStyle, RGBColor, ShapeStyle,
+    SizeDesc,
+};
+
+use plotters_backend::DrawingBackend;
+
+/// The style used to describe the mesh of a transformed 2D Cartesian chart.
+///
+/// The grid lines are transformed together with the plot, the X and Y axes are drawn along the
+/// transformed bottom and left edges of the plotting area, and the labels are placed next to the
+/// ends of the grid lines on the axes.
+///
+/// ```rust
+/// use plotters::prelude::*;
+///
+/// let drawing_area = SVGBackend::new("transformed_mesh.svg", (300, 300)).into_drawing_area();
+/// let mut chart = ChartBuilder::on(&drawing_area)
+///     .margin(40)
+///     .build_cartesian_2d(0.0..10.0, 0.0..10.0)
+///     .unwrap()
+///     .transformed(AffineTransform::shear(-0.3, 0.0).then(&AffineTransform::scale(0.8, 0.8)));
+///
+/// chart.configure_mesh().draw().unwrap();
+/// chart
+///     .draw_series(LineSeries::new((0..=10).map(|x| (x as f64, x as f64)), &RED))
+///     .unwrap();
+/// ```
+pub struct TransformedMeshStyle<'a, 'b, X: Ranged, Y: Ranged, DB: DrawingBackend> {
+    style: MeshStyle<'a, 'b, X, Y, DB>,
+    target: Option<&'b mut ChartContext<'a, DB, Transformed<Cartesian2d<X, Y>>>>,
+}
+
+impl<'a, 'b, X, Y, XT, YT, DB> TransformedMeshStyle<'a, 'b, X, Y, DB>
+where
+    X: Ranged<ValueType = XT> + ValueFormatter<XT>,
+    Y: Ranged<ValueType = YT> + ValueFormatter<YT>,
+    DB: DrawingBackend,
+{
+    pub(crate) fn new(chart: &'b mut ChartContext<'a, DB, Transformed<Cartesian2d<X, Y>>>) -> Self {
+        let base_tick_size = (5u32).percent().max(5).in_pixels(chart.plotting_area());
+        let tick_size = [base_tick_size, base_tick_size];
+
+        Self {
+            style: MeshStyle::detached(chart.drawing_area.dim_in_pixel(), tick_size, tick_size),
+            target: Some(chart),
+        }
+    }
+
+    /// Set how many labels for the X axis at most
+    /// - `value`: The maximum desired number of labels in the X axis
+    pub fn x_labels(&mut self, value: usize) -> &mut Self {
+        self.style.x_labels(value);
+        self
+    }
+
+    /// Set how many labels for the Y axis at most
+    /// - `value`: The maximum desired number of labels in the Y axis
+    pub fn y_labels(&mut self, value: usize) -> &mut Self {
+        self.style.y_labels(value);
+        self
+    }
+
+    /// Disable all the grid lines
+    pub fn disable_mesh(&mut self) -> &mut Self {
+        self.style.disable_mesh();
+        self
+    }
+
+    /// Hide the labels of both axes
+    pub fn hide_labels(&mut self) -> &mut Self {
+        self.style.hide_x_labels().hide_y_labels();
+        self
+    }
+
+    /// Set the style for the grid lines
+    /// - `style`: The grid line style
+    pub fn line_style<T: Into<ShapeStyle>>(&mut self, style: T) -> &mut Self {
+        self.style.bold_line_style(style);
+        self
+    }
+
+    /// Set the style of the axes
+    /// - `style`: The style of the axis lines
+    pub fn axis_style<T: Into<ShapeStyle>>(&mut self, style: T) -> &mut Self {
+        self.style.axis_style(style);
+        self
+    }
+
+    /// Set the style of the labels
+    /// - `style`: The text style of the labels
+    pub fn label_style<T: IntoTextStyle<'b>>(&mut self, style: T) -> &mut Self {
+        self.style.label_style(style);
+        self
+    }
+
+    /// Set the distance between the labels and the axes
+    /// - `value`: The distance
+    pub fn label_padding<S: SizeDesc>(&mut self, value: S) -> &mut Self {
+        let value = value.in_pixels(&self.style.parent_size);
+        self.style.x_label_padding(value).y_label_padding(value);
+        self
+    }
+
+    /// Set the formatter function for the X label text
+    /// - `fmt`: The formatter function
+    pub fn x_label_formatter(&mut self, fmt: &'b dyn Fn(&X::ValueType) -> String) -> &mut Self {
+        self.style.x_label_formatter(fmt);
+        self
+    }
+
+    /// Set the formatter function for the Y label text
+    /// - `fmt`: The formatter function
+    pub fn y_label_formatter(&mut self, fmt: &'b dyn Fn(&Y::ValueType) -> String) -> &mut Self {
+        self.style.y_label_formatter(fmt);
+        self
+    }
+
+    /// Draw the configured mesh on the target plot
+    pub fn draw(&mut self) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>> {
+        let target = self.target.take().unwrap();
+        let style = &self.style;
+
+        let default_mesh_color = RGBColor(0, 0, 0).mix(0.2);
+        let default_axis_color = RGBColor(0, 0, 0);
+        let default_label_font = FontDesc::new(
+            FontFamily::SansSerif,
+            f64::from((12i32).percent().max(12).in_pixels(&style.parent_size)),
+            FontStyle::Normal,
+        );
+
+        let line_style = style
+            .bold_line_style
+            .unwrap_or_else(|| (&default_mesh_color).into());
+        let axis_style = style
+            .axis_style
+            .unwrap_or_else(|| (&default_axis_color).into());
+        let x_label_style = style
+            .x_label_style
+            .clone()
+            .unwrap_or_else(|| default_label_font.clone().into());
+        let y_label_style = style
+            .y_label_style
+            .clone()
+            .unwrap_or_else(|| default_label_font.into());
+        let label_padding = (
+            style.x_label_padding.unwrap_or(style.x_tick_size[1]),
+            style.y_label_padding.unwrap_or(style.y_tick_size[0]),
+        );
+
+        let inner = target.as_coord_spec().inner();
+        let (x_spec, y_spec) = (inner.x_spec(), inner.y_spec());
+        let x_points = x_spec.key_points(BoldPoints(style.n_x_labels));
+        let y_points = y_spec.key_points(BoldPoints(style.n_y_labels));
+
+        let format_x = |value: &XT| match style.format_x {
+            Some(fmt) => fmt(value),
+            None => x_spec.format_ext(value),
+        };
+        let format_y = |value: &YT| match style.format_y {
+            Some(fmt) => fmt(value),
+            None => y_spec.format_ext(value),
+        };
+
+        target.draw_transformed_mesh(
+            (&x_points, &y_points),
+            (
+                Some(&line_style).filter(|_| style.draw_x_mesh),
+                Some(&line_style).filter(|_| style.draw_y_mesh),
+            ),
+            &axis_style,
+            (
+                Some(&x_label_style)
+                    .filter(|_| !style.hidden_labels[LabelAreaPosition::Bottom as usize]),
+                Some(&y_label_style)
+                    .filter(|_| !style.hidden_labels[LabelAreaPosition::Left as usize]),
+            ),
+            label_padding,
+            (&format_x, &format_y),
+        )
+    }
+}
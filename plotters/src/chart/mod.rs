@@ -23,7 +23,11 @@ mod state;
 pub use builder::{ChartBuilder, LabelAreaPosition};
 pub use context::ChartContext;
 pub use dual_coord::{DualCoordChartContext, DualCoordChartState};
-pub use mesh::{MeshStyle, SecondaryMeshStyle};
+pub use mesh::{
+    AxisDescOrientation, AxisDescPlacement, DrawnTick, EdgeLabels, Formatter, FormatterContext,
+    LabelFormatterWithContext, LabelLocale, LabelOverlap, MeshLayout, MeshStyle, PolarMeshStyle,
+    SecondaryMeshStyle, TernaryMeshStyle, TransformedMeshStyle,
+};
 pub use series::{SeriesAnno, SeriesLabelPosition, SeriesLabelStyle};
 pub use state::ChartState;
 
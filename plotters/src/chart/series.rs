@@ -1,7 +1,7 @@
 use super::ChartContext;
 use crate::coord::CoordTranslate;
 use crate::drawing::DrawingAreaErrorKind;
-use crate::element::{DynElement, EmptyElement, IntoDynElement, MultiLineText, Rectangle};
+use crate::element::{DynElement, EmptyElement, IntoDynElement, MultiLineText, Rectangle, Text};
 use crate::style::{IntoFont, IntoTextStyle, ShapeStyle, SizeDesc, TextStyle, TRANSPARENT};
 
 use plotters_backend::{BackendCoord, DrawingBackend, DrawingErrorKind};
@@ -14,6 +14,7 @@ type SeriesAnnoDrawFn<'a, DB> = dyn Fn(BackendCoord) -> DynElement<'a, DB, Backe
 pub struct SeriesAnno<'a, DB: DrawingBackend> {
     label: Option<String>,
     draw_func: Option<Box<SeriesAnnoDrawFn<'a, DB>>>,
+    legend_size: Option<(u32, u32)>,
 }
 
 impl<'a, DB: DrawingBackend> SeriesAnno<'a, DB> {
@@ -27,10 +28,15 @@ impl<'a, DB: DrawingBackend> SeriesAnno<'a, DB> {
         self.draw_func.as_ref().map(|x| x.as_ref())
     }
 
+    pub(crate) fn get_legend_size(&self) -> Option<(u32, u32)> {
+        self.legend_size
+    }
+
     pub(crate) fn new() -> Self {
         Self {
             label: None,
             draw_func: None,
+            legend_size: None,
         }
     }
 
@@ -65,6 +71,19 @@ impl<'a, DB: DrawingBackend> SeriesAnno<'a, DB> {
         self.draw_func = Some(Box::new(move |p| func(p).into_dyn()));
         self
     }
+
+    /**
+    Sets the size of the legend element in pixels, which makes the row of the label at least as
+    tall as the element, and the legend area at least as wide as it.
+
+    - `size`: The width and the height of the legend element
+
+    See [`ChartContext::configure_series_labels()`] for more information and examples.
+    */
+    pub fn legend_size(&mut self, size: (u32, u32)) -> &mut Self {
+        self.legend_size = Some(size);
+        self
+    }
 }
 
 /**
@@ -236,8 +255,7 @@ impl<'a, 'b, DB: DrawingBackend + 'a, CT: CoordTranslate> SeriesLabelStyle<'a, '
             temp.unwrap_or(default_style)
         };
 
-        let mut label_element = MultiLineText::<_, &str>::new((0, 0), &font);
-        let mut funcs = vec![];
+        let mut rows = vec![];
 
         for anno in self.target.series_anno.iter() {
             let label_text = anno.get_label();
@@ -247,28 +265,67 @@ impl<'a, 'b, DB: DrawingBackend + 'a, CT: CoordTranslate> SeriesLabelStyle<'a, '
                 continue;
             }
 
-            funcs.push(draw_func.unwrap_or(&|p: BackendCoord| EmptyElement::at(p).into_dyn()));
-            label_element.push_line(label_text);
+            rows.push((
+                label_text,
+                draw_func.unwrap_or(&|p: BackendCoord| EmptyElement::at(p).into_dyn()),
+                anno.get_legend_size(),
+            ));
         }
 
-        let (mut w, mut h) = label_element.estimate_dimension().map_err(|e| {
-            DrawingAreaErrorKind::BackendError(DrawingErrorKind::FontError(Box::new(e)))
-        })?;
-
         let margin = self.margin as i32;
+        let font_error =
+            |e| DrawingAreaErrorKind::BackendError(DrawingErrorKind::FontError(Box::new(e)));
+
+        let legend_area_size = rows
+            .iter()
+            .filter_map(|(_, _, size)| size.map(|(w, _)| w as i32 + margin))
+            .fold(self.legend_area_size as i32, i32::max);
+
+        // The labels are laid out as a multi-line text, unless some series sets the size of its
+        // legend element. Then each row is made as tall as its legend element, which is also
+        // kept apart from the label.
+        let mut label_element = None;
+        let (mut w, mut h, layout) = if rows.iter().all(|(_, _, size)| size.is_none()) {
+            let mut element = MultiLineText::<_, &str>::new((0, 0), &font);
+            for (label_text, _, _) in rows.iter() {
+                element.push_line(*label_text);
+            }
+            let (w, h) = element.estimate_dimension().map_err(font_error)?;
+            let layout: Vec<_> = element
+                .compute_line_layout()
+                .map_err(font_error)?
+                .into_iter()
+                .map(|((_, y0), (_, y1))| (y0, y1 - y0))
+                .collect();
+            label_element = Some(element);
+            (w, h, layout)
+        } else {
+            let font_size = font.font.get_size();
+            let line_height = font_size * 1.25;
+            let (mut w, mut h) = (0, 0);
+            let mut layout = vec![];
+            let mut top = 0.0;
+            for (label_text, _, size) in rows.iter() {
+                let (dx, dy) = font.font.box_size(label_text).map_err(font_error)?;
+                let row_height = size.map_or(line_height, |(_, legend_h)| {
+                    line_height.max(f64::from(legend_h) + line_height - font_size)
+                });
+                let y = (top + (row_height - line_height) / 2.0).round() as i32;
+                w = w.max(dx as i32);
+                h = h.max(y + dy as i32);
+                layout.push((y, dy as i32));
+                top += row_height;
+            }
+            (w, h, layout)
+        };
 
-        w += self.legend_area_size as i32 + margin * 2;
+        w += legend_area_size + margin * 2;
         h += margin * 2;
 
         let (area_w, area_h) = drawing_area.dim_in_pixel();
 
         let (label_x, label_y) = self.position.layout_label_area((w, h), (area_w, area_h));
 
-        label_element.relocate((
-            label_x + self.legend_area_size as i32 + margin,
-            label_y + margin,
-        ));
-
         drawing_area.draw(&Rectangle::new(
             [(label_x, label_y), (label_x + w, label_y + h)],
             self.background.filled(),
@@ -277,20 +334,63 @@ impl<'a, 'b, DB: DrawingBackend + 'a, CT: CoordTranslate> SeriesLabelStyle<'a, '
             [(label_x, label_y), (label_x + w, label_y + h)],
             self.border_style,
         ))?;
-        drawing_area.draw(&label_element)?;
-
-        for (((_, y0), (_, y1)), make_elem) in label_element
-            .compute_line_layout()
-            .map_err(|e| {
-                DrawingAreaErrorKind::BackendError(DrawingErrorKind::FontError(Box::new(e)))
-            })?
-            .into_iter()
-            .zip(funcs.into_iter())
-        {
-            let legend_element = make_elem((label_x + margin, (y0 + y1) / 2));
+
+        let text_x = label_x + legend_area_size + margin;
+        if let Some(mut label_element) = label_element {
+            label_element.relocate((text_x, label_y + margin));
+            drawing_area.draw(&label_element)?;
+        } else {
+            for ((label_text, _, _), (y, _)) in rows.iter().zip(layout.iter()) {
+                drawing_area.draw(&Text::new(
+                    *label_text,
+                    (text_x, label_y + margin + y),
+                    &font,
+                ))?;
+            }
+        }
+
+        for ((_, make_elem, _), (y, dy)) in rows.into_iter().zip(layout.into_iter()) {
+            let y0 = label_y + margin + y;
+            let legend_element = make_elem((label_x + margin, (y0 + y0 + dy) / 2));
             drawing_area.draw(&legend_element)?;
         }
 
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_series_labels_layout() {
+        let (drawing_area, labels) = create_text_recording_area(200, 200);
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0..10, 0..10)
+            .unwrap();
+        for label in ["a", "b", "c"].iter() {
+            chart
+                .draw_series(LineSeries::new(vec![(0, 0), (10, 10)], &RED))
+                .unwrap()
+                .label(*label)
+                .legend(|(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], RED));
+        }
+        chart.configure_series_labels().draw().unwrap();
+        drop(chart);
+        drop(drawing_area);
+
+        // Without any legend size, the labels are laid out as a multi-line text
+        let drawn = labels.all();
+        assert_eq!(labels.texts(), vec!["a", "b", "c"]);
+        let (drawing_area, expected) = create_text_recording_area(200, 200);
+        let font: TextStyle = ("sans-serif", 12).into_font().into();
+        let mut element = MultiLineText::<_, &str>::new(drawn[0].1, &font);
+        for label in ["a", "b", "c"].iter() {
+            element.push_line(*label);
+        }
+        drawing_area.draw(&element).unwrap();
+        drop(drawing_area);
+        assert_eq!(drawn, expected.all());
+    }
+}
@@ -107,6 +107,10 @@ impl<CT: CoordTranslate> ChartState<CT> {
             drawing_area: area.apply_coord_spec(self.coord),
             series_anno: vec![],
             drawing_area_pos: self.drawing_area_pos,
+            deferred_layer: None,
+            axis_offset: 0,
+            fixed_layout: false,
+            layout_only: false,
         }
     }
 }
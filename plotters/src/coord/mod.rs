@@ -52,9 +52,34 @@ pub mod cartesian {
     pub use super::ranged3d::Cartesian3d;
 }
 
+/// The polar coordinate system in 2d.
+pub mod polar {
+    pub use super::ranged2d::polar::PolarCoord;
+}
+
+/// The geographic coordinate system for the longitude and the latitude.
+pub mod geo {
+    pub use super::ranged2d::geo::{
+        GeoCoord, GeoProjection, Latitude, Longitude, MAX_MERCATOR_LATITUDE,
+    };
+}
+
+/// The ternary coordinate system for the three-component compositional data.
+pub mod ternary {
+    pub use super::ranged2d::ternary::TernaryCoord;
+}
+
+/// The coordinate system adapter which applies an affine transform to another coordinate system.
+pub mod transformed {
+    pub use super::ranged2d::transformed::{AffineTransform, Transformed};
+}
+
 mod translate;
 pub use translate::{CoordTranslate, ReverseCoordTranslate};
 
+mod nice;
+pub use nice::nice_range;
+
 /// The coordinate translation that only impose shift
 #[derive(Debug, Clone)]
 pub struct Shift(pub BackendCoord);
@@ -0,0 +1,113 @@
+use num_traits::Float;
+use std::ops::Range;
+
+/// The multipliers of the power of ten which are considered as nice tick steps
+const NICE_STEPS: [f64; 5] = [1.0, 2.0, 2.5, 5.0, 10.0];
+
+/// Expand the range to round numbers, so that the key points of the range land on clean values.
+/// For example, `0.0..47.3` becomes `0.0..50.0`.
+///
+/// The range is expanded to the multiples of a nice step, which is 1, 2, 2.5 or 5 times a power
+/// of ten, so that the range is divided into about `target_ticks` intervals of the nice step.
+/// A reversed range stays reversed, and an empty range is expanded around its value.
+/// Both `f32` and `f64` ranges are supported.
+///
+/// ```rust
+/// use plotters::coord::nice_range;
+///
+/// assert_eq!(nice_range(0.0..47.3, 5), 0.0..50.0);
+/// assert_eq!(nice_range(-0.13..0.92, 10), -0.2..1.0);
+/// ```
+/// - `range`: The raw range, usually the bounds of the data
+/// - `target_ticks`: The desired number of the intervals between the ticks
+/// - **returns**: The expanded range
+pub fn nice_range<T: Float>(range: Range<T>, target_ticks: usize) -> Range<T> {
+    let nice = match (
+        range.start.to_f64(),
+        range.end.to_f64(),
+        T::epsilon().to_f64(),
+    ) {
+        (Some(start), Some(end), Some(epsilon)) => {
+            nice_range_f64(start..end, target_ticks, epsilon)
+        }
+        _ => return range,
+    };
+    match (T::from(nice.start), T::from(nice.end)) {
+        (Some(start), Some(end)) => start..end,
+        _ => range,
+    }
+}
+
+/// Expand the range of which the values have the given relative precision
+fn nice_range_f64(range: Range<f64>, target_ticks: usize, epsilon: f64) -> Range<f64> {
+    let (lo, hi) = if range.start <= range.end {
+        (range.start, range.end)
+    } else {
+        (range.end, range.start)
+    };
+    if !lo.is_finite() || !hi.is_finite() {
+        return range;
+    }
+
+    let (lo, hi) = if lo == hi {
+        let pad = if lo == 0.0 { 1.0 } else { lo.abs() / 10.0 };
+        (lo - pad, hi + pad)
+    } else {
+        (lo, hi)
+    };
+
+    /* Prevent the rounding error, including the one of the value type, from pushing the step or
+     * the bounds to the next nice value */
+    let slack = |x: f64| (x.abs() * epsilon).max(1e-9);
+
+    let raw_step = (hi - lo) / target_ticks.max(1) as f64;
+    let scale = 10f64.powf(raw_step.log10().floor());
+    let step_slack = slack((lo.abs() + hi.abs()) / (hi - lo));
+    let step = NICE_STEPS
+        .iter()
+        .map(|m| m * scale)
+        .find(|step| *step >= raw_step * (1.0 - step_slack))
+        .unwrap_or(10.0 * scale);
+
+    let (new_lo, new_hi) = (
+        (lo / step + slack(lo / step)).floor() * step,
+        (hi / step - slack(hi / step)).ceil() * step,
+    );
+    let (new_lo, new_hi) = (round_to_step(new_lo, step), round_to_step(new_hi, step));
+
+    if range.start <= range.end {
+        new_lo..new_hi
+    } else {
+        new_hi..new_lo
+    }
+}
+
+/// Remove the floating point noise, such as `0.30000000000000004`, from a multiple of the step
+fn round_to_step(value: f64, step: f64) -> f64 {
+    let digits = (-step.log10().floor()).max(0.0) as i32 + 1;
+    let factor = 10f64.powi(digits);
+    (value * factor).round() / factor
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_nice_range() {
+        assert_eq!(nice_range(0.0..47.3, 5), 0.0..50.0);
+        assert_eq!(nice_range(0.0..47.3, 10), 0.0..50.0);
+        assert_eq!(nice_range(3.2..97.1, 10), 0.0..100.0);
+        assert_eq!(nice_range(-0.13..0.92, 10), -0.2..1.0);
+        assert_eq!(nice_range(0.0..100.0, 10), 0.0..100.0);
+        assert_eq!(nice_range(1013.0..1027.0, 5), 1010.0..1030.0);
+        assert_eq!(nice_range(47.3..0.0, 5), 50.0..0.0);
+        assert_eq!(nice_range(5.0..5.0, 10), 4.5..5.5);
+        assert_eq!(nice_range(0.0..0.0, 2), -1.0..1.0);
+        assert_eq!(nice_range(0.1..0.3, 2), 0.1..0.3);
+
+        assert_eq!(nice_range(0f32..47.3f32, 5), 0f32..50f32);
+        assert_eq!(nice_range(-0.13f32..0.92f32, 10), -0.2f32..1f32);
+        assert_eq!(nice_range(0.1f32..0.3f32, 2), 0.1f32..0.3f32);
+    }
+}
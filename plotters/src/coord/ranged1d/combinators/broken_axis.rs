@@ -0,0 +1,267 @@
+use crate::coord::ranged1d::{
+    AsRangedCoord, KeyPointHint, KeyPointWeight, NoDefaultFormatting, Ranged, ReversibleRanged,
+    ValueFormatter,
+};
+use std::ops::Range;
+
+/// The default size of the gap between the two segments of a broken axis, in pixels
+const DEFAULT_BREAK_GAP: i32 = 8;
+
+/// An axis decorator that breaks the axis into two segments.
+///
+/// This is useful when the data has a huge gap, for example some values are close to 0 and
+/// others are close to 10000. The two sub-ranges are mapped next to each other and the gap
+/// between them is reported by [axis_breaks](../trait.Ranged.html#method.axis_breaks), so the
+/// mesh can draw a break marker on the axis. The key points are generated per segment.
+///
+/// ```rust
+/// use plotters::prelude::*;
+///
+/// let mut buffer = vec![0; 1024 * 768 * 3];
+/// let root = BitMapBackend::with_buffer(&mut buffer, (1024, 768)).into_drawing_area();
+///
+/// let mut chart = ChartBuilder::on(&root)
+///     .x_label_area_size(30)
+///     .y_label_area_size(50)
+///     .build_cartesian_2d(0..10, (0.0..10.0).broken_axis(9_990.0..10_000.0))
+///     .unwrap();
+///
+/// chart.configure_mesh().draw().unwrap();
+/// ```
+#[derive(Clone)]
+pub struct BrokenAxis<R: Ranged> {
+    lower: R,
+    upper: R,
+    ratio: f64,
+    gap: i32,
+}
+
+/// The trait for the types that can be converted into a broken axis
+pub trait IntoBrokenAxis: AsRangedCoord {
+    /// Make a broken axis
+    ///
+    /// - `upper`: The range of the upper segment, which should be after the range of `self`
+    /// - **returns**: The converted range specification
+    fn broken_axis(self, upper: Self) -> BrokenAxis<Self::CoordDescType> {
+        BrokenAxis {
+            lower: self.into(),
+            upper: upper.into(),
+            ratio: 0.5,
+            gap: DEFAULT_BREAK_GAP,
+        }
+    }
+}
+
+impl<R: AsRangedCoord> IntoBrokenAxis for R {}
+
+impl<R: Ranged> BrokenAxis<R> {
+    /// Set the portion of the axis used by the lower segment
+    ///
+    /// - `ratio`: The portion of the axis, from 0.0 to 1.0, defaults to 0.5
+    /// - **returns**: The modified broken axis
+    pub fn ratio(mut self, ratio: f64) -> Self {
+        self.ratio = ratio.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Set the size of the gap between the two segments
+    ///
+    /// - `gap`: The gap size in pixels
+    /// - **returns**: The modified broken axis
+    pub fn gap(mut self, gap: u32) -> Self {
+        self.gap = gap as i32;
+        self
+    }
+
+    /// Get the pixel limits of the lower and upper segments
+    fn segment_limits(&self, limit: (i32, i32)) -> ((i32, i32), (i32, i32)) {
+        let size = limit.1 - limit.0;
+        let sign = size.signum();
+        let usable = (size.abs() - self.gap).max(0);
+        let lower_size = (usable as f64 * self.ratio).round() as i32;
+
+        let lower_end = limit.0 + sign * lower_size;
+        let upper_start = lower_end + sign * (size.abs() - usable);
+
+        ((limit.0, lower_end), (upper_start, limit.1))
+    }
+}
+
+/// The key point hint for one of the segments of a broken axis
+pub(super) struct SegmentHint {
+    pub(super) max_points: usize,
+    pub(super) bold_points: usize,
+    pub(super) light: bool,
+}
+
+impl KeyPointHint for SegmentHint {
+    fn max_num_points(&self) -> usize {
+        self.max_points
+    }
+
+    fn weight(&self) -> KeyPointWeight {
+        if self.light {
+            KeyPointWeight::Any
+        } else {
+            KeyPointWeight::Bold
+        }
+    }
+
+    fn bold_points(&self) -> usize {
+        self.bold_points
+    }
+}
+
+impl<T: PartialOrd, R: Ranged<ValueType = T> + ValueFormatter<T>> ValueFormatter<T>
+    for BrokenAxis<R>
+{
+    fn format(value: &T) -> String {
+        R::format(value)
+    }
+
+    fn format_ext(&self, value: &T) -> String {
+        if *value >= self.upper.range().start {
+            self.upper.format_ext(value)
+        } else {
+            self.lower.format_ext(value)
+        }
+    }
+}
+
+impl<R: Ranged> Ranged for BrokenAxis<R>
+where
+    R::ValueType: PartialOrd,
+{
+    type FormatOption = NoDefaultFormatting;
+    type ValueType = R::ValueType;
+
+    fn map(&self, value: &Self::ValueType, limit: (i32, i32)) -> i32 {
+        let (lower_limit, upper_limit) = self.segment_limits(limit);
+        if *value >= self.upper.range().start {
+            self.upper.map(value, upper_limit)
+        } else if *value > self.lower.range().end {
+            /* The value falls into the gap, so we put it on the break */
+            lower_limit.1
+        } else {
+            self.lower.map(value, lower_limit)
+        }
+    }
+
+    fn key_points<Hint: KeyPointHint>(&self, hint: Hint) -> Vec<Self::ValueType> {
+        let split = |total: usize| {
+            let lower = (total as f64 * self.ratio).round() as usize;
+            (lower, total.saturating_sub(lower))
+        };
+
+        let (lower_max, upper_max) = split(hint.max_num_points());
+        let (lower_bold, upper_bold) = split(hint.bold_points());
+        let light = hint.weight().allow_light_points();
+
+        let mut ret = self.lower.key_points(SegmentHint {
+            max_points: lower_max,
+            bold_points: lower_bold,
+            light,
+        });
+        ret.extend(self.upper.key_points(SegmentHint {
+            max_points: upper_max,
+            bold_points: upper_bold,
+            light,
+        }));
+        ret
+    }
+
+    fn range(&self) -> Range<Self::ValueType> {
+        self.lower.range().start..self.upper.range().end
+    }
+
+    fn axis_breaks(&self, limit: (i32, i32)) -> Vec<Range<i32>> {
+        let ((_, lower_end), (upper_start, _)) = self.segment_limits(limit);
+        if lower_end == upper_start {
+            return vec![];
+        }
+        std::iter::once(lower_end.min(upper_start)..lower_end.max(upper_start)).collect()
+    }
+
+    fn axis_markers(&self, limit: (i32, i32)) -> Vec<i32> {
+        let (lower_limit, upper_limit) = self.segment_limits(limit);
+        let mut ret = self.lower.axis_markers(lower_limit);
+        ret.extend(self.upper.axis_markers(upper_limit));
+        ret
+    }
+}
+
+impl<R: ReversibleRanged> ReversibleRanged for BrokenAxis<R>
+where
+    R::ValueType: PartialOrd,
+{
+    fn unmap(&self, input: i32, limit: (i32, i32)) -> Option<Self::ValueType> {
+        let (lower_limit, upper_limit) = self.segment_limits(limit);
+        let within = |(start, end): (i32, i32)| start.min(end) <= input && input <= start.max(end);
+        if within(lower_limit) {
+            self.lower.unmap(input, lower_limit)
+        } else if within(upper_limit) {
+            self.upper.unmap(input, upper_limit)
+        } else {
+            /* The position is in the gap or outside of the axis */
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_broken_axis() {
+        let coord = (0..10).broken_axis(9990..10000);
+
+        assert_eq!(coord.range(), 0..10000);
+        assert_eq!(coord.map(&0, (0, 108)), 0);
+        assert_eq!(coord.map(&10, (0, 108)), 50);
+        assert_eq!(coord.map(&9990, (0, 108)), 58);
+        assert_eq!(coord.map(&10000, (0, 108)), 108);
+        assert_eq!(coord.axis_breaks((0, 108)), vec![50..58]);
+        assert_eq!(coord.axis_breaks((108, 0)), vec![50..58]);
+        assert_eq!(coord.map(&11, (0, 108)), 50);
+        assert_eq!(coord.map(&5000, (0, 108)), 50);
+        assert_eq!(coord.map(&9989, (108, 0)), 58);
+
+        let key_points = coord.key_points(10);
+        assert!(key_points.iter().any(|&x| x < 10));
+        assert!(key_points.iter().any(|&x| x >= 9990));
+        assert!(key_points.len() <= 10);
+
+        let coord = (0.0..10.0).broken_axis(9990.0..10000.0);
+        assert_eq!(coord.unmap(25, (0, 108)), Some(5.0));
+        assert_eq!(coord.unmap(83, (0, 108)), Some(9995.0));
+        assert_eq!(coord.unmap(54, (0, 108)), None);
+        assert_eq!(coord.unmap(25, (108, 0)), Some(9995.0));
+
+        let coord = (0..10).broken_axis(9990..10000).ratio(0.25).gap(0);
+        assert_eq!(coord.map(&10, (0, 100)), 25);
+        assert!(coord.axis_breaks((0, 100)).is_empty());
+    }
+
+    #[test]
+    fn test_broken_axis_format_with_owning_segment() {
+        use crate::coord::ranged1d::types::IntoAngleCoord;
+
+        /* The segments format the same value differently, so we can tell which one is used */
+        let coord = (0.0..1.0).radians().broken_axis((90.0..180.0).degrees());
+        assert_eq!(coord.format_ext(&0.5), "0.5");
+        assert_eq!(coord.format_ext(&90.0), "90\u{b0}");
+        assert_eq!(coord.format_ext(&180.0), "180\u{b0}");
+    }
+
+    #[test]
+    fn test_broken_axis_markers() {
+        use crate::coord::combinators::IntoLinLogRange;
+
+        let coord = (0.0..1e3)
+            .linlog_scale(10.0)
+            .linear_portion(0.5)
+            .broken_axis((2e3..3e5).linlog_scale(1e4).linear_portion(0.5));
+        assert_eq!(coord.axis_markers((0, 108)), [25, 83]);
+    }
+}
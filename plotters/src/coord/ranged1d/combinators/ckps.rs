@@ -75,8 +75,24 @@ where
     fn axis_pixel_range(&self, limit: (i32, i32)) -> Range<i32> {
         self.inner.axis_pixel_range(limit)
     }
+
+    fn axis_breaks(&self, limit: (i32, i32)) -> Vec<Range<i32>> {
+        self.inner.axis_breaks(limit)
+    }
+
+    fn axis_markers(&self, limit: (i32, i32)) -> Vec<i32> {
+        self.inner.axis_markers(limit)
+    }
+
+    fn key_point_tier(&self, value: &Self::ValueType) -> usize {
+        self.inner.key_point_tier(value)
+    }
 }
 
+impl_continuous_unmap!(WithKeyPoints, |this, input, limit| this
+    .inner
+    .unmap(input, limit));
+
 impl<R: DiscreteRanged> DiscreteRanged for WithKeyPoints<R>
 where
     R::ValueType: Clone,
@@ -200,8 +216,24 @@ impl<R: Ranged> Ranged for WithKeyPointMethod<R> {
     fn axis_pixel_range(&self, limit: (i32, i32)) -> Range<i32> {
         self.inner.axis_pixel_range(limit)
     }
+
+    fn axis_breaks(&self, limit: (i32, i32)) -> Vec<Range<i32>> {
+        self.inner.axis_breaks(limit)
+    }
+
+    fn axis_markers(&self, limit: (i32, i32)) -> Vec<i32> {
+        self.inner.axis_markers(limit)
+    }
+
+    fn key_point_tier(&self, value: &Self::ValueType) -> usize {
+        self.inner.key_point_tier(value)
+    }
 }
 
+impl_continuous_unmap!(WithKeyPointMethod, |this, input, limit| this
+    .inner
+    .unmap(input, limit));
+
 impl<R: DiscreteRanged> DiscreteRanged for WithKeyPointMethod<R> {
     fn size(&self) -> usize {
         self.inner.size()
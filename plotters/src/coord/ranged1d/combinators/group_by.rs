@@ -70,6 +70,15 @@ impl<T: DiscreteRanged> Ranged for GroupBy<T> {
     fn range(&self) -> Range<T::ValueType> {
         self.0.range()
     }
+    fn axis_breaks(&self, limit: (i32, i32)) -> Vec<Range<i32>> {
+        self.0.axis_breaks(limit)
+    }
+    fn axis_markers(&self, limit: (i32, i32)) -> Vec<i32> {
+        self.0.axis_markers(limit)
+    }
+    fn key_point_tier(&self, value: &T::ValueType) -> usize {
+        self.0.key_point_tier(value)
+    }
     // TODO: See issue issue #88
     fn key_points<HintType: KeyPointHint>(&self, hint: HintType) -> Vec<T::ValueType> {
         let range = 0..(self.0.size() + self.1) / self.1;
@@ -287,6 +287,14 @@ where
         self.inner.map(value, limit)
     }
 
+    fn axis_breaks(&self, limit: (i32, i32)) -> Vec<Range<i32>> {
+        self.inner.axis_breaks(limit)
+    }
+
+    fn axis_markers(&self, limit: (i32, i32)) -> Vec<i32> {
+        self.inner.axis_markers(limit)
+    }
+
     fn key_points<Hint: KeyPointHint>(&self, hint: Hint) -> Vec<T::ValueType> {
         if self.grid_value.is_empty() {
             return vec![];
@@ -418,6 +426,28 @@ mod test {
         assert_eq!(coord.index_of(&1.0), Some(0));
     }
 
+    #[test]
+    fn test_f32_segmented_linspace() {
+        use crate::coord::ranged1d::{IntoSegmentedCoord, SegmentValue};
+
+        let coord32 = (-4f32..4f32).step(0.25).use_round().into_segmented();
+        let coord64 = (-4f64..4f64).step(0.25).use_round().into_segmented();
+
+        assert_eq!(coord32.size(), coord64.size());
+        assert_eq!(
+            coord32.index_of(&SegmentValue::Exact(1.1)),
+            coord64.index_of(&SegmentValue::Exact(1.1))
+        );
+        for (a, b) in coord32
+            .key_points(20)
+            .iter()
+            .zip(coord64.key_points(20).iter())
+        {
+            assert_eq!(coord32.format_ext(a), coord64.format_ext(b));
+            assert_eq!(coord32.map(a, (0, 320)), coord64.map(b, (0, 320)));
+        }
+    }
+
     #[cfg(feature = "chrono")]
     #[test]
     fn test_duration_linspace() {
@@ -1,3 +1,4 @@
+use super::broken_axis::SegmentHint;
 use crate::coord::ranged1d::types::RangedCoordf64;
 use crate::coord::ranged1d::{
     AsRangedCoord, DefaultFormatting, KeyPointHint, Ranged, ReversibleRanged,
@@ -5,6 +6,10 @@ use crate::coord::ranged1d::{
 use std::marker::PhantomData;
 use std::ops::Range;
 
+/// The relative error allowed when a key point is converted to the value type, which is the
+/// precision of `f32`, so that the key points such as 0.2 are kept for the `f32` axes
+const REPRESENTABLE_TOLERANCE: f64 = f32::EPSILON as f64;
+
 /// The trait for the type that is able to be presented in the log scale.
 /// This trait is primarily used by [LogRangeExt](struct.LogRangeExt.html).
 pub trait LogScalable: Clone {
@@ -60,6 +65,29 @@ impl_log_scalable!(i, isize);
 impl_log_scalable!(f, f32);
 impl_log_scalable!(f, f64);
 
+/// Describes where the key points of a log scale axis should be placed
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LogTicks {
+    /// Only place the bold key points on the powers of the base, for example 1, 10, 100, ...
+    Decades,
+    /// Place the bold key points on 1, 2 and 5 times the powers of the base if the range is
+    /// short enough, otherwise fall back to the powers of the base. This only takes effect for
+    /// the log scale with base 10.
+    OneTwoFive,
+}
+
+/// Describes where the light key points of a log scale axis, which are used for the fine grid and
+/// the minor tick marks, should be placed between the powers of the base
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LogMinorTicks {
+    /// Place the light key points on all the integer multiples of the powers of the base, for
+    /// example 2, 3, ..., 9, 20, 30, ... This only takes effect for the base of at least 3.
+    Multiples,
+    /// Place the light key points on 2 and 5 times the powers of the base, for example 2, 5, 20,
+    /// 50, ... This only takes effect for the log scale with base 10.
+    TwoFive,
+}
+
 /// Convert a range to a log scale coordinate spec
 pub trait IntoLogRange {
     /// The type of the value
@@ -76,6 +104,8 @@ impl<T: LogScalable> IntoLogRange for Range<T> {
             range: self,
             zero: 0.0,
             base: 10.0,
+            ticks: LogTicks::OneTwoFive,
+            minor_ticks: Some(LogMinorTicks::Multiples),
         }
     }
 }
@@ -96,6 +126,8 @@ pub struct LogRangeExt<V: LogScalable> {
     range: Range<V>,
     zero: f64,
     base: f64,
+    ticks: LogTicks,
+    minor_ticks: Option<LogMinorTicks>,
 }
 
 impl<V: LogScalable> LogRangeExt<V> {
@@ -114,13 +146,41 @@ impl<V: LogScalable> LogRangeExt<V> {
         self
     }
 
-    /// Set the base multiplier
+    /// Set the base of the logarithm, which also decides the key points, for example 2,
+    /// `std::f64::consts::E` or 10. By default it's 10, and the bases not greater than 1 are
+    /// ignored.
     pub fn base(mut self, base: f64) -> Self {
-        if self.base > 1.0 {
+        if base > 1.0 {
             self.base = base;
         }
         self
     }
+
+    /// Set where the bold key points, which are used for labels and the coarse grid, are placed.
+    /// By default, [LogTicks::OneTwoFive] is used.
+    pub fn ticks(mut self, ticks: LogTicks) -> Self {
+        self.ticks = ticks;
+        self
+    }
+
+    /// Set if the light key points, which are used for the fine grid and minor tick marks, should
+    /// include the integer multiples of the powers of the base, for example 2, 3, ..., 9, 20, 30, ...
+    /// By default the sub-decade light key points are enabled.
+    pub fn minor_ticks(mut self, enabled: bool) -> Self {
+        self.minor_ticks = match self.minor_ticks {
+            Some(minor_ticks) if enabled => Some(minor_ticks),
+            _ if enabled => Some(LogMinorTicks::Multiples),
+            _ => None,
+        };
+        self
+    }
+
+    /// Enable the light key points between the powers of the base, and set where they are
+    /// placed, see [LogMinorTicks] for details
+    pub fn minor_ticks_at(mut self, minor_ticks: LogMinorTicks) -> Self {
+        self.minor_ticks = Some(minor_ticks);
+        self
+    }
 }
 
 impl<V: LogScalable> From<LogRangeExt<V>> for LogCoord<V> {
@@ -151,6 +211,8 @@ impl<V: LogScalable> From<LogRangeExt<V>> for LogCoord<V> {
             base: spec.base,
             zero_point,
             negative,
+            ticks: spec.ticks,
+            minor_ticks: spec.minor_ticks,
             marker: PhantomData,
         }
     }
@@ -170,6 +232,8 @@ pub struct LogCoord<V: LogScalable> {
     base: f64,
     zero_point: f64,
     negative: bool,
+    ticks: LogTicks,
+    minor_ticks: Option<LogMinorTicks>,
     marker: PhantomData<V>,
 }
 
@@ -195,6 +259,38 @@ impl<V: LogScalable> LogCoord<V> {
 
         (V::as_f64(&a) - V::as_f64(&b)).abs() < f64::EPSILON
     }
+
+    /// Check if the normalized value can be represented by the value type, for example the
+    /// fractional values can't be used as the key points of an integer axis
+    fn is_representable(&self, fv: f64) -> bool {
+        let fv = if self.negative { -fv } else { fv } + self.zero_point;
+        (V::from_f64(fv).as_f64() - fv).abs() <= fv.abs().max(1.0) * REPRESENTABLE_TOLERANCE
+    }
+}
+
+/// Find the key points for a log scale axis in the normalized range `start..end`.
+/// The key points are `m * base^k` for the given mantissas `m`, and for every `step`-th exponent `k`.
+fn log_key_points(start: f64, end: f64, base: f64, mantissas: &[f64], step: i32) -> Vec<f64> {
+    let base_ln = base.ln();
+    let (lower, upper) = (start * (1.0 - 1e-10), end * (1.0 + 1e-10));
+    let first_exp = (start.ln() / base_ln).floor() as i32;
+    let last_exp = (end.ln() / base_ln).ceil() as i32;
+
+    let first_exp = first_exp - first_exp.rem_euclid(step);
+
+    let mut ret = vec![];
+    let mut exp = first_exp;
+    while exp <= last_exp {
+        let decade = base.powi(exp);
+        for m in mantissas {
+            let value = m * decade;
+            if lower <= value && value <= upper {
+                ret.push(value);
+            }
+        }
+        exp += step;
+    }
+    ret
 }
 
 impl<V: LogScalable> Ranged for LogCoord<V> {
@@ -210,57 +306,56 @@ impl<V: LogScalable> Ranged for LogCoord<V> {
     fn key_points<Hint: KeyPointHint>(&self, hint: Hint) -> Vec<Self::ValueType> {
         let max_points = hint.max_num_points();
 
-        let base = self.base;
-        let base_ln = base.ln();
-
         let Range { mut start, mut end } = self.normalized;
 
         if start > end {
             std::mem::swap(&mut start, &mut end);
         }
 
-        let bold_count = ((end / start).ln().abs() / base_ln).floor().max(1.0) as usize;
+        if max_points == 0 || !(start > 0.0 && end.is_finite() && self.base > 1.0) {
+            return vec![];
+        }
 
-        let light_density = if max_points < bold_count {
-            0
-        } else {
-            let density = 1 + (max_points - bold_count) / bold_count;
-            let mut exp = 1;
-            while exp * 10 <= density {
-                exp *= 10;
+        /* The candidates of the mantissas, from the densest to the sparsest */
+        let mut candidates = vec![];
+        if hint.weight().allow_light_points() {
+            match self.minor_ticks {
+                Some(LogMinorTicks::Multiples) if self.base >= 3.0 => {
+                    candidates.push((1..self.base.ceil() as u32).map(f64::from).collect());
+                }
+                Some(LogMinorTicks::TwoFive) if self.base == 10.0 => {
+                    candidates.push(vec![1.0, 2.0, 5.0]);
+                }
+                _ => {}
             }
-            exp - 1
-        };
-
-        let mut multiplier = base;
-        let mut cnt = 1;
-        while max_points < bold_count / cnt {
-            multiplier *= base;
-            cnt += 1;
+        }
+        if self.ticks == LogTicks::OneTwoFive && self.base == 10.0 {
+            candidates.push(vec![1.0, 2.0, 5.0]);
         }
 
-        let mut ret = vec![];
-        let mut val = (base).powf((start.ln() / base_ln).ceil());
+        let filter = |points: Vec<f64>| -> Vec<f64> {
+            points
+                .into_iter()
+                .filter(|&v| !self.is_inf(v) && self.is_representable(v))
+                .collect()
+        };
 
-        while val <= end {
-            if !self.is_inf(val) {
-                ret.push(self.f64_to_value(val));
+        for mantissas in candidates {
+            let points = filter(log_key_points(start, end, self.base, &mantissas, 1));
+            if points.len() <= max_points && points.len() > 1 {
+                return points.into_iter().map(|v| self.f64_to_value(v)).collect();
             }
-            for i in 1..=light_density {
-                let v = val
-                    * (1.0
-                        + multiplier / f64::from(light_density as u32 + 1) * f64::from(i as u32));
-                if v > end {
-                    break;
-                }
-                if !self.is_inf(val) {
-                    ret.push(self.f64_to_value(v));
-                }
-            }
-            val *= multiplier;
         }
 
-        ret
+        /* Fall back to the powers of the base, skipping some of them if there are too many */
+        let mut step = 1;
+        loop {
+            let points = filter(log_key_points(start, end, self.base, &[1.0], step));
+            if points.len() <= max_points {
+                return points.into_iter().map(|v| self.f64_to_value(v)).collect();
+            }
+            step += 1;
+        }
     }
 
     fn range(&self) -> Range<V> {
@@ -287,6 +382,397 @@ impl<V: LogScalable> From<LogRange<V>> for LogCoord<V> {
     }
 }
 
+/// Convert a range to a symmetric log scale coordinate spec
+pub trait IntoSymLogRange {
+    /// The type of the value
+    type ValueType: LogScalable;
+
+    /// Make the symmetric log scale coordinate, which is linear within `-threshold..threshold`
+    /// and logarithmic outside of it, so that the negative and positive values can be shown on
+    /// the same axis over many orders of magnitude
+    /// - `threshold`: The positive bound of the linear region around zero
+    fn symlog_scale(self, threshold: f64) -> SymLogCoord<Self::ValueType>;
+}
+
+impl<T: LogScalable> IntoSymLogRange for Range<T> {
+    type ValueType = T;
+    fn symlog_scale(self, threshold: f64) -> SymLogCoord<T> {
+        SymLogCoord::new(self, threshold, 10.0)
+    }
+}
+
+/// A symmetric log scaled coordinate axis. The value `x` is mapped linearly to
+/// `sign(x) * log(1 + |x| / threshold)`, which is almost linear within the threshold around zero
+/// and logarithmic outside of it.
+#[derive(Clone)]
+pub struct SymLogCoord<V: LogScalable> {
+    linear: RangedCoordf64,
+    logic: Range<V>,
+    threshold: f64,
+    base: f64,
+}
+
+impl<V: LogScalable> SymLogCoord<V> {
+    fn new(range: Range<V>, threshold: f64, base: f64) -> Self {
+        let threshold = if threshold > 0.0 { threshold } else { 1.0 };
+        let mut ret = Self {
+            linear: (0.0..1.0).into(),
+            logic: range,
+            threshold,
+            base,
+        };
+        ret.linear = (ret.transform(ret.value_to_f64(&ret.logic.start))
+            ..ret.transform(ret.value_to_f64(&ret.logic.end)))
+            .into();
+        ret
+    }
+
+    /// Set the base of the logarithm outside the linear region, which also decides the key
+    /// points, by default it's 10
+    pub fn base(self, base: f64) -> Self {
+        if base > 1.0 {
+            Self::new(self.logic, self.threshold, base)
+        } else {
+            self
+        }
+    }
+
+    /// Get the threshold of the linear region around zero
+    pub fn threshold(&self) -> f64 {
+        self.threshold
+    }
+
+    /// The integer types present the zero as 0.5 to the log scale, which isn't needed here
+    fn value_to_f64(&self, value: &V) -> f64 {
+        let fv = value.as_f64();
+        if fv == 0.5 && V::from_f64(0.5).as_f64() != 0.5 {
+            0.0
+        } else {
+            fv
+        }
+    }
+
+    fn transform(&self, fv: f64) -> f64 {
+        fv.signum() * (fv.abs() / self.threshold).ln_1p() / self.base.ln()
+    }
+
+    fn inverse(&self, tv: f64) -> f64 {
+        tv.signum() * (tv.abs() * self.base.ln()).exp_m1() * self.threshold
+    }
+
+    /// Check if the value can be represented by the value type, for example the fractional
+    /// values can't be used as the key points of an integer axis
+    fn is_representable(&self, fv: f64) -> bool {
+        (self.value_to_f64(&V::from_f64(fv)) - fv).abs()
+            <= fv.abs().max(1.0) * REPRESENTABLE_TOLERANCE
+    }
+}
+
+impl<V: LogScalable> Ranged for SymLogCoord<V> {
+    type FormatOption = DefaultFormatting;
+    type ValueType = V;
+
+    fn map(&self, value: &V, limit: (i32, i32)) -> i32 {
+        self.linear
+            .map(&self.transform(self.value_to_f64(value)), limit)
+    }
+
+    fn key_points<Hint: KeyPointHint>(&self, hint: Hint) -> Vec<Self::ValueType> {
+        let max_points = hint.max_num_points();
+        let (mut start, mut end) = (
+            self.value_to_f64(&self.logic.start),
+            self.value_to_f64(&self.logic.end),
+        );
+        if start > end {
+            std::mem::swap(&mut start, &mut end);
+        }
+        if max_points == 0 || !(start.is_finite() && end.is_finite()) {
+            return vec![];
+        }
+
+        let to_values = |points: Vec<f64>| -> Vec<V> {
+            points
+                .into_iter()
+                .filter(|&v| self.is_representable(v))
+                .map(V::from_f64)
+                .collect()
+        };
+
+        /* Within the linear region, the key points of a linear axis are used */
+        let magnitude = start.abs().max(end.abs());
+        if magnitude < self.threshold * self.base {
+            let linear: RangedCoordf64 = (start..end).into();
+            return to_values(linear.key_points(hint));
+        }
+
+        /* The key points are zero and the powers of the base outside of the threshold, on each side */
+        let base_ln = self.base.ln();
+        let first_exp = (self.threshold.ln() / base_ln - 1e-10).ceil() as i32;
+        let last_exp = (magnitude.ln() / base_ln + 1e-10).floor() as i32;
+        let (lower, upper) = (start - magnitude * 1e-10, end + magnitude * 1e-10);
+        let collect = |mantissas: &[f64], step: i32| -> Vec<f64> {
+            let mut positive = vec![];
+            for exp in (first_exp..=last_exp).filter(|exp| exp.rem_euclid(step) == 0) {
+                let decade = self.base.powi(exp);
+                positive.extend(mantissas.iter().map(|m| m * decade));
+            }
+            let mut points: Vec<f64> = positive.iter().rev().map(|v| -v).collect();
+            points.push(0.0);
+            points.extend(positive);
+            points.retain(|v| lower <= *v && *v <= upper);
+            points
+        };
+
+        if hint.weight().allow_light_points() && self.base >= 3.0 {
+            let mantissas: Vec<_> = (1..self.base.ceil() as u32).map(f64::from).collect();
+            let points = to_values(collect(&mantissas, 1));
+            if points.len() <= max_points && points.len() > 1 {
+                return points;
+            }
+        }
+
+        for step in 1..=(last_exp - first_exp + 1).max(1) {
+            let points = to_values(collect(&[1.0], step));
+            if points.len() <= max_points {
+                return points;
+            }
+        }
+        vec![]
+    }
+
+    fn range(&self) -> Range<V> {
+        self.logic.clone()
+    }
+}
+
+impl<V: LogScalable> ReversibleRanged for SymLogCoord<V> {
+    fn unmap(&self, input: i32, limit: (i32, i32)) -> Option<V> {
+        self.linear
+            .unmap(input, limit)
+            .map(|tv| V::from_f64(self.inverse(tv)))
+    }
+}
+
+/// Convert a range to a hybrid linear-log scale coordinate spec
+pub trait IntoLinLogRange {
+    /// The type of the value
+    type ValueType: LogScalable;
+
+    /// Make the hybrid coordinate, which is linear up to the pivot and logarithmic beyond it,
+    /// so that a dense low range and a sparse high range can be shown on the same axis
+    /// - `pivot`: The positive value where the scale changes from linear to logarithmic
+    fn linlog_scale(self, pivot: f64) -> LinLogCoord<Self::ValueType>;
+}
+
+impl<T: LogScalable> IntoLinLogRange for Range<T> {
+    type ValueType = T;
+    fn linlog_scale(self, pivot: f64) -> LinLogCoord<T> {
+        LinLogCoord::new(self, pivot, None)
+    }
+}
+
+/// A hybrid coordinate axis, which is linear up to the pivot value and logarithmic beyond it.
+///
+/// By default, the value `x` is mapped linearly to `x / pivot` below the pivot and to
+/// `1 + ln(x / pivot)` above it, so the scale changes smoothly at the pivot. The portion of the
+/// axis used by the linear part can be set with [LinLogCoord::linear_portion]. The pivot is
+/// reported by [axis_markers](../trait.Ranged.html#method.axis_markers), so the mesh draws a
+/// marker there on the axis.
+///
+/// ```rust
+/// use plotters::prelude::*;
+///
+/// let mut buffer = vec![0; 1024 * 768 * 3];
+/// let root = BitMapBackend::with_buffer(&mut buffer, (1024, 768)).into_drawing_area();
+///
+/// let mut chart = ChartBuilder::on(&root)
+///     .x_label_area_size(30)
+///     .y_label_area_size(50)
+///     .build_cartesian_2d(0..100, (0.0..1e5).linlog_scale(10.0).linear_portion(0.4))
+///     .unwrap();
+///
+/// chart.configure_mesh().draw().unwrap();
+/// ```
+#[derive(Clone)]
+pub struct LinLogCoord<V: LogScalable> {
+    linear: RangedCoordf64,
+    logic: Range<V>,
+    pivot: f64,
+    /// The factor of the logarithm part in the transformed space
+    log_factor: f64,
+}
+
+impl<V: LogScalable> LinLogCoord<V> {
+    fn new(range: Range<V>, pivot: f64, portion: Option<f64>) -> Self {
+        let pivot = if pivot > 0.0 { pivot } else { 1.0 };
+        let mut ret = Self {
+            linear: (0.0..1.0).into(),
+            logic: range,
+            pivot,
+            log_factor: 1.0,
+        };
+
+        let (start, end) = ret.normalized_range();
+        if let Some(portion) = portion.filter(|r| *r > 0.0 && *r < 1.0) {
+            if start < pivot && end > pivot {
+                let linear_len = 1.0 - start / pivot;
+                ret.log_factor = linear_len * (1.0 - portion) / (portion * (end / pivot).ln());
+            }
+        }
+
+        ret.linear = (ret.transform(ret.value_to_f64(&ret.logic.start))
+            ..ret.transform(ret.value_to_f64(&ret.logic.end)))
+            .into();
+        ret
+    }
+
+    /// Set the portion of the axis used by the linear part, by default the portion is decided by
+    /// the smooth change of the scale at the pivot. This only takes effect if the pivot is within
+    /// the range.
+    /// - `portion`: The portion, from 0.0 to 1.0 exclusively
+    pub fn linear_portion(self, portion: f64) -> Self {
+        Self::new(self.logic, self.pivot, Some(portion))
+    }
+
+    /// Get the pivot where the scale changes from linear to logarithmic
+    pub fn pivot(&self) -> f64 {
+        self.pivot
+    }
+
+    /// The integer types present the zero as 0.5 to the log scale, which isn't needed here
+    fn value_to_f64(&self, value: &V) -> f64 {
+        let fv = value.as_f64();
+        if fv == 0.5 && V::from_f64(0.5).as_f64() != 0.5 {
+            0.0
+        } else {
+            fv
+        }
+    }
+
+    /// Get the range in `f64`, in the ascending order
+    fn normalized_range(&self) -> (f64, f64) {
+        let (start, end) = (
+            self.value_to_f64(&self.logic.start),
+            self.value_to_f64(&self.logic.end),
+        );
+        (start.min(end), start.max(end))
+    }
+
+    fn transform(&self, fv: f64) -> f64 {
+        if fv <= self.pivot {
+            fv / self.pivot
+        } else {
+            1.0 + self.log_factor * (fv / self.pivot).ln()
+        }
+    }
+
+    fn inverse(&self, tv: f64) -> f64 {
+        if tv <= 1.0 {
+            tv * self.pivot
+        } else {
+            self.pivot * ((tv - 1.0) / self.log_factor).exp()
+        }
+    }
+
+    /// Check if the value can be represented by the value type, for example the fractional
+    /// values can't be used as the key points of an integer axis
+    fn is_representable(&self, fv: f64) -> bool {
+        (self.value_to_f64(&V::from_f64(fv)) - fv).abs()
+            <= fv.abs().max(1.0) * REPRESENTABLE_TOLERANCE
+    }
+}
+
+impl<V: LogScalable> Ranged for LinLogCoord<V> {
+    type FormatOption = DefaultFormatting;
+    type ValueType = V;
+
+    fn map(&self, value: &V, limit: (i32, i32)) -> i32 {
+        self.linear
+            .map(&self.transform(self.value_to_f64(value)), limit)
+    }
+
+    fn key_points<Hint: KeyPointHint>(&self, hint: Hint) -> Vec<Self::ValueType> {
+        let (start, end) = self.normalized_range();
+        if !(start.is_finite() && end.is_finite()) {
+            return vec![];
+        }
+
+        let linear_points = |start: f64, end: f64, hint: SegmentHint| {
+            RangedCoordf64::from(start..end).key_points(hint)
+        };
+        let log_points = |start: f64, end: f64, hint: SegmentHint| {
+            LogCoord::<f64>::from((start..end).log_scale()).key_points(hint)
+        };
+
+        /* The points are split between the two parts by their portions of the axis */
+        let (t_start, t_end) = (self.transform(start), self.transform(end));
+        let linear_share = if end <= self.pivot {
+            1.0
+        } else if start >= self.pivot {
+            0.0
+        } else {
+            (1.0 - t_start) / (t_end - t_start)
+        };
+        let split = |total: usize| {
+            let linear = (total as f64 * linear_share).round() as usize;
+            (linear, total.saturating_sub(linear))
+        };
+        let (linear_max, log_max) = split(hint.max_num_points());
+        let (linear_bold, log_bold) = split(hint.bold_points());
+        let light = hint.weight().allow_light_points();
+
+        let mut points = vec![];
+        if linear_max > 0 {
+            let hint = SegmentHint {
+                max_points: linear_max,
+                bold_points: linear_bold,
+                light,
+            };
+            points.extend(linear_points(start, end.min(self.pivot), hint));
+        }
+        if log_max > 0 {
+            let hint = SegmentHint {
+                max_points: log_max,
+                bold_points: log_bold,
+                light,
+            };
+            let lower = start.max(self.pivot);
+            points.extend(
+                log_points(lower, end, hint)
+                    .into_iter()
+                    .filter(|v| linear_max == 0 || *v > lower * (1.0 + 1e-9)),
+            );
+        }
+
+        points
+            .into_iter()
+            .filter(|&v| self.is_representable(v))
+            .map(V::from_f64)
+            .collect()
+    }
+
+    fn range(&self) -> Range<V> {
+        self.logic.clone()
+    }
+
+    fn axis_markers(&self, limit: (i32, i32)) -> Vec<i32> {
+        let (start, end) = self.normalized_range();
+        if start < self.pivot && self.pivot < end {
+            vec![self.linear.map(&1.0, limit)]
+        } else {
+            vec![]
+        }
+    }
+}
+
+impl<V: LogScalable> ReversibleRanged for LinLogCoord<V> {
+    fn unmap(&self, input: i32, limit: (i32, i32)) -> Option<V> {
+        self.linear
+            .unmap(input, limit)
+            .map(|tv| V::from_f64(self.inverse(tv)))
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -296,4 +782,155 @@ mod test {
 
         range.key_points(100);
     }
+
+    #[test]
+    fn test_log_key_points() {
+        use crate::coord::ranged1d::{BoldPoints, LightPoints};
+
+        let range: LogCoord<f64> = (1.0..5.0).log_scale().into();
+        assert_eq!(range.key_points(BoldPoints(10)), [1.0, 2.0, 5.0]);
+        assert_eq!(
+            range.key_points(LightPoints::new(10, 100)),
+            [1.0, 2.0, 3.0, 4.0, 5.0]
+        );
+
+        let range: LogCoord<f64> = (1.0..5.0).log_scale().ticks(LogTicks::Decades).into();
+        assert_eq!(range.key_points(BoldPoints(10)), [1.0]);
+
+        let range: LogCoord<f64> = (1.0..1e6).log_scale().into();
+        assert_eq!(
+            range.key_points(BoldPoints(10)),
+            [1.0, 10.0, 100.0, 1e3, 1e4, 1e5, 1e6]
+        );
+        assert_eq!(range.key_points(BoldPoints(4)), [1.0, 100.0, 1e4, 1e6]);
+
+        let range: LogCoord<u32> = (1..50).log_scale().into();
+        assert_eq!(range.key_points(BoldPoints(10)), [1, 2, 5, 10, 20, 50]);
+
+        let range: LogCoord<f64> = (1.0..1e6).log_scale().minor_ticks(false).into();
+        assert_eq!(range.key_points(LightPoints::new(10, 100)).len(), 19);
+
+        let range: LogCoord<f64> = (1.0..1e3)
+            .log_scale()
+            .ticks(LogTicks::Decades)
+            .minor_ticks_at(LogMinorTicks::TwoFive)
+            .into();
+        assert_eq!(range.key_points(BoldPoints(10)), [1.0, 10.0, 100.0, 1e3]);
+        assert_eq!(
+            range.key_points(LightPoints::new(10, 100)),
+            [1.0, 2.0, 5.0, 10.0, 20.0, 50.0, 100.0, 200.0, 500.0, 1e3]
+        );
+
+        let range: LogCoord<f64> = (1.0..64.0).log_scale().base(2.0).into();
+        assert_eq!(
+            range.key_points(BoldPoints(10)),
+            [1.0, 2.0, 4.0, 8.0, 16.0, 32.0, 64.0]
+        );
+        assert_eq!(range.key_points(BoldPoints(4)), [1.0, 4.0, 16.0, 64.0]);
+
+        let e = std::f64::consts::E;
+        let range: LogCoord<f64> = (1.0..e * e).log_scale().base(e).into();
+        let points = range.key_points(LightPoints::new(10, 100));
+        assert_eq!(points.len(), 3);
+        assert!((points[1] - e).abs() < 1e-9);
+
+        let range: LogCoord<f64> = (1.0..1e3).log_scale().base(0.5).into();
+        let default: LogCoord<f64> = (1.0..1e3).log_scale().into();
+        assert_eq!(
+            range.key_points(BoldPoints(10)),
+            default.key_points(BoldPoints(10))
+        );
+    }
+
+    #[test]
+    fn test_symlog_coord() {
+        use crate::coord::ranged1d::{BoldPoints, LightPoints};
+
+        let range: SymLogCoord<f64> = (-1e3..1e3).symlog_scale(1.0);
+        assert_eq!(range.map(&0.0, (0, 600)), 300);
+        assert!((range.map(&1e3, (0, 600)) - 600).abs() <= 1);
+        assert!((range.map(&-10.0, (0, 600)) + range.map(&10.0, (0, 600)) - 600).abs() <= 1);
+        assert!((range.unmap(450, (0, 600)).unwrap() - 30.6).abs() < 0.5);
+        assert_eq!(
+            range.key_points(BoldPoints(10)),
+            [-1e3, -100.0, -10.0, -1.0, 0.0, 1.0, 10.0, 100.0, 1e3]
+        );
+        assert_eq!(
+            range.key_points(BoldPoints(5)),
+            [-100.0, -1.0, 0.0, 1.0, 100.0]
+        );
+        assert_eq!(range.key_points(LightPoints::new(10, 100)).len(), 57);
+
+        let range: SymLogCoord<i32> = (-5..500).symlog_scale(10.0);
+        assert_eq!(range.map(&0, (0, 100)), 9);
+        assert_eq!(range.key_points(BoldPoints(10)), [0, 10, 100]);
+
+        let range: SymLogCoord<f64> = (-2.0..2.0).symlog_scale(1.0);
+        assert_eq!(range.key_points(BoldPoints(5)), [-2.0, -1.0, 0.0, 1.0, 2.0]);
+    }
+    #[test]
+    fn test_linlog_coord() {
+        use crate::coord::ranged1d::BoldPoints;
+
+        let range: LinLogCoord<f64> = (0.0..1e3).linlog_scale(10.0);
+        let map = |v: f64| range.map(&v, (0, 1000));
+        assert_eq!((map(0.0), map(1e3)), (0, 1000));
+        assert!((map(5.0) * 2 - map(10.0)).abs() <= 1);
+        assert!((map(1e3) - map(100.0) - (map(100.0) - map(10.0))).abs() <= 1);
+        assert!((map(10.0) - map(11.0) - (map(9.0) - map(10.0))).abs() <= 1);
+        assert_eq!(
+            range.axis_markers((0, 1000)),
+            vec![range.map(&10.0, (0, 1000))]
+        );
+        assert_eq!(
+            range
+                .unmap(range.map(&100.0, (0, 1000)), (0, 1000))
+                .map(f64::round),
+            Some(100.0)
+        );
+
+        let range = range.linear_portion(0.5);
+        assert_eq!(range.map(&10.0, (0, 100)), 50);
+        assert_eq!(range.map(&100.0, (0, 100)), 75);
+        let points = range.key_points(BoldPoints(10));
+        assert!(points.iter().any(|v| *v < 10.0) && points.contains(&100.0));
+        assert!(points.len() <= 10);
+
+        let range: LinLogCoord<u32> = (0..50).linlog_scale(100.0);
+        assert!(range.axis_markers((0, 100)).is_empty());
+        assert_eq!(
+            range.key_points(BoldPoints(6)),
+            RangedCoordf64::from(0.0..50.0)
+                .key_points(BoldPoints(6))
+                .into_iter()
+                .map(|v| v as u32)
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_f32_log_coords_match_f64() {
+        use crate::coord::ranged1d::LightPoints;
+
+        fn check<A: Ranged<ValueType = f32>, B: Ranged<ValueType = f64>>(a: A, b: B) {
+            for &(bold, light) in &[(10, 10), (10, 30), (30, 100)] {
+                let points: Vec<f32> = b
+                    .key_points(LightPoints::new(bold, light))
+                    .into_iter()
+                    .map(|v| v as f32)
+                    .collect();
+                assert_eq!(a.key_points(LightPoints::new(bold, light)), points);
+            }
+        }
+
+        check::<LogCoord<f32>, LogCoord<f64>>(
+            (0.01f32..100.0).log_scale().into(),
+            (0.01f64..100.0).log_scale().into(),
+        );
+        check(
+            (-100f32..100.0).symlog_scale(0.1),
+            (-100f64..100.0).symlog_scale(0.1),
+        );
+        check((0f32..1e3).linlog_scale(0.3), (0f64..1e3).linlog_scale(0.3));
+    }
 }
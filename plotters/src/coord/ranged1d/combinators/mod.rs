@@ -1,3 +1,59 @@
+/* A decorator can't forward the reverse mapping with a `ReversibleRanged` bound on the inner
+ * coordinate: the decorator of a discrete axis is discrete as well, so it's already reversible by
+ * the blanket implementation for the discrete axes, and the two implementations would conflict.
+ * So the reverse mapping is forwarded for each of the continuous built-in axes instead. */
+macro_rules! impl_continuous_unmap {
+    ($decorator:ident, |$this:ident, $input:ident, $limit:ident| $body:expr) => {
+        impl_continuous_unmap!(@types $decorator, |$this, $input, $limit| $body;
+            [] crate::coord::types::RangedCoordf32,
+            [] crate::coord::types::RangedCoordf64,
+            [] crate::coord::types::RangedAngle,
+            [V: crate::coord::combinators::LogScalable] crate::coord::combinators::LogCoord<V>,
+            [V: crate::coord::combinators::LogScalable] crate::coord::combinators::SymLogCoord<V>,
+            [V: crate::coord::combinators::LogScalable] crate::coord::combinators::LinLogCoord<V>,
+            [R: crate::coord::ranged1d::Ranged] crate::coord::combinators::QuantileCoord<R>,
+            [R: crate::coord::ranged1d::Ranged] crate::coord::combinators::BrokenAxis<R>,
+            [R: crate::coord::ranged1d::Ranged] crate::coord::combinators::StitchedAxis<R>
+        );
+        #[cfg(feature = "chrono")]
+        impl_continuous_unmap!(@types $decorator, |$this, $input, $limit| $body;
+            [DT: chrono::Datelike + chrono::Timelike + crate::coord::types::TimeValue]
+                crate::coord::types::RangedDateTime<DT>,
+            [] crate::coord::types::RangedTime,
+            [] crate::coord::types::RangedDuration,
+            [] crate::coord::types::RangedStdDuration
+        );
+        #[cfg(feature = "rust_decimal")]
+        impl_continuous_unmap!(@types $decorator, |$this, $input, $limit| $body;
+            [] crate::coord::types::RangedDecimal
+        );
+        #[cfg(feature = "uom")]
+        impl_continuous_unmap!(@types $decorator, |$this, $input, $limit| $body;
+            [Q: crate::coord::types::AxisQuantity] crate::coord::types::RangedQuantity<Q>
+        );
+    };
+    (@types $decorator:ident, |$this:ident, $input:ident, $limit:ident| $body:expr;
+        $([$($generics:tt)*] $inner:ty),*) => {
+        $(
+            impl<$($generics)*> crate::coord::ranged1d::ReversibleRanged for $decorator<$inner>
+            where
+                $inner: crate::coord::ranged1d::ReversibleRanged,
+                $decorator<$inner>: crate::coord::ranged1d::Ranged<
+                    ValueType = <$inner as crate::coord::ranged1d::Ranged>::ValueType,
+                >,
+            {
+                fn unmap(&self, $input: i32, $limit: (i32, i32)) -> Option<Self::ValueType> {
+                    let $this = self;
+                    $body
+                }
+            }
+        )*
+    };
+}
+
+mod broken_axis;
+pub use broken_axis::{BrokenAxis, IntoBrokenAxis};
+
 mod ckps;
 pub use ckps::{BindKeyPointMethod, BindKeyPoints, WithKeyPointMethod, WithKeyPoints};
 
@@ -8,7 +64,10 @@ mod linspace;
 pub use linspace::{IntoLinspace, Linspace};
 
 mod logarithmic;
-pub use logarithmic::{IntoLogRange, LogCoord, LogScalable};
+pub use logarithmic::{
+    IntoLinLogRange, IntoLogRange, IntoSymLogRange, LinLogCoord, LogCoord, LogMinorTicks,
+    LogRangeExt, LogScalable, LogTicks, SymLogCoord,
+};
 
 #[allow(deprecated)]
 pub use logarithmic::LogRange;
@@ -18,3 +77,15 @@ pub use nested::{BuildNestedCoord, NestedRange, NestedValue};
 
 mod partial_axis;
 pub use partial_axis::{make_partial_axis, IntoPartialAxis};
+
+mod quantile;
+pub use quantile::{IntoQuantileScale, QuantileCoord};
+
+mod reversed;
+pub use reversed::{IntoReversedAxis, Reversed};
+
+mod stitched;
+pub use stitched::{IntoStitchedAxis, StitchedAxis};
+
+mod stepped;
+pub use stepped::{IntoStepped, Stepped};
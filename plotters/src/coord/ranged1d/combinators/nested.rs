@@ -45,10 +45,72 @@ impl<C, V> From<C> for NestedValue<C, V> {
 /// for each value in discrete value, there is a secondary coordinate system.
 /// And the value is defined as a tuple of primary coordinate value and secondary
 /// coordinate value
+///
+/// Each category takes an equal slot of the axis, which is divided by the secondary coordinate
+/// of that category, so grouped bar charts can be drawn with a segmented secondary coordinate,
+/// without computing the offset of each bar by hand:
+///
+/// ```rust
+/// use plotters::prelude::*;
+///
+/// let mut buffer = vec![0; 1024 * 768 * 3];
+/// let root = BitMapBackend::with_buffer(&mut buffer, (1024, 768)).into_drawing_area();
+///
+/// let mut chart = ChartBuilder::on(&root)
+///     .x_label_area_size(50)
+///     .y_label_area_size(40)
+///     .build_cartesian_2d(
+///         ["2022", "2023"]
+///             .nested_coord(|_| ["Q1", "Q2", "Q3", "Q4"].into_segmented())
+///             .two_tier_labels(),
+///         0..100,
+///     )
+///     .unwrap();
+///
+/// chart.configure_mesh().disable_x_mesh().draw().unwrap();
+/// chart
+///     .draw_series(
+///         Histogram::vertical(&chart)
+///             .style(BLUE.filled())
+///             .data([
+///                 ((&"2022", SegmentValue::Exact(&"Q1")), 30),
+///                 ((&"2022", SegmentValue::Exact(&"Q2")), 45),
+///                 ((&"2023", SegmentValue::Exact(&"Q1")), 50),
+///             ]),
+///     )
+///     .unwrap();
+/// ```
 #[derive(Clone)]
 pub struct NestedRange<Primary: DiscreteRanged, Secondary: Ranged> {
     primary: Primary,
     secondary: Vec<Secondary>,
+    two_tier: bool,
+}
+
+impl<P: DiscreteRanged, S: Ranged> NestedRange<P, S> {
+    /// Get the pixel limits of the bucket of the category with the given index
+    fn bucket_limits(&self, idx: usize, limit: (i32, i32)) -> (i32, i32) {
+        let total = self.primary.size();
+
+        let bucket_size = (limit.1 - limit.0) / total as i32;
+        let mut residual = (limit.1 - limit.0) % total as i32;
+
+        if residual < 0 {
+            residual += total as i32;
+        }
+
+        let s_left = limit.0 + bucket_size * idx as i32 + residual.min(idx as i32);
+        let s_right = s_left + bucket_size + if (residual as usize) < idx { 1 } else { 0 };
+        (s_left, s_right)
+    }
+
+    /// Put the labels of the categories on a second tier of the axis, below the labels of the
+    /// nested values, instead of mixing them on the same tier. The nested values are labelled
+    /// as well in this mode, so the label area should be tall enough for both tiers.
+    pub fn two_tier_labels(mut self) -> Self {
+        self.two_tier = true;
+        self
+    }
 }
 
 impl<PT, ST, P, S> ValueFormatter<NestedValue<PT, ST>> for NestedRange<P, S>
@@ -64,6 +126,16 @@ where
             NestedValue::Value(_, val) => S::format(val),
         }
     }
+
+    fn format_ext(&self, value: &NestedValue<PT, ST>) -> String {
+        match value {
+            NestedValue::Category(cat) => self.primary.format_ext(cat),
+            NestedValue::Value(cat, val) => match self.primary.index_of(cat) {
+                Some(idx) => self.secondary[idx].format_ext(val),
+                None => S::format(val),
+            },
+        }
+    }
 }
 
 impl<P: DiscreteRanged, S: Ranged> Ranged for NestedRange<P, S> {
@@ -82,17 +154,7 @@ impl<P: DiscreteRanged, S: Ranged> Ranged for NestedRange<P, S> {
 
     fn map(&self, value: &Self::ValueType, limit: (i32, i32)) -> i32 {
         let idx = self.primary.index_of(value.category()).unwrap_or(0);
-        let total = self.primary.size();
-
-        let bucket_size = (limit.1 - limit.0) / total as i32;
-        let mut residual = (limit.1 - limit.0) % total as i32;
-
-        if residual < 0 {
-            residual += total as i32;
-        }
-
-        let s_left = limit.0 + bucket_size * idx as i32 + residual.min(idx as i32);
-        let s_right = s_left + bucket_size + if (residual as usize) < idx { 1 } else { 0 };
+        let (s_left, s_right) = self.bucket_limits(idx, limit);
 
         if let Some(secondary_value) = value.nested_value() {
             self.secondary[idx].map(secondary_value, (s_left, s_right))
@@ -101,8 +163,25 @@ impl<P: DiscreteRanged, S: Ranged> Ranged for NestedRange<P, S> {
         }
     }
 
+    fn axis_markers(&self, limit: (i32, i32)) -> Vec<i32> {
+        self.secondary
+            .iter()
+            .enumerate()
+            .flat_map(|(idx, secondary)| secondary.axis_markers(self.bucket_limits(idx, limit)))
+            .collect()
+    }
+
+    fn key_point_tier(&self, value: &Self::ValueType) -> usize {
+        match value {
+            NestedValue::Category(_) if self.two_tier => 1,
+            _ => 0,
+        }
+    }
+
     fn key_points<Hint: KeyPointHint>(&self, hint: Hint) -> Vec<Self::ValueType> {
-        if !hint.weight().allow_light_points() || hint.max_num_points() < self.primary.size() * 2 {
+        if (!self.two_tier && !hint.weight().allow_light_points())
+            || hint.max_num_points() < self.primary.size() * 2
+        {
             self.primary
                 .key_points(hint)
                 .into_iter()
@@ -178,7 +257,11 @@ where
             .map(|value| builder(value).into())
             .collect();
 
-        NestedRange { primary, secondary }
+        NestedRange {
+            primary,
+            secondary,
+            two_tier: false,
+        }
     }
 }
 
@@ -187,6 +270,7 @@ impl<T: AsRangedCoord> BuildNestedCoord for T where T::CoordDescType: DiscreteRa
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::coord::ranged1d::BoldPoints;
 
     #[test]
     fn test_nested_coord() {
@@ -203,4 +287,30 @@ mod test {
         assert_eq!(coord.index_of(&NestedValue::Value(5, 4)), Some(24));
         assert_eq!(coord.from_index(24), Some(NestedValue::Value(5, 4)));
     }
+
+    #[test]
+    fn test_two_tier_labels() {
+        let coord = (0..2).nested_coord(|_| 0..10);
+        assert_eq!(coord.key_points(BoldPoints(10)).len(), 3);
+        assert_eq!(coord.format_ext(&NestedValue::Category(1)), "1");
+
+        let coord = coord.two_tier_labels();
+        let points = coord.key_points(BoldPoints(10));
+        assert!(points.contains(&NestedValue::Category(1)));
+        assert!(points
+            .iter()
+            .any(|value| matches!(value, NestedValue::Value(1, _))));
+        assert_eq!(coord.format_ext(&NestedValue::Category(1)), "1");
+        assert_eq!(coord.key_point_tier(&NestedValue::Category(1)), 1);
+        assert_eq!(coord.key_point_tier(&NestedValue::Value(1, 5)), 0);
+        assert_eq!(coord.format_ext(&NestedValue::Value(1, 5)), "5");
+    }
+
+    #[test]
+    fn test_nested_axis_markers() {
+        use crate::coord::combinators::IntoLinLogRange;
+
+        let coord = (0..1).nested_coord(|_| (0.0..1e3).linlog_scale(10.0).linear_portion(0.5));
+        assert_eq!(coord.axis_markers((0, 200)), [50, 150]);
+    }
 }
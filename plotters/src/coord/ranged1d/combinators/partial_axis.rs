@@ -50,6 +50,18 @@ where
 
         left.min(right)..left.max(right)
     }
+
+    fn axis_breaks(&self, limit: (i32, i32)) -> Vec<Range<i32>> {
+        self.0.axis_breaks(limit)
+    }
+
+    fn axis_markers(&self, limit: (i32, i32)) -> Vec<i32> {
+        self.0.axis_markers(limit)
+    }
+
+    fn key_point_tier(&self, value: &Self::ValueType) -> usize {
+        self.0.key_point_tier(value)
+    }
 }
 
 impl<R: DiscreteRanged> DiscreteRanged for PartialAxis<R>
@@ -70,6 +82,8 @@ where
     }
 }
 
+impl_continuous_unmap!(PartialAxis, |this, input, limit| this.0.unmap(input, limit));
+
 /// Make a partial axis based on the percentage of visible portion.
 /// We can use `into_partial_axis` to create a partial axis range specification.
 /// But sometimes, we want to directly specify the percentage visible to the user.
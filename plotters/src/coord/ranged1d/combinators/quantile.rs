@@ -0,0 +1,287 @@
+use crate::coord::ranged1d::types::RangedCoordf64;
+use crate::coord::ranged1d::{
+    AsRangedCoord, KeyPointHint, NoDefaultFormatting, Ranged, ReversibleRanged, ValueFormatter,
+};
+use num_traits::{NumCast, ToPrimitive};
+use std::ops::Range;
+
+/// The quantile levels which are tried for the evenly spaced key points, from coarse to fine
+const LEVEL_STEPS: [f64; 7] = [0.5, 0.25, 0.2, 0.1, 0.05, 0.02, 0.01];
+
+/// A coordinate that maps the values through the empirical cumulative distribution of a data
+/// sample, i.e. to their ranks in the sample, so a heavily skewed distribution spreads evenly
+/// across the axis.
+///
+/// The value at the `p` quantile of the sample is mapped to the fraction `p` of the axis, and the
+/// values between the sample points are interpolated linearly. The key points, thus the grid
+/// lines and the labels, are placed at evenly spaced quantiles, or at the quantiles given by
+/// [QuantileCoord::quantiles], and the labels still show the original values.
+///
+/// ```rust
+/// use plotters::prelude::*;
+///
+/// let data: Vec<f64> = (1..=100).map(|x| (x as f64 / 10.0).exp()).collect();
+///
+/// let mut buffer = vec![0; 1024 * 768 * 3];
+/// let root = BitMapBackend::with_buffer(&mut buffer, (1024, 768)).into_drawing_area();
+///
+/// let mut chart = ChartBuilder::on(&root)
+///     .x_label_area_size(30)
+///     .y_label_area_size(50)
+///     .build_cartesian_2d(
+///         (1.0..22027.0)
+///             .quantile_scale(data.iter().copied())
+///             .quantiles(&[0.0, 0.5, 0.9, 0.99]),
+///         0.0..1.0,
+///     )
+///     .unwrap();
+///
+/// chart.configure_mesh().draw().unwrap();
+/// chart
+///     .draw_series(data.iter().map(|x| Circle::new((*x, 0.5), 3, BLUE.filled())))
+///     .unwrap();
+/// ```
+#[derive(Clone)]
+pub struct QuantileCoord<R: Ranged> {
+    inner: R,
+    /// The distinct sample values with their cumulative fractions, both strictly increasing
+    knots: Vec<(f64, f64)>,
+    levels: Option<Vec<f64>>,
+    fraction: RangedCoordf64,
+}
+
+/// The trait for the ranges that can be converted into a quantile coordinate
+pub trait IntoQuantileScale<V: ToPrimitive>: AsRangedCoord<Value = V> {
+    /// Make a coordinate that maps the values through the empirical distribution of the given
+    /// sample, see the documentation of [QuantileCoord] for details.
+    ///
+    /// - `data`: The sample, the values which are not finite are ignored
+    /// - **returns**: The newly created quantile coordinate, whose range is `self`
+    fn quantile_scale<I: IntoIterator<Item = V>>(
+        self,
+        data: I,
+    ) -> QuantileCoord<Self::CoordDescType>;
+}
+
+impl<V: ToPrimitive> IntoQuantileScale<V> for Range<V>
+where
+    Range<V>: AsRangedCoord<Value = V>,
+{
+    fn quantile_scale<I: IntoIterator<Item = V>>(
+        self,
+        data: I,
+    ) -> QuantileCoord<Self::CoordDescType> {
+        QuantileCoord {
+            inner: self.into(),
+            knots: cumulative_knots(data.into_iter().filter_map(|value| value.to_f64())),
+            levels: None,
+            fraction: (0.0..1.0).into(),
+        }
+    }
+}
+
+/// Build the distinct values of the sample with their cumulative fractions, in which each value
+/// takes the middle rank of its ties
+fn cumulative_knots<I: Iterator<Item = f64>>(data: I) -> Vec<(f64, f64)> {
+    let mut values: Vec<f64> = data.filter(|value| value.is_finite()).collect();
+    values.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+    let mut knots: Vec<(f64, f64)> = vec![];
+    let mut begin = 0;
+    while begin < values.len() {
+        let end = values[begin..]
+            .iter()
+            .position(|value| *value != values[begin])
+            .map_or(values.len(), |len| begin + len);
+        knots.push((values[begin], (begin + end - 1) as f64 / 2.0));
+        begin = end;
+    }
+
+    if let (Some(first), Some(last)) = (knots.first().copied(), knots.last().copied()) {
+        let span = last.1 - first.1;
+        for knot in knots.iter_mut() {
+            knot.1 = if span > 0.0 {
+                (knot.1 - first.1) / span
+            } else {
+                0.5
+            };
+        }
+    }
+    knots
+}
+
+/// Interpolate linearly on the polyline defined by the points, which is sorted by `x`, and
+/// clamp the points outside of the polyline to the ends
+fn interpolate(points: &[(f64, f64)], x: f64) -> f64 {
+    let idx = points.partition_point(|(px, _)| *px <= x);
+    if idx == 0 {
+        return points[0].1;
+    }
+    if idx == points.len() {
+        return points[idx - 1].1;
+    }
+    let ((x0, y0), (x1, y1)) = (points[idx - 1], points[idx]);
+    y0 + (y1 - y0) * (x - x0) / (x1 - x0)
+}
+
+impl<R: Ranged> QuantileCoord<R>
+where
+    R::ValueType: ToPrimitive + NumCast,
+{
+    /// Set the quantiles at which the key points are placed
+    /// - `levels`: The quantile levels, between 0 and 1, for example `[0.5, 0.9, 0.99]`
+    /// - **returns**: The coordinate with the key points at the given quantiles
+    pub fn quantiles(mut self, levels: &[f64]) -> Self {
+        let mut levels: Vec<f64> = levels
+            .iter()
+            .copied()
+            .filter(|level| (0.0..=1.0).contains(level))
+            .collect();
+        levels.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        levels.dedup();
+        self.levels = Some(levels);
+        self
+    }
+
+    /// Get the cumulative fraction of the sample at the value, i.e. the quantile level of the value
+    pub fn level_of(&self, value: &R::ValueType) -> Option<f64> {
+        if self.knots.len() < 2 {
+            return None;
+        }
+        Some(interpolate(&self.knots, value.to_f64()?))
+    }
+
+    /// Get the value at the given quantile level of the sample
+    pub fn value_at(&self, level: f64) -> Option<R::ValueType> {
+        if self.knots.len() < 2 {
+            return None;
+        }
+        let inverse: Vec<_> = self.knots.iter().map(|&(x, y)| (y, x)).collect();
+        NumCast::from(interpolate(&inverse, level))
+    }
+
+    /// Pick the quantile levels of the key points
+    fn key_levels(&self, max_points: usize) -> Vec<f64> {
+        if let Some(levels) = &self.levels {
+            if max_points == 0 {
+                return vec![];
+            }
+            let stride = levels.len().saturating_sub(1) / max_points + 1;
+            return levels.iter().step_by(stride).copied().collect();
+        }
+        match LEVEL_STEPS
+            .iter()
+            .rev()
+            .find(|step| ((1.0 / *step).round() as usize) < max_points)
+        {
+            Some(step) => {
+                let n = (1.0 / step).round() as usize;
+                (0..=n).map(|i| i as f64 / n as f64).collect()
+            }
+            None if max_points > 0 => vec![0.5],
+            None => vec![],
+        }
+    }
+}
+
+impl<T, R: Ranged<ValueType = T> + ValueFormatter<T>> ValueFormatter<T> for QuantileCoord<R> {
+    fn format(value: &T) -> String {
+        R::format(value)
+    }
+
+    fn format_ext(&self, value: &T) -> String {
+        self.inner.format_ext(value)
+    }
+}
+
+impl<R: Ranged> Ranged for QuantileCoord<R>
+where
+    R::ValueType: ToPrimitive + NumCast + PartialEq,
+{
+    type FormatOption = NoDefaultFormatting;
+    type ValueType = R::ValueType;
+
+    fn map(&self, value: &Self::ValueType, limit: (i32, i32)) -> i32 {
+        match self.level_of(value) {
+            Some(level) => self.fraction.map(&level, limit),
+            None => self.inner.map(value, limit),
+        }
+    }
+
+    fn key_points<Hint: KeyPointHint>(&self, hint: Hint) -> Vec<Self::ValueType> {
+        if self.knots.len() < 2 {
+            return self.inner.key_points(hint);
+        }
+        let mut points: Vec<Self::ValueType> = self
+            .key_levels(hint.max_num_points())
+            .into_iter()
+            .filter_map(|level| self.value_at(level))
+            .collect();
+        points.dedup();
+        points
+    }
+
+    fn range(&self) -> Range<Self::ValueType> {
+        self.inner.range()
+    }
+
+    fn axis_markers(&self, limit: (i32, i32)) -> Vec<i32> {
+        if self.knots.len() < 2 {
+            return self.inner.axis_markers(limit);
+        }
+        /* The scale of the inner coordinate isn't used when there's a sample */
+        vec![]
+    }
+}
+
+impl<R: ReversibleRanged> ReversibleRanged for QuantileCoord<R>
+where
+    R::ValueType: ToPrimitive + NumCast + PartialEq,
+{
+    fn unmap(&self, input: i32, limit: (i32, i32)) -> Option<Self::ValueType> {
+        if self.knots.len() < 2 {
+            return self.inner.unmap(input, limit);
+        }
+        self.value_at(self.fraction.unmap(input, limit)?)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::coord::ranged1d::BoldPoints;
+
+    #[test]
+    fn test_quantile_coord() {
+        let data = [1.0, 2.0, 3.0, 4.0, 1000.0];
+        let coord = (0.0..1000.0).quantile_scale(data.iter().copied());
+
+        assert_eq!(coord.range(), 0.0..1000.0);
+        assert_eq!(coord.map(&1.0, (0, 100)), 0);
+        assert_eq!(coord.map(&3.0, (0, 100)), 50);
+        assert_eq!(coord.map(&4.0, (0, 100)), 75);
+        assert_eq!(coord.map(&502.0, (0, 100)), 87);
+        assert_eq!(coord.map(&2000.0, (0, 100)), 100);
+        assert_eq!(
+            coord.key_points(BoldPoints(5)),
+            [1.0, 2.0, 3.0, 4.0, 1000.0]
+        );
+        assert_eq!(coord.key_points(BoldPoints(3)), [1.0, 3.0, 1000.0]);
+        assert_eq!(coord.unmap(50, (0, 100)), Some(3.0));
+
+        let coord = coord.quantiles(&[0.5, 0.875, 2.0]);
+        assert_eq!(coord.key_points(BoldPoints(5)), [3.0, 502.0]);
+        assert_eq!(coord.key_points(BoldPoints(1)), [3.0]);
+
+        let ties = (0..10).quantile_scale(vec![0, 5, 5, 5, 10]);
+        assert_eq!(ties.level_of(&5), Some(0.5));
+        assert_eq!(ties.map(&5, (0, 100)), 50);
+
+        let empty = (0.0..10.0).quantile_scale(vec![f64::NAN]);
+        assert_eq!(empty.map(&5.0, (0, 100)), 50);
+        assert_eq!(
+            empty.key_points(3),
+            RangedCoordf64::from(0.0..10.0).key_points(3)
+        );
+    }
+}
@@ -0,0 +1,132 @@
+use crate::coord::ranged1d::{
+    AsRangedCoord, DiscreteRanged, KeyPointHint, NoDefaultFormatting, Ranged, ValueFormatter,
+};
+use std::ops::Range;
+
+/// An axis decorator that flips the direction of the axis.
+///
+/// The larger values are mapped to the left of the X axis or the bottom of the Y axis, which is
+/// useful for the depth profiles and the rank charts, where the Y axis should increase downward.
+///
+/// ```rust
+/// use plotters::prelude::*;
+///
+/// let mut buffer = vec![0; 1024 * 768 * 3];
+/// let root = BitMapBackend::with_buffer(&mut buffer, (1024, 768)).into_drawing_area();
+///
+/// let mut chart = ChartBuilder::on(&root)
+///     .x_label_area_size(30)
+///     .y_label_area_size(50)
+///     .build_cartesian_2d(0.0..20.0, (0.0..1000.0).reversed())
+///     .unwrap();
+///
+/// chart.configure_mesh().y_desc("Depth").draw().unwrap();
+/// ```
+#[derive(Clone)]
+pub struct Reversed<R: Ranged>(R);
+
+/// The trait for the types that can be converted into a reversed axis
+pub trait IntoReversedAxis: AsRangedCoord {
+    /// Make a reversed axis
+    ///
+    /// - **returns**: The converted range specification
+    fn reversed(self) -> Reversed<Self::CoordDescType> {
+        Reversed(self.into())
+    }
+}
+
+impl<R: AsRangedCoord> IntoReversedAxis for R {}
+
+impl<T, R: Ranged<ValueType = T> + ValueFormatter<T>> ValueFormatter<T> for Reversed<R> {
+    fn format(value: &T) -> String {
+        R::format(value)
+    }
+
+    fn format_ext(&self, value: &T) -> String {
+        self.0.format_ext(value)
+    }
+}
+
+impl<R: Ranged> Ranged for Reversed<R> {
+    type FormatOption = NoDefaultFormatting;
+    type ValueType = R::ValueType;
+
+    fn map(&self, value: &Self::ValueType, limit: (i32, i32)) -> i32 {
+        self.0.map(value, (limit.1, limit.0))
+    }
+
+    fn key_points<Hint: KeyPointHint>(&self, hint: Hint) -> Vec<Self::ValueType> {
+        self.0.key_points(hint)
+    }
+
+    fn range(&self) -> Range<Self::ValueType> {
+        self.0.range()
+    }
+
+    fn axis_pixel_range(&self, limit: (i32, i32)) -> Range<i32> {
+        self.0.axis_pixel_range((limit.1, limit.0))
+    }
+
+    fn axis_breaks(&self, limit: (i32, i32)) -> Vec<Range<i32>> {
+        self.0.axis_breaks((limit.1, limit.0))
+    }
+
+    fn axis_markers(&self, limit: (i32, i32)) -> Vec<i32> {
+        self.0.axis_markers((limit.1, limit.0))
+    }
+
+    fn key_point_tier(&self, value: &Self::ValueType) -> usize {
+        self.0.key_point_tier(value)
+    }
+}
+
+impl_continuous_unmap!(Reversed, |this, input, limit| this
+    .0
+    .unmap(input, (limit.1, limit.0)));
+
+impl<R: DiscreteRanged> DiscreteRanged for Reversed<R> {
+    fn size(&self) -> usize {
+        self.0.size()
+    }
+
+    fn index_of(&self, value: &Self::ValueType) -> Option<usize> {
+        self.0.index_of(value)
+    }
+
+    fn from_index(&self, index: usize) -> Option<Self::ValueType> {
+        self.0.from_index(index)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::coord::combinators::IntoLogRange;
+    use crate::coord::ranged1d::types::{RangedCoordf64, RangedCoordi32};
+    use crate::coord::ranged1d::{BoldPoints, ReversibleRanged};
+
+    #[test]
+    fn test_reversed_axis() {
+        let coord: Reversed<_> = (0..10).reversed();
+        assert_eq!(coord.map(&0, (0, 100)), 100);
+        assert_eq!(coord.map(&10, (0, 100)), 0);
+        assert_eq!(coord.map(&3, (100, 0)), 30);
+        assert_eq!(coord.range(), 0..10);
+        let plain: RangedCoordi32 = (0..10).into();
+        assert_eq!(
+            coord.key_points(BoldPoints(3)),
+            plain.key_points(BoldPoints(3))
+        );
+        assert_eq!(coord.axis_pixel_range((0, 100)), 0..100);
+        assert_eq!(coord.index_of(&3), Some(3));
+
+        let coord = (0.0..10.0).reversed();
+        assert_eq!(coord.unmap(100, (0, 100)), Some(0.0));
+        let plain: RangedCoordf64 = (0.0..10.0).into();
+        assert_eq!(coord.format_ext(&2.5), plain.format_ext(&2.5));
+
+        let coord = (1.0f64..1000.0).log_scale().reversed();
+        let value = coord.unmap(coord.map(&10.0, (0, 300)), (0, 300)).unwrap();
+        assert!((value - 10.0).abs() < 1e-6);
+    }
+}
@@ -0,0 +1,202 @@
+use crate::coord::ranged1d::types::RangedCoordi128;
+use crate::coord::ranged1d::{
+    AsRangedCoord, DiscreteRanged, KeyPointHint, NoDefaultFormatting, Ranged, ValueFormatter,
+};
+use num_traits::{NumCast, PrimInt, ToPrimitive};
+use std::ops::Range;
+
+/// An integer coordinate where only the multiples of a given step hold meaning, for example
+/// only the even values, or the values in thousands.
+///
+/// The range is extended to the multiples of the step, and the key points, thus the grid lines
+/// and the labels, are always picked from the multiples of the step. As a discrete coordinate,
+/// each value belongs to the bucket starting at the multiple of step right below it, so a
+/// histogram on the segmented version of this coordinate has one bar per step.
+///
+/// ```rust
+/// use plotters::prelude::*;
+///
+/// let mut buffer = vec![0; 1024 * 768 * 3];
+/// let root = BitMapBackend::with_buffer(&mut buffer, (1024, 768)).into_drawing_area();
+///
+/// let mut chart = ChartBuilder::on(&root)
+///     .x_label_area_size(30)
+///     .y_label_area_size(50)
+///     .build_cartesian_2d((0..9500).stepped(1000).into_segmented(), 0..10)
+///     .unwrap();
+///
+/// chart.configure_mesh().draw().unwrap();
+/// chart
+///     .draw_series(
+///         Histogram::vertical(&chart)
+///             .style(BLUE.filled())
+///             .data([(1200, 1), (1700, 1), (4100, 1)].iter().copied()),
+///     )
+///     .unwrap();
+/// ```
+#[derive(Clone)]
+pub struct Stepped<R: Ranged> {
+    inner: R,
+    step: R::ValueType,
+}
+
+/// The trait for the integer ranges that can be converted into a stepped coordinate
+pub trait IntoStepped<V: PrimInt>: AsRangedCoord<Value = V> {
+    /// Make a coordinate that only uses the multiples of the given step, see the documentation
+    /// of [Stepped] for details.
+    ///
+    /// - `step`: The step, which should be positive
+    /// - **returns**: The newly created stepped coordinate
+    fn stepped(self, step: V) -> Stepped<Self::CoordDescType>;
+}
+
+impl<V: PrimInt> IntoStepped<V> for Range<V>
+where
+    Range<V>: AsRangedCoord<Value = V>,
+{
+    fn stepped(self, step: V) -> Stepped<Self::CoordDescType> {
+        assert!(step > V::zero(), "The step should be positive");
+        let start = floor_multiple(self.start, step);
+        let end = floor_multiple(self.end, step);
+        let end = if end < self.end { end + step } else { end };
+        Stepped {
+            inner: (start..end).into(),
+            step,
+        }
+    }
+}
+
+/// Get the largest multiple of the step that is not greater than the value
+fn floor_multiple<V: PrimInt>(value: V, step: V) -> V {
+    let rem = value % step;
+    if rem < V::zero() {
+        value - rem - step
+    } else {
+        value - rem
+    }
+}
+
+impl<R: Ranged> Stepped<R>
+where
+    R::ValueType: PrimInt,
+{
+    /// Get the step of this coordinate
+    pub fn step(&self) -> R::ValueType {
+        self.step
+    }
+
+    /// The range of the step indices, i.e. the values divided by the step
+    fn quotients(&self) -> Range<i128> {
+        let range = self.inner.range();
+        let step = self.step.to_i128().unwrap();
+        range.start.to_i128().unwrap() / step..range.end.to_i128().unwrap() / step
+    }
+}
+
+impl<T, R: Ranged<ValueType = T> + ValueFormatter<T>> ValueFormatter<T> for Stepped<R> {
+    fn format(value: &T) -> String {
+        R::format(value)
+    }
+
+    fn format_ext(&self, value: &T) -> String {
+        self.inner.format_ext(value)
+    }
+}
+
+impl<R: Ranged> Ranged for Stepped<R>
+where
+    R::ValueType: PrimInt,
+{
+    type FormatOption = NoDefaultFormatting;
+    type ValueType = R::ValueType;
+
+    fn map(&self, value: &Self::ValueType, limit: (i32, i32)) -> i32 {
+        self.inner.map(value, limit)
+    }
+
+    fn key_points<Hint: KeyPointHint>(&self, hint: Hint) -> Vec<Self::ValueType> {
+        let step = self.step.to_i128().unwrap();
+        let quotients: RangedCoordi128 = self.quotients().into();
+        quotients
+            .key_points(hint)
+            .into_iter()
+            .filter_map(|q| NumCast::from(q * step))
+            .collect()
+    }
+
+    fn range(&self) -> Range<Self::ValueType> {
+        self.inner.range()
+    }
+
+    fn axis_pixel_range(&self, limit: (i32, i32)) -> Range<i32> {
+        self.inner.axis_pixel_range(limit)
+    }
+
+    fn axis_breaks(&self, limit: (i32, i32)) -> Vec<Range<i32>> {
+        self.inner.axis_breaks(limit)
+    }
+
+    fn axis_markers(&self, limit: (i32, i32)) -> Vec<i32> {
+        self.inner.axis_markers(limit)
+    }
+}
+
+impl<R: Ranged> DiscreteRanged for Stepped<R>
+where
+    R::ValueType: PrimInt,
+{
+    fn size(&self) -> usize {
+        let quotients = self.quotients();
+        (quotients.end - quotients.start + 1) as usize
+    }
+
+    fn index_of(&self, value: &Self::ValueType) -> Option<usize> {
+        let range = self.inner.range();
+        if *value < range.start || *value > range.end {
+            return None;
+        }
+        let offset = floor_multiple(*value, self.step) - range.start;
+        (offset / self.step).to_usize()
+    }
+
+    fn from_index(&self, index: usize) -> Option<Self::ValueType> {
+        if index >= self.size() {
+            return None;
+        }
+        let range = self.inner.range();
+        let index: Self::ValueType = NumCast::from(index)?;
+        Some(range.start + index * self.step)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::coord::ranged1d::{BoldPoints, IntoSegmentedCoord, SegmentValue};
+
+    #[test]
+    fn test_stepped_coord() {
+        let coord = (3..95).stepped(10);
+
+        assert_eq!(coord.range(), 0..100);
+        assert_eq!(coord.step(), 10);
+        assert_eq!(coord.size(), 11);
+        assert_eq!(coord.index_of(&37), Some(3));
+        assert_eq!(coord.index_of(&101), None);
+        assert_eq!(coord.from_index(3), Some(30));
+        assert_eq!(coord.from_index(11), None);
+        assert_eq!(coord.map(&50, (0, 100)), 50);
+
+        assert_eq!(coord.key_points(BoldPoints(4)), [0, 50, 100]);
+        assert!(coord.key_points(100).iter().all(|v| v % 10 == 0));
+
+        let coord = (-25..25).stepped(2);
+        assert_eq!(coord.range(), -26..26);
+        assert_eq!(coord.index_of(&-25), Some(0));
+        assert!(coord.key_points(5).iter().all(|v| v % 2 == 0));
+
+        let coord = (0u32..9500).stepped(1000).into_segmented();
+        assert_eq!(coord.size(), 12);
+        assert_eq!(coord.index_of(&SegmentValue::Exact(1700)), Some(1));
+    }
+}
@@ -0,0 +1,284 @@
+use super::broken_axis::SegmentHint;
+use crate::coord::ranged1d::{
+    AsRangedCoord, KeyPointHint, NoDefaultFormatting, Ranged, ReversibleRanged, ValueFormatter,
+};
+use num_traits::ToPrimitive;
+use std::ops::Range;
+
+/// An axis decorator that stitches multiple disjoint sub-ranges into one contiguous axis.
+///
+/// The gaps between the sub-ranges are skipped, so the end of one sub-range and the start of
+/// the next one are mapped to the same pixel, and the values in the gaps are mapped to the start
+/// of the next sub-range. This is useful for the intraday financial charts, where only the
+/// trading hours should be shown. Unlike [BrokenAxis](struct.BrokenAxis.html), the axis line
+/// isn't broken. The key points are generated per sub-range, and when there are too many
+/// sub-ranges for the requested number of key points, only the starts of some sub-ranges are used.
+///
+/// ```rust
+/// use plotters::prelude::*;
+///
+/// let mut buffer = vec![0; 1024 * 768 * 3];
+/// let root = BitMapBackend::with_buffer(&mut buffer, (1024, 768)).into_drawing_area();
+///
+/// // The trading hours of three days, in hours since the start of the first day
+/// let sessions = vec![9.5..16.0, 33.5..40.0, 57.5..64.0];
+/// let mut chart = ChartBuilder::on(&root)
+///     .x_label_area_size(30)
+///     .y_label_area_size(50)
+///     .build_cartesian_2d(sessions.stitched_axis().unwrap(), 0.0..100.0)
+///     .unwrap();
+///
+/// chart.configure_mesh().draw().unwrap();
+/// ```
+#[derive(Clone)]
+pub struct StitchedAxis<R: Ranged> {
+    segments: Vec<R>,
+    weights: Vec<f64>,
+}
+
+/// The trait for the lists of sub-ranges that can be stitched into one axis
+pub trait IntoStitchedAxis {
+    /// The type of the coordinate spec of each sub-range
+    type CoordDescType: Ranged;
+
+    /// Make a stitched axis, each sub-range takes the same amount of pixels by default
+    ///
+    /// - **returns**: The converted range specification, or `None` if there's no sub-range
+    fn stitched_axis(self) -> Option<StitchedAxis<Self::CoordDescType>>;
+}
+
+impl<T: AsRangedCoord> IntoStitchedAxis for Vec<T> {
+    type CoordDescType = T::CoordDescType;
+
+    fn stitched_axis(self) -> Option<StitchedAxis<T::CoordDescType>> {
+        if self.is_empty() {
+            return None;
+        }
+        let weights = vec![1.0; self.len()];
+        Some(StitchedAxis {
+            segments: self.into_iter().map(Into::into).collect(),
+            weights,
+        })
+    }
+}
+
+impl<R: Ranged> StitchedAxis<R> {
+    /// Set the relative amount of the pixels used by each sub-range
+    ///
+    /// - `weights`: The weight of each sub-range, the missing and the non-positive weights are
+    ///   treated as 1
+    /// - **returns**: The modified stitched axis
+    pub fn weights(mut self, weights: &[f64]) -> Self {
+        for (i, weight) in self.weights.iter_mut().enumerate() {
+            *weight = weights.get(i).copied().filter(|w| *w > 0.0).unwrap_or(1.0);
+        }
+        self
+    }
+
+    /// Make the amount of the pixels used by each sub-range proportional to its length, so that
+    /// the scale is the same across all the sub-ranges
+    ///
+    /// - **returns**: The modified stitched axis
+    pub fn proportional(self) -> Self
+    where
+        R::ValueType: ToPrimitive,
+    {
+        let weights: Vec<_> = self
+            .segments
+            .iter()
+            .map(|segment| {
+                let range = segment.range();
+                match (range.start.to_f64(), range.end.to_f64()) {
+                    (Some(start), Some(end)) => (end - start).abs(),
+                    _ => 1.0,
+                }
+            })
+            .collect();
+        self.weights(&weights)
+    }
+
+    /// Get the pixel limits of each sub-range
+    fn segment_limits(&self, limit: (i32, i32)) -> Vec<(i32, i32)> {
+        let total: f64 = self.weights.iter().sum();
+        let size = f64::from(limit.1 - limit.0);
+        let mut acc = 0.0;
+        let mut start = limit.0;
+        self.weights
+            .iter()
+            .map(|weight| {
+                acc += weight;
+                let end = limit.0 + (size * acc / total).round() as i32;
+                let segment = (start, end);
+                start = end;
+                segment
+            })
+            .collect()
+    }
+}
+
+impl<T, R: Ranged<ValueType = T> + ValueFormatter<T>> ValueFormatter<T> for StitchedAxis<R> {
+    fn format(value: &T) -> String {
+        R::format(value)
+    }
+
+    fn format_ext(&self, value: &T) -> String {
+        match self.segments.first() {
+            Some(segment) => segment.format_ext(value),
+            None => R::format(value),
+        }
+    }
+}
+
+impl<R: Ranged> Ranged for StitchedAxis<R>
+where
+    R::ValueType: PartialOrd,
+{
+    type FormatOption = NoDefaultFormatting;
+    type ValueType = R::ValueType;
+
+    fn map(&self, value: &Self::ValueType, limit: (i32, i32)) -> i32 {
+        let limits = self.segment_limits(limit);
+        let last = self.segments.len().saturating_sub(1);
+        for (idx, (segment, segment_limit)) in self.segments.iter().zip(limits).enumerate() {
+            let range = segment.range();
+            if *value < range.start {
+                /* The value is in the gap before this sub-range, or before the whole axis */
+                return if idx == 0 {
+                    segment.map(value, segment_limit)
+                } else {
+                    segment_limit.0
+                };
+            }
+            if *value < range.end || idx == last {
+                return segment.map(value, segment_limit);
+            }
+        }
+        limit.0
+    }
+
+    fn key_points<Hint: KeyPointHint>(&self, hint: Hint) -> Vec<Self::ValueType> {
+        let total: f64 = self.weights.iter().sum();
+        let share = |count: usize, weight: f64| (count as f64 * weight / total).floor() as usize;
+        let max_points = hint.max_num_points();
+        let light = hint.weight().allow_light_points();
+
+        if self
+            .weights
+            .iter()
+            .all(|weight| share(max_points, *weight) > 0)
+        {
+            let last = self.segments.len().saturating_sub(1);
+            let mut ret = vec![];
+            for (idx, (segment, weight)) in self.segments.iter().zip(&self.weights).enumerate() {
+                let end = segment.range().end;
+                ret.extend(
+                    segment
+                        .key_points(SegmentHint {
+                            max_points: share(max_points, *weight),
+                            bold_points: share(hint.bold_points(), *weight).max(1),
+                            light,
+                        })
+                        .into_iter()
+                        /* The end of the sub-range is the same pixel as the start of the next one */
+                        .filter(|v| idx == last || *v < end),
+                );
+            }
+            return ret;
+        }
+
+        if max_points == 0 {
+            return vec![];
+        }
+        let step = self.segments.len().saturating_sub(1) / max_points + 1;
+        self.segments
+            .iter()
+            .step_by(step)
+            .map(|segment| segment.range().start)
+            .collect()
+    }
+
+    fn range(&self) -> Range<Self::ValueType> {
+        /* There's always a sub-range, the empty lists are rejected by stitched_axis */
+        self.segments[0].range().start..self.segments[self.segments.len() - 1].range().end
+    }
+
+    fn axis_markers(&self, limit: (i32, i32)) -> Vec<i32> {
+        self.segments
+            .iter()
+            .zip(self.segment_limits(limit))
+            .flat_map(|(segment, segment_limit)| segment.axis_markers(segment_limit))
+            .collect()
+    }
+}
+
+impl<R: ReversibleRanged> ReversibleRanged for StitchedAxis<R>
+where
+    R::ValueType: PartialOrd,
+{
+    fn unmap(&self, input: i32, limit: (i32, i32)) -> Option<Self::ValueType> {
+        let (low, high) = (limit.0.min(limit.1), limit.0.max(limit.1));
+        if input < low || input > high {
+            return None;
+        }
+        self.segments
+            .iter()
+            .zip(self.segment_limits(limit))
+            .filter(|(_, (start, end))| start != end)
+            .find(|(_, (start, end))| *start.min(end) <= input && input <= *start.max(end))
+            .and_then(|(segment, segment_limit)| segment.unmap(input, segment_limit))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_stitched_axis() {
+        assert!(Vec::<Range<f64>>::new().stitched_axis().is_none());
+
+        let coord = vec![0.0..10.0, 20.0..30.0, 40.0..50.0]
+            .stitched_axis()
+            .unwrap();
+
+        assert_eq!(coord.range(), 0.0..50.0);
+        assert_eq!(coord.map(&0.0, (0, 90)), 0);
+        assert_eq!(coord.map(&5.0, (0, 90)), 15);
+        assert_eq!(coord.map(&10.0, (0, 90)), 30);
+        assert_eq!(coord.map(&15.0, (0, 90)), 30);
+        assert_eq!(coord.map(&20.0, (0, 90)), 30);
+        assert_eq!(coord.map(&45.0, (0, 90)), 75);
+        assert_eq!(coord.map(&50.0, (0, 90)), 90);
+        assert_eq!(coord.map(&45.0, (90, 0)), 15);
+
+        let key_points = coord.key_points(9);
+        assert!(key_points.len() <= 9);
+        assert!(key_points.iter().all(|v| !(10.0..20.0).contains(v)));
+        assert!(key_points.iter().all(|v| !(30.0..40.0).contains(v)));
+        assert!(key_points.iter().any(|v| *v >= 40.0));
+
+        assert_eq!(coord.key_points(2), [0.0, 40.0]);
+        assert_eq!(coord.unmap(15, (0, 90)), Some(5.0));
+        assert_eq!(coord.unmap(45, (0, 90)), Some(25.0));
+        assert_eq!(coord.unmap(15, (90, 0)), Some(45.0));
+        assert_eq!(coord.unmap(100, (0, 90)), None);
+
+        let coord = vec![0..10, 20..50].stitched_axis().unwrap().proportional();
+        assert_eq!(coord.map(&10, (0, 80)), 20);
+        let coord = vec![0..10, 20..50].stitched_axis().unwrap().weights(&[3.0]);
+        assert_eq!(coord.map(&10, (0, 80)), 60);
+    }
+
+    #[test]
+    fn test_stitched_axis_markers() {
+        use crate::coord::combinators::IntoLinLogRange;
+
+        let coord = vec![
+            (0.0..1e3).linlog_scale(10.0).linear_portion(0.5),
+            (2e3..3e5).linlog_scale(1e4).linear_portion(0.5),
+        ]
+        .stitched_axis()
+        .unwrap();
+        assert_eq!(coord.axis_markers((0, 100)), [25, 75]);
+    }
+}
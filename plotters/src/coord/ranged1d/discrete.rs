@@ -134,6 +134,14 @@ where
             _ => "".to_string(),
         }
     }
+
+    fn format_ext(&self, value: &SegmentValue<T>) -> String {
+        match value {
+            SegmentValue::Exact(ref value) => self.0.format_ext(value),
+            SegmentValue::CenterOf(ref value) => self.0.format_ext(value),
+            _ => "".to_string(),
+        }
+    }
 }
 
 impl<D: DiscreteRanged> Ranged for SegmentedCoord<D> {
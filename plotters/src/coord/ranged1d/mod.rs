@@ -216,6 +216,26 @@ pub trait Ranged {
             limit.1..limit.0
         }
     }
+
+    /// This function provides the pixel ranges where the axis is broken, for example by
+    /// [BrokenAxis](../combinators/struct.BrokenAxis.html). The axis line is not drawn there.
+    fn axis_breaks(&self, _limit: (i32, i32)) -> Vec<Range<i32>> {
+        vec![]
+    }
+
+    /// This function provides the pixel positions where the scale of the axis changes, for
+    /// example the pivot of [LinLogCoord](../combinators/struct.LinLogCoord.html). The mesh draws
+    /// a marker across the axis line there.
+    fn axis_markers(&self, _limit: (i32, i32)) -> Vec<i32> {
+        vec![]
+    }
+
+    /// Get the tier of the label of the key point. The labels on the tier 0 are next to the
+    /// axis, and each further tier is put outside of the previous one, for example the category
+    /// labels of [NestedRange::two_tier_labels](../combinators/struct.NestedRange.html#method.two_tier_labels).
+    fn key_point_tier(&self, _value: &Self::ValueType) -> usize {
+        0
+    }
 }
 
 /// The trait indicates the ranged value can be map reversely, which means
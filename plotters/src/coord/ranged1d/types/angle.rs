@@ -0,0 +1,246 @@
+use std::f64::consts::PI;
+use std::ops::Range;
+
+use crate::coord::ranged1d::{
+    KeyPointHint, NoDefaultFormatting, Ranged, ReversibleRanged, ValueFormatter,
+};
+use crate::coord::types::RangedCoordf64;
+use crate::data::float::pretty_print_float;
+
+/// The denominator of the finest fraction of π used by the labels
+const PI_DENOMINATOR: i64 = 12;
+
+/// The unit of the values of [RangedAngle]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AngleUnit {
+    /// The values are in radians, and the labels are the fractions of π, such as `3π/4`
+    Radians,
+    /// The values are in degrees, and the labels carry the degree sign, such as `45°`
+    Degrees,
+}
+
+impl AngleUnit {
+    /// Get the steps of the key points, from the finest to the coarsest
+    fn steps(&self) -> [f64; 6] {
+        match self {
+            AngleUnit::Radians => [PI / 12.0, PI / 6.0, PI / 4.0, PI / 2.0, PI, 2.0 * PI],
+            AngleUnit::Degrees => [15.0, 30.0, 45.0, 90.0, 180.0, 360.0],
+        }
+    }
+}
+
+/// The ranged coordinate for the angles, which is used for the plots of the trigonometric
+/// functions.
+///
+/// The key points are placed at the multiples of π/4, π/2, π, etc. for the radians, or at the
+/// multiples of 30°, 45°, 90°, etc. for the degrees, and the labels of the radians are the
+/// fractions of π, such as `π/2` and `3π/4`. A range shorter than the finest step falls back to
+/// the key points of a plain numeric axis.
+///
+/// ```rust
+/// use plotters::prelude::*;
+/// use std::f64::consts::PI;
+///
+/// let mut buffer = vec![0; 1024 * 768 * 3];
+/// let root = BitMapBackend::with_buffer(&mut buffer, (1024, 768)).into_drawing_area();
+///
+/// let mut chart = ChartBuilder::on(&root)
+///     .x_label_area_size(30)
+///     .y_label_area_size(40)
+///     .build_cartesian_2d((0.0..2.0 * PI).radians(), -1.0..1.0)
+///     .unwrap();
+///
+/// chart.configure_mesh().draw().unwrap();
+/// chart
+///     .draw_series(LineSeries::new(
+///         (0..=100).map(|i| i as f64 * PI / 50.0).map(|x| (x, x.sin())),
+///         &RED,
+///     ))
+///     .unwrap();
+/// ```
+#[derive(Clone)]
+pub struct RangedAngle {
+    inner: RangedCoordf64,
+    unit: AngleUnit,
+}
+
+/// The trait for the ranges that can be converted into an angle coordinate
+pub trait IntoAngleCoord {
+    /// Make the angle coordinate of which the values are in radians
+    fn radians(self) -> RangedAngle;
+    /// Make the angle coordinate of which the values are in degrees
+    fn degrees(self) -> RangedAngle;
+}
+
+impl IntoAngleCoord for Range<f64> {
+    fn radians(self) -> RangedAngle {
+        RangedAngle {
+            inner: self.into(),
+            unit: AngleUnit::Radians,
+        }
+    }
+
+    fn degrees(self) -> RangedAngle {
+        RangedAngle {
+            inner: self.into(),
+            unit: AngleUnit::Degrees,
+        }
+    }
+}
+
+impl RangedAngle {
+    /// Get the unit of the values
+    pub fn unit(&self) -> AngleUnit {
+        self.unit
+    }
+}
+
+/// Format the angle in radians as a fraction of π, if it's close to a multiple of the finest
+/// fraction
+fn format_radians(value: f64) -> Option<String> {
+    let multiple = value / PI * PI_DENOMINATOR as f64;
+    let numerator = multiple.round();
+    if (multiple - numerator).abs() > 1e-6 {
+        return None;
+    }
+
+    let mut numerator = numerator as i64;
+    let mut denominator = PI_DENOMINATOR;
+    let divisor = gcd(numerator.abs(), denominator);
+    if divisor > 0 {
+        numerator /= divisor;
+        denominator /= divisor;
+    }
+
+    let coefficient = match numerator {
+        0 => return Some("0".to_string()),
+        1 => "".to_string(),
+        -1 => "-".to_string(),
+        n => n.to_string(),
+    };
+    Some(if denominator == 1 {
+        format!("{}\u{3c0}", coefficient)
+    } else {
+        format!("{}\u{3c0}/{}", coefficient, denominator)
+    })
+}
+
+fn gcd(a: i64, b: i64) -> i64 {
+    if b == 0 {
+        a
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+impl ValueFormatter<f64> for RangedAngle {
+    fn format(value: &f64) -> String {
+        format_radians(*value).unwrap_or_else(|| pretty_print_float(*value, false))
+    }
+
+    fn format_ext(&self, value: &f64) -> String {
+        match self.unit {
+            AngleUnit::Radians => Self::format(value),
+            AngleUnit::Degrees => format!("{}\u{b0}", pretty_print_float(*value, false)),
+        }
+    }
+}
+
+impl Ranged for RangedAngle {
+    type FormatOption = NoDefaultFormatting;
+    type ValueType = f64;
+
+    fn map(&self, value: &f64, limit: (i32, i32)) -> i32 {
+        self.inner.map(value, limit)
+    }
+
+    fn key_points<Hint: KeyPointHint>(&self, hint: Hint) -> Vec<f64> {
+        let max_points = hint.max_num_points();
+        let range = self.inner.range();
+        let (start, end) = (range.start.min(range.end), range.start.max(range.end));
+        if max_points == 0 || !(start.is_finite() && end.is_finite()) {
+            return vec![];
+        }
+
+        let steps = self.unit.steps();
+        let count =
+            |step: f64| ((end / step + 1e-9).floor() - (start / step - 1e-9).ceil()) as i64 + 1;
+        if count(steps[0]) < 2 {
+            return self.inner.key_points(hint);
+        }
+
+        /* Pick the finest step that fits, and the multiples of the full turn beyond the steps */
+        let mut step = steps
+            .iter()
+            .copied()
+            .find(|step| count(*step) as usize <= max_points)
+            .unwrap_or(steps[steps.len() - 1]);
+        let turn = steps[steps.len() - 1];
+        let mut turns = 1.0;
+        while count(step) as usize > max_points {
+            turns += 1.0;
+            step = turn * turns;
+        }
+
+        let first = (start / step - 1e-9).ceil() as i64;
+        (0..count(step))
+            .map(|idx| (first + idx) as f64 * step)
+            .collect()
+    }
+
+    fn range(&self) -> Range<f64> {
+        self.inner.range()
+    }
+}
+
+impl ReversibleRanged for RangedAngle {
+    fn unmap(&self, input: i32, limit: (i32, i32)) -> Option<f64> {
+        self.inner.unmap(input, limit)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::coord::ranged1d::BoldPoints;
+
+    #[test]
+    fn test_angle_coord() {
+        let coord = (0.0..2.0 * PI).radians();
+        let labels: Vec<_> = coord
+            .key_points(BoldPoints(10))
+            .iter()
+            .map(|value| coord.format_ext(value))
+            .collect();
+        assert_eq!(
+            labels,
+            ["0", "π/4", "π/2", "3π/4", "π", "5π/4", "3π/2", "7π/4", "2π"]
+        );
+        assert_eq!(coord.key_points(BoldPoints(3)).len(), 3);
+        assert_eq!(coord.map(&PI, (0, 100)), 50);
+
+        let coord = (-4.0 * PI..4.0 * PI).radians();
+        let labels: Vec<_> = coord
+            .key_points(BoldPoints(5))
+            .iter()
+            .map(|value| coord.format_ext(value))
+            .collect();
+        assert_eq!(labels, ["-4π", "-2π", "0", "2π", "4π"]);
+        assert_eq!(RangedAngle::format(&-PI), "-π");
+        assert_eq!(RangedAngle::format(&1.0), "1");
+
+        let coord = (0.0..360.0).degrees();
+        assert_eq!(coord.unit(), AngleUnit::Degrees);
+        assert_eq!(
+            coord.key_points(BoldPoints(10)),
+            [0.0, 45.0, 90.0, 135.0, 180.0, 225.0, 270.0, 315.0, 360.0]
+        );
+        assert_eq!(coord.format_ext(&45.0), "45°");
+
+        let coord = (0.0..0.1).radians();
+        assert_eq!(
+            coord.key_points(BoldPoints(5)),
+            RangedCoordf64::from(0.0..0.1).key_points(BoldPoints(5))
+        );
+    }
+}
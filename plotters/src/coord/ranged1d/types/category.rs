@@ -0,0 +1,141 @@
+use crate::coord::ranged1d::{
+    DiscreteRanged, KeyPointHint, NoDefaultFormatting, Ranged, ValueFormatter,
+};
+use std::fmt::Display;
+use std::ops::Range;
+
+/// A categorical coordinate defined by an explicit list of categories.
+///
+/// Each category gets a pixel slot of the same size, in the order of the list, and the category
+/// is mapped to the center of its slot, so the labels, the box plots and the points are centered
+/// in their slots. The labels are formatted with the `Display` trait of the categories.
+///
+/// ```rust
+/// use plotters::prelude::*;
+/// use plotters::coord::types::CategoryCoord;
+///
+/// let mut buffer = vec![0; 1024 * 768 * 3];
+/// let root = BitMapBackend::with_buffer(&mut buffer, (1024, 768)).into_drawing_area();
+///
+/// let mut chart = ChartBuilder::on(&root)
+///     .x_label_area_size(30)
+///     .y_label_area_size(50)
+///     .build_cartesian_2d(CategoryCoord::from(vec!["apple", "banana", "cherry"]), 0..10)
+///     .unwrap();
+///
+/// chart.configure_mesh().draw().unwrap();
+/// chart
+///     .draw_series(vec![("apple", 3), ("cherry", 7)].into_iter().map(|p| Circle::new(p, 5, RED)))
+///     .unwrap();
+/// ```
+///
+/// Please note: the behavior of constructing an empty category list may cause panic
+#[derive(Clone)]
+pub struct CategoryCoord<T: PartialEq> {
+    categories: Vec<T>,
+}
+
+impl<T: PartialEq> CategoryCoord<T> {
+    /// Create a new categorical coordinate
+    ///
+    /// - `categories`: The categories in the order they are placed on the axis
+    /// - **returns**: The newly created coordinate
+    pub fn new<I: IntoIterator<Item = T>>(categories: I) -> Self {
+        Self {
+            categories: categories.into_iter().collect(),
+        }
+    }
+
+    /// Get the categories of this coordinate
+    pub fn categories(&self) -> &[T] {
+        &self.categories
+    }
+
+    /// Get the pixel slot of the category, which is the part of the axis owned by it
+    ///
+    /// - `value`: The category
+    /// - `limit`: The pixel range of the whole axis
+    /// - **returns**: The pixel range of the slot, or `None` if it's not one of the categories
+    pub fn slot(&self, value: &T, limit: (i32, i32)) -> Option<Range<i32>> {
+        let idx = self.categories.iter().position(|x| x == value)?;
+        let edge = |i: usize| {
+            let span = f64::from(limit.1 - limit.0);
+            (f64::from(limit.0) + span * i as f64 / self.categories.len() as f64).round() as i32
+        };
+        Some(edge(idx)..edge(idx + 1))
+    }
+}
+
+impl<T: PartialEq + Display> ValueFormatter<T> for CategoryCoord<T> {
+    fn format(value: &T) -> String {
+        value.to_string()
+    }
+}
+
+impl<T: PartialEq + Clone> Ranged for CategoryCoord<T> {
+    type FormatOption = NoDefaultFormatting;
+    type ValueType = T;
+
+    fn range(&self) -> Range<T> {
+        // If the category list is empty, we should always panic
+        self.categories[0].clone()..self.categories[self.categories.len() - 1].clone()
+    }
+
+    fn map(&self, value: &T, limit: (i32, i32)) -> i32 {
+        match self.slot(value, limit) {
+            Some(slot) => (slot.start + slot.end) / 2,
+            None => limit.0,
+        }
+    }
+
+    fn key_points<Hint: KeyPointHint>(&self, hint: Hint) -> Vec<T> {
+        let max_points = hint.max_num_points();
+        if max_points == 0 {
+            return vec![];
+        }
+        let step = self.categories.len().saturating_sub(1) / max_points + 1;
+        self.categories.iter().step_by(step).cloned().collect()
+    }
+}
+
+impl<T: PartialEq + Clone> DiscreteRanged for CategoryCoord<T> {
+    fn size(&self) -> usize {
+        self.categories.len()
+    }
+
+    fn index_of(&self, value: &T) -> Option<usize> {
+        self.categories.iter().position(|x| x == value)
+    }
+
+    fn from_index(&self, index: usize) -> Option<T> {
+        self.categories.get(index).cloned()
+    }
+}
+
+impl<T: PartialEq> From<Vec<T>> for CategoryCoord<T> {
+    fn from(categories: Vec<T>) -> Self {
+        Self { categories }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::coord::ranged1d::ReversibleRanged;
+
+    #[test]
+    fn test_category_coord() {
+        let coord: CategoryCoord<&str> = vec!["a", "b", "c", "d"].into();
+
+        assert_eq!(coord.range(), "a".."d");
+        assert_eq!(coord.map(&"a", (0, 100)), 12);
+        assert_eq!(coord.map(&"d", (0, 100)), 87);
+        assert_eq!(coord.map(&"x", (0, 100)), 0);
+        assert_eq!(coord.slot(&"b", (0, 100)), Some(25..50));
+        assert_eq!(coord.key_points(4), ["a", "b", "c", "d"]);
+        assert_eq!(coord.key_points(2), ["a", "c"]);
+        assert_eq!(coord.format_ext(&"b"), "b");
+        assert_eq!(coord.unmap(60, (0, 100)), Some("c"));
+        assert_eq!(coord.from_index(3), Some("d"));
+    }
+}
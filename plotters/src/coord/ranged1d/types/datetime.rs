@@ -1,6 +1,9 @@
 /// The datetime coordinates
-use chrono::{Date, DateTime, Datelike, Duration, NaiveDate, NaiveDateTime, TimeZone, Timelike};
+use chrono::{
+    Date, DateTime, Datelike, Duration, NaiveDate, NaiveDateTime, NaiveTime, TimeZone, Timelike,
+};
 use std::ops::{Add, Range, Sub};
+use std::time::Duration as StdDuration;
 
 use crate::coord::ranged1d::{
     AsRangedCoord, DefaultFormatting, DiscreteRanged, KeyPointHint, NoDefaultFormatting, Ranged,
@@ -10,7 +13,7 @@ use crate::coord::ranged1d::{
 /// The trait that describe some time value. This is the uniformed abstraction that works
 /// for both Date, DateTime and Duration, etc.
 pub trait TimeValue: Eq + Sized {
-    type DateType: Datelike + PartialOrd;
+    type DateType: Datelike + PartialOrd + Clone;
 
     /// Returns the date that is no later than the time
     fn date_floor(&self) -> Self::DateType;
@@ -27,6 +30,13 @@ pub trait TimeValue: Eq + Sized {
     /// Cast current date type into this type
     fn from_date(date: Self::DateType) -> Self;
 
+    /// Returns the time when the local wall clock shows the given duration after the midnight
+    /// of the date. For the time zones with the daylight saving time, this is different from
+    /// adding the duration to the midnight, and the time skipped by the clock doesn't exist.
+    fn local_time_after(date: Self::DateType, offset: Duration) -> Option<Self> {
+        Some(Self::from_date(date).add(&offset))
+    }
+
     /// Map the coord spec
     fn map_coord(value: &Self, begin: &Self, end: &Self, limit: (i32, i32)) -> i32 {
         let total_span = end.subtract(begin);
@@ -142,7 +152,7 @@ impl<Z: TimeZone> TimeValue for DateTime<Z> {
         }
     }
     fn earliest_after_date(date: Date<Z>) -> DateTime<Z> {
-        date.and_hms(0, 0, 0)
+        Self::from_date(date)
     }
 
     fn subtract(&self, other: &DateTime<Z>) -> Duration {
@@ -157,7 +167,15 @@ impl<Z: TimeZone> TimeValue for DateTime<Z> {
     }
 
     fn from_date(date: Self::DateType) -> Self {
-        date.and_hms(0, 0, 0)
+        // The midnight may be skipped by the daylight saving time, then the clock starts at 1 AM
+        Self::local_time_after(date.clone(), Duration::zero())
+            .or_else(|| Self::local_time_after(date.clone(), Duration::hours(1)))
+            .unwrap_or_else(|| date.and_hms(0, 0, 0))
+    }
+
+    fn local_time_after(date: Self::DateType, offset: Duration) -> Option<Self> {
+        let local = date.naive_local().and_hms(0, 0, 0) + offset;
+        date.timezone().from_local_datetime(&local).earliest()
     }
 }
 
@@ -222,40 +240,44 @@ where
     }
 
     fn key_points<HintType: KeyPointHint>(&self, hint: HintType) -> Vec<Self::ValueType> {
-        let max_points = hint.max_num_points();
-        let mut ret = vec![];
-
-        let total_days = (self.1.clone() - self.0.clone()).num_days();
-        let total_weeks = (self.1.clone() - self.0.clone()).num_weeks();
+        calendar_key_points(&self.0, &self.1, hint.max_num_points())
+    }
+}
 
-        if total_days > 0 && total_days as usize <= max_points {
-            for day_idx in 0..=total_days {
-                ret.push(self.0.clone() + Duration::days(day_idx));
-            }
-            return ret;
-        }
+/// The date tick locator, which snaps the key points to the calendar boundaries.
+/// It tries days, Mondays of every week or every other week, and then falls back to the first
+/// days of months, quarters, half years and years, whichever fits in `max_points`.
+fn calendar_key_points<D>(start: &D, end: &D, max_points: usize) -> Vec<D>
+where
+    D: Datelike + TimeValue + Sub<D, Output = Duration> + Add<Duration, Output = D> + Clone,
+{
+    let total_days = (end.clone() - start.clone()).num_days();
 
-        if total_weeks > 0 && total_weeks as usize <= max_points {
-            for day_idx in 0..=total_weeks {
-                ret.push(self.0.clone() + Duration::weeks(day_idx));
-            }
-            return ret;
-        }
+    if total_days > 0 && total_days as usize <= max_points {
+        return (0..=total_days)
+            .map(|day_idx| start.clone() + Duration::days(day_idx))
+            .collect();
+    }
 
-        // When all data is in the same week, just plot properly.
-        if total_weeks == 0 {
-            ret.push(self.0.clone());
-            return ret;
-        }
+    let first_monday =
+        start.clone() + Duration::days((7 - start.weekday().num_days_from_monday() as i64) % 7);
+    let total_weeks = (end.clone() - first_monday.clone()).num_weeks();
 
-        let week_per_point = ((total_weeks as f64) / (max_points as f64)).ceil() as usize;
+    // When all data is in the same week, just plot properly.
+    if total_weeks <= 0 && total_days < 7 {
+        return vec![start.clone()];
+    }
 
-        for idx in 0..=(total_weeks as usize / week_per_point) {
-            ret.push(self.0.clone() + Duration::weeks((idx * week_per_point) as i64));
+    for &step in [1, 2].iter() {
+        let num_points = total_weeks / step + 1;
+        if num_points as usize <= max_points {
+            return (0..num_points)
+                .map(|idx| first_monday.clone() + Duration::weeks(idx * step))
+                .collect();
         }
-
-        ret
     }
+
+    Monthly(start.clone()..end.clone()).bold_key_points(&max_points)
 }
 
 impl<D> DiscreteRanged for RangedDate<D>
@@ -626,6 +648,309 @@ impl<T: TimeValue> IntoYearly<T> for Range<T> {
     }
 }
 
+/// The calendar period of the slots of [RangedDatePeriod]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DatePeriod {
+    /// The ISO 8601 weeks, which start on Mondays
+    IsoWeek,
+    /// The calendar months
+    Month,
+    /// The quarters of the fiscal years starting in the given month, from 1 to 12
+    FiscalQuarter(u32),
+    /// The fiscal years starting in the given month, from 1 to 12. A fiscal year is named by the
+    /// calendar year in which it ends, so the one from April 2023 to March 2024 is FY24.
+    FiscalYear(u32),
+}
+
+impl DatePeriod {
+    /// Get the length in months and the month offset of the periods made of the whole months
+    fn months(&self) -> Option<(i64, i64)> {
+        let phase = |start_month: u32| i64::from(start_month.clamp(1, 12) - 1);
+        match *self {
+            DatePeriod::IsoWeek => None,
+            DatePeriod::Month => Some((1, 0)),
+            DatePeriod::FiscalQuarter(start_month) => Some((3, phase(start_month))),
+            DatePeriod::FiscalYear(start_month) => Some((12, phase(start_month))),
+        }
+    }
+}
+
+/// The date coordinate that buckets the dates into the calendar periods, such as the ISO weeks,
+/// the calendar months or the fiscal quarters, so the aggregated time series is laid out with one
+/// slot per period.
+///
+/// Each period takes the same space on the axis regardless of its number of days, the value of a
+/// period is its start date, and the key points are placed at the period boundaries. The range
+/// spans from the start of the first period to the start of the last one, and the segmented
+/// version of the coordinate puts one slot per period, for example one bar in a histogram.
+///
+/// ```rust
+/// use chrono::NaiveDate;
+/// use plotters::prelude::*;
+///
+/// let mut buffer = vec![0; 1024 * 768 * 3];
+/// let root = BitMapBackend::with_buffer(&mut buffer, (1024, 768)).into_drawing_area();
+///
+/// let mut chart = ChartBuilder::on(&root)
+///     .x_label_area_size(30)
+///     .y_label_area_size(40)
+///     .build_cartesian_2d(
+///         (NaiveDate::from_ymd(2024, 1, 1)..NaiveDate::from_ymd(2024, 12, 31))
+///             .by_month()
+///             .into_segmented(),
+///         0..100,
+///     )
+///     .unwrap();
+///
+/// chart.configure_mesh().draw().unwrap();
+/// chart
+///     .draw_series(
+///         Histogram::vertical(&chart)
+///             .style(BLUE.filled())
+///             .data((1..=12).map(|m| (NaiveDate::from_ymd(2024, m, 15), m as i32 * 8))),
+///     )
+///     .unwrap();
+/// ```
+#[derive(Clone)]
+pub struct RangedDatePeriod<D> {
+    first: D,
+    count: usize,
+    period: DatePeriod,
+}
+
+/// The trait that converts a date range into the coordinate bucketed by the calendar periods
+pub trait IntoDatePeriod<D> {
+    /// Make the coordinate with one slot per ISO week, see [RangedDatePeriod] for details
+    fn by_iso_week(self) -> RangedDatePeriod<D>;
+    /// Make the coordinate with one slot per calendar month, see [RangedDatePeriod] for details
+    fn by_month(self) -> RangedDatePeriod<D>;
+    /// Make the coordinate with one slot per fiscal quarter, which is labelled like `FY24 Q3`
+    /// - `start_month`: The month in which the fiscal years start, from 1 to 12
+    fn by_fiscal_quarter(self, start_month: u32) -> RangedDatePeriod<D>;
+    /// Make the coordinate with one slot per fiscal year, which is labelled like `FY24`
+    /// - `start_month`: The month in which the fiscal years start, from 1 to 12
+    fn by_fiscal_year(self, start_month: u32) -> RangedDatePeriod<D>;
+}
+
+impl<D> IntoDatePeriod<D> for Range<D>
+where
+    D: Datelike + TimeValue<DateType = D> + Sub<D, Output = Duration> + Add<Duration, Output = D>,
+    D: Clone,
+{
+    fn by_iso_week(self) -> RangedDatePeriod<D> {
+        RangedDatePeriod::new(self, DatePeriod::IsoWeek)
+    }
+
+    fn by_month(self) -> RangedDatePeriod<D> {
+        RangedDatePeriod::new(self, DatePeriod::Month)
+    }
+
+    fn by_fiscal_quarter(self, start_month: u32) -> RangedDatePeriod<D> {
+        RangedDatePeriod::new(self, DatePeriod::FiscalQuarter(start_month))
+    }
+
+    fn by_fiscal_year(self, start_month: u32) -> RangedDatePeriod<D> {
+        RangedDatePeriod::new(self, DatePeriod::FiscalYear(start_month))
+    }
+}
+
+impl<D> RangedDatePeriod<D>
+where
+    D: Datelike + TimeValue<DateType = D> + Sub<D, Output = Duration> + Add<Duration, Output = D>,
+    D: Clone,
+{
+    /// Make the coordinate which covers all the periods overlapping with the range, including the
+    /// period of the end date
+    fn new(range: Range<D>, period: DatePeriod) -> Self {
+        let (start, end) = if range.end.clone() - range.start.clone() >= Duration::zero() {
+            (range.start, range.end)
+        } else {
+            (range.end, range.start)
+        };
+        let mut ret = Self {
+            first: start.clone(),
+            count: 1,
+            period,
+        };
+        ret.first = ret.period_start(&start);
+        ret.count = ret.period_index(&end) as usize + 1;
+        ret
+    }
+
+    /// Get the calendar period of the slots
+    pub fn period(&self) -> DatePeriod {
+        self.period
+    }
+
+    /// Get the start date of the period containing the date
+    fn period_start(&self, date: &D) -> D {
+        match self.period.months() {
+            None => {
+                date.clone() + Duration::days(-i64::from(date.weekday().num_days_from_monday()))
+            }
+            Some((length, phase)) => {
+                let number = month_number(date);
+                month_start(date, number - (number - phase).rem_euclid(length))
+            }
+        }
+    }
+
+    /// Get the index of the period containing the date, which is negative for the dates before
+    /// the first period
+    fn period_index(&self, date: &D) -> i64 {
+        match self.period.months() {
+            None => (self.period_start(date) - self.first.clone()).num_weeks(),
+            Some((length, phase)) => {
+                let number = month_number(date);
+                let start = number - (number - phase).rem_euclid(length);
+                (start - month_number(&self.first)).div_euclid(length)
+            }
+        }
+    }
+
+    /// Get the start date of the period with the given index
+    fn nth_period(&self, index: i64) -> D {
+        match self.period.months() {
+            None => self.first.clone() + Duration::weeks(index),
+            Some((length, _)) => {
+                month_start(&self.first, month_number(&self.first) + index * length)
+            }
+        }
+    }
+
+    /// Get the position of the date in the number of periods from the start of the first period
+    fn period_offset(&self, date: &D) -> f64 {
+        let index = self.period_index(date);
+        let start = self.nth_period(index);
+        let length = (self.nth_period(index + 1) - start.clone()).num_days();
+        index as f64 + (date.clone() - start).num_days() as f64 / length as f64
+    }
+}
+
+/// Get the number of months since the year 0
+fn month_number<D: Datelike>(date: &D) -> i64 {
+    i64::from(date.year()) * 12 + i64::from(date.month0())
+}
+
+/// Get the first day of the month with the given number of months since the year 0
+fn month_start<D: TimeValue>(builder: &D, number: i64) -> D::DateType {
+    builder.ymd(
+        number.div_euclid(12) as i32,
+        number.rem_euclid(12) as u32 + 1,
+        1,
+    )
+}
+
+impl<D: Datelike> ValueFormatter<D> for RangedDatePeriod<D> {
+    fn format(value: &D) -> String {
+        format!("{}-{:02}-{:02}", value.year(), value.month(), value.day())
+    }
+
+    fn format_ext(&self, value: &D) -> String {
+        match self.period {
+            DatePeriod::IsoWeek => {
+                let week = value.iso_week();
+                format!("{}-W{:02}", week.year(), week.week())
+            }
+            DatePeriod::Month => format!("{}-{:02}", value.year(), value.month()),
+            DatePeriod::FiscalQuarter(start_month) | DatePeriod::FiscalYear(start_month) => {
+                let number = month_number(value);
+                let year_start =
+                    number - (number - i64::from(start_month.clamp(1, 12) - 1)).rem_euclid(12);
+                let year = (year_start + 11).div_euclid(12).rem_euclid(100);
+                match self.period {
+                    DatePeriod::FiscalQuarter(_) => {
+                        format!("FY{:02} Q{}", year, (number - year_start) / 3 + 1)
+                    }
+                    _ => format!("FY{:02}", year),
+                }
+            }
+        }
+    }
+}
+
+impl<D> Ranged for RangedDatePeriod<D>
+where
+    D: Datelike + TimeValue<DateType = D> + Sub<D, Output = Duration> + Add<Duration, Output = D>,
+    D: Clone,
+{
+    type FormatOption = NoDefaultFormatting;
+    type ValueType = D;
+
+    fn range(&self) -> Range<D> {
+        self.first.clone()..self.nth_period(self.count as i64 - 1)
+    }
+
+    fn map(&self, value: &D, limit: (i32, i32)) -> i32 {
+        let offset = self.period_offset(value) / (self.count - 1).max(1) as f64;
+        limit.0 + (f64::from(limit.1 - limit.0) * offset).round() as i32
+    }
+
+    fn key_points<Hint: KeyPointHint>(&self, hint: Hint) -> Vec<D> {
+        let max_points = hint.max_num_points();
+        if max_points == 0 {
+            return vec![];
+        }
+
+        /* Try the steps that keep the boundaries aligned to the larger periods */
+        let steps: &[i64] = match self.period {
+            DatePeriod::IsoWeek => &[1, 2, 4, 13, 26, 52],
+            DatePeriod::Month => &[1, 2, 3, 6, 12],
+            DatePeriod::FiscalQuarter(_) => &[1, 2, 4],
+            DatePeriod::FiscalYear(_) => &[1, 2, 5, 10],
+        };
+        let last = self.count as i64 - 1;
+        let fits = |step: i64| (last / step + 1) as usize <= max_points;
+        let largest = steps[steps.len() - 1];
+        let mut step = match steps.iter().copied().find(|step| fits(*step)) {
+            Some(step) => step,
+            None => largest * (last / largest / max_points as i64).max(1),
+        };
+        while !fits(step) {
+            step += largest;
+        }
+
+        /* The numbers of the periods since the year 0 are aligned to the step */
+        let offset = match self.period.months() {
+            Some((length, phase)) => {
+                let number = (month_number(&self.first) - phase).div_euclid(length);
+                (step - number.rem_euclid(step)) % step
+            }
+            None => 0,
+        };
+        (0..)
+            .map(|k| offset + k * step)
+            .take_while(|index| *index <= last)
+            .map(|index| self.nth_period(index))
+            .collect()
+    }
+}
+
+impl<D> DiscreteRanged for RangedDatePeriod<D>
+where
+    D: Datelike + TimeValue<DateType = D> + Sub<D, Output = Duration> + Add<Duration, Output = D>,
+    D: Clone,
+{
+    fn size(&self) -> usize {
+        self.count
+    }
+
+    fn index_of(&self, value: &D) -> Option<usize> {
+        let index = self.period_index(value);
+        if index < 0 || index >= self.count as i64 {
+            return None;
+        }
+        Some(index as usize)
+    }
+
+    fn from_index(&self, index: usize) -> Option<D> {
+        if index >= self.count {
+            return None;
+        }
+        Some(self.nth_period(index as i64))
+    }
+}
+
 /// The ranged coordinate for the date and time
 #[derive(Clone)]
 pub struct RangedDateTime<DT: Datelike + Timelike + TimeValue>(DT, DT);
@@ -676,18 +1001,31 @@ where
                 let start_time_ns = u64::from(self.0.num_seconds_from_midnight()) * 1_000_000_000
                     + u64::from(self.0.nanosecond());
 
-                let mut start_time = DT::from_date(self.0.date_floor())
-                    + Duration::nanoseconds(if start_time_ns % actual_ns_per_point > 0 {
-                        start_time_ns + (actual_ns_per_point - start_time_ns % actual_ns_per_point)
-                    } else {
-                        start_time_ns
-                    } as i64);
+                /* The key points are aligned to the local wall clock, so that they stay on the
+                 * round hours across the daylight saving time transitions */
+                let start_date = self.0.date_floor();
+                let start_midnight = DT::from_date(start_date.clone());
+                let mut offset_ns = if start_time_ns % actual_ns_per_point > 0 {
+                    start_time_ns + (actual_ns_per_point - start_time_ns % actual_ns_per_point)
+                } else {
+                    start_time_ns
+                };
 
                 let mut ret = vec![];
 
-                while start_time < self.1 {
-                    ret.push(start_time.clone());
-                    start_time = start_time + Duration::nanoseconds(actual_ns_per_point as i64);
+                loop {
+                    let offset = Duration::nanoseconds(offset_ns as i64);
+                    if let Some(time) = DT::local_time_after(start_date.clone(), offset) {
+                        if time >= self.1 {
+                            break;
+                        }
+                        if time >= self.0 {
+                            ret.push(time);
+                        }
+                    } else if start_midnight.clone() + offset >= self.1 {
+                        break;
+                    }
+                    offset_ns += actual_ns_per_point;
                 }
 
                 return ret;
@@ -718,7 +1056,128 @@ where
     }
 }
 
-/// The coordinate that for duration of time
+/// The coordinate for the time of the day, which is able to place the ticks down to the
+/// nanoseconds. This is useful for the high-frequency samples within a second or a minute.
+/// The labels show as many fractional digits as the range requires, such as `09:30:00.250`.
+#[derive(Clone)]
+pub struct RangedTime(NaiveTime, NaiveTime);
+
+impl AsRangedCoord for Range<NaiveTime> {
+    type CoordDescType = RangedTime;
+    type Value = NaiveTime;
+}
+
+impl From<Range<NaiveTime>> for RangedTime {
+    fn from(range: Range<NaiveTime>) -> Self {
+        Self(range.start, range.end)
+    }
+}
+
+impl RangedTime {
+    fn span_ns(&self) -> i64 {
+        (self.1 - self.0).num_nanoseconds().unwrap_or(0)
+    }
+}
+
+impl ValueFormatter<NaiveTime> for RangedTime {
+    fn format(value: &NaiveTime) -> String {
+        value.format("%H:%M:%S%.f").to_string()
+    }
+
+    fn format_ext(&self, value: &NaiveTime) -> String {
+        let span = self.span_ns().abs();
+        let pattern = if span < 2_000 {
+            "%H:%M:%S%.9f"
+        } else if span < 2_000_000 {
+            "%H:%M:%S%.6f"
+        } else if span < 2_000_000_000 {
+            "%H:%M:%S%.3f"
+        } else if span < 600_000_000_000 {
+            "%H:%M:%S"
+        } else {
+            "%H:%M"
+        };
+        value.format(pattern).to_string()
+    }
+}
+
+impl Ranged for RangedTime {
+    type FormatOption = NoDefaultFormatting;
+    type ValueType = NaiveTime;
+
+    fn range(&self) -> Range<NaiveTime> {
+        self.0..self.1
+    }
+
+    fn map(&self, value: &Self::ValueType, limit: (i32, i32)) -> i32 {
+        let total_ns = self.span_ns();
+        if total_ns == 0 {
+            return (limit.0 + limit.1) / 2;
+        }
+        let value_ns = (*value - self.0).num_nanoseconds().unwrap_or(0);
+        limit.0 + (f64::from(limit.1 - limit.0) * value_ns as f64 / total_ns as f64 + 1e-10) as i32
+    }
+
+    fn key_points<HintType: KeyPointHint>(&self, hint: HintType) -> Vec<Self::ValueType> {
+        let total_ns = self.span_ns();
+        if total_ns <= 0 {
+            return vec![];
+        }
+
+        let period = match compute_period_per_point(total_ns as u64, hint.max_num_points(), true) {
+            Some(period) => period,
+            None => return vec![],
+        };
+
+        let midnight = NaiveTime::from_hms(0, 0, 0);
+        let start_ns = (self.0 - midnight).num_nanoseconds().unwrap() as u64;
+        let mut offset_ns = match start_ns % period {
+            0 => start_ns,
+            rem => start_ns + period - rem,
+        };
+
+        let end_ns = start_ns + total_ns as u64;
+        let mut ret = vec![];
+        while offset_ns < end_ns {
+            ret.push(midnight + Duration::nanoseconds(offset_ns as i64));
+            offset_ns += period;
+        }
+        ret
+    }
+}
+
+impl ReversibleRanged for RangedTime {
+    fn unmap(&self, input: i32, limit: (i32, i32)) -> Option<Self::ValueType> {
+        if limit.0 == limit.1 {
+            return None;
+        }
+        let fraction = f64::from(input - limit.0) / f64::from(limit.1 - limit.0);
+        let offset_ns = (self.span_ns() as f64 * fraction).round() as i64;
+        Some(self.0 + Duration::nanoseconds(offset_ns))
+    }
+}
+
+impl ReversibleRanged for RangedDuration {
+    fn unmap(&self, input: i32, limit: (i32, i32)) -> Option<Self::ValueType> {
+        if limit.0 == limit.1 {
+            return None;
+        }
+        let fraction = f64::from(input - limit.0) / f64::from(limit.1 - limit.0);
+        let total_span = self.1 - self.0;
+        let offset = match total_span.num_nanoseconds() {
+            Some(total_ns) => Duration::nanoseconds((total_ns as f64 * fraction).round() as i64),
+            None => Duration::milliseconds(
+                (total_span.num_milliseconds() as f64 * fraction).round() as i64,
+            ),
+        };
+        Some(self.0 + offset)
+    }
+}
+
+/// The coordinate that for duration of time.
+///
+/// The values are labeled with the unit that fits their magnitude, such as `250ms`, `1.5s`
+/// or `2min30s`, so it suits the benchmark results and the latency measurements.
 #[derive(Clone)]
 pub struct RangedDuration(Duration, Duration);
 
@@ -733,8 +1192,14 @@ impl From<Range<Duration>> for RangedDuration {
     }
 }
 
+impl ValueFormatter<Duration> for RangedDuration {
+    fn format(value: &Duration) -> String {
+        format_duration(value)
+    }
+}
+
 impl Ranged for RangedDuration {
-    type FormatOption = DefaultFormatting;
+    type FormatOption = NoDefaultFormatting;
     type ValueType = Duration;
 
     fn range(&self) -> Range<Duration> {
@@ -828,6 +1293,128 @@ impl Ranged for RangedDuration {
     }
 }
 
+/// The coordinate for the `std::time::Duration`, for example the elapsed time measured with
+/// `std::time::Instant`. It has the same tick steps and labels as [RangedDuration].
+#[derive(Clone)]
+pub struct RangedStdDuration(StdDuration, StdDuration);
+
+impl AsRangedCoord for Range<StdDuration> {
+    type CoordDescType = RangedStdDuration;
+    type Value = StdDuration;
+}
+
+impl From<Range<StdDuration>> for RangedStdDuration {
+    fn from(range: Range<StdDuration>) -> Self {
+        Self(range.start, range.end)
+    }
+}
+
+impl RangedStdDuration {
+    fn as_chrono(&self) -> RangedDuration {
+        RangedDuration(std_to_chrono(&self.0), std_to_chrono(&self.1))
+    }
+}
+
+/// Convert the `std::time::Duration`, the ones out of the range of chrono saturate
+fn std_to_chrono(value: &StdDuration) -> Duration {
+    Duration::from_std(*value).unwrap_or_else(|_| Duration::max_value())
+}
+
+impl ReversibleRanged for RangedStdDuration {
+    fn unmap(&self, input: i32, limit: (i32, i32)) -> Option<Self::ValueType> {
+        self.as_chrono().unmap(input, limit)?.to_std().ok()
+    }
+}
+
+impl ValueFormatter<StdDuration> for RangedStdDuration {
+    fn format(value: &StdDuration) -> String {
+        format_duration(&std_to_chrono(value))
+    }
+}
+
+impl Ranged for RangedStdDuration {
+    type FormatOption = NoDefaultFormatting;
+    type ValueType = StdDuration;
+
+    fn range(&self) -> Range<StdDuration> {
+        self.0..self.1
+    }
+
+    fn map(&self, value: &Self::ValueType, limit: (i32, i32)) -> i32 {
+        self.as_chrono().map(&std_to_chrono(value), limit)
+    }
+
+    fn key_points<HintType: KeyPointHint>(&self, hint: HintType) -> Vec<Self::ValueType> {
+        self.as_chrono()
+            .key_points(hint)
+            .into_iter()
+            .filter_map(|value| value.to_std().ok())
+            .collect()
+    }
+}
+
+/// Format the duration with the largest unit that is not greater than it. The durations
+/// shorter than a minute get a decimal number of seconds, milliseconds, microseconds or
+/// nanoseconds, the longer ones are broken down into days, hours, minutes and seconds.
+fn format_duration(value: &Duration) -> String {
+    const SECOND: i64 = 1_000_000_000;
+    const DECIMAL_UNITS: [(i64, &str); 4] = [
+        (SECOND, "s"),
+        (1_000_000, "ms"),
+        (1_000, "\u{b5}s"),
+        (1, "ns"),
+    ];
+    const COMPOUND_UNITS: [(i64, &str); 3] = [(86400, "d"), (3600, "h"), (60, "min")];
+
+    let printer = crate::data::float::FloatPrettyPrinter {
+        allow_scientific: false,
+        min_decimal: 0,
+        max_decimal: 3,
+    };
+
+    let sign = if *value < Duration::zero() { "-" } else { "" };
+    let abs_ns = match value.num_nanoseconds() {
+        Some(ns) => ns.unsigned_abs(),
+        None => return format!("{}{}d", sign, value.num_days().unsigned_abs()),
+    };
+
+    if abs_ns == 0 {
+        return "0s".to_string();
+    }
+
+    if abs_ns < 60 * SECOND as u64 {
+        let (unit, name) = DECIMAL_UNITS
+            .iter()
+            .find(|(unit, _)| abs_ns >= *unit as u64)
+            .copied()
+            .unwrap_or(DECIMAL_UNITS[3]);
+        return format!(
+            "{}{}{}",
+            sign,
+            printer.print(abs_ns as f64 / unit as f64),
+            name
+        );
+    }
+
+    let mut seconds = abs_ns / SECOND as u64;
+    let mut ret = sign.to_string();
+    for (unit, name) in COMPOUND_UNITS.iter() {
+        let count = seconds / *unit as u64;
+        if count > 0 {
+            ret.push_str(&format!("{}{}", count, name));
+        }
+        seconds %= *unit as u64;
+    }
+
+    let rest_ns = seconds * SECOND as u64 + abs_ns % SECOND as u64;
+    if rest_ns > 0 {
+        ret.push_str(&printer.print(rest_ns as f64 / SECOND as f64));
+        ret.push('s');
+    }
+
+    ret
+}
+
 #[allow(clippy::inconsistent_digit_grouping)]
 fn compute_period_per_point(total_ns: u64, max_points: usize, sub_daily: bool) -> Option<u64> {
     let min_ns_per_point = total_ns as f64 / max_points as f64;
@@ -915,20 +1502,13 @@ mod test {
         let kps = ranged_coord.key_points(23);
 
         assert!(kps.len() <= 23);
-        let max = kps
+        assert!(kps.iter().all(|d| d.month() == 1 && d.day() == 1));
+        let steps: Vec<_> = kps
             .iter()
             .zip(kps.iter().skip(1))
-            .map(|(p, n)| (*n - *p).num_days())
-            .max()
-            .unwrap();
-        let min = kps
-            .iter()
-            .zip(kps.iter().skip(1))
-            .map(|(p, n)| (*n - *p).num_days())
-            .min()
-            .unwrap();
-        assert_eq!(max, min);
-        assert_eq!(max % 7, 0);
+            .map(|(p, n)| n.year() - p.year())
+            .collect();
+        assert!(steps.iter().all(|&step| step == steps[0]));
     }
 
     #[test]
@@ -973,6 +1553,39 @@ mod test {
         assert_eq!(max, 1);
     }
 
+    #[test]
+    fn test_calendar_key_points() {
+        use chrono::Weekday;
+
+        // Weekly key points are on Mondays
+        let range = Utc.ymd(2019, 1, 2)..Utc.ymd(2019, 3, 1);
+        let ranged_coord = Into::<RangedDate<_>>::into(range);
+        let kps = ranged_coord.key_points(10);
+        assert!(!kps.is_empty() && kps.len() <= 10);
+        assert!(kps.iter().all(|d| d.weekday() == Weekday::Mon));
+
+        // Longer ranges snap to the first day of months
+        let range = Utc.ymd(2019, 1, 15)..Utc.ymd(2019, 12, 20);
+        let ranged_coord = Into::<RangedDate<_>>::into(range);
+        let kps = ranged_coord.key_points(12);
+        assert_eq!(kps.len(), 11);
+        assert!(kps.iter().all(|d| d.day() == 1));
+
+        // Multi-day datetime ranges snap to midnight
+        let coord: RangedDateTime<_> =
+            (Utc.ymd(2019, 1, 1).and_hms(7, 30, 0)..Utc.ymd(2019, 1, 6).and_hms(13, 0, 0)).into();
+        let kps = coord.key_points(10);
+        assert!(!kps.is_empty());
+        assert!(kps.iter().all(|t| t.num_seconds_from_midnight() == 0));
+
+        // Sub-daily datetime ranges snap to whole hours
+        let coord: RangedDateTime<_> =
+            (Utc.ymd(2019, 1, 1).and_hms(7, 30, 0)..Utc.ymd(2019, 1, 1).and_hms(19, 10, 0)).into();
+        let kps = coord.key_points(12);
+        assert!(!kps.is_empty());
+        assert!(kps.iter().all(|t| t.minute() == 0 && t.second() == 0));
+    }
+
     #[test]
     fn test_yearly_date_range() {
         use crate::coord::ranged1d::BoldPoints;
@@ -1051,20 +1664,15 @@ mod test {
         let kps = coord.key_points(23);
 
         assert!(kps.len() <= 23);
-        let max = kps
+        assert!(kps
             .iter()
-            .zip(kps.iter().skip(1))
-            .map(|(p, n)| (*n - *p).num_seconds())
-            .max()
-            .unwrap();
-        let min = kps
+            .all(|t| t.month() == 1 && t.day() == 1 && t.num_seconds_from_midnight() == 0));
+        let steps: Vec<_> = kps
             .iter()
             .zip(kps.iter().skip(1))
-            .map(|(p, n)| (*n - *p).num_seconds())
-            .min()
-            .unwrap();
-        assert!(max == min);
-        assert!(max % (24 * 3600 * 7) == 0);
+            .map(|(p, n)| n.year() - p.year())
+            .collect();
+        assert!(steps.iter().all(|&step| step == steps[0]));
     }
 
     #[test]
@@ -1115,6 +1723,67 @@ mod test {
         assert_eq!(max, 1800);
     }
 
+    /// A time zone with the daylight saving time, which is UTC+1 before 2 AM on 2021-03-28
+    /// and UTC+2 since then, so the local time from 2 AM to 3 AM doesn't exist on that day
+    #[derive(Clone, Copy, Debug)]
+    struct DstZone;
+
+    impl DstZone {
+        fn switch() -> NaiveDateTime {
+            NaiveDate::from_ymd(2021, 3, 28).and_hms(1, 0, 0)
+        }
+    }
+
+    impl TimeZone for DstZone {
+        type Offset = chrono::FixedOffset;
+
+        fn from_offset(_: &chrono::FixedOffset) -> Self {
+            DstZone
+        }
+        fn offset_from_local_date(&self, local: &NaiveDate) -> chrono::LocalResult<Self::Offset> {
+            self.offset_from_local_datetime(&local.and_hms(12, 0, 0))
+        }
+        fn offset_from_local_datetime(
+            &self,
+            local: &NaiveDateTime,
+        ) -> chrono::LocalResult<Self::Offset> {
+            let switch = Self::switch() + Duration::hours(1);
+            if *local < switch {
+                chrono::LocalResult::Single(chrono::FixedOffset::east(3600))
+            } else if *local < switch + Duration::hours(1) {
+                chrono::LocalResult::None
+            } else {
+                chrono::LocalResult::Single(chrono::FixedOffset::east(7200))
+            }
+        }
+        fn offset_from_utc_date(&self, utc: &NaiveDate) -> Self::Offset {
+            self.offset_from_utc_datetime(&utc.and_hms(0, 0, 0))
+        }
+        fn offset_from_utc_datetime(&self, utc: &NaiveDateTime) -> Self::Offset {
+            if *utc < Self::switch() {
+                chrono::FixedOffset::east(3600)
+            } else {
+                chrono::FixedOffset::east(7200)
+            }
+        }
+    }
+
+    #[test]
+    fn test_datetime_dst_transition() {
+        let start = DstZone.ymd(2021, 3, 28).and_hms(0, 0, 0);
+        let end = DstZone.ymd(2021, 3, 28).and_hms(8, 0, 0);
+        assert_eq!((end - start).num_hours(), 7);
+
+        let coord: RangedDateTime<_> = (start..end).into();
+        let hours: Vec<_> = coord.key_points(4).iter().map(|t| t.hour()).collect();
+        assert_eq!(hours, [0, 4, 6]);
+
+        let hours: Vec<_> = coord.key_points(10).iter().map(|t| t.hour()).collect();
+        assert_eq!(hours, [0, 1, 3, 4, 5, 6, 7]);
+        assert_eq!(coord.format_ext(&end), format!("{:?}", end));
+        assert!(coord.format_ext(&end).ends_with("08:00:00+02:00"));
+    }
+
     #[test]
     fn test_datetime_nano_range() {
         let start = Utc.ymd(2019, 1, 1).and_hms(0, 0, 0);
@@ -1166,6 +1835,61 @@ mod test {
         assert!(max % (24 * 3600 * 10000) == 0);
     }
 
+    #[test]
+    fn test_naive_time_sub_second() {
+        let start = NaiveTime::from_hms_milli(9, 30, 0, 0);
+        let end = NaiveTime::from_hms_milli(9, 30, 1, 0);
+        let coord: RangedTime = (start..end).into();
+
+        let kps = coord.key_points(5);
+        assert_eq!(kps.len(), 5);
+        assert_eq!(kps[1], NaiveTime::from_hms_milli(9, 30, 0, 200));
+        assert_eq!(coord.format_ext(&kps[1]), "09:30:00.200");
+        assert_eq!(coord.map(&kps[1], (0, 100)), 20);
+        assert_eq!(
+            coord.unmap(50, (0, 100)),
+            Some(start + Duration::milliseconds(500))
+        );
+
+        let end = NaiveTime::from_hms_micro(9, 30, 0, 10);
+        let coord: RangedTime = (start..end).into();
+        let kps = coord.key_points(5);
+        assert_eq!(kps.len(), 5);
+        assert_eq!(coord.format_ext(&kps[3]), "09:30:00.000006");
+
+        let coord: RangedTime = (start..NaiveTime::from_hms(10, 0, 0)).into();
+        assert_eq!(coord.format_ext(&start), "09:30");
+    }
+
+    #[test]
+    fn test_duration_format() {
+        let coord: RangedDuration = (Duration::zero()..Duration::seconds(2)).into();
+        let labels: Vec<_> = coord
+            .key_points(5)
+            .iter()
+            .map(|v| coord.format_ext(v))
+            .collect();
+        assert_eq!(labels, ["0s", "500ms", "1s", "1.5s"]);
+
+        assert_eq!(format_duration(&Duration::microseconds(3)), "3\u{b5}s");
+        assert_eq!(format_duration(&Duration::nanoseconds(-250)), "-250ns");
+        assert_eq!(format_duration(&Duration::milliseconds(1250)), "1.25s");
+        assert_eq!(format_duration(&Duration::seconds(150)), "2min30s");
+        assert_eq!(format_duration(&Duration::hours(26)), "1d2h");
+        assert_eq!(format_duration(&Duration::days(-1000000)), "-1000000d");
+    }
+
+    #[test]
+    fn test_std_duration() {
+        let coord: RangedStdDuration =
+            (StdDuration::from_millis(0)..StdDuration::from_millis(20)).into();
+
+        assert_eq!(coord.map(&StdDuration::from_millis(5), (0, 100)), 25);
+        let kps = coord.key_points(5);
+        assert_eq!(kps[1], StdDuration::from_millis(5));
+        assert_eq!(coord.format_ext(&kps[1]), "5ms");
+    }
+
     #[test]
     fn test_duration_daily_range() {
         let coord: RangedDuration = (Duration::days(0)..Duration::hours(25)).into();
@@ -1295,4 +2019,110 @@ mod test {
         let value = coord.unmap(500, (0, 1000));
         assert_eq!(value, Some(mid));
     }
+
+    #[test]
+    fn test_duration_with_unmap() {
+        let coord: RangedDuration = (Duration::hours(1)..Duration::hours(3)).into();
+        assert_eq!(coord.unmap(50, (0, 100)), Some(Duration::hours(2)));
+        assert_eq!(coord.unmap(25, (100, 0)), Some(Duration::minutes(150)));
+
+        let coord: RangedDuration = (Duration::zero()..Duration::days(1_000_000)).into();
+        assert_eq!(coord.unmap(50, (0, 100)), Some(Duration::days(500_000)));
+
+        let coord = RangedStdDuration::from(StdDuration::from_secs(0)..StdDuration::from_secs(10));
+        assert_eq!(coord.unmap(30, (0, 100)), Some(StdDuration::from_secs(3)));
+    }
+    #[test]
+    fn test_date_period() {
+        use crate::coord::ranged1d::{BoldPoints, IntoSegmentedCoord, SegmentValue};
+
+        let coord =
+            (NaiveDate::from_ymd(2024, 1, 10)..NaiveDate::from_ymd(2024, 12, 31)).by_month();
+        assert_eq!(coord.period(), DatePeriod::Month);
+        assert_eq!(coord.size(), 12);
+        assert_eq!(
+            coord.range(),
+            NaiveDate::from_ymd(2024, 1, 1)..NaiveDate::from_ymd(2024, 12, 1)
+        );
+        assert_eq!(coord.index_of(&NaiveDate::from_ymd(2024, 2, 29)), Some(1));
+        assert_eq!(coord.from_index(11), Some(NaiveDate::from_ymd(2024, 12, 1)));
+        assert_eq!(coord.from_index(12), None);
+
+        /* Every month takes the same space regardless of its days */
+        assert_eq!(coord.map(&NaiveDate::from_ymd(2024, 2, 1), (0, 110)), 10);
+        assert_eq!(coord.map(&NaiveDate::from_ymd(2024, 3, 1), (0, 110)), 20);
+        assert_eq!(coord.map(&NaiveDate::from_ymd(2024, 2, 15), (0, 110)), 15);
+
+        let kps = coord.key_points(BoldPoints(5));
+        assert_eq!(
+            kps,
+            [1, 4, 7, 10]
+                .iter()
+                .map(|m| NaiveDate::from_ymd(2024, *m, 1))
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(coord.format_ext(&kps[1]), "2024-04");
+
+        let coord = (Utc.ymd(2020, 12, 30)..Utc.ymd(2021, 1, 20)).by_iso_week();
+        assert_eq!(coord.size(), 4);
+        assert_eq!(coord.from_index(0), Some(Utc.ymd(2020, 12, 28)));
+        assert_eq!(coord.index_of(&Utc.ymd(2021, 1, 4)), Some(1));
+        assert_eq!(coord.index_of(&Utc.ymd(2020, 12, 27)), None);
+        assert_eq!(coord.format_ext(&Utc.ymd(2021, 1, 4)), "2021-W01");
+        assert_eq!(coord.format_ext(&Utc.ymd(2020, 12, 28)), "2020-W53");
+        assert_eq!(coord.key_points(BoldPoints(10)).len(), 4);
+        assert_eq!(coord.key_points(BoldPoints(3)).len(), 2);
+        assert_eq!(
+            coord
+                .into_segmented()
+                .format_ext(&SegmentValue::CenterOf(Utc.ymd(2021, 1, 4))),
+            "2021-W01"
+        );
+
+        let coord = (NaiveDate::from_ymd(2000, 1, 1)..NaiveDate::from_ymd(2019, 12, 31)).by_month();
+        let kps = coord.key_points(BoldPoints(10));
+        assert!(kps.len() <= 10);
+        assert!(kps.iter().all(|d| d.month() == 1 && d.day() == 1));
+    }
+    #[test]
+    fn test_fiscal_period() {
+        use crate::coord::ranged1d::BoldPoints;
+
+        let coord = (NaiveDate::from_ymd(2023, 5, 10)..NaiveDate::from_ymd(2024, 6, 1))
+            .by_fiscal_quarter(4);
+        assert_eq!(coord.size(), 5);
+        assert_eq!(coord.from_index(0), Some(NaiveDate::from_ymd(2023, 4, 1)));
+        assert_eq!(coord.from_index(3), Some(NaiveDate::from_ymd(2024, 1, 1)));
+        assert_eq!(coord.index_of(&NaiveDate::from_ymd(2023, 12, 31)), Some(2));
+        let labels: Vec<_> = (0..5)
+            .map(|idx| coord.format_ext(&coord.from_index(idx).unwrap()))
+            .collect();
+        assert_eq!(
+            labels,
+            ["FY24 Q1", "FY24 Q2", "FY24 Q3", "FY24 Q4", "FY25 Q1"]
+        );
+        assert_eq!(
+            coord.key_points(BoldPoints(2)),
+            [
+                NaiveDate::from_ymd(2023, 4, 1),
+                NaiveDate::from_ymd(2024, 4, 1)
+            ]
+        );
+
+        let coord =
+            (NaiveDate::from_ymd(2015, 1, 1)..NaiveDate::from_ymd(2024, 12, 31)).by_fiscal_year(10);
+        assert_eq!(coord.size(), 11);
+        assert_eq!(coord.from_index(0), Some(NaiveDate::from_ymd(2014, 10, 1)));
+        assert_eq!(coord.format_ext(&NaiveDate::from_ymd(2014, 10, 1)), "FY15");
+        let kps = coord.key_points(BoldPoints(3));
+        assert!(kps.len() <= 3 && kps.iter().all(|d| d.month() == 10));
+
+        let coord =
+            (NaiveDate::from_ymd(2024, 2, 1)..NaiveDate::from_ymd(2024, 8, 1)).by_fiscal_quarter(1);
+        assert_eq!(coord.from_index(0), Some(NaiveDate::from_ymd(2024, 1, 1)));
+        assert_eq!(
+            coord.format_ext(&NaiveDate::from_ymd(2024, 7, 1)),
+            "FY24 Q3"
+        );
+    }
 }
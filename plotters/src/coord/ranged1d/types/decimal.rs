@@ -0,0 +1,183 @@
+use num_traits::{FromPrimitive, ToPrimitive};
+use rust_decimal::Decimal;
+
+use std::ops::Range;
+
+use crate::coord::ranged1d::{
+    AsRangedCoord, KeyPointHint, NoDefaultFormatting, Ranged, ReversibleRanged, ValueFormatter,
+};
+
+/// The ranged coordinate for the arbitrary precision decimal numbers of `rust_decimal`.
+///
+/// The key points are computed in decimal arithmetic, so they are always the exact multiples of
+/// 1, 2 or 5 times a power of ten, such as `0.05` or `2500`, and the labels never show the
+/// rounding errors of the binary floating point numbers.
+#[derive(Clone)]
+pub struct RangedDecimal(Decimal, Decimal);
+
+impl From<Range<Decimal>> for RangedDecimal {
+    fn from(range: Range<Decimal>) -> Self {
+        RangedDecimal(range.start, range.end)
+    }
+}
+
+impl AsRangedCoord for Range<Decimal> {
+    type CoordDescType = RangedDecimal;
+    type Value = Decimal;
+}
+
+/// Get the number of the multiples of the step within the range, or `None` if the result
+/// doesn't fit in a decimal
+fn count_multiples((lo, hi): (Decimal, Decimal), step: Decimal) -> Option<Decimal> {
+    let first = lo.checked_div(step)?.ceil();
+    let last = hi.checked_div(step)?.floor();
+    last.checked_sub(first)?.checked_add(Decimal::ONE)
+}
+
+/// Get the power of ten which is the closest to the span but not greater than it
+fn decimal_scale(span: Decimal) -> Decimal {
+    let mut scale = Decimal::ONE;
+    while scale > span {
+        scale /= Decimal::TEN;
+    }
+    while let Some(next) = scale.checked_mul(Decimal::TEN).filter(|next| *next <= span) {
+        scale = next;
+    }
+    scale
+}
+
+fn compute_decimal_key_points(range: (Decimal, Decimal), max_points: usize) -> Vec<Decimal> {
+    if max_points == 0 {
+        return vec![];
+    }
+
+    let range = (range.0.min(range.1), range.0.max(range.1));
+    if range.0 == range.1 {
+        return vec![range.0];
+    }
+
+    let max_points = Decimal::from(max_points);
+    let fits = |step: Decimal| {
+        !step.is_zero() && matches!(count_multiples(range, step), Some(n) if n <= max_points)
+    };
+
+    /* Try the steps of 5, 2 and 1 times of each power of ten, from the coarsest to the finest,
+     * until there are too many points */
+    let mut power = decimal_scale(range.1 - range.0) * Decimal::TEN;
+    while !fits(power) {
+        match power.checked_mul(Decimal::TEN) {
+            Some(next) => power = next,
+            None => return vec![range.0, range.1],
+        }
+    }
+    let mut step = power;
+    'outer: loop {
+        for factor in [Decimal::ONE, Decimal::new(5, 1), Decimal::new(2, 1)].iter() {
+            let candidate = power * factor;
+            if !fits(candidate) {
+                break 'outer;
+            }
+            step = candidate;
+        }
+        power /= Decimal::TEN;
+    }
+
+    let mut ret = vec![];
+    let mut value = (range.0 / step).ceil() * step;
+    while value <= range.1 {
+        ret.push(value.normalize());
+        value += step;
+    }
+    ret
+}
+
+impl ValueFormatter<Decimal> for RangedDecimal {
+    fn format(value: &Decimal) -> String {
+        value.normalize().to_string()
+    }
+}
+
+impl Ranged for RangedDecimal {
+    type FormatOption = NoDefaultFormatting;
+    type ValueType = Decimal;
+
+    fn map(&self, value: &Decimal, limit: (i32, i32)) -> i32 {
+        // Corner case: If we have a range that have only one value,
+        // then we just assign everything to the only point
+        if self.1 == self.0 {
+            return (limit.1 - limit.0) / 2;
+        }
+
+        let logic_length = (*value - self.0)
+            .checked_div(self.1 - self.0)
+            .and_then(|ratio| ratio.to_f64())
+            .unwrap_or_else(|| {
+                let f = |v: Decimal| v.to_f64().unwrap_or(0.0);
+                (f(*value) - f(self.0)) / (f(self.1) - f(self.0))
+            });
+
+        let actual_length = limit.1 - limit.0;
+        if actual_length > 0 {
+            limit.0 + (f64::from(actual_length) * logic_length + 1e-3).floor() as i32
+        } else {
+            limit.0 + (f64::from(actual_length) * logic_length - 1e-3).ceil() as i32
+        }
+    }
+
+    fn key_points<Hint: KeyPointHint>(&self, hint: Hint) -> Vec<Decimal> {
+        compute_decimal_key_points((self.0, self.1), hint.max_num_points())
+    }
+
+    fn range(&self) -> Range<Decimal> {
+        self.0..self.1
+    }
+}
+
+impl ReversibleRanged for RangedDecimal {
+    fn unmap(&self, p: i32, (min, max): (i32, i32)) -> Option<Decimal> {
+        if p < min.min(max) || p > max.max(min) || min == max {
+            return None;
+        }
+
+        let logical_offset = Decimal::from_f64(f64::from(p - min) / f64::from(max - min))?;
+        Some(self.0 + (self.1 - self.0) * logical_offset)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn dec(text: &str) -> Decimal {
+        text.parse().unwrap()
+    }
+
+    #[test]
+    fn test_decimal_key_points() {
+        let coord: RangedDecimal = (dec("0.1")..dec("0.3")).into();
+        let points: Vec<_> = coord.key_points(5).iter().map(|v| v.to_string()).collect();
+        assert_eq!(points, ["0.1", "0.15", "0.2", "0.25", "0.3"]);
+
+        let coord: RangedDecimal = (dec("-1234.5")..dec("98765.4321")).into();
+        let points = coord.key_points(10);
+        assert!(points.len() <= 10 && points.len() >= 5);
+        assert!(points.iter().all(|v| (*v % dec("10000")).is_zero()));
+
+        let coord: RangedDecimal = (dec("1.00")..dec("1.00")).into();
+        assert_eq!(coord.key_points(10), [dec("1.00")]);
+        assert!(coord.key_points(0).is_empty());
+
+        let coord: RangedDecimal = (dec("0")..dec("0.0000000000000000000000000003")).into();
+        assert!(!coord.key_points(100).is_empty());
+    }
+
+    #[test]
+    fn test_decimal_mapping() {
+        let coord: RangedDecimal = (dec("10.00")..dec("20.00")).into();
+        assert_eq!(coord.map(&dec("15"), (0, 100)), 50);
+        assert_eq!(coord.map(&dec("10"), (100, 0)), 100);
+        assert_eq!(coord.unmap(25, (0, 100)), Some(dec("12.5")));
+        assert_eq!(coord.format_ext(&dec("12.50")), "12.5");
+        assert_eq!(coord.format_ext(&dec("100")), "100");
+    }
+}
@@ -1,16 +1,37 @@
+mod angle;
+pub use angle::{AngleUnit, IntoAngleCoord, RangedAngle};
+
+mod category;
+pub use category::CategoryCoord;
+
 #[cfg(feature = "chrono")]
 mod datetime;
 #[cfg(feature = "chrono")]
+pub(crate) use datetime::TimeValue;
+#[cfg(feature = "chrono")]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "chrono")))]
 pub use datetime::{
-    IntoMonthly, IntoYearly, Monthly, RangedDate, RangedDateTime, RangedDuration, Yearly,
+    DatePeriod, IntoDatePeriod, IntoMonthly, IntoYearly, Monthly, RangedDate, RangedDatePeriod,
+    RangedDateTime, RangedDuration, RangedStdDuration, RangedTime, Yearly,
 };
 
+#[cfg(feature = "rust_decimal")]
+mod decimal;
+#[cfg(feature = "rust_decimal")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "rust_decimal")))]
+pub use decimal::RangedDecimal;
+
 mod numeric;
 pub use numeric::{
     RangedCoordf32, RangedCoordf64, RangedCoordi128, RangedCoordi32, RangedCoordi64,
     RangedCoordisize, RangedCoordu128, RangedCoordu32, RangedCoordu64, RangedCoordusize,
 };
 
+#[cfg(feature = "uom")]
+mod quantity;
+#[cfg(feature = "uom")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "uom")))]
+pub use quantity::{AxisQuantity, RangedQuantity};
+
 mod slice;
 pub use slice::RangedSlice;
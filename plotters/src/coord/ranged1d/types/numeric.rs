@@ -128,24 +128,28 @@ macro_rules! gen_key_points_comp {
                 return vec![range.0 as $type];
             }
 
+            // The values closer to a multiple of the step than the precision of the value type are
+            // treated as the multiple, so that the f32 values such as 0.9995 are on the grid.
+            let tolerance = (range.0.abs().max(range.1.abs()) * <$type>::EPSILON as f64).max(f64::EPSILON);
+
             let mut scale = (10f64).powf((range.1 - range.0).log(10.0).floor());
             // The value granularity controls how we round the values.
             // To avoid generating key points like 1.00000000001, we round to the nearest multiple of the
             // value granularity.
             // By default, we make the granularity as the 1/10 of the scale.
             let mut value_granularity = scale / 10.0;
-            fn rem_euclid(a: f64, b: f64) -> f64 {
+            let rem_euclid = |a: f64, b: f64| -> f64 {
                 let ret = if b > 0.0 {
                     a - (a / b).floor() * b
                 } else {
                     a - (a / b).ceil() * b
                 };
-                if (ret - b).abs() < f64::EPSILON {
+                if (ret - b).abs() < tolerance || ret.abs() < tolerance {
                     0.0
                 } else {
                     ret
                 }
-            }
+            };
 
             // At this point we need to make sure that the loop invariant:
             // The scale must yield number of points than requested
@@ -190,7 +194,7 @@ macro_rules! gen_key_points_comp {
             let left_base = (left / value_granularity).floor() * value_granularity;
             let mut left_relative = left - left_base;
             let right = range.1 - rem_euclid(range.1, scale);
-            while (right - left_relative - left_base) >= -f64::EPSILON {
+            while (right - left_relative - left_base) >= -tolerance {
                 let new_left_relative =
                     (left_relative / value_granularity).round() * value_granularity;
                 if new_left_relative < 0.0 {
@@ -472,6 +476,22 @@ mod test {
         assert!(points.len() <= 2);
     }
 
+    #[test]
+    fn test_f32_coord_matches_f64() {
+        for &(start, end, n) in &[(0.0, 1.0, 6), (-200.0, 801.0, 20), (0.9995, 1.0005, 11)] {
+            let coord32: RangedCoordf32 = (start as f32..end as f32).into();
+            let coord64: RangedCoordf64 = (start..end).into();
+            let points32 = coord32.key_points(n);
+            let points64 = coord64.key_points(n);
+            assert_eq!(points32.len(), points64.len());
+            for (a, b) in points32.iter().zip(points64.iter()) {
+                assert_eq!(coord32.format_ext(a), coord64.format_ext(b));
+                /* The f32 values are less precise, thus the pixels may be off by one */
+                assert!((coord32.map(a, (0, 1000)) - coord64.map(b, (0, 1000))).abs() <= 1);
+            }
+        }
+    }
+
     #[test]
     fn regression_test_issue_304_intmax_keypoint_no_panic() {
         let coord: RangedCoordu32 = (0..u32::MAX).into();
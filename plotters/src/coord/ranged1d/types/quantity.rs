@@ -0,0 +1,218 @@
+use std::ops::Range;
+
+use uom::si::f64 as si;
+use uom::si::Unit;
+
+use crate::coord::ranged1d::{
+    AsRangedCoord, KeyPointHint, NoDefaultFormatting, Ranged, ReversibleRanged, ValueFormatter,
+};
+use crate::coord::types::RangedCoordf64;
+use crate::data::float::FloatPrettyPrinter;
+
+/// The SI prefixes used by the labels, with their powers of ten
+const SI_PREFIXES: [(i32, &str); 9] = [
+    (-12, "p"),
+    (-9, "n"),
+    (-6, "\u{b5}"),
+    (-3, "m"),
+    (0, ""),
+    (3, "k"),
+    (6, "M"),
+    (9, "G"),
+    (12, "T"),
+];
+
+/// The `uom` quantity that can be used as the value of [RangedQuantity]
+pub trait AxisQuantity: Clone + PartialOrd {
+    /// Get the abbreviation of the unit in which the values are labelled, without the prefix
+    fn unit_abbreviation() -> &'static str;
+    /// Get the value of the quantity in the unit of the labels
+    fn to_unit_value(&self) -> f64;
+    /// Create the quantity from the value in the unit of the labels
+    fn from_unit_value(value: f64) -> Self;
+}
+
+macro_rules! impl_axis_quantity {
+    ($($quantity:ident => $unit:path),* $(,)?) => {
+        $(
+            impl AxisQuantity for si::$quantity {
+                fn unit_abbreviation() -> &'static str {
+                    <$unit as Unit>::abbreviation()
+                }
+                fn to_unit_value(&self) -> f64 {
+                    self.get::<$unit>()
+                }
+                fn from_unit_value(value: f64) -> Self {
+                    si::$quantity::new::<$unit>(value)
+                }
+            }
+
+            impl AsRangedCoord for Range<si::$quantity> {
+                type CoordDescType = RangedQuantity<si::$quantity>;
+                type Value = si::$quantity;
+            }
+        )*
+    };
+}
+
+impl_axis_quantity!(
+    Length => uom::si::length::meter,
+    Mass => uom::si::mass::gram,
+    Time => uom::si::time::second,
+    Frequency => uom::si::frequency::hertz,
+    Velocity => uom::si::velocity::meter_per_second,
+    Acceleration => uom::si::acceleration::meter_per_second_squared,
+    Force => uom::si::force::newton,
+    Pressure => uom::si::pressure::pascal,
+    Energy => uom::si::energy::joule,
+    Power => uom::si::power::watt,
+    ElectricCurrent => uom::si::electric_current::ampere,
+    ElectricPotential => uom::si::electric_potential::volt,
+    ElectricCharge => uom::si::electric_charge::coulomb,
+    ElectricalResistance => uom::si::electrical_resistance::ohm,
+    Capacitance => uom::si::capacitance::farad,
+);
+
+/// Get the power of ten of the SI prefix which suits the magnitude of the value
+fn prefix_exponent(magnitude: f64) -> i32 {
+    if magnitude == 0.0 || !magnitude.is_finite() {
+        return 0;
+    }
+    let exponent = (magnitude.log10() / 3.0).floor() as i32 * 3;
+    exponent.clamp(-12, 12)
+}
+
+/// Format the value with the SI prefix of the given power of ten and the unit
+fn format_quantity(value: f64, exponent: i32, unit: &str) -> String {
+    let printer = FloatPrettyPrinter {
+        allow_scientific: false,
+        min_decimal: 0,
+        max_decimal: 5,
+    };
+    let prefix = SI_PREFIXES
+        .iter()
+        .find(|(e, _)| *e == exponent)
+        .map_or("", |(_, prefix)| prefix);
+    format!(
+        "{} {}{}",
+        printer.print(value / 10f64.powi(exponent)),
+        prefix,
+        unit
+    )
+}
+
+/// The ranged coordinate for the `uom` quantities, such as `Length` and `Time` of
+/// `uom::si::f64`.
+///
+/// The labels carry the unit, and the SI prefix of the unit is picked from the magnitude of the
+/// range, so the axis of `0.0..0.003` seconds is labelled as `0 ms` to `3 ms`.
+///
+/// ```rust
+/// use plotters::prelude::*;
+/// use uom::si::f64::Length;
+/// use uom::si::length::meter;
+///
+/// let mut buffer = vec![0; 1024 * 768 * 3];
+/// let root = BitMapBackend::with_buffer(&mut buffer, (1024, 768)).into_drawing_area();
+///
+/// let mut chart = ChartBuilder::on(&root)
+///     .x_label_area_size(30)
+///     .y_label_area_size(60)
+///     .build_cartesian_2d(0.0..10.0, Length::new::<meter>(0.0)..Length::new::<meter>(2500.0))
+///     .unwrap();
+///
+/// chart.configure_mesh().draw().unwrap();
+/// ```
+#[derive(Clone)]
+pub struct RangedQuantity<Q> {
+    range: Range<Q>,
+    scaled: RangedCoordf64,
+    exponent: i32,
+}
+
+impl<Q: AxisQuantity> From<Range<Q>> for RangedQuantity<Q> {
+    fn from(range: Range<Q>) -> Self {
+        let (start, end) = (range.start.to_unit_value(), range.end.to_unit_value());
+        let exponent = prefix_exponent(start.abs().max(end.abs()));
+        let scale = 10f64.powi(exponent);
+        Self {
+            scaled: (start / scale..end / scale).into(),
+            range,
+            exponent,
+        }
+    }
+}
+
+impl<Q: AxisQuantity> ValueFormatter<Q> for RangedQuantity<Q> {
+    fn format(value: &Q) -> String {
+        let value = value.to_unit_value();
+        format_quantity(value, prefix_exponent(value.abs()), Q::unit_abbreviation())
+    }
+
+    fn format_ext(&self, value: &Q) -> String {
+        format_quantity(value.to_unit_value(), self.exponent, Q::unit_abbreviation())
+    }
+}
+
+impl<Q: AxisQuantity> Ranged for RangedQuantity<Q> {
+    type FormatOption = NoDefaultFormatting;
+    type ValueType = Q;
+
+    fn map(&self, value: &Q, limit: (i32, i32)) -> i32 {
+        self.scaled
+            .map(&(value.to_unit_value() / 10f64.powi(self.exponent)), limit)
+    }
+
+    fn key_points<Hint: KeyPointHint>(&self, hint: Hint) -> Vec<Q> {
+        let scale = 10f64.powi(self.exponent);
+        self.scaled
+            .key_points(hint)
+            .into_iter()
+            .map(|value| Q::from_unit_value(value * scale))
+            .collect()
+    }
+
+    fn range(&self) -> Range<Q> {
+        self.range.clone()
+    }
+}
+
+impl<Q: AxisQuantity> ReversibleRanged for RangedQuantity<Q> {
+    fn unmap(&self, input: i32, limit: (i32, i32)) -> Option<Q> {
+        let value = self.scaled.unmap(input, limit)?;
+        Some(Q::from_unit_value(value * 10f64.powi(self.exponent)))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use uom::si::{length::meter, mass::kilogram, time::second};
+
+    #[test]
+    fn test_quantity_labels() {
+        let coord: RangedQuantity<_> =
+            (si::Length::new::<meter>(0.0)..si::Length::new::<meter>(2500.0)).into();
+        let labels: Vec<_> = coord
+            .key_points(6)
+            .iter()
+            .map(|value| coord.format_ext(value))
+            .collect();
+        assert_eq!(
+            labels,
+            ["0 km", "0.5 km", "1 km", "1.5 km", "2 km", "2.5 km"]
+        );
+        assert_eq!(coord.map(&si::Length::new::<meter>(1250.0), (0, 100)), 50);
+
+        let coord: RangedQuantity<_> =
+            (si::Time::new::<second>(0.0)..si::Time::new::<second>(0.003)).into();
+        assert_eq!(coord.format_ext(&si::Time::new::<second>(0.0015)), "1.5 ms");
+        let value = coord.unmap(50, (0, 100)).unwrap();
+        assert!((value.get::<second>() - 0.0015).abs() < 1e-12);
+
+        assert_eq!(
+            RangedQuantity::format(&si::Mass::new::<kilogram>(2.0)),
+            "2 kg"
+        );
+    }
+}
@@ -51,28 +51,40 @@ impl<X: Ranged, Y: Ranged> Cartesian2d<X, Y> {
         &self,
         h_limit: YH,
         v_limit: XH,
-        mut draw_mesh: DrawMesh,
+        draw_mesh: DrawMesh,
     ) -> Result<(), E> {
         let (xkp, ykp) = (
             self.logic_x.key_points(v_limit),
             self.logic_y.key_points(h_limit),
         );
 
-        for logic_x in xkp {
-            let x = self.logic_x.map(&logic_x, self.back_x);
+        self.draw_mesh_with_key_points(&xkp, &ykp, draw_mesh)
+    }
+
+    /// Draw the mesh for the coordinate system on the given key points
+    /// - `x_key_points`: The X values where the vertical mesh lines should be drawn
+    /// - `y_key_points`: The Y values where the horizontal mesh lines should be drawn
+    pub fn draw_mesh_with_key_points<E, DrawMesh: FnMut(MeshLine<X, Y>) -> Result<(), E>>(
+        &self,
+        x_key_points: &[X::ValueType],
+        y_key_points: &[Y::ValueType],
+        mut draw_mesh: DrawMesh,
+    ) -> Result<(), E> {
+        for logic_x in x_key_points {
+            let x = self.logic_x.map(logic_x, self.back_x);
             draw_mesh(MeshLine::XMesh(
                 (x, self.back_y.0),
                 (x, self.back_y.1),
-                &logic_x,
+                logic_x,
             ))?;
         }
 
-        for logic_y in ykp {
-            let y = self.logic_y.map(&logic_y, self.back_y);
+        for logic_y in y_key_points {
+            let y = self.logic_y.map(logic_y, self.back_y);
             draw_mesh(MeshLine::YMesh(
                 (self.back_x.0, y),
                 (self.back_x.1, y),
-                &logic_y,
+                logic_y,
             ))?;
         }
 
@@ -89,6 +101,12 @@ impl<X: Ranged, Y: Ranged> Cartesian2d<X, Y> {
         self.logic_y.range()
     }
 
+    /// Change the pixel range on the screen for this coordinate system
+    pub(crate) fn set_pixel_range(&mut self, actual: (Range<i32>, Range<i32>)) {
+        self.back_x = (actual.0.start, actual.0.end);
+        self.back_y = (actual.1.start, actual.1.end);
+    }
+
     /// Get the horizental backend coordinate range where X axis should be drawn
     pub fn get_x_axis_pixel_range(&self) -> Range<i32> {
         self.logic_x.axis_pixel_range(self.back_x)
@@ -99,6 +117,34 @@ impl<X: Ranged, Y: Ranged> Cartesian2d<X, Y> {
         self.logic_y.axis_pixel_range(self.back_y)
     }
 
+    /// Get the horizental backend coordinate ranges where X axis is broken
+    pub fn get_x_axis_breaks(&self) -> Vec<Range<i32>> {
+        self.logic_x.axis_breaks(self.back_x)
+    }
+
+    /// Get the vertical backend coordinate ranges where Y axis is broken
+    pub fn get_y_axis_breaks(&self) -> Vec<Range<i32>> {
+        self.logic_y.axis_breaks(self.back_y)
+    }
+
+    /// Get the horizental backend coordinates where X axis changes its scale
+    pub fn get_x_axis_markers(&self) -> Vec<i32> {
+        self.logic_x.axis_markers(self.back_x)
+    }
+
+    /// Get the vertical backend coordinates where Y axis changes its scale
+    pub fn get_y_axis_markers(&self) -> Vec<i32> {
+        self.logic_y.axis_markers(self.back_y)
+    }
+
+    /// Get the backend coordinates where the X axis and the Y axis reach the end of their ranges
+    pub fn get_axis_end_pixels(&self) -> (i32, i32) {
+        (
+            self.logic_x.map(&self.logic_x.range().end, self.back_x),
+            self.logic_y.map(&self.logic_y.range().end, self.back_y),
+        )
+    }
+
     /// Get the 1D coordinate spec for X axis
     pub fn x_spec(&self) -> &X {
         &self.logic_x
@@ -0,0 +1,274 @@
+/*!
+ The geographic coordinate system.
+
+ This module provides the coordinates of the longitude and the latitude in degrees, which are
+ projected to the screen with a map projection. Since both of the supported projections map
+ the longitude and the latitude independently, the geographic coordinate system is the
+ Cartesian product of the two axes, so all the series and the mesh of the 2D Cartesian charts
+ work on maps as well. The mesh becomes the graticule, with the labels such as `30°N` and
+ `120°W`.
+
+ This type of coordinate system is used by the chart constructed with [ChartBuilder::build_geo](../../chart/ChartBuilder.html#method.build_geo).
+*/
+
+use crate::coord::cartesian::Cartesian2d;
+use crate::coord::ranged1d::{
+    KeyPointHint, NoDefaultFormatting, Ranged, ReversibleRanged, ValueFormatter,
+};
+use crate::coord::types::RangedCoordf64;
+use crate::data::float::FloatPrettyPrinter;
+
+use std::ops::Range;
+
+/// The largest latitude that can be shown by the Web-Mercator projection, which makes the
+/// projected world map a square
+pub const MAX_MERCATOR_LATITUDE: f64 = 85.051_128_779_806_59;
+
+/// The graticule steps in degrees, from the coarsest to the finest
+const DEGREE_STEPS: [f64; 10] = [90.0, 60.0, 45.0, 30.0, 20.0, 15.0, 10.0, 5.0, 2.0, 1.0];
+
+/// The map projection from the latitude to the vertical position of the map
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum GeoProjection {
+    /// The equirectangular projection, which maps the latitude linearly
+    Equirectangular,
+    /// The Web-Mercator projection, which is used by most of the web maps. The latitudes are
+    /// limited to [MAX_MERCATOR_LATITUDE].
+    WebMercator,
+}
+
+impl GeoProjection {
+    /// Project the latitude in degrees, the result is in the same unit as the longitude
+    pub fn project(&self, lat: f64) -> f64 {
+        match self {
+            GeoProjection::Equirectangular => lat,
+            GeoProjection::WebMercator => {
+                let lat = lat
+                    .clamp(-MAX_MERCATOR_LATITUDE, MAX_MERCATOR_LATITUDE)
+                    .to_radians();
+                (std::f64::consts::FRAC_PI_4 + lat / 2.0)
+                    .tan()
+                    .ln()
+                    .to_degrees()
+            }
+        }
+    }
+
+    /// Get the latitude in degrees from the projected value
+    pub fn unproject(&self, y: f64) -> f64 {
+        match self {
+            GeoProjection::Equirectangular => y,
+            GeoProjection::WebMercator => {
+                (2.0 * y.to_radians().exp().atan() - std::f64::consts::FRAC_PI_2).to_degrees()
+            }
+        }
+    }
+
+    /// Get the ratio of the width to the height of the undistorted map of the given region. The
+    /// plotting area should have the same aspect ratio to keep the shapes on the map.
+    /// - `lon`: The range of the longitude
+    /// - `lat`: The range of the latitude
+    /// - **returns**: The aspect ratio
+    pub fn aspect_ratio(&self, lon: Range<f64>, lat: Range<f64>) -> f64 {
+        (lon.end - lon.start).abs() / (self.project(lat.end) - self.project(lat.start)).abs()
+    }
+}
+
+/// Get the graticule values within the range, which are the multiples of the largest step
+/// in degrees that gives no more than `max_points` values. The ranges too small for a whole
+/// degree step fall back to the decimal steps.
+fn degree_key_points(range: Range<f64>, max_points: usize) -> Vec<f64> {
+    let (lo, hi) = (range.start.min(range.end), range.start.max(range.end));
+    let count = |step: f64| ((hi / step).floor() - (lo / step).ceil()) as i64 + 1;
+
+    match DEGREE_STEPS
+        .iter()
+        .take_while(|step| count(**step) <= max_points as i64)
+        .last()
+    {
+        Some(&step) if count(step) > 1 => {
+            let first = (lo / step).ceil() as i64;
+            (0..count(step))
+                .map(|idx| (first + idx) as f64 * step)
+                .collect()
+        }
+        _ => {
+            let linear: RangedCoordf64 = (lo..hi).into();
+            linear.key_points(max_points)
+        }
+    }
+}
+
+/// Format the angle in degrees with the hemisphere suffix
+fn format_degree(value: f64, positive: &str, negative: &str) -> String {
+    let printer = FloatPrettyPrinter {
+        allow_scientific: false,
+        min_decimal: 0,
+        max_decimal: 4,
+    };
+    let text = printer.print(value.abs());
+    if text == "0" || text == "180" {
+        format!("{}\u{b0}", text)
+    } else if value > 0.0 {
+        format!("{}\u{b0}{}", text, positive)
+    } else {
+        format!("{}\u{b0}{}", text, negative)
+    }
+}
+
+/// The longitude axis in degrees, where the east is positive
+#[derive(Clone)]
+pub struct Longitude(RangedCoordf64);
+
+impl Longitude {
+    /// Create the longitude axis
+    /// - `range`: The range of the longitude in degrees, from the west to the east
+    pub fn new(range: Range<f64>) -> Self {
+        Self(range.into())
+    }
+}
+
+impl ValueFormatter<f64> for Longitude {
+    fn format(value: &f64) -> String {
+        format_degree(*value, "E", "W")
+    }
+}
+
+impl Ranged for Longitude {
+    type FormatOption = NoDefaultFormatting;
+    type ValueType = f64;
+
+    fn map(&self, value: &f64, limit: (i32, i32)) -> i32 {
+        self.0.map(value, limit)
+    }
+
+    fn key_points<Hint: KeyPointHint>(&self, hint: Hint) -> Vec<f64> {
+        degree_key_points(self.0.range(), hint.max_num_points())
+    }
+
+    fn range(&self) -> Range<f64> {
+        self.0.range()
+    }
+}
+
+impl ReversibleRanged for Longitude {
+    fn unmap(&self, input: i32, limit: (i32, i32)) -> Option<f64> {
+        self.0.unmap(input, limit)
+    }
+}
+
+/// The latitude axis in degrees, where the north is positive
+#[derive(Clone)]
+pub struct Latitude {
+    range: Range<f64>,
+    projection: GeoProjection,
+    projected: RangedCoordf64,
+}
+
+impl Latitude {
+    /// Create the latitude axis
+    /// - `range`: The range of the latitude in degrees, from the south to the north
+    /// - `projection`: The map projection
+    pub fn new(range: Range<f64>, projection: GeoProjection) -> Self {
+        let range = match projection {
+            GeoProjection::Equirectangular => range,
+            GeoProjection::WebMercator => {
+                let clamp = |lat: f64| lat.clamp(-MAX_MERCATOR_LATITUDE, MAX_MERCATOR_LATITUDE);
+                clamp(range.start)..clamp(range.end)
+            }
+        };
+        Self {
+            projected: (projection.project(range.start)..projection.project(range.end)).into(),
+            range,
+            projection,
+        }
+    }
+
+    /// Get the map projection of this axis
+    pub fn projection(&self) -> GeoProjection {
+        self.projection
+    }
+}
+
+impl ValueFormatter<f64> for Latitude {
+    fn format(value: &f64) -> String {
+        format_degree(*value, "N", "S")
+    }
+}
+
+impl Ranged for Latitude {
+    type FormatOption = NoDefaultFormatting;
+    type ValueType = f64;
+
+    fn map(&self, value: &f64, limit: (i32, i32)) -> i32 {
+        self.projected.map(&self.projection.project(*value), limit)
+    }
+
+    fn key_points<Hint: KeyPointHint>(&self, hint: Hint) -> Vec<f64> {
+        degree_key_points(self.range.clone(), hint.max_num_points())
+    }
+
+    fn range(&self) -> Range<f64> {
+        self.range.clone()
+    }
+}
+
+impl ReversibleRanged for Latitude {
+    fn unmap(&self, input: i32, limit: (i32, i32)) -> Option<f64> {
+        self.projected
+            .unmap(input, limit)
+            .map(|y| self.projection.unproject(y))
+    }
+}
+
+/// The geographic coordinate system, which maps `(longitude, latitude)` in degrees to the
+/// screen with the projection of its latitude axis
+pub type GeoCoord = Cartesian2d<Longitude, Latitude>;
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_web_mercator_projection() {
+        let mercator = GeoProjection::WebMercator;
+        assert!(mercator.project(0.0).abs() < 1e-9);
+        assert!((mercator.project(MAX_MERCATOR_LATITUDE) - 180.0).abs() < 1e-6);
+        assert!((mercator.unproject(mercator.project(51.5)) - 51.5).abs() < 1e-9);
+        assert!((mercator.aspect_ratio(-180.0..180.0, -90.0..90.0) - 1.0).abs() < 1e-6);
+
+        let lat = Latitude::new(-90.0..90.0, mercator);
+        assert_eq!(lat.range(), -MAX_MERCATOR_LATITUDE..MAX_MERCATOR_LATITUDE);
+        assert_eq!(lat.map(&0.0, (100, 0)), 50);
+        /* The high latitudes are stretched */
+        assert!(lat.map(&60.0, (100, 0)) < 50 - 2 * (50 - lat.map(&30.0, (100, 0))));
+        let lat_60 = lat.unmap(lat.map(&60.0, (10000, 0)), (10000, 0)).unwrap();
+        assert!((lat_60 - 60.0).abs() < 0.1);
+    }
+
+    #[test]
+    fn test_graticule_key_points() {
+        let lon = Longitude::new(-180.0..180.0);
+        assert_eq!(
+            lon.key_points(10),
+            [-180.0, -135.0, -90.0, -45.0, 0.0, 45.0, 90.0, 135.0, 180.0]
+        );
+        assert_eq!(lon.key_points(5), [-180.0, -90.0, 0.0, 90.0, 180.0]);
+
+        let lat = Latitude::new(-90.0..90.0, GeoProjection::Equirectangular);
+        assert_eq!(
+            lat.key_points(7),
+            [-90.0, -60.0, -30.0, 0.0, 30.0, 60.0, 90.0]
+        );
+
+        let lat = Latitude::new(47.1..48.3, GeoProjection::WebMercator);
+        let points = lat.key_points(5);
+        assert!(!points.is_empty() && points.iter().all(|v| (47.1..=48.3).contains(v)));
+
+        assert_eq!(lon.format_ext(&-120.0), "120\u{b0}W");
+        assert_eq!(lon.format_ext(&180.0), "180\u{b0}");
+        assert_eq!(lat.format_ext(&47.5), "47.5\u{b0}N");
+        assert_eq!(lat.format_ext(&0.0), "0\u{b0}");
+        assert_eq!(lat.format_ext(&-33.25), "33.25\u{b0}S");
+    }
+}
@@ -1 +1,5 @@
 pub mod cartesian;
+pub mod geo;
+pub mod polar;
+pub mod ternary;
+pub mod transformed;
@@ -0,0 +1,239 @@
+/*!
+ The 2-dimensional polar coordinate system.
+
+ This module provides the polar coordinate system, which is composed by an angular and a radial
+ ranged 1D coordinate specification. The whole range of the angular coordinate covers a full
+ turn, starting from the top of the circle and going clockwise.
+
+ This type of coordinate system is used by the chart constructed with [ChartBuilder::build_polar](../../chart/ChartBuilder.html#method.build_polar).
+
+ The existing series and elements work with this coordinate system, for example, a closed
+ `LineSeries` draws a radar chart, and the `Polygon` elements filled with the outlines from
+ [PolarCoord::sector](struct.PolarCoord.html#method.sector) draw a rose chart.
+*/
+
+use crate::coord::ranged1d::{Ranged, ReversibleRanged};
+use crate::coord::{CoordTranslate, ReverseCoordTranslate};
+
+use plotters_backend::BackendCoord;
+
+use std::f64::consts::PI;
+use std::ops::Range;
+
+/// The resolution used to map the angular coordinate to the fraction of a full turn
+const ANGLE_RESOLUTION: i32 = 1 << 20;
+
+/// The maximum length in pixels of the straight segments that approximate an arc
+const ARC_STEP: f64 = 2.0;
+
+/// A polar coordinate system described by an angular and a radial 1D ranged coordinate spec.
+#[derive(Clone)]
+pub struct PolarCoord<A: Ranged, R: Ranged> {
+    logic_angle: A,
+    logic_radius: R,
+    center: BackendCoord,
+    radius: i32,
+}
+
+impl<A: Ranged, R: Ranged> PolarCoord<A, R> {
+    /// Create a new polar coordinate system, the circle is the largest one that fits the
+    /// given pixel range.
+    /// - `logic_angle` and `logic_radius` : The description for the 1D coordinate system
+    /// - `actual`: The pixel range on the screen for this coordinate system
+    pub fn new<IntoA: Into<A>, IntoR: Into<R>>(
+        logic_angle: IntoA,
+        logic_radius: IntoR,
+        actual: (Range<i32>, Range<i32>),
+    ) -> Self {
+        let (w, h) = (
+            (actual.0.end - actual.0.start).abs(),
+            (actual.1.end - actual.1.start).abs(),
+        );
+        Self {
+            logic_angle: logic_angle.into(),
+            logic_radius: logic_radius.into(),
+            center: (
+                (actual.0.start + actual.0.end) / 2,
+                (actual.1.start + actual.1.end) / 2,
+            ),
+            radius: w.min(h) / 2,
+        }
+    }
+
+    /// Get the backend coordinate of the center of the circle
+    pub fn get_center(&self) -> BackendCoord {
+        self.center
+    }
+
+    /// Get the radius of the outer circle in pixels
+    pub fn get_radius(&self) -> i32 {
+        self.radius
+    }
+
+    /// Get the range of the angular axis
+    pub fn get_angle_range(&self) -> Range<A::ValueType> {
+        self.logic_angle.range()
+    }
+
+    /// Get the range of the radial axis
+    pub fn get_radius_range(&self) -> Range<R::ValueType> {
+        self.logic_radius.range()
+    }
+
+    /// Get the 1D coordinate spec for the angular axis
+    pub fn angle_spec(&self) -> &A {
+        &self.logic_angle
+    }
+
+    /// Get the 1D coordinate spec for the radial axis
+    pub fn radius_spec(&self) -> &R {
+        &self.logic_radius
+    }
+
+    /// Get the angle in radians for the given angular value, measured clockwise from the top
+    pub fn map_angle(&self, angle: &A::ValueType) -> f64 {
+        let pos = self.logic_angle.map(angle, (0, ANGLE_RESOLUTION));
+        f64::from(pos) / f64::from(ANGLE_RESOLUTION) * 2.0 * PI
+    }
+
+    /// Get the distance from the center in pixels for the given radial value
+    pub fn map_radius(&self, radius: &R::ValueType) -> i32 {
+        self.logic_radius.map(radius, (0, self.radius))
+    }
+
+    /// Get the backend coordinate of the point with the given angle in radians and the given
+    /// distance to the center in pixels
+    pub fn point_at(&self, angle: f64, radius: f64) -> BackendCoord {
+        (
+            self.center.0 + (radius * angle.sin()).round() as i32,
+            self.center.1 - (radius * angle.cos()).round() as i32,
+        )
+    }
+
+    /// Get the outline of the annular sector between the given angles and radii, which can be
+    /// filled with the `Polygon` element, for example, to draw the wedges of a rose chart. The
+    /// arcs are approximated by the points on the arcs that are at most a few pixels apart.
+    /// - `angle`: The angular range of the sector, which goes clockwise from the start angle
+    /// - `radius`: The inner and outer radius of the sector
+    /// - **returns**: The vertices of the outline in the polar coordinate
+    pub fn sector(
+        &self,
+        angle: Range<A::ValueType>,
+        radius: Range<R::ValueType>,
+    ) -> Vec<(A::ValueType, R::ValueType)>
+    where
+        A: ReversibleRanged,
+        A::ValueType: Clone,
+        R::ValueType: Clone,
+    {
+        let limit = (0, ANGLE_RESOLUTION);
+        let (start, end) = (
+            self.logic_angle.map(&angle.start, limit),
+            self.logic_angle.map(&angle.end, limit),
+        );
+        let outer = self
+            .map_radius(&radius.start)
+            .max(self.map_radius(&radius.end));
+        let sweep = f64::from(end - start).abs() / f64::from(ANGLE_RESOLUTION) * 2.0 * PI;
+        let steps = ((sweep * f64::from(outer) / ARC_STEP).ceil() as i32).max(1);
+
+        let mut arc = vec![angle.start.clone()];
+        arc.extend((1..steps).filter_map(|i| {
+            let pos = f64::from(start) + f64::from(end - start) * f64::from(i) / f64::from(steps);
+            self.logic_angle.unmap(pos.round() as i32, limit)
+        }));
+        arc.push(angle.end);
+
+        let mut outline: Vec<_> = arc
+            .iter()
+            .map(|a| (a.clone(), radius.end.clone()))
+            .collect();
+        outline.extend(arc.into_iter().rev().map(|a| (a, radius.start.clone())));
+        outline
+    }
+}
+
+impl<A: Ranged, R: Ranged> CoordTranslate for PolarCoord<A, R> {
+    type From = (A::ValueType, R::ValueType);
+
+    fn translate(&self, from: &Self::From) -> BackendCoord {
+        self.point_at(self.map_angle(&from.0), f64::from(self.map_radius(&from.1)))
+    }
+}
+
+impl<A: ReversibleRanged, R: ReversibleRanged> ReverseCoordTranslate for PolarCoord<A, R> {
+    fn reverse_translate(&self, input: BackendCoord) -> Option<Self::From> {
+        let (dx, dy) = (
+            f64::from(input.0 - self.center.0),
+            f64::from(self.center.1 - input.1),
+        );
+        let distance = dx.hypot(dy).round() as i32;
+        if distance > self.radius {
+            return None;
+        }
+        let mut angle = dx.atan2(dy);
+        if angle < 0.0 {
+            angle += 2.0 * PI;
+        }
+        let pos = (angle / (2.0 * PI) * f64::from(ANGLE_RESOLUTION)).round() as i32;
+        Some((
+            self.logic_angle.unmap(pos, (0, ANGLE_RESOLUTION))?,
+            self.logic_radius.unmap(distance, (0, self.radius))?,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::coord::types::RangedCoordf64;
+
+    #[test]
+    fn test_polar_coord() {
+        let coord: PolarCoord<RangedCoordf64, RangedCoordf64> =
+            PolarCoord::new(0.0..360.0, 0.0..10.0, (0..200, 0..100));
+
+        assert_eq!(coord.get_center(), (100, 50));
+        assert_eq!(coord.get_radius(), 50);
+        assert_eq!(coord.translate(&(0.0, 0.0)), (100, 50));
+        assert_eq!(coord.translate(&(0.0, 10.0)), (100, 0));
+        assert_eq!(coord.translate(&(90.0, 10.0)), (150, 50));
+        assert_eq!(coord.translate(&(180.0, 5.0)), (100, 75));
+        assert_eq!(coord.translate(&(270.0, 10.0)), (50, 50));
+    }
+
+    #[test]
+    fn test_polar_reverse_translate() {
+        let coord: PolarCoord<RangedCoordf64, RangedCoordf64> =
+            PolarCoord::new(0.0..360.0, 0.0..10.0, (0..200, 0..100));
+
+        let (angle, radius) = coord.reverse_translate((150, 50)).unwrap();
+        assert!((angle - 90.0).abs() < 1e-3);
+        assert!((radius - 10.0).abs() < 1e-3);
+        let (angle, radius) = coord.reverse_translate((100, 75)).unwrap();
+        assert!((angle - 180.0).abs() < 1e-3);
+        assert!((radius - 5.0).abs() < 1e-3);
+        let (angle, _) = coord.reverse_translate((75, 25)).unwrap();
+        assert!((angle - 315.0).abs() < 1e-3);
+        assert!(coord.reverse_translate((0, 0)).is_none());
+    }
+
+    #[test]
+    fn test_polar_sector() {
+        let coord: PolarCoord<RangedCoordf64, RangedCoordf64> =
+            PolarCoord::new(0.0..360.0, 0.0..10.0, (0..200, 0..200));
+
+        let outline = coord.sector(0.0..90.0, 5.0..10.0);
+        let n = outline.len() / 2;
+        assert_eq!(outline.len(), n * 2);
+        assert_eq!(outline[0], (0.0, 10.0));
+        assert_eq!(outline[n - 1], (90.0, 10.0));
+        assert_eq!(outline[n], (90.0, 5.0));
+        assert_eq!(outline[2 * n - 1], (0.0, 5.0));
+        for pair in outline[..n].windows(2) {
+            assert!(pair[0].0 < pair[1].0);
+            let (a, b) = (coord.translate(&pair[0]), coord.translate(&pair[1]));
+            assert!((a.0 - b.0).abs() <= 3 && (a.1 - b.1).abs() <= 3);
+        }
+    }
+}
@@ -0,0 +1,119 @@
+/*!
+ The ternary coordinate system.
+
+ This module provides the coordinate system for the three-component compositional data, where
+ the components of each point add up to a constant whole. The point is mapped to an equilateral
+ triangle, each vertex of which stands for the pure composition of one component: the first
+ component at the top, the second one at the bottom left and the third one at the bottom right.
+
+ This type of coordinate system is used by the chart constructed with [ChartBuilder::build_ternary](../../chart/ChartBuilder.html#method.build_ternary).
+*/
+
+use crate::coord::{CoordTranslate, ReverseCoordTranslate};
+
+use plotters_backend::BackendCoord;
+
+use std::ops::Range;
+
+/// A ternary coordinate system which maps the compositions `(a, b, c)` to an equilateral
+/// triangle. The components don't need to be normalized, they are divided by their sum.
+#[derive(Clone, Debug)]
+pub struct TernaryCoord {
+    vertices: [(f64, f64); 3],
+}
+
+impl TernaryCoord {
+    /// Create a new ternary coordinate system, the triangle is the largest equilateral triangle
+    /// that fits the given pixel range.
+    /// - `actual`: The pixel range on the screen for this coordinate system
+    pub fn new(actual: (Range<i32>, Range<i32>)) -> Self {
+        let (w, h) = (
+            f64::from((actual.0.end - actual.0.start).abs()),
+            f64::from((actual.1.end - actual.1.start).abs()),
+        );
+        let side = w.min(h * 2.0 / 3f64.sqrt());
+        let height = side * 3f64.sqrt() / 2.0;
+        let (cx, cy) = (
+            f64::from(actual.0.start + actual.0.end) / 2.0,
+            f64::from(actual.1.start + actual.1.end) / 2.0,
+        );
+        Self {
+            vertices: [
+                (cx, cy - height / 2.0),
+                (cx - side / 2.0, cy + height / 2.0),
+                (cx + side / 2.0, cy + height / 2.0),
+            ],
+        }
+    }
+
+    /// Get the backend coordinates of the vertices for the first, second and third component
+    pub fn get_vertices(&self) -> [BackendCoord; 3] {
+        let round = |(x, y): (f64, f64)| (x.round() as i32, y.round() as i32);
+        [
+            round(self.vertices[0]),
+            round(self.vertices[1]),
+            round(self.vertices[2]),
+        ]
+    }
+
+    /// Get the length of the triangle sides in pixels
+    pub fn get_side(&self) -> f64 {
+        self.vertices[2].0 - self.vertices[1].0
+    }
+}
+
+impl CoordTranslate for TernaryCoord {
+    type From = (f64, f64, f64);
+
+    fn translate(&self, &(a, b, c): &Self::From) -> BackendCoord {
+        let sum = a + b + c;
+        let sum = if sum.abs() > 0.0 { sum } else { 1.0 };
+        let [va, vb, vc] = self.vertices;
+        (
+            ((a * va.0 + b * vb.0 + c * vc.0) / sum).round() as i32,
+            ((a * va.1 + b * vb.1 + c * vc.1) / sum).round() as i32,
+        )
+    }
+}
+
+impl ReverseCoordTranslate for TernaryCoord {
+    fn reverse_translate(&self, input: BackendCoord) -> Option<Self::From> {
+        let [va, vb, vc] = self.vertices;
+        let (x, y) = (f64::from(input.0), f64::from(input.1));
+        let det = (vb.1 - vc.1) * (va.0 - vc.0) + (vc.0 - vb.0) * (va.1 - vc.1);
+        if det.abs() < 1.0 {
+            return None;
+        }
+        let a = ((vb.1 - vc.1) * (x - vc.0) + (vc.0 - vb.0) * (y - vc.1)) / det;
+        let b = ((vc.1 - va.1) * (x - vc.0) + (va.0 - vc.0) * (y - vc.1)) / det;
+        let c = 1.0 - a - b;
+        /* The pixels on the edges may be slightly outside of the exact triangle after rounding */
+        let tolerance = 1.0 / self.get_side();
+        if a < -tolerance || b < -tolerance || c < -tolerance {
+            return None;
+        }
+        let (a, b, c) = (a.max(0.0), b.max(0.0), c.max(0.0));
+        let sum = a + b + c;
+        Some((a / sum, b / sum, c / sum))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_ternary_coord() {
+        let coord = TernaryCoord::new((0..200, 0..200));
+
+        assert_eq!(coord.get_vertices(), [(100, 13), (0, 187), (200, 187)]);
+        assert_eq!(coord.translate(&(1.0, 0.0, 0.0)), (100, 13));
+        assert_eq!(coord.translate(&(0.0, 2.0, 0.0)), (0, 187));
+        assert_eq!(coord.translate(&(0.0, 0.5, 0.5)), (100, 187));
+        assert_eq!(coord.translate(&(1.0, 1.0, 1.0)), (100, 129));
+
+        let (a, b, c) = coord.reverse_translate((100, 187)).unwrap();
+        assert!(a.abs() < 1e-6 && (b - 0.5).abs() < 1e-6 && (c - 0.5).abs() < 1e-6);
+        assert!(coord.reverse_translate((10, 20)).is_none());
+    }
+}
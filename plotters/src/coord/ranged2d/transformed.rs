@@ -0,0 +1,212 @@
+/*!
+ The affine transformed coordinate system.
+
+ This module provides a coordinate adapter which applies an affine transform, such as a
+ rotation, a shear or a scaling, to the backend coordinate after the mapping of the inner
+ coordinate. Since the affine transforms keep the straight lines straight, all the elements
+ drawn on the inner coordinate are transformed as a whole, which makes the skewed axes and the
+ rotated plots possible without any support from the backend.
+
+ A 2D Cartesian chart can be transformed with [ChartContext::transformed](../../chart/struct.ChartContext.html#method.transformed).
+*/
+
+use crate::coord::{CoordTranslate, ReverseCoordTranslate};
+
+use plotters_backend::BackendCoord;
+
+/// A 2D affine transform of the backend coordinate, which maps `(x, y)` to
+/// `(a * x + b * y + c, d * x + e * y + f)` for the matrix `[[a, b, c], [d, e, f]]`
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct AffineTransform {
+    /// The first two rows of the 3x3 transform matrix
+    pub matrix: [[f64; 3]; 2],
+}
+
+impl Default for AffineTransform {
+    fn default() -> Self {
+        Self::identity()
+    }
+}
+
+impl AffineTransform {
+    /// Create the transform which keeps every point where it is
+    pub fn identity() -> Self {
+        Self {
+            matrix: [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0]],
+        }
+    }
+
+    /// Create the transform which moves the points
+    /// - `dx`: The horizontal distance in pixels
+    /// - `dy`: The vertical distance in pixels
+    pub fn translate(dx: f64, dy: f64) -> Self {
+        Self {
+            matrix: [[1.0, 0.0, dx], [0.0, 1.0, dy]],
+        }
+    }
+
+    /// Create the transform which scales the points from the origin
+    /// - `sx`: The horizontal scale
+    /// - `sy`: The vertical scale
+    pub fn scale(sx: f64, sy: f64) -> Self {
+        Self {
+            matrix: [[sx, 0.0, 0.0], [0.0, sy, 0.0]],
+        }
+    }
+
+    /// Create the transform which rotates the points around the origin. Since the Y axis of the
+    /// backend points downwards, the positive angles rotate the points clockwise on the screen.
+    /// - `degrees`: The angle in degrees
+    pub fn rotate(degrees: f64) -> Self {
+        let (sin, cos) = degrees.to_radians().sin_cos();
+        Self {
+            matrix: [[cos, -sin, 0.0], [sin, cos, 0.0]],
+        }
+    }
+
+    /// Create the transform which shears the points, so that `x` moves by `kx * y` and `y` moves
+    /// by `ky * x`
+    /// - `kx`: The horizontal shear factor
+    /// - `ky`: The vertical shear factor
+    pub fn shear(kx: f64, ky: f64) -> Self {
+        Self {
+            matrix: [[1.0, kx, 0.0], [ky, 1.0, 0.0]],
+        }
+    }
+
+    /// Combine this transform with the one applied after it
+    /// - `next`: The transform applied after this one
+    /// - **returns**: The combined transform
+    pub fn then(&self, next: &AffineTransform) -> Self {
+        let (m, n) = (&self.matrix, &next.matrix);
+        let row = |r: usize| {
+            [
+                n[r][0] * m[0][0] + n[r][1] * m[1][0],
+                n[r][0] * m[0][1] + n[r][1] * m[1][1],
+                n[r][0] * m[0][2] + n[r][1] * m[1][2] + n[r][2],
+            ]
+        };
+        Self {
+            matrix: [row(0), row(1)],
+        }
+    }
+
+    /// Make the transform work around the given pivot instead of the origin, for example, to
+    /// rotate around the center of the plotting area
+    /// - `pivot`: The backend coordinate of the pivot
+    /// - **returns**: The transform which keeps the pivot where it is
+    pub fn about(&self, (x, y): (f64, f64)) -> Self {
+        Self::translate(-x, -y)
+            .then(self)
+            .then(&Self::translate(x, y))
+    }
+
+    /// Get the inverse transform, if the transform is invertible
+    pub fn inverse(&self) -> Option<Self> {
+        let [[a, b, c], [d, e, f]] = self.matrix;
+        let det = a * e - b * d;
+        if det.abs() < 1e-12 {
+            return None;
+        }
+        Some(Self {
+            matrix: [
+                [e / det, -b / det, (b * f - c * e) / det],
+                [-d / det, a / det, (c * d - a * f) / det],
+            ],
+        })
+    }
+
+    /// Apply the transform to the point
+    pub fn apply(&self, (x, y): (f64, f64)) -> (f64, f64) {
+        let [[a, b, c], [d, e, f]] = self.matrix;
+        (a * x + b * y + c, d * x + e * y + f)
+    }
+
+    /// Apply the transform to the backend coordinate, the result is rounded to the pixel
+    pub fn apply_coord(&self, (x, y): BackendCoord) -> BackendCoord {
+        let (x, y) = self.apply((f64::from(x), f64::from(y)));
+        (x.round() as i32, y.round() as i32)
+    }
+}
+
+/// The coordinate system which applies an affine transform after the inner coordinate maps the
+/// values to the backend coordinate
+#[derive(Clone)]
+pub struct Transformed<C: CoordTranslate> {
+    inner: C,
+    transform: AffineTransform,
+}
+
+impl<C: CoordTranslate> Transformed<C> {
+    /// Create the transformed coordinate system
+    /// - `inner`: The coordinate system which maps the values to the backend coordinate
+    /// - `transform`: The transform applied to the backend coordinate, in which the origin is
+    ///   the upper left corner of the backend
+    pub fn new(inner: C, transform: AffineTransform) -> Self {
+        Self { inner, transform }
+    }
+
+    /// Get the inner coordinate system
+    pub fn inner(&self) -> &C {
+        &self.inner
+    }
+
+    /// Get the transform applied after the inner coordinate system
+    pub fn transform(&self) -> &AffineTransform {
+        &self.transform
+    }
+}
+
+impl<C: CoordTranslate> CoordTranslate for Transformed<C> {
+    type From = C::From;
+
+    fn translate(&self, from: &Self::From) -> BackendCoord {
+        self.transform.apply_coord(self.inner.translate(from))
+    }
+
+    fn depth(&self, from: &Self::From) -> i32 {
+        self.inner.depth(from)
+    }
+}
+
+impl<C: ReverseCoordTranslate> ReverseCoordTranslate for Transformed<C> {
+    fn reverse_translate(&self, input: BackendCoord) -> Option<Self::From> {
+        let inverse = self.transform.inverse()?;
+        self.inner.reverse_translate(inverse.apply_coord(input))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::coord::cartesian::Cartesian2d;
+    use crate::coord::types::RangedCoordi32;
+
+    #[test]
+    fn test_affine_transform() {
+        let rotate = AffineTransform::rotate(90.0).about((10.0, 10.0));
+        assert_eq!(rotate.apply_coord((10, 10)), (10, 10));
+        assert_eq!(rotate.apply_coord((20, 10)), (10, 20));
+
+        let shear = AffineTransform::shear(0.5, 0.0);
+        assert_eq!(shear.apply_coord((0, 10)), (5, 10));
+
+        let combined = AffineTransform::scale(2.0, 3.0).then(&AffineTransform::translate(1.0, 1.0));
+        assert_eq!(combined.apply_coord((1, 1)), (3, 4));
+        let back = combined.inverse().unwrap().apply((3.0, 4.0));
+        assert!((back.0 - 1.0).abs() < 1e-9 && (back.1 - 1.0).abs() < 1e-9);
+        assert!(AffineTransform::scale(0.0, 1.0).inverse().is_none());
+    }
+
+    #[test]
+    fn test_transformed_coord() {
+        let inner: Cartesian2d<RangedCoordi32, RangedCoordi32> =
+            Cartesian2d::new(0..100, 0..100, (0..100, 0..100));
+        let coord = Transformed::new(inner, AffineTransform::shear(0.5, 0.0));
+
+        assert_eq!(coord.translate(&(0, 0)), (0, 0));
+        assert_eq!(coord.translate(&(0, 100)), (50, 100));
+        assert_eq!(coord.reverse_translate((25, 50)), Some((0, 50)));
+        assert_eq!(coord.reverse_translate((75, 50)), Some((50, 50)));
+    }
+}
@@ -48,12 +48,7 @@ impl Mul<ProjectionMatrix> for ProjectionMatrix {
 impl Mul<(i32, i32, i32)> for ProjectionMatrix {
     type Output = (i32, i32);
     fn mul(self, (x, y, z): (i32, i32, i32)) -> (i32, i32) {
-        let (x, y, z) = (x as f64, y as f64, z as f64);
-        let m = self.0;
-        (
-            (x * m[0][0] + y * m[0][1] + z * m[0][2] + m[0][3]) as i32,
-            (x * m[1][0] + y * m[1][1] + z * m[1][2] + m[1][3]) as i32,
-        )
+        self * (x as f64, y as f64, z as f64)
     }
 }
 
@@ -61,9 +56,11 @@ impl Mul<(f64, f64, f64)> for ProjectionMatrix {
     type Output = (i32, i32);
     fn mul(self, (x, y, z): (f64, f64, f64)) -> (i32, i32) {
         let m = self.0;
+        /* The points behind the viewer of a perspective projection are pushed to the infinity */
+        let w = (x * m[3][0] + y * m[3][1] + z * m[3][2] + m[3][3]).max(1e-3);
         (
-            (x * m[0][0] + y * m[0][1] + z * m[0][2] + m[0][3]) as i32,
-            (x * m[1][0] + y * m[1][1] + z * m[1][2] + m[1][3]) as i32,
+            ((x * m[0][0] + y * m[0][1] + z * m[0][2] + m[0][3]) / w) as i32,
+            ((x * m[1][0] + y * m[1][1] + z * m[1][2] + m[1][3]) / w) as i32,
         )
     }
 }
@@ -121,6 +118,17 @@ impl ProjectionMatrix {
             [0.0, 0.0, 0.0, 1.0 / factor],
         ])
     }
+    /// Returns the matrix of the perspective projection, which shrinks the points farther from
+    /// the screen
+    /// - `distance`: The distance in pixels from the viewer to the plane of zero depth
+    pub fn perspective(distance: f64) -> Self {
+        ProjectionMatrix([
+            [1.0, 0.0, 0.0, 0.0],
+            [0.0, 1.0, 0.0, 0.0],
+            [0.0, 0.0, 1.0, 0.0],
+            [0.0, 0.0, 1.0 / distance, 1.0],
+        ])
+    }
     /// Normalize the matrix, this will make the metric unit to 1
     pub fn normalize(&mut self) {
         if self.0[3][3] > 1e-20 {
@@ -142,12 +150,17 @@ impl ProjectionMatrix {
 /// The helper struct to build a projection matrix
 #[derive(Copy, Clone)]
 pub struct ProjectionMatrixBuilder {
-    /// Specifies the yaw of the 3D coordinate system
+    /// Specifies the yaw of the 3D coordinate system, i.e. the azimuth of the view
     pub yaw: f64,
-    /// Specifies the pitch of the 3D coordinate system
+    /// Specifies the pitch of the 3D coordinate system, i.e. the elevation of the view
     pub pitch: f64,
     /// Specifies the scale of the 3D coordinate system
     pub scale: f64,
+    /// Specifies the strength of the perspective. The default value, zero, means the parallel
+    /// projection. Otherwise the viewer is placed at the distance of the size of the 3D box
+    /// divided by this value from the center of the box, so the far side of the box looks
+    /// smaller. The values between 0.2 and 0.5 give a moderate perspective effect.
+    pub perspective: f64,
     pivot_before: (i32, i32, i32),
     pivot_after: (i32, i32),
 }
@@ -158,6 +171,7 @@ impl Default for ProjectionMatrixBuilder {
             yaw: 0.5,
             pitch: 0.15,
             scale: 1.0,
+            perspective: 0.0,
             pivot_after: (0, 0),
             pivot_before: (0, 0, 0),
         }
@@ -199,6 +213,12 @@ impl ProjectionMatrixBuilder {
             ret = ret * ProjectionMatrix::scale(self.scale);
         }
 
+        if self.perspective > 1e-20 {
+            let (x, y, z) = self.pivot_before;
+            let size = 2.0 * f64::from(x.max(y).max(z).max(1)) * self.scale;
+            ret = ret * ProjectionMatrix::perspective(size / self.perspective);
+        }
+
         if self.pivot_after != (0, 0) {
             let (x, y) = self.pivot_after;
             ret = ret * ProjectionMatrix::shift(x as f64, y as f64, 0.0);
@@ -207,3 +227,36 @@ impl ProjectionMatrixBuilder {
         ret
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_perspective_projection() {
+        let mut pb = ProjectionMatrixBuilder::new();
+        pb.set_pivot((50, 50, 50), (100, 100));
+        let parallel = pb.into_matrix();
+
+        pb.perspective = 0.5;
+        let perspective = pb.into_matrix();
+
+        /* The center of the box stays at the pivot */
+        let (center, expected) = (perspective * (50, 50, 50), parallel * (50, 50, 50));
+        assert!((center.0 - expected.0).abs() <= 1 && (center.1 - expected.1).abs() <= 1);
+
+        let offset = |m: ProjectionMatrix, p: (i32, i32, i32)| {
+            let (x, y) = m * p;
+            ((x - 100).pow(2) + (y - 100).pow(2)) as f64
+        };
+        let corners = [(0, 0, 0), (100, 0, 0), (0, 100, 100), (100, 100, 100)];
+        for &corner in corners.iter() {
+            let far = perspective.projected_depth(corner) > parallel.projected_depth((50, 50, 50));
+            if far {
+                assert!(offset(perspective, corner) < offset(parallel, corner));
+            } else {
+                assert!(offset(perspective, corner) > offset(parallel, corner));
+            }
+        }
+    }
+}
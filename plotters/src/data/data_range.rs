@@ -2,7 +2,7 @@ use std::cmp::{Ordering, PartialOrd};
 use std::iter::IntoIterator;
 use std::ops::Range;
 
-use num_traits::{One, Zero};
+use num_traits::{Float, One, Zero};
 
 /// Build a range that fits the data
 ///
@@ -40,3 +40,49 @@ where
 
     lb.unwrap_or_else(Zero::zero)..ub.unwrap_or_else(One::one)
 }
+
+/// Build a range that fits the floating point data, with a margin on both sides, so that the
+/// extreme values are not drawn right on the edges of the plotting area. The values that are
+/// not finite, such as `NaN`, are ignored.
+///
+/// - `iter`: the iterator over the data
+/// - `margin`: the margin on each side, as a fraction of the span of the data
+/// - **returns** The resulting range. The range of the constant data is expanded around
+///   the value, and the range of the data without any finite value is `0..1`.
+///
+/// ```rust
+/// use plotters::data::fitting_range_with_margin;
+///
+/// let data = [4.0, 14.0, f64::NAN, -2.0, 2.0];
+/// let range = fitting_range_with_margin(data.iter().copied(), 0.125);
+/// assert_eq!(range, -4.0..16.0);
+/// ```
+pub fn fitting_range_with_margin<T: Float, I: IntoIterator<Item = T>>(
+    iter: I,
+    margin: f64,
+) -> Range<T> {
+    let (mut lb, mut ub) = (T::infinity(), T::neg_infinity());
+    for value in iter.into_iter().filter(|v| v.is_finite()) {
+        lb = lb.min(value);
+        ub = ub.max(value);
+    }
+
+    if lb > ub {
+        return T::zero()..T::one();
+    }
+
+    let two = T::one() + T::one();
+    if lb == ub {
+        /* The range of the constant data is as wide as the magnitude of the value */
+        let half = if lb.is_zero() {
+            T::one() / two
+        } else {
+            lb.abs() / two
+        };
+        lb = lb - half;
+        ub = ub + half;
+    }
+
+    let pad = T::from(margin).map_or_else(T::zero, |margin| (ub - lb) * margin);
+    lb - pad..ub + pad
+}
@@ -0,0 +1,205 @@
+use std::ops::Range;
+
+use super::Quartiles;
+
+/// The kernel of a [`KernelDensity`] estimate
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Kernel {
+    /// The standard normal kernel, which has the unbounded support
+    Gaussian,
+    /// The parabolic kernel `3/4 (1 - u^2)`, which is zero beyond one bandwidth from the samples
+    Epanechnikov,
+}
+
+impl Kernel {
+    fn weight(self, u: f64) -> f64 {
+        match self {
+            Kernel::Gaussian => (-0.5 * u * u).exp() / (2.0 * std::f64::consts::PI).sqrt(),
+            Kernel::Epanechnikov if u.abs() < 1.0 => 0.75 * (1.0 - u * u),
+            Kernel::Epanechnikov => 0.0,
+        }
+    }
+
+    /// The ratio of the bandwidth which smooths as much as the unit Gaussian bandwidth
+    fn equivalent_bandwidth(self) -> f64 {
+        match self {
+            Kernel::Gaussian => 1.0,
+            Kernel::Epanechnikov => 2.214,
+        }
+    }
+
+    /// The number of the bandwidths beyond which the weight is negligible
+    fn reach(self) -> f64 {
+        match self {
+            Kernel::Gaussian => 3.0,
+            Kernel::Epanechnikov => 1.0,
+        }
+    }
+}
+
+/// The kernel density estimate of the raw samples
+///
+/// The bandwidth of the kernel follows Silverman's rule of thumb unless it's set by
+/// [`KernelDensity::bandwidth()`], and the non-finite samples are ignored.
+///
+/// ```rust
+/// use plotters::prelude::*;
+///
+/// let kde = KernelDensity::new([1.0, 2.0, 3.0]).bandwidth(1.0);
+/// assert!(kde.density(2.0) > kde.density(1.0));
+/// assert_eq!(kde.support(), -2.0..6.0);
+/// ```
+#[derive(Clone, Debug)]
+pub struct KernelDensity {
+    samples: Vec<f64>,
+    kernel: Kernel,
+    bandwidth: Option<f64>,
+}
+
+impl KernelDensity {
+    /// Create a Gaussian kernel density estimate of the samples
+    ///
+    /// - `samples`: The raw samples
+    /// - **returns** The newly created estimate
+    pub fn new<T: Into<f64>, I: IntoIterator<Item = T>>(samples: I) -> Self {
+        Self {
+            samples: samples
+                .into_iter()
+                .map(Into::into)
+                .filter(|v: &f64| v.is_finite())
+                .collect(),
+            kernel: Kernel::Gaussian,
+            bandwidth: None,
+        }
+    }
+
+    /// Set the kernel of the estimate
+    pub fn kernel(mut self, kernel: Kernel) -> Self {
+        self.kernel = kernel;
+        self
+    }
+
+    /// Set the bandwidth of the kernel in the unit of the samples, the non-positive bandwidths
+    /// restore Silverman's rule of thumb
+    pub fn bandwidth(mut self, bandwidth: f64) -> Self {
+        self.bandwidth = Some(bandwidth).filter(|h| *h > 0.0);
+        self
+    }
+
+    /// Get the samples of the estimate
+    pub fn samples(&self) -> &[f64] {
+        &self.samples
+    }
+
+    /// Get the bandwidth of the kernel in effect
+    pub fn get_bandwidth(&self) -> f64 {
+        self.bandwidth.unwrap_or_else(|| {
+            silverman_bandwidth(&self.samples) * self.kernel.equivalent_bandwidth()
+        })
+    }
+
+    /// Get the estimated probability density at a value
+    pub fn density(&self, x: f64) -> f64 {
+        self.density_with(x, self.get_bandwidth())
+    }
+
+    fn density_with(&self, x: f64, h: f64) -> f64 {
+        if self.samples.is_empty() {
+            return 0.0;
+        }
+        let total: f64 = self
+            .samples
+            .iter()
+            .map(|v| self.kernel.weight((x - v) / h))
+            .sum();
+        total / (self.samples.len() as f64 * h)
+    }
+
+    /// Get the range beyond which the density is negligible, which is empty without any sample
+    pub fn support(&self) -> Range<f64> {
+        let (min, max) = self
+            .samples
+            .iter()
+            .fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), v| {
+                (min.min(*v), max.max(*v))
+            });
+        if min > max {
+            return 0.0..0.0;
+        }
+        let reach = self.get_bandwidth() * self.kernel.reach();
+        min - reach..max + reach
+    }
+
+    /// Sample the density at the evenly spaced values of a range
+    ///
+    /// - `range`: The range of the values, including both of its ends
+    /// - `resolution`: The number of the values, which is at least 2
+    /// - **returns** The values with their densities
+    pub fn sample(&self, range: Range<f64>, resolution: usize) -> Vec<(f64, f64)> {
+        let resolution = resolution.max(2);
+        let h = self.get_bandwidth();
+        (0..resolution)
+            .map(|idx| {
+                let x =
+                    range.start + (range.end - range.start) * idx as f64 / (resolution - 1) as f64;
+                (x, self.density_with(x, h))
+            })
+            .collect()
+    }
+}
+
+/// Estimate the bandwidth of the Gaussian kernel with Silverman's rule of thumb
+fn silverman_bandwidth(samples: &[f64]) -> f64 {
+    if samples.is_empty() {
+        return 1.0;
+    }
+    let n = samples.len() as f64;
+    let mean = samples.iter().sum::<f64>() / n;
+    let sd = (samples.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / n).sqrt();
+    let [_, lower, _, upper, _] = Quartiles::new(samples).values();
+    let spread = match f64::from(upper - lower) / 1.34 {
+        iqr if iqr > 0.0 && iqr < sd => iqr,
+        _ => sd,
+    };
+    if spread > 0.0 {
+        0.9 * spread * n.powf(-0.2)
+    } else {
+        1.0
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_kernel_density() {
+        assert_eq!(silverman_bandwidth(&[3.0, 3.0]), 1.0);
+        let samples: Vec<_> = (0..100).map(f64::from).collect();
+        let h = silverman_bandwidth(&samples);
+        assert!(h > 5.0 && h < 15.0);
+
+        let kde = KernelDensity::new([0.0, 1.0, 2.0, f64::NAN]).bandwidth(1.0);
+        assert_eq!(kde.samples(), [0.0, 1.0, 2.0]);
+        let density = kde.sample(0.0..2.0, 5);
+        assert_eq!(density.len(), 5);
+        assert_eq!(density[0].0, 0.0);
+        assert_eq!(density[4].0, 2.0);
+        assert!(density[2].1 > density[0].1);
+        assert!((density[0].1 - density[4].1).abs() < 1e-9);
+
+        let total: f64 = kde.sample(-5.0..7.0, 1201).iter().map(|p| p.1 * 0.01).sum();
+        assert!((total - 1.0).abs() < 1e-3);
+
+        let kde = kde.kernel(Kernel::Epanechnikov);
+        assert_eq!(kde.support(), -1.0..3.0);
+        assert_eq!(kde.density(3.5), 0.0);
+        assert_eq!(kde.density(0.0), 0.25);
+        let total: f64 = kde.sample(-1.0..3.0, 401).iter().map(|p| p.1 * 0.01).sum();
+        assert!((total - 1.0).abs() < 1e-3);
+
+        let empty = KernelDensity::new(Vec::<f64>::new());
+        assert_eq!(empty.density(0.0), 0.0);
+        assert_eq!(empty.support(), 0.0..0.0);
+    }
+}
@@ -4,10 +4,16 @@ Such as, down-sampling, etc.
 */
 
 mod data_range;
-pub use data_range::fitting_range;
+pub use data_range::{fitting_range, fitting_range_with_margin};
+
+mod kde;
+pub use kde::{Kernel, KernelDensity};
 
 mod quartiles;
 pub use quartiles::Quartiles;
 
+mod regression;
+pub use regression::RegressionFit;
+
 /// Handles the printing of floating-point numbers.
 pub mod float;
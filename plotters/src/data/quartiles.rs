@@ -62,6 +62,37 @@ impl Quartiles {
         }
     }
 
+    /// Create a new quartiles struct of which the fences are the ends of the Tukey whiskers, i.e.
+    /// the most extreme values within `factor` times the interquartile range beyond the quartiles.
+    /// The values beyond the fences are the outliers.
+    ///
+    /// - `s`: The array of the original values
+    /// - `factor`: The factor of the interquartile range, which is 1.5 by the convention
+    /// - **returns** The newly created quartiles
+    ///
+    /// ```rust
+    /// use plotters::prelude::*;
+    ///
+    /// let quartiles = Quartiles::with_tukey_fences(&[7, 15, 36, 39, 40, 41, 100], 1.5);
+    /// assert_eq!(quartiles.values(), [7.0, 25.5, 39.0, 40.5, 41.0]);
+    /// ```
+    pub fn with_tukey_fences<T: Into<f64> + Copy + PartialOrd>(s: &[T], factor: f64) -> Self {
+        let mut quartiles = Self::new(s);
+        let iqr = quartiles.upper - quartiles.lower;
+        let (low, high) = (
+            quartiles.lower - factor * iqr,
+            quartiles.upper + factor * iqr,
+        );
+        let within = s
+            .iter()
+            .map(|v| (*v).into())
+            .filter(|v| low <= *v && *v <= high);
+
+        quartiles.lower_fence = within.clone().fold(quartiles.lower, f64::min);
+        quartiles.upper_fence = within.fold(quartiles.upper, f64::max);
+        quartiles
+    }
+
     /// Get the quartiles values.
     ///
     /// - **returns** The array [lower fence, lower quartile, median, upper quartile, upper fence]
@@ -124,4 +155,21 @@ mod test {
             [0.0, 15.0, 20.0, 25.0, 40.0]
         );
     }
+
+    #[test]
+    fn test_tukey_fences() {
+        let values = [1.0, 2.0, 3.0, 4.0, 5.0, 20.0, -20.0];
+        assert_eq!(
+            Quartiles::with_tukey_fences(&values, 1.5).values(),
+            [1.0, 1.5, 3.0, 4.5, 5.0]
+        );
+        assert_eq!(
+            Quartiles::with_tukey_fences(&values, 10.0).values(),
+            [-20.0, 1.5, 3.0, 4.5, 20.0]
+        );
+        assert_eq!(
+            Quartiles::with_tukey_fences(&[10, 20], 0.0).values(),
+            [12.5, 12.5, 15.0, 17.5, 17.5]
+        );
+    }
 }
@@ -0,0 +1,310 @@
+/// The least-squares polynomial fit of the points
+///
+/// The fit of the degree `d` minimizes the squared residuals of `y = c0 + c1 x + ... + cd x^d`,
+/// and it's also the source of the confidence intervals of the mean response at any `x`.
+///
+/// ```rust
+/// use plotters::prelude::*;
+///
+/// let fit = RegressionFit::new(&[(0.0, 1.0), (1.0, 3.0), (2.0, 5.0)], 1).unwrap();
+/// assert!((fit.coefficients()[1] - 2.0).abs() < 1e-9);
+/// assert!((fit.predict(3.0) - 7.0).abs() < 1e-9);
+/// assert!((fit.r_squared() - 1.0).abs() < 1e-9);
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct RegressionFit {
+    coefficients: Vec<f64>,
+    /// The coefficients of the polynomial of the centered and scaled X, `(x - center) / scale`,
+    /// which keeps the normal matrix well conditioned
+    scaled: Vec<f64>,
+    center: f64,
+    scale: f64,
+    r_squared: f64,
+    /// The inverse of the normal matrix, i.e. the unscaled covariance of the coefficients
+    covariance: Vec<Vec<f64>>,
+    residual_variance: f64,
+    dof: usize,
+}
+
+impl RegressionFit {
+    /// Fit a polynomial to the points
+    ///
+    /// - `points`: The points, the points with a non-finite coordinate are ignored
+    /// - `degree`: The degree of the polynomial, which is 1 for the linear regression
+    /// - **returns** The fit, or `None` if there are not more distinct X values than the degree
+    pub fn new(points: &[(f64, f64)], degree: usize) -> Option<Self> {
+        let points: Vec<_> = points
+            .iter()
+            .filter(|(x, y)| x.is_finite() && y.is_finite())
+            .collect();
+        let terms = degree + 1;
+        if points.len() < terms {
+            return None;
+        }
+        let center = points.iter().map(|(x, _)| x).sum::<f64>() / points.len() as f64;
+        let scale = points
+            .iter()
+            .fold(0.0f64, |max, (x, _)| max.max((x - center).abs()));
+        if scale == 0.0 {
+            return None;
+        }
+
+        let mut normal = vec![vec![0.0; terms]; terms];
+        let mut moments = vec![0.0; terms];
+        for (x, y) in points.iter() {
+            let powers = powers((x - center) / scale, terms);
+            for i in 0..terms {
+                moments[i] += powers[i] * y;
+                for j in 0..terms {
+                    normal[i][j] += powers[i] * powers[j];
+                }
+            }
+        }
+        let covariance = invert(normal)?;
+        let scaled: Vec<f64> = covariance
+            .iter()
+            .map(|row| row.iter().zip(&moments).map(|(a, b)| a * b).sum())
+            .collect();
+        // Expand the polynomial of `x / scale - center / scale` with the Horner's method
+        let coefficients = scaled.iter().rev().fold(vec![], |acc: Vec<f64>, c| {
+            let mut next = vec![0.0; acc.len() + 1];
+            for (k, a) in acc.iter().enumerate() {
+                next[k + 1] += a / scale;
+                next[k] -= a * center / scale;
+            }
+            next[0] += c;
+            next
+        });
+
+        let mut fit = Self {
+            coefficients,
+            scaled,
+            center,
+            scale,
+            r_squared: 1.0,
+            covariance,
+            residual_variance: 0.0,
+            dof: points.len() - terms,
+        };
+        let mean = points.iter().map(|(_, y)| y).sum::<f64>() / points.len() as f64;
+        let total: f64 = points.iter().map(|(_, y)| (y - mean).powi(2)).sum();
+        let residual: f64 = points
+            .iter()
+            .map(|(x, y)| (y - fit.predict(*x)).powi(2))
+            .sum();
+        if total > 0.0 {
+            fit.r_squared = 1.0 - residual / total;
+        }
+        if fit.dof > 0 {
+            fit.residual_variance = residual / fit.dof as f64;
+        }
+        Some(fit)
+    }
+
+    /// Get the coefficients of the polynomial, from the constant term to the highest power of X
+    pub fn coefficients(&self) -> &[f64] {
+        &self.coefficients
+    }
+
+    /// Get the coefficient of determination of the fit
+    pub fn r_squared(&self) -> f64 {
+        self.r_squared
+    }
+
+    /// Get the fitted value at an X value
+    pub fn predict(&self, x: f64) -> f64 {
+        let u = (x - self.center) / self.scale;
+        self.scaled.iter().rev().fold(0.0, |acc, c| acc * u + c)
+    }
+
+    /// Get the half width of the confidence interval of the mean response at an X value
+    ///
+    /// - `x`: The X value
+    /// - `confidence`: The confidence level, i.e. 0.95
+    /// - **returns** The half width, or `None` if the fit has no residual degree of freedom
+    pub fn confidence_interval(&self, x: f64, confidence: f64) -> Option<f64> {
+        if self.dof == 0 || !(confidence > 0.0 && confidence < 1.0) {
+            return None;
+        }
+        let powers = powers((x - self.center) / self.scale, self.scaled.len());
+        let leverage: f64 = self
+            .covariance
+            .iter()
+            .zip(&powers)
+            .map(|(row, p)| p * row.iter().zip(&powers).map(|(a, b)| a * b).sum::<f64>())
+            .sum();
+        let t = student_t_quantile(0.5 + confidence / 2.0, self.dof);
+        Some(t * (self.residual_variance * leverage.max(0.0)).sqrt())
+    }
+}
+
+/// Get `1, x, x^2, ...` up to `count` terms
+fn powers(x: f64, count: usize) -> Vec<f64> {
+    std::iter::successors(Some(1.0), |p| Some(p * x))
+        .take(count)
+        .collect()
+}
+
+/// Invert the matrix with the Gauss-Jordan elimination, `None` if it's singular
+fn invert(mut matrix: Vec<Vec<f64>>) -> Option<Vec<Vec<f64>>> {
+    let size = matrix.len();
+    let scale = matrix
+        .iter()
+        .flatten()
+        .fold(0.0f64, |max, v| max.max(v.abs()));
+    let mut inverse: Vec<Vec<f64>> = (0..size)
+        .map(|i| (0..size).map(|j| if i == j { 1.0 } else { 0.0 }).collect())
+        .collect();
+    for col in 0..size {
+        let pivot = (col..size).max_by(|a, b| {
+            matrix[*a][col]
+                .abs()
+                .partial_cmp(&matrix[*b][col].abs())
+                .unwrap_or(std::cmp::Ordering::Equal)
+        })?;
+        if matrix[pivot][col].abs() <= scale * 1e-12 {
+            return None;
+        }
+        matrix.swap(col, pivot);
+        inverse.swap(col, pivot);
+        let factor = matrix[col][col];
+        for j in 0..size {
+            matrix[col][j] /= factor;
+            inverse[col][j] /= factor;
+        }
+        for row in (0..size).filter(|row| *row != col) {
+            let factor = matrix[row][col];
+            for j in 0..size {
+                matrix[row][j] -= factor * matrix[col][j];
+                inverse[row][j] -= factor * inverse[col][j];
+            }
+        }
+    }
+    Some(inverse)
+}
+
+/// The quantile of the standard normal distribution, with Acklam's rational approximation
+fn normal_quantile(p: f64) -> f64 {
+    const A: [f64; 6] = [
+        -3.969_683_028_665_376e1,
+        2.209_460_984_245_205e2,
+        -2.759_285_104_469_687e2,
+        1.383_577_518_672_69e2,
+        -3.066_479_806_614_716e1,
+        2.506_628_277_459_239,
+    ];
+    const B: [f64; 5] = [
+        -5.447_609_879_822_406e1,
+        1.615_858_368_580_409e2,
+        -1.556_989_798_598_866e2,
+        6.680_131_188_771_972e1,
+        -1.328_068_155_288_572e1,
+    ];
+    const C: [f64; 6] = [
+        -7.784_894_002_430_293e-3,
+        -3.223_964_580_411_365e-1,
+        -2.400_758_277_161_838,
+        -2.549_732_539_343_734,
+        4.374_664_141_464_968,
+        2.938_163_982_698_783,
+    ];
+    const D: [f64; 4] = [
+        7.784_695_709_041_462e-3,
+        3.224_671_290_700_398e-1,
+        2.445_134_137_142_996,
+        3.754_408_661_907_416,
+    ];
+    let horner = |coefficients: &[f64], x: f64| coefficients.iter().fold(0.0, |acc, c| acc * x + c);
+    let tail = |q: f64| {
+        let q = (-2.0 * q.ln()).sqrt();
+        horner(&C, q) / (horner(&D, q) * q + 1.0)
+    };
+    match p {
+        p if p < 0.02425 => tail(p),
+        p if p > 1.0 - 0.02425 => -tail(1.0 - p),
+        p => {
+            let q = p - 0.5;
+            let r = q * q;
+            horner(&A, r) * q / (horner(&B, r) * r + 1.0)
+        }
+    }
+}
+
+/// The quantile of Student's t distribution, which is exact for one and two degrees of freedom
+/// and follows the Cornish-Fisher expansion otherwise
+fn student_t_quantile(p: f64, dof: usize) -> f64 {
+    match dof {
+        1 => (std::f64::consts::PI * (p - 0.5)).tan(),
+        2 => (2.0 * p - 1.0) / (2.0 * p * (1.0 - p)).sqrt(),
+        _ => {
+            let z = normal_quantile(p);
+            let v = dof as f64;
+            let z2 = z * z;
+            let g1 = (z2 + 1.0) * z / 4.0;
+            let g2 = ((5.0 * z2 + 16.0) * z2 + 3.0) * z / 96.0;
+            let g3 = (((3.0 * z2 + 19.0) * z2 + 17.0) * z2 - 15.0) * z / 384.0;
+            let g4 =
+                ((((79.0 * z2 + 776.0) * z2 + 1482.0) * z2 - 1920.0) * z2 - 945.0) * z / 92160.0;
+            z + g1 / v + g2 / (v * v) + g3 / (v * v * v) + g4 / (v * v * v * v)
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_quantiles() {
+        assert!((normal_quantile(0.975) - 1.959_964).abs() < 1e-6);
+        assert!((normal_quantile(0.001) + 3.090_232).abs() < 1e-6);
+        assert!((student_t_quantile(0.975, 1) - 12.706_2).abs() < 1e-3);
+        assert!((student_t_quantile(0.975, 2) - 4.302_7).abs() < 1e-3);
+        assert!((student_t_quantile(0.975, 10) - 2.228_1).abs() < 1e-3);
+        assert!((student_t_quantile(0.95, 30) - 1.697_3).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_regression_fit() {
+        assert_eq!(RegressionFit::new(&[(1.0, 1.0)], 1), None);
+        assert_eq!(RegressionFit::new(&[(1.0, 1.0), (1.0, 2.0)], 1), None);
+
+        let points: Vec<_> = (0..5)
+            .map(|x| (f64::from(x), f64::from(x * x) - 1.0))
+            .collect();
+        let fit = RegressionFit::new(&points, 2).unwrap();
+        for (c, expected) in fit.coefficients().iter().zip(&[-1.0, 0.0, 1.0]) {
+            assert!((c - expected).abs() < 1e-9);
+        }
+        assert!(fit.confidence_interval(2.0, 0.95).unwrap() < 1e-6);
+
+        let points = [
+            (0.0, 0.0),
+            (1.0, 2.0),
+            (2.0, 1.0),
+            (3.0, 3.0),
+            (f64::NAN, 0.0),
+        ];
+        let fit = RegressionFit::new(&points, 1).unwrap();
+        assert!((fit.coefficients()[0] - 0.3).abs() < 1e-9);
+        assert!((fit.coefficients()[1] - 0.8).abs() < 1e-9);
+        assert!((fit.r_squared() - 0.64).abs() < 1e-9);
+        let center = fit.confidence_interval(1.5, 0.95).unwrap();
+        let edge = fit.confidence_interval(3.0, 0.95).unwrap();
+        assert!(center > 0.0 && edge > center);
+        // s^2 = 1.8 / 2, t = 4.3027, and the leverage at the mean of X is 1 / n
+        assert!((center - 4.3027 * (0.9f64 / 4.0).sqrt()).abs() < 1e-3);
+        assert_eq!(fit.confidence_interval(1.5, 1.0), None);
+
+        // The raw powers of the large X values would make the normal matrix singular
+        let points: Vec<_> = (0..6)
+            .map(|x| {
+                let x = 1e6 + f64::from(x);
+                (x, (x - 1e6).powi(3))
+            })
+            .collect();
+        let fit = RegressionFit::new(&points, 3).unwrap();
+        assert!((fit.predict(1e6 + 2.5) - 15.625).abs() < 1e-6);
+        assert!((fit.r_squared() - 1.0).abs() < 1e-9);
+    }
+}
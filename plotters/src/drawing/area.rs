@@ -203,6 +203,22 @@ impl<DB: DrawingBackend, X: Ranged, Y: Ranged> DrawingArea<DB, Cartesian2d<X, Y>
         })
     }
 
+    /// Draw the mesh on the given key points
+    pub(crate) fn draw_mesh_with_key_points<DrawFunc>(
+        &self,
+        mut draw_func: DrawFunc,
+        x_key_points: &[X::ValueType],
+        y_key_points: &[Y::ValueType],
+    ) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>>
+    where
+        DrawFunc: FnMut(&mut DB, MeshLine<X, Y>) -> Result<(), DrawingErrorKind<DB::ErrorType>>,
+    {
+        self.backend_ops(move |b| {
+            self.coord
+                .draw_mesh_with_key_points(x_key_points, y_key_points, |line| draw_func(b, line))
+        })
+    }
+
     /// Get the range of X of the guest coordinate for current drawing area
     pub fn get_x_range(&self) -> Range<X::ValueType> {
         self.coord.get_x_range()
@@ -222,6 +238,26 @@ impl<DB: DrawingBackend, X: Ranged, Y: Ranged> DrawingArea<DB, Cartesian2d<X, Y>
     pub fn get_y_axis_pixel_range(&self) -> Range<i32> {
         self.coord.get_y_axis_pixel_range()
     }
+
+    /// Get the ranges of X of the backend coordinate where the X axis is broken
+    pub fn get_x_axis_breaks(&self) -> Vec<Range<i32>> {
+        self.coord.get_x_axis_breaks()
+    }
+
+    /// Get the ranges of Y of the backend coordinate where the Y axis is broken
+    pub fn get_y_axis_breaks(&self) -> Vec<Range<i32>> {
+        self.coord.get_y_axis_breaks()
+    }
+
+    /// Get the X of the backend coordinate where the X axis changes its scale
+    pub fn get_x_axis_markers(&self) -> Vec<i32> {
+        self.coord.get_x_axis_markers()
+    }
+
+    /// Get the Y of the backend coordinate where the Y axis changes its scale
+    pub fn get_y_axis_markers(&self) -> Vec<i32> {
+        self.coord.get_y_axis_markers()
+    }
 }
 
 impl<DB: DrawingBackend, CT: CoordTranslate> DrawingArea<DB, CT> {
@@ -271,6 +307,16 @@ impl<DB: DrawingBackend, CT: CoordTranslate> DrawingArea<DB, CT> {
         (self.rect.x0..self.rect.x1, self.rect.y0..self.rect.y1)
     }
 
+    /// Move the edges of this area inward by the given numbers of pixels, or outward for the
+    /// negative numbers. The coordinate specification is kept, so it's up to the caller to
+    /// update it for the new area.
+    pub(crate) fn move_edges(&mut self, (top, bottom, left, right): (i32, i32, i32, i32)) {
+        self.rect.x0 += left;
+        self.rect.y0 += top;
+        self.rect.x1 -= right;
+        self.rect.y1 -= bottom;
+    }
+
     /// Perform operation on the drawing backend
     fn backend_ops<R, O: FnOnce(&mut DB) -> Result<R, DrawingErrorKind<DB::ErrorType>>>(
         &self,
@@ -487,8 +533,8 @@ impl<DB: DrawingBackend> DrawingArea<DB, Shift> {
 
         let x_padding = (self.rect.x1 - self.rect.x0) / 2;
 
-        let (_, text_h) = self.estimate_text_size(text, &style)?;
-        let y_padding = (text_h / 2).min(5) as i32;
+        let title_h = self.title_height(text, &style)?;
+        let y_padding = (title_h - self.estimate_text_size(text, &style)?.1) as i32 / 2;
 
         let style = &style.pos(Pos::new(HPos::Center, VPos::Top));
 
@@ -503,15 +549,25 @@ impl<DB: DrawingBackend> DrawingArea<DB, Shift> {
         Ok(Self {
             rect: Rect {
                 x0: self.rect.x0,
-                y0: self.rect.y0 + y_padding * 2 + text_h as i32,
+                y0: self.rect.y0 + title_h as i32,
                 x1: self.rect.x1,
                 y1: self.rect.y1,
             },
             backend: self.backend.clone(),
-            coord: Shift((self.rect.x0, self.rect.y0 + y_padding * 2 + text_h as i32)),
+            coord: Shift((self.rect.x0, self.rect.y0 + title_h as i32)),
         })
     }
 
+    /// Get the height taken by a title drawn by [`DrawingArea::titled()`], including its padding
+    pub(crate) fn title_height(
+        &self,
+        text: &str,
+        style: &TextStyle,
+    ) -> Result<u32, DrawingAreaError<DB>> {
+        let (_, text_h) = self.estimate_text_size(text, style)?;
+        Ok(text_h + 2 * (text_h / 2).min(5))
+    }
+
     /// Draw text on the drawing area
     pub fn draw_text(
         &self,
@@ -4,9 +4,12 @@ use crate::drawing::DrawingArea;
 use crate::style::RGBAColor;
 use plotters_backend::{
     BackendColor, BackendCoord, BackendStyle, BackendTextStyle, DrawingBackend, DrawingErrorKind,
+    FontTransform,
 };
 
+use std::cell::{Cell, RefCell};
 use std::collections::VecDeque;
+use std::rc::Rc;
 
 pub fn check_color(left: BackendColor, right: RGBAColor) {
     assert_eq!(
@@ -33,6 +36,7 @@ pub struct MockedBackend {
     check_draw_path: VecDeque<Box<dyn FnMut(RGBAColor, u32, Vec<BackendCoord>)>>,
     check_draw_circle: VecDeque<Box<dyn FnMut(RGBAColor, u32, bool, BackendCoord, u32)>>,
     check_draw_text: VecDeque<Box<dyn FnMut(RGBAColor, &str, f64, BackendCoord, &str)>>,
+    check_text_transform: VecDeque<Box<dyn FnMut(FontTransform, &str)>>,
     check_fill_polygon: VecDeque<Box<dyn FnMut(RGBAColor, Vec<BackendCoord>)>>,
     drop_check: Option<Box<dyn FnMut(&Self)>>,
 }
@@ -72,6 +76,7 @@ impl MockedBackend {
             check_draw_path: vec![].into(),
             check_draw_circle: vec![].into(),
             check_draw_text: vec![].into(),
+            check_text_transform: vec![].into(),
             check_fill_polygon: vec![].into(),
             drop_check: None,
         }
@@ -90,6 +95,7 @@ impl MockedBackend {
     def_set_checker_func!(check_draw_path, RGBAColor, u32, Vec<BackendCoord>);
     def_set_checker_func!(check_draw_circle, RGBAColor, u32, bool, BackendCoord, u32);
     def_set_checker_func!(check_draw_text, RGBAColor, &str, f64, BackendCoord, &str);
+    def_set_checker_func!(check_text_transform, FontTransform, &str);
     def_set_checker_func!(drop_check, &Self);
     def_set_checker_func!(check_fill_polygon, RGBAColor, Vec<BackendCoord>);
 
@@ -263,6 +269,13 @@ impl DrawingBackend for MockedBackend {
                 self.check_draw_text.push_back(checker);
             }
         }
+        if let Some(mut checker) = self.check_text_transform.pop_front() {
+            checker(style.transform(), text);
+
+            if self.check_text_transform.is_empty() {
+                self.check_text_transform.push_back(checker);
+            }
+        }
         Ok(())
     }
 }
@@ -294,3 +307,56 @@ pub fn create_mocked_drawing_area<F: FnOnce(&mut MockedBackend)>(
     setup(&mut backend);
     backend.into_drawing_area()
 }
+
+/// The texts drawn on a mocked drawing area, in the order they are drawn
+#[derive(Clone, Default)]
+pub struct DrawnTexts(Rc<RefCell<Vec<(String, BackendCoord)>>>);
+
+impl DrawnTexts {
+    /// Get the texts drawn so far along with their positions
+    pub fn all(&self) -> Vec<(String, BackendCoord)> {
+        self.0.borrow().clone()
+    }
+
+    /// Get the texts drawn so far
+    pub fn texts(&self) -> Vec<String> {
+        self.0
+            .borrow()
+            .iter()
+            .map(|(text, _)| text.clone())
+            .collect()
+    }
+}
+
+/// Create a mocked drawing area which records all the texts drawn on it
+pub fn create_text_recording_area(
+    width: u32,
+    height: u32,
+) -> (DrawingArea<MockedBackend, Shift>, DrawnTexts) {
+    let texts = DrawnTexts::default();
+    let texts_ref = texts.clone();
+    let drawing_area = create_mocked_drawing_area(width, height, move |m| {
+        m.check_draw_text(move |_, _, _, pos, text| {
+            texts_ref.0.borrow_mut().push((text.to_string(), pos))
+        });
+    });
+    (drawing_area, texts)
+}
+
+/// Draw on a mocked drawing area, and read the call counters of the backend once the drawing
+/// area and everything drawn on it are dropped
+pub fn count_mocked_calls<T, R, D>(width: u32, height: u32, read: R, draw: D) -> T
+where
+    T: Copy + Default + 'static,
+    R: Fn(&MockedBackend) -> T + 'static,
+    D: FnOnce(&DrawingArea<MockedBackend, Shift>),
+{
+    let counts = Rc::new(Cell::new(T::default()));
+    let counts_ref = counts.clone();
+    let drawing_area = create_mocked_drawing_area(width, height, move |m| {
+        m.drop_check(move |b| counts_ref.set(read(b)));
+    });
+    draw(&drawing_area);
+    drop(drawing_area);
+    counts.get()
+}
@@ -1,7 +1,10 @@
 #[cfg(test)]
 mod mocked;
 #[cfg(test)]
-pub use mocked::{check_color, create_mocked_drawing_area, MockedBackend};
+pub use mocked::{
+    check_color, count_mocked_calls, create_mocked_drawing_area, create_text_recording_area,
+    DrawnTexts, MockedBackend,
+};
 
 /// This is the dummy backend placeholder for the backend that never fails
 #[derive(Debug)]
@@ -504,6 +504,184 @@ fn test_rect_element() {
     }
 }
 
+/**
+A rectangle element with the rounded corners, i.e. the legend boxes, the callouts and the cards.
+
+Each corner has its own radius, which is shrunk with the others of the same rectangle when the
+radii of two adjacent corners don't fit the side between them. The rectangle is filled with a
+filled style and stroked otherwise, and it's optionally outlined with the border style on top.
+
+```
+use plotters::prelude::*;
+let drawing_area = SVGBackend::new("rounded_rect.svg", (300, 200)).into_drawing_area();
+drawing_area.fill(&WHITE).unwrap();
+let card = RoundedRect::new([(20, 20), (280, 180)], 16, WHITE.mix(0.9).filled())
+    .corner_radii([16, 16, 0, 0])
+    .border_style(Some(BLUE.stroke_width(2)));
+drawing_area.draw(&card).unwrap();
+```
+*/
+pub struct RoundedRect<Coord, Size: SizeDesc> {
+    points: [Coord; 2],
+    /// The radii of the upper left, the upper right, the lower right and the lower left corners
+    radii: [Size; 4],
+    style: ShapeStyle,
+    border_style: Option<ShapeStyle>,
+}
+
+impl<Coord, Size: SizeDesc + Clone> RoundedRect<Coord, Size> {
+    /// Create a new rounded rectangle
+    /// - `points`: The left upper and right lower corner of the rectangle
+    /// - `radius`: The radius of all the corners
+    /// - `style`: The shape style
+    /// - returns the created element
+    pub fn new<S: Into<ShapeStyle>>(points: [Coord; 2], radius: Size, style: S) -> Self {
+        Self {
+            points,
+            radii: [radius.clone(), radius.clone(), radius.clone(), radius],
+            style: style.into(),
+            border_style: None,
+        }
+    }
+}
+
+impl<Coord, Size: SizeDesc> RoundedRect<Coord, Size> {
+    /// Set the radii of the upper left, the upper right, the lower right and the lower left
+    /// corners
+    pub fn corner_radii(mut self, radii: [Size; 4]) -> Self {
+        self.radii = radii;
+        self
+    }
+
+    /// Set the style of the outline drawn over the rectangle, `None` hides it, which is the default
+    pub fn border_style<S: Into<ShapeStyle>>(mut self, style: Option<S>) -> Self {
+        self.border_style = style.map(Into::into);
+        self
+    }
+
+    /// Get the outline of the rectangle in pixels, from the top of the upper left corner
+    fn outline(&self, a: BackendCoord, b: BackendCoord, ps: (u32, u32)) -> Vec<BackendCoord> {
+        let (a, b) = ((a.0.min(b.0), a.1.min(b.1)), (a.0.max(b.0), a.1.max(b.1)));
+        let (width, height) = (f64::from(b.0 - a.0), f64::from(b.1 - a.1));
+        let radii: Vec<_> = self
+            .radii
+            .iter()
+            .map(|r| f64::from(r.in_pixels(&ps).max(0)))
+            .collect();
+        let scale = [
+            width / (radii[0] + radii[1]),
+            height / (radii[1] + radii[2]),
+            width / (radii[2] + radii[3]),
+            height / (radii[3] + radii[0]),
+        ]
+        .iter()
+        .fold(
+            1.0f64,
+            |scale, s| if s.is_finite() { scale.min(*s) } else { scale },
+        );
+
+        let quarter = std::f64::consts::FRAC_PI_2;
+        let corners = [
+            (a.0, a.1, 1, 1),
+            (b.0, a.1, -1, 1),
+            (b.0, b.1, -1, -1),
+            (a.0, b.1, 1, -1),
+        ];
+        let mut points = vec![];
+        for (idx, ((x, y, sx, sy), radius)) in corners.iter().zip(&radii).enumerate() {
+            let radius = radius * scale;
+            let offset = radius.round() as i32;
+            let center = (x + sx * offset, y + sy * offset);
+            let start = quarter * (idx + 2) as f64;
+            points.extend(arc_points(
+                center,
+                (radius, radius),
+                (start, start + quarter),
+            ));
+        }
+        points.dedup();
+        points
+    }
+}
+
+impl<'a, Coord, Size: SizeDesc> PointCollection<'a, Coord> for &'a RoundedRect<Coord, Size> {
+    type Point = &'a Coord;
+    type IntoIter = &'a [Coord];
+    fn point_iter(self) -> &'a [Coord] {
+        &self.points
+    }
+}
+
+impl<Coord, DB: DrawingBackend, Size: SizeDesc> Drawable<DB> for RoundedRect<Coord, Size> {
+    fn draw<I: Iterator<Item = BackendCoord>>(
+        &self,
+        mut points: I,
+        backend: &mut DB,
+        ps: (u32, u32),
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        if let (Some(a), Some(b)) = (points.next(), points.next()) {
+            let mut outline = self.outline(a, b, ps);
+            outline.push(outline[0]);
+            draw_outline(outline.clone(), &self.style, backend)?;
+            if let Some(style) = &self.border_style {
+                backend.draw_path(outline, style)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_rounded_rect_element() {
+    use crate::prelude::*;
+    let rect = RoundedRect::new([(100, 50), (0, 0)], 0, BLUE);
+    assert_eq!(
+        rect.outline((100, 50), (0, 0), (200, 200)),
+        [(0, 0), (100, 0), (100, 50), (0, 50)]
+    );
+    let outline = rect
+        .corner_radii([10, 0, 20, 0])
+        .outline((0, 0), (100, 50), (200, 200));
+    assert_eq!(outline[0], (0, 10));
+    assert!(outline.contains(&(10, 0)));
+    assert!(outline.contains(&(100, 0)));
+    assert!(outline.contains(&(100, 30)));
+    assert!(outline.contains(&(80, 50)));
+    assert_eq!(outline[outline.len() - 1], (0, 50));
+    // The radii of 40 and 20 on the height of 30 are shrunk to 20 and 10
+    let outline = RoundedRect::new([(0, 0), (100, 30)], 0, BLUE)
+        .corner_radii([40, 0, 0, 20])
+        .outline((0, 0), (100, 30), (200, 200));
+    assert_eq!(outline[0], (0, 20));
+    assert!(outline.contains(&(20, 0)));
+    assert!(outline.contains(&(10, 30)));
+
+    let da = crate::create_mocked_drawing_area(200, 200, |m| {
+        m.check_fill_polygon(|c, p| {
+            assert_eq!(c, BLUE.to_rgba());
+            assert_eq!(p[0], (0, 20));
+        });
+        m.check_draw_path(|c, _, p| {
+            assert_eq!(c, RED.to_rgba());
+            assert_eq!(p.first(), p.last());
+        });
+        m.drop_check(|b| {
+            assert_eq!(b.num_fill_polygon_call, 1);
+            assert_eq!(b.num_draw_path_call, 1);
+        });
+    });
+    da.draw(
+        &RoundedRect::new(
+            [(0, 0), (100, 100)],
+            crate::style::RelativeSize::Width(0.1),
+            BLUE.filled(),
+        )
+        .border_style(Some(RED)),
+    )
+    .expect("Drawing Failure");
+}
+
 /// A circle element
 pub struct Circle<Coord, Size: SizeDesc> {
     center: Coord,
@@ -569,9 +747,361 @@ fn test_circle_element() {
         .expect("Drawing Failure");
 }
 
-/// An element of a filled polygon
+/// Approximate the elliptical arc with the lines which are within [`CURVE_TOLERANCE`] of it
+/// - `center`: The center of the ellipse
+/// - `radii`: The horizontal and the vertical radii in pixels
+/// - `angles`: The start and the end angles in radians, clockwise from the right, of which the
+///   sweep is at most a full turn
+fn arc_points(center: BackendCoord, radii: (f64, f64), angles: (f64, f64)) -> Vec<BackendCoord> {
+    let full = 2.0 * std::f64::consts::PI;
+    let sweep = (angles.1 - angles.0).clamp(-full, full);
+    let radius = radii.0.max(radii.1);
+    let step = if radius > CURVE_TOLERANCE {
+        2.0 * (1.0 - CURVE_TOLERANCE / radius).acos()
+    } else {
+        full / 4.0
+    };
+    let count = (sweep.abs() / step).ceil().max(1.0) as usize;
+    let mut points: Vec<_> = (0..=count)
+        .map(|idx| {
+            let (sin, cos) = (angles.0 + sweep * idx as f64 / count as f64).sin_cos();
+            (
+                (f64::from(center.0) + radii.0 * cos).round() as i32,
+                (f64::from(center.1) + radii.1 * sin).round() as i32,
+            )
+        })
+        .collect();
+    points.dedup();
+    points
+}
+
+/// Draw the outline of a curved shape, which is filled as a polygon with a filled style
+fn draw_outline<DB: DrawingBackend>(
+    points: Vec<BackendCoord>,
+    style: &ShapeStyle,
+    backend: &mut DB,
+) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+    if style.filled {
+        backend.fill_polygon(points, &style.color.to_backend_color())
+    } else {
+        backend.draw_path(points, style)
+    }
+}
+
+/**
+An axis-aligned ellipse element, which is filled with a filled style and stroked otherwise.
+
+```
+use plotters::prelude::*;
+let drawing_area = SVGBackend::new("ellipse.svg", (300, 200)).into_drawing_area();
+drawing_area.fill(&WHITE).unwrap();
+drawing_area
+    .draw(&Ellipse::new((150, 100), (120, 60), BLUE.stroke_width(2)))
+    .unwrap();
+```
+*/
+pub struct Ellipse<Coord, Size: SizeDesc> {
+    center: Coord,
+    radii: (Size, Size),
+    style: ShapeStyle,
+}
+
+impl<Coord, Size: SizeDesc> Ellipse<Coord, Size> {
+    /// Create a new ellipse element
+    /// - `center` The center of the ellipse
+    /// - `radii` The horizontal and the vertical radii of the ellipse
+    /// - `style` The style of the ellipse
+    /// - Return: The newly created ellipse element
+    pub fn new<S: Into<ShapeStyle>>(center: Coord, radii: (Size, Size), style: S) -> Self {
+        Self {
+            center,
+            radii,
+            style: style.into(),
+        }
+    }
+}
+
+impl<'a, Coord, Size: SizeDesc> PointCollection<'a, Coord> for &'a Ellipse<Coord, Size> {
+    type Point = &'a Coord;
+    type IntoIter = std::iter::Once<&'a Coord>;
+    fn point_iter(self) -> std::iter::Once<&'a Coord> {
+        std::iter::once(&self.center)
+    }
+}
+
+impl<Coord, DB: DrawingBackend, Size: SizeDesc> Drawable<DB> for Ellipse<Coord, Size> {
+    fn draw<I: Iterator<Item = BackendCoord>>(
+        &self,
+        mut points: I,
+        backend: &mut DB,
+        ps: (u32, u32),
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        if let Some(center) = points.next() {
+            let rx = self.radii.0.in_pixels(&ps).max(0);
+            let ry = self.radii.1.in_pixels(&ps).max(0);
+            let angles = (0.0, 2.0 * std::f64::consts::PI);
+            let points = arc_points(center, (f64::from(rx), f64::from(ry)), angles);
+            return draw_outline(points, &self.style, backend);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_ellipse_element() {
+    use crate::prelude::*;
+    let da = crate::create_mocked_drawing_area(300, 300, |m| {
+        m.check_draw_path(|c, _, path| {
+            assert_eq!(c, BLUE.to_rgba());
+            assert_eq!(path[0], (250, 150));
+            assert_eq!(path[path.len() - 1], (250, 150));
+            assert!(path.iter().any(|p| p.1 == 200));
+            assert!(path.iter().any(|p| p.0 == 50));
+            for (x, y) in path {
+                let (dx, dy) = (f64::from(x - 150) / 100.0, f64::from(y - 150) / 50.0);
+                assert!((dx.hypot(dy) - 1.0).abs() < 0.02);
+            }
+        });
+        m.check_fill_polygon(|c, path| {
+            assert_eq!(c, RED.to_rgba());
+            assert!(path.iter().any(|p| p.1 == 130));
+        });
+        m.drop_check(|b| {
+            assert_eq!(b.num_draw_path_call, 1);
+            assert_eq!(b.num_fill_polygon_call, 1);
+        });
+    });
+    da.draw(&Ellipse::new((150, 150), (100, 50), BLUE))
+        .expect("Drawing Failure");
+    da.draw(&Ellipse::new((150, 150), (10, 20), RED.filled()))
+        .expect("Drawing Failure");
+}
+
+/**
+A circular arc element, which goes clockwise from the start angle to the end angle in degrees,
+where the angle 0 points to the right, i.e. the angle markers and the orbits. The arc is filled as
+the circular segment closed by its chord with a filled style, see [`CircularSector`] for the
+slices.
+
+```
+use plotters::prelude::*;
+let drawing_area = SVGBackend::new("arc.svg", (300, 200)).into_drawing_area();
+drawing_area.fill(&WHITE).unwrap();
+drawing_area
+    .draw(&CircularArc::new((150, 100), 80, (180.0, 315.0), RED.stroke_width(2)))
+    .unwrap();
+```
+*/
+pub struct CircularArc<Coord, Size: SizeDesc> {
+    center: Coord,
+    radius: Size,
+    angles: (f64, f64),
+    style: ShapeStyle,
+}
+
+impl<Coord, Size: SizeDesc> CircularArc<Coord, Size> {
+    /// Create a new arc element
+    /// - `center` The center of the circle
+    /// - `radius` The radius of the circle
+    /// - `angles` The start and the end angles in degrees
+    /// - `style` The style of the arc
+    /// - Return: The newly created arc element
+    pub fn new<S: Into<ShapeStyle>>(
+        center: Coord,
+        radius: Size,
+        angles: (f64, f64),
+        style: S,
+    ) -> Self {
+        Self {
+            center,
+            radius,
+            angles,
+            style: style.into(),
+        }
+    }
+}
+
+impl<'a, Coord, Size: SizeDesc> PointCollection<'a, Coord> for &'a CircularArc<Coord, Size> {
+    type Point = &'a Coord;
+    type IntoIter = std::iter::Once<&'a Coord>;
+    fn point_iter(self) -> std::iter::Once<&'a Coord> {
+        std::iter::once(&self.center)
+    }
+}
+
+impl<Coord, DB: DrawingBackend, Size: SizeDesc> Drawable<DB> for CircularArc<Coord, Size> {
+    fn draw<I: Iterator<Item = BackendCoord>>(
+        &self,
+        mut points: I,
+        backend: &mut DB,
+        ps: (u32, u32),
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        if let Some(center) = points.next() {
+            let radius = f64::from(self.radius.in_pixels(&ps).max(0));
+            let angles = (self.angles.0.to_radians(), self.angles.1.to_radians());
+            let points = arc_points(center, (radius, radius), angles);
+            return draw_outline(points, &self.style, backend);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_arc_element() {
+    use crate::prelude::*;
+    let da = crate::create_mocked_drawing_area(300, 300, |m| {
+        m.check_draw_path(|c, _, path| {
+            assert_eq!(c, BLUE.to_rgba());
+            assert_eq!(path[0], (250, 150));
+            assert_eq!(path[path.len() - 1], (150, 250));
+            assert!(path.iter().all(|p| p.0 >= 150 && p.1 >= 150));
+        });
+        m.drop_check(|b| assert_eq!(b.num_draw_path_call, 1));
+    });
+    da.draw(&CircularArc::new((150, 150), 100, (0.0, 90.0), BLUE))
+        .expect("Drawing Failure");
+}
+
+/**
+A circular sector element, which is the slice of a circle between the start angle and the end
+angle in degrees, see [`CircularArc`] for the angles. It's filled with a filled style and its
+outline is stroked otherwise.
+
+```
+use plotters::prelude::*;
+let drawing_area = SVGBackend::new("circular_sector.svg", (300, 200)).into_drawing_area();
+drawing_area.fill(&WHITE).unwrap();
+drawing_area
+    .draw(&CircularSector::new((150, 100), 80, (-90.0, 30.0), GREEN.filled()))
+    .unwrap();
+```
+*/
+pub struct CircularSector<Coord, Size: SizeDesc> {
+    center: Coord,
+    radius: Size,
+    angles: (f64, f64),
+    style: ShapeStyle,
+}
+
+impl<Coord, Size: SizeDesc> CircularSector<Coord, Size> {
+    /// Create a new circular sector element
+    /// - `center` The center of the circle
+    /// - `radius` The radius of the circle
+    /// - `angles` The start and the end angles in degrees
+    /// - `style` The style of the sector
+    /// - Return: The newly created circular sector element
+    pub fn new<S: Into<ShapeStyle>>(
+        center: Coord,
+        radius: Size,
+        angles: (f64, f64),
+        style: S,
+    ) -> Self {
+        Self {
+            center,
+            radius,
+            angles,
+            style: style.into(),
+        }
+    }
+}
+
+impl<'a, Coord, Size: SizeDesc> PointCollection<'a, Coord> for &'a CircularSector<Coord, Size> {
+    type Point = &'a Coord;
+    type IntoIter = std::iter::Once<&'a Coord>;
+    fn point_iter(self) -> std::iter::Once<&'a Coord> {
+        std::iter::once(&self.center)
+    }
+}
+
+impl<Coord, DB: DrawingBackend, Size: SizeDesc> Drawable<DB> for CircularSector<Coord, Size> {
+    fn draw<I: Iterator<Item = BackendCoord>>(
+        &self,
+        mut points: I,
+        backend: &mut DB,
+        ps: (u32, u32),
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        if let Some(center) = points.next() {
+            let radius = f64::from(self.radius.in_pixels(&ps).max(0));
+            let angles = (self.angles.0.to_radians(), self.angles.1.to_radians());
+            let mut points = vec![center];
+            points.extend(arc_points(center, (radius, radius), angles));
+            points.push(center);
+            return draw_outline(points, &self.style, backend);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_circular_sector_element() {
+    use crate::prelude::*;
+    let da = crate::create_mocked_drawing_area(300, 300, |m| {
+        m.check_fill_polygon(|c, path| {
+            assert_eq!(c, BLUE.to_rgba());
+            assert_eq!(path[0], (150, 150));
+            assert_eq!(path[1], (150, 50));
+            assert_eq!(path[path.len() - 2], (50, 150));
+            assert_eq!(path[path.len() - 1], (150, 150));
+        });
+        m.check_draw_path(|_, _, path| {
+            assert_eq!(path.first(), path.last());
+        });
+        m.drop_check(|b| {
+            assert_eq!(b.num_fill_polygon_call, 1);
+            assert_eq!(b.num_draw_path_call, 1);
+        });
+    });
+    da.draw(&CircularSector::new(
+        (150, 150),
+        100,
+        (-90.0, -180.0),
+        BLUE.filled(),
+    ))
+    .expect("Drawing Failure");
+    da.draw(&CircularSector::new((150, 150), 100, (0.0, 45.0), BLUE))
+        .expect("Drawing Failure");
+}
+
+/// The rule deciding which points are inside a [`Polygon`] of multiple rings
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FillRule {
+    /// A point is inside if it's inside an odd number of the rings, so the rings inside another
+    /// ring are the holes regardless of their directions
+    EvenOdd,
+    /// A point is inside if the rings wind around it a nonzero number of times, so the holes are
+    /// the rings going in the opposite direction of the ring around them
+    NonZero,
+}
+
+/**
+An element of a filled polygon, which is either a single ring of points or multiple rings, i.e.
+the outer boundaries with the holes of the map shapes and the annular regions.
+
+The rings of a polygon are filled by the [`FillRule`] of the polygon on any backend. They are
+expected not to cross each other, but they may be nested to any depth.
+
+```
+use plotters::prelude::*;
+let drawing_area = SVGBackend::new("polygon_with_holes.svg", (200, 200)).into_drawing_area();
+drawing_area.fill(&WHITE).unwrap();
+let ring = |r: f64| -> Vec<(i32, i32)> {
+    (0..64)
+        .map(|i| (i as f64 * 360.0 / 64.0).to_radians())
+        .map(|a| ((100.0 + r * a.cos()) as i32, (100.0 + r * a.sin()) as i32))
+        .collect()
+};
+let annulus = Polygon::with_rings([ring(90.0), ring(50.0)], BLUE.mix(0.5));
+drawing_area.draw(&annulus).unwrap();
+```
+*/
 pub struct Polygon<Coord> {
     points: Vec<Coord>,
+    /// The number of the points of each ring
+    rings: Vec<usize>,
+    fill_rule: FillRule,
     style: ShapeStyle,
 }
 impl<Coord> Polygon<Coord> {
@@ -580,11 +1110,125 @@ impl<Coord> Polygon<Coord> {
     /// - `style`: The shape style
     /// - returns the created element
     pub fn new<P: Into<Vec<Coord>>, S: Into<ShapeStyle>>(points: P, style: S) -> Self {
+        let points = points.into();
         Self {
-            points: points.into(),
+            rings: vec![points.len()],
+            points,
+            fill_rule: FillRule::EvenOdd,
+            style: style.into(),
+        }
+    }
+
+    /// Create a new polygon of multiple rings
+    /// - `rings`: The iterator of the rings, each of which is the points of a closed boundary
+    /// - `style`: The shape style
+    /// - returns the created element
+    pub fn with_rings<R, P, S>(rings: R, style: S) -> Self
+    where
+        R: IntoIterator<Item = P>,
+        P: Into<Vec<Coord>>,
+        S: Into<ShapeStyle>,
+    {
+        let mut points = vec![];
+        let mut lengths = vec![];
+        for ring in rings {
+            let ring = ring.into();
+            lengths.push(ring.len());
+            points.extend(ring);
+        }
+        Self {
+            points,
+            rings: lengths,
+            fill_rule: FillRule::EvenOdd,
             style: style.into(),
         }
     }
+
+    /// Set the rule deciding which points are inside the rings, which is [`FillRule::EvenOdd`]
+    /// by default
+    pub fn fill_rule(mut self, rule: FillRule) -> Self {
+        self.fill_rule = rule;
+        self
+    }
+}
+
+/// Twice the signed area of a ring
+fn ring_area(ring: &[BackendCoord]) -> i64 {
+    ring.iter()
+        .zip(ring.iter().cycle().skip(1))
+        .map(|(a, b)| i64::from(a.0) * i64::from(b.1) - i64::from(b.0) * i64::from(a.1))
+        .sum()
+}
+
+/// Check if a point is inside a ring
+fn ring_contains(ring: &[BackendCoord], (x, y): BackendCoord) -> bool {
+    let (x, y) = (f64::from(x) + 0.5, f64::from(y) + 0.5);
+    let mut inside = false;
+    for (a, b) in ring.iter().zip(ring.iter().cycle().skip(1)) {
+        let (ax, ay, bx, by) = (a.0 as f64, a.1 as f64, b.0 as f64, b.1 as f64);
+        if (ay > y) != (by > y) && x < ax + (y - ay) * (bx - ax) / (by - ay) {
+            inside = !inside;
+        }
+    }
+    inside
+}
+
+/// Resolve the fill rule of the rings, which makes a single ring of which the winding number is 0
+/// outside and 1 inside, so it's filled in the same way on the backends filling by either rule.
+/// The rings which don't change the fill are dropped, the others are directed by the fill inside
+/// them, and they're joined to the first ring by the bridges going there and back.
+fn resolve_rings(rings: &[&[BackendCoord]], rule: FillRule) -> Vec<BackendCoord> {
+    let mut rings: Vec<_> = rings
+        .iter()
+        .map(|ring| (*ring, ring_area(ring)))
+        .filter(|(_, area)| *area != 0)
+        .collect();
+    rings.sort_by_key(|(_, area)| std::cmp::Reverse(area.abs()));
+
+    // The depth, the winding number and the fill inside each ring, found from its innermost
+    // container, which is the smallest larger ring containing it
+    let mut states: Vec<(usize, i64, bool)> = vec![];
+    let mut outline: Vec<BackendCoord> = vec![];
+    for (idx, (ring, area)) in rings.iter().enumerate() {
+        let parent = (0..idx)
+            .rev()
+            .find(|p| rings[*p].1.abs() > area.abs() && ring_contains(rings[*p].0, ring[0]));
+        let (depth, winding, filled) = parent.map_or((0, 0, false), |p| states[p]);
+        let depth = depth + 1;
+        let winding = winding + area.signum();
+        let inside = match rule {
+            FillRule::EvenOdd => depth % 2 == 1,
+            FillRule::NonZero => winding != 0,
+        };
+        states.push((depth, winding, inside));
+        if inside == filled {
+            continue;
+        }
+
+        let mut ring = ring.to_vec();
+        if (*area > 0) != inside {
+            ring.reverse();
+        }
+        match outline.first().copied() {
+            None => {
+                outline.extend_from_slice(&ring);
+                outline.push(ring[0]);
+            }
+            Some(anchor) => {
+                let distance = |p: &BackendCoord| {
+                    let (dx, dy) = (i64::from(p.0 - anchor.0), i64::from(p.1 - anchor.1));
+                    dx * dx + dy * dy
+                };
+                let nearest = (0..ring.len())
+                    .min_by_key(|idx| distance(&ring[*idx]))
+                    .unwrap_or(0);
+                outline.extend_from_slice(&ring[nearest..]);
+                outline.extend_from_slice(&ring[..=nearest]);
+                outline.push(anchor);
+            }
+        }
+    }
+    outline
 }
 
 impl<'a, Coord> PointCollection<'a, Coord> for &'a Polygon<Coord> {
@@ -602,7 +1246,19 @@ impl<Coord, DB: DrawingBackend> Drawable<DB> for Polygon<Coord> {
         backend: &mut DB,
         _: (u32, u32),
     ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
-        backend.fill_polygon(points, &self.style.color.to_backend_color())
+        if self.rings.len() <= 1 {
+            return backend.fill_polygon(points, &self.style.color.to_backend_color());
+        }
+        let points: Vec<_> = points.collect();
+        let mut rings = vec![];
+        let mut rest = &points[..];
+        for len in &self.rings {
+            let (ring, tail) = rest.split_at((*len).min(rest.len()));
+            rings.push(ring);
+            rest = tail;
+        }
+        let outline = resolve_rings(&rings, self.fill_rule);
+        backend.fill_polygon(outline, &self.style.color.to_backend_color())
     }
 }
 
@@ -628,3 +1284,247 @@ fn test_polygon_element() {
     da.draw(&Polygon::new(points.clone(), BLUE))
         .expect("Drawing Failure");
 }
+
+#[cfg(test)]
+#[test]
+fn test_polygon_with_rings() {
+    use crate::prelude::*;
+    let outer = [(0, 0), (100, 0), (100, 100), (0, 100)];
+    let hole = [(25, 25), (75, 25), (75, 75), (25, 75)];
+    let island = [(40, 40), (40, 60), (60, 60), (60, 40)];
+    let reversed: Vec<_> = hole.iter().rev().copied().collect();
+
+    let outline = resolve_rings(&[&hole, &outer], FillRule::EvenOdd);
+    assert_eq!(outline.len(), 11);
+    assert_eq!(&outline[..4], outer);
+    assert_eq!(outline[10], outline[0]);
+    assert!(ring_area(&outline[5..9]) * ring_area(&outer) < 0);
+    assert_eq!(resolve_rings(&[&outer, &hole], FillRule::NonZero).len(), 5);
+    assert_eq!(
+        resolve_rings(&[&outer, &reversed], FillRule::NonZero).len(),
+        11
+    );
+    // The island is inside the hole of the even-odd polygon and fills it again
+    let outline = resolve_rings(&[&outer, &hole, &island], FillRule::EvenOdd);
+    assert_eq!(outline.len(), 17);
+    assert!(ring_area(&outline[11..15]) * ring_area(&outer) > 0);
+
+    let da = crate::create_mocked_drawing_area(200, 200, |m| {
+        m.check_fill_polygon(|c, p| {
+            assert_eq!(c, BLUE.to_rgba());
+            assert_eq!(p.len(), 11);
+        });
+        m.drop_check(|b| assert_eq!(b.num_fill_polygon_call, 1));
+    });
+    da.draw(
+        &Polygon::with_rings(vec![outer.to_vec(), reversed], BLUE).fill_rule(FillRule::NonZero),
+    )
+    .expect("Drawing Failure");
+}
+
+/// The maximum distance in pixels between a curve and the lines approximating it
+const CURVE_TOLERANCE: f64 = 0.25;
+
+/// The maximum depth of the subdivision of a Bézier curve
+const BEZIER_MAX_DEPTH: u32 = 16;
+
+/// Approximate the cubic Bézier curve with the lines by the adaptive subdivision, which splits the
+/// curve in halves until the control points are close enough to the chord. The points after the
+/// start point are pushed to `out`.
+fn flatten_cubic(p: [(f64, f64); 4], depth: u32, out: &mut Vec<BackendCoord>) {
+    let (dx, dy) = (p[3].0 - p[0].0, p[3].1 - p[0].1);
+    let chord = dx.hypot(dy);
+    let distance = |q: (f64, f64)| {
+        if chord > 1e-9 {
+            ((q.0 - p[0].0) * dy - (q.1 - p[0].1) * dx).abs() / chord
+        } else {
+            (q.0 - p[0].0).hypot(q.1 - p[0].1)
+        }
+    };
+    if depth >= BEZIER_MAX_DEPTH || distance(p[1]).max(distance(p[2])) <= CURVE_TOLERANCE {
+        out.push((p[3].0.round() as i32, p[3].1.round() as i32));
+        return;
+    }
+    let mid = |a: (f64, f64), b: (f64, f64)| ((a.0 + b.0) / 2.0, (a.1 + b.1) / 2.0);
+    let (p01, p12, p23) = (mid(p[0], p[1]), mid(p[1], p[2]), mid(p[2], p[3]));
+    let (p012, p123) = (mid(p01, p12), mid(p12, p23));
+    let center = mid(p012, p123);
+    flatten_cubic([p[0], p01, p012, center], depth + 1, out);
+    flatten_cubic([center, p123, p23, p[3]], depth + 1, out);
+}
+
+/// Approximate the cubic Bézier curve of the backend coordinates with the lines
+fn flatten_bezier(p: [BackendCoord; 4]) -> Vec<BackendCoord> {
+    let mut points = vec![p[0]];
+    flatten_cubic(p.map(|(x, y)| (f64::from(x), f64::from(y))), 0, &mut points);
+    points.dedup();
+    points
+}
+
+/**
+A cubic Bézier curve from the start point to the end point, which leaves the start point towards
+the first control point and arrives at the end point from the second control point.
+
+The curve is shaped in the pixel space after the four points are mapped, and it's approximated
+with the lines by the adaptive subdivision, so it's smooth at any scale on any backend. The curve
+is filled as the region closed by its chord when the style is filled.
+
+```
+use plotters::prelude::*;
+let drawing_area = SVGBackend::new("cubic_bezier.svg", (300, 200)).into_drawing_area();
+drawing_area.fill(&WHITE).unwrap();
+drawing_area
+    .draw(&CubicBezier::new([(20, 180), (100, 0), (200, 200), (280, 20)], BLUE.stroke_width(2)))
+    .unwrap();
+```
+*/
+pub struct CubicBezier<Coord> {
+    points: [Coord; 4],
+    style: ShapeStyle,
+}
+
+impl<Coord> CubicBezier<Coord> {
+    /// Create a new cubic Bézier curve
+    /// - `points`: The start point, the two control points and the end point
+    /// - `style`: The shape style
+    /// - returns the created element
+    pub fn new<S: Into<ShapeStyle>>(points: [Coord; 4], style: S) -> Self {
+        Self {
+            points,
+            style: style.into(),
+        }
+    }
+}
+
+impl<'a, Coord> PointCollection<'a, Coord> for &'a CubicBezier<Coord> {
+    type Point = &'a Coord;
+    type IntoIter = &'a [Coord];
+    fn point_iter(self) -> &'a [Coord] {
+        &self.points
+    }
+}
+
+impl<Coord, DB: DrawingBackend> Drawable<DB> for CubicBezier<Coord> {
+    fn draw<I: Iterator<Item = BackendCoord>>(
+        &self,
+        mut points: I,
+        backend: &mut DB,
+        _: (u32, u32),
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        if let (Some(a), Some(b), Some(c), Some(d)) =
+            (points.next(), points.next(), points.next(), points.next())
+        {
+            return draw_outline(flatten_bezier([a, b, c, d]), &self.style, backend);
+        }
+        Ok(())
+    }
+}
+
+/**
+A quadratic Bézier curve from the start point to the end point, of which both ends point towards
+the control point.
+
+See [`CubicBezier`] for how the curve is approximated and filled.
+
+```
+use plotters::prelude::*;
+let drawing_area = SVGBackend::new("quad_bezier.svg", (300, 200)).into_drawing_area();
+drawing_area.fill(&WHITE).unwrap();
+drawing_area
+    .draw(&QuadBezier::new([(20, 180), (150, 0), (280, 180)], RED.mix(0.5).filled()))
+    .unwrap();
+```
+*/
+pub struct QuadBezier<Coord> {
+    points: [Coord; 3],
+    style: ShapeStyle,
+}
+
+impl<Coord> QuadBezier<Coord> {
+    /// Create a new quadratic Bézier curve
+    /// - `points`: The start point, the control point and the end point
+    /// - `style`: The shape style
+    /// - returns the created element
+    pub fn new<S: Into<ShapeStyle>>(points: [Coord; 3], style: S) -> Self {
+        Self {
+            points,
+            style: style.into(),
+        }
+    }
+}
+
+impl<'a, Coord> PointCollection<'a, Coord> for &'a QuadBezier<Coord> {
+    type Point = &'a Coord;
+    type IntoIter = &'a [Coord];
+    fn point_iter(self) -> &'a [Coord] {
+        &self.points
+    }
+}
+
+impl<Coord, DB: DrawingBackend> Drawable<DB> for QuadBezier<Coord> {
+    fn draw<I: Iterator<Item = BackendCoord>>(
+        &self,
+        mut points: I,
+        backend: &mut DB,
+        _: (u32, u32),
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        if let (Some(a), Some(b), Some(c)) = (points.next(), points.next(), points.next()) {
+            // The quadratic curve is the cubic one with the control points two thirds of the way
+            // from the ends to the control point
+            let third = |p: BackendCoord| {
+                (
+                    p.0 + ((b.0 - p.0) as f64 * 2.0 / 3.0).round() as i32,
+                    p.1 + ((b.1 - p.1) as f64 * 2.0 / 3.0).round() as i32,
+                )
+            };
+            let points = flatten_bezier([a, third(a), third(c), c]);
+            return draw_outline(points, &self.style, backend);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_bezier_element() {
+    use crate::prelude::*;
+    let da = crate::create_mocked_drawing_area(300, 300, |m| {
+        m.check_draw_path(|c, _, path| {
+            assert_eq!(c, BLUE.to_rgba());
+            assert_eq!(path, vec![(0, 0), (300, 0)]);
+        });
+        m.check_draw_path(|_, _, path| {
+            assert!(path.len() > 8);
+            assert_eq!(path[0], (0, 300));
+            assert_eq!(path[path.len() - 1], (300, 300));
+            assert!(path.contains(&(150, 150)));
+            for pair in path.windows(2) {
+                assert!(pair[0].0 < pair[1].0);
+            }
+        });
+        m.check_fill_polygon(|c, path| {
+            assert_eq!(c, RED.to_rgba());
+            assert_eq!(path[0], (0, 300));
+            assert!(path.contains(&(150, 150)));
+        });
+        m.drop_check(|b| {
+            assert_eq!(b.num_draw_path_call, 2);
+            assert_eq!(b.num_fill_polygon_call, 1);
+        });
+    });
+    da.draw(&CubicBezier::new(
+        [(0, 0), (100, 0), (200, 0), (300, 0)],
+        BLUE,
+    ))
+    .expect("Drawing Failure");
+    da.draw(&CubicBezier::new(
+        [(0, 300), (100, 100), (200, 100), (300, 300)],
+        BLUE,
+    ))
+    .expect("Drawing Failure");
+    da.draw(&QuadBezier::new(
+        [(0, 300), (150, 0), (300, 300)],
+        RED.filled(),
+    ))
+    .expect("Drawing Failure");
+}
@@ -47,6 +47,7 @@ impl<K, V> BoxplotOrient<K, V> for BoxplotOrientH<K, V> {
 }
 
 const DEFAULT_WIDTH: u32 = 10;
+const DEFAULT_OUTLIER_SIZE: u32 = 3;
 
 /// The boxplot element
 pub struct Boxplot<K, O: BoxplotOrient<K, f32>> {
@@ -56,6 +57,8 @@ pub struct Boxplot<K, O: BoxplotOrient<K, f32>> {
     offset: f64,
     key: K,
     values: [f32; 5],
+    outliers: Vec<f32>,
+    outlier_size: u32,
     _p: PhantomData<O>,
 }
 
@@ -73,15 +76,7 @@ impl<K: Clone> Boxplot<K, BoxplotOrientV<K, f32>> {
     /// let plot = Boxplot::new_vertical("group", &quartiles);
     /// ```
     pub fn new_vertical(key: K, quartiles: &Quartiles) -> Self {
-        Self {
-            style: Into::<ShapeStyle>::into(BLACK),
-            width: DEFAULT_WIDTH,
-            whisker_width: 1.0,
-            offset: 0.0,
-            key,
-            values: quartiles.values(),
-            _p: PhantomData,
-        }
+        Self::with_quartiles(key, quartiles)
     }
 }
 
@@ -99,6 +94,13 @@ impl<K: Clone> Boxplot<K, BoxplotOrientH<K, f32>> {
     /// let plot = Boxplot::new_horizontal("group", &quartiles);
     /// ```
     pub fn new_horizontal(key: K, quartiles: &Quartiles) -> Self {
+        Self::with_quartiles(key, quartiles)
+    }
+}
+
+impl<K, O: BoxplotOrient<K, f32>> Boxplot<K, O> {
+    /// Create a new boxplot element of either orientation
+    pub(crate) fn with_quartiles(key: K, quartiles: &Quartiles) -> Self {
         Self {
             style: Into::<ShapeStyle>::into(BLACK),
             width: DEFAULT_WIDTH,
@@ -106,12 +108,12 @@ impl<K: Clone> Boxplot<K, BoxplotOrientH<K, f32>> {
             offset: 0.0,
             key,
             values: quartiles.values(),
+            outliers: vec![],
+            outlier_size: DEFAULT_OUTLIER_SIZE,
             _p: PhantomData,
         }
     }
-}
 
-impl<K, O: BoxplotOrient<K, f32>> Boxplot<K, O> {
     /// Set the style of the boxplot.
     ///
     /// - `S`: The required style
@@ -175,6 +177,38 @@ impl<K, O: BoxplotOrient<K, f32>> Boxplot<K, O> {
         self.offset = offset.into();
         self
     }
+
+    /// Set the outliers, which are drawn as circles beyond the whiskers.
+    ///
+    /// - `outliers`: The values of the outliers
+    /// - **returns** The up-to-dated boxplot element
+    ///
+    /// ```rust
+    /// use plotters::prelude::*;
+    ///
+    /// let quartiles = Quartiles::with_tukey_fences(&[7, 15, 36, 39, 40, 41, 100], 1.5);
+    /// let plot = Boxplot::new_vertical("group", &quartiles).outliers([100.0]);
+    /// ```
+    pub fn outliers<I: IntoIterator<Item = f32>>(mut self, outliers: I) -> Self {
+        self.outliers = outliers.into_iter().collect();
+        self
+    }
+
+    /// Set the radius of the circles of the outliers.
+    ///
+    /// - `size`: The required radius in pixels
+    /// - **returns** The up-to-dated boxplot element
+    ///
+    /// ```rust
+    /// use plotters::prelude::*;
+    ///
+    /// let quartiles = Quartiles::new(&[7, 15, 36, 39, 40, 41]);
+    /// let plot = Boxplot::new_vertical("group", &quartiles).outlier_size(4);
+    /// ```
+    pub fn outlier_size(mut self, size: u32) -> Self {
+        self.outlier_size = size;
+        self
+    }
 }
 
 impl<'a, K: Clone, O: BoxplotOrient<K, f32>> PointCollection<'a, (O::XType, O::YType)>
@@ -185,6 +219,7 @@ impl<'a, K: Clone, O: BoxplotOrient<K, f32>> PointCollection<'a, (O::XType, O::Y
     fn point_iter(self) -> Self::IntoIter {
         self.values
             .iter()
+            .chain(self.outliers.iter())
             .map(|v| O::make_coord(self.key.clone(), *v))
             .collect()
     }
@@ -197,8 +232,8 @@ impl<K, DB: DrawingBackend, O: BoxplotOrient<K, f32>> Drawable<DB> for Boxplot<K
         backend: &mut DB,
         _: (u32, u32),
     ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
-        let points: Vec<_> = points.take(5).collect();
-        if points.len() == 5 {
+        let points: Vec<_> = points.collect();
+        if points.len() >= 5 {
             let width = f64::from(self.width);
             let moved = |coord| O::with_offset(coord, self.offset);
             let start_bar = |coord| O::with_offset(moved(coord), -width / 2.0);
@@ -248,6 +283,10 @@ impl<K, DB: DrawingBackend, O: BoxplotOrient<K, f32>> Drawable<DB> for Boxplot<K
                 end_whisker(points[4]),
                 &self.style,
             )?;
+
+            for outlier in &points[5..] {
+                backend.draw_circle(moved(*outlier), self.outlier_size, &self.style, false)?;
+            }
         }
         Ok(())
     }
@@ -0,0 +1,306 @@
+use std::collections::BTreeMap;
+use std::ops::Range;
+
+use chrono::{Datelike, Duration, NaiveDate, Weekday};
+
+use crate::element::{Drawable, PointCollection};
+use crate::style::colors::colormaps::ColorMap;
+use crate::style::text_anchor::{HPos, Pos, VPos};
+use crate::style::{Color, IntoFont, RGBAColor, RGBColor, ShapeStyle, TextStyle, BLACK};
+use plotters_backend::{BackendCoord, DrawingBackend, DrawingErrorKind};
+
+/// The gap between the labels and the cells in pixels
+const LABEL_GAP: i32 = 4;
+
+/**
+A calendar heatmap, which lays out one cell per day of a range of dates in the week columns and
+fills the cells with the colors of their values, i.e. the contribution graph of a year.
+
+The weeks go from the left to the right and the days of each week from the top to the bottom,
+starting from [`CalendarHeatmap::first_weekday()`]. The cells are the largest squares fitting the
+area the calendar is drawn on, with the abbreviated month names above the weeks where the months
+begin and the names of every other weekday on the left. The values are mapped to the colormap over
+[`CalendarHeatmap::value_range()`], and the days without a value are filled with
+[`CalendarHeatmap::empty_style()`].
+
+# Examples
+
+```
+use chrono::NaiveDate;
+use plotters::prelude::*;
+let drawing_area = SVGBackend::new("calendar_heatmap.svg", (700, 120)).into_drawing_area();
+drawing_area.fill(&WHITE).unwrap();
+let start = NaiveDate::from_ymd_opt(2023, 1, 1).unwrap();
+let end = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+let values = start
+    .iter_days()
+    .take_while(|day| *day < end)
+    .enumerate()
+    .filter(|(idx, _)| idx % 3 != 0)
+    .map(|(idx, day)| (day, (idx % 7) as f64));
+let calendar = CalendarHeatmap::new(start..end, values, ViridisRGB);
+drawing_area.margin(5, 5, 5, 5).draw(&calendar).unwrap();
+```
+*/
+pub struct CalendarHeatmap<'a> {
+    origin: (i32, i32),
+    range: Range<NaiveDate>,
+    values: BTreeMap<NaiveDate, f64>,
+    colormap: Box<dyn Fn(f64) -> RGBAColor + 'a>,
+    value_range: Option<Range<f64>>,
+    data_range: Range<f64>,
+    first_weekday: Weekday,
+    gap: u32,
+    empty_style: ShapeStyle,
+    label_style: Option<TextStyle<'a>>,
+}
+
+impl<'a> CalendarHeatmap<'a> {
+    /// Create a new calendar heatmap
+    ///
+    /// - `range`: The days of the calendar, excluding the end
+    /// - `values`: The values of the days, the values of the same day are summed and the
+    ///   non-finite values are ignored
+    /// - `colormap`: The colormap of the values
+    /// - **returns** The newly created calendar heatmap
+    pub fn new<C, M, I>(range: Range<NaiveDate>, values: I, colormap: M) -> Self
+    where
+        C: Color,
+        M: ColorMap<C, f64> + 'a,
+        I: IntoIterator<Item = (NaiveDate, f64)>,
+    {
+        let mut sums = BTreeMap::new();
+        for (day, value) in values {
+            if value.is_finite() && range.start <= day && day < range.end {
+                *sums.entry(day).or_insert(0.0) += value;
+            }
+        }
+        let (min, max) = sums
+            .values()
+            .fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), v| {
+                (min.min(*v), max.max(*v))
+            });
+        Self {
+            origin: (0, 0),
+            range,
+            values: sums,
+            colormap: Box::new(move |h| colormap.get_color(h).to_rgba()),
+            value_range: None,
+            data_range: min..max,
+            first_weekday: Weekday::Sun,
+            gap: 2,
+            empty_style: RGBColor(235, 237, 240).filled(),
+            label_style: Some(("sans-serif", 10).into_font().color(&BLACK)),
+        }
+    }
+
+    /// Set the range of the values which is mapped to the whole colormap, which is the range of
+    /// the values by default
+    pub fn value_range(mut self, range: Range<f64>) -> Self {
+        self.value_range = Some(range);
+        self
+    }
+
+    /// Set the first day of the weeks, which is Sunday by default
+    pub fn first_weekday(mut self, weekday: Weekday) -> Self {
+        self.first_weekday = weekday;
+        self
+    }
+
+    /// Set the gap between the cells in pixels, which is 2 by default
+    pub fn gap(mut self, gap: u32) -> Self {
+        self.gap = gap;
+        self
+    }
+
+    /// Set the style of the days without a value
+    pub fn empty_style<S: Into<ShapeStyle>>(mut self, style: S) -> Self {
+        self.empty_style = style.into();
+        self
+    }
+
+    /// Set the style of the month and the weekday labels, `None` hides them
+    pub fn label_style<S: Into<TextStyle<'a>>>(mut self, style: Option<S>) -> Self {
+        self.label_style = style.map(Into::into);
+        self
+    }
+
+    /// Get the number of the week columns
+    pub fn weeks(&self) -> u32 {
+        match self.range.end.pred_opt() {
+            Some(last) if self.range.start < self.range.end => self.cell_of(last).0 + 1,
+            _ => 0,
+        }
+    }
+
+    /// Get the column and the row of the cell of a day
+    pub fn cell_of(&self, day: NaiveDate) -> (u32, u32) {
+        let first = self.first_weekday.num_days_from_sunday();
+        let lead = (self.range.start.weekday().num_days_from_sunday() + 7 - first) % 7;
+        let offset = (day - self.range.start).num_days() + i64::from(lead);
+        ((offset / 7) as u32, (offset % 7) as u32)
+    }
+
+    /// Get the color of a value
+    pub fn color_of(&self, value: f64) -> RGBAColor {
+        let range = self.value_range.as_ref().unwrap_or(&self.data_range);
+        let h = if range.end > range.start {
+            ((value - range.start) / (range.end - range.start)).clamp(0.0, 1.0)
+        } else {
+            1.0
+        };
+        (self.colormap)(h)
+    }
+
+    /// Get the day on a row of the calendar, for the names of the weekdays
+    fn day_of_row(&self, row: u32) -> NaiveDate {
+        let start_row = self.cell_of(self.range.start).1;
+        self.range.start + Duration::days(i64::from(row) - i64::from(start_row))
+    }
+}
+
+impl<'a, 'b> PointCollection<'a, (i32, i32)> for &'a CalendarHeatmap<'b> {
+    type Point = &'a (i32, i32);
+    type IntoIter = std::iter::Once<&'a (i32, i32)>;
+    fn point_iter(self) -> std::iter::Once<&'a (i32, i32)> {
+        std::iter::once(&self.origin)
+    }
+}
+
+impl<'a, DB: DrawingBackend> Drawable<DB> for CalendarHeatmap<'a> {
+    fn draw<I: Iterator<Item = BackendCoord>>(
+        &self,
+        mut pos: I,
+        backend: &mut DB,
+        parent_dim: (u32, u32),
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let origin = pos.next().unwrap_or(self.origin);
+        let weeks = self.weeks() as i32;
+        if weeks == 0 {
+            return Ok(());
+        }
+
+        let (mut left, mut top) = (0, 0);
+        if let Some(style) = &self.label_style {
+            for row in (1..7).step_by(2) {
+                let text = self.day_of_row(row).format("%a").to_string();
+                let (width, _) = backend.estimate_text_size(&text, style)?;
+                left = left.max(width as i32 + LABEL_GAP);
+            }
+            top = backend.estimate_text_size("Jan", style)?.1 as i32 + LABEL_GAP;
+        }
+        let cell = ((parent_dim.0 as i32 - left) / weeks).min((parent_dim.1 as i32 - top) / 7);
+        if cell <= 0 {
+            return Ok(());
+        }
+        let (x0, y0) = (origin.0 + left, origin.1 + top);
+        let size = (cell - self.gap as i32).max(1);
+
+        for day in self
+            .range
+            .start
+            .iter_days()
+            .take_while(|day| *day < self.range.end)
+        {
+            let (col, row) = self.cell_of(day);
+            let (x, y) = (x0 + col as i32 * cell, y0 + row as i32 * cell);
+            let style = match self.values.get(&day) {
+                Some(value) => self.color_of(*value).filled(),
+                None => self.empty_style,
+            };
+            backend.draw_rect((x, y), (x + size - 1, y + size - 1), &style, true)?;
+        }
+
+        if let Some(style) = &self.label_style {
+            let month_style = style.pos(Pos::new(HPos::Left, VPos::Bottom));
+            let mut month = self.range.start.with_day(1).unwrap_or(self.range.start);
+            while month < self.range.end {
+                let (col, row) = self.cell_of(month);
+                // The label goes to the first column which is in the month from its top row
+                let col = if row == 0 { col } else { col + 1 };
+                if month >= self.range.start && (col as i32) < weeks {
+                    let text = month.format("%b").to_string();
+                    let x = x0 + col as i32 * cell;
+                    backend.draw_text(&text, &month_style, (x, y0 - LABEL_GAP))?;
+                }
+                month = match month.month() {
+                    12 => NaiveDate::from_ymd_opt(month.year() + 1, 1, 1),
+                    m => NaiveDate::from_ymd_opt(month.year(), m + 1, 1),
+                }
+                .unwrap_or(self.range.end);
+            }
+
+            let weekday_style = style.pos(Pos::new(HPos::Right, VPos::Center));
+            for row in (1..7).step_by(2) {
+                let text = self.day_of_row(row).format("%a").to_string();
+                let y = y0 + row as i32 * cell + size / 2;
+                backend.draw_text(&text, &weekday_style, (x0 - LABEL_GAP, y))?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_calendar_heatmap() {
+        let day = |m, d| NaiveDate::from_ymd_opt(2023, m, d).unwrap();
+        // 2023-01-01 is a Sunday
+        let calendar = CalendarHeatmap::new(
+            day(1, 1)..day(3, 1),
+            [
+                (day(1, 2), 1.0),
+                (day(1, 2), 2.0),
+                (day(1, 9), 1.0),
+                (day(5, 1), 9.0),
+            ],
+            DerivedColorMap::new(&[BLACK, WHITE]),
+        );
+        assert_eq!(calendar.weeks(), 9);
+        assert_eq!(calendar.cell_of(day(1, 2)), (0, 1));
+        assert_eq!(calendar.cell_of(day(2, 28)), (8, 2));
+        assert_eq!(calendar.color_of(3.0), WHITE.to_rgba());
+        assert_eq!(calendar.color_of(0.0), BLACK.to_rgba());
+
+        let calendar = calendar.first_weekday(Weekday::Mon);
+        assert_eq!(calendar.cell_of(day(1, 1)), (0, 6));
+        assert_eq!(calendar.cell_of(day(1, 2)), (1, 0));
+        assert_eq!(calendar.weeks(), 10);
+        assert_eq!(calendar.day_of_row(0), day(1, 2) - Duration::days(7));
+
+        let calendar = || {
+            CalendarHeatmap::new(
+                day(1, 31)..day(3, 31),
+                [(day(2, 1), 1.0)],
+                DerivedColorMap::new(&[BLACK, WHITE]),
+            )
+        };
+        let drawing_area = create_mocked_drawing_area(100, 70, |m| {
+            m.check_draw_rect(|_, _, filled, upper_left, bottom_right| {
+                assert!(filled);
+                assert_eq!(bottom_right.0 - upper_left.0, 7);
+                assert_eq!(upper_left.0 % 10, 0);
+                assert_eq!(upper_left.1 % 10, 0);
+            });
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_rect_call, 59);
+                assert_eq!(b.num_draw_text_call, 0);
+            });
+        });
+        drawing_area
+            .draw(&calendar().label_style(None::<TextStyle>))
+            .unwrap();
+
+        let drawing_area = create_mocked_drawing_area(300, 200, |m| {
+            m.check_draw_text(|_, _, _, _, text| {
+                assert!(["Feb", "Mar", "Mon", "Wed", "Fri"].contains(&text));
+            });
+            m.drop_check(|b| assert_eq!(b.num_draw_text_call, 5));
+        });
+        drawing_area.draw(&calendar()).unwrap();
+    }
+}
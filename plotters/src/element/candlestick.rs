@@ -1,5 +1,5 @@
 /*!
-  The candlestick element, which showing the high/low/open/close price
+  The candlestick and the OHLC bar elements, which showing the high/low/open/close price
 */
 
 use std::cmp::Ordering;
@@ -12,9 +12,24 @@ use plotters_backend::{BackendCoord, DrawingBackend, DrawingErrorKind};
 pub struct CandleStick<X, Y: PartialOrd> {
     style: ShapeStyle,
     width: u32,
+    wick_width: Option<u32>,
+    hollow: bool,
     points: [(X, Y); 4],
 }
 
+/// Pick the style of gain if the close value is greater than the open value
+fn gain_or_loss_style<Y: PartialOrd, GS: Into<ShapeStyle>, LS: Into<ShapeStyle>>(
+    open: &Y,
+    close: &Y,
+    gain_style: GS,
+    loss_style: LS,
+) -> ShapeStyle {
+    match open.partial_cmp(close) {
+        Some(Ordering::Less) => gain_style.into(),
+        _ => loss_style.into(),
+    }
+}
+
 impl<X: Clone, Y: PartialOrd> CandleStick<X, Y> {
     /// Create a new candlestick element, which requires the Y coordinate can be compared
     ///
@@ -46,11 +61,10 @@ impl<X: Clone, Y: PartialOrd> CandleStick<X, Y> {
         width: u32,
     ) -> Self {
         Self {
-            style: match open.partial_cmp(&close) {
-                Some(Ordering::Less) => gain_style.into(),
-                _ => loss_style.into(),
-            },
+            style: gain_or_loss_style(&open, &close, gain_style, loss_style),
             width,
+            wick_width: None,
+            hollow: false,
             points: [
                 (x.clone(), open),
                 (x.clone(), high),
@@ -59,6 +73,56 @@ impl<X: Clone, Y: PartialOrd> CandleStick<X, Y> {
             ],
         }
     }
+
+    /// Set the stroke width of the wicks, which is the stroke width of the style by default
+    ///
+    /// - `width`: The stroke width of the wicks in pixels
+    /// - **returns** The up-to-dated candlestick element
+    pub fn wick_width(mut self, width: u32) -> Self {
+        self.wick_width = Some(width);
+        self
+    }
+
+    /// Set if the body is drawn as an outline, regardless of the style being filled
+    ///
+    /// - `hollow`: If the body is hollow
+    /// - **returns** The up-to-dated candlestick element
+    ///
+    /// ```rust
+    /// use plotters::prelude::*;
+    ///
+    /// // the hollow bodies for the gains, and the filled ones for the losses
+    /// let (open, close) = (130.06, 129.15);
+    /// let candlestick = CandleStick::new(0, open, 131.37, 128.83, close, &GREEN, RED.filled(), 15)
+    ///     .hollow(open < close);
+    /// ```
+    pub fn hollow(mut self, hollow: bool) -> Self {
+        self.hollow = hollow;
+        self
+    }
+
+    /// Set the style by a function of the open, high, low and close values, which overrides the
+    /// styles of the gain and loss
+    ///
+    /// - `style_fn`: The function which returns the style of the candlestick
+    /// - **returns** The up-to-dated candlestick element
+    ///
+    /// ```rust
+    /// use plotters::prelude::*;
+    ///
+    /// let candlestick = CandleStick::new(0, 130.06, 131.37, 128.83, 129.15, &GREEN, &RED, 15)
+    ///     .style_by(|open, high, low, close| {
+    ///         if high - low > 2.0 { YELLOW.filled() } else { BLUE.filled() }
+    ///     });
+    /// ```
+    pub fn style_by<S: Into<ShapeStyle>, F: FnOnce(&Y, &Y, &Y, &Y) -> S>(
+        mut self,
+        style_fn: F,
+    ) -> Self {
+        let [(_, open), (_, high), (_, low), (_, close)] = &self.points;
+        self.style = style_fn(open, high, low, close).into();
+        self
+    }
 }
 
 impl<'a, X: 'a, Y: PartialOrd + 'a> PointCollection<'a, (X, Y)> for &'a CandleStick<X, Y> {
@@ -78,7 +142,7 @@ impl<X, Y: PartialOrd, DB: DrawingBackend> Drawable<DB> for CandleStick<X, Y> {
     ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
         let mut points: Vec<_> = points.take(4).collect();
         if points.len() == 4 {
-            let fill = self.style.filled;
+            let fill = self.style.filled && !self.hollow;
             if points[0].1 > points[3].1 {
                 points.swap(0, 3);
             }
@@ -87,8 +151,12 @@ impl<X, Y: PartialOrd, DB: DrawingBackend> Drawable<DB> for CandleStick<X, Y> {
                 self.width as i32 - self.width as i32 / 2,
             );
 
-            backend.draw_line(points[0], points[1], &self.style)?;
-            backend.draw_line(points[2], points[3], &self.style)?;
+            let wick_style = match self.wick_width {
+                Some(width) => self.style.stroke_width(width),
+                None => self.style,
+            };
+            backend.draw_line(points[0], points[1], &wick_style)?;
+            backend.draw_line(points[2], points[3], &wick_style)?;
 
             points[0].0 -= l;
             points[3].0 += r;
@@ -98,3 +166,145 @@ impl<X, Y: PartialOrd, DB: DrawingBackend> Drawable<DB> for CandleStick<X, Y> {
         Ok(())
     }
 }
+
+/// The OHLC bar data point element, which is a vertical bar over the high/low range with a tick to
+/// the left at the open value and a tick to the right at the close value
+pub struct OhlcBar<X, Y: PartialOrd> {
+    style: ShapeStyle,
+    tick_width: u32,
+    points: [(X, Y); 4],
+}
+
+impl<X: Clone, Y: PartialOrd> OhlcBar<X, Y> {
+    /// Create a new OHLC bar element, which requires the Y coordinate can be compared
+    ///
+    /// - `x`: The x coordinate
+    /// - `open`: The open value
+    /// - `high`: The high value
+    /// - `low`: The low value
+    /// - `close`: The close value
+    /// - `gain_style`: The style for gain
+    /// - `loss_style`: The style for loss
+    /// - `tick_width`: The length of the open and close ticks
+    /// - **returns** The newly created OHLC bar element
+    ///
+    /// ```rust
+    /// use chrono::prelude::*;
+    /// use plotters::prelude::*;
+    ///
+    /// let bar = OhlcBar::new(Local::now(), 130.0600, 131.3700, 128.8300, 129.1500, &GREEN, &RED, 5);
+    /// ```
+    #[allow(clippy::too_many_arguments)]
+    pub fn new<GS: Into<ShapeStyle>, LS: Into<ShapeStyle>>(
+        x: X,
+        open: Y,
+        high: Y,
+        low: Y,
+        close: Y,
+        gain_style: GS,
+        loss_style: LS,
+        tick_width: u32,
+    ) -> Self {
+        Self {
+            style: gain_or_loss_style(&open, &close, gain_style, loss_style),
+            tick_width,
+            points: [
+                (x.clone(), open),
+                (x.clone(), high),
+                (x.clone(), low),
+                (x, close),
+            ],
+        }
+    }
+
+    /// Set the style by a function of the open, high, low and close values, which overrides the
+    /// styles of the gain and loss
+    ///
+    /// - `style_fn`: The function which returns the style of the OHLC bar
+    /// - **returns** The up-to-dated OHLC bar element
+    pub fn style_by<S: Into<ShapeStyle>, F: FnOnce(&Y, &Y, &Y, &Y) -> S>(
+        mut self,
+        style_fn: F,
+    ) -> Self {
+        let [(_, open), (_, high), (_, low), (_, close)] = &self.points;
+        self.style = style_fn(open, high, low, close).into();
+        self
+    }
+}
+
+impl<'a, X: 'a, Y: PartialOrd + 'a> PointCollection<'a, (X, Y)> for &'a OhlcBar<X, Y> {
+    type Point = &'a (X, Y);
+    type IntoIter = &'a [(X, Y)];
+    fn point_iter(self) -> &'a [(X, Y)] {
+        &self.points
+    }
+}
+
+impl<X, Y: PartialOrd, DB: DrawingBackend> Drawable<DB> for OhlcBar<X, Y> {
+    fn draw<I: Iterator<Item = BackendCoord>>(
+        &self,
+        points: I,
+        backend: &mut DB,
+        _: (u32, u32),
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let points: Vec<_> = points.take(4).collect();
+        if points.len() == 4 {
+            let (open, high, low, close) = (points[0], points[1], points[2], points[3]);
+            let tick = self.tick_width as i32;
+            backend.draw_line(high, low, &self.style)?;
+            backend.draw_line((open.0 - tick, open.1), open, &self.style)?;
+            backend.draw_line(close, (close.0 + tick, close.1), &self.style)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_candlestick_options() {
+        let da = crate::create_mocked_drawing_area(300, 300, |m| {
+            m.check_draw_line(|c, width, _, _| {
+                assert_eq!(c, YELLOW.to_rgba());
+                assert_eq!(width, 3);
+            });
+            m.check_draw_line(|_, width, _, _| assert_eq!(width, 3));
+            m.check_draw_rect(|c, _, fill, u, d| {
+                assert_eq!(c, YELLOW.to_rgba());
+                assert!(!fill);
+                assert_eq!([u, d], [(95, 120), (105, 150)]);
+            });
+            m.drop_check(|b| assert_eq!(b.num_draw_rect_call, 1));
+        });
+        let candlestick =
+            CandleStick::new(100, 150, 100, 200, 120, GREEN.filled(), RED.filled(), 10)
+                .wick_width(3)
+                .hollow(true)
+                .style_by(|open, _, _, close| {
+                    if close < open {
+                        YELLOW.filled()
+                    } else {
+                        BLUE.filled()
+                    }
+                });
+        da.draw(&candlestick).unwrap();
+    }
+
+    #[test]
+    fn test_ohlc_bar() {
+        let da = crate::create_mocked_drawing_area(300, 300, |m| {
+            m.check_draw_line(|c, _, from, to| {
+                assert_eq!(c, RED.to_rgba());
+                assert_eq!([from, to], [(100, 100), (100, 200)]);
+            });
+            m.check_draw_line(|_, _, from, to| assert_eq!([from, to], [(95, 150), (100, 150)]));
+            m.check_draw_line(|_, _, from, to| assert_eq!([from, to], [(100, 120), (105, 120)]));
+            m.drop_check(|b| assert_eq!(b.num_draw_line_call, 3));
+        });
+        da.draw(&OhlcBar::new(100, 150, 100, 200, 120, GREEN, RED, 5))
+            .unwrap();
+    }
+}
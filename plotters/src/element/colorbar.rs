@@ -0,0 +1,198 @@
+use std::ops::Range;
+use std::rc::Rc;
+
+use crate::data::float::pretty_print_float;
+use crate::element::{Drawable, PointCollection};
+use crate::style::colors::colormaps::ColorMap;
+use crate::style::text_anchor::{HPos, Pos, VPos};
+use crate::style::{Color, IntoFont, RGBAColor, ShapeStyle, TextStyle, BLACK};
+use plotters_backend::{BackendCoord, DrawingBackend, DrawingErrorKind};
+
+/// The length of the tick marks in pixels
+const TICK_SIZE: i32 = 4;
+
+/**
+A vertical colorbar, which is the legend of the colors of a colormap over a range of values.
+
+The colorbar fills the height of the area it's drawn on, with the gradient strip on the left of
+the area and the evenly spaced tick labels on its right. The upper end of the strip is the end of
+the range. It's also created by [`crate::series::ColormapPointSeries::colorbar()`] to match the
+colors of the series.
+
+# Examples
+
+```
+use plotters::prelude::*;
+let drawing_area = SVGBackend::new("colorbar.svg", (350, 200)).into_drawing_area();
+drawing_area.fill(&WHITE).unwrap();
+let (_, colorbar_area) = drawing_area.split_horizontally(300);
+let colorbar = ColorBar::new(ViridisRGB, 0.0..100.0).ticks(6);
+colorbar_area.margin(10, 10, 0, 0).draw(&colorbar).unwrap();
+```
+*/
+pub struct ColorBar<'a> {
+    origin: (i32, i32),
+    colormap: Rc<dyn Fn(f64) -> RGBAColor + 'a>,
+    range: Range<f64>,
+    bar_width: u32,
+    ticks: usize,
+    border_style: Option<ShapeStyle>,
+    label_style: Option<TextStyle<'a>>,
+    label_formatter: Rc<dyn Fn(f64) -> String + 'a>,
+}
+
+impl<'a> ColorBar<'a> {
+    /// Create a new colorbar
+    ///
+    /// - `colormap`: The colormap of the colorbar
+    /// - `range`: The range of the values which is mapped to the whole colormap
+    /// - **returns** The newly created colorbar
+    pub fn new<C: Color, M: ColorMap<C, f64> + 'a>(colormap: M, range: Range<f64>) -> Self {
+        Self::from_fn(Rc::new(move |h| colormap.get_color(h).to_rgba()), range)
+    }
+
+    /// Create a colorbar from the function which maps `[0, 1]` to the colors
+    pub(crate) fn from_fn(colormap: Rc<dyn Fn(f64) -> RGBAColor + 'a>, range: Range<f64>) -> Self {
+        Self {
+            origin: (0, 0),
+            colormap,
+            range,
+            bar_width: 16,
+            ticks: 5,
+            border_style: Some(BLACK.into()),
+            label_style: Some(("sans-serif", 12).into_font().color(&BLACK)),
+            label_formatter: Rc::new(|value| pretty_print_float(value, false)),
+        }
+    }
+
+    /// Set the width of the gradient strip in pixels, which is 16 by default
+    pub fn bar_width(mut self, width: u32) -> Self {
+        self.bar_width = width;
+        self
+    }
+
+    /// Set the number of the tick labels including both ends of the range, which is at least 2
+    pub fn ticks(mut self, ticks: usize) -> Self {
+        self.ticks = ticks.max(2);
+        self
+    }
+
+    /// Set the style of the border of the strip and the tick marks, `None` hides them
+    pub fn border_style<S: Into<ShapeStyle>>(mut self, style: Option<S>) -> Self {
+        self.border_style = style.map(Into::into);
+        self
+    }
+
+    /// Set the style of the tick labels, `None` hides them
+    pub fn label_style<S: Into<TextStyle<'a>>>(mut self, style: Option<S>) -> Self {
+        self.label_style = style.map(Into::into);
+        self
+    }
+
+    /// Set the function which formats the tick labels
+    pub fn label_formatter<F: Fn(f64) -> String + 'a>(mut self, formatter: F) -> Self {
+        self.label_formatter = Rc::new(formatter);
+        self
+    }
+
+    /// Get the values of the tick labels, from the start to the end of the range
+    pub fn tick_values(&self) -> Vec<f64> {
+        let Range { start, end } = self.range;
+        (0..self.ticks)
+            .map(|idx| start + (end - start) * idx as f64 / (self.ticks - 1) as f64)
+            .collect()
+    }
+}
+
+impl<'a, 'b> PointCollection<'a, (i32, i32)> for &'a ColorBar<'b> {
+    type Point = &'a (i32, i32);
+    type IntoIter = std::iter::Once<&'a (i32, i32)>;
+    fn point_iter(self) -> std::iter::Once<&'a (i32, i32)> {
+        std::iter::once(&self.origin)
+    }
+}
+
+impl<'a, DB: DrawingBackend> Drawable<DB> for ColorBar<'a> {
+    fn draw<I: Iterator<Item = BackendCoord>>(
+        &self,
+        mut pos: I,
+        backend: &mut DB,
+        parent_dim: (u32, u32),
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let origin = pos.next().unwrap_or(self.origin);
+        // Leave the room for the half of the labels at both ends of the strip
+        let padding = match &self.label_style {
+            Some(style) => (style.font.get_size() / 2.0).ceil() as i32,
+            None => 0,
+        };
+        let (x0, x1) = (origin.0, origin.0 + self.bar_width as i32 - 1);
+        let (top, bottom) = (
+            origin.1 + padding,
+            origin.1 + parent_dim.1 as i32 - 1 - padding,
+        );
+        if bottom <= top {
+            return Ok(());
+        }
+
+        for y in top..=bottom {
+            let h = f64::from(bottom - y) / f64::from(bottom - top);
+            let style = (self.colormap)(h).filled();
+            backend.draw_line((x0, y), (x1, y), &style)?;
+        }
+        if let Some(style) = &self.border_style {
+            backend.draw_rect((x0, top), (x1, bottom), style, false)?;
+        }
+
+        let label_style = self
+            .label_style
+            .as_ref()
+            .map(|style| style.pos(Pos::new(HPos::Left, VPos::Center)));
+        for (idx, value) in self.tick_values().into_iter().enumerate() {
+            let y = bottom
+                - (f64::from(bottom - top) * idx as f64 / (self.ticks - 1) as f64).round() as i32;
+            if let Some(style) = &self.border_style {
+                backend.draw_line((x1, y), (x1 + TICK_SIZE, y), style)?;
+            }
+            if let Some(style) = &label_style {
+                let text = (self.label_formatter)(value);
+                backend.draw_text(&text, style, (x1 + TICK_SIZE + 2, y))?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_colorbar() {
+        let colorbar = ColorBar::new(DerivedColorMap::new(&[BLACK, WHITE]), 0.0..10.0);
+        assert_eq!(colorbar.tick_values(), [0.0, 2.5, 5.0, 7.5, 10.0]);
+
+        let drawing_area = create_mocked_drawing_area(100, 101, |m| {
+            m.check_draw_text(|_, _, _, pos, text| {
+                assert_eq!(pos.0, 21);
+                match text {
+                    "0" => assert_eq!(pos.1, 100),
+                    "10" => assert_eq!(pos.1, 0),
+                    _ => panic!("Unexpected label {}", text),
+                }
+            });
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_line_call, 103);
+                assert_eq!(b.num_draw_rect_call, 1);
+                assert_eq!(b.num_draw_text_call, 2);
+            });
+        });
+        drawing_area
+            .draw(
+                &ColorBar::new(DerivedColorMap::new(&[BLACK, WHITE]), 0.0..10.0)
+                    .ticks(0)
+                    .label_style(Some(("sans-serif", 0.0).into_font())),
+            )
+            .unwrap();
+    }
+}
@@ -0,0 +1,97 @@
+use crate::style::{Color, Palette, Palette99, RGBAColor};
+
+/**
+A node of the hierarchical data presented by a [`crate::element::Treemap`] or a
+[`crate::element::Sunburst`].
+
+A leaf has its own weight, while a group weighs the total of its children. The nodes without a
+color inherit the color of their parents.
+*/
+#[derive(Clone, Debug, PartialEq)]
+pub struct HierarchyNode {
+    label: String,
+    weight: f64,
+    color: Option<RGBAColor>,
+    children: Vec<HierarchyNode>,
+}
+
+impl HierarchyNode {
+    /// Create a leaf node
+    ///
+    /// - `label`: The label of the node
+    /// - `weight`: The weight of the node, the non-positive or non-finite weights count as zero
+    /// - **returns** The newly created node
+    pub fn leaf<L: Into<String>>(label: L, weight: f64) -> Self {
+        Self {
+            label: label.into(),
+            weight: if weight.is_finite() {
+                weight.max(0.0)
+            } else {
+                0.0
+            },
+            color: None,
+            children: vec![],
+        }
+    }
+
+    /// Create a group node
+    ///
+    /// - `label`: The label of the node
+    /// - `children`: The children of the node
+    /// - **returns** The newly created node, which weighs the total of the children
+    pub fn group<L: Into<String>, I: IntoIterator<Item = HierarchyNode>>(
+        label: L,
+        children: I,
+    ) -> Self {
+        let children: Vec<_> = children.into_iter().collect();
+        Self {
+            label: label.into(),
+            weight: children.iter().map(|c| c.weight).sum(),
+            color: None,
+            children,
+        }
+    }
+
+    /// Set the color of the node, which is inherited by the children without a color
+    pub fn color<C: Color>(mut self, color: C) -> Self {
+        self.color = Some(color.to_rgba());
+        self
+    }
+
+    /// Get the label of the node
+    pub fn label(&self) -> &str {
+        &self.label
+    }
+
+    /// Get the weight of the node
+    pub fn weight(&self) -> f64 {
+        self.weight
+    }
+
+    /// Get the color of the node, if it has its own color
+    pub fn own_color(&self) -> Option<RGBAColor> {
+        self.color
+    }
+
+    /// Get the children of the node
+    pub fn children(&self) -> &[HierarchyNode] {
+        &self.children
+    }
+}
+
+/// Find the node with the path of the indices among the siblings from the root node, and the
+/// color it inherits, which defaults to the color of [`Palette99`] picked by the root index
+pub(super) fn find_node<'a>(
+    roots: &'a [HierarchyNode],
+    path: &[usize],
+) -> (&'a HierarchyNode, RGBAColor) {
+    let mut color = Palette99::pick(path[0]).to_rgba();
+    let mut nodes = roots;
+    let mut node = &nodes[path[0]];
+    for &index in path {
+        node = &nodes[index];
+        color = node.color.unwrap_or(color);
+        nodes = &node.children;
+    }
+    (node, color)
+}
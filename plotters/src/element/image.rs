@@ -4,7 +4,7 @@
 ))]
 use image::{DynamicImage, GenericImageView};
 
-use super::{Drawable, PointCollection};
+use super::{BackendCoordAndOrigin, Drawable, PointCollection};
 use plotters_backend::{BackendCoord, DrawingBackend, DrawingErrorKind};
 
 use plotters_bitmap::bitmap_pixel::{PixelFormat, RGBPixel};
@@ -19,6 +19,7 @@ use plotters_bitmap::BitMapBackend;
 
 use std::borrow::Borrow;
 use std::marker::PhantomData;
+use std::ops::Range;
 
 enum Buffer<'a> {
     Owned(Vec<u8>),
@@ -59,11 +60,27 @@ impl<'a> Buffer<'a> {
     }
 }
 
+/// The method of resampling a [`BitMapElement`] stretched to a different size
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Resampling {
+    /// Take the nearest pixel, which keeps the pixels sharp, i.e. the pixel art and the map tiles
+    Nearest,
+    /// Interpolate the four nearest pixels linearly, which makes the smooth images
+    Bilinear,
+}
+
 /// The element that contains a bitmap on it
+///
+/// The bitmap is drawn in its own size from its position by default, and it's resampled to fill
+/// the rectangle from its position to a corner with [`BitMapElement::stretch_to()`], so it's
+/// scaled with the chart, i.e. the logos and the map tiles under the plots. Only the part of a
+/// stretched bitmap in the drawing area is resampled and drawn.
 pub struct BitMapElement<'a, Coord, P: PixelFormat = RGBPixel> {
     image: Buffer<'a>,
     size: (u32, u32),
     pos: Coord,
+    corner: Option<Coord>,
+    resampling: Resampling,
     phantom: PhantomData<P>,
 }
 
@@ -78,6 +95,8 @@ impl<'a, Coord, P: PixelFormat> BitMapElement<'a, Coord, P> {
             image: Buffer::Owned(vec![0; (size.0 * size.1) as usize * P::PIXEL_SIZE]),
             size,
             pos,
+            corner: None,
+            resampling: Resampling::Bilinear,
             phantom: PhantomData,
         }
     }
@@ -99,6 +118,8 @@ impl<'a, Coord, P: PixelFormat> BitMapElement<'a, Coord, P> {
             image: Buffer::Owned(buf),
             size,
             pos,
+            corner: None,
+            resampling: Resampling::Bilinear,
             phantom: PhantomData,
         })
     }
@@ -119,6 +140,8 @@ impl<'a, Coord, P: PixelFormat> BitMapElement<'a, Coord, P> {
             image: Buffer::BorrowedMut(buf),
             size,
             pos,
+            corner: None,
+            resampling: Resampling::Bilinear,
             phantom: PhantomData,
         })
     }
@@ -140,6 +163,8 @@ impl<'a, Coord, P: PixelFormat> BitMapElement<'a, Coord, P> {
             image: Buffer::Borrowed(buf),
             size,
             pos,
+            corner: None,
+            resampling: Resampling::Bilinear,
             phantom: PhantomData,
         })
     }
@@ -147,22 +172,44 @@ impl<'a, Coord, P: PixelFormat> BitMapElement<'a, Coord, P> {
     /// Copy the existing bitmap element to another location
     ///
     /// - `pos`: The new location to copy
+    ///
+    /// The copy is drawn in the size of the bitmap, since the corner of the stretched bitmap is in
+    /// the old coordinate.
     pub fn copy_to<Coord2>(&self, pos: Coord2) -> BitMapElement<Coord2, P> {
         BitMapElement {
             image: Buffer::Borrowed(self.image.borrow()),
             size: self.size,
             pos,
+            corner: None,
+            resampling: self.resampling,
             phantom: PhantomData,
         }
     }
 
-    /// Move the existing bitmap element to a new position
+    /// Move the existing bitmap element to a new position, the corner of the stretched bitmap
+    /// stays
     ///
     /// - `pos`: The new position
     pub fn move_to(&mut self, pos: Coord) {
         self.pos = pos;
     }
 
+    /// Stretch the bitmap to fill the rectangle from its position to a corner
+    ///
+    /// - `corner`: The opposite corner of the rectangle
+    /// - **returns**: The stretched bitmap element
+    pub fn stretch_to(mut self, corner: Coord) -> Self {
+        self.corner = Some(corner);
+        self
+    }
+
+    /// Set the method of resampling the stretched bitmap, which is [`Resampling::Bilinear`] by
+    /// default
+    pub fn resampling(mut self, resampling: Resampling) -> Self {
+        self.resampling = resampling;
+        self
+    }
+
     /// Make the bitmap element as a bitmap backend, so that we can use
     /// plotters drawing functionality on the bitmap element
     pub fn as_bitmap_backend(&mut self) -> BitMapBackend<P> {
@@ -182,6 +229,8 @@ impl<'a, Coord> From<(Coord, DynamicImage)> for BitMapElement<'a, Coord, RGBPixe
             pos,
             image: Buffer::Owned(rgb_image),
             size: (w, h),
+            corner: None,
+            resampling: Resampling::Bilinear,
             phantom: PhantomData,
         }
     }
@@ -199,30 +248,219 @@ impl<'a, Coord> From<(Coord, DynamicImage)> for BitMapElement<'a, Coord, BGRXPix
             pos,
             image: Buffer::Owned(rgb_image),
             size: (w, h),
+            corner: None,
+            resampling: Resampling::Bilinear,
             phantom: PhantomData,
         }
     }
 }
 
-impl<'a, 'b, Coord> PointCollection<'a, Coord> for &'a BitMapElement<'b, Coord> {
+#[cfg(all(
+    not(all(target_arch = "wasm32", not(target_os = "wasi"))),
+    feature = "image"
+))]
+impl<'a, Coord> BitMapElement<'a, Coord, RGBPixel> {
+    /// Create a new bitmap element from an encoded image, i.e. the content of a PNG or a JPEG file
+    ///
+    /// - `pos`: The left upper coordinate of the element
+    /// - `bytes`: The encoded image, of which the format is guessed from the content
+    /// - **returns**: The newly created image element, or the error decoding the image
+    pub fn from_encoded(pos: Coord, bytes: &[u8]) -> image::ImageResult<Self> {
+        Ok((pos, image::load_from_memory(bytes)?).into())
+    }
+}
+
+/// Resample the RGB image to the target size, of which only the pixels in the window of the
+/// ranges of the X and the Y coordinates are computed
+fn resample(
+    src: &[u8],
+    (iw, ih): (u32, u32),
+    (tw, th): (u32, u32),
+    (xs, ys): (Range<u32>, Range<u32>),
+    method: Resampling,
+) -> Option<Vec<u8>> {
+    let pixel =
+        |x: u32, y: u32, c: usize| f64::from(src[(x as usize + y as usize * iw as usize) * 3 + c]);
+    // The position of the center of a target pixel in the source image
+    let source = |t: u32, target: u32, size: u32| {
+        (f64::from(t) + 0.5) * f64::from(size) / f64::from(target) - 0.5
+    };
+    let capacity = (xs.len()).checked_mul(ys.len())?.checked_mul(3)?;
+    let mut dst = Vec::with_capacity(capacity);
+    for ty in ys {
+        let sy = source(ty, th, ih).clamp(0.0, f64::from(ih - 1));
+        for tx in xs.clone() {
+            let sx = source(tx, tw, iw).clamp(0.0, f64::from(iw - 1));
+            for c in 0..3 {
+                let value = match method {
+                    Resampling::Nearest => pixel(sx.round() as u32, sy.round() as u32, c),
+                    Resampling::Bilinear => {
+                        let (x0, y0) = (sx.floor() as u32, sy.floor() as u32);
+                        let (x1, y1) = ((x0 + 1).min(iw - 1), (y0 + 1).min(ih - 1));
+                        let (fx, fy) = (sx - f64::from(x0), sy - f64::from(y0));
+                        let top = pixel(x0, y0, c) * (1.0 - fx) + pixel(x1, y0, c) * fx;
+                        let bottom = pixel(x0, y1, c) * (1.0 - fx) + pixel(x1, y1, c) * fx;
+                        top * (1.0 - fy) + bottom * fy
+                    }
+                };
+                dst.push(value.round() as u8);
+            }
+        }
+    }
+    Some(dst)
+}
+
+impl<'a, 'b, Coord> PointCollection<'a, Coord, BackendCoordAndOrigin>
+    for &'a BitMapElement<'b, Coord>
+{
     type Point = &'a Coord;
-    type IntoIter = std::iter::Once<&'a Coord>;
+    type IntoIter = std::iter::Chain<std::iter::Once<&'a Coord>, std::option::Iter<'a, Coord>>;
     fn point_iter(self) -> Self::IntoIter {
-        std::iter::once(&self.pos)
+        std::iter::once(&self.pos).chain(self.corner.iter())
     }
 }
 
-impl<'a, Coord, DB: DrawingBackend> Drawable<DB> for BitMapElement<'a, Coord> {
-    fn draw<I: Iterator<Item = BackendCoord>>(
+impl<'a, Coord, DB: DrawingBackend> Drawable<DB, BackendCoordAndOrigin>
+    for BitMapElement<'a, Coord>
+{
+    fn draw<I: Iterator<Item = (BackendCoord, BackendCoord)>>(
         &self,
         mut points: I,
         backend: &mut DB,
-        _: (u32, u32),
+        parent_dim: (u32, u32),
     ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
-        if let Some((x, y)) = points.next() {
+        let ((x, y), area) = match points.next() {
+            Some(point) => point,
+            None => return Ok(()),
+        };
+        let (origin, target) = match points.next() {
+            Some(((cx, cy), _)) => (
+                (x.min(cx), y.min(cy)),
+                ((cx - x).unsigned_abs(), (cy - y).unsigned_abs()),
+            ),
+            None => ((x, y), self.size),
+        };
+        if target == self.size || self.size.0 == 0 || self.size.1 == 0 {
+            // The element in its own size is put at its position truncated to the drawing area
+            let clamp = |v: i32, start: i32, size: u32| v.max(start).min(start + size as i32);
+            let pos = (
+                clamp(origin.0, area.0, parent_dim.0),
+                clamp(origin.1, area.1, parent_dim.1),
+            );
             // TODO: convert the pixel format when needed
-            return backend.blit_bitmap((x, y), self.size, self.image.as_ref());
+            return backend.blit_bitmap(pos, self.size, self.image.as_ref());
         }
-        Ok(())
+        // Only the part of the stretched image in the drawing area is resampled
+        let visible = |start: i32, size: u32, area: i32, limit: u32| {
+            let from = (i64::from(area) - i64::from(start)).clamp(0, i64::from(size));
+            let to = (i64::from(area) + i64::from(limit) - i64::from(start))
+                .clamp(from, i64::from(size));
+            from as u32..to as u32
+        };
+        let xs = visible(origin.0, target.0, area.0, parent_dim.0);
+        let ys = visible(origin.1, target.1, area.1, parent_dim.1);
+        if xs.is_empty() || ys.is_empty() {
+            return Ok(());
+        }
+        let pos = (origin.0 + xs.start as i32, origin.1 + ys.start as i32);
+        let size = (xs.len() as u32, ys.len() as u32);
+        match resample(
+            self.image.as_ref(),
+            self.size,
+            target,
+            (xs, ys),
+            self.resampling,
+        ) {
+            Some(image) => backend.blit_bitmap(pos, size, &image),
+            None => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_resample() {
+        let image = [0, 0, 0, 200, 100, 50];
+        let nearest = resample(&image, (2, 1), (4, 1), (0..4, 0..1), Resampling::Nearest);
+        assert_eq!(
+            nearest.unwrap(),
+            [0, 0, 0, 0, 0, 0, 200, 100, 50, 200, 100, 50]
+        );
+        let bilinear = resample(&image, (2, 1), (4, 1), (0..4, 0..1), Resampling::Bilinear);
+        assert_eq!(
+            bilinear.unwrap(),
+            [0, 0, 0, 50, 25, 13, 150, 75, 38, 200, 100, 50]
+        );
+        assert_eq!(
+            resample(&image, (2, 1), (1, 2), (0..1, 0..2), Resampling::Bilinear).unwrap(),
+            [100, 50, 25, 100, 50, 25]
+        );
+        assert_eq!(
+            resample(&image, (2, 1), (4, 1), (1..3, 0..1), Resampling::Bilinear).unwrap(),
+            [50, 25, 13, 150, 75, 38]
+        );
+    }
+
+    #[test]
+    fn test_stretched_bitmap_element() {
+        let image = vec![255, 0, 0, 0, 0, 255];
+        let drawing_area = create_mocked_drawing_area(101, 101, |m| {
+            m.check_draw_pixel(|c, (x, y)| {
+                assert!((10..14).contains(&x) && (20..22).contains(&y));
+                let expected = if x < 12 { RED } else { BLUE };
+                assert_eq!(c, expected.to_rgba());
+            });
+            m.drop_check(|b| assert_eq!(b.num_draw_pixel_call, 8));
+        });
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0..100, 0..100)
+            .unwrap();
+        let element = BitMapElement::with_owned_buffer((14, 80), (2, 1), image)
+            .unwrap()
+            .stretch_to((10, 78))
+            .resampling(Resampling::Nearest);
+        chart.draw_series(std::iter::once(element)).unwrap();
+    }
+
+    #[test]
+    fn test_stretched_bitmap_element_is_clipped() {
+        let drawing_area = create_mocked_drawing_area(101, 101, |m| {
+            m.check_draw_pixel(|c, (x, y)| {
+                assert!((0..101).contains(&x) && (0..101).contains(&y));
+                assert_eq!(c, BLUE.to_rgba());
+            });
+            m.drop_check(|b| assert_eq!(b.num_draw_pixel_call, 101 * 101));
+        });
+        // The stretched image is too large to be allocated, but only the visible part is drawn
+        let element =
+            BitMapElement::with_owned_buffer((-1 << 29, 0), (2, 1), vec![255, 0, 0, 0, 0, 255])
+                .unwrap()
+                .stretch_to((1 << 29, 1 << 30))
+                .resampling(Resampling::Nearest);
+        drawing_area.draw(&element).unwrap();
+    }
+
+    #[cfg(all(
+        not(all(target_arch = "wasm32", not(target_os = "wasi"))),
+        feature = "image"
+    ))]
+    #[test]
+    fn test_bitmap_element_from_encoded() {
+        let image = image::RgbImage::from_raw(2, 1, vec![255, 0, 0, 0, 0, 255]).unwrap();
+        let mut bytes = vec![];
+        DynamicImage::ImageRgb8(image)
+            .write_to(
+                &mut std::io::Cursor::new(&mut bytes),
+                image::ImageOutputFormat::Png,
+            )
+            .unwrap();
+        let element = BitMapElement::from_encoded((0, 0), &bytes).unwrap();
+        assert_eq!(element.size, (2, 1));
+        assert_eq!(element.image.as_ref(), [255, 0, 0, 0, 0, 255]);
+        assert!(BitMapElement::from_encoded((0, 0), &bytes[1..]).is_err());
     }
 }
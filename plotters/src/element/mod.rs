@@ -176,7 +176,7 @@ pub use composable::{ComposedElement, EmptyElement};
 mod candlestick;
 #[cfg(feature = "candlestick")]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "candlestick")))]
-pub use candlestick::CandleStick;
+pub use candlestick::{CandleStick, OhlcBar};
 
 #[cfg(feature = "errorbar")]
 mod errorbar;
@@ -189,18 +189,62 @@ mod boxplot;
 #[cfg(feature = "boxplot")]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "boxplot")))]
 pub use boxplot::Boxplot;
+#[cfg(feature = "boxplot")]
+pub(crate) use boxplot::{BoxplotOrient, BoxplotOrientH, BoxplotOrientV};
+
+#[cfg(feature = "violin")]
+mod violin;
+#[cfg(feature = "violin")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "violin")))]
+pub use violin::Violin;
+
+#[cfg(feature = "colorbar")]
+mod colorbar;
+#[cfg(feature = "colorbar")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "colorbar")))]
+pub use colorbar::ColorBar;
+
+#[cfg(feature = "calendar")]
+mod calendar;
+#[cfg(feature = "calendar")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "calendar")))]
+pub use calendar::CalendarHeatmap;
 
 #[cfg(feature = "bitmap_backend")]
 mod image;
 #[cfg(feature = "bitmap_backend")]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "bitmap_backend")))]
-pub use self::image::BitMapElement;
+pub use self::image::{BitMapElement, Resampling};
 
 mod dynelem;
 pub use dynelem::{DynElement, IntoDynElement};
 
 mod pie;
-pub use pie::Pie;
+pub use pie::{Pie, PieLabelPosition};
+
+#[cfg(feature = "sankey")]
+mod sankey;
+#[cfg(feature = "sankey")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "sankey")))]
+pub use sankey::{Sankey, SankeyLayout, SankeyLinkColor};
+
+#[cfg(any(feature = "treemap", feature = "sunburst"))]
+mod hierarchy;
+#[cfg(any(feature = "treemap", feature = "sunburst"))]
+#[cfg_attr(doc_cfg, doc(cfg(any(feature = "treemap", feature = "sunburst"))))]
+pub use hierarchy::HierarchyNode;
+
+#[cfg(feature = "sunburst")]
+mod sunburst;
+#[cfg(feature = "sunburst")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "sunburst")))]
+pub use sunburst::{Sunburst, SunburstLabelFit, SunburstSector};
+
+#[cfg(feature = "treemap")]
+mod treemap;
+#[cfg(feature = "treemap")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "treemap")))]
+pub use treemap::{Treemap, TreemapCell};
 
 use crate::coord::CoordTranslate;
 use crate::drawing::Rect;
@@ -273,6 +317,27 @@ impl CoordMapper for BackendCoordOnly {
     }
 }
 
+/**
+Used for the elements clipping themselves to the drawing area, i.e. the stretched bitmaps, which
+get the backend coordinates without truncating them to the drawing area, together with the upper
+left corner of the area.
+*/
+pub struct BackendCoordAndOrigin;
+
+impl CoordMapper for BackendCoordAndOrigin {
+    type Output = (BackendCoord, BackendCoord);
+    fn map<CT: CoordTranslate>(
+        coord_trans: &CT,
+        from: &CT::From,
+        rect: &Rect,
+    ) -> (BackendCoord, BackendCoord) {
+        (
+            coord_trans.translate(from),
+            rect.truncate((i32::MIN, i32::MIN)),
+        )
+    }
+}
+
 /**
 Used for 3d coordinate transformations.
 
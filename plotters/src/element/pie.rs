@@ -1,6 +1,7 @@
 use crate::{
     element::{Drawable, PointCollection},
-    style::{IntoFont, RGBColor, TextStyle, BLACK},
+    style::text_anchor::{HPos, Pos, VPos},
+    style::{Color, IntoFont, RGBColor, ShapeStyle, TextStyle, BLACK},
 };
 use plotters_backend::{BackendCoord, DrawingBackend, DrawingErrorKind};
 use std::{error::Error, f64::consts::PI, fmt::Display};
@@ -19,6 +20,15 @@ impl Display for PieError {
 
 impl Error for PieError {}
 
+/// The placement of the labels of the slices of a [Pie]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PieLabelPosition {
+    /// The labels are outside of the pie, next to the middle of the slices
+    Outside,
+    /// The labels are centered in the slices
+    Inside,
+}
+
 /// A Pie Graph
 pub struct Pie<'a, Coord, Label: Display> {
     center: &'a Coord, // cartesian coord
@@ -32,6 +42,11 @@ pub struct Pie<'a, Coord, Label: Display> {
     label_offset: f64,
     percentage_style: Option<TextStyle<'a>>,
     donut_hole: f64, // radius of the hole in case of a donut chart
+    slice_styles: Vec<ShapeStyle>,
+    label_position: PieLabelPosition,
+    leader_style: Option<ShapeStyle>,
+    explode: Vec<f64>,
+    center_label: Option<(String, TextStyle<'a>)>,
 }
 
 impl<'a, Label: Display> Pie<'a, (i32, i32), Label> {
@@ -64,6 +79,11 @@ impl<'a, Label: Display> Pie<'a, (i32, i32), Label> {
             label_offset: radius_5pct,
             percentage_style: None,
             donut_hole: 0.0,
+            slice_styles: Vec::new(),
+            label_position: PieLabelPosition::Outside,
+            leader_style: None,
+            explode: Vec::new(),
+            center_label: None,
         }
     }
 
@@ -94,6 +114,65 @@ impl<'a, Label: Display> Pie<'a, (i32, i32), Label> {
         self.percentage_style = Some(label_style.into());
     }
 
+    /// Set the styles of the wedges, which take over the colors.
+    ///
+    /// The wedges without a style are filled with their colors, and the wedges of which the style
+    /// isn't filled are outlined.
+    /// ```
+    /// use plotters::prelude::*;
+    /// let mut pie = Pie::new(&(50,50), &10.0, &[50.0, 50.0], &[RED, BLUE], &["Red", "Blue"]);
+    /// pie.slice_styles([RED.filled(), BLUE.stroke_width(2)]);
+    /// ```
+    pub fn slice_styles<S: Into<ShapeStyle>, I: IntoIterator<Item = S>>(&mut self, styles: I) {
+        self.slice_styles = styles.into_iter().map(Into::into).collect();
+    }
+
+    /// Set where the labels are placed, outside of the pie by default.
+    ///
+    /// The inner labels are centered in the wedges, and the percentages go right below them.
+    pub fn label_position(&mut self, position: PieLabelPosition) {
+        self.label_position = position;
+    }
+
+    /// Enables drawing the leader lines from the wedges to their outer labels, with the given style.
+    ///
+    /// A leader line goes out from the middle of the wedge's arc by the label offset, then bends
+    /// horizontally towards the label, which is vertically centered at the end of the line.
+    /// ```
+    /// use plotters::prelude::*;
+    /// let mut pie = Pie::new(&(50,50), &10.0, &[50.0, 50.0], &[RED, BLUE], &["Red", "Blue"]);
+    /// pie.label_offset(10.0);
+    /// pie.leader_lines(BLACK);
+    /// ```
+    pub fn leader_lines<S: Into<ShapeStyle>>(&mut self, style: S) {
+        self.leader_style = Some(style.into());
+    }
+
+    /// Pushes the wedges out of the pie by the given offsets in pixels, in the order of the wedges.
+    ///
+    /// The labels of an exploded wedge move along with it, and the wedges without an offset stay
+    /// in place.
+    /// ```
+    /// use plotters::prelude::*;
+    /// let mut pie = Pie::new(&(50,50), &10.0, &[50.0, 25.0, 25.0], &[RED, BLUE, GREEN], &["Red", "Blue", "Green"]);
+    /// pie.explode([0.0, 3.0]); // only the blue wedge is pushed out
+    /// ```
+    pub fn explode<I: IntoIterator<Item = f64>>(&mut self, offsets: I) {
+        self.explode = offsets.into_iter().collect();
+    }
+
+    /// Draws a text at the center of the pie with the given style, such as the total of a donut
+    /// chart.
+    /// ```
+    /// use plotters::prelude::*;
+    /// let mut pie = Pie::new(&(50,50), &10.0, &[50.0, 50.0], &[RED, BLUE], &["Red", "Blue"]);
+    /// pie.donut_hole(6.0);
+    /// pie.center_label("Total: 100", ("sans-serif", 4));
+    /// ```
+    pub fn center_label<T: Into<String>, S: Into<TextStyle<'a>>>(&mut self, text: T, style: S) {
+        self.center_label = Some((text.into(), style.into()));
+    }
+
     /// Enables creating a donut chart with a hole of the specified radius.
     ///
     /// The passed value must be greater than zero and lower than the chart overall radius, otherwise it'll be ignored.
@@ -107,10 +186,12 @@ impl<'a, Label: Display> Pie<'a, (i32, i32), Label> {
 impl<'a, DB: DrawingBackend, Label: Display> Drawable<DB> for Pie<'a, (i32, i32), Label> {
     fn draw<I: Iterator<Item = BackendCoord>>(
         &self,
-        _pos: I,
+        mut pos: I,
         backend: &mut DB,
         _parent_dim: (u32, u32),
     ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        // the center translated into the backend, so the pie is placed relative to its area
+        let pie_center = pos.next().unwrap_or(*self.center);
         let mut offset_theta = self.start_radian;
 
         // const reused for every radian calculation
@@ -119,27 +200,37 @@ impl<'a, DB: DrawingBackend, Label: Display> Drawable<DB> for Pie<'a, (i32, i32)
         // this all could be avoided if backend could draw a curve/bezier line as part of a polygon.
         let radian_increment = PI / 180.0 / self.radius.sqrt() * 2.0;
         let mut perc_labels = Vec::new();
+        let mut inner_labels = Vec::new();
         for (index, slice) in self.sizes.iter().enumerate() {
-            let slice_style = self
-                .colors
-                .get(index)
-                .ok_or_else(|| DrawingErrorKind::FontError(Box::new(PieError::LengthMismatch)))?;
+            let slice_style = match self.slice_styles.get(index) {
+                Some(style) => *style,
+                None => self
+                    .colors
+                    .get(index)
+                    .ok_or_else(|| DrawingErrorKind::FontError(Box::new(PieError::LengthMismatch)))?
+                    .filled(),
+            };
             let label = self
                 .labels
                 .get(index)
                 .ok_or_else(|| DrawingErrorKind::FontError(Box::new(PieError::LengthMismatch)))?;
-            // start building wedge line against the previous edge
-            let mut points = if self.donut_hole == 0.0 {
-                vec![*self.center]
-            } else {
-                vec![]
-            };
             let ratio = slice / self.total;
             let theta_final = ratio * 2.0 * PI + offset_theta; // end radian for the wedge
 
             // calculate middle for labels before mutating offset
             let middle_theta = ratio * PI + offset_theta;
 
+            // an exploded wedge, along with its labels, is pushed out along its middle
+            let explode = self.explode.get(index).copied().unwrap_or(0.0);
+            let center = theta_to_ordinal_coord(explode, middle_theta, &pie_center);
+
+            // start building wedge line against the previous edge
+            let mut points = if self.donut_hole == 0.0 {
+                vec![center]
+            } else {
+                vec![]
+            };
+
             let slice_start = offset_theta;
 
             // calculate every fraction of radian for the wedge, offsetting for every iteration, clockwise
@@ -148,23 +239,23 @@ impl<'a, DB: DrawingBackend, Label: Display> Drawable<DB> for Pie<'a, (i32, i32)
             // but f64 doesn't implement the Range trait, and it would requires the Step trait (increment by 1.0 or 0.0001?)
             // which is unstable therefore cannot be implemented outside of std, even as a newtype for radians.
             while offset_theta <= theta_final {
-                let coord = theta_to_ordinal_coord(*self.radius, offset_theta, self.center);
+                let coord = theta_to_ordinal_coord(*self.radius, offset_theta, &center);
                 points.push(coord);
                 offset_theta += radian_increment;
             }
             // final point of the wedge may not fall exactly on a radian, so add it extra
-            let final_coord = theta_to_ordinal_coord(*self.radius, theta_final, self.center);
+            let final_coord = theta_to_ordinal_coord(*self.radius, theta_final, &center);
             points.push(final_coord);
 
             if self.donut_hole > 0.0 {
                 while offset_theta >= slice_start {
-                    let coord = theta_to_ordinal_coord(self.donut_hole, offset_theta, self.center);
+                    let coord = theta_to_ordinal_coord(self.donut_hole, offset_theta, &center);
                     points.push(coord);
                     offset_theta -= radian_increment;
                 }
                 // final point of the wedge may not fall exactly on a radian, so add it extra
                 let final_coord_inner =
-                    theta_to_ordinal_coord(self.donut_hole, slice_start, self.center);
+                    theta_to_ordinal_coord(self.donut_hole, slice_start, &center);
                 points.push(final_coord_inner);
             }
 
@@ -174,21 +265,71 @@ impl<'a, DB: DrawingBackend, Label: Display> Drawable<DB> for Pie<'a, (i32, i32)
             // draw wedge
             // TODO: Currently the backend doesn't have API to draw an arc. We need add that in the
             // future
-            backend.fill_polygon(points, slice_style)?;
+            if slice_style.filled {
+                backend.fill_polygon(points, &slice_style)?;
+            } else {
+                let first = points[0];
+                points.push(first);
+                backend.draw_path(points, &slice_style)?;
+            }
+
+            let inner_coord = theta_to_ordinal_coord(
+                (self.radius + self.donut_hole) / 2.0,
+                middle_theta,
+                &center,
+            );
+            match (self.label_position, &self.leader_style) {
+                (PieLabelPosition::Inside, _) => {
+                    // drawn on top of all the wedges, like the percentages
+                    inner_labels.push((label.to_string(), inner_coord));
+                }
+                (PieLabelPosition::Outside, Some(leader_style)) => {
+                    let edge = theta_to_ordinal_coord(*self.radius, middle_theta, &center);
+                    let bend = theta_to_ordinal_coord(
+                        self.radius + self.label_offset,
+                        middle_theta,
+                        &center,
+                    );
+                    // the bend goes towards the side of the pie the label is on
+                    let (direction, hpos) = if bend.0 <= center.0 {
+                        (-1, HPos::Right)
+                    } else {
+                        (1, HPos::Left)
+                    };
+                    let elbow_length = (self.label_offset / 2.0).max(4.0).round() as i32;
+                    let elbow = (bend.0 + direction * elbow_length, bend.1);
+                    backend.draw_path(vec![edge, bend, elbow], leader_style)?;
 
-            // label coords from the middle
-            let mut mid_coord =
-                theta_to_ordinal_coord(self.radius + self.label_offset, middle_theta, self.center);
+                    let style = self.label_style.pos(Pos::new(hpos, VPos::Center));
+                    let label_coord = (elbow.0 + direction * 2, elbow.1);
+                    backend.draw_text(&label.to_string(), &style, label_coord)?;
+                }
+                (PieLabelPosition::Outside, None) => {
+                    // label coords from the middle
+                    let mut mid_coord = theta_to_ordinal_coord(
+                        self.radius + self.label_offset,
+                        middle_theta,
+                        &center,
+                    );
 
-            // ensure label's doesn't fall in the circle
-            let label_size = backend.estimate_text_size(&label.to_string(), &self.label_style)?;
-            // if on the left hand side of the pie, offset whole label to the left
-            if mid_coord.0 <= self.center.0 {
-                mid_coord.0 -= label_size.0 as i32;
+                    // ensure label's doesn't fall in the circle
+                    let label_size =
+                        backend.estimate_text_size(&label.to_string(), &self.label_style)?;
+                    // if on the left hand side of the pie, offset whole label to the left
+                    if mid_coord.0 <= center.0 {
+                        mid_coord.0 -= label_size.0 as i32;
+                    }
+                    // put label
+                    backend.draw_text(&label.to_string(), &self.label_style, mid_coord)?;
+                }
             }
-            // put label
-            backend.draw_text(&label.to_string(), &self.label_style, mid_coord)?;
             if let Some(percentage_style) = &self.percentage_style {
+                if self.label_position == PieLabelPosition::Inside {
+                    // right below the inner label of the wedge
+                    let style = percentage_style.pos(Pos::new(HPos::Center, VPos::Top));
+                    perc_labels.push((format!("{:.1}%", (ratio * 100.0)), inner_coord, style));
+                    continue;
+                }
                 let perc_label = format!("{:.1}%", (ratio * 100.0));
                 let label_size = backend.estimate_text_size(&perc_label, percentage_style)?;
                 let text_x_mid = (label_size.0 as f64 / 2.0).round() as i32;
@@ -197,17 +338,29 @@ impl<'a, DB: DrawingBackend, Label: Display> Drawable<DB> for Pie<'a, (i32, i32)
                 let perc_coord = theta_to_ordinal_coord(
                     perc_radius,
                     middle_theta,
-                    &(self.center.0 - text_x_mid, self.center.1 - text_y_mid),
+                    &(center.0 - text_x_mid, center.1 - text_y_mid),
                 );
                 // perc_coord.0 -= middle_label_size.0.round() as i32;
-                perc_labels.push((perc_label, perc_coord));
+                perc_labels.push((perc_label, perc_coord, percentage_style.clone()));
             }
         }
         // while percentages are generated during the first main iterations,
         // they have to go on top of the already drawn wedges, so require a new iteration.
-        for (label, coord) in perc_labels {
-            let style = self.percentage_style.as_ref().unwrap();
-            backend.draw_text(&label, style, coord)?;
+        let inner_vpos = if self.percentage_style.is_some() {
+            VPos::Bottom
+        } else {
+            VPos::Center
+        };
+        let inner_style = self.label_style.pos(Pos::new(HPos::Center, inner_vpos));
+        for (label, coord) in inner_labels {
+            backend.draw_text(&label, &inner_style, coord)?;
+        }
+        for (label, coord, style) in perc_labels {
+            backend.draw_text(&label, &style, coord)?;
+        }
+        if let Some((text, style)) = &self.center_label {
+            let style = style.pos(Pos::new(HPos::Center, VPos::Center));
+            backend.draw_text(text, &style, pie_center)?;
         }
         Ok(())
     }
@@ -237,7 +390,7 @@ fn theta_to_ordinal_coord(radius: f64, theta: f64, ordinal_offset: &(i32, i32))
 #[cfg(test)]
 mod test {
     use super::*;
-    // use crate::prelude::*;
+    use crate::style::{BLUE, RED};
 
     #[test]
     fn polar_coord_to_cartestian_coord() {
@@ -264,4 +417,75 @@ mod test {
         assert!(labels.first().is_none());
         assert_eq!(radius, 801.0);
     }
+
+    #[test]
+    fn pie_label_placements() {
+        let (center, radius) = ((100, 100), 50.0);
+        let sizes = [1.0, 3.0];
+        let colors = [RED, BLUE];
+        let labels = ["a", "b"];
+
+        let area = crate::create_mocked_drawing_area(200, 200, |m| {
+            m.drop_check(|b| {
+                assert_eq!(b.num_fill_polygon_call, 1);
+                assert_eq!(b.num_draw_path_call, 3);
+                assert_eq!(b.num_draw_text_call, 2);
+            });
+        });
+        let mut pie = Pie::new(&center, &radius, &sizes, &colors, &labels);
+        pie.slice_styles([RED.filled(), BLUE.stroke_width(2)]);
+        pie.leader_lines(BLACK);
+        area.draw(&pie).unwrap();
+
+        let area = crate::create_mocked_drawing_area(200, 200, |m| {
+            m.check_draw_text(|_, _, _, pos, text| {
+                assert!(["a", "b", "25.0%", "75.0%"].contains(&text));
+                let distance = f64::from(pos.0 - 100).hypot(f64::from(pos.1 - 100));
+                assert!((distance - 25.0).abs() <= 1.0);
+            });
+            m.drop_check(|b| {
+                assert_eq!(b.num_fill_polygon_call, 2);
+                assert_eq!(b.num_draw_text_call, 4);
+            });
+        });
+        let mut pie = Pie::new(&center, &radius, &sizes, &colors, &labels);
+        pie.label_position(PieLabelPosition::Inside);
+        pie.percentages(("sans-serif", 10));
+        area.draw(&pie).unwrap();
+    }
+
+    #[test]
+    fn donut_with_explode_and_center_label() {
+        let (center, radius) = ((100, 100), 50.0);
+        let sizes = [1.0, 1.0];
+        let colors = [RED, BLUE];
+        let labels = ["a", "b"];
+
+        let area = crate::create_mocked_drawing_area(200, 200, |m| {
+            // the first wedge is the right half, pushed out to the right by 10 pixels
+            m.check_fill_polygon(|_, points| {
+                assert!(points.iter().all(|p| p.0 >= 110));
+                assert!(points.iter().any(|p| p.0 == 160));
+            });
+            m.check_fill_polygon(|_, points| {
+                assert!(points.iter().all(|p| p.0 <= 100));
+            });
+            m.check_draw_text(|_, _, _, _, _| {});
+            m.check_draw_text(|_, _, _, _, _| {});
+            m.check_draw_text(|_, _, _, pos, text| {
+                assert_eq!(text, "Total");
+                assert_eq!(pos, (100, 100));
+            });
+            m.drop_check(|b| {
+                assert_eq!(b.num_fill_polygon_call, 2);
+                assert_eq!(b.num_draw_text_call, 3);
+            });
+        });
+        let mut pie = Pie::new(&center, &radius, &sizes, &colors, &labels);
+        pie.start_angle(-90.0);
+        pie.donut_hole(20.0);
+        pie.explode([10.0]);
+        pie.center_label("Total", ("sans-serif", 10));
+        area.draw(&pie).unwrap();
+    }
 }
@@ -0,0 +1,495 @@
+use crate::{
+    element::{Drawable, PointCollection},
+    style::text_anchor::{HPos, Pos, VPos},
+    style::{Color, IntoFont, Palette, Palette99, RGBAColor, TextStyle, BLACK},
+};
+use plotters_backend::{BackendCoord, DrawingBackend, DrawingErrorKind};
+use std::cmp::Ordering;
+
+/// The number of the segments of the curves of the ribbons
+const RIBBON_SEGMENTS: usize = 32;
+
+/// The gap between the nodes and their labels in pixels
+const LABEL_GAP: i32 = 6;
+
+/// The maximum number of the bands of the colors of a gradient link
+const GRADIENT_BANDS: i32 = 32;
+
+/// The coloring of the ribbons of the links of a [Sankey]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SankeyLinkColor {
+    /// The ribbons have the colors of their source nodes
+    Source,
+    /// The ribbons have the colors of their target nodes
+    Target,
+    /// The ribbons fade from the colors of their source nodes to the colors of their target nodes
+    Gradient,
+}
+
+/// The positions of the nodes and the links of a [Sankey], in pixels relative to its area
+#[derive(Clone, Debug, PartialEq)]
+pub struct SankeyLayout {
+    /// The upper left and the lower right corners of the nodes, by the node index
+    pub nodes: Vec<[(f64, f64); 2]>,
+    /// The layer of the nodes from left to right, by the node index
+    pub layers: Vec<usize>,
+    /// The outlines of the ribbons by the link index, which are the upper edges from the source
+    /// to the target followed by the lower edges back to the source. The outlines of the ignored
+    /// links are empty.
+    pub links: Vec<Vec<(f64, f64)>>,
+}
+
+/**
+A Sankey diagram, which presents the weighted flows between the nodes as the ribbons with the
+widths proportional to the weights.
+
+The nodes are placed in the layers from left to right, so that each link goes from a layer to a
+later one: a node is one layer after the latest source of its incoming links, and the nodes
+without an outgoing link are moved to the last layer. The nodes of a layer are stacked in the
+order of their indices and centered vertically, and the height of a node is the larger of the
+total weights of its incoming and its outgoing links. The ribbons are horizontal at both ends and
+stacked on the nodes in the order of the positions of the other ends, so they cross as little as
+the order of the nodes allows.
+
+The diagram fills the drawing area it's drawn on, and the labels of the nodes are drawn on the
+right of the nodes, except that the labels of the last layer are drawn on the left.
+
+# Examples
+
+```
+use plotters::prelude::*;
+let drawing_area = SVGBackend::new("sankey.svg", (400, 300)).into_drawing_area();
+drawing_area.fill(&WHITE).unwrap();
+let sankey = Sankey::new(
+    ["Coal", "Gas", "Solar", "Power", "Homes", "Industry", "Losses"],
+    [(0, 3, 30.0), (1, 3, 20.0), (2, 3, 10.0), (3, 4, 25.0), (3, 5, 20.0), (3, 6, 15.0)],
+)
+.node_width(12)
+.link_color(SankeyLinkColor::Gradient);
+drawing_area.margin(10, 10, 10, 10).draw(&sankey).unwrap();
+```
+*/
+pub struct Sankey<'a> {
+    origin: (i32, i32),
+    labels: Vec<String>,
+    links: Vec<(usize, usize, f64)>,
+    node_colors: Vec<RGBAColor>,
+    node_width: u32,
+    node_padding: u32,
+    link_color: SankeyLinkColor,
+    link_opacity: f64,
+    label_style: Option<TextStyle<'a>>,
+}
+
+impl<'a> Sankey<'a> {
+    /// Create a new Sankey diagram
+    ///
+    /// - `nodes`: The labels of the nodes
+    /// - `links`: The links as the `(source, target, weight)` triples, where the source and the
+    ///   target are the indices of the nodes. The links with an invalid node index, a non-positive
+    ///   weight, or which form a cycle are ignored.
+    /// - **returns** The newly created diagram
+    pub fn new<L, N, E>(nodes: N, links: E) -> Self
+    where
+        L: Into<String>,
+        N: IntoIterator<Item = L>,
+        E: IntoIterator<Item = (usize, usize, f64)>,
+    {
+        Self {
+            origin: (0, 0),
+            labels: nodes.into_iter().map(Into::into).collect(),
+            links: links.into_iter().collect(),
+            node_colors: vec![],
+            node_width: 16,
+            node_padding: 10,
+            link_color: SankeyLinkColor::Source,
+            link_opacity: 0.5,
+            label_style: Some(("sans-serif", 12).into_font().color(&BLACK)),
+        }
+    }
+
+    /// Set the width of the nodes in pixels, which is 16 by default
+    pub fn node_width(mut self, width: u32) -> Self {
+        self.node_width = width;
+        self
+    }
+
+    /// Set the vertical gap between the nodes of a layer in pixels, which is 10 by default
+    pub fn node_padding(mut self, padding: u32) -> Self {
+        self.node_padding = padding;
+        self
+    }
+
+    /// Set the colors of the nodes, the nodes without a color use the colors of [`Palette99`]
+    pub fn node_colors<C: Color, I: IntoIterator<Item = C>>(mut self, colors: I) -> Self {
+        self.node_colors = colors.into_iter().map(|c| c.to_rgba()).collect();
+        self
+    }
+
+    /// Set how the ribbons of the links are colored, which is [`SankeyLinkColor::Source`] by
+    /// default
+    pub fn link_color(mut self, link_color: SankeyLinkColor) -> Self {
+        self.link_color = link_color;
+        self
+    }
+
+    /// Set the opacity of the ribbons of the links, which is 0.5 by default
+    pub fn link_opacity(mut self, opacity: f64) -> Self {
+        self.link_opacity = opacity;
+        self
+    }
+
+    /// Set the style of the labels of the nodes, `None` hides the labels
+    pub fn label_style<S: Into<TextStyle<'a>>>(mut self, style: Option<S>) -> Self {
+        self.label_style = style.map(Into::into);
+        self
+    }
+
+    fn node_color(&self, node: usize) -> RGBAColor {
+        self.node_colors
+            .get(node)
+            .copied()
+            .unwrap_or_else(|| Palette99::pick(node).to_rgba())
+    }
+
+    /// Flag the links which are drawn, i.e. the valid links except the ones closing a cycle
+    fn drawn_links(&self) -> Vec<bool> {
+        let n = self.labels.len();
+        let mut drawn: Vec<bool> = self
+            .links
+            .iter()
+            .map(|&(source, target, weight)| {
+                source < n && target < n && source != target && weight.is_finite() && weight > 0.0
+            })
+            .collect();
+        // Drop the links going back to a node on the stack of a depth-first search
+        fn visit(node: usize, sankey: &Sankey, drawn: &mut [bool], state: &mut [u8]) {
+            state[node] = 1;
+            for (index, &(source, target, _)) in sankey.links.iter().enumerate() {
+                if source != node || !drawn[index] {
+                    continue;
+                }
+                match state[target] {
+                    0 => visit(target, sankey, drawn, state),
+                    1 => drawn[index] = false,
+                    _ => {}
+                }
+            }
+            state[node] = 2;
+        }
+        let mut state = vec![0; n];
+        for node in 0..n {
+            if state[node] == 0 {
+                visit(node, self, &mut drawn, &mut state);
+            }
+        }
+        drawn
+    }
+
+    /// Compute the layer of each node with the longest paths from the sources
+    fn layers(&self, drawn: &[bool]) -> Vec<usize> {
+        let n = self.labels.len();
+        let links: Vec<_> = self
+            .links
+            .iter()
+            .zip(drawn)
+            .filter(|(_, drawn)| **drawn)
+            .map(|(link, _)| *link)
+            .collect();
+        let mut layers = vec![0; n];
+        for _ in 0..n {
+            let mut changed = false;
+            for &(source, target, _) in &links {
+                if layers[target] <= layers[source] {
+                    layers[target] = layers[source] + 1;
+                    changed = true;
+                }
+            }
+            if !changed {
+                break;
+            }
+        }
+        let last = layers.iter().copied().max().unwrap_or(0);
+        for (node, layer) in layers.iter_mut().enumerate() {
+            let has_incoming = links.iter().any(|l| l.1 == node);
+            let has_outgoing = links.iter().any(|l| l.0 == node);
+            if has_incoming && !has_outgoing {
+                *layer = last;
+            }
+        }
+        layers
+    }
+
+    /**
+    Compute the positions of the nodes and the ribbons of the links.
+
+    - `(width, height)`: The size of the area of the diagram in pixels
+    - **returns** The layout of the diagram
+    */
+    pub fn layout(&self, (width, height): (u32, u32)) -> SankeyLayout {
+        let (width, height) = (f64::from(width), f64::from(height));
+        let n = self.labels.len();
+        let drawn = self.drawn_links();
+        let layers = self.layers(&drawn);
+
+        let (mut incoming, mut outgoing) = (vec![0.0; n], vec![0.0; n]);
+        for (&(source, target, weight), _) in self.links.iter().zip(&drawn).filter(|(_, d)| **d) {
+            outgoing[source] += weight;
+            incoming[target] += weight;
+        }
+        let values: Vec<f64> = incoming
+            .iter()
+            .zip(&outgoing)
+            .map(|(i, o): (&f64, &f64)| i.max(*o))
+            .collect();
+
+        let columns = layers.iter().copied().max().map_or(0, |l| l + 1);
+        let padding = f64::from(self.node_padding);
+        let column_nodes: Vec<Vec<usize>> = (0..columns)
+            .map(|c| (0..n).filter(|node| layers[*node] == c).collect())
+            .collect();
+        let scale = column_nodes
+            .iter()
+            .filter_map(|nodes| {
+                let total: f64 = nodes.iter().map(|node| values[*node]).sum();
+                let space = height - padding * nodes.len().saturating_sub(1) as f64;
+                (total > 0.0).then(|| space.max(0.0) / total)
+            })
+            .fold(f64::INFINITY, f64::min);
+        let scale = if scale.is_finite() { scale } else { 0.0 };
+
+        let node_width = f64::from(self.node_width);
+        let mut nodes = vec![[(0.0, 0.0); 2]; n];
+        for (column, members) in column_nodes.iter().enumerate() {
+            let x = if columns > 1 {
+                column as f64 * (width - node_width) / (columns - 1) as f64
+            } else {
+                0.0
+            };
+            let total: f64 = members
+                .iter()
+                .map(|node| values[*node] * scale)
+                .sum::<f64>()
+                + padding * members.len().saturating_sub(1) as f64;
+            let mut y = (height - total) / 2.0;
+            for &node in members {
+                let bottom = y + values[node] * scale;
+                nodes[node] = [(x, y), (x + node_width, bottom)];
+                y = bottom + padding;
+            }
+        }
+
+        // Stack the ends of the ribbons on the nodes in the order of the positions of the other ends
+        let center = |node: usize| (nodes[node][0].1 + nodes[node][1].1) / 2.0;
+        let mut ends = vec![(0.0, 0.0); self.links.len()];
+        let mut order: Vec<usize> = (0..self.links.len()).filter(|l| drawn[*l]).collect();
+        let by_center =
+            |a: usize, b: usize| center(a).partial_cmp(&center(b)).unwrap_or(Ordering::Equal);
+        order.sort_by(|a, b| by_center(self.links[*a].1, self.links[*b].1));
+        let mut offsets: Vec<f64> = nodes.iter().map(|node| node[0].1).collect();
+        for &link in &order {
+            let (source, _, weight) = self.links[link];
+            ends[link].0 = offsets[source];
+            offsets[source] += weight * scale;
+        }
+        order.sort_by(|a, b| by_center(self.links[*a].0, self.links[*b].0));
+        let mut offsets: Vec<f64> = nodes.iter().map(|node| node[0].1).collect();
+        for &link in &order {
+            let (_, target, weight) = self.links[link];
+            ends[link].1 = offsets[target];
+            offsets[target] += weight * scale;
+        }
+
+        let links = self
+            .links
+            .iter()
+            .zip(&ends)
+            .zip(&drawn)
+            .map(|((link, &(source_y, target_y)), drawn)| {
+                if !drawn {
+                    return vec![];
+                }
+                let (source, target, weight) = *link;
+                let (x0, x1) = (nodes[source][1].0, nodes[target][0].0);
+                let (thickness, mid) = (weight * scale, (x0 + x1) / 2.0);
+                // The cubic Bezier curves with the horizontal tangents at both ends
+                let edge = |t: f64, y0: f64, y1: f64| {
+                    let u = 1.0 - t;
+                    let x = x0 * u * u * u + 3.0 * mid * u * t + x1 * t * t * t;
+                    (x, y0 + (y1 - y0) * t * t * (3.0 - 2.0 * t))
+                };
+                let steps = (0..=RIBBON_SEGMENTS).map(|k| k as f64 / RIBBON_SEGMENTS as f64);
+                steps
+                    .clone()
+                    .map(|t| edge(t, source_y, target_y))
+                    .chain(
+                        steps
+                            .rev()
+                            .map(|t| edge(t, source_y + thickness, target_y + thickness)),
+                    )
+                    .collect()
+            })
+            .collect();
+
+        SankeyLayout {
+            nodes,
+            layers,
+            links,
+        }
+    }
+}
+
+impl<'a, 'b> PointCollection<'a, (i32, i32)> for &'a Sankey<'b> {
+    type Point = &'a (i32, i32);
+    type IntoIter = std::iter::Once<&'a (i32, i32)>;
+    fn point_iter(self) -> std::iter::Once<&'a (i32, i32)> {
+        std::iter::once(&self.origin)
+    }
+}
+
+impl<'a, DB: DrawingBackend> Drawable<DB> for Sankey<'a> {
+    fn draw<I: Iterator<Item = BackendCoord>>(
+        &self,
+        mut pos: I,
+        backend: &mut DB,
+        parent_dim: (u32, u32),
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let origin = pos.next().unwrap_or(self.origin);
+        let layout = self.layout(parent_dim);
+        let to_coord =
+            |(x, y): (f64, f64)| (origin.0 + x.round() as i32, origin.1 + y.round() as i32);
+
+        for (&(source, target, _), outline) in self.links.iter().zip(&layout.links) {
+            if outline.is_empty() {
+                continue;
+            }
+            let (from, to) = (self.node_color(source), self.node_color(target));
+            let color = match self.link_color {
+                SankeyLinkColor::Source => from,
+                SankeyLinkColor::Target => to,
+                SankeyLinkColor::Gradient => {
+                    // Fill the ribbon in the bands of the quantized colors, which end one pixel
+                    // before the next band, so the translucent bands don't overlap
+                    let count = outline.len() / 2;
+                    let top = &outline[..count];
+                    let bottom = |k: usize| outline[outline.len() - 1 - k].1;
+                    let edges_at = |x: f64| {
+                        let k = top.partition_point(|p| p.0 <= x).max(1).min(count - 1);
+                        let span = top[k].0 - top[k - 1].0;
+                        let r = if span > 0.0 {
+                            (x - top[k - 1].0) / span
+                        } else {
+                            0.0
+                        };
+                        (
+                            top[k - 1].1 + (top[k].1 - top[k - 1].1) * r,
+                            bottom(k - 1) + (bottom(k) - bottom(k - 1)) * r,
+                        )
+                    };
+                    let (left, right) = (top[0].0.round() as i32, top[count - 1].0.round() as i32);
+                    let bands = (right - left).min(GRADIENT_BANDS);
+                    for band in 0..bands {
+                        let x0 = f64::from(left + (right - left) * band / bands);
+                        let x1 = f64::from(left + (right - left) * (band + 1) / bands - 1);
+                        let (upper0, lower0) = edges_at(x0);
+                        let (upper1, lower1) = edges_at(x1);
+                        let inner = || (0..count).filter(|k| x0 < top[*k].0 && top[*k].0 < x1);
+                        let mut points = vec![(x0, upper0)];
+                        points.extend(inner().map(|k| top[k]));
+                        points.push((x1, upper1));
+                        points.push((x1, lower1));
+                        points.extend(inner().rev().map(|k| (top[k].0, bottom(k))));
+                        points.push((x0, lower0));
+                        let t = (f64::from(band) + 0.5) / f64::from(bands);
+                        backend.fill_polygon(
+                            points.into_iter().map(to_coord),
+                            &from.blend(&to, t).mix(self.link_opacity),
+                        )?;
+                    }
+                    continue;
+                }
+            };
+            backend.fill_polygon(
+                outline.iter().copied().map(to_coord),
+                &color.mix(self.link_opacity),
+            )?;
+        }
+
+        let last = layout.layers.iter().copied().max().unwrap_or(0);
+        for (node, corners) in layout.nodes.iter().enumerate() {
+            let (upper_left, lower_right) = (to_coord(corners[0]), to_coord(corners[1]));
+            backend.draw_rect(upper_left, lower_right, &self.node_color(node), true)?;
+            if let Some(style) = &self.label_style {
+                let y = (upper_left.1 + lower_right.1) / 2;
+                let (x, hpos) = if layout.layers[node] < last || last == 0 {
+                    (lower_right.0 + LABEL_GAP, HPos::Left)
+                } else {
+                    (upper_left.0 - LABEL_GAP, HPos::Right)
+                };
+                let style = style.pos(Pos::new(hpos, VPos::Center));
+                backend.draw_text(&self.labels[node], &style, (x, y))?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_sankey_layout() {
+        let sankey = Sankey::new(
+            ["a", "b", "c", "d"],
+            [
+                (0, 1, 2.0),
+                (1, 2, 1.0),
+                (0, 3, 2.0),
+                (2, 0, 1.0),
+                (1, 7, 1.0),
+                (3, 3, 1.0),
+            ],
+        )
+        .node_width(10)
+        .node_padding(20);
+        let layout = sankey.layout((110, 100));
+        assert_eq!(layout.layers, [0, 1, 2, 2]);
+        assert_eq!(layout.nodes[0], [(0.0, 0.0), (10.0, 100.0)]);
+        assert_eq!(layout.nodes[1], [(50.0, 25.0), (60.0, 75.0)]);
+        assert_eq!(layout.nodes[2], [(100.0, 2.5), (110.0, 27.5)]);
+        assert_eq!(layout.nodes[3], [(100.0, 47.5), (110.0, 97.5)]);
+        // The cycle and the invalid links are dropped
+        assert!(layout.links[3..].iter().all(Vec::is_empty));
+
+        let ribbon = &layout.links[0];
+        assert_eq!(ribbon.len(), 2 * (RIBBON_SEGMENTS + 1));
+        assert_eq!(ribbon[0], (10.0, 0.0));
+        assert_eq!(ribbon[RIBBON_SEGMENTS], (50.0, 25.0));
+        assert_eq!(ribbon[ribbon.len() - 1], (10.0, 50.0));
+    }
+
+    #[test]
+    fn test_draw_sankey() {
+        let drawing_area = create_mocked_drawing_area(100, 100, |m| {
+            m.check_fill_polygon(|color, _| assert_eq!(color.3, 0.5));
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_rect_call, 5);
+                assert_eq!(b.num_fill_polygon_call, 2 + GRADIENT_BANDS as u32);
+                assert_eq!(b.num_draw_line_call, 0);
+                assert_eq!(b.num_draw_text_call, 3);
+            });
+        });
+        let sankey = Sankey::new(["a", "b", "c"], [(0, 1, 1.0), (0, 2, 2.0)]);
+        drawing_area.draw(&sankey).unwrap();
+        drawing_area
+            .draw(
+                &Sankey::new(["a", "b"], [(0, 1, 1.0)])
+                    .link_color(SankeyLinkColor::Gradient)
+                    .label_style(None::<TextStyle>)
+                    .node_colors([RED]),
+            )
+            .unwrap();
+    }
+}
@@ -0,0 +1,470 @@
+use crate::{
+    element::{Drawable, HierarchyNode, PointCollection},
+    style::text_anchor::{HPos, Pos, VPos},
+    style::{Color, FontTransform, IntoFont, ShapeStyle, TextStyle, BLACK, WHITE},
+};
+use plotters_backend::{BackendCoord, DrawingBackend, DrawingErrorKind};
+use std::f64::consts::PI;
+
+use super::hierarchy::find_node;
+
+/// The smallest font size of the labels shrunk to fit their sectors
+const MIN_LABEL_SIZE: f64 = 6.0;
+
+/// How the labels of a [Sunburst] are fitted into their sectors
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SunburstLabelFit {
+    /// The labels are straight and perpendicular to the radius, and shrunk to fit the sectors
+    Shrink,
+    /// The labels curve along the middle arcs of the sectors, and are shrunk if they are still
+    /// too long
+    Curve,
+}
+
+/// A sector of the layout of a [Sunburst], in pixels relative to its area
+#[derive(Clone, Debug, PartialEq)]
+pub struct SunburstSector {
+    /// The start and the end angles of the sector in radians, clockwise from the top
+    pub angles: (f64, f64),
+    /// The inner and the outer radii of the sector
+    pub radii: (f64, f64),
+    /// The ring of the sector, which is 0 for the innermost ring
+    pub depth: usize,
+    /// The indices of the node and its ancestors among their siblings, from the root node, e.g.
+    /// `[2, 0]` is the first child of the third root node
+    pub path: Vec<usize>,
+}
+
+/**
+A sunburst chart, which presents the hierarchical weighted data as the concentric rings of the
+annular sectors, with the angles proportional to the weights.
+
+The root nodes are in the innermost ring, and the children of a node are in the next ring, within
+the angles of their parent. The chart can drill down to a node with [`Sunburst::focus()`], so the
+node is in the innermost ring as a full circle, and the depth of the rings can be limited by
+[`Sunburst::max_depth()`]. The colors are inherited like in a [`crate::element::Treemap`], and
+get lighter with the depth.
+
+The chart is centered in the drawing area it's drawn on and fills its shorter side. The labels
+are drawn only where they fit, see [`SunburstLabelFit`].
+
+# Examples
+
+```
+use plotters::prelude::*;
+let drawing_area = SVGBackend::new("sunburst.svg", (300, 300)).into_drawing_area();
+drawing_area.fill(&WHITE).unwrap();
+let sunburst = Sunburst::new([
+    HierarchyNode::group(
+        "Europe",
+        [HierarchyNode::leaf("France", 68.0), HierarchyNode::leaf("Spain", 48.0)],
+    ),
+    HierarchyNode::group(
+        "Asia",
+        [HierarchyNode::leaf("Japan", 125.0), HierarchyNode::leaf("Korea", 52.0)],
+    ),
+])
+.inner_radius(30.0)
+.label_fit(SunburstLabelFit::Curve);
+drawing_area.draw(&sunburst).unwrap();
+```
+*/
+pub struct Sunburst<'a> {
+    origin: (i32, i32),
+    roots: Vec<HierarchyNode>,
+    inner_radius: f64,
+    focus: Vec<usize>,
+    max_depth: Option<usize>,
+    depth_shading: f64,
+    border_style: Option<ShapeStyle>,
+    label_style: Option<TextStyle<'a>>,
+    label_fit: SunburstLabelFit,
+}
+
+impl<'a> Sunburst<'a> {
+    /// Create a new sunburst chart
+    ///
+    /// - `roots`: The root nodes of the hierarchy
+    /// - **returns** The newly created chart
+    pub fn new<I: IntoIterator<Item = HierarchyNode>>(roots: I) -> Self {
+        Self {
+            origin: (0, 0),
+            roots: roots.into_iter().collect(),
+            inner_radius: 0.0,
+            focus: vec![],
+            max_depth: None,
+            depth_shading: 0.2,
+            border_style: Some(WHITE.into()),
+            label_style: Some(("sans-serif", 12).into_font().color(&BLACK)),
+            label_fit: SunburstLabelFit::Shrink,
+        }
+    }
+
+    /// Set the radius of the hole in the middle in pixels, which is 0 by default
+    pub fn inner_radius(mut self, radius: f64) -> Self {
+        self.inner_radius = radius.max(0.0);
+        self
+    }
+
+    /// Drill down to the node with the path of the indices among the siblings from the root node,
+    /// which is drawn in the innermost ring with its descendants around it. An empty path, which
+    /// is the default, shows all the root nodes.
+    pub fn focus<I: IntoIterator<Item = usize>>(mut self, path: I) -> Self {
+        self.focus = path.into_iter().collect();
+        self
+    }
+
+    /// Limit the number of the rings, e.g. 1 draws only the innermost ring, and panics if the
+    /// number is 0
+    pub fn max_depth(mut self, depth: usize) -> Self {
+        assert!(depth > 0, "The max depth of a sunburst must be positive");
+        self.max_depth = Some(depth);
+        self
+    }
+
+    /// Set how much lighter the sectors get with each ring, from 0.0 to 1.0, which is 0.2 by
+    /// default
+    pub fn depth_shading(mut self, shading: f64) -> Self {
+        self.depth_shading = shading;
+        self
+    }
+
+    /// Set the style of the borders of the sectors, `None` removes the borders
+    pub fn border_style<S: Into<ShapeStyle>>(mut self, style: Option<S>) -> Self {
+        self.border_style = style.map(Into::into);
+        self
+    }
+
+    /// Set the style of the labels, `None` hides the labels
+    pub fn label_style<S: Into<TextStyle<'a>>>(mut self, style: Option<S>) -> Self {
+        self.label_style = style.map(Into::into);
+        self
+    }
+
+    /// Set how the labels are fitted into the sectors, which is [`SunburstLabelFit::Shrink`] by
+    /// default
+    pub fn label_fit(mut self, fit: SunburstLabelFit) -> Self {
+        self.label_fit = fit;
+        self
+    }
+
+    /// Get the nodes in the innermost ring with the path of the focused node, if the focus is
+    /// valid
+    fn top_nodes(&self) -> Option<(&[HierarchyNode], Vec<usize>)> {
+        match self.focus.split_last() {
+            None => Some((&self.roots, vec![])),
+            Some((last, parent)) => {
+                let mut siblings = &self.roots[..];
+                for &index in parent {
+                    siblings = siblings.get(index)?.children();
+                }
+                let node = siblings.get(*last)?;
+                Some((std::slice::from_ref(node), self.focus.clone()))
+            }
+        }
+    }
+
+    /**
+    Compute the sectors of the nodes.
+
+    - `(width, height)`: The size of the area of the chart in pixels
+    - **returns** The sectors of the drawn nodes with a positive weight, where each node goes
+      before its children
+    */
+    pub fn layout(&self, (width, height): (u32, u32)) -> Vec<SunburstSector> {
+        let mut sectors = vec![];
+        let (nodes, mut path) = match self.top_nodes() {
+            Some(top) => top,
+            None => return sectors,
+        };
+        // The path of a focused node ends with its own index, which is given to the placement
+        let focused = path.pop();
+        let height_of_tree = nodes.iter().map(tree_height).max().unwrap_or(0);
+        let rings = self
+            .max_depth
+            .map_or(height_of_tree, |max| max.min(height_of_tree));
+        if rings == 0 {
+            return sectors;
+        }
+        let radius = f64::from(width.min(height)) / 2.0;
+        let ring_width = (radius - self.inner_radius).max(0.0) / rings as f64;
+        let mut layout = SectorLayout {
+            ring_width,
+            inner_radius: self.inner_radius,
+            rings,
+            sectors: &mut sectors,
+        };
+        layout.place(nodes, (0.0, 2.0 * PI), 0, &mut path, focused);
+        sectors
+    }
+}
+
+/// The number of the levels of the subtree of the node
+fn tree_height(node: &HierarchyNode) -> usize {
+    1 + node.children().iter().map(tree_height).max().unwrap_or(0)
+}
+
+/// The state of the placement of the sectors
+struct SectorLayout<'b> {
+    ring_width: f64,
+    inner_radius: f64,
+    rings: usize,
+    sectors: &'b mut Vec<SunburstSector>,
+}
+
+impl<'b> SectorLayout<'b> {
+    /// Place the nodes in the ring within the angles, `index` overrides the index of a single
+    /// focused node in the path
+    fn place(
+        &mut self,
+        nodes: &[HierarchyNode],
+        (start, end): (f64, f64),
+        ring: usize,
+        path: &mut Vec<usize>,
+        index: Option<usize>,
+    ) {
+        let total: f64 = nodes.iter().map(HierarchyNode::weight).sum();
+        if total <= 0.0 {
+            return;
+        }
+        let inner = self.inner_radius + self.ring_width * ring as f64;
+        let mut angle = start;
+        for (i, node) in nodes.iter().enumerate() {
+            if node.weight() <= 0.0 {
+                continue;
+            }
+            let span = (end - start) * node.weight() / total;
+            path.push(index.unwrap_or(i));
+            self.sectors.push(SunburstSector {
+                angles: (angle, angle + span),
+                radii: (inner, inner + self.ring_width),
+                depth: ring,
+                path: path.clone(),
+            });
+            if ring + 1 < self.rings {
+                self.place(node.children(), (angle, angle + span), ring + 1, path, None);
+            }
+            path.pop();
+            angle += span;
+        }
+    }
+}
+
+/// Get the point at the angle clockwise from the top and the radius around the center
+fn polar((cx, cy): (f64, f64), angle: f64, radius: f64) -> (f64, f64) {
+    let (sin, cos) = angle.sin_cos();
+    (cx + radius * sin, cy - radius * cos)
+}
+
+/// Get the outline of a sector
+fn sector_outline(center: (f64, f64), sector: &SunburstSector) -> Vec<BackendCoord> {
+    let ((start, end), (inner, outer)) = (sector.angles, sector.radii);
+    // About a degree per step, more precise than the pixels for any reasonable radius
+    let steps = ((end - start).to_degrees().ceil() as usize).max(1);
+    let arc = |radius: f64| {
+        (0..=steps).map(move |k| {
+            polar(
+                center,
+                start + (end - start) * k as f64 / steps as f64,
+                radius,
+            )
+        })
+    };
+    let mut points: Vec<_> = arc(outer).collect();
+    if inner > 0.0 {
+        points.extend(arc(inner).collect::<Vec<_>>().into_iter().rev());
+    } else if end - start < 2.0 * PI - 1e-9 {
+        points.push(center);
+    }
+    points
+        .into_iter()
+        .map(|(x, y)| (x.round() as i32, y.round() as i32))
+        .collect()
+}
+
+impl<'a> Sunburst<'a> {
+    /// Draw the label of a sector, shrinking it if it doesn't fit
+    fn draw_label<DB: DrawingBackend>(
+        &self,
+        backend: &mut DB,
+        center: (f64, f64),
+        sector: &SunburstSector,
+        label: &str,
+        style: &TextStyle,
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let ((start, end), (inner, outer)) = (sector.angles, sector.radii);
+        let (middle, radius) = ((start + end) / 2.0, (inner + outer) / 2.0);
+        let full_disk = inner <= 0.0 && end - start >= 2.0 * PI - 1e-9;
+        // The room along the arc and across the ring
+        let (length, thickness) = if full_disk {
+            (outer * 1.6, outer * 1.6)
+        } else {
+            ((end - start).min(PI) * radius * 0.9, (outer - inner) * 0.8)
+        };
+        let (width, height) = backend.estimate_text_size(label, style)?;
+        let (width, height) = (f64::from(width.max(1)), f64::from(height.max(1)));
+        let size = style.font.get_size();
+        let size = size * (length / width).min(thickness / height).min(1.0);
+        if size < MIN_LABEL_SIZE {
+            return Ok(());
+        }
+        let style = TextStyle {
+            font: style.font.resize(size),
+            ..style.clone()
+        }
+        .pos(Pos::new(HPos::Center, VPos::Center));
+        let to_coord = |(x, y): (f64, f64)| (x.round() as i32, y.round() as i32);
+        if full_disk {
+            return backend.draw_text(label, &style, to_coord(center));
+        }
+        // The labels on the lower half are turned over so they are never upside down
+        let upright = middle.cos() >= 0.0;
+        let rotation = |angle: f64| {
+            let degrees = angle.to_degrees();
+            FontTransform::RotateAngle(if upright { degrees } else { degrees + 180.0 } as f32)
+        };
+        if self.label_fit == SunburstLabelFit::Shrink {
+            let style = style.transform(rotation(middle));
+            return backend.draw_text(label, &style, to_coord(polar(center, middle, radius)));
+        }
+        let mut glyphs = vec![];
+        for c in label.chars() {
+            let c = c.to_string();
+            let (w, _) = backend.estimate_text_size(&c, &style)?;
+            glyphs.push((c, f64::from(w)));
+        }
+        let total: f64 = glyphs.iter().map(|(_, w)| w).sum();
+        let direction = if upright { 1.0 } else { -1.0 };
+        let mut offset = -total / 2.0;
+        for (c, w) in glyphs {
+            let angle = middle + direction * (offset + w / 2.0) / radius;
+            let style = style.transform(rotation(angle));
+            backend.draw_text(&c, &style, to_coord(polar(center, angle, radius)))?;
+            offset += w;
+        }
+        Ok(())
+    }
+}
+
+impl<'a, 'b> PointCollection<'a, (i32, i32)> for &'a Sunburst<'b> {
+    type Point = &'a (i32, i32);
+    type IntoIter = std::iter::Once<&'a (i32, i32)>;
+    fn point_iter(self) -> std::iter::Once<&'a (i32, i32)> {
+        std::iter::once(&self.origin)
+    }
+}
+
+impl<'a, DB: DrawingBackend> Drawable<DB> for Sunburst<'a> {
+    fn draw<I: Iterator<Item = BackendCoord>>(
+        &self,
+        mut pos: I,
+        backend: &mut DB,
+        parent_dim: (u32, u32),
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let origin = pos.next().unwrap_or(self.origin);
+        let center = (
+            f64::from(origin.0) + f64::from(parent_dim.0) / 2.0,
+            f64::from(origin.1) + f64::from(parent_dim.1) / 2.0,
+        );
+        let sectors = self.layout(parent_dim);
+        for sector in &sectors {
+            let (_, color) = find_node(&self.roots, &sector.path);
+            let shading = (sector.depth as f64 * self.depth_shading).clamp(0.0, 1.0);
+            let color = color.blend(&WHITE, shading);
+            let mut outline = sector_outline(center, sector);
+            backend.fill_polygon(outline.iter().copied(), &color)?;
+            if let Some(style) = &self.border_style {
+                outline.push(outline[0]);
+                backend.draw_path(outline, style)?;
+            }
+        }
+        if let Some(style) = &self.label_style {
+            for sector in &sectors {
+                let (node, _) = find_node(&self.roots, &sector.path);
+                if !node.label().is_empty() {
+                    self.draw_label(backend, center, sector, node.label(), style)?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+
+    fn hierarchy() -> Vec<HierarchyNode> {
+        vec![
+            HierarchyNode::group(
+                "a",
+                [
+                    HierarchyNode::leaf("b", 1.0),
+                    HierarchyNode::group("c", [HierarchyNode::leaf("d", 2.0)]),
+                ],
+            ),
+            HierarchyNode::leaf("e", 3.0),
+            HierarchyNode::leaf("f", 0.0),
+        ]
+    }
+
+    #[test]
+    fn test_sunburst_layout() {
+        let sectors = Sunburst::new(hierarchy())
+            .inner_radius(20.0)
+            .layout((200, 100));
+        let paths: Vec<_> = sectors.iter().map(|s| s.path.clone()).collect();
+        assert_eq!(
+            paths,
+            [vec![0], vec![0, 0], vec![0, 1], vec![0, 1, 0], vec![1]]
+        );
+        assert_eq!(sectors[0].angles, (0.0, PI));
+        assert_eq!(sectors[0].radii, (20.0, 30.0));
+        assert_eq!(sectors[2].angles, (PI / 3.0, PI));
+        assert_eq!(sectors[3].radii, (40.0, 50.0));
+        assert_eq!(sectors[4].angles, (PI, 2.0 * PI));
+
+        let sectors = Sunburst::new(hierarchy()).focus([0, 1]).layout((100, 100));
+        assert_eq!(sectors.len(), 2);
+        assert_eq!(sectors[0].path, [0, 1]);
+        assert_eq!(sectors[0].radii, (0.0, 25.0));
+        assert_eq!(sectors[1].angles, (0.0, 2.0 * PI));
+        assert_eq!(sectors[1].depth, 1);
+
+        assert_eq!(
+            Sunburst::new(hierarchy())
+                .max_depth(1)
+                .layout((100, 100))
+                .len(),
+            2
+        );
+        assert!(Sunburst::new(hierarchy())
+            .focus([5])
+            .layout((100, 100))
+            .is_empty());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_sunburst_zero_max_depth() {
+        let _ = Sunburst::new([HierarchyNode::leaf("a", 1.0)]).max_depth(0);
+    }
+
+    #[test]
+    fn test_draw_sunburst() {
+        let drawing_area = create_mocked_drawing_area(100, 100, |m| {
+            m.check_fill_polygon(|_, points| {
+                assert!(points
+                    .iter()
+                    .all(|p| (p.0 - 50).pow(2) + (p.1 - 50).pow(2) <= 51 * 51));
+            });
+            m.drop_check(|b| {
+                assert_eq!(b.num_fill_polygon_call, 5);
+                assert_eq!(b.num_draw_path_call, 5);
+            });
+        });
+        let sunburst = Sunburst::new(hierarchy()).label_fit(SunburstLabelFit::Curve);
+        drawing_area.draw(&sunburst).unwrap();
+    }
+}
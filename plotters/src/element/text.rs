@@ -1,6 +1,7 @@
 use std::borrow::Borrow;
 
 use super::{Drawable, PointCollection};
+use crate::style::text_anchor::{HPos, Pos, VPos};
 use crate::style::{FontDesc, FontResult, LayoutBox, TextStyle};
 use plotters_backend::{BackendCoord, DrawingBackend, DrawingErrorKind};
 
@@ -272,3 +273,241 @@ impl<'a, Coord: 'a, DB: DrawingBackend, T: Borrow<str>> Drawable<DB>
         Ok(())
     }
 }
+
+/// The height of the baseline from the bottom of a line of text, in the fraction of its height
+const DESCENT: f64 = 0.24;
+
+/// The size of the superscripts and the subscripts relative to the size of their styles
+const SCRIPT_SCALE: f64 = 0.7;
+
+/// The vertical position of a span of a [`RichText`]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BaselineShift {
+    /// The span is on the baseline
+    Baseline,
+    /// The span is smaller and raised above the baseline
+    Superscript,
+    /// The span is smaller and lowered below the baseline
+    Subscript,
+}
+
+/**
+A single line text element of the spans of different styles, so a label can mix the bold, the
+colored, the sized, the superscript and the subscript runs, i.e. `CO₂ (µg/m³)` or a highlighted
+value in the middle of a sentence.
+
+The spans are laid out from the left to the right on a common baseline, and the whole line is
+placed at its position by the anchor of [`RichText::anchor()`], while the anchors of the span
+styles are ignored.
+
+```
+use plotters::prelude::*;
+use plotters::style::text_anchor::{HPos, Pos, VPos};
+let drawing_area = SVGBackend::new("rich_text.svg", (300, 100)).into_drawing_area();
+drawing_area.fill(&WHITE).unwrap();
+let style = ("sans-serif", 20).into_font();
+let label = RichText::new((150, 50))
+    .span("CO", style.clone())
+    .subscript("2", style.clone())
+    .span(" is ", style.clone())
+    .span("412", style.style(FontStyle::Bold).color(&RED))
+    .span(" µg/m", style.clone())
+    .superscript("3", style)
+    .anchor(Pos::new(HPos::Center, VPos::Center));
+drawing_area.draw(&label).unwrap();
+```
+*/
+pub struct RichText<'a, Coord> {
+    spans: Vec<(String, TextStyle<'a>, BaselineShift)>,
+    coord: Coord,
+    anchor: Pos,
+}
+
+impl<'a, Coord> RichText<'a, Coord> {
+    /// Create an empty rich text element, to which the spans are appended
+    ///
+    /// `pos`: The position of the anchor of the text, which is the upper left corner by default
+    pub fn new(pos: Coord) -> Self {
+        Self {
+            spans: vec![],
+            coord: pos,
+            anchor: Pos::new(HPos::Left, VPos::Top),
+        }
+    }
+
+    /// Create a rich text element of the spans on the baseline
+    ///
+    /// `pos`: The position of the anchor of the text
+    /// `spans`: The text and the style of each span
+    pub fn from_spans<T, S, I>(pos: Coord, spans: I) -> Self
+    where
+        T: Into<String>,
+        S: Into<TextStyle<'a>>,
+        I: IntoIterator<Item = (T, S)>,
+    {
+        spans
+            .into_iter()
+            .fold(Self::new(pos), |text, (span, style)| text.span(span, style))
+    }
+
+    fn push<T: Into<String>, S: Into<TextStyle<'a>>>(
+        mut self,
+        text: T,
+        style: S,
+        shift: BaselineShift,
+    ) -> Self {
+        self.spans.push((text.into(), style.into(), shift));
+        self
+    }
+
+    /// Append a span on the baseline
+    pub fn span<T: Into<String>, S: Into<TextStyle<'a>>>(self, text: T, style: S) -> Self {
+        self.push(text, style, BaselineShift::Baseline)
+    }
+
+    /// Append a superscript span, which is drawn smaller than its style
+    pub fn superscript<T: Into<String>, S: Into<TextStyle<'a>>>(self, text: T, style: S) -> Self {
+        self.push(text, style, BaselineShift::Superscript)
+    }
+
+    /// Append a subscript span, which is drawn smaller than its style
+    pub fn subscript<T: Into<String>, S: Into<TextStyle<'a>>>(self, text: T, style: S) -> Self {
+        self.push(text, style, BaselineShift::Subscript)
+    }
+
+    /// Set the anchor of the whole text
+    pub fn anchor(mut self, anchor: Pos) -> Self {
+        self.anchor = anchor;
+        self
+    }
+
+    /// Move the location to the specified location
+    pub fn relocate(&mut self, coord: Coord) {
+        self.coord = coord
+    }
+
+    /// Get the style of each span as it's drawn
+    fn span_styles(&self) -> impl Iterator<Item = (&str, TextStyle<'a>, BaselineShift)> + '_ {
+        self.spans.iter().map(|(text, style, shift)| {
+            let mut style = style.pos(Pos::new(HPos::Left, VPos::Top));
+            if *shift != BaselineShift::Baseline {
+                style.font = style.font.resize(style.font.get_size() * SCRIPT_SCALE);
+            }
+            (text.as_str(), style, *shift)
+        })
+    }
+
+    /// Lay out the spans with the sizes of their original and their drawn fonts
+    ///
+    /// - **returns**: The upper left corner of each span relative to the upper left corner of the
+    ///   whole text, and the size of the whole text
+    fn layout<E, F>(&self, mut measure: F) -> Result<(Vec<BackendCoord>, (i32, i32)), E>
+    where
+        F: FnMut(&str, &FontDesc<'a>) -> Result<(u32, u32), E>,
+    {
+        let mut boxes = vec![];
+        let mut x = 0;
+        for ((text, style, shift), (_, original, _)) in self.span_styles().zip(&self.spans) {
+            let (width, height) = measure(text, &style.font)?;
+            let line = match shift {
+                BaselineShift::Baseline => 0.0,
+                _ => f64::from(measure("", &original.font)?.1.max(height)),
+            };
+            // The baseline of the span relative to the common baseline, which is lower downwards
+            let baseline = match shift {
+                BaselineShift::Baseline => 0.0,
+                BaselineShift::Superscript => -0.35 * line,
+                BaselineShift::Subscript => 0.15 * line,
+            };
+            let height = f64::from(height);
+            let top = baseline - (1.0 - DESCENT) * height;
+            boxes.push((x, top, baseline + DESCENT * height));
+            x += width as i32;
+        }
+        let top = boxes.iter().fold(0.0f64, |top, b| top.min(b.1));
+        let bottom = boxes.iter().fold(0.0f64, |bottom, b| bottom.max(b.2));
+        let positions = boxes
+            .iter()
+            .map(|(x, t, _)| (*x, (t - top).round() as i32))
+            .collect();
+        Ok((positions, (x, (bottom - top).round() as i32)))
+    }
+
+    /// Estimate the rich text element's dimension
+    pub fn estimate_dimension(&self) -> FontResult<(i32, i32)> {
+        Ok(self.layout(|text, font| font.box_size(text))?.1)
+    }
+}
+
+impl<'b, 'a, Coord: 'a> PointCollection<'a, Coord> for &'a RichText<'b, Coord> {
+    type Point = &'a Coord;
+    type IntoIter = std::iter::Once<&'a Coord>;
+    fn point_iter(self) -> Self::IntoIter {
+        std::iter::once(&self.coord)
+    }
+}
+
+impl<'a, Coord: 'a, DB: DrawingBackend> Drawable<DB> for RichText<'a, Coord> {
+    fn draw<I: Iterator<Item = BackendCoord>>(
+        &self,
+        mut points: I,
+        backend: &mut DB,
+        _: (u32, u32),
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let (x0, y0) = match points.next() {
+            Some(pos) => pos,
+            None => return Ok(()),
+        };
+        let (positions, (width, height)) = self
+            .layout(|text, font| font.box_size(text))
+            .map_err(|e| DrawingErrorKind::FontError(Box::new(e)))?;
+        let dx = match self.anchor.h_pos {
+            HPos::Left => 0,
+            HPos::Center => -width / 2,
+            HPos::Right => -width,
+        };
+        let dy = match self.anchor.v_pos {
+            VPos::Top => 0,
+            VPos::Center => -height / 2,
+            VPos::Bottom => -height,
+        };
+        for ((text, style, _), (x, y)) in self.span_styles().zip(positions) {
+            backend.draw_text(text, &style, (x0 + dx + x, y0 + dy + y))?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_rich_text_layout() {
+    use crate::prelude::*;
+    let style = ("sans-serif", 20).into_font();
+    let text = RichText::new((0, 0))
+        .span("CO", style.clone())
+        .subscript("2", style.clone())
+        .superscript("3", style.color(&RED));
+    // Every character is a half of the font size wide, and the lines are as high as the font size
+    let (positions, size) = text
+        .layout(|text, font| {
+            let size = font.get_size() as u32;
+            Ok::<_, ()>((text.chars().count() as u32 * size / 2, size))
+        })
+        .unwrap();
+    // The regular text is 15.2 above and 4.8 below the baseline, the superscript of the height
+    // of 14 is raised by 7 to 17.6 above it, and the subscript is lowered by 3 to 6.4 below it
+    assert_eq!(positions, [(0, 2), (20, 10), (27, 0)]);
+    assert_eq!(size, (34, 24));
+
+    let text = RichText::from_spans((50, 50), [("a", style.clone()), ("b", style.clone())])
+        .anchor(Pos::new(HPos::Right, VPos::Bottom));
+    let (w, h) = text.estimate_dimension().unwrap();
+    let drawing_area = crate::create_mocked_drawing_area(100, 100, |m| {
+        m.check_draw_text(move |_, _, _, pos, text| match text {
+            "a" => assert_eq!(pos, (50 - w, 50 - h)),
+            _ => assert!(pos.0 > 50 - w && pos.1 == 50 - h),
+        });
+        m.drop_check(|b| assert_eq!(b.num_draw_text_call, 2));
+    });
+    drawing_area.draw(&text).unwrap();
+}
@@ -0,0 +1,358 @@
+use crate::{
+    element::{Drawable, HierarchyNode, PointCollection},
+    style::text_anchor::{HPos, Pos, VPos},
+    style::{Color, IntoFont, ShapeStyle, TextStyle, BLACK, WHITE},
+};
+use plotters_backend::{BackendCoord, DrawingBackend, DrawingErrorKind};
+use std::cmp::Ordering;
+
+use super::hierarchy::find_node;
+
+/// The gap between the edges of the cells and their labels in pixels
+const LABEL_INSET: i32 = 4;
+
+/// A cell of the layout of a [Treemap], in pixels relative to its area
+#[derive(Clone, Debug, PartialEq)]
+pub struct TreemapCell {
+    /// The upper left and the lower right corners of the cell
+    pub rect: [(f64, f64); 2],
+    /// The depth of the node, which is 0 for the root nodes
+    pub depth: usize,
+    /// The indices of the node and its ancestors among their siblings, from the root node, e.g.
+    /// `[2, 0]` is the first child of the third root node
+    pub path: Vec<usize>,
+}
+
+/**
+A treemap, which presents the hierarchical weighted data as the nested rectangles with the areas
+proportional to the weights.
+
+The rectangles of the siblings are placed with the squarified layout, which keeps them as close
+to squares as possible, and the children of a group are placed inside its rectangle, below the
+header with the label of the group. The root nodes are colored with the colors of
+[`crate::style::Palette99`] unless they have their own colors, and the descendants inherit the
+colors and get lighter with the depth. The depth of the drawn nodes can be limited by
+[`Treemap::max_depth()`].
+
+The treemap fills the drawing area it's drawn on, and the labels are drawn only where they fit.
+
+# Examples
+
+```
+use plotters::prelude::*;
+let drawing_area = SVGBackend::new("treemap.svg", (400, 300)).into_drawing_area();
+drawing_area.fill(&WHITE).unwrap();
+let treemap = Treemap::new([
+    HierarchyNode::group(
+        "Fruits",
+        [HierarchyNode::leaf("Apples", 30.0), HierarchyNode::leaf("Pears", 12.0)],
+    ),
+    HierarchyNode::group(
+        "Vegetables",
+        [HierarchyNode::leaf("Carrots", 20.0), HierarchyNode::leaf("Leeks", 8.0)],
+    ),
+    HierarchyNode::leaf("Bread", 15.0).color(RED),
+]);
+drawing_area.draw(&treemap).unwrap();
+```
+*/
+pub struct Treemap<'a> {
+    origin: (i32, i32),
+    roots: Vec<HierarchyNode>,
+    padding: u32,
+    header: u32,
+    max_depth: Option<usize>,
+    depth_shading: f64,
+    border_style: Option<ShapeStyle>,
+    label_style: Option<TextStyle<'a>>,
+}
+
+impl<'a> Treemap<'a> {
+    /// Create a new treemap
+    ///
+    /// - `roots`: The root nodes of the hierarchy
+    /// - **returns** The newly created treemap
+    pub fn new<I: IntoIterator<Item = HierarchyNode>>(roots: I) -> Self {
+        Self {
+            origin: (0, 0),
+            roots: roots.into_iter().collect(),
+            padding: 2,
+            header: 16,
+            max_depth: None,
+            depth_shading: 0.25,
+            border_style: Some(WHITE.into()),
+            label_style: Some(("sans-serif", 12).into_font().color(&BLACK)),
+        }
+    }
+
+    /// Set the gap between the rectangles of the groups and their children in pixels, which is 2
+    /// by default
+    pub fn padding(mut self, padding: u32) -> Self {
+        self.padding = padding;
+        self
+    }
+
+    /// Set the height of the headers of the groups in pixels, which is 16 by default
+    pub fn group_header(mut self, height: u32) -> Self {
+        self.header = height;
+        self
+    }
+
+    /// Limit the depth of the drawn nodes, e.g. 1 draws only the root nodes, and panics if the
+    /// depth is 0
+    pub fn max_depth(mut self, depth: usize) -> Self {
+        assert!(depth > 0, "The max depth of a treemap must be positive");
+        self.max_depth = Some(depth);
+        self
+    }
+
+    /// Set how much lighter the nodes get with each level of the depth, from 0.0 to 1.0, which is
+    /// 0.25 by default
+    pub fn depth_shading(mut self, shading: f64) -> Self {
+        self.depth_shading = shading;
+        self
+    }
+
+    /// Set the style of the borders of the rectangles, `None` removes the borders
+    pub fn border_style<S: Into<ShapeStyle>>(mut self, style: Option<S>) -> Self {
+        self.border_style = style.map(Into::into);
+        self
+    }
+
+    /// Set the style of the labels, `None` hides the labels
+    pub fn label_style<S: Into<TextStyle<'a>>>(mut self, style: Option<S>) -> Self {
+        self.label_style = style.map(Into::into);
+        self
+    }
+
+    /// Check if the children of a node at the depth are drawn
+    fn expands(&self, node: &HierarchyNode, depth: usize) -> bool {
+        !node.children().is_empty() && self.max_depth.filter(|max| depth + 1 >= *max).is_none()
+    }
+
+    /**
+    Compute the rectangles of the nodes.
+
+    - `(width, height)`: The size of the area of the treemap in pixels
+    - **returns** The cells of the drawn nodes with a positive weight, where each group goes
+      before its children
+    */
+    pub fn layout(&self, (width, height): (u32, u32)) -> Vec<TreemapCell> {
+        let mut cells = vec![];
+        let rect = [(0.0, 0.0), (f64::from(width), f64::from(height))];
+        self.layout_nodes(&self.roots, rect, &mut vec![], &mut cells);
+        cells
+    }
+
+    fn layout_nodes(
+        &self,
+        nodes: &[HierarchyNode],
+        rect: [(f64, f64); 2],
+        path: &mut Vec<usize>,
+        cells: &mut Vec<TreemapCell>,
+    ) {
+        let weights: Vec<_> = nodes.iter().map(HierarchyNode::weight).collect();
+        let depth = path.len();
+        for (index, (node, rect)) in nodes.iter().zip(squarify(&weights, rect)).enumerate() {
+            let rect = match rect {
+                Some(rect) => rect,
+                None => continue,
+            };
+            path.push(index);
+            cells.push(TreemapCell {
+                rect,
+                depth,
+                path: path.clone(),
+            });
+            if self.expands(node, depth) {
+                let (padding, header) = (f64::from(self.padding), f64::from(self.header));
+                let inner = [
+                    (rect[0].0 + padding, rect[0].1 + header.max(padding)),
+                    (rect[1].0 - padding, rect[1].1 - padding),
+                ];
+                if inner[0].0 < inner[1].0 && inner[0].1 < inner[1].1 {
+                    self.layout_nodes(node.children(), inner, path, cells);
+                }
+            }
+            path.pop();
+        }
+    }
+}
+
+/// Squarify the rectangles of the weights, which are `None` for the non-positive weights
+fn squarify(weights: &[f64], rect: [(f64, f64); 2]) -> Vec<Option<[(f64, f64); 2]>> {
+    let mut result = vec![None; weights.len()];
+    let [(mut x0, mut y0), (x1, y1)] = rect;
+    let total: f64 = weights.iter().filter(|w| **w > 0.0).sum();
+    if total <= 0.0 || x1 <= x0 || y1 <= y0 {
+        return result;
+    }
+    let mut order: Vec<usize> = (0..weights.len()).filter(|i| weights[*i] > 0.0).collect();
+    order.sort_by(|a, b| {
+        weights[*b]
+            .partial_cmp(&weights[*a])
+            .unwrap_or(Ordering::Equal)
+    });
+    let scale = (x1 - x0) * (y1 - y0) / total;
+    let areas: Vec<f64> = order.iter().map(|i| weights[*i] * scale).collect();
+
+    // The worst aspect ratio of a row with the total area and the largest and smallest areas
+    let worst = |sum: f64, max: f64, min: f64, side: f64| {
+        let (sum, side) = (sum * sum, side * side);
+        (side * max / sum).max(sum / (side * min))
+    };
+    let mut start = 0;
+    while start < areas.len() {
+        let (width, height) = (x1 - x0, y1 - y0);
+        let side = width.min(height);
+        let mut end = start + 1;
+        let mut sum = areas[start];
+        while end < areas.len() {
+            let (max, min) = (areas[start], areas[end - 1]);
+            let next = sum + areas[end];
+            if worst(next, max, areas[end], side) > worst(sum, max, min, side) {
+                break;
+            }
+            sum = next;
+            end += 1;
+        }
+        // The row is laid along the shorter side, and the rest of the rectangle is filled next
+        let thickness = sum / side;
+        let mut offset = 0.0;
+        for (k, area) in areas.iter().enumerate().take(end).skip(start) {
+            let length = area / thickness;
+            result[order[k]] = Some(if width >= height {
+                [(x0, y0 + offset), (x0 + thickness, y0 + offset + length)]
+            } else {
+                [(x0 + offset, y0), (x0 + offset + length, y0 + thickness)]
+            });
+            offset += length;
+        }
+        if width >= height {
+            x0 += thickness;
+        } else {
+            y0 += thickness;
+        }
+        start = end;
+    }
+    result
+}
+
+impl<'a, 'b> PointCollection<'a, (i32, i32)> for &'a Treemap<'b> {
+    type Point = &'a (i32, i32);
+    type IntoIter = std::iter::Once<&'a (i32, i32)>;
+    fn point_iter(self) -> std::iter::Once<&'a (i32, i32)> {
+        std::iter::once(&self.origin)
+    }
+}
+
+impl<'a, DB: DrawingBackend> Drawable<DB> for Treemap<'a> {
+    fn draw<I: Iterator<Item = BackendCoord>>(
+        &self,
+        mut pos: I,
+        backend: &mut DB,
+        parent_dim: (u32, u32),
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let origin = pos.next().unwrap_or(self.origin);
+        let to_coord =
+            |(x, y): (f64, f64)| (origin.0 + x.round() as i32, origin.1 + y.round() as i32);
+        for cell in self.layout(parent_dim) {
+            let (node, color) = find_node(&self.roots, &cell.path);
+            let shading = (cell.depth as f64 * self.depth_shading).clamp(0.0, 1.0);
+            let color = color.blend(&WHITE, shading);
+            let (upper_left, lower_right) = (to_coord(cell.rect[0]), to_coord(cell.rect[1]));
+            backend.draw_rect(upper_left, lower_right, &color, true)?;
+            if let Some(style) = &self.border_style {
+                backend.draw_rect(upper_left, lower_right, style, false)?;
+            }
+
+            let style = match &self.label_style {
+                Some(style) if !node.label().is_empty() => style,
+                _ => continue,
+            };
+            let (text_width, text_height) = backend.estimate_text_size(node.label(), style)?;
+            let (width, height) = (lower_right.0 - upper_left.0, lower_right.1 - upper_left.1);
+            let fits = |room: i32| {
+                text_width as i32 + 2 * LABEL_INSET <= width && text_height as i32 <= room
+            };
+            let x = upper_left.0 + LABEL_INSET;
+            if self.expands(node, cell.depth) {
+                let header = (self.header as i32).min(height);
+                if fits(header) {
+                    let style = style.pos(Pos::new(HPos::Left, VPos::Center));
+                    backend.draw_text(node.label(), &style, (x, upper_left.1 + header / 2))?;
+                }
+            } else if fits(height - 2 * LABEL_INSET) {
+                let style = style.pos(Pos::new(HPos::Left, VPos::Top));
+                backend.draw_text(node.label(), &style, (x, upper_left.1 + LABEL_INSET))?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_squarify() {
+        let rects = squarify(
+            &[6.0, 6.0, 4.0, 3.0, 0.0, 2.0, 2.0, 1.0],
+            [(0.0, 0.0), (6.0, 4.0)],
+        );
+        assert_eq!(rects[0], Some([(0.0, 0.0), (3.0, 2.0)]));
+        assert_eq!(rects[1], Some([(0.0, 2.0), (3.0, 4.0)]));
+        assert_eq!(rects[4], None);
+        let area: f64 = rects
+            .iter()
+            .flatten()
+            .map(|[a, b]| (b.0 - a.0) * (b.1 - a.1))
+            .sum();
+        assert!((area - 24.0f64).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_treemap_layout() {
+        let treemap = Treemap::new([
+            HierarchyNode::group(
+                "a",
+                [HierarchyNode::leaf("b", 1.0), HierarchyNode::leaf("c", 1.0)],
+            ),
+            HierarchyNode::leaf("d", 2.0),
+            HierarchyNode::leaf("e", -1.0),
+        ])
+        .padding(5)
+        .group_header(10);
+        let cells = treemap.layout((100, 50));
+        let paths: Vec<_> = cells.iter().map(|c| c.path.clone()).collect();
+        assert_eq!(paths, [vec![0], vec![0, 0], vec![0, 1], vec![1]]);
+        assert_eq!(cells[0].rect, [(0.0, 0.0), (50.0, 50.0)]);
+        assert_eq!(cells[1].rect, [(5.0, 10.0), (25.0, 45.0)]);
+        assert_eq!(cells[2].depth, 1);
+        assert_eq!(cells[3].rect, [(50.0, 0.0), (100.0, 50.0)]);
+        assert_eq!(treemap.max_depth(1).layout((100, 50)).len(), 2);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_treemap_zero_max_depth() {
+        let _ = Treemap::new([HierarchyNode::leaf("a", 1.0)]).max_depth(0);
+    }
+
+    #[test]
+    fn test_draw_treemap() {
+        let drawing_area = create_mocked_drawing_area(100, 100, |m| {
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_rect_call, 4);
+            });
+        });
+        let treemap = Treemap::new([HierarchyNode::group(
+            "a",
+            [HierarchyNode::leaf("b", 1.0).color(RED)],
+        )])
+        .label_style(None::<TextStyle>);
+        drawing_area.draw(&treemap).unwrap();
+    }
+}
@@ -0,0 +1,212 @@
+use std::marker::PhantomData;
+
+use crate::data::Quartiles;
+use crate::element::{BoxplotOrient, BoxplotOrientH, BoxplotOrientV, Drawable, PointCollection};
+use crate::style::{Color, ShapeStyle, BLACK, WHITE};
+use plotters_backend::{BackendCoord, DrawingBackend, DrawingErrorKind};
+
+const DEFAULT_WIDTH: u32 = 40;
+
+/// The violin element, which is a density curve mirrored around the key, optionally with an
+/// inner box plot.
+///
+/// The density is sampled at the given values, and the widths of the samples are relative to
+/// the width of the element, so the widest part of the violin is as wide as the element.
+pub struct Violin<K, O: BoxplotOrient<K, f32>> {
+    style: ShapeStyle,
+    inner_style: ShapeStyle,
+    width: u32,
+    key: K,
+    values: Vec<f32>,
+    widths: Vec<f64>,
+    inner: Option<[f32; 5]>,
+    _p: PhantomData<O>,
+}
+
+impl<K: Clone> Violin<K, BoxplotOrientV<K, f32>> {
+    /// Create a new vertical violin element.
+    ///
+    /// - `key`: The key (the X axis value)
+    /// - `density`: The pairs of the Y axis value and the density at the value, in the order of
+    ///   the values
+    /// - **returns** The newly created violin element
+    ///
+    /// ```rust
+    /// use plotters::prelude::*;
+    ///
+    /// let plot = Violin::new_vertical("group", vec![(0.0, 0.1), (1.0, 0.4), (2.0, 0.2)]);
+    /// ```
+    pub fn new_vertical<I: IntoIterator<Item = (f32, f64)>>(key: K, density: I) -> Self {
+        Self::with_density(key, density)
+    }
+}
+
+impl<K: Clone> Violin<K, BoxplotOrientH<K, f32>> {
+    /// Create a new horizontal violin element.
+    ///
+    /// - `key`: The key (the Y axis value)
+    /// - `density`: The pairs of the X axis value and the density at the value, in the order of
+    ///   the values
+    /// - **returns** The newly created violin element
+    ///
+    /// ```rust
+    /// use plotters::prelude::*;
+    ///
+    /// let plot = Violin::new_horizontal("group", vec![(0.0, 0.1), (1.0, 0.4), (2.0, 0.2)]);
+    /// ```
+    pub fn new_horizontal<I: IntoIterator<Item = (f32, f64)>>(key: K, density: I) -> Self {
+        Self::with_density(key, density)
+    }
+}
+
+impl<K, O: BoxplotOrient<K, f32>> Violin<K, O> {
+    pub(crate) fn with_density<I: IntoIterator<Item = (f32, f64)>>(key: K, density: I) -> Self {
+        let (values, widths): (Vec<_>, Vec<_>) = density.into_iter().unzip();
+        let max = widths.iter().copied().fold(0.0, f64::max);
+        Self {
+            style: BLACK.into(),
+            inner_style: BLACK.filled(),
+            width: DEFAULT_WIDTH,
+            key,
+            values,
+            widths: widths
+                .into_iter()
+                .map(|w| if max > 0.0 { w.max(0.0) / max } else { 0.0 })
+                .collect(),
+            inner: None,
+            _p: PhantomData,
+        }
+    }
+
+    /// Set the style of the density shape, which is filled if the style is filled.
+    ///
+    /// - `style`: The required style
+    /// - **returns** The up-to-dated violin element
+    pub fn style<S: Into<ShapeStyle>>(mut self, style: S) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Set the width of the widest part of the violin.
+    ///
+    /// - `width`: The required width in pixels
+    /// - **returns** The up-to-dated violin element
+    pub fn width(mut self, width: u32) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Overlay an inner box plot, of which the box spans the lower and the upper quartiles, the
+    /// line spans the fences, and the white dot marks the median.
+    ///
+    /// - `quartiles`: The quartiles of the inner box plot
+    /// - **returns** The up-to-dated violin element
+    pub fn inner_box(mut self, quartiles: &Quartiles) -> Self {
+        self.inner = Some(quartiles.values());
+        self
+    }
+
+    /// Set the style of the inner box plot.
+    ///
+    /// - `style`: The required style
+    /// - **returns** The up-to-dated violin element
+    pub fn inner_style<S: Into<ShapeStyle>>(mut self, style: S) -> Self {
+        self.inner_style = style.into();
+        self
+    }
+}
+
+impl<'a, K: Clone, O: BoxplotOrient<K, f32>> PointCollection<'a, (O::XType, O::YType)>
+    for &'a Violin<K, O>
+{
+    type Point = (O::XType, O::YType);
+    type IntoIter = Vec<Self::Point>;
+    fn point_iter(self) -> Self::IntoIter {
+        self.values
+            .iter()
+            .chain(self.inner.iter().flatten())
+            .map(|v| O::make_coord(self.key.clone(), *v))
+            .collect()
+    }
+}
+
+impl<K, DB: DrawingBackend, O: BoxplotOrient<K, f32>> Drawable<DB> for Violin<K, O> {
+    fn draw<I: Iterator<Item = BackendCoord>>(
+        &self,
+        points: I,
+        backend: &mut DB,
+        _: (u32, u32),
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let points: Vec<_> = points.collect();
+        if points.len() < self.values.len() {
+            return Ok(());
+        }
+        let (curve, inner) = points.split_at(self.values.len());
+
+        let half_width = f64::from(self.width) / 2.0;
+        let mut outline: Vec<_> = curve
+            .iter()
+            .zip(&self.widths)
+            .map(|(coord, w)| O::with_offset(*coord, w * half_width))
+            .collect();
+        outline.extend(
+            curve
+                .iter()
+                .zip(&self.widths)
+                .rev()
+                .map(|(coord, w)| O::with_offset(*coord, -w * half_width)),
+        );
+        if self.style.filled {
+            backend.fill_polygon(outline, &self.style)?;
+        } else if let Some(first) = outline.first().copied() {
+            outline.push(first);
+            backend.draw_path(outline, &self.style)?;
+        }
+
+        if inner.len() == 5 {
+            let box_width = (half_width / 4.0).max(2.0);
+            backend.draw_line(inner[0], inner[4], &self.inner_style)?;
+            let corner1 = O::with_offset(inner[1], -box_width / 2.0);
+            let corner2 = O::with_offset(inner[3], box_width / 2.0);
+            let upper_left = (corner1.0.min(corner2.0), corner1.1.min(corner2.1));
+            let bottom_right = (corner1.0.max(corner2.0), corner1.1.max(corner2.1));
+            backend.draw_rect(upper_left, bottom_right, &self.inner_style, true)?;
+            backend.draw_circle(
+                inner[2],
+                (box_width / 2.0).max(1.0) as u32,
+                &WHITE.filled(),
+                true,
+            )?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_draw_violin() {
+        let root = create_mocked_drawing_area(1024, 768, |m| {
+            m.check_fill_polygon(|_, points| {
+                assert_eq!(points.len(), 6);
+                assert_eq!(points[1].0 - points[4].0, 40);
+            });
+            m.drop_check(|b| {
+                assert_eq!(b.num_fill_polygon_call, 1);
+                assert_eq!(b.num_draw_rect_call, 1);
+                assert_eq!(b.num_draw_circle_call, 1);
+            });
+        });
+        let chart = ChartBuilder::on(&root)
+            .build_cartesian_2d(0..2, 0f32..100f32)
+            .unwrap();
+
+        let violin = Violin::new_vertical(1, vec![(10.0, 1.0), (50.0, 4.0), (90.0, 0.0)])
+            .style(RED.filled())
+            .inner_box(&Quartiles::new(&[10, 50, 90]));
+        chart.plotting_area().draw(&violin).unwrap();
+    }
+}
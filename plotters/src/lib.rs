@@ -713,18 +713,51 @@ pub fn register_font(
 | Name    |  Description | Additional Dependency |Default?|
 |---------|--------------|--------|------------|
 | datetime | Enable the date and time coordinate support | chrono | Yes |
+| rust\_decimal | Enable the `rust_decimal::Decimal` coordinate support | rust\_decimal | No |
+| uom | Enable the coordinate support for the `uom` quantities, labelled with their units | uom | No |
 
 - Element, series and util functions
 
 | Name    |  Description | Additional Dependency |Default?|
 |---------|--------------|--------|------------|
-| errorbar | The errorbar element support | None | Yes |
-| candlestick | The candlestick element support | None | Yes |
-| boxplot | The boxplot element support | None | Yes |
-| area\_series | The area series support | None | Yes |
-| line\_series | The line series support | None | Yes |
+| errorbar | The errorbar element and series support | None | Yes |
+| candlestick | The candlestick and OHLC bar element support | None | Yes |
+| boxplot | The boxplot element and series support | None | Yes |
+| violin | The violin element support, implies `boxplot` | None | Yes |
+| colorbar | The color bar element support for the colormaps | None | Yes |
+| calendar | The calendar heatmap element support | chrono | Yes |
+| sankey | The Sankey diagram element support | None | Yes |
+| treemap | The treemap element support | None | Yes |
+| sunburst | The sunburst element support | None | Yes |
 | histogram | The histogram series support | None | Yes |
-| point\_series| The point series support | None | Yes |
+| area\_series | The area series support | None | Yes |
+| band\_series | The band series support | None | Yes |
+| bollinger\_series | The Bollinger band series support | None | Yes |
+| colormap\_point\_series | The point series support colored by a colormap | None | Yes |
+| contour\_series | The contour series support | None | Yes |
+| dendrogram\_series | The dendrogram series support for the hierarchical clustering | None | Yes |
+| ecdf\_series | The empirical cumulative distribution function series support | None | Yes |
+| filled\_contour\_series | The filled contour series support | None | Yes |
+| funnel\_series | The funnel series support | None | Yes |
+| gantt\_series | The Gantt chart series support | None | Yes |
+| grouped\_bar\_series | The grouped bar series support | None | Yes |
+| heatmap\_series | The heatmap series support | None | Yes |
+| kde\_series | The kernel density estimate series support | None | Yes |
+| line\_series | The line, dashed line, dotted line and step line series support | None | Yes |
+| moving\_average\_series | The moving average series support | None | Yes |
+| point\_series| The point, bubble and 3D point series support | None | Yes |
+| quiver\_series | The quiver series support for the vector fields | None | Yes |
+| radar\_series | The radar chart axes and series support | None | Yes |
+| regression\_series | The least-squares regression series support | None | Yes |
+| ridgeline\_series | The ridgeline series support | None | Yes |
+| spline\_series | The spline series support | None | Yes |
+| stacked\_area\_series | The stacked area and streamgraph series support | None | Yes |
+| stacked\_bar\_series | The stacked bar series support | None | Yes |
+| streamline\_series | The streamline series support for the vector fields | None | Yes |
+| surface\_series | The surface, wireframe and 3D line series support | None | Yes |
+| violin\_series | The violin series support | None | Yes |
+| waterfall\_series | The waterfall series support | None | Yes |
+| wind\_rose\_series | The wind rose series support for the polar coordinate | None | Yes |
 
 - Misc
 
@@ -814,10 +847,17 @@ pub mod prelude {
         cartesian::Cartesian2d,
         combinators::{
             make_partial_axis, BindKeyPointMethod, BindKeyPoints, BuildNestedCoord, GroupBy,
-            IntoLinspace, IntoLogRange, IntoPartialAxis, Linspace, LogCoord, LogScalable,
-            NestedRange, NestedValue, ToGroupByRange,
+            IntoBrokenAxis, IntoLinLogRange, IntoLinspace, IntoLogRange, IntoPartialAxis,
+            IntoQuantileScale, IntoReversedAxis, IntoStepped, IntoStitchedAxis, IntoSymLogRange,
+            LinLogCoord, Linspace, LogCoord, LogScalable, NestedRange, NestedValue, QuantileCoord,
+            ToGroupByRange,
         },
+        geo::{GeoCoord, GeoProjection},
+        polar::PolarCoord,
         ranged1d::{DiscreteRanged, IntoSegmentedCoord, Ranged, SegmentValue},
+        ternary::TernaryCoord,
+        transformed::{AffineTransform, Transformed},
+        types::{IntoAngleCoord, RangedAngle},
         CoordTranslate,
     };
 
@@ -827,9 +867,18 @@ pub mod prelude {
     #[cfg(feature = "chrono")]
     #[cfg_attr(doc_cfg, doc(cfg(feature = "chrono")))]
     pub use crate::coord::types::{
-        IntoMonthly, IntoYearly, RangedDate, RangedDateTime, RangedDuration,
+        DatePeriod, IntoDatePeriod, IntoMonthly, IntoYearly, RangedDate, RangedDatePeriod,
+        RangedDateTime, RangedDuration, RangedStdDuration, RangedTime,
     };
 
+    #[cfg(feature = "rust_decimal")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "rust_decimal")))]
+    pub use crate::coord::types::RangedDecimal;
+
+    #[cfg(feature = "uom")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "uom")))]
+    pub use crate::coord::types::RangedQuantity;
+
     // Re-export the backend for backward compatibility
     pub use plotters_backend::DrawingBackend;
 
@@ -839,18 +888,98 @@ pub mod prelude {
     #[cfg(feature = "area_series")]
     #[cfg_attr(doc_cfg, doc(cfg(feature = "area_series")))]
     pub use crate::series::AreaSeries;
+    #[cfg(feature = "band_series")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "band_series")))]
+    pub use crate::series::BandSeries;
+    #[cfg(feature = "bollinger_series")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "bollinger_series")))]
+    pub use crate::series::BollingerSeries;
+    #[cfg(feature = "boxplot")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "boxplot")))]
+    pub use crate::series::BoxplotSeries;
+    #[cfg(feature = "colormap_point_series")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "colormap_point_series")))]
+    pub use crate::series::ColormapPointSeries;
+    #[cfg(feature = "contour_series")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "contour_series")))]
+    pub use crate::series::ContourSeries;
+    #[cfg(feature = "ecdf_series")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "ecdf_series")))]
+    pub use crate::series::EcdfSeries;
+    #[cfg(feature = "errorbar")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "errorbar")))]
+    pub use crate::series::ErrorBarSeries;
+    #[cfg(feature = "filled_contour_series")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "filled_contour_series")))]
+    pub use crate::series::FilledContourSeries;
+    #[cfg(feature = "grouped_bar_series")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "grouped_bar_series")))]
+    pub use crate::series::GroupedBarSeries;
+    #[cfg(feature = "heatmap_series")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "heatmap_series")))]
+    pub use crate::series::HeatmapSeries;
     #[cfg(feature = "histogram")]
     #[cfg_attr(doc_cfg, doc(cfg(feature = "histogram")))]
     pub use crate::series::Histogram;
-    #[cfg(feature = "point_series")]
-    #[cfg_attr(doc_cfg, doc(cfg(feature = "point_series")))]
-    pub use crate::series::PointSeries;
-    #[cfg(feature = "surface_series")]
-    #[cfg_attr(doc_cfg, doc(cfg(feature = "surface_series")))]
-    pub use crate::series::SurfaceSeries;
+    #[cfg(feature = "kde_series")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "kde_series")))]
+    pub use crate::series::KdeSeries;
+    #[cfg(feature = "quiver_series")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "quiver_series")))]
+    pub use crate::series::QuiverSeries;
+    #[cfg(feature = "regression_series")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "regression_series")))]
+    pub use crate::series::RegressionSeries;
+    #[cfg(feature = "ridgeline_series")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "ridgeline_series")))]
+    pub use crate::series::RidgelineSeries;
+    #[cfg(feature = "stacked_bar_series")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "stacked_bar_series")))]
+    pub use crate::series::StackedBarSeries;
+    #[cfg(feature = "streamline_series")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "streamline_series")))]
+    pub use crate::series::StreamlineSeries;
+    #[cfg(feature = "violin_series")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "violin_series")))]
+    pub use crate::series::ViolinSeries;
+    #[cfg(feature = "wind_rose_series")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "wind_rose_series")))]
+    pub use crate::series::WindRoseSeries;
     #[cfg(feature = "line_series")]
     #[cfg_attr(doc_cfg, doc(cfg(feature = "line_series")))]
-    pub use crate::series::{DashedLineSeries, DottedLineSeries, LineSeries};
+    pub use crate::series::{
+        DashedLineSeries, DottedLineSeries, LineSeries, StepLineSeries, StepMode,
+    };
+    #[cfg(feature = "dendrogram_series")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "dendrogram_series")))]
+    pub use crate::series::{DendrogramOrientation, DendrogramSeries};
+    #[cfg(feature = "funnel_series")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "funnel_series")))]
+    pub use crate::series::{FunnelConversion, FunnelSeries, FunnelShape};
+    #[cfg(feature = "gantt_series")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "gantt_series")))]
+    pub use crate::series::{GanttSeries, GanttTask};
+    #[cfg(feature = "surface_series")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "surface_series")))]
+    pub use crate::series::{Line3DSeries, SurfaceSeries, WireframeSeries};
+    #[cfg(feature = "moving_average_series")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "moving_average_series")))]
+    pub use crate::series::{MovingAverage, MovingAverageSeries};
+    #[cfg(feature = "point_series")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "point_series")))]
+    pub use crate::series::{Point3DSeries, PointSeries};
+    #[cfg(feature = "radar_series")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "radar_series")))]
+    pub use crate::series::{RadarAxes, RadarSeries};
+    #[cfg(feature = "spline_series")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "spline_series")))]
+    pub use crate::series::{SplineMode, SplineSeries};
+    #[cfg(feature = "stacked_area_series")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "stacked_area_series")))]
+    pub use crate::series::{StackedAreaSeries, StreamOffset, StreamgraphSeries};
+    #[cfg(feature = "waterfall_series")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "waterfall_series")))]
+    pub use crate::series::{WaterfallSeries, WaterfallStep};
 
     // Styles
     pub use crate::style::{BLACK, BLUE, CYAN, GREEN, MAGENTA, RED, TRANSPARENT, WHITE, YELLOW};
@@ -859,6 +988,12 @@ pub mod prelude {
     #[cfg_attr(doc_cfg, doc(cfg(feature = "full_palette")))]
     pub use crate::style::full_palette;
 
+    #[cfg(feature = "calendar")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "calendar")))]
+    pub use crate::element::CalendarHeatmap;
+    #[cfg(feature = "colorbar")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "colorbar")))]
+    pub use crate::element::ColorBar;
     #[cfg(feature = "colormaps")]
     #[cfg_attr(doc_cfg, doc(cfg(feature = "colormaps")))]
     pub use crate::style::colors::colormaps::*;
@@ -871,26 +1006,44 @@ pub mod prelude {
 
     // Elements
     pub use crate::element::{
-        Circle, Cross, Cubiod, DynElement, EmptyElement, IntoDynElement, MultiLineText,
-        PathElement, Pie, Pixel, Polygon, Rectangle, Text, TriangleMarker,
+        BaselineShift, Circle, CircularArc, CircularSector, Cross, CubicBezier, Cubiod, DynElement,
+        Ellipse, EmptyElement, FillRule, IntoDynElement, MultiLineText, PathElement, Pie,
+        PieLabelPosition, Pixel, Polygon, QuadBezier, Rectangle, RichText, RoundedRect, Text,
+        TriangleMarker,
     };
 
+    #[cfg(any(feature = "treemap", feature = "sunburst"))]
+    #[cfg_attr(doc_cfg, doc(cfg(any(feature = "treemap", feature = "sunburst"))))]
+    pub use crate::element::HierarchyNode;
+    #[cfg(feature = "treemap")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "treemap")))]
+    pub use crate::element::Treemap;
+    #[cfg(feature = "sankey")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "sankey")))]
+    pub use crate::element::{Sankey, SankeyLinkColor};
+    #[cfg(feature = "sunburst")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "sunburst")))]
+    pub use crate::element::{Sunburst, SunburstLabelFit};
+
     #[cfg(feature = "boxplot")]
     #[cfg_attr(doc_cfg, doc(cfg(feature = "boxplot")))]
     pub use crate::element::Boxplot;
-    #[cfg(feature = "candlestick")]
-    #[cfg_attr(doc_cfg, doc(cfg(feature = "candlestick")))]
-    pub use crate::element::CandleStick;
     #[cfg(feature = "errorbar")]
     #[cfg_attr(doc_cfg, doc(cfg(feature = "errorbar")))]
     pub use crate::element::ErrorBar;
+    #[cfg(feature = "violin")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "violin")))]
+    pub use crate::element::Violin;
+    #[cfg(feature = "candlestick")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "candlestick")))]
+    pub use crate::element::{CandleStick, OhlcBar};
 
     #[cfg(feature = "bitmap_backend")]
     #[cfg_attr(doc_cfg, doc(cfg(feature = "bitmap_backend")))]
-    pub use crate::element::BitMapElement;
+    pub use crate::element::{BitMapElement, Resampling};
 
     // Data
-    pub use crate::data::Quartiles;
+    pub use crate::data::{Kernel, KernelDensity, Quartiles, RegressionFit};
 
     // TODO: This should be deprecated and completely removed
     #[cfg(feature = "deprecated_items")]
@@ -0,0 +1,320 @@
+use crate::element::{DynElement, IntoDynElement, PathElement, Polygon};
+#[cfg(feature = "bollinger_series")]
+use crate::series::MovingAverage;
+use crate::style::{Color, ShapeStyle};
+use num_traits::Float;
+use plotters_backend::DrawingBackend;
+
+/**
+A band series fills the ribbon between a lower and an upper bound along the X axis, which is
+usually the confidence interval or the error band around a fitted curve.
+
+It takes an iterator of `(x, y_low, y_high)` tuples in the order of the X values. The ribbon is
+filled with the given style, which is usually translucent so that the curves under it remain
+visible. The edges of the ribbon and the center line, i.e. the midpoints of the bounds, are
+optionally drawn as well.
+
+# Example
+
+```
+use plotters::prelude::*;
+let drawing_area = SVGBackend::new("band_series.svg", (300, 200)).into_drawing_area();
+drawing_area.fill(&WHITE).unwrap();
+let mut chart = ChartBuilder::on(&drawing_area)
+    .margin(10)
+    .set_left_and_bottom_label_area_size(20)
+    .build_cartesian_2d(0.0..4.0, 0.0..3.0)
+    .unwrap();
+chart.configure_mesh().draw().unwrap();
+let band = (0..=40).map(|x| {
+    let x = x as f64 / 10.0;
+    let fit = 0.5 + 0.5 * x;
+    let error = 0.2 + 0.05 * x;
+    (x, fit - error, fit + error)
+});
+chart
+    .draw_series(BandSeries::new(band, BLUE.mix(0.2)).center_line(BLUE))
+    .unwrap();
+```
+*/
+pub struct BandSeries<DB: DrawingBackend, X: Clone, Y: Clone> {
+    band_style: ShapeStyle,
+    border_style: Option<ShapeStyle>,
+    center_style: Option<ShapeStyle>,
+    center: Vec<(X, Y)>,
+    data: Vec<(X, Y, Y)>,
+    state: u32,
+    _p: std::marker::PhantomData<DB>,
+}
+
+impl<DB: DrawingBackend, X: Clone, Y: Clone> BandSeries<DB, X, Y> {
+    /**
+    Creates a band series without the edges and the center line.
+
+    - `iter`: The `(x, y_low, y_high)` tuples of the band, in the order of the X values
+    - `band_style`: The style of the ribbon between the bounds
+    - **returns** The newly created series
+
+    See [`BandSeries`] for more information and examples.
+    */
+    pub fn new<S: Into<ShapeStyle>, I: IntoIterator<Item = (X, Y, Y)>>(
+        iter: I,
+        band_style: S,
+    ) -> Self {
+        Self {
+            band_style: band_style.into(),
+            border_style: None,
+            center_style: None,
+            center: vec![],
+            data: iter.into_iter().collect(),
+            state: 0,
+            _p: std::marker::PhantomData,
+        }
+    }
+
+    /**
+    Sets the style of the edges of the ribbon, i.e. the lines along the lower and the upper
+    bounds.
+
+    See [`BandSeries`] for more information and examples.
+    */
+    pub fn border_style<S: Into<ShapeStyle>>(mut self, style: S) -> Self {
+        self.border_style = Some(style.into());
+        self
+    }
+}
+
+impl<DB: DrawingBackend, X: Clone, Y: Float> BandSeries<DB, X, Y> {
+    /**
+    Draws the center line of the band, which connects the midpoints of the bounds.
+
+    See [`BandSeries`] for more information and examples.
+    */
+    pub fn center_line<S: Into<ShapeStyle>>(mut self, style: S) -> Self {
+        let two = Y::one() + Y::one();
+        self.center = self
+            .data
+            .iter()
+            .map(|(x, low, high)| (x.clone(), (*low + *high) / two))
+            .collect();
+        self.center_style = Some(style.into());
+        self
+    }
+}
+
+impl<DB: DrawingBackend, X: Clone + 'static, Y: Clone + 'static> Iterator for BandSeries<DB, X, Y> {
+    type Item = DynElement<'static, DB, (X, Y)>;
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            self.state += 1;
+            match self.state {
+                1 => {
+                    let outline: Vec<_> = self
+                        .data
+                        .iter()
+                        .map(|(x, _, high)| (x.clone(), high.clone()))
+                        .chain(
+                            self.data
+                                .iter()
+                                .rev()
+                                .map(|(x, low, _)| (x.clone(), low.clone())),
+                        )
+                        .collect();
+                    return Some(Polygon::new(outline, self.band_style).into_dyn());
+                }
+                2 | 3 => {
+                    if let Some(style) = self.border_style {
+                        let upper = self.state == 2;
+                        let edge: Vec<_> = self
+                            .data
+                            .iter()
+                            .map(|(x, low, high)| {
+                                (x.clone(), if upper { high.clone() } else { low.clone() })
+                            })
+                            .collect();
+                        return Some(PathElement::new(edge, style).into_dyn());
+                    }
+                }
+                4 => {
+                    if let Some(style) = self.center_style {
+                        let center = std::mem::take(&mut self.center);
+                        return Some(PathElement::new(center, style).into_dyn());
+                    }
+                }
+                _ => return None,
+            }
+        }
+    }
+}
+
+#[cfg(feature = "bollinger_series")]
+/**
+The Bollinger band series, which draws the rolling mean of the data points as the center line of
+a [`BandSeries`] spanning `k` rolling standard deviations above and below it, i.e. the volatility
+band of a price or the normal range of a monitored metric.
+
+The statistics of each data point are computed over the window of the last `window` values, so
+the band starts at the point where the first window is full. The standard deviation is the
+population one, as in the conventional definition of the Bollinger bands.
+
+# Example
+
+```
+use plotters::prelude::*;
+let drawing_area = SVGBackend::new("bollinger_series.svg", (300, 200)).into_drawing_area();
+drawing_area.fill(&WHITE).unwrap();
+let mut chart = ChartBuilder::on(&drawing_area)
+    .margin(10)
+    .set_left_and_bottom_label_area_size(20)
+    .build_cartesian_2d(0..40, 0.0..20.0)
+    .unwrap();
+chart.configure_mesh().draw().unwrap();
+let prices: Vec<_> = (0..40)
+    .map(|x| (x, 10.0 + 3.0 * (x as f64 / 5.0).sin() + (x % 3) as f64))
+    .collect();
+chart
+    .draw_series(BollingerSeries::new(prices.clone(), 10, 2.0, BLUE).border_style(BLUE.mix(0.5)))
+    .unwrap();
+chart.draw_series(LineSeries::new(prices, BLACK)).unwrap();
+```
+*/
+pub struct BollingerSeries<DB: DrawingBackend, X: Clone> {
+    band: BandSeries<DB, X, f64>,
+}
+
+#[cfg(feature = "bollinger_series")]
+impl<DB: DrawingBackend, X: Clone> BollingerSeries<DB, X> {
+    /**
+    Creates a Bollinger band series, of which the band is filled with the translucent color of
+    the center line.
+
+    - `iter`: The data points, in the order of the X values
+    - `window`: The number of the values in the rolling window, a window of zero is taken as one
+    - `k`: The number of the standard deviations between the center line and each bound
+    - `style`: The style of the center line
+    - **returns** The newly created series
+
+    See [`BollingerSeries`] for more information and examples.
+    */
+    pub fn new<S: Into<ShapeStyle>, I: IntoIterator<Item = (X, f64)>>(
+        iter: I,
+        window: usize,
+        k: f64,
+        style: S,
+    ) -> Self {
+        let style = style.into();
+        let data: Vec<_> = iter.into_iter().collect();
+        let window = window.max(1);
+        let means = MovingAverage::Simple(window).smooth(data.iter().cloned());
+        let bands = means
+            .into_iter()
+            .zip(data.windows(window))
+            .map(|((x, mean), values)| {
+                let n = window as f64;
+                let variance = values.iter().map(|(_, y)| (y - mean).powi(2)).sum::<f64>() / n;
+                let deviation = k * variance.sqrt();
+                (x, mean - deviation, mean + deviation)
+            });
+        let band = BandSeries::new(bands, style.color.mix(0.2).filled()).center_line(style);
+        Self { band }
+    }
+
+    /// Set the style of the band between the bounds
+    pub fn band_style<S: Into<ShapeStyle>>(mut self, style: S) -> Self {
+        self.band.band_style = style.into();
+        self
+    }
+
+    /// Set the style of the lines along the lower and the upper bounds
+    pub fn border_style<S: Into<ShapeStyle>>(mut self, style: S) -> Self {
+        self.band = self.band.border_style(style);
+        self
+    }
+
+    /// Get the `(x, y_low, y_high)` tuples of the band
+    pub fn bands(&self) -> &[(X, f64, f64)] {
+        &self.band.data
+    }
+}
+
+#[cfg(feature = "bollinger_series")]
+impl<DB: DrawingBackend, X: Clone + 'static> Iterator for BollingerSeries<DB, X> {
+    type Item = DynElement<'static, DB, (X, f64)>;
+    fn next(&mut self) -> Option<Self::Item> {
+        self.band.next()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::drawing::MockedBackend;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_band_series() {
+        let drawing_area = create_mocked_drawing_area(100, 100, |m| {
+            m.check_fill_polygon(|_, points| assert_eq!(points.len(), 6));
+            m.check_draw_path(|_, _, points| assert_eq!(points.len(), 3));
+            m.drop_check(|b| {
+                assert_eq!(b.num_fill_polygon_call, 2);
+                assert_eq!(b.num_draw_path_call, 3);
+            });
+        });
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0.0..2.0, 0.0..4.0)
+            .unwrap();
+        let band = [(0.0, 1.0, 2.0), (1.0, 1.5, 3.0), (2.0, 2.0, 4.0)];
+        chart
+            .draw_series(BandSeries::new(band, BLUE.mix(0.2)))
+            .unwrap();
+        chart
+            .draw_series(
+                BandSeries::new(band, BLUE.mix(0.2))
+                    .border_style(BLUE)
+                    .center_line(RED),
+            )
+            .unwrap();
+    }
+
+    #[test]
+    #[cfg(feature = "bollinger_series")]
+    fn test_bollinger_series() {
+        let data = [(0, 1.0), (1, 3.0), (2, 5.0), (3, 5.0)];
+        let series = BollingerSeries::<MockedBackend, _>::new(data, 2, 2.0, BLUE);
+        assert_eq!(
+            series.bands(),
+            [(1, 0.0, 4.0), (2, 2.0, 6.0), (3, 5.0, 5.0)]
+        );
+        assert_eq!(series.count(), 2);
+        assert_eq!(
+            BollingerSeries::<MockedBackend, _>::new(data, 0, 2.0, BLUE).bands(),
+            [(0, 1.0, 1.0), (1, 3.0, 3.0), (2, 5.0, 5.0), (3, 5.0, 5.0)]
+        );
+        assert!(BollingerSeries::<MockedBackend, _>::new(data, 5, 2.0, BLUE)
+            .bands()
+            .is_empty());
+
+        let drawing_area = create_mocked_drawing_area(101, 101, |m| {
+            m.check_fill_polygon(|c, points| {
+                assert_eq!(c, RED.mix(0.2).to_rgba());
+                assert_eq!(points, [(50, 25), (100, 25), (100, 75), (50, 75)]);
+            });
+            m.check_draw_path(|c, _, points| {
+                assert_eq!(c, RED.to_rgba());
+                assert_eq!(points, [(50, 50), (100, 50)]);
+            });
+            m.drop_check(|b| {
+                assert_eq!(b.num_fill_polygon_call, 1);
+                assert_eq!(b.num_draw_path_call, 1);
+            });
+        });
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0.0..100.0, 0.0..100.0)
+            .unwrap();
+        let data = [(0.0, 25.0), (50.0, 75.0), (100.0, 25.0)];
+        chart
+            .draw_series(BollingerSeries::new(data, 2, 1.0, RED))
+            .unwrap();
+    }
+}
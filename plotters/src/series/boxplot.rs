@@ -0,0 +1,192 @@
+use std::marker::PhantomData;
+
+use crate::data::Quartiles;
+use crate::element::{Boxplot, BoxplotOrient, BoxplotOrientH, BoxplotOrientV};
+use crate::style::{ShapeStyle, BLACK};
+
+/// The default factor of the interquartile range of the Tukey fences
+const DEFAULT_FENCE_FACTOR: f64 = 1.5;
+
+/**
+Presents the distribution of the raw samples of each category as box plots.
+
+The quartiles, the whiskers and the outliers are computed from the samples: the box spans the
+lower and the upper quartiles with a line at the median, the whiskers reach the most extreme
+samples within the Tukey fences, which are [`BoxplotSeries::fence_factor()`] times the
+interquartile range beyond the quartiles, and the samples beyond the fences are drawn as circles.
+The categories without any sample are skipped.
+
+# Examples
+
+```
+use plotters::prelude::*;
+let drawing_area = SVGBackend::new("boxplot_series.svg", (300, 200)).into_drawing_area();
+drawing_area.fill(&WHITE).unwrap();
+let mut chart = ChartBuilder::on(&drawing_area)
+    .margin(5)
+    .set_left_and_bottom_label_area_size(20)
+    .build_cartesian_2d(["a", "b"][..].into_segmented(), 0f32..60f32)
+    .unwrap();
+chart.configure_mesh().draw().unwrap();
+chart
+    .draw_series(
+        BoxplotSeries::vertical(vec![
+            (SegmentValue::CenterOf(&"a"), vec![7.0, 15.0, 36.0, 39.0, 40.0, 41.0]),
+            (SegmentValue::CenterOf(&"b"), vec![20.0, 22.0, 25.0, 26.0, 29.0, 55.0]),
+        ])
+        .width(20)
+        .style(BLUE),
+    )
+    .unwrap();
+```
+*/
+pub struct BoxplotSeries<K, O: BoxplotOrient<K, f32>> {
+    iter: std::vec::IntoIter<(K, Vec<f64>)>,
+    fence_factor: f64,
+    style: ShapeStyle,
+    width: u32,
+    whisker_width: f64,
+    outlier_size: u32,
+    _p: PhantomData<O>,
+}
+
+impl<K> BoxplotSeries<K, BoxplotOrientV<K, f32>> {
+    /// Create a new vertical box plot series
+    ///
+    /// - `data`: The categories with their samples, the categories are on the X axis
+    /// - **returns** The newly created series
+    pub fn vertical<T: Into<f64>, S: IntoIterator<Item = T>, I: IntoIterator<Item = (K, S)>>(
+        data: I,
+    ) -> Self {
+        Self::new(data)
+    }
+}
+
+impl<K> BoxplotSeries<K, BoxplotOrientH<K, f32>> {
+    /// Create a new horizontal box plot series
+    ///
+    /// - `data`: The categories with their samples, the categories are on the Y axis
+    /// - **returns** The newly created series
+    pub fn horizontal<T: Into<f64>, S: IntoIterator<Item = T>, I: IntoIterator<Item = (K, S)>>(
+        data: I,
+    ) -> Self {
+        Self::new(data)
+    }
+}
+
+impl<K, O: BoxplotOrient<K, f32>> BoxplotSeries<K, O> {
+    fn new<T: Into<f64>, S: IntoIterator<Item = T>, I: IntoIterator<Item = (K, S)>>(
+        data: I,
+    ) -> Self {
+        let data: Vec<_> = data
+            .into_iter()
+            .map(|(key, samples)| {
+                let samples = samples
+                    .into_iter()
+                    .map(Into::into)
+                    .filter(|v: &f64| !v.is_nan())
+                    .collect();
+                (key, samples)
+            })
+            .collect();
+        Self {
+            iter: data.into_iter(),
+            fence_factor: DEFAULT_FENCE_FACTOR,
+            style: BLACK.into(),
+            width: 10,
+            whisker_width: 1.0,
+            outlier_size: 3,
+            _p: PhantomData,
+        }
+    }
+
+    /// Set the factor of the interquartile range of the Tukey fences, which is 1.5 by default
+    pub fn fence_factor(mut self, factor: f64) -> Self {
+        self.fence_factor = factor;
+        self
+    }
+
+    /// Set the style of the box plots
+    pub fn style<S: Into<ShapeStyle>>(mut self, style: S) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Set the width of the boxes in pixels
+    pub fn width(mut self, width: u32) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Set the width of the whisker caps as a fraction of the box width
+    pub fn whisker_width(mut self, whisker_width: f64) -> Self {
+        self.whisker_width = whisker_width;
+        self
+    }
+
+    /// Set the radius of the circles of the outliers in pixels
+    pub fn outlier_size(mut self, size: u32) -> Self {
+        self.outlier_size = size;
+        self
+    }
+}
+
+impl<K: Clone, O: BoxplotOrient<K, f32>> Iterator for BoxplotSeries<K, O> {
+    type Item = Boxplot<K, O>;
+    fn next(&mut self) -> Option<Self::Item> {
+        for (key, samples) in self.iter.by_ref() {
+            if samples.is_empty() {
+                continue;
+            }
+            let quartiles = Quartiles::with_tukey_fences(&samples, self.fence_factor);
+            let [low, _, _, _, high] = quartiles.values();
+            let outliers: Vec<_> = samples
+                .iter()
+                .map(|v| *v as f32)
+                .filter(|v| *v < low || *v > high)
+                .collect();
+            return Some(
+                Boxplot::<K, O>::with_quartiles(key, &quartiles)
+                    .style(self.style)
+                    .width(self.width)
+                    .whisker_width(self.whisker_width)
+                    .outliers(outliers)
+                    .outlier_size(self.outlier_size),
+            );
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_boxplot_series() {
+        let drawing_area = create_mocked_drawing_area(200, 200, |m| {
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_rect_call, 2);
+                assert_eq!(b.num_draw_circle_call, 2);
+            });
+        });
+        let chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0f32..100f32, 0..3)
+            .unwrap();
+
+        let series: Vec<_> = BoxplotSeries::horizontal(vec![
+            (0, vec![1.0, 2.0, 3.0, 4.0, 5.0, 20.0, -20.0]),
+            (1, vec![]),
+            (2, vec![10.0, 12.0, f64::NAN]),
+        ])
+        .collect();
+        assert_eq!(series.len(), 2);
+
+        let series = BoxplotSeries::horizontal(vec![(0, vec![1, 2, 3, 4, 5, 20, -20])]).chain(
+            BoxplotSeries::horizontal(vec![(2, vec![1, 2, 3, 4, 5, 20])]).fence_factor(20.0),
+        );
+        for boxplot in series {
+            chart.plotting_area().draw(&boxplot).unwrap();
+        }
+    }
+}
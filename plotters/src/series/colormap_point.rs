@@ -0,0 +1,181 @@
+use std::ops::Range;
+use std::rc::Rc;
+
+use crate::element::{ColorBar, PointElement};
+use crate::style::colors::colormaps::ColorMap;
+use crate::style::{Color, RGBAColor, ShapeStyle, SizeDesc};
+
+/**
+The scatter plot object which maps a scalar of each point through a colormap to the color of its
+marker, which visualizes a third variable on a 2D scatter plot.
+
+The colors are picked from the colormap after the scalars are normalized with the range of the
+scalars, unless it's set by [`ColormapPointSeries::value_range()`], and the points with a
+non-finite scalar are skipped. [`ColormapPointSeries::colorbar()`] creates the matching
+[`ColorBar`] of the series.
+
+# Examples
+
+```
+use plotters::prelude::*;
+let drawing_area = SVGBackend::new("colormap_point_series.svg", (350, 200)).into_drawing_area();
+drawing_area.fill(&WHITE).unwrap();
+let (chart_area, colorbar_area) = drawing_area.split_horizontally(300);
+let mut chart = ChartBuilder::on(&chart_area)
+    .margin(10)
+    .set_left_and_bottom_label_area_size(20)
+    .build_cartesian_2d(0.0..10.0, 0.0..10.0)
+    .unwrap();
+chart.configure_mesh().draw().unwrap();
+let series = ColormapPointSeries::<_, Circle<_, _>, _>::new(
+    (0..50).map(|i| {
+        let x = i as f64 / 5.0;
+        ((x, 5.0 + 4.0 * (x / 2.0).sin()), x * x)
+    }),
+    4,
+    ViridisRGB,
+);
+let colorbar = series.colorbar();
+chart.draw_series(series).unwrap();
+colorbar_area.margin(10, 30, 0, 0).draw(&colorbar).unwrap();
+```
+*/
+pub struct ColormapPointSeries<'a, Coord, E, Size: SizeDesc + Clone> {
+    data_iter: std::vec::IntoIter<(Coord, f64)>,
+    size: Size,
+    value_range: Range<f64>,
+    filled: bool,
+    colormap: Rc<dyn Fn(f64) -> RGBAColor + 'a>,
+    make_point: &'a dyn Fn(Coord, Size, ShapeStyle) -> E,
+}
+
+impl<'a, Coord, E, Size: SizeDesc + Clone> ColormapPointSeries<'a, Coord, E, Size>
+where
+    E: PointElement<Coord, Size>,
+{
+    /// Create a new colormap point series with the element that implements point trait
+    ///
+    /// - `iter`: The points with their scalars
+    /// - `size`: The size of the markers
+    /// - `colormap`: The colormap from which the colors of the markers are picked
+    /// - **returns** The newly created series
+    pub fn new<C, M, I>(iter: I, size: Size, colormap: M) -> Self
+    where
+        C: Color,
+        M: ColorMap<C, f64> + 'a,
+        I: IntoIterator<Item = (Coord, f64)>,
+    {
+        Self::of_element(iter, size, colormap, &E::make_point)
+    }
+}
+
+impl<'a, Coord, E, Size: SizeDesc + Clone> ColormapPointSeries<'a, Coord, E, Size> {
+    /// Create a new colormap point series with the customized function for element creation,
+    /// similar to [`crate::series::PointSeries::of_element()`]
+    pub fn of_element<C, M, I, F>(iter: I, size: Size, colormap: M, cons: &'a F) -> Self
+    where
+        C: Color,
+        M: ColorMap<C, f64> + 'a,
+        I: IntoIterator<Item = (Coord, f64)>,
+        F: Fn(Coord, Size, ShapeStyle) -> E,
+    {
+        let data: Vec<_> = iter
+            .into_iter()
+            .filter(|(_, value)| value.is_finite())
+            .collect();
+        let (min, max) = data
+            .iter()
+            .fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), (_, v)| {
+                (min.min(*v), max.max(*v))
+            });
+        Self {
+            data_iter: data.into_iter(),
+            size,
+            value_range: if min <= max { min..max } else { 0.0..1.0 },
+            filled: true,
+            colormap: Rc::new(move |h| colormap.get_color(h).to_rgba()),
+            make_point: cons,
+        }
+    }
+
+    /// Set the range of the scalars which is mapped to the whole colormap, the scalars out of the
+    /// range take the colors at the ends of the colormap
+    pub fn value_range(mut self, range: Range<f64>) -> Self {
+        self.value_range = range;
+        self
+    }
+
+    /// Set if the markers are filled, which is the default, or outlined
+    pub fn filled(mut self, filled: bool) -> Self {
+        self.filled = filled;
+        self
+    }
+
+    /// Get the color of the marker of a scalar
+    pub fn color_of(&self, value: f64) -> RGBAColor {
+        let Range { start, end } = self.value_range;
+        let h = if end > start {
+            ((value - start) / (end - start)).clamp(0.0, 1.0)
+        } else {
+            0.5
+        };
+        (self.colormap)(h)
+    }
+
+    /// Create the colorbar of the colormap over the range of the scalars
+    pub fn colorbar(&self) -> ColorBar<'a> {
+        ColorBar::from_fn(self.colormap.clone(), self.value_range.clone())
+    }
+}
+
+impl<'a, Coord, E, Size: SizeDesc + Clone> Iterator for ColormapPointSeries<'a, Coord, E, Size> {
+    type Item = E;
+    fn next(&mut self) -> Option<Self::Item> {
+        let (coord, value) = self.data_iter.next()?;
+        let color = self.color_of(value);
+        let style = if self.filled {
+            color.filled()
+        } else {
+            color.into()
+        };
+        Some((self.make_point)(coord, self.size.clone(), style))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_colormap_point_series() {
+        let colormap = DerivedColorMap::new(&[BLACK, WHITE]);
+        let series = ColormapPointSeries::of_element(
+            [
+                ((0, 0), 0.0),
+                ((1, 1), f64::NAN),
+                ((2, 2), 10.0),
+                ((3, 3), 5.0),
+            ],
+            3,
+            colormap,
+            &|coord, size, style| (coord, size, style),
+        );
+        assert_eq!(series.color_of(-1.0), BLACK.to_rgba());
+        assert_eq!(series.color_of(10.0), WHITE.to_rgba());
+        assert_eq!(series.colorbar().tick_values()[4], 10.0);
+        let points: Vec<_> = series.filled(false).collect();
+        assert_eq!(points.len(), 3);
+        assert_eq!(points[1].0, (2, 2));
+        assert!(!points[1].2.filled);
+        assert_eq!(points[2].2.color, RGBColor(128, 128, 128).to_rgba());
+
+        let series = ColormapPointSeries::<_, Circle<_, _>, _>::new(
+            [((0, 0), 1.0)],
+            3,
+            DerivedColorMap::new(&[BLACK, WHITE]),
+        )
+        .value_range(0.0..2.0);
+        assert_eq!(series.colorbar().tick_values(), [0.0, 0.5, 1.0, 1.5, 2.0]);
+    }
+}
@@ -0,0 +1,513 @@
+use std::collections::HashMap;
+
+use crate::coord::ranged1d::Ranged;
+use crate::coord::types::RangedCoordf64;
+use crate::data::float::pretty_print_float;
+use crate::element::{Drawable, PointCollection};
+use crate::style::text_anchor::{HPos, Pos, VPos};
+use crate::style::{IntoFont, ShapeStyle, TextStyle, BLACK};
+use plotters_backend::{BackendCoord, DrawingBackend, DrawingErrorKind};
+
+/// A scalar field sampled on a rectilinear grid, of which `values[j][i]` is the value at
+/// `(xs[i], ys[j])`
+pub(super) struct ScalarGrid {
+    pub(super) xs: Vec<f64>,
+    pub(super) ys: Vec<f64>,
+    pub(super) values: Vec<Vec<f64>>,
+}
+
+/// An edge of the grid, which is either the horizontal edge from `(i, j)` to `(i + 1, j)`, or
+/// the vertical edge from `(i, j)` to `(i, j + 1)`
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub(super) enum GridEdge {
+    Horizontal(usize, usize),
+    Vertical(usize, usize),
+}
+
+impl ScalarGrid {
+    pub(super) fn new<X, Y, V, R, I>(xs: X, ys: Y, rows: I) -> Self
+    where
+        X: IntoIterator<Item = f64>,
+        Y: IntoIterator<Item = f64>,
+        V: Into<f64>,
+        R: IntoIterator<Item = V>,
+        I: IntoIterator<Item = R>,
+    {
+        let xs: Vec<_> = xs.into_iter().collect();
+        let ys: Vec<_> = ys.into_iter().collect();
+        let values = rows
+            .into_iter()
+            .take(ys.len())
+            .map(|row| {
+                let mut row: Vec<f64> = row.into_iter().take(xs.len()).map(Into::into).collect();
+                row.resize(xs.len(), f64::NAN);
+                row
+            })
+            .collect();
+        Self { xs, ys, values }
+    }
+
+    pub(super) fn value(&self, i: usize, j: usize) -> f64 {
+        self.values
+            .get(j)
+            .and_then(|row| row.get(i))
+            .copied()
+            .unwrap_or(f64::NAN)
+    }
+
+    /// Get the range of the finite values
+    pub(super) fn value_range(&self) -> Option<(f64, f64)> {
+        let (min, max) = self
+            .values
+            .iter()
+            .flatten()
+            .filter(|v| v.is_finite())
+            .fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), v| {
+                (min.min(*v), max.max(*v))
+            });
+        Some((min, max)).filter(|(min, max)| min <= max)
+    }
+
+    /// Get the evenly spaced levels at the round numbers within the range of the values
+    pub(super) fn default_levels(&self, count: usize) -> Vec<f64> {
+        match self.value_range() {
+            Some((min, max)) if min < max => RangedCoordf64::from(min..max).key_points(count),
+            Some((min, _)) => vec![min],
+            None => vec![],
+        }
+    }
+
+    /// Get the cells of the grid, i.e. the indices of their lower left corners
+    pub(super) fn cells(&self) -> impl Iterator<Item = (usize, usize)> {
+        let (nx, ny) = (self.xs.len(), self.ys.len());
+        (0..ny.saturating_sub(1)).flat_map(move |j| (0..nx.saturating_sub(1)).map(move |i| (i, j)))
+    }
+
+    /// Get the point on the edge where the linearly interpolated value is the level
+    pub(super) fn edge_point(&self, edge: GridEdge, level: f64) -> (f64, f64) {
+        let ((i0, j0), (i1, j1)) = match edge {
+            GridEdge::Horizontal(i, j) => ((i, j), (i + 1, j)),
+            GridEdge::Vertical(i, j) => ((i, j), (i, j + 1)),
+        };
+        let (v0, v1) = (self.value(i0, j0), self.value(i1, j1));
+        let t = if v1 != v0 {
+            ((level - v0) / (v1 - v0)).clamp(0.0, 1.0)
+        } else {
+            0.5
+        };
+        (
+            self.xs[i0] + (self.xs[i1] - self.xs[i0]) * t,
+            self.ys[j0] + (self.ys[j1] - self.ys[j0]) * t,
+        )
+    }
+
+    /// Run the marching squares over the cells, and get the segments of the iso-line at the
+    /// level, as the pairs of the grid edges they cross
+    fn segments(&self, level: f64) -> Vec<(GridEdge, GridEdge)> {
+        let mut segments = vec![];
+        for (i, j) in self.cells() {
+            let corners = [
+                self.value(i, j),
+                self.value(i + 1, j),
+                self.value(i + 1, j + 1),
+                self.value(i, j + 1),
+            ];
+            if corners.iter().any(|v| !v.is_finite()) {
+                continue;
+            }
+            let case = corners
+                .iter()
+                .enumerate()
+                .filter(|(_, v)| **v >= level)
+                .fold(0, |case, (bit, _)| case | (1 << bit));
+
+            let bottom = GridEdge::Horizontal(i, j);
+            let right = GridEdge::Vertical(i + 1, j);
+            let top = GridEdge::Horizontal(i, j + 1);
+            let left = GridEdge::Vertical(i, j);
+            /* The saddles are resolved by the average of the corners, i.e. the value at the center */
+            let center_above = corners.iter().sum::<f64>() / 4.0 >= level;
+            match case {
+                1 | 14 => segments.push((left, bottom)),
+                2 | 13 => segments.push((bottom, right)),
+                3 | 12 => segments.push((left, right)),
+                4 | 11 => segments.push((right, top)),
+                6 | 9 => segments.push((bottom, top)),
+                7 | 8 => segments.push((top, left)),
+                5 | 10 if center_above == (case == 5) => {
+                    segments.push((bottom, right));
+                    segments.push((top, left));
+                }
+                5 | 10 => {
+                    segments.push((left, bottom));
+                    segments.push((right, top));
+                }
+                _ => {}
+            }
+        }
+        segments
+    }
+
+    /// Get the iso-lines at the level, each of which is either an open polyline ending at the
+    /// border of the grid, or a closed polyline of which the first and the last points are the same
+    pub(super) fn iso_lines(&self, level: f64) -> Vec<Vec<(f64, f64)>> {
+        let segments = self.segments(level);
+        let mut incidence: HashMap<GridEdge, Vec<usize>> = HashMap::new();
+        for (idx, (from, to)) in segments.iter().enumerate() {
+            incidence.entry(*from).or_default().push(idx);
+            incidence.entry(*to).or_default().push(idx);
+        }
+
+        /* Walk from the open ends first, so that the open polylines aren't broken in the middle */
+        let open_ends = segments
+            .iter()
+            .flat_map(|(from, to)| [*from, *to])
+            .filter(|edge| incidence[edge].len() == 1);
+        let all_edges = segments.iter().map(|(from, _)| *from);
+
+        let mut used = vec![false; segments.len()];
+        let mut lines = vec![];
+        for start in open_ends.chain(all_edges).collect::<Vec<_>>() {
+            let mut edge = start;
+            let mut line = vec![self.edge_point(edge, level)];
+            while let Some(idx) = incidence[&edge].iter().copied().find(|idx| !used[*idx]) {
+                used[idx] = true;
+                let (from, to) = segments[idx];
+                edge = if from == edge { to } else { from };
+                line.push(self.edge_point(edge, level));
+            }
+            if line.len() > 1 {
+                lines.push(line);
+            }
+        }
+        lines
+    }
+}
+
+/**
+An iso-line of a [`ContourSeries`], which is a polyline with an optional inline label of its level.
+
+The label is put at the middle of the polyline, and the polyline is cut around the label, so the
+label doesn't overlap the line. The polylines shorter than twice the width of the label are not
+labelled.
+*/
+pub struct ContourLine<'a> {
+    points: Vec<(f64, f64)>,
+    style: ShapeStyle,
+    label: Option<(String, TextStyle<'a>)>,
+}
+
+impl<'a, 'b> PointCollection<'b, (f64, f64)> for &'b ContourLine<'a> {
+    type Point = &'b (f64, f64);
+    type IntoIter = &'b [(f64, f64)];
+    fn point_iter(self) -> &'b [(f64, f64)] {
+        &self.points
+    }
+}
+
+/// Get the point at the distance along the path, and the index of the first point after it
+fn point_along(path: &[BackendCoord], distance: f64) -> (BackendCoord, usize) {
+    let mut remaining = distance;
+    for (idx, window) in path.windows(2).enumerate() {
+        let (a, b) = (window[0], window[1]);
+        let length = f64::from(b.0 - a.0).hypot(f64::from(b.1 - a.1));
+        if remaining <= length && length > 0.0 {
+            let t = remaining / length;
+            let x = f64::from(a.0) + f64::from(b.0 - a.0) * t;
+            let y = f64::from(a.1) + f64::from(b.1 - a.1) * t;
+            return ((x.round() as i32, y.round() as i32), idx + 1);
+        }
+        remaining -= length;
+    }
+    (path[path.len() - 1], path.len())
+}
+
+impl<'a, DB: DrawingBackend> Drawable<DB> for ContourLine<'a> {
+    fn draw<I: Iterator<Item = BackendCoord>>(
+        &self,
+        points: I,
+        backend: &mut DB,
+        _: (u32, u32),
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let path: Vec<_> = points.collect();
+        if let Some((text, style)) = &self.label {
+            let length: f64 = path
+                .windows(2)
+                .map(|w| f64::from(w[1].0 - w[0].0).hypot(f64::from(w[1].1 - w[0].1)))
+                .sum();
+            let (width, _) = backend.estimate_text_size(text, style)?;
+            let gap = f64::from(width) / 2.0 + 2.0;
+            if length >= 2.0 * f64::from(width) {
+                let (cut_start, before) = point_along(&path, length / 2.0 - gap);
+                let (center, _) = point_along(&path, length / 2.0);
+                let (cut_end, after) = point_along(&path, length / 2.0 + gap);
+
+                let mut head = path[..before].to_vec();
+                head.push(cut_start);
+                let mut tail = vec![cut_end];
+                tail.extend_from_slice(&path[after..]);
+                backend.draw_path(head, &self.style)?;
+                backend.draw_path(tail, &self.style)?;
+                return backend.draw_text(text, style, center);
+            }
+        }
+        backend.draw_path(path, &self.style)
+    }
+}
+
+/**
+Presents a scalar field on a grid as the contour lines, i.e. the iso-lines at the chosen levels.
+
+The iso-lines are extracted with the marching squares algorithm, of which the saddle cells are
+resolved by the average value of the cell. The levels are the round numbers within the range of
+the values unless they're set by [`ContourSeries::levels()`], and each level is drawn with its own
+style from [`ContourSeries::styles()`]. The levels can be printed inline on the lines with
+[`ContourSeries::labels()`]. The cells with a non-finite value are skipped.
+
+# Examples
+
+```
+use plotters::prelude::*;
+let drawing_area = SVGBackend::new("contour_series.svg", (300, 300)).into_drawing_area();
+drawing_area.fill(&WHITE).unwrap();
+let mut chart = ChartBuilder::on(&drawing_area)
+    .margin(5)
+    .set_left_and_bottom_label_area_size(20)
+    .build_cartesian_2d(-2.0..2.0, -2.0..2.0)
+    .unwrap();
+chart.configure_mesh().draw().unwrap();
+let steps = (0..=40).map(|v| v as f64 / 10.0 - 2.0);
+chart
+    .draw_series(
+        ContourSeries::new(steps.clone(), steps, |x, y| x * x + y * y)
+            .levels([0.5, 1.0, 2.0, 3.0])
+            .styles([BLUE.stroke_width(2), GREEN.into(), RED.into()])
+            .labels(true),
+    )
+    .unwrap();
+```
+*/
+pub struct ContourSeries<'a> {
+    grid: ScalarGrid,
+    levels: Option<Vec<f64>>,
+    styles: Vec<ShapeStyle>,
+    label_style: Option<TextStyle<'a>>,
+    label_formatter: Option<Box<dyn Fn(f64) -> String + 'a>>,
+    /// The level index to extract next, and the extracted lines of the current level
+    next_level: usize,
+    pending: Vec<Vec<(f64, f64)>>,
+}
+
+impl<'a> ContourSeries<'a> {
+    /**
+    Creates a contour series from the values of a function over a grid.
+
+    - `xs`: The X values of the grid
+    - `ys`: The Y values of the grid
+    - `f`: The scalar field
+    - **returns** The newly created series
+
+    See [`ContourSeries`] for more information and examples.
+    */
+    pub fn new<X, Y, F>(xs: X, ys: Y, f: F) -> Self
+    where
+        X: IntoIterator<Item = f64>,
+        Y: IntoIterator<Item = f64>,
+        F: Fn(f64, f64) -> f64,
+    {
+        let xs: Vec<_> = xs.into_iter().collect();
+        let ys: Vec<_> = ys.into_iter().collect();
+        let rows: Vec<Vec<_>> = ys
+            .iter()
+            .map(|y| xs.iter().map(|x| f(*x, *y)).collect())
+            .collect();
+        Self::from_grid(xs, ys, rows)
+    }
+
+    /**
+    Creates a contour series from the values sampled on a grid.
+
+    - `xs`: The X values of the grid
+    - `ys`: The Y values of the grid
+    - `rows`: The rows of the values, one for each Y value, each of which has one value for
+      each X value. The missing values are treated as non-finite.
+    - **returns** The newly created series
+    */
+    pub fn from_grid<X, Y, V, R, I>(xs: X, ys: Y, rows: I) -> Self
+    where
+        X: IntoIterator<Item = f64>,
+        Y: IntoIterator<Item = f64>,
+        V: Into<f64>,
+        R: IntoIterator<Item = V>,
+        I: IntoIterator<Item = R>,
+    {
+        Self {
+            grid: ScalarGrid::new(xs, ys, rows),
+            levels: None,
+            styles: vec![BLACK.into()],
+            label_style: None,
+            label_formatter: None,
+            next_level: 0,
+            pending: vec![],
+        }
+    }
+
+    /// Set the levels of the iso-lines
+    pub fn levels<I: IntoIterator<Item = f64>>(mut self, levels: I) -> Self {
+        self.levels = Some(levels.into_iter().collect());
+        self
+    }
+
+    /// Set the style of all the levels
+    pub fn style<S: Into<ShapeStyle>>(mut self, style: S) -> Self {
+        self.styles = vec![style.into()];
+        self
+    }
+
+    /// Set the styles of the levels, in the order of the levels. The styles are repeated if there
+    /// are more levels than the styles
+    pub fn styles<S: Into<ShapeStyle>, I: IntoIterator<Item = S>>(mut self, styles: I) -> Self {
+        let styles: Vec<_> = styles.into_iter().map(Into::into).collect();
+        if !styles.is_empty() {
+            self.styles = styles;
+        }
+        self
+    }
+
+    /// Set if the levels are printed inline on the lines
+    pub fn labels(mut self, labels: bool) -> Self {
+        if !labels {
+            self.label_formatter = None;
+        } else if self.label_formatter.is_none() {
+            self.label_formatter = Some(Box::new(|level| pretty_print_float(level, false)));
+        }
+        self
+    }
+
+    /// Set the function which formats the inline labels, which also turns on the labels
+    pub fn label_formatter<F: Fn(f64) -> String + 'a>(mut self, formatter: F) -> Self {
+        self.label_formatter = Some(Box::new(formatter));
+        self
+    }
+
+    /// Set the style of the inline labels, which are black by default
+    pub fn label_style<S: Into<TextStyle<'a>>>(mut self, style: S) -> Self {
+        self.label_style = Some(style.into());
+        self
+    }
+
+    fn level(&mut self, idx: usize) -> Option<f64> {
+        if self.levels.is_none() {
+            self.levels = Some(self.grid.default_levels(10));
+        }
+        self.levels
+            .as_ref()
+            .and_then(|levels| levels.get(idx))
+            .copied()
+    }
+}
+
+impl<'a> Iterator for ContourSeries<'a> {
+    type Item = ContourLine<'a>;
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.pending.is_empty() {
+            let level = self.level(self.next_level)?;
+            self.pending = self.grid.iso_lines(level);
+            self.pending.reverse();
+            self.next_level += 1;
+        }
+        let idx = self.next_level - 1;
+        let level = self.level(idx)?;
+        let label = self.label_formatter.as_ref().map(|formatter| {
+            let style = self
+                .label_style
+                .clone()
+                .unwrap_or_else(|| ("sans-serif", 10).into_font().into());
+            (
+                formatter(level),
+                style.pos(Pos::new(HPos::Center, VPos::Center)),
+            )
+        });
+        Some(ContourLine {
+            points: self.pending.pop()?,
+            style: self.styles[idx % self.styles.len()],
+            label,
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_iso_lines() {
+        let steps: Vec<_> = (0..=4).map(f64::from).collect();
+        let grid = ScalarGrid::new(
+            steps.clone(),
+            steps.clone(),
+            steps
+                .iter()
+                .map(|y| steps.iter().map(move |x| (x - 2.0).abs() + (y - 2.0).abs())),
+        );
+        assert_eq!(grid.value_range(), Some((0.0, 4.0)));
+
+        /* The diamond around the center is a closed line */
+        let lines = grid.iso_lines(1.5);
+        assert_eq!(lines.len(), 1);
+        assert_eq!(lines[0].first(), lines[0].last());
+        for (x, y) in &lines[0] {
+            assert!(((x - 2.0).abs() + (y - 2.0).abs() - 1.5).abs() < 1e-9);
+        }
+
+        /* The level of the corners cuts the corners of the grid as four open lines */
+        let lines = grid.iso_lines(3.5);
+        assert_eq!(lines.len(), 4);
+        assert!(lines.iter().all(|line| line.first() != line.last()));
+        assert!(grid.iso_lines(5.0).is_empty());
+
+        /* A saddle */
+        let grid = ScalarGrid::new([0.0, 1.0], [0.0, 1.0], [[1.0, 0.0], [0.0, 1.0]]);
+        assert_eq!(grid.iso_lines(0.5).len(), 2);
+    }
+
+    #[test]
+    fn test_contour_series() {
+        let drawing_area = create_mocked_drawing_area(200, 200, |m| {
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_path_call, 4);
+                assert_eq!(b.num_draw_text_call, 1);
+            });
+        });
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(-1.0..1.0, -1.0..1.0)
+            .unwrap();
+
+        let steps = (0..=20).map(|v| f64::from(v) / 10.0 - 1.0);
+        let series = ContourSeries::from_grid(steps.clone(), steps, vec![vec![f64::NAN]]);
+        assert_eq!(series.count(), 0);
+
+        let steps = (0..=20).map(|v| f64::from(v) / 10.0 - 1.0);
+        let lines: Vec<_> = ContourSeries::new(steps.clone(), steps.clone(), |x, y| x * x + y * y)
+            .levels([0.25, 0.5])
+            .styles([RED, BLUE])
+            .collect();
+        assert_eq!(lines.len(), 2);
+        assert_eq!(lines[1].style.color, BLUE.to_rgba());
+
+        chart
+            .draw_series(
+                ContourSeries::new(steps.clone(), steps.clone(), |x, y| x * x + y * y)
+                    .levels([0.25, 0.5]),
+            )
+            .unwrap();
+        chart
+            .draw_series(
+                ContourSeries::new(steps.clone(), steps, |x, y| x * x + y * y)
+                    .levels([0.25])
+                    .labels(true),
+            )
+            .unwrap();
+    }
+}
@@ -0,0 +1,317 @@
+use std::ops::Range;
+
+use crate::element::{Drawable, PointCollection};
+use crate::style::text_anchor::{HPos, Pos, VPos};
+use crate::style::{IntoFont, ShapeStyle, TextStyle, BLACK};
+use plotters_backend::{BackendCoord, DrawingBackend, DrawingErrorKind};
+
+/// The gap between a leaf and its label in pixels
+const LABEL_GAP: i32 = 4;
+
+/// The direction of the height axis of a [`DendrogramSeries`]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DendrogramOrientation {
+    /// The leaves go along the X axis and the heights along the Y axis, so the labels are below
+    /// the leaves
+    Vertical,
+    /// The leaves go along the Y axis and the heights along the X axis, so the labels are on the
+    /// left of the leaves
+    Horizontal,
+}
+
+/**
+A link of a [`DendrogramSeries`], which is the bracket joining two clusters at the height of
+their merge, with the labels of the children which are leaves.
+*/
+pub struct DendrogramLink<'a> {
+    /// The first child, the two corners at the height of the merge and the second child
+    points: [(f64, f64); 4],
+    style: ShapeStyle,
+    labels: [Option<String>; 2],
+    label_style: Option<TextStyle<'a>>,
+    orientation: DendrogramOrientation,
+}
+
+impl<'a, 'b> PointCollection<'a, (f64, f64)> for &'a DendrogramLink<'b> {
+    type Point = &'a (f64, f64);
+    type IntoIter = &'a [(f64, f64)];
+    fn point_iter(self) -> &'a [(f64, f64)] {
+        &self.points
+    }
+}
+
+impl<'a, DB: DrawingBackend> Drawable<DB> for DendrogramLink<'a> {
+    fn draw<I: Iterator<Item = BackendCoord>>(
+        &self,
+        points: I,
+        backend: &mut DB,
+        _: (u32, u32),
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let points: Vec<_> = points.take(4).collect();
+        if points.len() < 4 {
+            return Ok(());
+        }
+        backend.draw_path(points.iter().copied(), &self.style)?;
+        if let Some(style) = &self.label_style {
+            let (style, offset) = match self.orientation {
+                DendrogramOrientation::Vertical => {
+                    (style.pos(Pos::new(HPos::Center, VPos::Top)), (0, LABEL_GAP))
+                }
+                DendrogramOrientation::Horizontal => (
+                    style.pos(Pos::new(HPos::Right, VPos::Center)),
+                    (-LABEL_GAP, 0),
+                ),
+            };
+            for (label, point) in self.labels.iter().zip([points[0], points[3]]) {
+                if let Some(label) = label {
+                    let pos = (point.0 + offset.0, point.1 + offset.1);
+                    backend.draw_text(label, &style, pos)?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/**
+Presents a hierarchical clustering as a dendrogram, the tree of the brackets joining the clusters
+at the heights of their merges.
+
+The clustering is given as the list of the merges, in the convention of the linkage matrices: of
+the `n` leaves and the `n - 1` merges, the clusters `0` to `n - 1` are the leaves and the cluster
+`n + i` is the result of the merge `i`. The leaves are placed at the positions `0` to `n - 1` in
+the order which keeps the brackets from crossing, see [`DendrogramSeries::leaf_order()`], and each
+merged cluster is placed at the middle of its two children. The merges referring to a cluster
+which doesn't exist yet or which is already merged are ignored.
+
+# Examples
+
+```
+use plotters::prelude::*;
+let merges = [(0, 3, 0.5), (1, 2, 1.0), (4, 5, 2.5), (6, 7, 3.0)];
+let series = DendrogramSeries::new(merges).labels(["a", "b", "c", "d", "e"]);
+let drawing_area = SVGBackend::new("dendrogram_series.svg", (300, 200)).into_drawing_area();
+drawing_area.fill(&WHITE).unwrap();
+let mut chart = ChartBuilder::on(&drawing_area)
+    .margin(10)
+    .margin_bottom(20)
+    .y_label_area_size(30)
+    .build_cartesian_2d(series.leaf_range(), series.height_range())
+    .unwrap();
+chart
+    .configure_mesh()
+    .disable_x_mesh()
+    .disable_x_axis()
+    .draw()
+    .unwrap();
+chart.draw_series(series).unwrap();
+```
+*/
+pub struct DendrogramSeries<'a> {
+    /// The children and the height of each valid merge
+    merges: Vec<(usize, usize, f64)>,
+    leaves: usize,
+    order: Vec<usize>,
+    /// The position and the height of each cluster
+    nodes: Vec<(f64, f64)>,
+    labels: Vec<String>,
+    style: ShapeStyle,
+    label_style: TextStyle<'a>,
+    orientation: DendrogramOrientation,
+    idx: usize,
+}
+
+impl<'a> DendrogramSeries<'a> {
+    /**
+    Creates a dendrogram series.
+
+    - `merges`: The two clusters and the height of each merge
+    - **returns** The newly created series
+
+    See [`DendrogramSeries`] for more information and examples.
+    */
+    pub fn new<I: IntoIterator<Item = (usize, usize, f64)>>(merges: I) -> Self {
+        let merges: Vec<_> = merges.into_iter().collect();
+        let leaves = merges.len() + 1;
+        let mut merged = vec![false; leaves + merges.len()];
+        let mut valid = vec![];
+        for (idx, (a, b, height)) in merges.into_iter().enumerate() {
+            let exists = |c: usize| c < leaves + idx && !merged[c];
+            if a != b && exists(a) && exists(b) && height.is_finite() {
+                merged[a] = true;
+                merged[b] = true;
+                valid.push((a, b, height));
+            } else {
+                // Keep the numbering of the clusters, the invalid merge makes a cluster merged away
+                merged[leaves + idx] = true;
+                valid.push((usize::MAX, usize::MAX, 0.0));
+            }
+        }
+
+        // Walk the trees from their roots, the roots of the last merges first
+        let mut order = vec![];
+        let mut stack: Vec<_> = (0..merged.len()).filter(|c| !merged[*c]).collect();
+        while let Some(cluster) = stack.pop() {
+            match valid.get(cluster.wrapping_sub(leaves)) {
+                Some((a, b, _)) if cluster >= leaves => stack.extend([*b, *a]),
+                _ => order.push(cluster),
+            }
+        }
+
+        let mut nodes = vec![(0.0, 0.0); merged.len()];
+        for (pos, leaf) in order.iter().enumerate() {
+            nodes[*leaf] = (pos as f64, 0.0);
+        }
+        for (idx, (a, b, height)) in valid.iter().enumerate() {
+            if *a != usize::MAX {
+                nodes[leaves + idx] = ((nodes[*a].0 + nodes[*b].0) / 2.0, *height);
+            }
+        }
+
+        Self {
+            merges: valid,
+            leaves,
+            order,
+            nodes,
+            labels: vec![],
+            style: BLACK.into(),
+            label_style: ("sans-serif", 12).into_font().color(&BLACK),
+            orientation: DendrogramOrientation::Vertical,
+            idx: 0,
+        }
+    }
+
+    /// Set the labels of the leaves in the order of their indices in the merges
+    pub fn labels<L: Into<String>, I: IntoIterator<Item = L>>(mut self, labels: I) -> Self {
+        self.labels = labels.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Set the direction of the height axis, which is [`DendrogramOrientation::Vertical`] by
+    /// default
+    pub fn orientation(mut self, orientation: DendrogramOrientation) -> Self {
+        self.orientation = orientation;
+        self
+    }
+
+    /// Set the style of the brackets
+    pub fn style<S: Into<ShapeStyle>>(mut self, style: S) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Set the style of the labels of the leaves
+    pub fn label_style<S: Into<TextStyle<'a>>>(mut self, style: S) -> Self {
+        self.label_style = style.into();
+        self
+    }
+
+    /// Get the indices of the leaves in the order of their positions
+    pub fn leaf_order(&self) -> &[usize] {
+        &self.order
+    }
+
+    /// Get the range of the positions of the leaves with the margin of a half position at both
+    /// ends, which is the range of the leaf axis fitting the series
+    pub fn leaf_range(&self) -> Range<f64> {
+        -0.5..self.leaves as f64 - 0.5
+    }
+
+    /// Get the range from zero to the highest merge, which is the range of the height axis
+    /// fitting the series
+    pub fn height_range(&self) -> Range<f64> {
+        let max = self
+            .merges
+            .iter()
+            .filter(|(a, _, _)| *a != usize::MAX)
+            .fold(0.0, |max, (_, _, h)| h.max(max));
+        0.0..max
+    }
+
+    fn point(&self, (position, height): (f64, f64)) -> (f64, f64) {
+        match self.orientation {
+            DendrogramOrientation::Vertical => (position, height),
+            DendrogramOrientation::Horizontal => (height, position),
+        }
+    }
+}
+
+impl<'a> Iterator for DendrogramSeries<'a> {
+    type Item = DendrogramLink<'a>;
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some((a, b, height)) = self.merges.get(self.idx).copied() {
+            self.idx += 1;
+            if a == usize::MAX {
+                continue;
+            }
+            let (a_node, b_node) = (self.nodes[a], self.nodes[b]);
+            let label = |c: usize| self.labels.get(c).filter(|_| c < self.leaves).cloned();
+            return Some(DendrogramLink {
+                points: [
+                    self.point(a_node),
+                    self.point((a_node.0, height)),
+                    self.point((b_node.0, height)),
+                    self.point(b_node),
+                ],
+                style: self.style,
+                labels: [label(a), label(b)],
+                label_style: Some(self.label_style.clone()).filter(|_| !self.labels.is_empty()),
+                orientation: self.orientation,
+            });
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_dendrogram_series() {
+        // 0 and 3 merge at 1, then 2 joins them at 2 and 1 joins them all at 3
+        let series = DendrogramSeries::new([(0, 3, 1.0), (2, 4, 2.0), (5, 1, 3.0)]);
+        assert_eq!(series.leaf_order(), [2, 0, 3, 1]);
+        assert_eq!(series.leaf_range(), -0.5..3.5);
+        assert_eq!(series.height_range(), 0.0..3.0);
+        let links: Vec<_> = series.collect();
+        assert_eq!(links.len(), 3);
+        assert_eq!(
+            links[1].points,
+            [(0.0, 0.0), (0.0, 2.0), (1.5, 2.0), (1.5, 1.0)]
+        );
+        assert_eq!(links[2].points[1], (0.75, 3.0));
+        assert!(links[0].label_style.is_none());
+
+        // The second merge refers to a merged cluster, so the last leaf is left alone
+        let series = DendrogramSeries::new([(0, 1, 1.0), (0, 2, 2.0)]);
+        assert_eq!(series.leaf_order(), [0, 1, 2]);
+        assert_eq!(series.count(), 1);
+
+        let drawing_area = create_mocked_drawing_area(101, 101, |m| {
+            m.check_draw_path(|_, _, path| {
+                assert_eq!(path, [(0, 100), (50, 100), (50, 0), (0, 0)]);
+            });
+            m.check_draw_text(|_, _, _, pos, text| match text {
+                "a" => assert_eq!(pos, (-4, 100)),
+                "b" => assert_eq!(pos, (-4, 0)),
+                _ => panic!("Unexpected label {}", text),
+            });
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_path_call, 1);
+                assert_eq!(b.num_draw_text_call, 2);
+            });
+        });
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0.0..2.0, 0.0..1.0)
+            .unwrap();
+        chart
+            .draw_series(
+                DendrogramSeries::new([(0, 1, 1.0)])
+                    .labels(["a", "b"])
+                    .orientation(DendrogramOrientation::Horizontal),
+            )
+            .unwrap();
+    }
+}
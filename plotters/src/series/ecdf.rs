@@ -0,0 +1,235 @@
+use std::cmp::Ordering;
+use std::marker::PhantomData;
+use std::ops::Range;
+
+use crate::element::{DynElement, IntoDynElement, PathElement, Polygon};
+use crate::style::{Color, ShapeStyle, BLUE};
+use plotters_backend::DrawingBackend;
+
+/**
+Presents the distribution of the raw samples as their empirical cumulative distribution function.
+
+The ECDF is the step function of the fraction of the samples which are less than or equal to each
+value, so it rises by `1 / n` at each of the `n` samples from 0 at the smallest sample to 1 at the
+largest one. The steps are extended to [`EcdfSeries::range()`], i.e. the range of the X axis, when
+it's set, and the confidence band of the Dvoretzky-Kiefer-Wolfowitz inequality is drawn under the
+steps when [`EcdfSeries::confidence_band()`] is set.
+
+# Examples
+
+```
+use plotters::prelude::*;
+let drawing_area = SVGBackend::new("ecdf_series.svg", (300, 200)).into_drawing_area();
+drawing_area.fill(&WHITE).unwrap();
+let mut chart = ChartBuilder::on(&drawing_area)
+    .margin(5)
+    .set_left_and_bottom_label_area_size(20)
+    .build_cartesian_2d(0.0..10.0, 0.0..1.0)
+    .unwrap();
+chart.configure_mesh().draw().unwrap();
+chart
+    .draw_series(
+        EcdfSeries::new([1.2, 1.9, 2.1, 2.4, 2.5, 3.1, 3.3, 4.8, 5.0, 5.2])
+            .range(0.0..10.0)
+            .confidence_band(Some(0.95)),
+    )
+    .unwrap();
+chart
+    .draw_series(
+        EcdfSeries::new([3.5, 4.1, 4.4, 5.2, 6.0, 6.3, 7.7])
+            .range(0.0..10.0)
+            .style(RED),
+    )
+    .unwrap();
+```
+*/
+pub struct EcdfSeries<DB: DrawingBackend> {
+    samples: Vec<f64>,
+    range: Option<Range<f64>>,
+    confidence: Option<f64>,
+    style: ShapeStyle,
+    band_style: ShapeStyle,
+    state: u32,
+    _p: PhantomData<DB>,
+}
+
+impl<DB: DrawingBackend> EcdfSeries<DB> {
+    /**
+    Creates an empirical cumulative distribution function series.
+
+    - `samples`: The raw samples, the non-finite samples are ignored
+    - **returns** The newly created series
+
+    See [`EcdfSeries`] for more information and examples.
+    */
+    pub fn new<T: Into<f64>, I: IntoIterator<Item = T>>(samples: I) -> Self {
+        let mut samples: Vec<f64> = samples
+            .into_iter()
+            .map(Into::into)
+            .filter(|v: &f64| v.is_finite())
+            .collect();
+        samples.sort_by(|a, b| a.partial_cmp(b).unwrap_or(Ordering::Equal));
+        Self {
+            samples,
+            range: None,
+            confidence: None,
+            style: BLUE.into(),
+            band_style: BLUE.mix(0.2).filled(),
+            state: 0,
+            _p: PhantomData,
+        }
+    }
+
+    /// Set the range to which the steps are extended below the smallest sample and above the
+    /// largest sample
+    pub fn range(mut self, range: Range<f64>) -> Self {
+        self.range = Some(range);
+        self
+    }
+
+    /// Set the confidence level of the band, i.e. 0.95, `None` hides the band, which is the default
+    pub fn confidence_band(mut self, confidence: Option<f64>) -> Self {
+        self.confidence = confidence.filter(|c| *c > 0.0 && *c < 1.0);
+        self
+    }
+
+    /// Set the style of the steps
+    pub fn style<S: Into<ShapeStyle>>(mut self, style: S) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Set the style of the confidence band
+    pub fn band_style<S: Into<ShapeStyle>>(mut self, style: S) -> Self {
+        self.band_style = style.into();
+        self
+    }
+
+    /// Get the fraction of the samples which are less than or equal to a value
+    pub fn fraction(&self, x: f64) -> f64 {
+        if self.samples.is_empty() {
+            return 0.0;
+        }
+        let count = self.samples.partition_point(|v| *v <= x);
+        count as f64 / self.samples.len() as f64
+    }
+
+    /// Get the half width of the confidence band, which is `sqrt(ln(2 / (1 - confidence)) / 2n)`
+    pub fn band_width(&self) -> Option<f64> {
+        let n = self.samples.len();
+        self.confidence
+            .filter(|_| n > 0)
+            .map(|c| ((2.0 / (1.0 - c)).ln() / (2.0 * n as f64)).sqrt())
+    }
+
+    /// Get the corners of the steps from the bottom left to the top right, shifted by `offset`
+    /// and clamped to `[0, 1]`
+    fn steps(&self, offset: f64) -> Vec<(f64, f64)> {
+        let n = self.samples.len() as f64;
+        let level = |count: usize| (count as f64 / n + offset).clamp(0.0, 1.0);
+        let mut steps = vec![];
+        if let Some(range) = &self.range {
+            steps.push((range.start, level(0)));
+        }
+        let mut idx = 0;
+        while idx < self.samples.len() {
+            let x = self.samples[idx];
+            steps.push((x, level(idx)));
+            idx += self.samples[idx..].partition_point(|v| *v <= x);
+            steps.push((x, level(idx)));
+        }
+        if let Some(range) = &self.range {
+            steps.push((range.end, level(self.samples.len())));
+        }
+        steps
+    }
+
+    /// Get the corners of the steps, which is empty without any sample
+    pub fn points(&self) -> Vec<(f64, f64)> {
+        if self.samples.is_empty() {
+            return vec![];
+        }
+        self.steps(0.0)
+    }
+}
+
+impl<DB: DrawingBackend> Iterator for EcdfSeries<DB> {
+    type Item = DynElement<'static, DB, (f64, f64)>;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.samples.is_empty() {
+            return None;
+        }
+        if self.state == 0 {
+            self.state = 1;
+            if let Some(width) = self.band_width() {
+                let mut band = self.steps(width);
+                band.extend(self.steps(-width).into_iter().rev());
+                return Some(Polygon::new(band, self.band_style).into_dyn());
+            }
+        }
+        if self.state == 1 {
+            self.state = 2;
+            return Some(PathElement::new(self.points(), self.style).into_dyn());
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::drawing::MockedBackend;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_ecdf_series() {
+        let series = EcdfSeries::<MockedBackend>::new([3.0, 1.0, f64::NAN, 2.0, 2.0]);
+        assert_eq!(series.fraction(0.0), 0.0);
+        assert_eq!(series.fraction(2.0), 0.75);
+        assert_eq!(series.fraction(3.0), 1.0);
+        assert_eq!(series.band_width(), None);
+        assert_eq!(
+            series.points(),
+            [
+                (1.0, 0.0),
+                (1.0, 0.25),
+                (2.0, 0.25),
+                (2.0, 0.75),
+                (3.0, 0.75),
+                (3.0, 1.0)
+            ]
+        );
+        assert_eq!(series.count(), 1);
+
+        let series = EcdfSeries::<MockedBackend>::new([1.0, 2.0])
+            .range(0.0..4.0)
+            .confidence_band(Some(0.95));
+        let width = series.band_width().unwrap();
+        assert!((width - (40.0f64.ln() / 4.0).sqrt()).abs() < 1e-12);
+        assert_eq!(series.points()[0], (0.0, 0.0));
+        assert_eq!(series.points()[5], (4.0, 1.0));
+        assert_eq!(series.steps(width)[5], (4.0, 1.0));
+        assert_eq!(series.steps(-width)[0], (0.0, 0.0));
+        assert_eq!(series.count(), 2);
+        assert_eq!(
+            EcdfSeries::<MockedBackend>::new(Vec::<f64>::new()).count(),
+            0
+        );
+
+        let drawing_area = create_mocked_drawing_area(101, 101, |m| {
+            m.check_draw_path(|_, _, points| {
+                assert_eq!(points, [(0, 100), (50, 100), (50, 0), (100, 0)]);
+            });
+            m.drop_check(|b| {
+                assert_eq!(b.num_fill_polygon_call, 0);
+                assert_eq!(b.num_draw_path_call, 1);
+            });
+        });
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0.0..100.0, 0.0..1.0)
+            .unwrap();
+        chart
+            .draw_series(EcdfSeries::new([50.0]).range(0.0..100.0))
+            .unwrap();
+    }
+}
@@ -0,0 +1,259 @@
+use std::ops::{Add, Sub};
+
+use crate::element::{Drawable, PointCollection};
+use crate::style::{ShapeStyle, BLACK};
+use plotters_backend::{BackendCoord, DrawingBackend, DrawingErrorKind};
+
+/// The default width of the caps in pixels
+const DEFAULT_CAP_WIDTH: u32 = 10;
+
+/// The default radius of the markers in pixels
+const DEFAULT_MARKER_SIZE: u32 = 3;
+
+/**
+A point of an [`ErrorBarSeries`] with its error bars, which are drawn as the lines spanning the
+error ranges with the caps at the ends, and a circle marking the point.
+*/
+pub struct ErrorBarPoint<X, Y> {
+    /// The point, followed by the ends of the X error range and the ends of the Y error range
+    points: [(X, Y); 5],
+    has_x: bool,
+    has_y: bool,
+    style: ShapeStyle,
+    cap_width: u32,
+    marker_size: u32,
+}
+
+impl<'a, X, Y> PointCollection<'a, (X, Y)> for &'a ErrorBarPoint<X, Y> {
+    type Point = &'a (X, Y);
+    type IntoIter = &'a [(X, Y)];
+    fn point_iter(self) -> &'a [(X, Y)] {
+        &self.points
+    }
+}
+
+impl<X, Y, DB: DrawingBackend> Drawable<DB> for ErrorBarPoint<X, Y> {
+    fn draw<I: Iterator<Item = BackendCoord>>(
+        &self,
+        points: I,
+        backend: &mut DB,
+        _: (u32, u32),
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let points: Vec<_> = points.take(5).collect();
+        if points.len() < 5 {
+            return Ok(());
+        }
+        let half_cap = self.cap_width as i32 / 2;
+        if self.has_x {
+            backend.draw_line(points[1], points[2], &self.style)?;
+            for end in points[1..3].iter().filter(|_| self.cap_width > 0) {
+                let from = (end.0, end.1 - half_cap);
+                let to = (end.0, end.1 + half_cap);
+                backend.draw_line(from, to, &self.style)?;
+            }
+        }
+        if self.has_y {
+            backend.draw_line(points[3], points[4], &self.style)?;
+            for end in points[3..5].iter().filter(|_| self.cap_width > 0) {
+                let from = (end.0 - half_cap, end.1);
+                let to = (end.0 + half_cap, end.1);
+                backend.draw_line(from, to, &self.style)?;
+            }
+        }
+        if self.marker_size > 0 {
+            backend.draw_circle(points[0], self.marker_size, &self.style, self.style.filled)?;
+        }
+        Ok(())
+    }
+}
+
+/**
+Presents the points with their errors along the X axis, the Y axis or both as error bars.
+
+The errors are given for each point, either symmetric, i.e. the same distance below and above the
+point, or asymmetric, i.e. a pair of the distances below and above the point. The points without
+an error, i.e. beyond the end of the errors, are drawn without the error bars of the axis. Unlike
+[`crate::element::ErrorBar`], which takes the ends of a single error range, the ranges are
+computed from the points and the errors.
+
+# Examples
+
+```
+use plotters::prelude::*;
+let drawing_area = SVGBackend::new("error_bar_series.svg", (300, 200)).into_drawing_area();
+drawing_area.fill(&WHITE).unwrap();
+let mut chart = ChartBuilder::on(&drawing_area)
+    .margin(10)
+    .set_left_and_bottom_label_area_size(20)
+    .build_cartesian_2d(0.0..6.0, 0.0..6.0)
+    .unwrap();
+chart.configure_mesh().draw().unwrap();
+let points = [(1.0, 3.3), (2.0, 2.1), (3.0, 1.5), (4.0, 1.9), (5.0, 1.0)];
+chart
+    .draw_series(
+        ErrorBarSeries::new(points)
+            .y_errors_asymmetric([(0.4, 0.3), (0.2, 0.5), (0.3, 0.3), (0.5, 0.2), (0.1, 0.6)])
+            .x_errors([0.2; 5])
+            .cap_width(6)
+            .style(BLUE.filled()),
+    )
+    .unwrap();
+```
+*/
+pub struct ErrorBarSeries<X, Y> {
+    points: Vec<(X, Y)>,
+    x_ranges: Vec<(X, X)>,
+    y_ranges: Vec<(Y, Y)>,
+    style: ShapeStyle,
+    cap_width: u32,
+    marker_size: u32,
+    index: usize,
+}
+
+impl<X, Y> ErrorBarSeries<X, Y>
+where
+    X: Clone + Add<Output = X> + Sub<Output = X>,
+    Y: Clone + Add<Output = Y> + Sub<Output = Y>,
+{
+    /// Create a new error bar series without any error
+    ///
+    /// - `points`: The points of the series
+    /// - **returns** The newly created series
+    pub fn new<I: IntoIterator<Item = (X, Y)>>(points: I) -> Self {
+        Self {
+            points: points.into_iter().collect(),
+            x_ranges: vec![],
+            y_ranges: vec![],
+            style: BLACK.into(),
+            cap_width: DEFAULT_CAP_WIDTH,
+            marker_size: DEFAULT_MARKER_SIZE,
+            index: 0,
+        }
+    }
+
+    /// Set the symmetric errors along the X axis, one for each point
+    pub fn x_errors<I: IntoIterator<Item = X>>(self, errors: I) -> Self {
+        self.x_errors_asymmetric(errors.into_iter().map(|e| (e.clone(), e)))
+    }
+
+    /// Set the asymmetric errors along the X axis, which are the pairs of the distances to the
+    /// left and to the right of the points, one for each point
+    pub fn x_errors_asymmetric<I: IntoIterator<Item = (X, X)>>(mut self, errors: I) -> Self {
+        self.x_ranges = self
+            .points
+            .iter()
+            .zip(errors)
+            .map(|((x, _), (left, right))| (x.clone() - left, x.clone() + right))
+            .collect();
+        self
+    }
+
+    /// Set the symmetric errors along the Y axis, one for each point
+    pub fn y_errors<I: IntoIterator<Item = Y>>(self, errors: I) -> Self {
+        self.y_errors_asymmetric(errors.into_iter().map(|e| (e.clone(), e)))
+    }
+
+    /// Set the asymmetric errors along the Y axis, which are the pairs of the distances below and
+    /// above the points, one for each point
+    pub fn y_errors_asymmetric<I: IntoIterator<Item = (Y, Y)>>(mut self, errors: I) -> Self {
+        self.y_ranges = self
+            .points
+            .iter()
+            .zip(errors)
+            .map(|((_, y), (below, above))| (y.clone() - below, y.clone() + above))
+            .collect();
+        self
+    }
+
+    /// Set the style of the error bars and the markers, the markers are filled if the style is
+    /// filled
+    pub fn style<S: Into<ShapeStyle>>(mut self, style: S) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Set the width of the caps at the ends of the error bars in pixels, 0 removes the caps
+    pub fn cap_width(mut self, width: u32) -> Self {
+        self.cap_width = width;
+        self
+    }
+
+    /// Set the radius of the markers of the points in pixels, 0 removes the markers
+    pub fn marker_size(mut self, size: u32) -> Self {
+        self.marker_size = size;
+        self
+    }
+}
+
+impl<X: Clone, Y: Clone> Iterator for ErrorBarSeries<X, Y> {
+    type Item = ErrorBarPoint<X, Y>;
+    fn next(&mut self) -> Option<Self::Item> {
+        let (x, y) = self.points.get(self.index)?.clone();
+        let x_range = self.x_ranges.get(self.index).cloned();
+        let y_range = self.y_ranges.get(self.index).cloned();
+        self.index += 1;
+
+        let (has_x, has_y) = (x_range.is_some(), y_range.is_some());
+        let (left, right) = x_range.unwrap_or_else(|| (x.clone(), x.clone()));
+        let (below, above) = y_range.unwrap_or_else(|| (y.clone(), y.clone()));
+        Some(ErrorBarPoint {
+            points: [
+                (x.clone(), y.clone()),
+                (left, y.clone()),
+                (right, y.clone()),
+                (x.clone(), below),
+                (x, above),
+            ],
+            has_x,
+            has_y,
+            style: self.style,
+            cap_width: self.cap_width,
+            marker_size: self.marker_size,
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_error_bar_series() {
+        let bars: Vec<_> = ErrorBarSeries::new([(1.0, 2.0), (2.0, 3.0)])
+            .y_errors_asymmetric([(0.5, 1.0)])
+            .x_errors([0.25, 0.5])
+            .collect();
+        assert_eq!(bars.len(), 2);
+        assert_eq!(bars[0].points[1..3], [(0.75, 2.0), (1.25, 2.0)]);
+        assert_eq!(bars[0].points[3..5], [(1.0, 1.5), (1.0, 3.0)]);
+        assert!(bars[0].has_y);
+        assert!(bars[1].has_x && !bars[1].has_y);
+
+        let drawing_area = create_mocked_drawing_area(100, 100, |m| {
+            m.drop_check(|b| {
+                // The bar without the caps is a single line
+                assert_eq!(b.num_draw_line_call, 10);
+                assert_eq!(b.num_draw_circle_call, 1);
+            });
+        });
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0.0..4.0, 0.0..4.0)
+            .unwrap();
+        chart
+            .draw_series(
+                ErrorBarSeries::new([(1.0, 2.0), (2.0, 3.0)])
+                    .y_errors([0.5, 0.5])
+                    .x_errors([0.5])
+                    .marker_size(0),
+            )
+            .unwrap();
+        chart
+            .draw_series(
+                ErrorBarSeries::new([(3.0, 1.0)])
+                    .y_errors([0.5])
+                    .cap_width(0),
+            )
+            .unwrap();
+    }
+}
@@ -0,0 +1,470 @@
+use std::collections::HashMap;
+
+use super::contour::{GridEdge, ScalarGrid};
+use crate::element::Polygon;
+use crate::style::colors::colormaps::ColorMap;
+use crate::style::{Color, RGBAColor};
+
+/// A vertex of the filled regions, which is either a node of the grid, or the point where a level,
+/// given by its index, crosses an edge of the grid. The adjacent cells share the same vertices on
+/// their common edge, so their regions can be merged.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+enum VertexKey {
+    Node(usize, usize),
+    Crossing(GridEdge, usize),
+}
+
+/// A vertex of a polygon on the grid, with the value of the field at it
+type Vertex = (VertexKey, f64);
+
+/// Get the edge of the grid where the segment between the two vertices of a clipped cell is on
+fn segment_edge(p: VertexKey, q: VertexKey) -> Option<GridEdge> {
+    match (p, q) {
+        (VertexKey::Crossing(edge, _), _) | (_, VertexKey::Crossing(edge, _)) => Some(edge),
+        (VertexKey::Node(i0, j0), VertexKey::Node(i1, j1)) if j0 == j1 => {
+            Some(GridEdge::Horizontal(i0.min(i1), j0))
+        }
+        (VertexKey::Node(i0, j0), VertexKey::Node(_, j1)) => {
+            Some(GridEdge::Vertical(i0, j0.min(j1)))
+        }
+    }
+}
+
+/// Clip the polygon to the part where the value is on the kept side of the threshold, which is
+/// the level of the given index. The new vertices are on the edges of the grid, since the clipped
+/// polygons only have the edges inside the cell at the levels.
+fn clip_polygon(
+    polygon: &[Vertex],
+    (level, threshold): (usize, f64),
+    keep_above: bool,
+) -> Vec<Vertex> {
+    let inside = |v: &Vertex| (v.1 >= threshold) == keep_above || v.1 == threshold;
+    let mut result = vec![];
+    for (idx, p) in polygon.iter().enumerate() {
+        let q = &polygon[(idx + 1) % polygon.len()];
+        if inside(p) {
+            result.push(*p);
+        }
+        /* The vertex at the threshold is the crossing itself, which is already in the result */
+        if inside(p) != inside(q) && p.1 != threshold && q.1 != threshold {
+            if let Some(edge) = segment_edge(p.0, q.0) {
+                result.push((VertexKey::Crossing(edge, level), threshold));
+            }
+        }
+    }
+    result
+}
+
+/// Get the twice of the signed area of the polygon
+fn signed_area(points: &[(f64, f64)]) -> f64 {
+    points
+        .iter()
+        .zip(points.iter().cycle().skip(1))
+        .map(|(p, q)| p.0 * q.1 - q.0 * p.1)
+        .sum()
+}
+
+/// Check if the point is inside of the polygon, by the number of the edges a ray crosses
+fn contains(points: &[(f64, f64)], (x, y): (f64, f64)) -> bool {
+    points
+        .iter()
+        .zip(points.iter().cycle().skip(1))
+        .filter(|(p, q)| (p.1 > y) != (q.1 > y))
+        .filter(|(p, q)| x < p.0 + (y - p.1) / (q.1 - p.1) * (q.0 - p.0))
+        .count()
+        % 2
+        == 1
+}
+
+/// Join the outlines into the loops of the vertices, by following the directed edges
+fn trace_loops(edges: &[(VertexKey, VertexKey)]) -> Vec<Vec<VertexKey>> {
+    let mut outgoing: HashMap<VertexKey, Vec<usize>> = HashMap::new();
+    for (idx, (from, _)) in edges.iter().enumerate() {
+        outgoing.entry(*from).or_default().push(idx);
+    }
+
+    let mut used = vec![false; edges.len()];
+    let mut loops = vec![];
+    for idx in 0..edges.len() {
+        if used[idx] {
+            continue;
+        }
+        let start = edges[idx].0;
+        let mut outline = vec![];
+        let mut next = Some(idx);
+        while let Some(idx) = next {
+            used[idx] = true;
+            let (from, to) = edges[idx];
+            outline.push(from);
+            next = if to == start {
+                None
+            } else {
+                outgoing[&to].iter().copied().find(|idx| !used[*idx])
+            };
+        }
+        loops.push(outline);
+    }
+    loops
+}
+
+/**
+Presents a scalar field on a grid as the filled regions between the consecutive contour levels,
+like the `contourf` plots of matplotlib.
+
+Each connected region between two consecutive levels is filled as a polygon, traced along the
+contour lines and the boundary of the grid, with the holes joined to its outline. The color of a
+region is picked from the colormap at the middle value of the two levels, normalized with the
+first and the last levels. The levels are the round numbers within the range of the values,
+extended to the smallest and the largest values, unless they're set by
+[`FilledContourSeries::levels()`], in which case the values out of the levels are left blank.
+The cells with a non-finite value are also left blank. A [`crate::series::ContourSeries`] over
+the same grid and levels draws the boundaries of the regions.
+
+# Examples
+
+```
+use plotters::prelude::*;
+let drawing_area = SVGBackend::new("filled_contour_series.svg", (300, 300)).into_drawing_area();
+drawing_area.fill(&WHITE).unwrap();
+let mut chart = ChartBuilder::on(&drawing_area)
+    .margin(5)
+    .set_left_and_bottom_label_area_size(20)
+    .build_cartesian_2d(-2.0..2.0, -2.0..2.0)
+    .unwrap();
+let steps = (0..=40).map(|v| v as f64 / 10.0 - 2.0);
+let f = |x: f64, y: f64| (-x * x - y * y).exp();
+chart
+    .draw_series(
+        FilledContourSeries::new(steps.clone(), steps.clone(), f, ViridisRGB)
+            .levels((0..=5).map(|v| v as f64 / 5.0)),
+    )
+    .unwrap();
+chart
+    .draw_series(ContourSeries::new(steps.clone(), steps, f).levels((0..=5).map(|v| v as f64 / 5.0)))
+    .unwrap();
+chart.configure_mesh().draw().unwrap();
+```
+*/
+pub struct FilledContourSeries<'a> {
+    grid: ScalarGrid,
+    levels: Option<Vec<f64>>,
+    colormap: Box<dyn Fn(f64) -> RGBAColor + 'a>,
+    band: usize,
+    pending: Vec<Polygon<(f64, f64)>>,
+}
+
+impl<'a> FilledContourSeries<'a> {
+    /**
+    Creates a filled contour series from the values of a function over a grid.
+
+    - `xs`: The X values of the grid
+    - `ys`: The Y values of the grid
+    - `f`: The scalar field
+    - `colormap`: The colormap from which the colors of the regions are picked
+    - **returns** The newly created series
+
+    See [`FilledContourSeries`] for more information and examples.
+    */
+    pub fn new<X, Y, F, C, M>(xs: X, ys: Y, f: F, colormap: M) -> Self
+    where
+        X: IntoIterator<Item = f64>,
+        Y: IntoIterator<Item = f64>,
+        F: Fn(f64, f64) -> f64,
+        C: Color,
+        M: ColorMap<C, f64> + 'a,
+    {
+        let xs: Vec<_> = xs.into_iter().collect();
+        let ys: Vec<_> = ys.into_iter().collect();
+        let rows: Vec<Vec<_>> = ys
+            .iter()
+            .map(|y| xs.iter().map(|x| f(*x, *y)).collect())
+            .collect();
+        Self::from_grid(xs, ys, rows, colormap)
+    }
+
+    /**
+    Creates a filled contour series from the values sampled on a grid.
+
+    - `xs`: The X values of the grid
+    - `ys`: The Y values of the grid
+    - `rows`: The rows of the values, one for each Y value, each of which has one value for
+      each X value. The missing values are treated as non-finite.
+    - `colormap`: The colormap from which the colors of the regions are picked
+    - **returns** The newly created series
+    */
+    pub fn from_grid<X, Y, V, R, I, C, M>(xs: X, ys: Y, rows: I, colormap: M) -> Self
+    where
+        X: IntoIterator<Item = f64>,
+        Y: IntoIterator<Item = f64>,
+        V: Into<f64>,
+        R: IntoIterator<Item = V>,
+        I: IntoIterator<Item = R>,
+        C: Color,
+        M: ColorMap<C, f64> + 'a,
+    {
+        let grid = ScalarGrid::new(xs, ys, rows);
+        Self {
+            grid,
+            levels: None,
+            colormap: Box::new(move |h| colormap.get_color(h).to_rgba()),
+            band: 0,
+            pending: vec![],
+        }
+    }
+
+    /// Set the levels which bound the filled regions, in the increasing order
+    pub fn levels<I: IntoIterator<Item = f64>>(mut self, levels: I) -> Self {
+        self.levels = Some(levels.into_iter().collect());
+        self
+    }
+
+    /// Get the levels which bound the filled regions, in the increasing order
+    fn band_levels(&mut self) -> &[f64] {
+        if self.levels.is_none() {
+            let mut levels = self.grid.default_levels(10);
+            if let Some((min, max)) = self.grid.value_range() {
+                if levels.first().filter(|first| **first <= min).is_none() {
+                    levels.insert(0, min);
+                }
+                if levels.last().filter(|last| max <= **last).is_none() {
+                    levels.push(max);
+                }
+            }
+            self.levels = Some(levels);
+        }
+        self.levels.as_deref().unwrap_or(&[])
+    }
+
+    /// Get the position of the vertex
+    fn position(&self, key: VertexKey) -> (f64, f64) {
+        let levels = self.levels.as_deref().unwrap_or(&[]);
+        match key {
+            VertexKey::Node(i, j) => (self.grid.xs[i], self.grid.ys[j]),
+            VertexKey::Crossing(edge, level) => self.grid.edge_point(edge, levels[level]),
+        }
+    }
+
+    /// Get the outlines of the regions between the levels of the band, with the index of the
+    /// lower level. The outlines of the cells are clipped to the band, and the edges shared by
+    /// two cells cancel out, which leaves the boundaries of the merged regions.
+    fn band_outlines(&self, band: usize) -> Vec<Vec<(f64, f64)>> {
+        let levels = self.levels.as_deref().unwrap_or(&[]);
+        let grid = &self.grid;
+        let mut edges = vec![];
+        let mut open: HashMap<(VertexKey, VertexKey), Vec<usize>> = HashMap::new();
+        for (i, j) in grid.cells() {
+            let cell: Vec<_> = [(i, j), (i + 1, j), (i + 1, j + 1), (i, j + 1)]
+                .iter()
+                .map(|&(i, j)| (VertexKey::Node(i, j), grid.value(i, j)))
+                .collect();
+            if cell.iter().any(|v| !v.1.is_finite()) {
+                continue;
+            }
+            let lower = clip_polygon(&cell, (band, levels[band]), true);
+            let region = clip_polygon(&lower, (band + 1, levels[band + 1]), false);
+            if region.len() < 3 {
+                continue;
+            }
+            for (idx, from) in region.iter().enumerate() {
+                let edge = (from.0, region[(idx + 1) % region.len()].0);
+                let reversed = open.get_mut(&(edge.1, edge.0)).and_then(|list| list.pop());
+                match reversed {
+                    Some(idx) => edges[idx] = None,
+                    None => {
+                        open.entry(edge).or_default().push(edges.len());
+                        edges.push(Some(edge));
+                    }
+                }
+            }
+        }
+
+        let edges: Vec<_> = edges.into_iter().flatten().collect();
+        trace_loops(&edges)
+            .into_iter()
+            .map(|outline| outline.into_iter().map(|key| self.position(key)).collect())
+            .filter(|points: &Vec<_>| points.len() >= 3)
+            .collect()
+    }
+
+    /// Get the filled regions of the band, with the index of the lower level
+    fn band_regions(&self, band: usize) -> Vec<Polygon<(f64, f64)>> {
+        let levels = self.levels.as_deref().unwrap_or(&[]);
+        let (first, last) = (levels[0], levels[levels.len() - 1]);
+        let (xs, ys) = (&self.grid.xs, &self.grid.ys);
+        /* The outlines have the same orientation as the cells, and the holes have the opposite */
+        let orientation = (xs[xs.len() - 1] - xs[0]) * (ys[ys.len() - 1] - ys[0]);
+        let (mut outers, holes): (Vec<_>, Vec<_>) = self
+            .band_outlines(band)
+            .into_iter()
+            .partition(|points| signed_area(points) * orientation >= 0.0);
+
+        for hole in holes {
+            let container = outers
+                .iter()
+                .enumerate()
+                .filter(|(_, outer)| contains(outer, hole[0]))
+                .map(|(idx, outer)| (idx, signed_area(outer).abs()))
+                .fold(
+                    None,
+                    |found: Option<(usize, f64)>, (idx, area)| match found {
+                        Some((_, smallest)) if smallest <= area => found,
+                        _ => Some((idx, area)),
+                    },
+                );
+            let outer = match container {
+                Some((idx, _)) => &mut outers[idx],
+                None => continue,
+            };
+            /* Join the hole to the outline with a bridge between their closest vertices */
+            let distance = |(i, k): (usize, usize)| {
+                let (dx, dy) = (outer[i].0 - hole[k].0, outer[i].1 - hole[k].1);
+                dx * dx + dy * dy
+            };
+            let mut bridge = (0, 0);
+            for i in 0..outer.len() {
+                for k in 0..hole.len() {
+                    if distance((i, k)) < distance(bridge) {
+                        bridge = (i, k);
+                    }
+                }
+            }
+            let (i, k) = bridge;
+            let joined: Vec<_> = outer[..=i]
+                .iter()
+                .chain(&hole[k..])
+                .chain(&hole[..=k])
+                .chain(&outer[i..])
+                .copied()
+                .collect();
+            *outer = joined;
+        }
+
+        let h = ((levels[band] + levels[band + 1]) / 2.0 - first) / (last - first);
+        let color = (self.colormap)(h);
+        outers
+            .into_iter()
+            .map(|points| Polygon::new(points, color.filled()))
+            .collect()
+    }
+}
+
+impl<'a> Iterator for FilledContourSeries<'a> {
+    type Item = Polygon<(f64, f64)>;
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.pending.is_empty() {
+            let levels = self.band_levels();
+            let bands = if levels.first() < levels.last() {
+                levels.len() - 1
+            } else {
+                0
+            };
+            if self.band >= bands {
+                return None;
+            }
+            self.pending = self.band_regions(self.band);
+            self.pending.reverse();
+            self.band += 1;
+        }
+        self.pending.pop()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::element::PointCollection;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_clip_polygon() {
+        let square: Vec<_> = [(0, 0, 0.0), (1, 0, 1.0), (1, 1, 2.0), (0, 1, 1.0)]
+            .iter()
+            .map(|&(i, j, v)| (VertexKey::Node(i, j), v))
+            .collect();
+        let above = clip_polygon(&square, (0, 1.5), true);
+        assert_eq!(
+            above,
+            [
+                (VertexKey::Crossing(GridEdge::Vertical(1, 0), 0), 1.5),
+                (VertexKey::Node(1, 1), 2.0),
+                (VertexKey::Crossing(GridEdge::Horizontal(0, 1), 0), 1.5),
+            ]
+        );
+        let below = clip_polygon(&square, (0, 1.5), false);
+        assert_eq!(below.len(), 5);
+        assert!(clip_polygon(&square, (0, 3.0), true).is_empty());
+        assert_eq!(clip_polygon(&square, (0, 3.0), false).len(), 4);
+        /* The vertex at the threshold isn't crossed again */
+        assert_eq!(clip_polygon(&square, (0, 1.0), true).len(), 3);
+    }
+
+    #[test]
+    fn test_filled_contour_series() {
+        let regions: Vec<_> = FilledContourSeries::from_grid(
+            [0.0, 1.0, 2.0],
+            [0.0, 1.0],
+            [[0.0, 1.0, 2.0], [1.0, 2.0, 3.0]],
+            ViridisRGB,
+        )
+        .levels([0.0, 1.5, 3.0])
+        .collect();
+        /* Both cells are split by 1.5, and their parts in each band are merged */
+        assert_eq!(regions.len(), 2);
+        let lower = (&regions[0]).point_iter();
+        assert_eq!(lower.len(), 6);
+        assert!(lower.iter().all(|(x, y)| x + y <= 1.5 + 1e-9));
+        let upper = (&regions[1]).point_iter();
+        assert_eq!(upper.len(), 6);
+        assert!(upper.iter().all(|(x, y)| x + y >= 1.5 - 1e-9));
+
+        let regions: Vec<_> = FilledContourSeries::from_grid(
+            [0.0, 1.0],
+            [0.0, 1.0],
+            [[0.0, f64::NAN], [1.0, 2.0]],
+            ViridisRGB,
+        )
+        .collect();
+        assert!(regions.is_empty());
+
+        let drawing_area = create_mocked_drawing_area(100, 100, |m| {
+            m.drop_check(|b| assert_eq!(b.num_fill_polygon_call, 4));
+        });
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0.0..2.0, 0.0..2.0)
+            .unwrap();
+        let steps = (0..=4).map(|v| v as f64 / 2.0);
+        chart
+            .draw_series(
+                FilledContourSeries::new(steps.clone(), steps, |x, y| x + y, ViridisRGB)
+                    .levels([0.0, 1.0, 2.0, 3.0, 4.0]),
+            )
+            .unwrap();
+    }
+
+    #[test]
+    fn test_filled_contour_holes() {
+        let steps = (0..=4).map(|v| v as f64 - 2.0);
+        let regions: Vec<_> = FilledContourSeries::new(
+            steps.clone(),
+            steps,
+            |x, y| x.abs().max(y.abs()),
+            ViridisRGB,
+        )
+        .levels([0.0, 1.5, 2.0])
+        .collect();
+        assert_eq!(regions.len(), 2);
+
+        /* The inner square with the cut corners, merged from the cells around the center */
+        let inner = (&regions[0]).point_iter();
+        assert!(inner
+            .iter()
+            .all(|(x, y)| x.abs().max(y.abs()) <= 1.5 + 1e-9));
+        assert_eq!(signed_area(inner), 2.0 * 8.5);
+
+        /* The ring around it, as the outline joined with the hole */
+        let ring = (&regions[1]).point_iter();
+        assert!(ring.iter().all(|(x, y)| x.abs().max(y.abs()) >= 1.5 - 1e-9));
+        assert!(ring.contains(&(-2.0, -2.0)) && ring.contains(&(1.5, 1.0)));
+        assert_eq!(signed_area(ring), 2.0 * (16.0 - 8.5));
+    }
+}
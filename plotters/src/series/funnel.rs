@@ -0,0 +1,295 @@
+use std::ops::Range;
+
+use crate::element::{Drawable, PointCollection};
+use crate::style::text_anchor::{HPos, Pos, VPos};
+use crate::style::{Color, IntoFont, Palette, Palette99, ShapeStyle, TextStyle, BLACK};
+use plotters_backend::{BackendCoord, DrawingBackend, DrawingErrorKind};
+
+/// The shape of the stages of a [`FunnelSeries`]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FunnelShape {
+    /// The stages are the trapezoids narrowing from their own values to the values of the next
+    /// stages, and the last stage is a rectangle
+    Trapezoid,
+    /// The stages are the rectangles of their own values
+    Bar,
+}
+
+/// The base of the conversion percentages of a [`FunnelSeries`]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FunnelConversion {
+    /// The percentages are relative to the first stage
+    OfFirst,
+    /// The percentages are relative to the previous stage, and the first stage is 100%
+    OfPrevious,
+}
+
+/**
+A stage of a [`FunnelSeries`], which is a filled trapezoid or rectangle with the label and the
+conversion percentage centered in it.
+*/
+pub struct FunnelStage<'a> {
+    /// The upper left, the upper right, the lower right and the lower left corners
+    points: [(f64, f64); 4],
+    style: ShapeStyle,
+    label: String,
+    annotation: Option<String>,
+    label_style: Option<TextStyle<'a>>,
+}
+
+impl<'a, 'b> PointCollection<'a, (f64, f64)> for &'a FunnelStage<'b> {
+    type Point = &'a (f64, f64);
+    type IntoIter = &'a [(f64, f64)];
+    fn point_iter(self) -> &'a [(f64, f64)] {
+        &self.points
+    }
+}
+
+impl<'a, DB: DrawingBackend> Drawable<DB> for FunnelStage<'a> {
+    fn draw<I: Iterator<Item = BackendCoord>>(
+        &self,
+        points: I,
+        backend: &mut DB,
+        _: (u32, u32),
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let mut points: Vec<_> = points.take(4).collect();
+        if points.len() < 4 {
+            return Ok(());
+        }
+        let center = (
+            points.iter().map(|p| p.0).sum::<i32>() / 4,
+            points.iter().map(|p| p.1).sum::<i32>() / 4,
+        );
+        if self.style.filled {
+            backend.fill_polygon(points, &self.style)?;
+        } else {
+            points.push(points[0]);
+            backend.draw_path(points, &self.style)?;
+        }
+
+        let style = match &self.label_style {
+            Some(style) => style.pos(Pos::new(HPos::Center, VPos::Center)),
+            None => return Ok(()),
+        };
+        let lines: Vec<&str> = std::iter::once(self.label.as_str())
+            .chain(self.annotation.as_deref())
+            .filter(|line| !line.is_empty())
+            .collect();
+        let line_height = backend.estimate_text_size("0", &style)?.1 as i32;
+        let mut y = center.1 - line_height * (lines.len() as i32 - 1) / 2;
+        for line in lines {
+            backend.draw_text(line, &style, (center.0, y))?;
+            y += line_height;
+        }
+        Ok(())
+    }
+}
+
+/**
+Presents the ordered stages of a process as a funnel, i.e. the sales or the analytics funnels.
+
+Each stage is a label with a value, and the stages are drawn from the top to the bottom as the
+shapes centered on the X axis with the widths of the values, so the funnel narrows as the values
+drop. The stages are labelled with the percentages of the conversion, and they are styled with
+the colors of [`Palette99`] by default.
+
+The stages use the coordinates `(x, y)` where the X axis is centered at 0 and the stages are one
+unit high, so the chart fits the funnel with [`FunnelSeries::x_range()`] and
+[`FunnelSeries::y_range()`].
+
+# Examples
+
+```
+use plotters::prelude::*;
+let drawing_area = SVGBackend::new("funnel.svg", (300, 250)).into_drawing_area();
+drawing_area.fill(&WHITE).unwrap();
+let funnel = FunnelSeries::new([
+    ("Visits", 1000.0),
+    ("Sign-ups", 420.0),
+    ("Trials", 180.0),
+    ("Purchases", 65.0),
+])
+.conversion(Some(FunnelConversion::OfPrevious));
+let mut chart = ChartBuilder::on(&drawing_area)
+    .margin(10)
+    .build_cartesian_2d(funnel.x_range(), funnel.y_range())
+    .unwrap();
+chart.draw_series(funnel).unwrap();
+```
+*/
+pub struct FunnelSeries<'a> {
+    stages: Vec<(String, f64)>,
+    shape: FunnelShape,
+    conversion: Option<FunnelConversion>,
+    styles: Vec<ShapeStyle>,
+    label_style: Option<TextStyle<'a>>,
+    gap: f64,
+    index: usize,
+}
+
+impl<'a> FunnelSeries<'a> {
+    /**
+    Creates a funnel series.
+
+    - `stages`: The labels and the values of the stages, from the top to the bottom
+    - **returns** The newly created series
+
+    See [`FunnelSeries`] for more information and examples.
+    */
+    pub fn new<L: Into<String>, I: IntoIterator<Item = (L, f64)>>(stages: I) -> Self {
+        Self {
+            stages: stages
+                .into_iter()
+                .map(|(label, value)| (label.into(), value.max(0.0)))
+                .collect(),
+            shape: FunnelShape::Trapezoid,
+            conversion: Some(FunnelConversion::OfFirst),
+            styles: vec![],
+            label_style: Some(("sans-serif", 12).into_font().color(&BLACK)),
+            gap: 0.1,
+            index: 0,
+        }
+    }
+
+    /// Set the shape of the stages, which is [`FunnelShape::Trapezoid`] by default
+    pub fn shape(mut self, shape: FunnelShape) -> Self {
+        self.shape = shape;
+        self
+    }
+
+    /// Set the base of the conversion percentages, which is [`FunnelConversion::OfFirst`] by
+    /// default, `None` hides the percentages
+    pub fn conversion(mut self, conversion: Option<FunnelConversion>) -> Self {
+        self.conversion = conversion;
+        self
+    }
+
+    /// Set the styles of the stages from the top to the bottom, the stages without a style use the
+    /// colors of [`Palette99`]
+    pub fn styles<S: Into<ShapeStyle>, I: IntoIterator<Item = S>>(mut self, styles: I) -> Self {
+        self.styles = styles.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Set the style of the labels and the percentages, `None` hides them
+    pub fn label_style<S: Into<TextStyle<'a>>>(mut self, style: Option<S>) -> Self {
+        self.label_style = style.map(Into::into);
+        self
+    }
+
+    /// Set the gap between the stages as a fraction of their height, which is 0.1 by default
+    pub fn gap(mut self, gap: f64) -> Self {
+        self.gap = gap.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Get the range of the X axis which fits the widest stage
+    pub fn x_range(&self) -> Range<f64> {
+        let widest = self.stages.iter().map(|(_, v)| *v).fold(0.0, f64::max);
+        let half = if widest > 0.0 { widest / 2.0 } else { 1.0 };
+        -half..half
+    }
+
+    /// Get the range of the Y axis which fits the stages
+    pub fn y_range(&self) -> Range<f64> {
+        0.0..self.stages.len().max(1) as f64
+    }
+}
+
+impl<'a> Iterator for FunnelSeries<'a> {
+    type Item = FunnelStage<'a>;
+    fn next(&mut self) -> Option<Self::Item> {
+        let (label, value) = self.stages.get(self.index)?.clone();
+        let index = self.index;
+        self.index += 1;
+
+        let bottom_value = match self.shape {
+            FunnelShape::Trapezoid => self.stages.get(index + 1).map_or(value, |(_, v)| *v),
+            FunnelShape::Bar => value,
+        };
+        let top = (self.stages.len() - index) as f64 - self.gap / 2.0;
+        let bottom = top - 1.0 + self.gap;
+        let base = match self.conversion {
+            Some(FunnelConversion::OfFirst) => Some(self.stages[0].1),
+            Some(FunnelConversion::OfPrevious) => Some(self.stages[index.max(1) - 1].1),
+            None => None,
+        };
+        let annotation = base.map(|base| {
+            let percentage = if base > 0.0 {
+                value / base * 100.0
+            } else {
+                0.0
+            };
+            format!("{:.1}%", percentage)
+        });
+        Some(FunnelStage {
+            points: [
+                (-value / 2.0, top),
+                (value / 2.0, top),
+                (bottom_value / 2.0, bottom),
+                (-bottom_value / 2.0, bottom),
+            ],
+            style: self
+                .styles
+                .get(index)
+                .copied()
+                .unwrap_or_else(|| Palette99::pick(index).filled()),
+            label,
+            annotation,
+            label_style: self.label_style.clone(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_funnel_series() {
+        let funnel = FunnelSeries::new([("a", 100.0), ("b", 50.0), ("c", 10.0)]).gap(0.0);
+        assert_eq!(funnel.x_range(), -50.0..50.0);
+        assert_eq!(funnel.y_range(), 0.0..3.0);
+        let stages: Vec<_> = funnel
+            .conversion(Some(FunnelConversion::OfPrevious))
+            .collect();
+        assert_eq!(
+            stages[0].points,
+            [(-50.0, 3.0), (50.0, 3.0), (25.0, 2.0), (-25.0, 2.0)]
+        );
+        assert_eq!(stages[2].points[2], (5.0, 0.0));
+        let annotations: Vec<_> = stages.iter().map(|s| s.annotation.clone()).collect();
+        assert_eq!(
+            annotations,
+            [
+                Some("100.0%".to_string()),
+                Some("50.0%".to_string()),
+                Some("20.0%".to_string())
+            ]
+        );
+
+        let drawing_area = create_mocked_drawing_area(100, 100, |m| {
+            m.check_draw_text(|_, _, _, _, text| assert!(text == "a" || text == "100.0%"));
+            m.drop_check(|b| {
+                assert_eq!(b.num_fill_polygon_call, 2);
+                assert_eq!(b.num_draw_path_call, 1);
+                assert_eq!(b.num_draw_text_call, 2);
+            });
+        });
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(-1.0..1.0, 0.0..2.0)
+            .unwrap();
+        chart
+            .draw_series(
+                FunnelSeries::new([("a", 1.0), ("b", 0.5)])
+                    .styles([RED.filled(), BLUE.stroke_width(1)])
+                    .label_style(None::<TextStyle>)
+                    .conversion(None),
+            )
+            .unwrap();
+        chart
+            .draw_series(FunnelSeries::new([("a", 1.0)]).shape(FunnelShape::Bar))
+            .unwrap();
+    }
+}
@@ -0,0 +1,409 @@
+use crate::chart::ChartContext;
+use crate::coord::cartesian::Cartesian2d;
+use crate::coord::ranged1d::{DiscreteRanged, Ranged};
+use crate::element::{Drawable, PointCollection};
+use crate::style::{Color, ShapeStyle, BLACK, BLUE};
+use plotters_backend::{BackendCoord, DrawingBackend, DrawingErrorKind};
+
+/// The size of the arrow heads of the dependencies in pixels
+const ARROW_HEAD_SIZE: i32 = 6;
+
+/**
+A task of a [`GanttSeries`], which spans from the start to the end on a row.
+
+The task is drawn with the style of the series unless it has its own style, and the fraction of
+the task which is done is filled with the progress style of the series.
+*/
+#[derive(Clone, Debug)]
+pub struct GanttTask<R, X> {
+    row: R,
+    start: X,
+    end: X,
+    style: Option<ShapeStyle>,
+    progress: Option<f64>,
+}
+
+impl<R, X> GanttTask<R, X> {
+    /// Create a new task
+    ///
+    /// - `row`: The row of the task
+    /// - `start`: The start of the task
+    /// - `end`: The end of the task
+    /// - **returns** The newly created task
+    pub fn new(row: R, start: X, end: X) -> Self {
+        Self {
+            row,
+            start,
+            end,
+            style: None,
+            progress: None,
+        }
+    }
+
+    /// Set the style of the bar of the task, which overrides the style of the series
+    pub fn style<S: Into<ShapeStyle>>(mut self, style: S) -> Self {
+        self.style = Some(style.into());
+        self
+    }
+
+    /// Set the fraction of the task which is done, from 0.0 to 1.0
+    pub fn progress(mut self, fraction: f64) -> Self {
+        self.progress = Some(fraction.clamp(0.0, 1.0));
+        self
+    }
+}
+
+impl<R, X> From<(R, X, X)> for GanttTask<R, X> {
+    fn from((row, start, end): (R, X, X)) -> Self {
+        Self::new(row, start, end)
+    }
+}
+
+enum GanttShape {
+    Bar { progress: Option<(f64, ShapeStyle)> },
+    Dependency,
+}
+
+/**
+An element of a [`GanttSeries`], which is either the bar of a task or the arrow of a dependency.
+
+The bar is a rectangle with the margin on the top and the bottom, and the done fraction of it is
+filled with the progress style. The arrow goes from the middle of the end of a task to the middle
+of the start of the other task, turning halfway between them.
+*/
+pub struct GanttElement<X, Y> {
+    points: Vec<(X, Y)>,
+    style: ShapeStyle,
+    margin: u32,
+    shape: GanttShape,
+}
+
+impl<'a, X, Y> PointCollection<'a, (X, Y)> for &'a GanttElement<X, Y> {
+    type Point = &'a (X, Y);
+    type IntoIter = &'a [(X, Y)];
+    fn point_iter(self) -> &'a [(X, Y)] {
+        &self.points
+    }
+}
+
+impl<X, Y, DB: DrawingBackend> Drawable<DB> for GanttElement<X, Y> {
+    fn draw<I: Iterator<Item = BackendCoord>>(
+        &self,
+        points: I,
+        backend: &mut DB,
+        _: (u32, u32),
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let points: Vec<_> = points.collect();
+        match &self.shape {
+            GanttShape::Bar { progress } => {
+                if points.len() < 2 {
+                    return Ok(());
+                }
+                let (a, b) = (points[0], points[1]);
+                let margin = self.margin as i32;
+                let from = (a.0.min(b.0), a.1.min(b.1) + margin);
+                let to = (a.0.max(b.0), a.1.max(b.1) - margin);
+                backend.draw_rect(from, to, &self.style, self.style.filled)?;
+                if let Some((fraction, style)) = progress {
+                    let width = f64::from(to.0 - from.0) * fraction;
+                    let done = (from.0 + width.round() as i32, to.1);
+                    if done.0 > from.0 {
+                        backend.draw_rect(from, done, style, true)?;
+                    }
+                }
+                Ok(())
+            }
+            GanttShape::Dependency => {
+                if points.len() < 4 {
+                    return Ok(());
+                }
+                let from = (points[0].0, (points[0].1 + points[1].1) / 2);
+                let to = (points[2].0, (points[2].1 + points[3].1) / 2);
+                let turn = (from.0 + to.0) / 2;
+                let base = (to.0 - ARROW_HEAD_SIZE, to.1);
+                backend.draw_path(vec![from, (turn, from.1), (turn, to.1), base], &self.style)?;
+                let half = ARROW_HEAD_SIZE / 2;
+                backend.fill_polygon(
+                    vec![to, (base.0, to.1 - half), (base.0, to.1 + half)],
+                    &self.style.color.filled(),
+                )
+            }
+        }
+    }
+}
+
+/**
+Presents the tasks of a schedule as a Gantt chart, i.e. horizontal bars on the rows of the tasks
+along the time axis.
+
+The rows are the categories on the Y axis, and the X axis is usually a date or a time axis, but
+any axis works. Each task is a [`GanttTask`], or a tuple of the row, the start and the end. The
+tasks can be styled one by one and can show the fraction which is done. The dependencies between
+the tasks are drawn as the arrows from the end of a task to the start of the task depending on
+it, which are drawn over the bars.
+
+# Examples
+
+```
+use chrono::NaiveDate;
+use plotters::prelude::*;
+let date = |m, d| NaiveDate::from_ymd_opt(2024, m, d).unwrap();
+let drawing_area = SVGBackend::new("gantt.svg", (400, 200)).into_drawing_area();
+drawing_area.fill(&WHITE).unwrap();
+let mut chart = ChartBuilder::on(&drawing_area)
+    .margin(5)
+    .set_left_and_bottom_label_area_size(20)
+    .build_cartesian_2d(date(1, 1)..date(3, 31), (0..2).into_segmented())
+    .unwrap();
+chart.configure_mesh().draw().unwrap();
+chart
+    .draw_series(
+        GanttSeries::new(&chart)
+            .tasks([
+                GanttTask::new(0, date(1, 8), date(2, 5)).progress(1.0),
+                GanttTask::new(1, date(2, 5), date(3, 4)).progress(0.4),
+                GanttTask::new(2, date(2, 19), date(3, 25)).style(RED.mix(0.4).filled()),
+            ])
+            .dependencies([(0, 1), (0, 2)]),
+    )
+    .unwrap();
+```
+*/
+pub struct GanttSeries<X, BR: DiscreteRanged> {
+    br: BR,
+    margin: u32,
+    style: ShapeStyle,
+    progress_style: ShapeStyle,
+    dependency_style: ShapeStyle,
+    /// The tasks by the task index, which are `None` if the rows are out of the range of the axis
+    tasks: Vec<Option<GanttTask<usize, X>>>,
+    dependencies: Vec<(usize, usize)>,
+    index: usize,
+}
+
+impl<X, BR> GanttSeries<X, BR>
+where
+    X: Clone,
+    BR: DiscreteRanged + Clone,
+{
+    /**
+    Creates a Gantt series with the rows on the Y axis.
+
+    See [`GanttSeries`] for more information and examples.
+    */
+    pub fn new<XCoord, DB: DrawingBackend>(
+        parent: &ChartContext<DB, Cartesian2d<XCoord, BR>>,
+    ) -> Self
+    where
+        XCoord: Ranged<ValueType = X>,
+    {
+        Self {
+            br: parent.as_coord_spec().y_spec().clone(),
+            margin: 5,
+            style: BLUE.mix(0.4).filled(),
+            progress_style: BLUE.filled(),
+            dependency_style: BLACK.into(),
+            tasks: vec![],
+            dependencies: vec![],
+            index: 0,
+        }
+    }
+
+    /**
+    Sets the style of the bars of the tasks without their own styles.
+    */
+    pub fn style<S: Into<ShapeStyle>>(mut self, style: S) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /**
+    Sets the style of the done fractions of the tasks, which is always filled.
+    */
+    pub fn progress_style<S: Into<ShapeStyle>>(mut self, style: S) -> Self {
+        self.progress_style = style.into();
+        self
+    }
+
+    /**
+    Sets the style of the arrows of the dependencies.
+    */
+    pub fn dependency_style<S: Into<ShapeStyle>>(mut self, style: S) -> Self {
+        self.dependency_style = style.into();
+        self
+    }
+
+    /**
+    Sets the margin for each bar on the top and the bottom, in backend pixels.
+    */
+    pub fn margin(mut self, value: u32) -> Self {
+        self.margin = value;
+        self
+    }
+
+    /**
+    Specifies the tasks of the chart.
+
+    The tasks are indexed in the order of the iterator, which are used by
+    [`GanttSeries::dependencies()`]. The tasks on a row out of the range of the axis are ignored.
+    */
+    pub fn tasks<TB, T, I>(mut self, iter: I) -> Self
+    where
+        TB: Into<BR::ValueType>,
+        T: Into<GanttTask<TB, X>>,
+        I: IntoIterator<Item = T>,
+    {
+        let br = &self.br;
+        self.tasks = iter
+            .into_iter()
+            .map(|task| {
+                let task = task.into();
+                let row = br
+                    .index_of(&task.row.into())
+                    .filter(|row| *row < br.size())?;
+                Some(GanttTask {
+                    row,
+                    start: task.start,
+                    end: task.end,
+                    style: task.style,
+                    progress: task.progress,
+                })
+            })
+            .collect();
+        self
+    }
+
+    /**
+    Specifies the dependencies between the tasks.
+
+    Each dependency is a pair of the task indices, of which the second task depends on the first
+    one, and it's drawn as an arrow from the end of the first task to the start of the second one.
+    */
+    pub fn dependencies<I: IntoIterator<Item = (usize, usize)>>(mut self, iter: I) -> Self {
+        self.dependencies = iter.into_iter().collect();
+        self
+    }
+
+    fn task(&self, index: usize) -> Option<&GanttTask<usize, X>> {
+        self.tasks.get(index)?.as_ref()
+    }
+
+    /// Get the element of the bar or the dependency with the given index, the bars go first
+    fn element(&self, index: usize) -> Option<GanttElement<X, BR::ValueType>> {
+        if let Some(task) = self.tasks.get(index) {
+            let task = task.as_ref()?;
+            let (row, next_row) = (
+                self.br.from_index(task.row)?,
+                self.br.from_index(task.row + 1)?,
+            );
+            let progress_style = self.progress_style;
+            return Some(GanttElement {
+                points: vec![(task.start.clone(), row), (task.end.clone(), next_row)],
+                style: task.style.unwrap_or(self.style),
+                margin: self.margin,
+                shape: GanttShape::Bar {
+                    progress: task.progress.map(|fraction| (fraction, progress_style)),
+                },
+            });
+        }
+        let (from, to) = self.dependencies.get(index - self.tasks.len())?;
+        let (from, to) = (self.task(*from)?, self.task(*to)?);
+        Some(GanttElement {
+            points: vec![
+                (from.end.clone(), self.br.from_index(from.row)?),
+                (from.end.clone(), self.br.from_index(from.row + 1)?),
+                (to.start.clone(), self.br.from_index(to.row)?),
+                (to.start.clone(), self.br.from_index(to.row + 1)?),
+            ],
+            style: self.dependency_style,
+            margin: 0,
+            shape: GanttShape::Dependency,
+        })
+    }
+}
+
+impl<X, BR> Iterator for GanttSeries<X, BR>
+where
+    X: Clone,
+    BR: DiscreteRanged + Clone,
+{
+    type Item = GanttElement<X, BR::ValueType>;
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.index < self.tasks.len() + self.dependencies.len() {
+            self.index += 1;
+            if let Some(element) = self.element(self.index - 1) {
+                return Some(element);
+            }
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_gantt_series() {
+        let drawing_area = create_mocked_drawing_area(100, 100, |_| {});
+        let chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0.0..10.0, (0..1).into_segmented())
+            .unwrap();
+
+        let elements: Vec<_> = GanttSeries::new(&chart)
+            .tasks([
+                GanttTask::new(0, 1.0, 3.0).progress(1.5),
+                GanttTask::new(5, 2.0, 4.0),
+                GanttTask::new(1, 3.0, 6.0).style(RED),
+            ])
+            .dependencies([(0, 2), (0, 1), (2, 7)])
+            .collect();
+        assert_eq!(elements.len(), 3);
+        assert_eq!(elements[0].points[0].0, 1.0);
+        assert!(matches!(elements[0].points[0].1, SegmentValue::Exact(0)));
+        assert!(matches!(
+            elements[0].shape,
+            GanttShape::Bar {
+                progress: Some((p, _))
+            } if p == 1.0
+        ));
+        assert_eq!(elements[1].style.color, RED.to_rgba());
+        assert_eq!(elements[2].points[0].0, 3.0);
+        assert_eq!(elements[2].points[2].0, 3.0);
+        assert!(matches!(elements[2].shape, GanttShape::Dependency));
+    }
+
+    #[test]
+    fn test_draw_gantt_series() {
+        let drawing_area = create_mocked_drawing_area(101, 101, |m| {
+            m.check_draw_rect(|c, _, filled, from, to| {
+                assert!(filled);
+                if c == BLUE.to_rgba() {
+                    assert_eq!((from.0, to.0), (10, 30));
+                }
+            });
+            m.check_draw_path(|_, _, points| {
+                assert_eq!(points, [(50, 75), (55, 75), (55, 25), (54, 25)]);
+            });
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_rect_call, 3);
+                assert_eq!(b.num_draw_path_call, 1);
+                assert_eq!(b.num_fill_polygon_call, 1);
+            });
+        });
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0.0..100.0, (0..1).into_segmented())
+            .unwrap();
+        chart
+            .draw_series(
+                GanttSeries::new(&chart)
+                    .tasks([
+                        GanttTask::new(0, 10.0, 50.0).progress(0.5),
+                        GanttTask::new(1, 60.0, 90.0),
+                    ])
+                    .dependencies([(0, 1)]),
+            )
+            .unwrap();
+    }
+}
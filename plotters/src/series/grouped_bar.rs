@@ -0,0 +1,330 @@
+use std::collections::btree_map::IntoIter as BTreeMapIter;
+use std::collections::BTreeMap;
+use std::marker::PhantomData;
+use std::ops::Add;
+
+use super::histogram::{HistogramType, Horizontal, Vertical};
+use super::layers::{accumulate_columns, layer_legends, layer_style};
+use crate::chart::ChartContext;
+use crate::coord::cartesian::Cartesian2d;
+use crate::coord::ranged1d::{DiscreteRanged, Ranged};
+use crate::coord::CoordTranslate;
+use crate::element::Rectangle;
+use crate::style::ShapeStyle;
+use plotters_backend::DrawingBackend;
+
+/**
+Presents multiple value columns per category as bars side by side.
+
+Each data item is a category with one value per group, and the slot of each category is shared by
+the bars of all the groups, in the order of the values. The bar width is computed from the width
+of the slot, so the bars of a category are centered on the slot, and thus on the label of the
+category of a segmented or categorical coordinate. The groups are styled with the colors of
+[`crate::style::Palette99`] by default, and [`ChartContext::draw_grouped_series()`] adds a legend
+entry for each labelled group.
+
+# Examples
+
+```
+use plotters::prelude::*;
+let drawing_area = SVGBackend::new("grouped_bar.svg", (300, 200)).into_drawing_area();
+drawing_area.fill(&WHITE).unwrap();
+let mut chart = ChartBuilder::on(&drawing_area)
+    .margin(5)
+    .set_left_and_bottom_label_area_size(20)
+    .build_cartesian_2d((1..3).into_segmented(), 0..6)
+    .unwrap();
+chart.configure_mesh().draw().unwrap();
+chart
+    .draw_grouped_series(
+        GroupedBarSeries::vertical(&chart)
+            .styles([RED.filled(), BLUE.filled()])
+            .labels(["2023", "2024"])
+            .margin(10)
+            .spacing(2)
+            .data([(1, [3, 4]), (2, [2, 5]), (3, [4, 1])]),
+    )
+    .unwrap();
+chart.configure_series_labels().draw().unwrap();
+```
+*/
+pub struct GroupedBarSeries<BR, A, Tag = Vertical>
+where
+    BR: DiscreteRanged,
+    Tag: HistogramType,
+{
+    br: BR,
+    slot_size: u32,
+    margin: u32,
+    spacing: u32,
+    baseline: A,
+    styles: Vec<ShapeStyle>,
+    labels: Vec<String>,
+    groups: usize,
+    iter: BTreeMapIter<usize, Vec<A>>,
+    /// The bars of the current category which are not yet emitted, in reversed order
+    pending: Vec<(usize, usize, A)>,
+    _p: PhantomData<Tag>,
+}
+
+impl<BR, A, Tag> GroupedBarSeries<BR, A, Tag>
+where
+    BR: DiscreteRanged + Clone,
+    A: Add<A, Output = A> + Default + Clone,
+    Tag: HistogramType,
+{
+    fn empty(br: &BR, slot_size: u32) -> Self {
+        Self {
+            br: br.clone(),
+            slot_size,
+            margin: 5,
+            spacing: 0,
+            baseline: A::default(),
+            styles: vec![],
+            labels: vec![],
+            groups: 0,
+            iter: BTreeMap::new().into_iter(),
+            pending: vec![],
+            _p: PhantomData,
+        }
+    }
+
+    /**
+    Sets the styles of the groups, in the order of the values of each category.
+
+    The groups without a style use the colors of [`crate::style::Palette99`].
+    */
+    pub fn styles<S: Into<ShapeStyle>, I: IntoIterator<Item = S>>(mut self, styles: I) -> Self {
+        self.styles = styles.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /**
+    Sets the labels of the groups, in the order of the values of each category.
+
+    The labels are used by [`ChartContext::draw_grouped_series()`] for the legend entries.
+    */
+    pub fn labels<L: Into<String>, I: IntoIterator<Item = L>>(mut self, labels: I) -> Self {
+        self.labels = labels.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /**
+    Sets the baseline of the bars.
+    */
+    pub fn baseline(mut self, baseline: A) -> Self {
+        self.baseline = baseline;
+        self
+    }
+
+    /**
+    Sets the margin on both sides of each category slot, in backend pixels.
+    */
+    pub fn margin(mut self, value: u32) -> Self {
+        self.margin = value;
+        self
+    }
+
+    /**
+    Sets the spacing between the bars of the same category, in backend pixels.
+    */
+    pub fn spacing(mut self, value: u32) -> Self {
+        self.spacing = value;
+        self
+    }
+
+    /**
+    Specifies the input data for the grouped bars.
+
+    Each item is a category with the values of the groups. The values of the items with the same
+    category are added up group by group.
+    */
+    pub fn data<TB, V, I>(mut self, iter: I) -> Self
+    where
+        TB: Into<BR::ValueType>,
+        V: IntoIterator<Item = A>,
+        I: IntoIterator<Item = (TB, V)>,
+    {
+        let buffer = accumulate_columns(&self.br, iter);
+        self.groups = buffer.values().map(Vec::len).max().unwrap_or(0);
+        self.iter = buffer.into_iter();
+        self
+    }
+
+    /**
+    Returns the label and the style of each group, in the order of the values of each category.
+    */
+    pub fn layers(&self) -> Vec<(String, ShapeStyle)> {
+        layer_legends(&self.labels, &self.styles)
+    }
+
+    /// Get the margins before and after the bar of the group in the slot of its category
+    fn bar_margins(&self, group: usize) -> (u32, u32) {
+        let groups = self.groups.max(self.styles.len()).max(self.labels.len()) as u32;
+        let spacing = self.spacing * groups.saturating_sub(1);
+        let usable = self.slot_size.saturating_sub(2 * self.margin + spacing);
+        let width = (usable / groups.max(1)).max(1);
+
+        let before = (self.margin + group as u32 * (width + self.spacing)).min(self.slot_size);
+        (before, self.slot_size.saturating_sub(before + width))
+    }
+
+    /// Get the next bar, i.e. the category index, the group index and the value
+    fn next_bar(&mut self) -> Option<(usize, usize, A)> {
+        while self.pending.is_empty() {
+            let (x, values) = self.iter.next()?;
+            self.pending = values
+                .into_iter()
+                .enumerate()
+                .map(|(group, value)| (x, group, value))
+                .rev()
+                .collect();
+        }
+        self.pending.pop()
+    }
+}
+
+/// Get the size of a slot of the discrete coordinate in pixels, which is the minimum spacing of
+/// two adjacent keys, so that the bars never overlap the next slot when the pixels are rounded
+fn slot_size<BR: DiscreteRanged, F: Fn(BR::ValueType) -> i32>(br: &BR, to_pixel: F) -> u32 {
+    let pixels: Vec<_> = (0..br.size())
+        .filter_map(|idx| br.from_index(idx))
+        .map(to_pixel)
+        .collect();
+    pixels
+        .windows(2)
+        .map(|pair| (pair[1] - pair[0]).unsigned_abs())
+        .min()
+        .unwrap_or(0)
+}
+
+impl<BR, A> GroupedBarSeries<BR, A, Vertical>
+where
+    BR: DiscreteRanged + Clone,
+    A: Add<A, Output = A> + Default + Clone,
+{
+    /**
+    Creates a vertical grouped bar series.
+
+    See [`GroupedBarSeries`] for more information and examples.
+    */
+    pub fn vertical<ACoord, DB: DrawingBackend>(
+        parent: &ChartContext<DB, Cartesian2d<BR, ACoord>>,
+    ) -> Self
+    where
+        ACoord: Ranged<ValueType = A>,
+    {
+        let coord = parent.as_coord_spec();
+        let y = coord.get_y_range().start;
+        let size = slot_size(coord.x_spec(), |x| coord.translate(&(x, y.clone())).0);
+        Self::empty(coord.x_spec(), size)
+    }
+}
+
+impl<BR, A> GroupedBarSeries<BR, A, Horizontal>
+where
+    BR: DiscreteRanged + Clone,
+    A: Add<A, Output = A> + Default + Clone,
+{
+    /**
+    Creates a horizontal grouped bar series.
+
+    See [`GroupedBarSeries`] for more information and examples.
+    */
+    pub fn horizontal<ACoord, DB: DrawingBackend>(
+        parent: &ChartContext<DB, Cartesian2d<ACoord, BR>>,
+    ) -> Self
+    where
+        ACoord: Ranged<ValueType = A>,
+    {
+        let coord = parent.as_coord_spec();
+        let x = coord.get_x_range().start;
+        let size = slot_size(coord.y_spec(), |y| coord.translate(&(x.clone(), y)).1);
+        Self::empty(coord.y_spec(), size)
+    }
+}
+
+impl<BR, A> Iterator for GroupedBarSeries<BR, A, Vertical>
+where
+    BR: DiscreteRanged + Clone,
+    A: Add<A, Output = A> + Default + Clone,
+{
+    type Item = Rectangle<(BR::ValueType, A)>;
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some((x, group, value)) = self.next_bar() {
+            if let (Some(x), Some(nx)) = (self.br.from_index(x), self.br.from_index(x + 1)) {
+                let style = layer_style(&self.styles, group);
+                let (left, right) = self.bar_margins(group);
+                let mut rect = Rectangle::new([(x, value), (nx, self.baseline.clone())], style);
+                rect.set_margin(0, 0, left, right);
+                return Some(rect);
+            }
+        }
+        None
+    }
+}
+
+impl<BR, A> Iterator for GroupedBarSeries<BR, A, Horizontal>
+where
+    BR: DiscreteRanged + Clone,
+    A: Add<A, Output = A> + Default + Clone,
+{
+    type Item = Rectangle<(A, BR::ValueType)>;
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some((y, group, value)) = self.next_bar() {
+            if let (Some(y), Some(ny)) = (self.br.from_index(y), self.br.from_index(y + 1)) {
+                let style = layer_style(&self.styles, group);
+                let (top, bottom) = self.bar_margins(group);
+                let mut rect = Rectangle::new([(value, y), (self.baseline.clone(), ny)], style);
+                rect.set_margin(top, bottom, 0, 0);
+                return Some(rect);
+            }
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_grouped_bar_series() {
+        let drawing_area = create_mocked_drawing_area(111, 100, |m| {
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_rect_call, 4);
+            });
+        });
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d((0..1).into_segmented(), 0..10)
+            .unwrap();
+
+        let series = GroupedBarSeries::vertical(&chart)
+            .labels(["a", "b", "c"])
+            .margin(5)
+            .spacing(2)
+            .data(vec![(0, vec![2, 3]), (0, vec![1, 1, 4]), (1, vec![5])]);
+        assert_eq!(series.layers().len(), 3);
+        assert_eq!(series.slot_size, 55);
+        assert_eq!(series.bar_margins(0), (5, 37));
+        assert_eq!(series.bar_margins(1), (20, 22));
+        assert_eq!(series.bar_margins(2), (35, 7));
+
+        chart.draw_grouped_series(series).unwrap();
+        assert_eq!(chart.series_anno.len(), 3);
+    }
+
+    #[test]
+    fn test_slot_size_uses_minimum_spacing() {
+        let keys = (0..3).into_segmented();
+        let pixels = [0, 30, 55, 90, 120];
+        assert_eq!(
+            super::slot_size(&keys, |v| match v {
+                SegmentValue::Exact(i) | SegmentValue::CenterOf(i) => pixels[i as usize],
+                SegmentValue::Last => pixels[4],
+            }),
+            25
+        );
+        assert_eq!(super::slot_size(&(0..1).into_segmented(), |_| 0), 0);
+    }
+}
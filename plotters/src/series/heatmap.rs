@@ -0,0 +1,259 @@
+use crate::chart::ChartContext;
+use crate::coord::cartesian::Cartesian2d;
+use crate::coord::ranged1d::DiscreteRanged;
+use crate::element::{Drawable, PointCollection};
+use crate::style::colors::colormaps::ColorMap;
+use crate::style::text_anchor::{HPos, Pos, VPos};
+use crate::style::{Color, IntoFont, RGBAColor, ShapeStyle, TextStyle, BLACK, WHITE};
+use plotters_backend::{BackendCoord, DrawingBackend, DrawingErrorKind};
+
+/**
+A cell of a [`HeatmapSeries`], which is a filled rectangle with an optional centered label.
+*/
+pub struct HeatmapCell<'a, Coord> {
+    corners: [Coord; 2],
+    style: ShapeStyle,
+    label: Option<(String, TextStyle<'a>)>,
+}
+
+impl<'a, 'b, Coord> PointCollection<'b, Coord> for &'b HeatmapCell<'a, Coord> {
+    type Point = &'b Coord;
+    type IntoIter = &'b [Coord];
+    fn point_iter(self) -> &'b [Coord] {
+        &self.corners
+    }
+}
+
+impl<'a, Coord, DB: DrawingBackend> Drawable<DB> for HeatmapCell<'a, Coord> {
+    fn draw<I: Iterator<Item = BackendCoord>>(
+        &self,
+        mut points: I,
+        backend: &mut DB,
+        _: (u32, u32),
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        if let (Some(a), Some(b)) = (points.next(), points.next()) {
+            let upper_left = (a.0.min(b.0), a.1.min(b.1));
+            let bottom_right = (a.0.max(b.0), a.1.max(b.1));
+            backend.draw_rect(upper_left, bottom_right, &self.style, true)?;
+            if let Some((text, style)) = &self.label {
+                let center = ((a.0 + b.0) / 2, (a.1 + b.1) / 2);
+                backend.draw_text(text, style, center)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/**
+Presents a 2D grid of values as a heatmap, which fills one cell per value with a color from a
+colormap.
+
+Each row of the grid is a slot of the Y axis, and each value of a row is a slot of the X axis,
+so the value at row `r` and column `c` fills the slot from the `c`th to the `c + 1`th value of the
+X axis, and from the `r`th to the `r + 1`th value of the Y axis. Both axes must be discrete, and
+the segmented coordinates put the labels of the categories at the centers of the cells, which is
+the usual layout of the correlation matrices. The colors are picked from the colormap after the
+values are normalized with the range of the values, unless it's set by
+[`HeatmapSeries::value_range()`]. The non-finite values are left blank.
+
+# Examples
+
+```
+use plotters::prelude::*;
+let drawing_area = SVGBackend::new("heatmap_series.svg", (300, 300)).into_drawing_area();
+drawing_area.fill(&WHITE).unwrap();
+let names = ["a", "b", "c"];
+let mut chart = ChartBuilder::on(&drawing_area)
+    .margin(5)
+    .set_left_and_bottom_label_area_size(20)
+    .build_cartesian_2d(names[..].into_segmented(), names[..].into_segmented())
+    .unwrap();
+chart.configure_mesh().disable_mesh().draw().unwrap();
+let correlation = [[1.0, 0.8, -0.2], [0.8, 1.0, 0.1], [-0.2, 0.1, 1.0]];
+chart
+    .draw_series(
+        HeatmapSeries::new(&chart, correlation, ViridisRGB)
+            .value_range(-1.0..1.0)
+            .annotate(true),
+    )
+    .unwrap();
+```
+*/
+pub struct HeatmapSeries<'a, XR: DiscreteRanged, YR: DiscreteRanged> {
+    x_spec: XR,
+    y_spec: YR,
+    values: std::vec::IntoIter<(usize, usize, f64)>,
+    value_range: (f64, f64),
+    colormap: Box<dyn Fn(f64) -> RGBAColor + 'a>,
+    annotate: bool,
+    label_style: Option<TextStyle<'a>>,
+    label_formatter: Box<dyn Fn(f64) -> String + 'a>,
+}
+
+impl<'a, XR, YR> HeatmapSeries<'a, XR, YR>
+where
+    XR: DiscreteRanged + Clone,
+    YR: DiscreteRanged + Clone,
+{
+    /**
+    Creates a heatmap series.
+
+    - `parent`: The chart on which the heatmap is drawn, of which both axes are discrete
+    - `rows`: The rows of the values, from the first slot of the Y axis
+    - `colormap`: The colormap from which the colors of the cells are picked
+    - **returns** The newly created series
+
+    See [`HeatmapSeries`] for more information and examples.
+    */
+    pub fn new<DB, V, R, I, C, M>(
+        parent: &ChartContext<DB, Cartesian2d<XR, YR>>,
+        rows: I,
+        colormap: M,
+    ) -> Self
+    where
+        DB: DrawingBackend,
+        V: Into<f64>,
+        R: IntoIterator<Item = V>,
+        I: IntoIterator<Item = R>,
+        C: Color,
+        M: ColorMap<C, f64> + 'a,
+    {
+        let values: Vec<_> = rows
+            .into_iter()
+            .enumerate()
+            .flat_map(|(row, values)| {
+                values
+                    .into_iter()
+                    .enumerate()
+                    .map(move |(col, value)| (row, col, value.into()))
+            })
+            .filter(|(_, _, value)| value.is_finite())
+            .collect();
+        let value_range = values.iter().fold(
+            (f64::INFINITY, f64::NEG_INFINITY),
+            |(min, max), (_, _, v)| (min.min(*v), max.max(*v)),
+        );
+        let coord = parent.as_coord_spec();
+        Self {
+            x_spec: coord.x_spec().clone(),
+            y_spec: coord.y_spec().clone(),
+            values: values.into_iter(),
+            value_range,
+            colormap: Box::new(move |h| colormap.get_color(h).to_rgba()),
+            annotate: false,
+            label_style: None,
+            label_formatter: Box::new(|value| format!("{:.2}", value)),
+        }
+    }
+
+    /// Set the range of the values which is mapped to the whole colormap, the values out of the
+    /// range take the colors at the ends of the colormap
+    pub fn value_range(mut self, range: std::ops::Range<f64>) -> Self {
+        self.value_range = (range.start, range.end);
+        self
+    }
+
+    /// Set if the values are printed at the centers of the cells
+    pub fn annotate(mut self, annotate: bool) -> Self {
+        self.annotate = annotate;
+        self
+    }
+
+    /// Set the style of the labels of the values. By default, the labels are black or white,
+    /// whichever contrasts with the color of the cell more
+    pub fn label_style<S: Into<TextStyle<'a>>>(mut self, style: S) -> Self {
+        self.label_style = Some(style.into());
+        self
+    }
+
+    /// Set the function which formats the labels of the values, which print two decimal places
+    /// by default
+    pub fn label_formatter<F: Fn(f64) -> String + 'a>(mut self, formatter: F) -> Self {
+        self.label_formatter = Box::new(formatter);
+        self
+    }
+
+    /// Get the style of the label on a cell of the color
+    fn label_style_on(&self, color: RGBAColor) -> TextStyle<'a> {
+        let style = self.label_style.clone().unwrap_or_else(|| {
+            let (r, g, b) = color.rgb();
+            let luminance = 0.299 * f64::from(r) + 0.587 * f64::from(g) + 0.114 * f64::from(b);
+            let text_color = if luminance > 128.0 { &BLACK } else { &WHITE };
+            TextStyle::from(("sans-serif", 12).into_font()).color(text_color)
+        });
+        style.pos(Pos::new(HPos::Center, VPos::Center))
+    }
+}
+
+impl<'a, XR, YR> Iterator for HeatmapSeries<'a, XR, YR>
+where
+    XR: DiscreteRanged + Clone,
+    YR: DiscreteRanged + Clone,
+{
+    type Item = HeatmapCell<'a, (XR::ValueType, YR::ValueType)>;
+    fn next(&mut self) -> Option<Self::Item> {
+        let (min, max) = self.value_range;
+        for (row, col, value) in self.values.by_ref() {
+            let corners = (
+                self.x_spec.from_index(col),
+                self.x_spec.from_index(col + 1),
+                self.y_spec.from_index(row),
+                self.y_spec.from_index(row + 1),
+            );
+            if let (Some(x0), Some(x1), Some(y0), Some(y1)) = corners {
+                let h = if max > min {
+                    ((value - min) / (max - min)).clamp(0.0, 1.0)
+                } else {
+                    0.5
+                };
+                let color = (self.colormap)(h);
+                let label = if self.annotate {
+                    Some(((self.label_formatter)(value), self.label_style_on(color)))
+                } else {
+                    None
+                };
+                return Some(HeatmapCell {
+                    corners: [(x0, y0), (x1, y1)],
+                    style: color.filled(),
+                    label,
+                });
+            }
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_heatmap_series() {
+        let drawing_area = create_mocked_drawing_area(100, 100, |m| {
+            m.check_draw_rect(|_, _, filled, _, _| assert!(filled));
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_rect_call, 4);
+                assert_eq!(b.num_draw_text_call, 4);
+            });
+        });
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d((0..1).into_segmented(), (0..1).into_segmented())
+            .unwrap();
+
+        let cells: Vec<_> =
+            HeatmapSeries::new(&chart, vec![vec![1.0, 3.0, f64::NAN, 5.0]], ViridisRGB).collect();
+        assert_eq!(cells.len(), 2);
+        assert_eq!(cells[0].style.color, ViridisRGB::get_color(0.0).to_rgba());
+        assert_eq!(cells[1].style.color, ViridisRGB::get_color(0.5).to_rgba());
+        assert!(cells[0].label.is_none());
+
+        chart
+            .draw_series(
+                HeatmapSeries::new(&chart, [[1.0, 3.0], [5.0, 0.0]], ViridisRGB)
+                    .value_range(1.0..5.0)
+                    .annotate(true)
+                    .label_formatter(|v| format!("{}", v)),
+            )
+            .unwrap();
+    }
+}
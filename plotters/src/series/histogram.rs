@@ -43,6 +43,39 @@ Here is a variation with [`Histogram::horizontal()`], replacing `(1..3).into_seg
 
 ![](https://cdn.jsdelivr.net/gh/facorread/plotters-doc-data@a617d37/apidoc/histogram_horizontal.svg)
 
+The horizontal histogram takes the values on the X axis and the categories on the Y axis, so
+[`Histogram::margin()`] separates the bars vertically and [`Histogram::baseline()`] moves the left ends
+of the bars. This is the usual layout of a ranking chart with long category names: reversing the
+segmented Y axis puts the first category on top, and the label formatter turns the indices into
+names, which are centered on the bars. [`MeshStyle::y_label_inset()`](crate::chart::MeshStyle::y_label_inset)
+draws the names over the bars, so the long names don't need a wide label area:
+
+```
+use plotters::prelude::*;
+let names = ["Alpha Centauri Industries", "Beta", "Gamma Corporation"];
+let drawing_area = SVGBackend::new("histogram_ranking.svg", (400, 200)).into_drawing_area();
+drawing_area.fill(&WHITE).unwrap();
+let mut chart_context = ChartBuilder::on(&drawing_area)
+    .margin(5)
+    .x_label_area_size(20)
+    .y_label_area_size(10)
+    .build_cartesian_2d(0..10, (0..2).into_segmented().reversed())
+    .unwrap();
+chart_context
+    .configure_mesh()
+    .disable_y_mesh()
+    .y_label_inset(5)
+    .on_top(true)
+    .y_label_formatter(&|y| match y {
+        SegmentValue::Exact(v) | SegmentValue::CenterOf(v) => names[*v as usize].to_string(),
+        SegmentValue::Last => String::new(),
+    })
+    .draw()
+    .unwrap();
+chart_context.draw_series(Histogram::horizontal(&chart_context).style(BLUE.mix(0.3).filled()).margin(5)
+    .data([(0, 9), (1, 7), (2, 4)])).unwrap();
+```
+
 The spacing between histogram bars is adjusted with [`Histogram::margin()`].
 Here is a version of the figure where `.margin(10)` has been replaced by `.margin(20)`;
 the resulting bars are narrow and more spaced:
@@ -220,13 +253,14 @@ where
 
     See [`Histogram`] for more information and examples.
     */
-    pub fn horizontal<ACoord, DB: DrawingBackend>(
+    pub fn horizontal<ACoord, DB: DrawingBackend + 'a>(
         parent: &ChartContext<DB, Cartesian2d<ACoord, BR>>,
     ) -> Self
     where
         ACoord: Ranged<ValueType = A>,
     {
         let dp = parent.as_coord_spec().y_spec();
+
         Self::empty(dp)
     }
 }
@@ -278,3 +312,75 @@ where
         None
     }
 }
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_horizontal_histogram() {
+        let drawing_area = create_mocked_drawing_area(111, 111, |m| {
+            m.check_draw_rect(|_, _, _, upper_left, bottom_right| {
+                assert_eq!(upper_left, (22, 60));
+                assert_eq!(bottom_right, (88, 105));
+            });
+            m.check_draw_rect(|_, _, _, upper_left, bottom_right| {
+                assert_eq!(upper_left, (22, 5));
+                assert_eq!(bottom_right, (66, 50));
+            });
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_rect_call, 2);
+            });
+        });
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0..10, (0..1).into_segmented().reversed())
+            .unwrap();
+
+        let histogram = Histogram::horizontal(&chart)
+            .margin(5)
+            .baseline(2)
+            .style(BLUE.filled())
+            .data([(1, 8)]);
+        chart.draw_series(histogram).unwrap();
+        let histogram = Histogram::horizontal(&chart)
+            .margin(5)
+            .baseline(2)
+            .data([(0, 4), (0, 2)]);
+        chart.draw_series(histogram).unwrap();
+    }
+    #[test]
+    fn test_horizontal_histogram_inset_labels() {
+        let names = ["Alpha Centauri Industries", "Beta", "Gamma Corporation"];
+        let (drawing_area, labels) = create_text_recording_area(200, 100);
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .x_label_area_size(10)
+            .y_label_area_size(10)
+            .build_cartesian_2d(0..10, (0..2).into_segmented().reversed())
+            .unwrap();
+        chart
+            .configure_mesh()
+            .disable_mesh()
+            .disable_x_axis()
+            .y_label_inset(5)
+            .y_label_formatter(&|y| match y {
+                SegmentValue::Exact(v) | SegmentValue::CenterOf(v) => {
+                    names[*v as usize].to_string()
+                }
+                SegmentValue::Last => String::new(),
+            })
+            .draw()
+            .unwrap();
+        drop(chart);
+        drop(drawing_area);
+
+        // The names start right of the axis, inside the plotting area, and are centered on the bars
+        assert_eq!(
+            labels.all(),
+            vec![
+                (names[0].to_string(), (15, 14)),
+                (names[1].to_string(), (15, 44)),
+                (names[2].to_string(), (15, 74)),
+            ]
+        );
+    }
+}
@@ -0,0 +1,211 @@
+use std::marker::PhantomData;
+use std::ops::Range;
+
+use crate::data::{Kernel, KernelDensity};
+use crate::element::{DynElement, IntoDynElement, PathElement, Polygon};
+use crate::style::{ShapeStyle, BLUE};
+use plotters_backend::DrawingBackend;
+
+/**
+Presents the distribution of the raw samples as the curve of their kernel density estimate.
+
+The density is estimated by [`KernelDensity`] with the Gaussian kernel and the bandwidth of
+Silverman's rule of thumb by default, and it's sampled at [`KdeSeries::resolution()`] evenly
+spaced values over the support of the estimate, i.e. three bandwidths beyond the samples for the
+Gaussian kernel. The curve is drawn as a line, and optionally as a filled area above zero.
+
+# Examples
+
+```
+use plotters::prelude::*;
+let samples = [1.2, 1.9, 2.1, 2.4, 2.5, 3.1, 3.3, 4.8, 5.0, 5.2];
+let drawing_area = SVGBackend::new("kde_series.svg", (300, 200)).into_drawing_area();
+drawing_area.fill(&WHITE).unwrap();
+let mut chart = ChartBuilder::on(&drawing_area)
+    .margin(5)
+    .set_left_and_bottom_label_area_size(20)
+    .build_cartesian_2d(-1.0..8.0, 0.0..0.4)
+    .unwrap();
+chart.configure_mesh().draw().unwrap();
+chart
+    .draw_series(KdeSeries::new(samples).area_style(Some(BLUE.mix(0.2))))
+    .unwrap();
+chart
+    .draw_series(
+        KdeSeries::new(samples)
+            .kernel(Kernel::Epanechnikov)
+            .style(RED),
+    )
+    .unwrap();
+```
+*/
+pub struct KdeSeries<DB: DrawingBackend> {
+    kde: KernelDensity,
+    resolution: usize,
+    range: Option<Range<f64>>,
+    scale: f64,
+    style: ShapeStyle,
+    area_style: Option<ShapeStyle>,
+    curve: Vec<(f64, f64)>,
+    state: u32,
+    _p: PhantomData<DB>,
+}
+
+impl<DB: DrawingBackend> KdeSeries<DB> {
+    /**
+    Creates a kernel density estimate series.
+
+    - `samples`: The raw samples, the non-finite samples are ignored
+    - **returns** The newly created series
+
+    See [`KdeSeries`] for more information and examples.
+    */
+    pub fn new<T: Into<f64>, I: IntoIterator<Item = T>>(samples: I) -> Self {
+        Self {
+            kde: KernelDensity::new(samples),
+            resolution: 200,
+            range: None,
+            scale: 1.0,
+            style: BLUE.into(),
+            area_style: None,
+            curve: vec![],
+            state: 0,
+            _p: PhantomData,
+        }
+    }
+
+    /// Set the kernel of the estimate, which is [`Kernel::Gaussian`] by default
+    pub fn kernel(mut self, kernel: Kernel) -> Self {
+        self.kde = self.kde.kernel(kernel);
+        self
+    }
+
+    /// Set the bandwidth of the kernel in the unit of the samples
+    pub fn bandwidth(mut self, bandwidth: f64) -> Self {
+        self.kde = self.kde.bandwidth(bandwidth);
+        self
+    }
+
+    /// Set the number of the values at which the density is sampled, which is at least 2
+    pub fn resolution(mut self, resolution: usize) -> Self {
+        self.resolution = resolution.max(2);
+        self
+    }
+
+    /// Set the range over which the density is sampled instead of the support of the estimate
+    pub fn range(mut self, range: Range<f64>) -> Self {
+        self.range = Some(range);
+        self
+    }
+
+    /// Set the factor of the density, i.e. the number of the samples times the bin width to
+    /// overlay the curve on a histogram of the counts
+    pub fn scale(mut self, scale: f64) -> Self {
+        self.scale = scale;
+        self
+    }
+
+    /// Set the style of the curve
+    pub fn style<S: Into<ShapeStyle>>(mut self, style: S) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Set the style of the area under the curve, `None` draws the curve only, which is the default
+    pub fn area_style<S: Into<ShapeStyle>>(mut self, style: Option<S>) -> Self {
+        self.area_style = style.map(Into::into);
+        self
+    }
+
+    /// Get the estimate of the series
+    pub fn estimate(&self) -> &KernelDensity {
+        &self.kde
+    }
+
+    /// Get the points of the curve, which is empty without any sample
+    pub fn points(&self) -> Vec<(f64, f64)> {
+        if self.kde.samples().is_empty() {
+            return vec![];
+        }
+        let range = self.range.clone().unwrap_or_else(|| self.kde.support());
+        self.kde
+            .sample(range, self.resolution)
+            .into_iter()
+            .map(|(x, density)| (x, density * self.scale))
+            .collect()
+    }
+}
+
+impl<DB: DrawingBackend> Iterator for KdeSeries<DB> {
+    type Item = DynElement<'static, DB, (f64, f64)>;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.state == 0 {
+            self.curve = self.points();
+            self.state = if self.curve.is_empty() { 2 } else { 1 };
+            if let (Some(area_style), Some(first), Some(last)) =
+                (self.area_style, self.curve.first(), self.curve.last())
+            {
+                let mut area = self.curve.clone();
+                area.push((last.0, 0.0));
+                area.push((first.0, 0.0));
+                return Some(Polygon::new(area, area_style).into_dyn());
+            }
+        }
+        if self.state == 1 {
+            self.state = 2;
+            let curve = std::mem::take(&mut self.curve);
+            return Some(PathElement::new(curve, self.style).into_dyn());
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::drawing::MockedBackend;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_kde_series() {
+        let series = KdeSeries::<MockedBackend>::new([1.0, 2.0, 3.0])
+            .bandwidth(1.0)
+            .resolution(9)
+            .scale(3.0);
+        let points = series.points();
+        assert_eq!(points.len(), 9);
+        assert_eq!(points[0].0, -2.0);
+        assert_eq!(points[8].0, 6.0);
+        assert_eq!(points[4].1, series.estimate().density(2.0) * 3.0);
+        assert_eq!(series.count(), 1);
+
+        let series = KdeSeries::<MockedBackend>::new([1.0, 2.0, 3.0])
+            .area_style(Some(RED))
+            .range(0.0..4.0);
+        assert_eq!(series.points()[0].0, 0.0);
+        assert_eq!(series.count(), 2);
+        assert_eq!(KdeSeries::<MockedBackend>::new(vec![f64::NAN]).count(), 0);
+
+        let drawing_area = create_mocked_drawing_area(101, 101, |m| {
+            m.check_fill_polygon(|_, points| {
+                assert_eq!(points[0], (0, 100));
+                assert_eq!(points[points.len() - 1], (0, 100));
+            });
+            m.drop_check(|b| {
+                assert_eq!(b.num_fill_polygon_call, 1);
+                assert_eq!(b.num_draw_path_call, 1);
+            });
+        });
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0.0..100.0, 0.0..1.0)
+            .unwrap();
+        chart
+            .draw_series(
+                KdeSeries::new([50.0])
+                    .kernel(Kernel::Epanechnikov)
+                    .bandwidth(50.0)
+                    .area_style(Some(BLUE)),
+            )
+            .unwrap();
+    }
+}
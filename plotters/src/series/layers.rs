@@ -0,0 +1,54 @@
+#[cfg(any(feature = "stacked_bar_series", feature = "grouped_bar_series"))]
+use {crate::coord::ranged1d::DiscreteRanged, std::collections::BTreeMap, std::ops::Add};
+
+use crate::style::{Color, Palette, Palette99, ShapeStyle};
+
+/// Get the style of a layer, the layers without a style use the colors of [`Palette99`]
+pub(super) fn layer_style(styles: &[ShapeStyle], layer: usize) -> ShapeStyle {
+    styles
+        .get(layer)
+        .copied()
+        .unwrap_or_else(|| Palette99::pick(layer).filled())
+}
+
+/// Get the label and the style of each layer which has either of them
+#[cfg(any(
+    feature = "stacked_area_series",
+    feature = "stacked_bar_series",
+    feature = "grouped_bar_series"
+))]
+pub(super) fn layer_legends(labels: &[String], styles: &[ShapeStyle]) -> Vec<(String, ShapeStyle)> {
+    (0..styles.len().max(labels.len()))
+        .map(|idx| {
+            let label = labels.get(idx).cloned().unwrap_or_default();
+            (label, layer_style(styles, idx))
+        })
+        .collect()
+}
+
+/// Collect the values of each category by the category index, and add up the values of the
+/// items with the same category column by column
+#[cfg(any(feature = "stacked_bar_series", feature = "grouped_bar_series"))]
+pub(super) fn accumulate_columns<BR, A, TB, V, I>(br: &BR, iter: I) -> BTreeMap<usize, Vec<A>>
+where
+    BR: DiscreteRanged,
+    A: Add<A, Output = A> + Clone,
+    TB: Into<BR::ValueType>,
+    V: IntoIterator<Item = A>,
+    I: IntoIterator<Item = (TB, V)>,
+{
+    let mut buffer = BTreeMap::<usize, Vec<A>>::new();
+    for (x, values) in iter.into_iter() {
+        if let Some(x) = br.index_of(&x.into()) {
+            let columns = buffer.entry(x).or_default();
+            for (idx, value) in values.into_iter().enumerate() {
+                if idx < columns.len() {
+                    columns[idx] = columns[idx].clone() + value;
+                } else {
+                    columns.push(value);
+                }
+            }
+        }
+    }
+    buffer
+}
@@ -1,8 +1,9 @@
 use crate::element::{
-    Circle, DashedPathElement, DottedPathElement, DynElement, IntoDynElement, PathElement,
+    Circle, DashedPathElement, DottedPathElement, Drawable, DynElement, IntoDynElement,
+    PathElement, PointCollection,
 };
 use crate::style::{ShapeStyle, SizeDesc};
-use plotters_backend::{BackendCoord, DrawingBackend};
+use plotters_backend::{BackendCoord, DrawingBackend, DrawingErrorKind};
 use std::marker::PhantomData;
 
 /**
@@ -209,6 +210,123 @@ impl<I: Iterator + Clone, Size: SizeDesc, Marker: 'static> IntoIterator
     }
 }
 
+/// Where the steps of a [`StepLineSeries`] change their value
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum StepMode {
+    /// The value changes at the start of the step, i.e. each point's value is taken over the
+    /// interval before it
+    Pre,
+    /// The value changes at the end of the step, i.e. each point's value is held until the next
+    /// point
+    Post,
+    /// The value changes halfway between the points
+    Mid,
+}
+
+/// The staircase path of a [`StepLineSeries`]. The steps are computed in the backend coordinate,
+/// so any kind of the coordinate is supported, including the discrete ones.
+pub struct StepPathElement<Coord> {
+    points: Vec<Coord>,
+    mode: StepMode,
+    style: ShapeStyle,
+}
+
+impl<'a, Coord> PointCollection<'a, Coord> for &'a StepPathElement<Coord> {
+    type Point = &'a Coord;
+    type IntoIter = &'a [Coord];
+    fn point_iter(self) -> &'a [Coord] {
+        &self.points
+    }
+}
+
+impl<Coord, DB: DrawingBackend> Drawable<DB> for StepPathElement<Coord> {
+    fn draw<I: Iterator<Item = BackendCoord>>(
+        &self,
+        points: I,
+        backend: &mut DB,
+        _: (u32, u32),
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let mut path = vec![];
+        let mut last: Option<BackendCoord> = None;
+        for (x, y) in points {
+            if let Some((x0, y0)) = last {
+                match self.mode {
+                    StepMode::Pre => path.push((x0, y)),
+                    StepMode::Post => path.push((x, y0)),
+                    StepMode::Mid => {
+                        let xm = (x0 + x) / 2;
+                        path.push((xm, y0));
+                        path.push((xm, y));
+                    }
+                }
+            }
+            path.push((x, y));
+            last = Some((x, y));
+        }
+        backend.draw_path(path, &self.style)
+    }
+}
+
+/**
+The step line series, which connects the data points with horizontal and vertical segments
+rather than the straight lines, as in the histogram outlines, the digital signals or the survival
+curves. See [`StepMode`] for where the steps change their value.
+
+# Example
+
+```
+use plotters::prelude::*;
+let drawing_area = SVGBackend::new("step_line_series.svg", (300, 200)).into_drawing_area();
+drawing_area.fill(&WHITE).unwrap();
+let mut chart = ChartBuilder::on(&drawing_area)
+    .margin(10)
+    .set_left_and_bottom_label_area_size(20)
+    .build_cartesian_2d(0.0..4.0, 0.0..1.0)
+    .unwrap();
+chart.configure_mesh().draw().unwrap();
+let survival = [(0.0, 1.0), (0.5, 0.9), (1.2, 0.7), (2.0, 0.6), (3.1, 0.3), (4.0, 0.3)];
+chart
+    .draw_series(StepLineSeries::new(survival, StepMode::Post, BLUE))
+    .unwrap();
+```
+*/
+pub struct StepLineSeries<Coord> {
+    element: Option<StepPathElement<Coord>>,
+}
+
+impl<Coord> StepLineSeries<Coord> {
+    /**
+    Creates a new step line series.
+
+    - `iter`: The data points, in the order along the X axis
+    - `mode`: Where the steps change their value
+    - `style`: The style of the line
+    - **returns** The newly created series
+
+    See [`StepLineSeries`] for more information and examples.
+    */
+    pub fn new<I: IntoIterator<Item = Coord>, S: Into<ShapeStyle>>(
+        iter: I,
+        mode: StepMode,
+        style: S,
+    ) -> Self {
+        Self {
+            element: Some(StepPathElement {
+                points: iter.into_iter().collect(),
+                mode,
+                style: style.into(),
+            }),
+        }
+    }
+}
+
+impl<Coord> Iterator for StepLineSeries<Coord> {
+    type Item = StepPathElement<Coord>;
+    fn next(&mut self) -> Option<Self::Item> {
+        self.element.take()
+    }
+}
+
 #[cfg(test)]
 mod test {
     use crate::prelude::*;
@@ -255,4 +373,45 @@ mod test {
             .draw_series(DottedLineSeries::new((0..=50).map(|x| (x, 0)), 5, 5, mk_f))
             .expect("Drawing Error");
     }
+
+    #[test]
+    fn test_step_line_series() {
+        for (mode, expected) in [
+            (
+                StepMode::Pre,
+                vec![(0, 90), (0, 50), (40, 50), (40, 10), (80, 10)],
+            ),
+            (
+                StepMode::Post,
+                vec![(0, 90), (40, 90), (40, 50), (80, 50), (80, 10)],
+            ),
+            (
+                StepMode::Mid,
+                vec![
+                    (0, 90),
+                    (20, 90),
+                    (20, 50),
+                    (40, 50),
+                    (60, 50),
+                    (60, 10),
+                    (80, 10),
+                ],
+            ),
+        ] {
+            let drawing_area = create_mocked_drawing_area(101, 101, move |m| {
+                m.check_draw_path(move |_, _, path| assert_eq!(path, expected));
+                m.drop_check(|b| assert_eq!(b.num_draw_path_call, 1));
+            });
+            let mut chart = ChartBuilder::on(&drawing_area)
+                .build_cartesian_2d(0..100, 0..100)
+                .unwrap();
+            chart
+                .draw_series(StepLineSeries::new(
+                    [(0, 10), (40, 50), (80, 90)],
+                    mode,
+                    RED,
+                ))
+                .unwrap();
+        }
+    }
 }
@@ -12,27 +12,166 @@
 
 #[cfg(feature = "area_series")]
 mod area_series;
+#[cfg(feature = "band_series")]
+mod band;
+#[cfg(feature = "boxplot")]
+mod boxplot;
+#[cfg(feature = "colormap_point_series")]
+mod colormap_point;
+#[cfg(feature = "contour_series")]
+mod contour;
+#[cfg(feature = "dendrogram_series")]
+mod dendrogram;
+#[cfg(feature = "ecdf_series")]
+mod ecdf;
+#[cfg(feature = "errorbar")]
+mod error_bar;
+#[cfg(feature = "filled_contour_series")]
+mod filled_contour;
+#[cfg(feature = "funnel_series")]
+mod funnel;
+#[cfg(feature = "gantt_series")]
+mod gantt;
+#[cfg(feature = "grouped_bar_series")]
+mod grouped_bar;
+#[cfg(feature = "heatmap_series")]
+mod heatmap;
 #[cfg(feature = "histogram")]
 mod histogram;
+#[cfg(feature = "kde_series")]
+mod kde;
+#[cfg(any(
+    feature = "grouped_bar_series",
+    feature = "ridgeline_series",
+    feature = "stacked_area_series",
+    feature = "stacked_bar_series"
+))]
+mod layers;
 #[cfg(feature = "line_series")]
 mod line_series;
+#[cfg(feature = "moving_average_series")]
+mod moving_average;
 #[cfg(feature = "point_series")]
 mod point_series;
+#[cfg(feature = "quiver_series")]
+mod quiver;
+#[cfg(feature = "radar_series")]
+mod radar;
+#[cfg(feature = "regression_series")]
+mod regression;
+#[cfg(feature = "ridgeline_series")]
+mod ridgeline;
+#[cfg(feature = "spline_series")]
+mod spline;
+#[cfg(feature = "stacked_area_series")]
+mod stacked_area;
+#[cfg(feature = "stacked_bar_series")]
+mod stacked_bar;
+#[cfg(feature = "streamline_series")]
+mod streamline;
 #[cfg(feature = "surface_series")]
 mod surface;
+#[cfg(feature = "violin_series")]
+mod violin;
+#[cfg(feature = "waterfall_series")]
+mod waterfall;
+#[cfg(feature = "wind_rose_series")]
+mod wind_rose;
 
 #[cfg(feature = "area_series")]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "area_series")))]
 pub use area_series::AreaSeries;
+#[cfg(feature = "band_series")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "band_series")))]
+pub use band::BandSeries;
+#[cfg(feature = "bollinger_series")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "bollinger_series")))]
+pub use band::BollingerSeries;
+#[cfg(feature = "boxplot")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "boxplot")))]
+pub use boxplot::BoxplotSeries;
+#[cfg(feature = "colormap_point_series")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "colormap_point_series")))]
+pub use colormap_point::ColormapPointSeries;
+#[cfg(feature = "contour_series")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "contour_series")))]
+pub use contour::{ContourLine, ContourSeries};
+#[cfg(feature = "dendrogram_series")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "dendrogram_series")))]
+pub use dendrogram::{DendrogramLink, DendrogramOrientation, DendrogramSeries};
+#[cfg(feature = "ecdf_series")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "ecdf_series")))]
+pub use ecdf::EcdfSeries;
+#[cfg(feature = "errorbar")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "errorbar")))]
+pub use error_bar::{ErrorBarPoint, ErrorBarSeries};
+#[cfg(feature = "filled_contour_series")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "filled_contour_series")))]
+pub use filled_contour::FilledContourSeries;
+#[cfg(feature = "funnel_series")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "funnel_series")))]
+pub use funnel::{FunnelConversion, FunnelSeries, FunnelShape, FunnelStage};
+#[cfg(feature = "gantt_series")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "gantt_series")))]
+pub use gantt::{GanttElement, GanttSeries, GanttTask};
+#[cfg(feature = "grouped_bar_series")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "grouped_bar_series")))]
+pub use grouped_bar::GroupedBarSeries;
+#[cfg(feature = "heatmap_series")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "heatmap_series")))]
+pub use heatmap::{HeatmapCell, HeatmapSeries};
 #[cfg(feature = "histogram")]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "histogram")))]
 pub use histogram::Histogram;
+#[cfg(feature = "histogram")]
+pub(crate) use histogram::HistogramType;
+#[cfg(feature = "kde_series")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "kde_series")))]
+pub use kde::KdeSeries;
 #[cfg(feature = "line_series")]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "line_series")))]
-pub use line_series::{DashedLineSeries, DottedLineSeries, LineSeries};
+pub use line_series::{
+    DashedLineSeries, DottedLineSeries, LineSeries, StepLineSeries, StepMode, StepPathElement,
+};
+#[cfg(feature = "moving_average_series")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "moving_average_series")))]
+pub use moving_average::{MovingAverage, MovingAverageSeries};
 #[cfg(feature = "point_series")]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "point_series")))]
-pub use point_series::PointSeries;
+pub use point_series::{Point3DSeries, PointSeries};
+#[cfg(feature = "quiver_series")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "quiver_series")))]
+pub use quiver::{QuiverArrow, QuiverSeries};
+#[cfg(feature = "radar_series")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "radar_series")))]
+pub use radar::{RadarAxes, RadarSeries, RadarShape};
+#[cfg(feature = "regression_series")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "regression_series")))]
+pub use regression::RegressionSeries;
+#[cfg(feature = "ridgeline_series")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "ridgeline_series")))]
+pub use ridgeline::{RidgelineRow, RidgelineSeries};
+#[cfg(feature = "spline_series")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "spline_series")))]
+pub use spline::{SplineMode, SplinePathElement, SplineSeries};
+#[cfg(feature = "stacked_area_series")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "stacked_area_series")))]
+pub use stacked_area::{StackedAreaSeries, StreamOffset, StreamgraphSeries};
+#[cfg(feature = "stacked_bar_series")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "stacked_bar_series")))]
+pub use stacked_bar::StackedBarSeries;
+#[cfg(feature = "streamline_series")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "streamline_series")))]
+pub use streamline::{Streamline, StreamlineSeries};
 #[cfg(feature = "surface_series")]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "surface_series")))]
-pub use surface::SurfaceSeries;
+pub use surface::{Line3DSeries, SurfaceSeries, WireframeSeries};
+#[cfg(feature = "violin_series")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "violin_series")))]
+pub use violin::ViolinSeries;
+#[cfg(feature = "waterfall_series")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "waterfall_series")))]
+pub use waterfall::{WaterfallElement, WaterfallSeries, WaterfallStep};
+#[cfg(feature = "wind_rose_series")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "wind_rose_series")))]
+pub use wind_rose::WindRoseSeries;
@@ -0,0 +1,159 @@
+use crate::element::PathElement;
+use crate::style::ShapeStyle;
+
+/// How a [`MovingAverageSeries`] smooths the values of the data points
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum MovingAverage {
+    /// The simple moving average, i.e. the mean of the last `n` values, which starts at the
+    /// `n`-th data point once the window is full
+    Simple(usize),
+    /// The exponential moving average with the smoothing factor `2 / (n + 1)`, which starts at
+    /// the first value
+    Exponential(usize),
+}
+
+impl MovingAverage {
+    /**
+    Smooths the values of the data points.
+
+    - `iter`: The data points, in the order along the X axis
+    - **returns** The smoothed data points, a window of zero is taken as one
+
+    ```
+    use plotters::prelude::*;
+    let data = [(0, 1.0), (1, 3.0), (2, 5.0), (3, 3.0)];
+    assert_eq!(MovingAverage::Simple(2).smooth(data), [(1, 2.0), (2, 4.0), (3, 4.0)]);
+    assert_eq!(MovingAverage::Exponential(3).smooth(data), [(0, 1.0), (1, 2.0), (2, 3.5), (3, 3.25)]);
+    ```
+    */
+    pub fn smooth<X, I: IntoIterator<Item = (X, f64)>>(&self, iter: I) -> Vec<(X, f64)> {
+        let data: Vec<_> = iter.into_iter().collect();
+        match *self {
+            MovingAverage::Simple(window) => {
+                let window = window.max(1);
+                let values: Vec<_> = data.iter().map(|(_, y)| *y).collect();
+                let mut sum = 0.0;
+                data.into_iter()
+                    .enumerate()
+                    .filter_map(|(idx, (x, y))| {
+                        sum += y;
+                        if idx >= window {
+                            sum -= values[idx - window];
+                        }
+                        (idx + 1 >= window).then(|| (x, sum / window as f64))
+                    })
+                    .collect()
+            }
+            MovingAverage::Exponential(window) => {
+                let alpha = 2.0 / (window.max(1) as f64 + 1.0);
+                let mut average = None;
+                data.into_iter()
+                    .map(|(x, y)| {
+                        let value = average.map_or(y, |avg: f64| avg + alpha * (y - avg));
+                        average = Some(value);
+                        (x, value)
+                    })
+                    .collect()
+            }
+        }
+    }
+}
+
+/**
+The moving average series, which smooths the data points with a [`MovingAverage`] and draws
+the smoothed values as a line, i.e. as the trend overlaid on a noisy time series.
+
+# Example
+
+```
+use plotters::prelude::*;
+let drawing_area = SVGBackend::new("moving_average_series.svg", (300, 200)).into_drawing_area();
+drawing_area.fill(&WHITE).unwrap();
+let mut chart = ChartBuilder::on(&drawing_area)
+    .margin(10)
+    .set_left_and_bottom_label_area_size(20)
+    .build_cartesian_2d(0..10, 0.0..6.0)
+    .unwrap();
+chart.configure_mesh().draw().unwrap();
+let data = [3.0, 4.5, 2.5, 4.0, 5.0, 3.5, 4.0, 2.0, 3.0, 1.5, 2.5];
+let data: Vec<_> = (0..).zip(data).collect();
+chart.draw_series(LineSeries::new(data.clone(), BLACK.mix(0.3))).unwrap();
+chart
+    .draw_series(MovingAverageSeries::new(data.clone(), MovingAverage::Simple(3), BLUE))
+    .unwrap();
+chart
+    .draw_series(MovingAverageSeries::new(data, MovingAverage::Exponential(3), RED))
+    .unwrap();
+```
+*/
+pub struct MovingAverageSeries<X> {
+    element: Option<PathElement<(X, f64)>>,
+}
+
+impl<X> MovingAverageSeries<X> {
+    /**
+    Creates a new moving average series.
+
+    - `iter`: The data points, in the order along the X axis
+    - `average`: How the values are smoothed
+    - `style`: The style of the line
+    - **returns** The newly created series
+
+    See [`MovingAverageSeries`] for more information and examples.
+    */
+    pub fn new<I: IntoIterator<Item = (X, f64)>, S: Into<ShapeStyle>>(
+        iter: I,
+        average: MovingAverage,
+        style: S,
+    ) -> Self {
+        Self {
+            element: Some(PathElement::new(average.smooth(iter), style)),
+        }
+    }
+}
+
+impl<X> Iterator for MovingAverageSeries<X> {
+    type Item = PathElement<(X, f64)>;
+    fn next(&mut self) -> Option<Self::Item> {
+        self.element.take()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_moving_average_series() {
+        let data = [(0.0, 2.0), (1.0, 4.0), (2.0, 6.0), (3.0, 2.0)];
+        assert_eq!(
+            MovingAverage::Simple(3).smooth(data),
+            [(2.0, 4.0), (3.0, 4.0)]
+        );
+        assert_eq!(MovingAverage::Simple(0).smooth(data), data);
+        assert_eq!(MovingAverage::Simple(5).smooth(data), []);
+        assert_eq!(MovingAverage::Exponential(1).smooth(data), data);
+        assert_eq!(
+            MovingAverage::Exponential(3).smooth(data),
+            [(0.0, 2.0), (1.0, 3.0), (2.0, 4.5), (3.0, 3.25)]
+        );
+
+        let drawing_area = create_mocked_drawing_area(101, 101, |m| {
+            m.check_draw_path(|_, _, path| {
+                assert_eq!(path, [(50, 50), (100, 50)]);
+            });
+            m.drop_check(|b| assert_eq!(b.num_draw_path_call, 1));
+        });
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0.0..100.0, 0.0..100.0)
+            .unwrap();
+        let data = [(0.0, 0.0), (50.0, 100.0), (100.0, 0.0)];
+        chart
+            .draw_series(MovingAverageSeries::new(
+                data,
+                MovingAverage::Simple(2),
+                RED,
+            ))
+            .unwrap();
+    }
+}
@@ -1,12 +1,61 @@
-use crate::element::PointElement;
+use std::cmp::Ordering;
+use std::marker::PhantomData;
+
+use crate::chart::ChartContext;
+use crate::coord::cartesian::Cartesian3d;
+use crate::coord::ranged1d::Ranged;
+use crate::element::{Circle, DynElement, IntoDynElement, PathElement, PointElement};
 use crate::style::{ShapeStyle, SizeDesc};
+use plotters_backend::DrawingBackend;
+
+/**
+The point plot object, which takes an iterator of points in guest coordinate system
+and create an element for each point.
+
+With [`PointSeries::with_sizes()`], each point has a value as the third dimension, which is
+mapped to the area of its marker, i.e. the radius of a circle grows with the square root of the
+value, so that a value twice as big looks twice as big. The largest value, or
+[`PointSeries::max_value()`] when it's set, gets the given size, and the points with a
+non-positive value are skipped. [`PointSeries::size_legend()`] gives the marker sizes of a size
+legend, whose rows are as tall as their markers when the legends are given the sizes by
+[`crate::chart::SeriesAnno::legend_size()`].
+
+# Examples
 
-/// The point plot object, which takes an iterator of points in guest coordinate system
-/// and create an element for each point
+```
+use plotters::prelude::*;
+let drawing_area = SVGBackend::new("bubble_series.svg", (300, 200)).into_drawing_area();
+drawing_area.fill(&WHITE).unwrap();
+let mut chart = ChartBuilder::on(&drawing_area)
+    .margin(5)
+    .set_left_and_bottom_label_area_size(20)
+    .build_cartesian_2d(0.0..10.0, 0.0..10.0)
+    .unwrap();
+chart.configure_mesh().draw().unwrap();
+let style = BLUE.mix(0.5).filled();
+let series = PointSeries::<_, _, Circle<_, _>, _>::with_sizes(
+    [((2.0, 3.0), 10.0), ((5.0, 6.0), 40.0), ((8.0, 4.0), 90.0)],
+    20,
+    style,
+);
+let legend = series.size_legend([10.0, 50.0, 100.0]);
+chart.draw_series(series).unwrap();
+for (label, size) in legend {
+    chart
+        .add_series_label(label)
+        .legend(move |(x, y)| Circle::new((x + size as i32, y), size, style))
+        .legend_size((2 * size, 2 * size));
+}
+chart.configure_series_labels().draw().unwrap();
+```
+*/
 pub struct PointSeries<'a, Coord, I: IntoIterator<Item = Coord>, E, Size: SizeDesc + Clone> {
     style: ShapeStyle,
     size: Size,
     data_iter: I::IntoIter,
+    values: std::vec::IntoIter<f64>,
+    max_value: f64,
+    scale: fn(&Size, f64) -> Size,
     make_point: &'a dyn Fn(Coord, Size, ShapeStyle) -> E,
 }
 
@@ -15,9 +64,12 @@ impl<'a, Coord, I: IntoIterator<Item = Coord>, E, Size: SizeDesc + Clone> Iterat
 {
     type Item = E;
     fn next(&mut self) -> Option<Self::Item> {
-        self.data_iter
-            .next()
-            .map(|x| (self.make_point)(x, self.size.clone(), self.style))
+        let point = self.data_iter.next()?;
+        let size = match self.values.next() {
+            Some(value) => (self.scale)(&self.size, (value / self.max_value).sqrt()),
+            None => self.size.clone(),
+        };
+        Some((self.make_point)(point, size, self.style))
     }
 }
 
@@ -34,6 +86,9 @@ where
             data_iter: iter.into_iter(),
             size,
             style: style.into(),
+            values: vec![].into_iter(),
+            max_value: 0.0,
+            scale: |size, _| size.clone(),
             make_point: &|a, b, c| E::make_point(a, b, c),
         }
     }
@@ -55,7 +110,315 @@ impl<'a, Coord, I: IntoIterator<Item = Coord>, E, Size: SizeDesc + Clone>
             data_iter: iter.into_iter(),
             size,
             style: style.into(),
+            values: vec![].into_iter(),
+            max_value: 0.0,
+            scale: |size, _| size.clone(),
             make_point: cons,
         }
     }
 }
+
+impl<'a, Coord, E: PointElement<Coord, u32>> PointSeries<'a, Coord, Vec<Coord>, E, u32> {
+    /// Create a new point series with the marker sizes mapped from the values of the points.
+    /// See [`PointSeries`] for more information and examples.
+    ///
+    /// - `iter`: The points with their values
+    /// - `max_size`: The size of the marker of the largest value in pixels
+    /// - `style`: The style of the markers
+    /// - **returns** The newly created series
+    pub fn with_sizes<S: Into<ShapeStyle>, V: IntoIterator<Item = (Coord, f64)>>(
+        iter: V,
+        max_size: u32,
+        style: S,
+    ) -> Self {
+        let (points, values): (Vec<_>, Vec<_>) =
+            iter.into_iter().filter(|(_, value)| *value > 0.0).unzip();
+        let max_value = values
+            .iter()
+            .copied()
+            .filter(|v| v.is_finite())
+            .fold(0.0, f64::max);
+        Self {
+            data_iter: points.into_iter(),
+            size: max_size,
+            style: style.into(),
+            values: values.into_iter(),
+            max_value,
+            scale: |size, factor| (f64::from(*size) * factor).round() as u32,
+            make_point: &|a, b, c| E::make_point(a, b, c),
+        }
+    }
+}
+
+impl<'a, Coord, I: IntoIterator<Item = Coord>, E> PointSeries<'a, Coord, I, E, u32> {
+    /// Set the value which gets the full size instead of the largest value, which keeps the
+    /// sizes of several series comparable
+    pub fn max_value(mut self, value: f64) -> Self {
+        if value > 0.0 {
+            self.max_value = value;
+        }
+        self
+    }
+
+    /// Get the size of the marker of a value in pixels
+    pub fn size_of(&self, value: f64) -> u32 {
+        if !(value > 0.0 && self.max_value > 0.0) {
+            return 0;
+        }
+        (self.scale)(&self.size, (value / self.max_value).sqrt())
+    }
+
+    /// Get the label and the marker size of each value of a size legend
+    pub fn size_legend<L: IntoIterator<Item = f64>>(&self, values: L) -> Vec<(String, u32)> {
+        values
+            .into_iter()
+            .map(|v| (format!("{}", v), self.size_of(v)))
+            .collect()
+    }
+}
+
+/**
+The 3D point plot object, which projects the points through the 3D coordinate of the chart and
+uses the depth of the points as a visual cue.
+
+The points are drawn from the farthest to the nearest one, so that the nearer points cover the
+farther ones. With [`Point3DSeries::depth_cue()`], the sizes and the opacities of the markers
+decrease with the depth, from the nearest point which keeps the given size and style to the
+farthest one. The drop lines connect the points to the base plane, i.e. the lower end of the Y
+axis, which helps to read the positions of points floating in the space.
+
+# Examples
+
+```
+use plotters::prelude::*;
+let drawing_area = SVGBackend::new("point_3d_series.svg", (640, 480)).into_drawing_area();
+drawing_area.fill(&WHITE).unwrap();
+let mut chart = ChartBuilder::on(&drawing_area)
+    .margin(10)
+    .build_cartesian_3d(-1.0..1.0, 0.0..1.0, -1.0..1.0)
+    .unwrap();
+chart.configure_axes().draw().unwrap();
+let points = (0..50).map(|i| {
+    let t = i as f64 / 50.0;
+    ((t * 20.0).cos() * t, t, (t * 20.0).sin() * t)
+});
+chart
+    .draw_series(
+        Point3DSeries::new(&chart, points, 6, BLUE.filled())
+            .depth_cue(0.5, 0.3)
+            .drop_lines(Some(BLACK.mix(0.3))),
+    )
+    .unwrap();
+```
+*/
+pub struct Point3DSeries<DB: DrawingBackend, X, Y, Z> {
+    points: Vec<((X, Y, Z), f64)>,
+    base: Y,
+    size: u32,
+    style: ShapeStyle,
+    size_factor: f64,
+    alpha_factor: f64,
+    drop_style: Option<ShapeStyle>,
+    idx: usize,
+    dropped: bool,
+    _p: PhantomData<DB>,
+}
+
+impl<DB: DrawingBackend, X, Y: Clone, Z> Point3DSeries<DB, X, Y, Z> {
+    /// Create a new 3D point series without the depth cue
+    ///
+    /// - `parent`: The 3D chart on which the series is drawn, of which the projection gives the
+    ///   depths of the points
+    /// - `iter`: The points
+    /// - `size`: The size of the nearest marker in pixels
+    /// - `style`: The style of the nearest marker
+    /// - **returns** The newly created series
+    pub fn new<XR, YR, ZR, I, S>(
+        parent: &ChartContext<DB, Cartesian3d<XR, YR, ZR>>,
+        iter: I,
+        size: u32,
+        style: S,
+    ) -> Self
+    where
+        XR: Ranged<ValueType = X>,
+        YR: Ranged<ValueType = Y>,
+        ZR: Ranged<ValueType = Z>,
+        I: IntoIterator<Item = (X, Y, Z)>,
+        S: Into<ShapeStyle>,
+    {
+        let coord = parent.as_coord_spec();
+        let mut points: Vec<_> = iter
+            .into_iter()
+            .map(|p| {
+                let depth = f64::from(coord.projected_depth(&p.0, &p.1, &p.2));
+                (p, depth)
+            })
+            .collect();
+        points.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(Ordering::Equal));
+        let (near, far) = match (points.last(), points.first()) {
+            (Some(near), Some(far)) => (near.1, far.1),
+            _ => (0.0, 0.0),
+        };
+        for (_, depth) in points.iter_mut() {
+            *depth = if far > near {
+                (*depth - near) / (far - near)
+            } else {
+                0.0
+            };
+        }
+        Self {
+            points,
+            base: coord.logic_y.range().start,
+            size,
+            style: style.into(),
+            size_factor: 1.0,
+            alpha_factor: 1.0,
+            drop_style: None,
+            idx: 0,
+            dropped: false,
+            _p: PhantomData,
+        }
+    }
+
+    /// Set the size and the opacity of the farthest marker relative to the nearest one, both
+    /// from 0.0 to 1.0, which is 1.0 for no depth cue by default
+    pub fn depth_cue(mut self, size: f64, alpha: f64) -> Self {
+        self.size_factor = size.clamp(0.0, 1.0);
+        self.alpha_factor = alpha.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Set the style of the drop lines to the base plane, `None` hides them, which is the default
+    pub fn drop_lines<S: Into<ShapeStyle>>(mut self, style: Option<S>) -> Self {
+        self.drop_style = style.map(Into::into);
+        self
+    }
+}
+
+impl<DB: DrawingBackend, X, Y, Z> Iterator for Point3DSeries<DB, X, Y, Z>
+where
+    X: Clone + 'static,
+    Y: Clone + 'static,
+    Z: Clone + 'static,
+{
+    type Item = DynElement<'static, DB, (X, Y, Z)>;
+    fn next(&mut self) -> Option<Self::Item> {
+        let ((x, y, z), depth) = self.points.get(self.idx)?.clone();
+        if let (Some(style), false) = (self.drop_style, self.dropped) {
+            self.dropped = true;
+            let base = (x.clone(), self.base.clone(), z.clone());
+            return Some(PathElement::new(vec![base, (x, y, z)], style).into_dyn());
+        }
+        self.idx += 1;
+        self.dropped = false;
+        let cue = |factor: f64| 1.0 - depth * (1.0 - factor);
+        let size = (f64::from(self.size) * cue(self.size_factor)).round() as u32;
+        let mut style = self.style;
+        style.color.3 *= cue(self.alpha_factor);
+        Some(Circle::new((x, y, z), size, style).into_dyn())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_point_series_with_sizes() {
+        let series = PointSeries::<_, _, Circle<_, _>, _>::with_sizes(
+            [
+                ((0, 0), 25.0),
+                ((1, 1), 100.0),
+                ((2, 2), 0.0),
+                ((3, 3), f64::NAN),
+            ],
+            20,
+            RED,
+        );
+        assert_eq!(series.size_of(25.0), 10);
+        assert_eq!(series.size_of(-1.0), 0);
+        assert_eq!(
+            series.size_legend([1.0, 100.0]),
+            [("1".to_string(), 2), ("100".to_string(), 20)]
+        );
+        assert_eq!(series.count(), 2);
+        let series = PointSeries::<_, _, Circle<_, _>, _>::with_sizes([((0, 0), 1.0)], 20, RED);
+        assert_eq!(series.max_value(400.0).size_of(100.0), 10);
+
+        /* The legend rows are as tall as their markers, and the labels are right to them */
+        let drawing_area = create_mocked_drawing_area(201, 201, |m| {
+            m.check_draw_circle(|_, _, _, _, radius| assert_eq!(radius, 10));
+            m.check_draw_circle(|_, _, _, _, radius| assert_eq!(radius, 20));
+            m.check_draw_text(|_, _, _, pos, text| assert_eq!((pos, text), ((168, 81), "25")));
+            m.check_draw_text(|_, _, _, pos, text| assert_eq!((pos, text), ((168, 114), "100")));
+            m.check_draw_circle(|_, _, _, pos, radius| assert_eq!((pos, radius), ((128, 85), 10)));
+            m.check_draw_circle(|_, _, _, pos, radius| assert_eq!((pos, radius), ((138, 118), 20)));
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_circle_call, 4);
+                assert_eq!(b.num_draw_text_call, 2);
+            });
+        });
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0.0..100.0, 0.0..100.0)
+            .unwrap();
+        let series = PointSeries::<_, _, Circle<_, _>, _>::with_sizes(
+            [((20.0, 20.0), 25.0), ((80.0, 80.0), 100.0)],
+            20,
+            BLUE,
+        );
+        let legend = series.size_legend([25.0, 100.0]);
+        chart.draw_series(series).unwrap();
+        for (label, size) in legend {
+            chart
+                .add_series_label(label)
+                .legend(move |(x, y)| Circle::new((x + size as i32, y), size, BLUE))
+                .legend_size((2 * size, 2 * size));
+        }
+        chart.configure_series_labels().draw().unwrap();
+    }
+
+    #[test]
+    fn test_point_3d_series() {
+        let drawing_area = create_mocked_drawing_area(200, 200, |m| {
+            m.check_draw_circle(|c, _, _, _, radius| {
+                assert_eq!(radius, 5);
+                assert_eq!(c.3, 0.5);
+            });
+            m.check_draw_circle(|c, _, _, _, radius| {
+                assert_eq!(radius, 10);
+                assert_eq!(c.3, 1.0);
+            });
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_circle_call, 2);
+                assert_eq!(b.num_draw_path_call, 2);
+            });
+        });
+        let chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_3d(0.0..1.0, 0.0..1.0, 0.0..1.0)
+            .unwrap();
+        let coord = chart.as_coord_spec();
+        let (a, b) = ((0.0, 0.5, 0.0), (1.0, 0.5, 1.0));
+        let (near, far) =
+            if coord.projected_depth(&a.0, &a.1, &a.2) < coord.projected_depth(&b.0, &b.1, &b.2) {
+                (a, b)
+            } else {
+                (b, a)
+            };
+        let series = Point3DSeries::new(&chart, [near, far], 10, RED)
+            .depth_cue(0.5, 0.5)
+            .drop_lines(Some(BLACK));
+        assert_eq!(series.points[0].0, far);
+        assert_eq!(series.base, 0.0);
+        assert_eq!(series.count(), 4);
+
+        let mut chart = chart;
+        chart
+            .draw_series(
+                Point3DSeries::new(&chart, [near, far], 10, RED)
+                    .depth_cue(0.5, 0.5)
+                    .drop_lines(Some(BLACK)),
+            )
+            .unwrap();
+    }
+}
@@ -0,0 +1,269 @@
+use crate::element::{Drawable, PointCollection};
+use crate::style::colors::colormaps::ColorMap;
+use crate::style::{Color, RGBAColor, ShapeStyle, BLACK};
+use plotters_backend::{BackendCoord, DrawingBackend, DrawingErrorKind};
+
+/// The default length of the arrow heads in pixels
+const DEFAULT_HEAD_LENGTH: u32 = 8;
+
+/// The default width of the arrow heads in pixels
+const DEFAULT_HEAD_WIDTH: u32 = 6;
+
+/**
+An arrow of a [`QuiverSeries`], which is a line from the tail to the tip with a filled triangular
+head at the tip. The head is shrunk to the length of the arrow if the arrow is shorter than it.
+*/
+pub struct QuiverArrow {
+    points: [(f64, f64); 2],
+    style: ShapeStyle,
+    head_length: u32,
+    head_width: u32,
+}
+
+impl<'a> PointCollection<'a, (f64, f64)> for &'a QuiverArrow {
+    type Point = &'a (f64, f64);
+    type IntoIter = &'a [(f64, f64)];
+    fn point_iter(self) -> &'a [(f64, f64)] {
+        &self.points
+    }
+}
+
+impl<DB: DrawingBackend> Drawable<DB> for QuiverArrow {
+    fn draw<I: Iterator<Item = BackendCoord>>(
+        &self,
+        mut points: I,
+        backend: &mut DB,
+        _: (u32, u32),
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let (tail, tip) = match (points.next(), points.next()) {
+            (Some(tail), Some(tip)) => (tail, tip),
+            _ => return Ok(()),
+        };
+        let (dx, dy) = (f64::from(tip.0 - tail.0), f64::from(tip.1 - tail.1));
+        let length = dx.hypot(dy);
+        if length == 0.0 {
+            return Ok(());
+        }
+        let head_length = f64::from(self.head_length).min(length);
+        let head_width =
+            f64::from(self.head_width) * head_length / f64::from(self.head_length.max(1));
+        let base = draw_arrow_head(
+            backend,
+            tip,
+            (dx, dy),
+            (head_length, head_width),
+            &self.style,
+        )?;
+        backend.draw_line(tail, base, &self.style)
+    }
+}
+
+/// Draw a filled triangular arrow head with the tip at the given point
+///
+/// - `tip`: The tip of the arrow head
+/// - `direction`: The direction of the arrow, which doesn't need to be normalized
+/// - `(length, width)`: The length and the width of the arrow head in pixels
+/// - `style`: The style of the arrow, of which the color fills the head
+/// - **returns** The middle of the base of the head, where the shaft of the arrow ends
+pub(super) fn draw_arrow_head<DB: DrawingBackend>(
+    backend: &mut DB,
+    tip: BackendCoord,
+    direction: (f64, f64),
+    (length, width): (f64, f64),
+    style: &ShapeStyle,
+) -> Result<BackendCoord, DrawingErrorKind<DB::ErrorType>> {
+    let norm = direction.0.hypot(direction.1);
+    if norm == 0.0 || length <= 0.0 {
+        return Ok(tip);
+    }
+    let (ux, uy) = (direction.0 / norm, direction.1 / norm);
+    let half_width = width / 2.0;
+    let base = (
+        f64::from(tip.0) - ux * length,
+        f64::from(tip.1) - uy * length,
+    );
+    let to_coord = |x: f64, y: f64| (x.round() as i32, y.round() as i32);
+    backend.fill_polygon(
+        vec![
+            tip,
+            to_coord(base.0 - uy * half_width, base.1 + ux * half_width),
+            to_coord(base.0 + uy * half_width, base.1 - ux * half_width),
+        ],
+        &style.color.filled(),
+    )?;
+    Ok(to_coord(base.0, base.1))
+}
+
+/**
+Presents a vector field as arrows, like the `quiver` plots of matplotlib.
+
+Each sample is a point `(x, y)` with a vector `(u, v)`, which is drawn as an arrow from the point
+to `(x + u * scale, y + v * scale)`, so the arrows are scaled in the units of the axes by
+[`QuiverSeries::scale()`]. The heads of the arrows are sized in pixels, so they don't depend on
+the scale. The arrows are colored with the style, or with the magnitudes of the vectors picked
+from a colormap set by [`QuiverSeries::color_by_magnitude()`]. The samples with a non-finite
+component are skipped.
+
+# Examples
+
+```
+use plotters::prelude::*;
+let drawing_area = SVGBackend::new("quiver_series.svg", (300, 300)).into_drawing_area();
+drawing_area.fill(&WHITE).unwrap();
+let mut chart = ChartBuilder::on(&drawing_area)
+    .margin(10)
+    .set_left_and_bottom_label_area_size(20)
+    .build_cartesian_2d(-2.2..2.2, -2.2..2.2)
+    .unwrap();
+chart.configure_mesh().draw().unwrap();
+let samples = (-4..=4).flat_map(|i| {
+    (-4..=4).map(move |j| {
+        let (x, y) = (i as f64 / 2.0, j as f64 / 2.0);
+        (x, y, -y, x)
+    })
+});
+chart
+    .draw_series(
+        QuiverSeries::new(samples)
+            .scale(0.2)
+            .head_size(6, 4)
+            .color_by_magnitude(ViridisRGB),
+    )
+    .unwrap();
+```
+*/
+pub struct QuiverSeries<'a> {
+    samples: std::vec::IntoIter<(f64, f64, f64, f64)>,
+    magnitude_range: (f64, f64),
+    scale: f64,
+    style: ShapeStyle,
+    colormap: Option<Box<dyn Fn(f64) -> RGBAColor + 'a>>,
+    head_length: u32,
+    head_width: u32,
+}
+
+impl<'a> QuiverSeries<'a> {
+    /**
+    Creates a quiver series.
+
+    - `samples`: The `(x, y, u, v)` samples, i.e. the points and the vectors at the points
+    - **returns** The newly created series
+
+    See [`QuiverSeries`] for more information and examples.
+    */
+    pub fn new<I: IntoIterator<Item = (f64, f64, f64, f64)>>(samples: I) -> Self {
+        let samples: Vec<_> = samples
+            .into_iter()
+            .filter(|(x, y, u, v)| [x, y, u, v].iter().all(|c| c.is_finite()))
+            .collect();
+        let magnitude_range = samples.iter().fold(
+            (f64::INFINITY, f64::NEG_INFINITY),
+            |(min, max), (_, _, u, v)| (min.min(u.hypot(*v)), max.max(u.hypot(*v))),
+        );
+        Self {
+            samples: samples.into_iter(),
+            magnitude_range,
+            scale: 1.0,
+            style: BLACK.into(),
+            colormap: None,
+            head_length: DEFAULT_HEAD_LENGTH,
+            head_width: DEFAULT_HEAD_WIDTH,
+        }
+    }
+
+    /// Set the factor by which the vectors are multiplied to get the arrows in the units of the
+    /// axes, which is 1.0 by default
+    pub fn scale(mut self, scale: f64) -> Self {
+        self.scale = scale;
+        self
+    }
+
+    /// Set the style of the arrows, of which the color is replaced by the colormap if the arrows
+    /// are colored with the magnitudes
+    pub fn style<S: Into<ShapeStyle>>(mut self, style: S) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Set the length and the width of the arrow heads in pixels
+    pub fn head_size(mut self, length: u32, width: u32) -> Self {
+        self.head_length = length;
+        self.head_width = width;
+        self
+    }
+
+    /// Color the arrows with the magnitudes of the vectors, which are normalized with the range
+    /// of the magnitudes of the samples
+    pub fn color_by_magnitude<C: Color, M: ColorMap<C, f64> + 'a>(mut self, colormap: M) -> Self {
+        self.colormap = Some(Box::new(move |h| colormap.get_color(h).to_rgba()));
+        self
+    }
+}
+
+impl<'a> Iterator for QuiverSeries<'a> {
+    type Item = QuiverArrow;
+    fn next(&mut self) -> Option<Self::Item> {
+        let (x, y, u, v) = self.samples.next()?;
+        let mut style = self.style;
+        if let Some(colormap) = &self.colormap {
+            let (min, max) = self.magnitude_range;
+            let h = if max > min {
+                (u.hypot(v) - min) / (max - min)
+            } else {
+                0.5
+            };
+            style.color = colormap(h);
+        }
+        Some(QuiverArrow {
+            points: [(x, y), (x + u * self.scale, y + v * self.scale)],
+            style,
+            head_length: self.head_length,
+            head_width: self.head_width,
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_quiver_series() {
+        let arrows: Vec<_> = QuiverSeries::new([
+            (0.0, 0.0, 1.0, 0.0),
+            (1.0, 1.0, 0.0, 3.0),
+            (2.0, 2.0, f64::NAN, 1.0),
+        ])
+        .scale(0.5)
+        .color_by_magnitude(ViridisRGB)
+        .collect();
+        assert_eq!(arrows.len(), 2);
+        assert_eq!(arrows[1].points, [(1.0, 1.0), (1.0, 2.5)]);
+        assert_eq!(arrows[0].style.color, ViridisRGB::get_color(0.0).to_rgba());
+        assert_eq!(arrows[1].style.color, ViridisRGB::get_color(1.0).to_rgba());
+
+        let drawing_area = create_mocked_drawing_area(101, 101, |m| {
+            m.check_draw_line(|_, _, from, to| {
+                assert_eq!(from, (0, 100));
+                assert_eq!(to, (42, 100));
+            });
+            m.check_fill_polygon(|_, points| {
+                assert_eq!(points, [(50, 100), (42, 103), (42, 97)]);
+            });
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_line_call, 1);
+                assert_eq!(b.num_fill_polygon_call, 1);
+            });
+        });
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0.0..100.0, 0.0..100.0)
+            .unwrap();
+        chart
+            .draw_series(QuiverSeries::new([
+                (0.0, 0.0, 50.0, 0.0),
+                (0.0, 0.0, 0.0, 0.0),
+            ]))
+            .unwrap();
+    }
+}
@@ -0,0 +1,305 @@
+use std::f64::consts::PI;
+use std::ops::Range;
+
+use crate::element::{Drawable, PathElement, PointCollection, Text};
+use crate::style::text_anchor::{HPos, Pos, VPos};
+use crate::style::{ShapeStyle, TextStyle, BLUE};
+use plotters_backend::{BackendCoord, DrawingBackend, DrawingErrorKind};
+
+/**
+The axes of a radar chart, which are angularly spaced around the origin, each with its own label
+and value range.
+
+The axes are laid out in a unit circle, i.e. the values at the start of the ranges are at the
+origin, and the values at the end of the ranges are at the distance 1, so the radar chart is drawn
+on a cartesian chart of the range `-1.0..1.0` on both axes, plus some room for the labels. The
+first axis points up by default, and the other axes follow counterclockwise.
+
+# Examples
+
+```
+use plotters::prelude::*;
+let drawing_area = SVGBackend::new("radar_series.svg", (300, 300)).into_drawing_area();
+drawing_area.fill(&WHITE).unwrap();
+let mut chart = ChartBuilder::on(&drawing_area)
+    .margin(10)
+    .build_cartesian_2d(-1.3..1.3, -1.3..1.3)
+    .unwrap();
+let axes = RadarAxes::new([
+    ("Speed", 0.0..10.0),
+    ("Power", 0.0..100.0),
+    ("Range", 0.0..500.0),
+    ("Price", 50.0..0.0),
+    ("Comfort", 0.0..5.0),
+]);
+chart.draw_series(axes.grid(4, BLACK.mix(0.3))).unwrap();
+chart.draw_series(axes.labels(("sans-serif", 12))).unwrap();
+chart
+    .draw_series(axes.observation([7.0, 80.0, 300.0, 30.0, 4.0]).style(RED.mix(0.4).filled()))
+    .unwrap()
+    .label("Model A");
+chart
+    .draw_series(axes.observation([5.0, 50.0, 450.0, 20.0, 3.0]).style(BLUE.stroke_width(2)))
+    .unwrap()
+    .label("Model B");
+```
+*/
+#[derive(Clone)]
+pub struct RadarAxes {
+    axes: Vec<(String, Range<f64>)>,
+    start_angle: f64,
+}
+
+impl RadarAxes {
+    /// Create the axes of a radar chart
+    ///
+    /// - `axes`: The label and the value range of each axis, in the counterclockwise order
+    /// - **returns** The newly created axes
+    pub fn new<L: Into<String>, I: IntoIterator<Item = (L, Range<f64>)>>(axes: I) -> Self {
+        Self {
+            axes: axes
+                .into_iter()
+                .map(|(label, range)| (label.into(), range))
+                .collect(),
+            start_angle: PI / 2.0,
+        }
+    }
+
+    /// Set the direction of the first axis in degrees, counterclockwise from the positive X
+    /// direction, which is 90 by default, i.e. the first axis points up
+    pub fn start_angle(mut self, degrees: f64) -> Self {
+        self.start_angle = degrees.to_radians();
+        self
+    }
+
+    /// Get the number of the axes
+    pub fn len(&self) -> usize {
+        self.axes.len()
+    }
+
+    /// Check if there's no axis
+    pub fn is_empty(&self) -> bool {
+        self.axes.is_empty()
+    }
+
+    fn direction(&self, axis: usize) -> (f64, f64) {
+        let theta = self.start_angle + 2.0 * PI * axis as f64 / self.axes.len() as f64;
+        (theta.cos(), theta.sin())
+    }
+
+    /// Get the position of a value on an axis. The values out of the range of the axis are
+    /// clamped to the range
+    ///
+    /// - `axis`: The index of the axis
+    /// - `value`: The value on the axis
+    /// - **returns** The position of the value, or `None` if there's no such axis
+    pub fn project(&self, axis: usize, value: f64) -> Option<(f64, f64)> {
+        let (_, range) = self.axes.get(axis)?;
+        let ratio = if range.end != range.start {
+            ((value - range.start) / (range.end - range.start)).clamp(0.0, 1.0)
+        } else {
+            0.0
+        };
+        let (dx, dy) = self.direction(axis);
+        Some((dx * ratio, dy * ratio))
+    }
+
+    /// Get the polygonal grid of the axes, i.e. the spokes from the origin to the ends of the
+    /// axes and the rings connecting the evenly spaced positions on the axes
+    ///
+    /// - `rings`: The number of the rings, the outermost of which connects the ends of the axes
+    /// - `style`: The style of the grid
+    /// - **returns** The elements of the grid, which are empty if there's no axis
+    pub fn grid<S: Into<ShapeStyle>>(
+        &self,
+        rings: usize,
+        style: S,
+    ) -> Vec<PathElement<(f64, f64)>> {
+        if self.axes.is_empty() {
+            return vec![];
+        }
+        let style = style.into();
+        let spokes = (0..self.axes.len()).map(|axis| {
+            let (dx, dy) = self.direction(axis);
+            PathElement::new(vec![(0.0, 0.0), (dx, dy)], style)
+        });
+        let rings = (1..=rings).map(|ring| {
+            let radius = ring as f64 / rings as f64;
+            let points: Vec<_> = (0..=self.axes.len())
+                .map(|axis| {
+                    let (dx, dy) = self.direction(axis % self.axes.len());
+                    (dx * radius, dy * radius)
+                })
+                .collect();
+            PathElement::new(points, style)
+        });
+        spokes.chain(rings).collect()
+    }
+
+    /// Get the labels of the axes, which are put right beyond the ends of the axes
+    ///
+    /// - `style`: The style of the labels
+    /// - **returns** The text elements of the labels
+    pub fn labels<'a, S: Into<TextStyle<'a>>>(
+        &self,
+        style: S,
+    ) -> Vec<Text<'a, (f64, f64), String>> {
+        let style = style.into();
+        self.axes
+            .iter()
+            .enumerate()
+            .map(|(axis, (label, _))| {
+                let (dx, dy) = self.direction(axis);
+                let hpos = match dx {
+                    dx if dx > 0.1 => HPos::Left,
+                    dx if dx < -0.1 => HPos::Right,
+                    _ => HPos::Center,
+                };
+                let vpos = match dy {
+                    dy if dy > 0.1 => VPos::Bottom,
+                    dy if dy < -0.1 => VPos::Top,
+                    _ => VPos::Center,
+                };
+                let pos = (dx * 1.05, dy * 1.05);
+                Text::new(label.clone(), pos, style.pos(Pos::new(hpos, vpos)))
+            })
+            .collect()
+    }
+
+    /// Create the series of an observation
+    ///
+    /// - `values`: The values of the observation, one for each axis. The missing values are taken
+    ///   as the starts of the ranges of their axes, and the extra values are ignored
+    /// - **returns** The newly created series
+    pub fn observation<I: IntoIterator<Item = f64>>(&self, values: I) -> RadarSeries {
+        let mut values = values.into_iter();
+        let points = self
+            .axes
+            .iter()
+            .enumerate()
+            .filter_map(|(axis, (_, range))| {
+                self.project(axis, values.next().unwrap_or(range.start))
+            })
+            .collect();
+        RadarSeries {
+            shape: Some(RadarShape {
+                points,
+                style: BLUE.into(),
+            }),
+        }
+    }
+}
+
+/// The polygon of an observation of a [`RadarSeries`], which is filled if its style is filled,
+/// otherwise stroked
+pub struct RadarShape {
+    points: Vec<(f64, f64)>,
+    style: ShapeStyle,
+}
+
+impl<'a> PointCollection<'a, (f64, f64)> for &'a RadarShape {
+    type Point = &'a (f64, f64);
+    type IntoIter = &'a [(f64, f64)];
+    fn point_iter(self) -> &'a [(f64, f64)] {
+        &self.points
+    }
+}
+
+impl<DB: DrawingBackend> Drawable<DB> for RadarShape {
+    fn draw<I: Iterator<Item = BackendCoord>>(
+        &self,
+        points: I,
+        backend: &mut DB,
+        _: (u32, u32),
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let mut points: Vec<_> = points.collect();
+        if self.style.filled {
+            backend.fill_polygon(points, &self.style)
+        } else if let Some(first) = points.first().copied() {
+            points.push(first);
+            backend.draw_path(points, &self.style)
+        } else {
+            Ok(())
+        }
+    }
+}
+
+/**
+Presents an observation on the axes of a radar chart as a polygon.
+
+The series is created by [`RadarAxes::observation()`], see [`RadarAxes`] for more information and
+examples.
+*/
+pub struct RadarSeries {
+    shape: Option<RadarShape>,
+}
+
+impl RadarSeries {
+    /// Set the style of the polygon, which is filled if the style is filled, otherwise stroked
+    pub fn style<S: Into<ShapeStyle>>(mut self, style: S) -> Self {
+        if let Some(shape) = &mut self.shape {
+            shape.style = style.into();
+        }
+        self
+    }
+}
+
+impl Iterator for RadarSeries {
+    type Item = RadarShape;
+    fn next(&mut self) -> Option<RadarShape> {
+        self.shape.take()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_radar_axes() {
+        let axes = RadarAxes::new([
+            ("a", 0.0..10.0),
+            ("b", 0.0..1.0),
+            ("c", 10.0..0.0),
+            ("d", 0.0..1.0),
+        ]);
+        assert_eq!(axes.len(), 4);
+
+        let (x, y) = axes.project(0, 5.0).unwrap();
+        assert!(x.abs() < 1e-9 && (y - 0.5).abs() < 1e-9);
+        let (x, y) = axes.project(1, 2.0).unwrap();
+        assert!((x + 1.0).abs() < 1e-9 && y.abs() < 1e-9);
+        let (x, y) = axes.project(2, 2.5).unwrap();
+        assert!(x.abs() < 1e-9 && (y + 0.75).abs() < 1e-9);
+        assert!(axes.project(4, 0.0).is_none());
+
+        assert_eq!(axes.grid(3, BLACK).len(), 7);
+        assert_eq!(axes.labels(("sans-serif", 10)).len(), 4);
+
+        let empty = RadarAxes::new(Vec::<(&str, _)>::new());
+        assert!(empty.grid(3, BLACK).is_empty());
+        assert!(empty.labels(("sans-serif", 10)).is_empty());
+        assert_eq!(empty.observation([1.0]).count(), 1);
+
+        let drawing_area = create_mocked_drawing_area(101, 101, |m| {
+            // The missing values are at the origin
+            m.check_fill_polygon(|_, points| {
+                assert_eq!(points, [(50, 0), (0, 50), (50, 50), (50, 50)])
+            });
+            m.drop_check(|b| {
+                assert_eq!(b.num_fill_polygon_call, 1);
+                assert_eq!(b.num_draw_path_call, 1);
+            });
+        });
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(-1.0..1.0, -1.0..1.0)
+            .unwrap();
+        chart
+            .draw_series(axes.observation([10.0, 1.0]).style(RED.filled()))
+            .unwrap();
+        chart
+            .draw_series(axes.observation([1.0, 1.0, 1.0, 1.0, 1.0]))
+            .unwrap();
+    }
+}
@@ -0,0 +1,281 @@
+use std::marker::PhantomData;
+use std::ops::Range;
+
+use crate::chart::ChartContext;
+use crate::coord::cartesian::Cartesian2d;
+use crate::coord::ranged1d::Ranged;
+use crate::data::RegressionFit;
+use crate::element::{DynElement, EmptyElement, IntoDynElement, PathElement, Polygon, Text};
+use crate::style::{Color, IntoFont, ShapeStyle, TextStyle, BLACK, RED};
+use plotters_backend::DrawingBackend;
+
+/**
+Presents the least-squares regression of the points as the fitted line across the X range of the
+chart.
+
+The regression is linear by default, and it fits a polynomial of [`RegressionSeries::degree()`]
+otherwise, see [`RegressionFit`]. The fitted curve is optionally drawn over the confidence band of
+the mean response, and annotated with the coefficient of determination at the upper left corner of
+the chart. Nothing is drawn when the points don't determine the fit.
+
+# Examples
+
+```
+use plotters::prelude::*;
+let drawing_area = SVGBackend::new("regression_series.svg", (300, 200)).into_drawing_area();
+drawing_area.fill(&WHITE).unwrap();
+let points = [(1.0, 1.8), (2.0, 2.9), (3.0, 3.1), (4.0, 4.6), (5.0, 4.9), (6.0, 6.3)];
+let mut chart = ChartBuilder::on(&drawing_area)
+    .margin(5)
+    .set_left_and_bottom_label_area_size(20)
+    .build_cartesian_2d(0.0..7.0, 0.0..8.0)
+    .unwrap();
+chart.configure_mesh().draw().unwrap();
+chart
+    .draw_series(points.iter().map(|p| Circle::new(*p, 3, BLUE.filled())))
+    .unwrap();
+chart
+    .draw_series(
+        RegressionSeries::new(&chart, points)
+            .confidence_band(Some(0.95))
+            .annotate(true),
+    )
+    .unwrap();
+```
+*/
+pub struct RegressionSeries<'a, DB: DrawingBackend> {
+    points: Vec<(f64, f64)>,
+    x_range: Range<f64>,
+    corner: (f64, f64),
+    degree: usize,
+    resolution: usize,
+    style: ShapeStyle,
+    confidence: Option<f64>,
+    band_style: Option<ShapeStyle>,
+    annotate: bool,
+    label_style: TextStyle<'a>,
+    fit: Option<RegressionFit>,
+    state: u32,
+    _p: PhantomData<DB>,
+}
+
+impl<'a, DB: DrawingBackend> RegressionSeries<'a, DB> {
+    /**
+    Creates a regression series.
+
+    - `parent`: The chart on which the series is drawn, of which the X range bounds the line
+    - `points`: The points to fit
+    - **returns** The newly created series
+
+    See [`RegressionSeries`] for more information and examples.
+    */
+    pub fn new<XR, YR, I>(parent: &ChartContext<DB, Cartesian2d<XR, YR>>, points: I) -> Self
+    where
+        XR: Ranged<ValueType = f64>,
+        YR: Ranged<ValueType = f64>,
+        I: IntoIterator<Item = (f64, f64)>,
+    {
+        let coord = parent.as_coord_spec();
+        let (x_range, y_range) = (coord.x_spec().range(), coord.y_spec().range());
+        Self {
+            points: points.into_iter().collect(),
+            corner: (x_range.start, y_range.end),
+            x_range,
+            degree: 1,
+            resolution: 100,
+            style: RED.stroke_width(2),
+            confidence: None,
+            band_style: None,
+            annotate: false,
+            label_style: ("sans-serif", 12).into_font().color(&BLACK),
+            fit: None,
+            state: 0,
+            _p: PhantomData,
+        }
+    }
+
+    /// Set the degree of the polynomial, which is 1 for the linear regression by default
+    pub fn degree(mut self, degree: usize) -> Self {
+        self.degree = degree.max(1);
+        self
+    }
+
+    /// Set the number of the points on the curve of a polynomial and on the bounds of the
+    /// confidence band, which is at least 2
+    pub fn resolution(mut self, resolution: usize) -> Self {
+        self.resolution = resolution.max(2);
+        self
+    }
+
+    /// Set the style of the fitted curve
+    pub fn style<S: Into<ShapeStyle>>(mut self, style: S) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Set the confidence level of the band, i.e. 0.95, `None` hides the band, which is the default
+    pub fn confidence_band(mut self, confidence: Option<f64>) -> Self {
+        self.confidence = confidence;
+        self
+    }
+
+    /// Set the style of the confidence band, which is the translucent color of the curve by default
+    pub fn band_style<S: Into<ShapeStyle>>(mut self, style: S) -> Self {
+        self.band_style = Some(style.into());
+        self
+    }
+
+    /// Set if the coefficient of determination is printed at the upper left corner of the chart
+    pub fn annotate(mut self, annotate: bool) -> Self {
+        self.annotate = annotate;
+        self
+    }
+
+    /// Set the style of the annotation
+    pub fn label_style<S: Into<TextStyle<'a>>>(mut self, style: S) -> Self {
+        self.label_style = style.into();
+        self
+    }
+
+    /// Get the fit of the points, or `None` if the points don't determine it
+    pub fn fit(&self) -> Option<RegressionFit> {
+        RegressionFit::new(&self.points, self.degree)
+    }
+
+    /// Get the X values at which the curve and the band are sampled, the straight line of a
+    /// linear regression needs only its ends but the band around it is curved
+    fn sample_xs(&self, curve: bool) -> Vec<f64> {
+        let count = if curve && self.degree == 1 {
+            2
+        } else {
+            self.resolution
+        };
+        let Range { start, end } = self.x_range;
+        (0..count)
+            .map(|idx| start + (end - start) * idx as f64 / (count - 1) as f64)
+            .collect()
+    }
+}
+
+impl<'a, DB: DrawingBackend + 'a> Iterator for RegressionSeries<'a, DB> {
+    type Item = DynElement<'a, DB, (f64, f64)>;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.state == 0 {
+            self.fit = self.fit();
+            self.state = if self.fit.is_some() { 1 } else { 4 };
+        }
+        let fit = self.fit.as_ref()?;
+        if self.state == 1 {
+            self.state = 2;
+            let xs = self.sample_xs(false);
+            let widths: Option<Vec<_>> = self.confidence.and_then(|confidence| {
+                xs.iter()
+                    .map(|x| fit.confidence_interval(*x, confidence))
+                    .collect()
+            });
+            if let Some(widths) = widths {
+                let upper = xs
+                    .iter()
+                    .zip(&widths)
+                    .map(|(x, w)| (*x, fit.predict(*x) + w));
+                let lower = xs
+                    .iter()
+                    .zip(&widths)
+                    .map(|(x, w)| (*x, fit.predict(*x) - w));
+                let band: Vec<_> = upper.chain(lower.rev()).collect();
+                let style = self
+                    .band_style
+                    .unwrap_or_else(|| self.style.color.mix(0.2).filled());
+                return Some(Polygon::new(band, style).into_dyn());
+            }
+        }
+        if self.state == 2 {
+            self.state = 3;
+            let curve: Vec<_> = self
+                .sample_xs(true)
+                .into_iter()
+                .map(|x| (x, fit.predict(x)))
+                .collect();
+            return Some(PathElement::new(curve, self.style).into_dyn());
+        }
+        if self.state == 3 {
+            self.state = 4;
+            if self.annotate {
+                let text = format!("R\u{b2} = {:.3}", fit.r_squared());
+                let label = EmptyElement::at(self.corner)
+                    + Text::new(text, (5, 5), self.label_style.clone());
+                return Some(label.into_dyn());
+            }
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_regression_series() {
+        let drawing_area = create_mocked_drawing_area(101, 101, |m| {
+            m.check_draw_path(|_, _, points| {
+                assert_eq!(points, [(0, 100), (100, 0)]);
+            });
+            m.check_draw_text(|_, _, _, pos, text| {
+                assert_eq!(text, "R\u{b2} = 1.000");
+                assert_eq!(pos, (5, 5));
+            });
+            m.drop_check(|b| {
+                assert_eq!(b.num_fill_polygon_call, 0);
+                assert_eq!(b.num_draw_path_call, 1);
+                assert_eq!(b.num_draw_text_call, 1);
+            });
+        });
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0.0..100.0, 0.0..100.0)
+            .unwrap();
+        let points = [(10.0, 10.0), (20.0, 20.0), (30.0, 30.0)];
+        let series = RegressionSeries::new(&chart, points).annotate(true);
+        assert_eq!(series.fit().unwrap().coefficients().len(), 2);
+        chart.draw_series(series).unwrap();
+
+        let series = RegressionSeries::new(&chart, [(1.0, 2.0), (2.0, 1.0), (3.0, 2.5)])
+            .degree(2)
+            .resolution(10);
+        assert_eq!(series.sample_xs(true).len(), 10);
+        assert_eq!(series.count(), 1);
+
+        let series = RegressionSeries::new(&chart, [(1.0, 2.0), (2.0, 1.0), (3.0, 2.5)])
+            .confidence_band(Some(0.95));
+        assert_eq!(series.count(), 2);
+        assert_eq!(RegressionSeries::new(&chart, [(1.0, 2.0)]).count(), 0);
+    }
+
+    #[test]
+    fn test_regression_confidence_band() {
+        let points = [(0.0, 0.0), (1.0, 2.0), (2.0, 1.0), (3.0, 3.0)];
+        let drawing_area = create_mocked_drawing_area(301, 201, |m| {
+            m.check_fill_polygon(|_, band| {
+                assert_eq!(band.len(), 14);
+                let width = |idx: usize| f64::from(band[13 - idx].1 - band[idx].1);
+                // The half width is t * s * sqrt(1 / n + (x - 1.5)^2 / 5) with s^2 = 0.9
+                // and t = 4.3027, which is 10 pixels per unit
+                let expected = |x: f64| {
+                    2.0 * 10.0 * 4.3027 * (0.9 * (0.25 + (x - 1.5f64).powi(2) / 5.0)).sqrt()
+                };
+                for (idx, x) in [(0, 0.0), (3, 1.5), (6, 3.0)] {
+                    assert!((width(idx) - expected(x)).abs() <= 1.0);
+                }
+                assert!(width(3) < width(1) && width(1) < width(0));
+            });
+            m.drop_check(|b| assert_eq!(b.num_fill_polygon_call, 1));
+        });
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0.0..3.0, -10.0..10.0)
+            .unwrap();
+        let series = RegressionSeries::new(&chart, points)
+            .resolution(7)
+            .confidence_band(Some(0.95));
+        chart.draw_series(series).unwrap();
+    }
+}
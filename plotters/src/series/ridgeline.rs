@@ -0,0 +1,329 @@
+use std::ops::Range;
+
+use super::layers::layer_style;
+use crate::element::{Drawable, PointCollection};
+use crate::style::text_anchor::{HPos, Pos, VPos};
+use crate::style::{IntoFont, ShapeStyle, TextStyle, BLACK};
+use plotters_backend::{BackendCoord, DrawingBackend, DrawingErrorKind};
+
+/**
+A row of a [`RidgelineSeries`], which is a filled curve above the baseline of the row with the
+label of the row at its left end.
+*/
+pub struct RidgelineRow<'a> {
+    /// The anchor of the label on the baseline, followed by the points of the curve
+    points: Vec<(f64, f64)>,
+    style: ShapeStyle,
+    border_style: ShapeStyle,
+    label: String,
+    label_style: Option<TextStyle<'a>>,
+}
+
+impl<'a, 'b> PointCollection<'a, (f64, f64)> for &'a RidgelineRow<'b> {
+    type Point = &'a (f64, f64);
+    type IntoIter = &'a [(f64, f64)];
+    fn point_iter(self) -> &'a [(f64, f64)] {
+        &self.points
+    }
+}
+
+impl<'a, DB: DrawingBackend> Drawable<DB> for RidgelineRow<'a> {
+    fn draw<I: Iterator<Item = BackendCoord>>(
+        &self,
+        mut points: I,
+        backend: &mut DB,
+        _: (u32, u32),
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let anchor = match points.next() {
+            Some(anchor) => anchor,
+            None => return Ok(()),
+        };
+        let curve: Vec<_> = points.collect();
+        if let (Some(first), Some(last)) = (curve.first(), curve.last()) {
+            let mut area = curve.clone();
+            area.push((last.0, anchor.1));
+            area.push((first.0, anchor.1));
+            backend.fill_polygon(area, &self.style)?;
+            backend.draw_path(curve.iter().copied(), &self.border_style)?;
+        }
+        if let Some(style) = &self.label_style {
+            let style = style.pos(Pos::new(HPos::Left, VPos::Bottom));
+            backend.draw_text(&self.label, &style, (anchor.0 + 2, anchor.1 - 2))?;
+        }
+        Ok(())
+    }
+}
+
+/**
+Presents the distributions of many groups as a ridgeline plot, a.k.a. the joyplot.
+
+Each group is a curve, i.e. a density estimate or a line plot, and the groups are laid out as the
+rows from the top to the bottom of a single chart. The rows have the height of one unit, and the
+curves are scaled to the peak height of `1 + overlap` units, so they overlap the rows above them,
+and the lower rows are drawn over the upper ones. The rows are labelled at the left end of their
+baselines, and they are styled with the colors of [`crate::style::Palette99`] by default.
+
+The Y axis of the chart is in the offset coordinates of the rows, and
+[`RidgelineSeries::baseline()`] and [`RidgelineSeries::map_value()`] map the values of the rows
+to it, while [`RidgelineSeries::row_label()`] labels the Y axis with the rows.
+
+# Examples
+
+```
+use plotters::prelude::*;
+let drawing_area = SVGBackend::new("ridgeline.svg", (300, 250)).into_drawing_area();
+drawing_area.fill(&WHITE).unwrap();
+let bump = |center: f64| {
+    (0..=40).map(move |i| {
+        let x = i as f64 / 4.0;
+        (x, (-(x - center).powi(2)).exp())
+    })
+};
+let ridgeline = RidgelineSeries::new([
+    ("January", bump(3.0)),
+    ("February", bump(4.5)),
+    ("March", bump(6.0)),
+])
+.overlap(0.8);
+let mut chart = ChartBuilder::on(&drawing_area)
+    .margin(10)
+    .x_label_area_size(20)
+    .build_cartesian_2d(ridgeline.x_range(), ridgeline.y_range())
+    .unwrap();
+chart
+    .configure_mesh()
+    .disable_y_mesh()
+    .disable_y_axis()
+    .draw()
+    .unwrap();
+chart.draw_series(ridgeline).unwrap();
+```
+*/
+pub struct RidgelineSeries<'a> {
+    rows: Vec<(String, Vec<(f64, f64)>)>,
+    overlap: f64,
+    normalized: bool,
+    styles: Vec<ShapeStyle>,
+    border_style: ShapeStyle,
+    label_style: Option<TextStyle<'a>>,
+    index: usize,
+}
+
+impl<'a> RidgelineSeries<'a> {
+    /**
+    Creates a ridgeline series.
+
+    - `rows`: The labels and the curves of the rows, from the top to the bottom
+    - **returns** The newly created series
+
+    See [`RidgelineSeries`] for more information and examples.
+    */
+    pub fn new<L, P, I>(rows: I) -> Self
+    where
+        L: Into<String>,
+        P: IntoIterator<Item = (f64, f64)>,
+        I: IntoIterator<Item = (L, P)>,
+    {
+        Self {
+            rows: rows
+                .into_iter()
+                .map(|(label, curve)| (label.into(), curve.into_iter().collect()))
+                .collect(),
+            overlap: 0.5,
+            normalized: false,
+            styles: vec![],
+            border_style: BLACK.into(),
+            label_style: Some(("sans-serif", 12).into_font().into()),
+            index: 0,
+        }
+    }
+
+    /// Set how far the peaks reach into the rows above as a fraction of the row height, which is
+    /// 0.5 by default
+    pub fn overlap(mut self, overlap: f64) -> Self {
+        self.overlap = overlap.max(-1.0);
+        self
+    }
+
+    /// Set if every row is scaled to its own peak instead of the peak of all the rows
+    pub fn normalized(mut self, normalized: bool) -> Self {
+        self.normalized = normalized;
+        self
+    }
+
+    /// Set the fill styles of the rows from the top to the bottom
+    pub fn styles<S: Into<ShapeStyle>, I: IntoIterator<Item = S>>(mut self, styles: I) -> Self {
+        self.styles = styles.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Set the style of the curves, which is black by default
+    pub fn border_style<S: Into<ShapeStyle>>(mut self, style: S) -> Self {
+        self.border_style = style.into();
+        self
+    }
+
+    /// Set the style of the row labels, `None` hides them
+    pub fn label_style<S: Into<TextStyle<'a>>>(mut self, style: Option<S>) -> Self {
+        self.label_style = style.map(Into::into);
+        self
+    }
+
+    /// Get the range of the X axis which fits the curves
+    pub fn x_range(&self) -> Range<f64> {
+        let (min, max) = self.x_bounds();
+        if min < max {
+            min..max
+        } else {
+            min - 0.5..min + 0.5
+        }
+    }
+
+    /// Get the range of the Y axis which fits the rows and the peaks of the top row
+    pub fn y_range(&self) -> Range<f64> {
+        0.0..self.baseline(0) + self.peak_height().max(1.0)
+    }
+
+    /// Get the Y coordinate of the baseline of a row
+    pub fn baseline(&self, row: usize) -> f64 {
+        self.rows.len().saturating_sub(row + 1) as f64
+    }
+
+    /// Map a value of a row to the Y coordinate, in the same scale as the curve of the row
+    pub fn map_value(&self, row: usize, value: f64) -> f64 {
+        self.baseline(row) + value * self.scale(row)
+    }
+
+    /// Get the label of the row whose baseline is at the Y coordinate, which suits
+    /// [`crate::chart::MeshStyle::y_label_formatter()`]
+    pub fn row_label(&self, y: f64) -> Option<&str> {
+        let row = self.rows.len() as f64 - 1.0 - y;
+        if row.fract().abs() > 1e-6 || row < 0.0 {
+            return None;
+        }
+        self.rows.get(row as usize).map(|(label, _)| label.as_str())
+    }
+
+    fn scale(&self, row: usize) -> f64 {
+        let peak = if self.normalized {
+            self.rows
+                .get(row)
+                .map_or(0.0, |(_, curve)| Self::peak(curve))
+        } else {
+            self.rows
+                .iter()
+                .map(|(_, curve)| Self::peak(curve))
+                .fold(0.0, f64::max)
+        };
+        if peak > 0.0 {
+            self.peak_height() / peak
+        } else {
+            0.0
+        }
+    }
+
+    fn peak_height(&self) -> f64 {
+        1.0 + self.overlap
+    }
+
+    fn peak(curve: &[(f64, f64)]) -> f64 {
+        curve.iter().map(|p| p.1).fold(0.0, f64::max)
+    }
+
+    fn x_bounds(&self) -> (f64, f64) {
+        let xs = self
+            .rows
+            .iter()
+            .flat_map(|(_, curve)| curve.iter().map(|p| p.0));
+        let (min, max) = xs.fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), x| {
+            (min.min(x), max.max(x))
+        });
+        if min <= max {
+            (min, max)
+        } else {
+            (0.0, 0.0)
+        }
+    }
+}
+
+impl<'a> Iterator for RidgelineSeries<'a> {
+    type Item = RidgelineRow<'a>;
+    fn next(&mut self) -> Option<Self::Item> {
+        let row = self.index;
+        let label = self.rows.get(row)?.0.clone();
+        self.index += 1;
+
+        let (baseline, scale) = (self.baseline(row), self.scale(row));
+        let mut points = vec![(self.x_bounds().0, baseline)];
+        points.extend(
+            self.rows[row]
+                .1
+                .iter()
+                .map(|&(x, value)| (x, baseline + value * scale)),
+        );
+        Some(RidgelineRow {
+            points,
+            style: layer_style(&self.styles, row),
+            border_style: self.border_style,
+            label,
+            label_style: self.label_style.clone(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_ridgeline_series() {
+        let ridgeline = RidgelineSeries::new([
+            ("a", vec![(0.0, 0.0), (1.0, 2.0), (2.0, 0.0)]),
+            ("b", vec![(1.0, 0.0), (2.0, 1.0), (4.0, 0.0)]),
+        ]);
+        assert_eq!(ridgeline.x_range(), 0.0..4.0);
+        assert_eq!(ridgeline.y_range(), 0.0..2.5);
+        assert_eq!(ridgeline.baseline(0), 1.0);
+        assert_eq!(ridgeline.map_value(0, 2.0), 2.5);
+        assert_eq!(ridgeline.map_value(1, 1.0), 0.75);
+        assert_eq!(ridgeline.row_label(1.0), Some("a"));
+        assert_eq!(ridgeline.row_label(0.0), Some("b"));
+        assert_eq!(ridgeline.row_label(0.5), None);
+        assert_eq!(ridgeline.row_label(2.0), None);
+
+        let normalized = RidgelineSeries::new([("a", vec![(0.0, 2.0)]), ("b", vec![(0.0, 1.0)])])
+            .normalized(true);
+        assert_eq!(normalized.map_value(1, 1.0), 1.5);
+
+        let rows: Vec<_> = ridgeline.collect();
+        assert_eq!(
+            rows[1].points,
+            [(0.0, 0.0), (1.0, 0.0), (2.0, 0.75), (4.0, 0.0)]
+        );
+
+        let drawing_area = create_mocked_drawing_area(101, 101, |m| {
+            m.check_fill_polygon(|_, points| {
+                assert_eq!(points, [(0, 100), (100, 0), (100, 100), (0, 100)]);
+            });
+            m.check_draw_text(|_, _, _, pos, text| {
+                assert_eq!(text, "a");
+                assert_eq!(pos, (2, 98));
+            });
+            m.drop_check(|b| {
+                assert_eq!(b.num_fill_polygon_call, 1);
+                assert_eq!(b.num_draw_path_call, 1);
+                assert_eq!(b.num_draw_text_call, 1);
+            });
+        });
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0.0..100.0, 0.0..1.5)
+            .unwrap();
+        chart
+            .draw_series(RidgelineSeries::new([(
+                "a",
+                vec![(0.0, 0.0), (100.0, 1.0)],
+            )]))
+            .unwrap();
+    }
+}
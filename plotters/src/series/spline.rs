@@ -0,0 +1,222 @@
+use crate::element::{Drawable, PointCollection};
+use crate::style::ShapeStyle;
+use plotters_backend::{BackendCoord, DrawingBackend, DrawingErrorKind};
+
+/// How a [`SplineSeries`] places the control points of the curve between the data points
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum SplineMode {
+    /// The uniform Catmull-Rom spline, of which the tangent at each point is parallel to the
+    /// chord between its neighbours and proportional to the length of the chord
+    CatmullRom,
+    /// The cubic Bezier curves whose control points are along the same chords but scaled by the
+    /// distances to the neighbours, which overshoots less when the points are unevenly spaced
+    Bezier,
+}
+
+/// The smooth curve of a [`SplineSeries`]. The curve is computed in the backend coordinate, which
+/// is the same curve as in the guest coordinate for the linear axes.
+pub struct SplinePathElement<Coord> {
+    points: Vec<Coord>,
+    mode: SplineMode,
+    tension: f64,
+    resolution: u32,
+    style: ShapeStyle,
+}
+
+impl<Coord> SplinePathElement<Coord> {
+    /// Get the control points of the Bezier curves, which are the incoming and the outgoing
+    /// ones of each point
+    fn control_offsets(&self, points: &[(f64, f64)]) -> Vec<((f64, f64), (f64, f64))> {
+        let scale = (1.0 - self.tension) / 3.0;
+        (0..points.len())
+            .map(|idx| {
+                let prev = points[idx.saturating_sub(1)];
+                let next = points[(idx + 1).min(points.len() - 1)];
+                let chord = (next.0 - prev.0, next.1 - prev.1);
+                let (f_in, f_out) = match self.mode {
+                    SplineMode::CatmullRom => (0.5, 0.5),
+                    SplineMode::Bezier => {
+                        let p = points[idx];
+                        let d_in = (p.0 - prev.0).hypot(p.1 - prev.1);
+                        let d_out = (next.0 - p.0).hypot(next.1 - p.1);
+                        if d_in + d_out > 0.0 {
+                            (d_in / (d_in + d_out), d_out / (d_in + d_out))
+                        } else {
+                            (0.0, 0.0)
+                        }
+                    }
+                };
+                (
+                    (chord.0 * scale * f_in, chord.1 * scale * f_in),
+                    (chord.0 * scale * f_out, chord.1 * scale * f_out),
+                )
+            })
+            .collect()
+    }
+}
+
+impl<'a, Coord> PointCollection<'a, Coord> for &'a SplinePathElement<Coord> {
+    type Point = &'a Coord;
+    type IntoIter = &'a [Coord];
+    fn point_iter(self) -> &'a [Coord] {
+        &self.points
+    }
+}
+
+impl<Coord, DB: DrawingBackend> Drawable<DB> for SplinePathElement<Coord> {
+    fn draw<I: Iterator<Item = BackendCoord>>(
+        &self,
+        points: I,
+        backend: &mut DB,
+        _: (u32, u32),
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let points: Vec<_> = points.map(|(x, y)| (x as f64, y as f64)).collect();
+        let first = match points.first() {
+            Some(first) => *first,
+            None => return Ok(()),
+        };
+        let offsets = self.control_offsets(&points);
+        let mut path = vec![(first.0.round() as i32, first.1.round() as i32)];
+        for idx in 1..points.len() {
+            let (p0, p3) = (points[idx - 1], points[idx]);
+            let (_, out) = offsets[idx - 1];
+            let (into, _) = offsets[idx];
+            let p1 = (p0.0 + out.0, p0.1 + out.1);
+            let p2 = (p3.0 - into.0, p3.1 - into.1);
+            for step in 1..=self.resolution.max(1) {
+                let t = f64::from(step) / f64::from(self.resolution.max(1));
+                let s = 1.0 - t;
+                let (a, b, c, d) = (s * s * s, 3.0 * s * s * t, 3.0 * s * t * t, t * t * t);
+                let x = a * p0.0 + b * p1.0 + c * p2.0 + d * p3.0;
+                let y = a * p0.1 + b * p1.1 + c * p2.1 + d * p3.1;
+                path.push((x.round() as i32, y.round() as i32));
+            }
+        }
+        backend.draw_path(path, &self.style)
+    }
+}
+
+/**
+The spline series, which connects the data points with a smooth curve passing through all of
+them rather than the straight lines, which looks better than a [`LineSeries`](crate::series::LineSeries) for sparse data.
+See [`SplineMode`] for how the curve is shaped between the points.
+
+# Example
+
+```
+use plotters::prelude::*;
+let drawing_area = SVGBackend::new("spline_series.svg", (300, 200)).into_drawing_area();
+drawing_area.fill(&WHITE).unwrap();
+let mut chart = ChartBuilder::on(&drawing_area)
+    .margin(10)
+    .set_left_and_bottom_label_area_size(20)
+    .build_cartesian_2d(0.0..6.0, 0.0..4.0)
+    .unwrap();
+chart.configure_mesh().draw().unwrap();
+let data = [(0.0, 1.0), (1.0, 3.0), (2.5, 2.0), (3.0, 3.5), (5.0, 0.5), (6.0, 1.5)];
+chart
+    .draw_series(SplineSeries::new(data, BLUE).mode(SplineMode::Bezier))
+    .unwrap();
+chart
+    .draw_series(PointSeries::of_element(data, 3, BLUE.filled(), &|c, s, st| {
+        Circle::new(c, s, st)
+    }))
+    .unwrap();
+```
+*/
+pub struct SplineSeries<Coord> {
+    element: Option<SplinePathElement<Coord>>,
+}
+
+impl<Coord> SplineSeries<Coord> {
+    /**
+    Creates a new spline series of the Catmull-Rom spline, with no tension and 16 samples for
+    each segment between two points.
+
+    - `iter`: The data points, in the order along the curve
+    - `style`: The style of the curve
+    - **returns** The newly created series
+
+    See [`SplineSeries`] for more information and examples.
+    */
+    pub fn new<I: IntoIterator<Item = Coord>, S: Into<ShapeStyle>>(iter: I, style: S) -> Self {
+        Self {
+            element: Some(SplinePathElement {
+                points: iter.into_iter().collect(),
+                mode: SplineMode::CatmullRom,
+                tension: 0.0,
+                resolution: 16,
+                style: style.into(),
+            }),
+        }
+    }
+
+    /// Set how the control points of the curve are placed
+    pub fn mode(mut self, mode: SplineMode) -> Self {
+        if let Some(element) = &mut self.element {
+            element.mode = mode;
+        }
+        self
+    }
+
+    /// Set the tension of the curve, from 0.0 for the smoothest curve to 1.0 for the straight
+    /// lines between the points
+    pub fn tension(mut self, tension: f64) -> Self {
+        if let Some(element) = &mut self.element {
+            element.tension = tension.clamp(0.0, 1.0);
+        }
+        self
+    }
+
+    /// Set the number of the samples on the curve between two consecutive points
+    pub fn resolution(mut self, samples: u32) -> Self {
+        if let Some(element) = &mut self.element {
+            element.resolution = samples;
+        }
+        self
+    }
+}
+
+impl<Coord> Iterator for SplineSeries<Coord> {
+    type Item = SplinePathElement<Coord>;
+    fn next(&mut self) -> Option<Self::Item> {
+        self.element.take()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_spline_series() {
+        let drawing_area = create_mocked_drawing_area(101, 101, |m| {
+            m.check_draw_path(|_, _, path| {
+                assert_eq!(path.len(), 9);
+                assert_eq!(path[0], (0, 100));
+                assert_eq!(path[4], (50, 50));
+                assert_eq!(path[8], (100, 100));
+                /* The curve is smooth at the peak, so it bulges over the straight lines */
+                assert!(path[2].1 < 75 && path[6].1 < 75);
+            });
+            m.drop_check(|b| assert_eq!(b.num_draw_path_call, 3));
+        });
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0.0..100.0, 0.0..100.0)
+            .unwrap();
+        let data = [(0.0, 0.0), (50.0, 50.0), (100.0, 0.0)];
+        chart
+            .draw_series(SplineSeries::new(data, RED).resolution(4))
+            .unwrap();
+        chart
+            .draw_series(
+                SplineSeries::new(data, RED)
+                    .mode(SplineMode::Bezier)
+                    .resolution(4),
+            )
+            .unwrap();
+        chart
+            .draw_series(SplineSeries::new(data, RED).tension(0.5).resolution(4))
+            .unwrap();
+    }
+}
@@ -0,0 +1,424 @@
+use num_traits::Float;
+
+use super::layers::{layer_legends, layer_style};
+use crate::element::Polygon;
+use crate::style::ShapeStyle;
+
+/**
+Presents multiple aligned series as stacked areas.
+
+Each data item is an X value with one value per layer, in the order of the X values. The values
+of each layer are accumulated on top of the layers before it, and the band between the cumulative
+values below and above the layer is filled with the style of the layer, so the top of the stack
+traces the total. The missing values of an item are treated as zeros. In the normalized mode, the
+values of each item are scaled so that they add up to 100, which shows the share of each layer
+rather than its amount. The layers are styled with the colors of [`crate::style::Palette99`] by
+default, and [`crate::chart::ChartContext::draw_stacked_area_series()`] adds a legend entry for
+each labelled layer.
+
+# Examples
+
+```
+use plotters::prelude::*;
+let drawing_area = SVGBackend::new("stacked_area.svg", (300, 200)).into_drawing_area();
+drawing_area.fill(&WHITE).unwrap();
+let mut chart = ChartBuilder::on(&drawing_area)
+    .margin(5)
+    .set_left_and_bottom_label_area_size(20)
+    .build_cartesian_2d(2018..2022, 0.0..12.0)
+    .unwrap();
+chart.configure_mesh().draw().unwrap();
+chart
+    .draw_stacked_area_series(
+        StackedAreaSeries::new([
+            (2018, [3.0, 2.0, 1.0]),
+            (2019, [3.0, 4.0, 2.0]),
+            (2020, [3.5, 1.0, 5.0]),
+            (2021, [4.0, 2.5, 4.0]),
+            (2022, [4.0, 3.0, 4.5]),
+        ])
+        .styles([RED.mix(0.6).filled(), GREEN.mix(0.6).filled(), BLUE.mix(0.6).filled()])
+        .labels(["Rent", "Food", "Travel"]),
+    )
+    .unwrap();
+chart.configure_series_labels().draw().unwrap();
+```
+*/
+pub struct StackedAreaSeries<X, Y> {
+    xs: Vec<X>,
+    values: Vec<Vec<Y>>,
+    normalized: bool,
+    offset: Option<StreamOffset>,
+    styles: Vec<ShapeStyle>,
+    labels: Vec<String>,
+    /// The cumulative values of each item, starting from zero, which are computed on the first
+    /// band
+    stacks: Option<Vec<Vec<Y>>>,
+    layer: usize,
+}
+
+impl<X: Clone, Y: Float> StackedAreaSeries<X, Y> {
+    /**
+    Creates a stacked area series.
+
+    - `data`: The X values with the values of the layers, from the bottom of the stack to the
+      top, in the order of the X values
+    - **returns** The newly created series
+
+    See [`StackedAreaSeries`] for more information and examples.
+    */
+    pub fn new<V: IntoIterator<Item = Y>, I: IntoIterator<Item = (X, V)>>(data: I) -> Self {
+        let (xs, values) = data
+            .into_iter()
+            .map(|(x, values)| (x, values.into_iter().collect()))
+            .unzip();
+        Self {
+            xs,
+            values,
+            normalized: false,
+            offset: None,
+            styles: vec![],
+            labels: vec![],
+            stacks: None,
+            layer: 0,
+        }
+    }
+
+    /**
+    Sets if the values of each item are scaled to add up to 100. The items of which the values
+    add up to zero are left at zero.
+    */
+    pub fn normalized(mut self, normalized: bool) -> Self {
+        self.normalized = normalized;
+        self
+    }
+
+    /**
+    Sets the styles of the layers, from the bottom of the stack to the top.
+
+    The layers without a style use the colors of [`crate::style::Palette99`].
+    */
+    pub fn styles<S: Into<ShapeStyle>, I: IntoIterator<Item = S>>(mut self, styles: I) -> Self {
+        self.styles = styles.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /**
+    Sets the labels of the layers, from the bottom of the stack to the top.
+
+    The labels are used by [`crate::chart::ChartContext::draw_stacked_area_series()`] for the
+    legend entries.
+    */
+    pub fn labels<L: Into<String>, I: IntoIterator<Item = L>>(mut self, labels: I) -> Self {
+        self.labels = labels.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /**
+    Returns the label and the style of each layer, from the bottom of the stack to the top.
+    */
+    pub fn layers(&self) -> Vec<(String, ShapeStyle)> {
+        layer_legends(&self.labels, &self.styles)
+    }
+
+    /// Get the cumulative values of each item, with the same number of the layers for all items
+    fn stacks(&self) -> Vec<Vec<Y>> {
+        let layers = self.values.iter().map(Vec::len).max().unwrap_or(0);
+        let hundred = Y::from(100).unwrap_or_else(Y::one);
+        let mut stacks: Vec<Vec<Y>> = self
+            .values
+            .iter()
+            .map(|values| {
+                let total = values.iter().fold(Y::zero(), |total, v| total + *v);
+                let scale = match self.normalized {
+                    true if total != Y::zero() => hundred / total,
+                    true => Y::zero(),
+                    false => Y::one(),
+                };
+                let mut stack = vec![Y::zero()];
+                for layer in 0..layers {
+                    let value = values.get(layer).copied().unwrap_or_else(Y::zero);
+                    stack.push(stack[layer] + value * scale);
+                }
+                stack
+            })
+            .collect();
+        if let Some(offset) = self.offset {
+            let baselines = offset.baselines(&stacks);
+            for (stack, baseline) in stacks.iter_mut().zip(baselines) {
+                stack.iter_mut().for_each(|v| *v = *v + baseline);
+            }
+        }
+        stacks
+    }
+}
+
+impl<X: Clone, Y: Float> Iterator for StackedAreaSeries<X, Y> {
+    type Item = Polygon<(X, Y)>;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.stacks.is_none() {
+            self.stacks = Some(self.stacks());
+        }
+        let stacks = self.stacks.as_ref()?;
+        let layer = self.layer;
+        stacks.first().filter(|stack| layer + 1 < stack.len())?;
+        self.layer += 1;
+
+        let upper = self
+            .xs
+            .iter()
+            .zip(stacks)
+            .map(|(x, stack)| (x.clone(), stack[layer + 1]));
+        let lower = self
+            .xs
+            .iter()
+            .zip(stacks)
+            .rev()
+            .map(|(x, stack)| (x.clone(), stack[layer]));
+        Some(Polygon::new(
+            upper.chain(lower).collect::<Vec<_>>(),
+            layer_style(&self.styles, layer),
+        ))
+    }
+}
+
+/// How a [`StreamgraphSeries`] shifts the baseline of the stack at each X value
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum StreamOffset {
+    /// The stack is centered around zero, so the streamgraph is symmetric
+    Silhouette,
+    /// The baseline is shifted to minimize the changes of the slopes of the layers, weighted by
+    /// their thicknesses, which is the flowing layout of Byron and Wattenberg. The baselines are
+    /// then shifted together so that the stack is centered around zero on average.
+    Wiggle,
+}
+
+impl StreamOffset {
+    /// Get the baseline of each item from the cumulative values stacked from zero
+    fn baselines<Y: Float>(self, stacks: &[Vec<Y>]) -> Vec<Y> {
+        let totals = stacks
+            .iter()
+            .map(|stack| stack.last().copied().unwrap_or_else(Y::zero));
+        let two = Y::one() + Y::one();
+        match self {
+            StreamOffset::Silhouette => totals.map(|total| -total / two).collect(),
+            StreamOffset::Wiggle => {
+                let mut baseline = Y::zero();
+                let mut baselines = vec![baseline];
+                for pair in stacks.windows(2) {
+                    let (prev, next) = (&pair[0], &pair[1]);
+                    let (mut weights, mut moments, mut below) = (Y::zero(), Y::zero(), Y::zero());
+                    for layer in 1..next.len().min(prev.len()) {
+                        let value = next[layer] - next[layer - 1];
+                        let change = value - (prev[layer] - prev[layer - 1]);
+                        weights = weights + value;
+                        moments = moments + value * (below + change / two);
+                        below = below + change;
+                    }
+                    if weights != Y::zero() {
+                        baseline = baseline - moments / weights;
+                    }
+                    baselines.push(baseline);
+                }
+                let centers: Vec<_> = baselines
+                    .iter()
+                    .zip(totals)
+                    .map(|(baseline, total)| *baseline + total / two)
+                    .collect();
+                let count = Y::from(centers.len().max(1)).unwrap_or_else(Y::one);
+                let mean = centers.iter().fold(Y::zero(), |sum, c| sum + *c) / count;
+                baselines
+                    .into_iter()
+                    .map(|baseline| baseline - mean)
+                    .collect()
+            }
+        }
+    }
+}
+
+/**
+Presents multiple aligned series as a streamgraph, which is a stacked area chart of which the
+baseline flows around zero, to show how the composition of a total changes over time.
+
+The data and the layers are the same as [`StackedAreaSeries`], except that the whole stack of
+each item is shifted by the baseline computed by the [`StreamOffset`], which is
+[`StreamOffset::Wiggle`] by default. The values should be non-negative. A streamgraph is drawn
+with [`crate::chart::ChartContext::draw_stacked_area_series()`] for the legend entries as well.
+
+# Examples
+
+```
+use plotters::prelude::*;
+let drawing_area = SVGBackend::new("streamgraph.svg", (300, 200)).into_drawing_area();
+drawing_area.fill(&WHITE).unwrap();
+let mut chart = ChartBuilder::on(&drawing_area)
+    .margin(5)
+    .set_left_and_bottom_label_area_size(20)
+    .build_cartesian_2d(0.0..10.0, -10.0..10.0)
+    .unwrap();
+chart.configure_mesh().draw().unwrap();
+let data = (0..=20).map(|x| {
+    let x = x as f64 / 2.0;
+    (x, [2.0 + x.sin(), 3.0 + (x / 2.0).cos() * 2.0, 1.0 + x / 4.0])
+});
+chart
+    .draw_stacked_area_series(
+        StreamgraphSeries::new(data)
+            .offset(StreamOffset::Wiggle)
+            .labels(["a", "b", "c"]),
+    )
+    .unwrap();
+```
+*/
+pub struct StreamgraphSeries<X, Y> {
+    inner: StackedAreaSeries<X, Y>,
+}
+
+impl<X: Clone, Y: Float> StreamgraphSeries<X, Y> {
+    /**
+    Creates a streamgraph series.
+
+    - `data`: The X values with the values of the layers, from the bottom of the stack to the
+      top, in the order of the X values
+    - **returns** The newly created series
+
+    See [`StreamgraphSeries`] for more information and examples.
+    */
+    pub fn new<V: IntoIterator<Item = Y>, I: IntoIterator<Item = (X, V)>>(data: I) -> Self {
+        let mut inner = StackedAreaSeries::new(data);
+        inner.offset = Some(StreamOffset::Wiggle);
+        Self { inner }
+    }
+
+    /// Sets how the baseline of the stack is shifted at each X value
+    pub fn offset(mut self, offset: StreamOffset) -> Self {
+        self.inner.offset = Some(offset);
+        self
+    }
+
+    /// Sets if the values of each item are scaled to add up to 100 before the baseline is
+    /// shifted, see [`StackedAreaSeries::normalized()`]
+    pub fn normalized(mut self, normalized: bool) -> Self {
+        self.inner = self.inner.normalized(normalized);
+        self
+    }
+
+    /// Sets the styles of the layers, from the bottom of the stack to the top
+    pub fn styles<S: Into<ShapeStyle>, I: IntoIterator<Item = S>>(mut self, styles: I) -> Self {
+        self.inner = self.inner.styles(styles);
+        self
+    }
+
+    /// Sets the labels of the layers, from the bottom of the stack to the top
+    pub fn labels<L: Into<String>, I: IntoIterator<Item = L>>(mut self, labels: I) -> Self {
+        self.inner = self.inner.labels(labels);
+        self
+    }
+
+    /// Returns the label and the style of each layer, from the bottom of the stack to the top
+    pub fn layers(&self) -> Vec<(String, ShapeStyle)> {
+        self.inner.layers()
+    }
+}
+
+impl<X, Y> From<StreamgraphSeries<X, Y>> for StackedAreaSeries<X, Y> {
+    fn from(series: StreamgraphSeries<X, Y>) -> Self {
+        series.inner
+    }
+}
+
+impl<X: Clone, Y: Float> Iterator for StreamgraphSeries<X, Y> {
+    type Item = Polygon<(X, Y)>;
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::element::PointCollection;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_stacked_area_series() {
+        let series = StackedAreaSeries::new(vec![(0, vec![1.0, 2.0]), (1, vec![3.0])])
+            .styles([RED.filled()])
+            .labels(["a", "b"]);
+        let layers = series.layers();
+        assert_eq!(layers.len(), 2);
+        assert_eq!(layers[1].1.color, Palette99::pick(1).to_rgba());
+
+        let bands: Vec<Vec<_>> = series.map(|band| (&band).point_iter().to_vec()).collect();
+        assert_eq!(bands.len(), 2);
+        assert_eq!(bands[0], [(0, 1.0), (1, 3.0), (1, 0.0), (0, 0.0)]);
+        assert_eq!(bands[1], [(0, 3.0), (1, 3.0), (1, 3.0), (0, 1.0)]);
+
+        let bands: Vec<Vec<_>> = StackedAreaSeries::new([(0, [1.0, 3.0]), (1, [0.0, 0.0])])
+            .normalized(true)
+            .map(|band| (&band).point_iter().to_vec())
+            .collect();
+        assert_eq!(bands[0], [(0, 25.0), (1, 0.0), (1, 0.0), (0, 0.0)]);
+        assert_eq!(bands[1], [(0, 100.0), (1, 0.0), (1, 0.0), (0, 25.0)]);
+
+        let drawing_area = create_mocked_drawing_area(100, 100, |m| {
+            m.drop_check(|b| assert_eq!(b.num_fill_polygon_call, 2));
+        });
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0..1, 0.0..100.0)
+            .unwrap();
+        chart
+            .draw_stacked_area_series(
+                StackedAreaSeries::new([(0, [1.0, 3.0]), (1, [2.0, 2.0])]).labels(["a", "b"]),
+            )
+            .unwrap();
+        chart
+            .configure_series_labels()
+            .position(SeriesLabelPosition::UpperLeft)
+            .draw()
+            .unwrap();
+    }
+
+    #[test]
+    fn test_streamgraph_series() {
+        let data = [(0, [1.0, 3.0]), (1, [2.0, 2.0]), (2, [4.0, 4.0])];
+        let bands: Vec<Vec<_>> = StreamgraphSeries::new(data)
+            .offset(StreamOffset::Silhouette)
+            .map(|band| (&band).point_iter().to_vec())
+            .collect();
+        assert_eq!(
+            bands[0],
+            [
+                (0, 1.0 - 2.0),
+                (1, 2.0 - 2.0),
+                (2, 4.0 - 4.0),
+                (2, -4.0),
+                (1, -2.0),
+                (0, -2.0)
+            ]
+        );
+        assert_eq!(bands[1][..3], [(0, 2.0), (1, 2.0), (2, 4.0)]);
+
+        /* A constant stack doesn't wiggle, and it's centered around zero */
+        let bands: Vec<Vec<_>> = StreamgraphSeries::new([(0, [1.0, 1.0]), (1, [1.0, 1.0])])
+            .map(|band| (&band).point_iter().to_vec())
+            .collect();
+        assert_eq!(bands[0], [(0, 0.0), (1, 0.0), (1, -1.0), (0, -1.0)]);
+
+        /* The slopes of the layers are balanced by their thicknesses, 4 * -3/7 + 3 * 4/7 = 0 */
+        let bands: Vec<Vec<_>> = StreamgraphSeries::new([(0, [4.0f64, 1.0]), (1, [4.0, 3.0])])
+            .map(|band| (&band).point_iter().to_vec())
+            .collect();
+        assert!((bands[0][2].1 - bands[0][3].1 + 3.0 / 7.0).abs() < 1e-9);
+        assert!((bands[1][1].1 - bands[1][0].1 - 11.0 / 7.0).abs() < 1e-9);
+
+        let drawing_area = create_mocked_drawing_area(100, 100, |m| {
+            m.drop_check(|b| assert_eq!(b.num_fill_polygon_call, 2));
+        });
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0..2, -10.0..10.0)
+            .unwrap();
+        chart
+            .draw_stacked_area_series(StreamgraphSeries::new(data).labels(["a", "b"]))
+            .unwrap();
+    }
+}
@@ -0,0 +1,295 @@
+use std::collections::{btree_map::IntoIter as BTreeMapIter, BTreeMap};
+use std::marker::PhantomData;
+use std::ops::Add;
+
+use super::histogram::{HistogramType, Horizontal, Vertical};
+use super::layers::{accumulate_columns, layer_legends, layer_style};
+use crate::chart::ChartContext;
+use crate::coord::cartesian::Cartesian2d;
+use crate::coord::ranged1d::{DiscreteRanged, Ranged};
+use crate::element::Rectangle;
+use crate::style::ShapeStyle;
+use plotters_backend::DrawingBackend;
+
+/**
+Presents multiple value columns per category as stacked bars.
+
+Each data item is a category with one value per layer. The values of each layer are accumulated
+on top of the layers before it, so the cumulative baselines don't need to be computed by hand.
+The positive values are stacked upward from the baseline, and the negative values are stacked
+downward, so the layers of mixed signs don't overlap. The layers are styled with the colors of
+[`Palette99`] by default, and [`ChartContext::draw_stacked_series()`] adds a legend entry for each
+labelled layer.
+
+# Examples
+
+```
+use plotters::prelude::*;
+let drawing_area = SVGBackend::new("stacked_bar.svg", (300, 200)).into_drawing_area();
+drawing_area.fill(&WHITE).unwrap();
+let mut chart = ChartBuilder::on(&drawing_area)
+    .margin(5)
+    .set_left_and_bottom_label_area_size(20)
+    .build_cartesian_2d((1..3).into_segmented(), 0..12)
+    .unwrap();
+chart.configure_mesh().draw().unwrap();
+chart
+    .draw_stacked_series(
+        StackedBarSeries::vertical(&chart)
+            .styles([RED.filled(), GREEN.filled(), BLUE.filled()])
+            .labels(["Rent", "Food", "Travel"])
+            .margin(10)
+            .data([(1, [3, 2, 1]), (2, [3, 4, 2]), (3, [3, 1, 5])]),
+    )
+    .unwrap();
+chart.configure_series_labels().draw().unwrap();
+```
+*/
+pub struct StackedBarSeries<BR, A, Tag = Vertical>
+where
+    BR: DiscreteRanged,
+    Tag: HistogramType,
+{
+    br: BR,
+    margin: u32,
+    baseline: A,
+    styles: Vec<ShapeStyle>,
+    labels: Vec<String>,
+    iter: BTreeMapIter<usize, Vec<A>>,
+    /// The segments of the current category which are not yet emitted, in reversed order
+    pending: Vec<(usize, usize, A, A)>,
+    _p: PhantomData<Tag>,
+}
+
+impl<BR, A, Tag> StackedBarSeries<BR, A, Tag>
+where
+    BR: DiscreteRanged + Clone,
+    A: Add<A, Output = A> + Default + Clone + PartialOrd,
+    Tag: HistogramType,
+{
+    fn empty(br: &BR) -> Self {
+        Self {
+            br: br.clone(),
+            margin: 5,
+            baseline: A::default(),
+            styles: vec![],
+            labels: vec![],
+            iter: BTreeMap::new().into_iter(),
+            pending: vec![],
+            _p: PhantomData,
+        }
+    }
+
+    /**
+    Sets the styles of the layers, from the bottom of the stack to the top.
+
+    The layers without a style use the colors of [`Palette99`].
+    */
+    pub fn styles<S: Into<ShapeStyle>, I: IntoIterator<Item = S>>(mut self, styles: I) -> Self {
+        self.styles = styles.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /**
+    Sets the labels of the layers, from the bottom of the stack to the top.
+
+    The labels are used by [`ChartContext::draw_stacked_series()`] for the legend entries.
+    */
+    pub fn labels<L: Into<String>, I: IntoIterator<Item = L>>(mut self, labels: I) -> Self {
+        self.labels = labels.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /**
+    Sets the baseline from which the layers are stacked.
+    */
+    pub fn baseline(mut self, baseline: A) -> Self {
+        self.baseline = baseline;
+        self
+    }
+
+    /**
+    Sets the margin for each bar, in backend pixels.
+    */
+    pub fn margin(mut self, value: u32) -> Self {
+        self.margin = value;
+        self
+    }
+
+    /**
+    Specifies the input data for the stacked bars.
+
+    Each item is a category with the values of the layers, from the bottom of the stack to the
+    top. The values of the items with the same category are added up layer by layer.
+    */
+    pub fn data<TB, V, I>(mut self, iter: I) -> Self
+    where
+        TB: Into<BR::ValueType>,
+        V: IntoIterator<Item = A>,
+        I: IntoIterator<Item = (TB, V)>,
+    {
+        self.iter = accumulate_columns(&self.br, iter).into_iter();
+        self
+    }
+
+    /**
+    Returns the label and the style of each layer, from the bottom of the stack to the top.
+    */
+    pub fn layers(&self) -> Vec<(String, ShapeStyle)> {
+        layer_legends(&self.labels, &self.styles)
+    }
+
+    fn layer_style(&self, layer: usize) -> ShapeStyle {
+        layer_style(&self.styles, layer)
+    }
+
+    /// Get the next segment, i.e. the category index, the layer index and the value range
+    fn next_segment(&mut self) -> Option<(usize, usize, A, A)> {
+        while self.pending.is_empty() {
+            let (x, values) = self.iter.next()?;
+            let zero = A::default();
+            let (mut positive, mut negative) = (self.baseline.clone(), self.baseline.clone());
+            for (layer, value) in values.into_iter().enumerate() {
+                let stack = if value < zero {
+                    &mut negative
+                } else if zero < value {
+                    &mut positive
+                } else {
+                    continue;
+                };
+                let from = stack.clone();
+                *stack = from.clone() + value;
+                self.pending.push((x, layer, from, stack.clone()));
+            }
+            self.pending.reverse();
+        }
+        self.pending.pop()
+    }
+}
+
+impl<BR, A> StackedBarSeries<BR, A, Vertical>
+where
+    BR: DiscreteRanged + Clone,
+    A: Add<A, Output = A> + Default + Clone + PartialOrd,
+{
+    /**
+    Creates a vertical stacked bar series.
+
+    See [`StackedBarSeries`] for more information and examples.
+    */
+    pub fn vertical<ACoord, DB: DrawingBackend>(
+        parent: &ChartContext<DB, Cartesian2d<BR, ACoord>>,
+    ) -> Self
+    where
+        ACoord: Ranged<ValueType = A>,
+    {
+        Self::empty(parent.as_coord_spec().x_spec())
+    }
+}
+
+impl<BR, A> StackedBarSeries<BR, A, Horizontal>
+where
+    BR: DiscreteRanged + Clone,
+    A: Add<A, Output = A> + Default + Clone + PartialOrd,
+{
+    /**
+    Creates a horizontal stacked bar series.
+
+    See [`StackedBarSeries`] for more information and examples.
+    */
+    pub fn horizontal<ACoord, DB: DrawingBackend>(
+        parent: &ChartContext<DB, Cartesian2d<ACoord, BR>>,
+    ) -> Self
+    where
+        ACoord: Ranged<ValueType = A>,
+    {
+        Self::empty(parent.as_coord_spec().y_spec())
+    }
+}
+
+impl<BR, A> Iterator for StackedBarSeries<BR, A, Vertical>
+where
+    BR: DiscreteRanged + Clone,
+    A: Add<A, Output = A> + Default + Clone + PartialOrd,
+{
+    type Item = Rectangle<(BR::ValueType, A)>;
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some((x, layer, from, to)) = self.next_segment() {
+            if let (Some(x), Some(nx)) = (self.br.from_index(x), self.br.from_index(x + 1)) {
+                let mut rect = Rectangle::new([(x, to), (nx, from)], self.layer_style(layer));
+                rect.set_margin(0, 0, self.margin, self.margin);
+                return Some(rect);
+            }
+        }
+        None
+    }
+}
+
+impl<BR, A> Iterator for StackedBarSeries<BR, A, Horizontal>
+where
+    BR: DiscreteRanged + Clone,
+    A: Add<A, Output = A> + Default + Clone + PartialOrd,
+{
+    type Item = Rectangle<(A, BR::ValueType)>;
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some((y, layer, from, to)) = self.next_segment() {
+            if let (Some(y), Some(ny)) = (self.br.from_index(y), self.br.from_index(y + 1)) {
+                let mut rect = Rectangle::new([(to, y), (from, ny)], self.layer_style(layer));
+                rect.set_margin(self.margin, self.margin, 0, 0);
+                return Some(rect);
+            }
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::element::PointCollection;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_stacked_bar_series() {
+        let drawing_area = create_mocked_drawing_area(100, 100, |_| {});
+        let chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d((0..1).into_segmented(), -10..10)
+            .unwrap();
+
+        let series = StackedBarSeries::vertical(&chart)
+            .styles([RED.filled(), BLUE.filled()])
+            .labels(["a", "b", "c"])
+            .data(vec![(0, vec![2, 3, -4]), (0, vec![1]), (1, vec![0, 5])]);
+
+        let layers = series.layers();
+        assert_eq!(layers.len(), 3);
+        assert_eq!(layers[1].0, "b");
+        assert_eq!(layers[1].1.color, BLUE.to_rgba());
+        assert_eq!(layers[2].1.color, Palette99::pick(2).to_rgba());
+
+        let segments: Vec<_> = series
+            .map(|rect| {
+                let points = (&rect).point_iter();
+                (points[0].1, points[1].1)
+            })
+            .collect();
+        assert_eq!(segments, [(3, 0), (6, 3), (-4, 0), (5, 0)]);
+    }
+
+    #[test]
+    fn test_stacked_bar_horizontal() {
+        let drawing_area = create_mocked_drawing_area(100, 100, |_| {});
+        let chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0.0..10.0, (0..1).into_segmented())
+            .unwrap();
+
+        let segments: Vec<_> = StackedBarSeries::horizontal(&chart)
+            .baseline(1.0)
+            .data([(1, [2.0, 3.0])])
+            .map(|rect| {
+                let points = (&rect).point_iter();
+                (points[0].0, points[1].0)
+            })
+            .collect();
+        assert_eq!(segments, [(3.0, 1.0), (6.0, 3.0)]);
+    }
+}
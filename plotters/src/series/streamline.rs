@@ -0,0 +1,388 @@
+use super::quiver::draw_arrow_head;
+use crate::element::{Drawable, PointCollection};
+use crate::style::{ShapeStyle, BLACK};
+use plotters_backend::{BackendCoord, DrawingBackend, DrawingErrorKind};
+
+/// The number of the cells of the occupancy mask along each axis at the density 1.0
+const MASK_CELLS: f64 = 30.0;
+
+/// The step of the integration in the cells of the occupancy mask
+const STEP_IN_CELLS: f64 = 0.2;
+
+/// The longest streamline in the sizes of the domain
+const MAX_LENGTH: f64 = 4.0;
+
+/// The shortest streamline in the sizes of the domain, the shorter ones are dropped
+const MIN_LENGTH: f64 = 0.1;
+
+/// The vector field sampled on a grid, which is bilinearly interpolated between the samples
+struct VectorGrid {
+    xs: Vec<f64>,
+    ys: Vec<f64>,
+    /// The vectors of each row, one row for each Y value, the missing ones are non-finite
+    rows: Vec<Vec<(f64, f64)>>,
+}
+
+impl VectorGrid {
+    /// Get the cell containing the value and the position of the value inside the cell
+    fn locate(values: &[f64], value: f64) -> Option<(usize, f64)> {
+        if values.len() < 2 || !(values[0] <= value && value <= values[values.len() - 1]) {
+            return None;
+        }
+        let idx = values
+            .partition_point(|v| *v <= value)
+            .clamp(1, values.len() - 1)
+            - 1;
+        let width = values[idx + 1] - values[idx];
+        let t = if width > 0.0 {
+            (value - values[idx]) / width
+        } else {
+            0.0
+        };
+        Some((idx, t))
+    }
+
+    fn vector(&self, i: usize, j: usize) -> (f64, f64) {
+        self.rows
+            .get(j)
+            .and_then(|row| row.get(i))
+            .copied()
+            .unwrap_or((f64::NAN, f64::NAN))
+    }
+
+    /// Get the interpolated vector at the point, or `None` if the point is out of the grid
+    fn sample(&self, x: f64, y: f64) -> Option<(f64, f64)> {
+        let (i, tx) = Self::locate(&self.xs, x)?;
+        let (j, ty) = Self::locate(&self.ys, y)?;
+        let lerp =
+            |a: (f64, f64), b: (f64, f64), t: f64| (a.0 + (b.0 - a.0) * t, a.1 + (b.1 - a.1) * t);
+        let bottom = lerp(self.vector(i, j), self.vector(i + 1, j), tx);
+        let top = lerp(self.vector(i, j + 1), self.vector(i + 1, j + 1), tx);
+        let (u, v) = lerp(bottom, top, ty);
+        if u.is_finite() && v.is_finite() {
+            Some((u, v))
+        } else {
+            None
+        }
+    }
+}
+
+/// A streamline of a [`StreamlineSeries`], which is a path along the vector field with an
+/// optional arrow head at its middle pointing in the direction of the field
+pub struct Streamline {
+    points: Vec<(f64, f64)>,
+    style: ShapeStyle,
+    arrow: Option<(u32, u32)>,
+}
+
+impl<'a> PointCollection<'a, (f64, f64)> for &'a Streamline {
+    type Point = &'a (f64, f64);
+    type IntoIter = &'a [(f64, f64)];
+    fn point_iter(self) -> &'a [(f64, f64)] {
+        &self.points
+    }
+}
+
+impl<DB: DrawingBackend> Drawable<DB> for Streamline {
+    fn draw<I: Iterator<Item = BackendCoord>>(
+        &self,
+        points: I,
+        backend: &mut DB,
+        _: (u32, u32),
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let points: Vec<_> = points.collect();
+        backend.draw_path(points.iter().copied(), &self.style)?;
+        if let (Some((length, width)), Some(last)) = (self.arrow, points.len().checked_sub(1)) {
+            let mid = last / 2;
+            let (from, to) = (points[mid.saturating_sub(2)], points[(mid + 2).min(last)]);
+            let direction = (f64::from(to.0 - from.0), f64::from(to.1 - from.1));
+            let size = (f64::from(length), f64::from(width));
+            draw_arrow_head(backend, points[mid], direction, size, &self.style)?;
+        }
+        Ok(())
+    }
+}
+
+/**
+Presents a vector field sampled on a grid as streamlines, which are the curves tangent to the
+field everywhere, like the `streamplot` plots of matplotlib.
+
+The streamlines are integrated with the fourth-order Runge-Kutta method through the field, which
+is bilinearly interpolated between the samples. The domain of the grid is divided into a mask of
+30 by 30 cells at the density 1.0, and the streamlines start from the centers of the cells which
+no streamline has passed through yet, and are traced in both directions until they leave the
+domain, reach a cell where another streamline has passed, or reach a point where the field
+vanishes or isn't finite, so the streamlines are spaced about evenly. The streamlines shorter than
+a tenth of the domain are dropped. A higher density draws more
+and closer streamlines. Each streamline has an arrow head at its middle by default.
+
+# Examples
+
+```
+use plotters::prelude::*;
+let drawing_area = SVGBackend::new("streamline_series.svg", (300, 300)).into_drawing_area();
+drawing_area.fill(&WHITE).unwrap();
+let mut chart = ChartBuilder::on(&drawing_area)
+    .margin(10)
+    .set_left_and_bottom_label_area_size(20)
+    .build_cartesian_2d(-2.0..2.0, -2.0..2.0)
+    .unwrap();
+chart.configure_mesh().draw().unwrap();
+let steps = (0..=20).map(|v| v as f64 / 5.0 - 2.0);
+chart
+    .draw_series(
+        StreamlineSeries::new(steps.clone(), steps, |x, y| (-y - x / 4.0, x - y / 4.0))
+            .density(0.8)
+            .style(BLUE),
+    )
+    .unwrap();
+```
+*/
+pub struct StreamlineSeries {
+    grid: VectorGrid,
+    density: f64,
+    style: ShapeStyle,
+    arrow: Option<(u32, u32)>,
+    lines: Option<std::vec::IntoIter<Vec<(f64, f64)>>>,
+}
+
+impl StreamlineSeries {
+    /**
+    Creates a streamline series from the vectors of a function over a grid.
+
+    - `xs`: The X values of the grid, in the increasing order
+    - `ys`: The Y values of the grid, in the increasing order
+    - `f`: The vector field, which maps a point to the vector `(u, v)` at it
+    - **returns** The newly created series
+
+    See [`StreamlineSeries`] for more information and examples.
+    */
+    pub fn new<X, Y, F>(xs: X, ys: Y, f: F) -> Self
+    where
+        X: IntoIterator<Item = f64>,
+        Y: IntoIterator<Item = f64>,
+        F: Fn(f64, f64) -> (f64, f64),
+    {
+        let xs: Vec<_> = xs.into_iter().collect();
+        let ys: Vec<_> = ys.into_iter().collect();
+        let rows: Vec<Vec<_>> = ys
+            .iter()
+            .map(|y| xs.iter().map(|x| f(*x, *y)).collect())
+            .collect();
+        Self::from_grid(xs, ys, rows)
+    }
+
+    /**
+    Creates a streamline series from the vectors sampled on a grid.
+
+    - `xs`: The X values of the grid, in the increasing order
+    - `ys`: The Y values of the grid, in the increasing order
+    - `rows`: The rows of the vectors `(u, v)`, one for each Y value, each of which has one
+      vector for each X value. The missing vectors are treated as non-finite.
+    - **returns** The newly created series
+    */
+    pub fn from_grid<X, Y, R, I>(xs: X, ys: Y, rows: I) -> Self
+    where
+        X: IntoIterator<Item = f64>,
+        Y: IntoIterator<Item = f64>,
+        R: IntoIterator<Item = (f64, f64)>,
+        I: IntoIterator<Item = R>,
+    {
+        Self {
+            grid: VectorGrid {
+                xs: xs.into_iter().collect(),
+                ys: ys.into_iter().collect(),
+                rows: rows
+                    .into_iter()
+                    .map(|row| row.into_iter().collect())
+                    .collect(),
+            },
+            density: 1.0,
+            style: BLACK.into(),
+            arrow: Some((8, 6)),
+            lines: None,
+        }
+    }
+
+    /// Set the density of the streamlines, which is 1.0 by default
+    pub fn density(mut self, density: f64) -> Self {
+        self.density = density;
+        self
+    }
+
+    /// Set the style of the streamlines and the arrow heads
+    pub fn style<S: Into<ShapeStyle>>(mut self, style: S) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Set the length and the width of the arrow heads in pixels, or remove the arrow heads
+    pub fn arrows(mut self, size: Option<(u32, u32)>) -> Self {
+        self.arrow = size;
+        self
+    }
+
+    /// Trace all the streamlines from the cells of the occupancy mask
+    fn trace_all(&self) -> Vec<Vec<(f64, f64)>> {
+        let (xs, ys) = (&self.grid.xs, &self.grid.ys);
+        let (x0, x1, y0, y1) = match (xs.first(), xs.last(), ys.first(), ys.last()) {
+            (Some(x0), Some(x1), Some(y0), Some(y1)) if x0 < x1 && y0 < y1 => (*x0, *x1, *y0, *y1),
+            _ => return vec![],
+        };
+        let cells = (MASK_CELLS * self.density).round().max(1.0) as usize;
+        let mut mask = vec![false; cells * cells];
+
+        /* The streamlines are traced in the unit square, so the steps are even on both axes */
+        let to_domain = |(px, py): (f64, f64)| (x0 + px * (x1 - x0), y0 + py * (y1 - y0));
+        let cell_of = |(px, py): (f64, f64)| {
+            let i = ((px * cells as f64) as usize).min(cells - 1);
+            let j = ((py * cells as f64) as usize).min(cells - 1);
+            j * cells + i
+        };
+        let direction = |p: (f64, f64), sign: f64| {
+            let (x, y) = to_domain(p);
+            let (u, v) = self.grid.sample(x, y)?;
+            let (du, dv) = (u / (x1 - x0), v / (y1 - y0));
+            let speed = du.hypot(dv);
+            if speed > 0.0 {
+                Some((sign * du / speed, sign * dv / speed))
+            } else {
+                None
+            }
+        };
+        let step = STEP_IN_CELLS / cells as f64;
+        let max_steps = (MAX_LENGTH / step) as usize;
+
+        let mut lines = vec![];
+        for seed in 0..cells * cells {
+            if mask[seed] {
+                continue;
+            }
+            let start = (
+                ((seed % cells) as f64 + 0.5) / cells as f64,
+                ((seed / cells) as f64 + 0.5) / cells as f64,
+            );
+            let mut halves = vec![];
+            let mut occupied = vec![seed];
+            for sign in [-1.0, 1.0].iter() {
+                let mut p = start;
+                let mut cell = seed;
+                let mut half = vec![];
+                for _ in 0..max_steps {
+                    let k1 = match direction(p, *sign) {
+                        Some(k) => k,
+                        None => break,
+                    };
+                    let at = |k: (f64, f64), h: f64| (p.0 + k.0 * h, p.1 + k.1 * h);
+                    let k2 = direction(at(k1, step / 2.0), *sign);
+                    let k3 = k2.and_then(|k2| direction(at(k2, step / 2.0), *sign));
+                    let k4 = k3.and_then(|k3| direction(at(k3, step), *sign));
+                    let (k2, k3, k4) = match (k2, k3, k4) {
+                        (Some(k2), Some(k3), Some(k4)) => (k2, k3, k4),
+                        _ => break,
+                    };
+                    let next = (
+                        p.0 + step / 6.0 * (k1.0 + 2.0 * k2.0 + 2.0 * k3.0 + k4.0),
+                        p.1 + step / 6.0 * (k1.1 + 2.0 * k2.1 + 2.0 * k3.1 + k4.1),
+                    );
+                    if !(0.0..=1.0).contains(&next.0) || !(0.0..=1.0).contains(&next.1) {
+                        break;
+                    }
+                    let next_cell = cell_of(next);
+                    if next_cell != cell {
+                        if mask[next_cell] || occupied.contains(&next_cell) {
+                            break;
+                        }
+                        occupied.push(next_cell);
+                        cell = next_cell;
+                    }
+                    half.push(next);
+                    p = next;
+                }
+                halves.push(half);
+            }
+            let forward = halves.pop().unwrap_or_default();
+            let mut line = halves.pop().unwrap_or_default();
+            if (line.len() + forward.len()) as f64 * step < MIN_LENGTH {
+                continue;
+            }
+            for cell in &occupied {
+                mask[*cell] = true;
+            }
+            line.reverse();
+            line.push(start);
+            line.extend(forward);
+            lines.push(line.into_iter().map(to_domain).collect());
+        }
+        lines
+    }
+}
+
+impl Iterator for StreamlineSeries {
+    type Item = Streamline;
+    fn next(&mut self) -> Option<Streamline> {
+        if self.lines.is_none() {
+            self.lines = Some(self.trace_all().into_iter());
+        }
+        let points = self.lines.as_mut()?.next()?;
+        Some(Streamline {
+            points,
+            style: self.style,
+            arrow: self.arrow,
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_vector_grid() {
+        let grid = VectorGrid {
+            xs: vec![0.0, 1.0, 3.0],
+            ys: vec![0.0, 1.0],
+            rows: vec![
+                vec![(0.0, 0.0), (1.0, 0.0), (3.0, 0.0)],
+                vec![(0.0, 2.0), (1.0, 2.0)],
+            ],
+        };
+        assert_eq!(grid.sample(0.5, 0.0), Some((0.5, 0.0)));
+        assert_eq!(grid.sample(0.5, 0.5), Some((0.5, 1.0)));
+        assert_eq!(grid.sample(0.0, 1.0), Some((0.0, 2.0)));
+        /* The vector at (3.0, 1.0) is missing */
+        assert_eq!(grid.sample(2.0, 0.0), None);
+        assert_eq!(grid.sample(-1.0, 0.0), None);
+    }
+
+    #[test]
+    fn test_streamline_series() {
+        /* A uniform field to the right gives the horizontal streamlines, one per row of cells */
+        let steps = (0..=10).map(|v| v as f64 / 10.0);
+        let lines: Vec<_> = StreamlineSeries::new(steps.clone(), steps, |_, _| (1.0, 0.0))
+            .density(0.2)
+            .collect();
+        assert_eq!(lines.len(), 6);
+        for line in &lines {
+            let (first, last) = (line.points[0], line.points[line.points.len() - 1]);
+            assert!(first.0 < 0.05 && last.0 > 0.95);
+            assert!(line.points.iter().all(|p| (p.1 - first.1).abs() < 1e-9));
+        }
+
+        let drawing_area = create_mocked_drawing_area(100, 100, |m| {
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_path_call, 6);
+                assert_eq!(b.num_fill_polygon_call, 6);
+            });
+        });
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0.0..1.0, 0.0..1.0)
+            .unwrap();
+        chart.draw_series(lines).unwrap();
+
+        let steps = (0..=4).map(|v| v as f64);
+        let no_field = StreamlineSeries::new(steps.clone(), steps, |_, _| (0.0, 0.0));
+        assert_eq!(no_field.count(), 0);
+    }
+}
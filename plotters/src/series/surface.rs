@@ -1,4 +1,4 @@
-use crate::element::Polygon;
+use crate::element::{PathElement, Polygon};
 use crate::style::{colors::BLUE, Color, ShapeStyle};
 use std::marker::PhantomData;
 
@@ -248,3 +248,238 @@ where
         }
     }
 }
+
+/**
+Represents polylines in the 3D space, such as the parametric curves.
+
+Unlike a [`SurfaceSeries`], the series draws the lines between the points only, and it's
+projected through the 3D coordinate like any other element of the chart.
+
+# Examples
+
+```
+use plotters::prelude::*;
+let drawing_area = SVGBackend::new("line_3d_series.svg", (640, 480)).into_drawing_area();
+drawing_area.fill(&WHITE).unwrap();
+let mut chart_context = ChartBuilder::on(&drawing_area)
+    .margin(10)
+    .build_cartesian_3d(-1.0..1.0f64, 0.0..1.0f64, -1.0..1.0f64)
+    .unwrap();
+chart_context.configure_axes().draw().unwrap();
+chart_context.draw_series(Line3DSeries::parametric(
+    (0..=500).map(|t| t as f64 / 500.0),
+    |t| ((t * 30.0).cos() * t, t, (t * 30.0).sin() * t),
+    RED,
+)).unwrap();
+```
+*/
+pub struct Line3DSeries<X, Y, Z> {
+    points: Vec<(X, Y, Z)>,
+    style: ShapeStyle,
+}
+
+impl<X, Y, Z> Line3DSeries<X, Y, Z> {
+    /// Create a new 3D line series connecting the points in order
+    pub fn new<I: IntoIterator<Item = (X, Y, Z)>, S: Into<ShapeStyle>>(
+        points: I,
+        style: S,
+    ) -> Self {
+        Self {
+            points: points.into_iter().collect(),
+            style: style.into(),
+        }
+    }
+
+    /// Create a new 3D line series of the parametric curve, which is evaluated at each value of
+    /// the parameter. See [`Line3DSeries`] for more information and examples.
+    pub fn parametric<T, I: IntoIterator<Item = T>, F: Fn(T) -> (X, Y, Z), S: Into<ShapeStyle>>(
+        params: I,
+        curve: F,
+        style: S,
+    ) -> Self {
+        Self::new(params.into_iter().map(curve), style)
+    }
+}
+
+impl<X, Y, Z> Iterator for Line3DSeries<X, Y, Z> {
+    type Item = PathElement<(X, Y, Z)>;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.points.is_empty() {
+            return None;
+        }
+        let points = std::mem::take(&mut self.points);
+        Some(PathElement::new(points, self.style))
+    }
+}
+
+/**
+Represents the wireframe of functions of two variables or of a mesh.
+
+The wireframe draws the grid lines of a [`SurfaceSeries`] without filling the panels, i.e. one
+line for each value of the first free variable and one line for each value of the second one.
+
+# Examples
+
+```
+use plotters::prelude::*;
+let drawing_area = SVGBackend::new("wireframe_series.svg", (640, 480)).into_drawing_area();
+drawing_area.fill(&WHITE).unwrap();
+let mut chart_context = ChartBuilder::on(&drawing_area)
+    .margin(10)
+    .build_cartesian_3d(-3.0..3.0f64, -3.0..3.0f64, -3.0..3.0f64)
+    .unwrap();
+chart_context.configure_axes().draw().unwrap();
+chart_context.draw_series(WireframeSeries::xoz(
+    (-15..=15).map(|v| v as f64 / 5.0),
+    (-15..=15).map(|v| v as f64 / 5.0),
+    |x, z| (0.7 * (x * x + z * z)).cos(),
+).style(BLUE.mix(0.6))).unwrap();
+```
+*/
+pub struct WireframeSeries<X, Y, Z> {
+    grid: Vec<Vec<(X, Y, Z)>>,
+    style: ShapeStyle,
+    idx: usize,
+}
+
+impl<X: Clone, Y: Clone, Z: Clone> WireframeSeries<X, Y, Z> {
+    /// Create a new wireframe of the mesh, of which each row of the grid is a line and so is each
+    /// column of the grid
+    pub fn from_grid(grid: Vec<Vec<(X, Y, Z)>>) -> Self {
+        Self {
+            grid,
+            style: BLUE.into(),
+            idx: 0,
+        }
+    }
+
+    fn from_function<D, IterA, IterB, F>(a: IterA, b: IterB, f: F) -> Self
+    where
+        D: Direction<X, Y, Z>,
+        D::Input1Type: Clone,
+        D::Input2Type: Clone,
+        IterA: IntoIterator<Item = D::Input1Type>,
+        IterB: IntoIterator<Item = D::Input2Type>,
+        F: Fn(D::Input1Type, D::Input2Type) -> D::OutputType,
+    {
+        let b: Vec<_> = b.into_iter().collect();
+        let grid = a
+            .into_iter()
+            .map(|a| {
+                b.iter()
+                    .map(|b| D::make_coord((a.clone(), b.clone()), f(a.clone(), b.clone())))
+                    .collect()
+            })
+            .collect();
+        Self::from_grid(grid)
+    }
+
+    /// Create a new wireframe of the function whose value is in the z direction
+    pub fn xoy<IterA: IntoIterator<Item = X>, IterB: IntoIterator<Item = Y>, F: Fn(X, Y) -> Z>(
+        a: IterA,
+        b: IterB,
+        f: F,
+    ) -> Self {
+        Self::from_function::<XOY, _, _, _>(a, b, f)
+    }
+
+    /// Create a new wireframe of the function whose value is in the y direction
+    pub fn xoz<IterA: IntoIterator<Item = X>, IterB: IntoIterator<Item = Z>, F: Fn(X, Z) -> Y>(
+        a: IterA,
+        b: IterB,
+        f: F,
+    ) -> Self {
+        Self::from_function::<XOZ, _, _, _>(a, b, f)
+    }
+
+    /// Create a new wireframe of the function whose value is in the x direction
+    pub fn yoz<IterA: IntoIterator<Item = Y>, IterB: IntoIterator<Item = Z>, F: Fn(Y, Z) -> X>(
+        a: IterA,
+        b: IterB,
+        f: F,
+    ) -> Self {
+        Self::from_function::<YOZ, _, _, _>(a, b, f)
+    }
+
+    /// Sets the style of the lines. See [`WireframeSeries`] for more information and examples.
+    pub fn style<S: Into<ShapeStyle>>(mut self, s: S) -> Self {
+        self.style = s.into();
+        self
+    }
+}
+
+impl<X: Clone, Y: Clone, Z: Clone> Iterator for WireframeSeries<X, Y, Z> {
+    type Item = PathElement<(X, Y, Z)>;
+    fn next(&mut self) -> Option<Self::Item> {
+        let columns = self.grid.iter().map(Vec::len).max().unwrap_or(0);
+        while self.idx < self.grid.len() + columns {
+            let idx = self.idx;
+            self.idx += 1;
+            // The empty rows and the empty columns of a ragged grid are skipped
+            let line: Vec<_> = if let Some(row) = self.grid.get(idx) {
+                row.clone()
+            } else {
+                let col = idx - self.grid.len();
+                self.grid
+                    .iter()
+                    .filter_map(|row| row.get(col).cloned())
+                    .collect()
+            };
+            if !line.is_empty() {
+                return Some(PathElement::new(line, self.style));
+            }
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::element::PointCollection;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_line_3d_series() {
+        let series = Line3DSeries::parametric(0..5, |t| (t, t * t, 0), RED);
+        let lines: Vec<_> = series.collect();
+        assert_eq!(lines.len(), 1);
+        assert_eq!(lines[0].point_iter().len(), 5);
+        assert_eq!(lines[0].point_iter()[4], (4, 16, 0));
+        assert_eq!(Line3DSeries::<i32, i32, i32>::new(vec![], RED).count(), 0);
+    }
+
+    #[test]
+    fn test_wireframe_series() {
+        let lines: Vec<_> = WireframeSeries::xoz(0..3, 0..4, |x, z| x * z).collect();
+        assert_eq!(lines.len(), 7);
+        assert_eq!(
+            lines[1].point_iter(),
+            [(1, 0, 0), (1, 1, 1), (1, 2, 2), (1, 3, 3)]
+        );
+        assert_eq!(lines[5].point_iter(), [(0, 0, 2), (1, 2, 2), (2, 4, 2)]);
+        assert_eq!(
+            WireframeSeries::<i32, i32, i32>::from_grid(vec![]).count(),
+            0
+        );
+        let ragged = vec![vec![(0, 0, 0), (1, 0, 0)], vec![], vec![(0, 0, 2)]];
+        let lines: Vec<_> = WireframeSeries::from_grid(ragged).collect();
+        assert_eq!(lines.len(), 4);
+        assert_eq!(lines[1].point_iter(), [(0, 0, 2)]);
+        assert_eq!(lines[2].point_iter(), [(0, 0, 0), (0, 0, 2)]);
+        assert_eq!(lines[3].point_iter(), [(1, 0, 0)]);
+
+        let drawing_area = create_mocked_drawing_area(200, 200, |m| {
+            m.check_draw_path(|_, _, path| assert_eq!(path.len(), 3));
+            m.drop_check(|b| assert_eq!(b.num_draw_path_call, 6));
+        });
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_3d(0.0..2.0, 0.0..2.0, 0.0..2.0)
+            .unwrap();
+        let grid = (0..3)
+            .map(|x| (0..3).map(|y| (x as f64, y as f64, 1.0)).collect())
+            .collect();
+        chart
+            .draw_series(WireframeSeries::from_grid(grid).style(RED))
+            .unwrap();
+    }
+}
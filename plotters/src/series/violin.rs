@@ -0,0 +1,200 @@
+use std::marker::PhantomData;
+
+use crate::data::{KernelDensity, Quartiles};
+use crate::element::{BoxplotOrient, BoxplotOrientH, BoxplotOrientV, Violin};
+use crate::style::{Color, ShapeStyle, BLACK, BLUE};
+
+/**
+Presents the distribution of the raw samples of each category as violins.
+
+The density of each category is estimated with a Gaussian kernel, and it's mirrored around the
+category to make a filled shape which is as wide as [`ViolinSeries::width()`] at its widest part.
+The bandwidth of the kernel follows Silverman's rule of thumb unless it's set by
+[`ViolinSeries::bandwidth()`], and the density is sampled at [`ViolinSeries::resolution()`] evenly
+spaced values between the smallest and the largest samples. The categories without any sample are
+skipped.
+
+# Examples
+
+```
+use plotters::prelude::*;
+let drawing_area = SVGBackend::new("violin_series.svg", (300, 200)).into_drawing_area();
+drawing_area.fill(&WHITE).unwrap();
+let mut chart = ChartBuilder::on(&drawing_area)
+    .margin(5)
+    .set_left_and_bottom_label_area_size(20)
+    .build_cartesian_2d(["a", "b"][..].into_segmented(), 0f32..60f32)
+    .unwrap();
+chart.configure_mesh().draw().unwrap();
+chart
+    .draw_series(
+        ViolinSeries::vertical(vec![
+            (SegmentValue::CenterOf(&"a"), vec![7.0, 15.0, 36.0, 39.0, 40.0, 41.0]),
+            (SegmentValue::CenterOf(&"b"), vec![20.0, 22.0, 25.0, 26.0, 29.0, 55.0]),
+        ])
+        .width(60)
+        .inner_box(true),
+    )
+    .unwrap();
+```
+*/
+pub struct ViolinSeries<K, O: BoxplotOrient<K, f32>> {
+    iter: std::vec::IntoIter<(K, Vec<f64>)>,
+    bandwidth: Option<f64>,
+    resolution: usize,
+    style: ShapeStyle,
+    width: u32,
+    inner_box: bool,
+    inner_style: ShapeStyle,
+    _p: PhantomData<O>,
+}
+
+impl<K> ViolinSeries<K, BoxplotOrientV<K, f32>> {
+    /// Create a new vertical violin series
+    ///
+    /// - `data`: The categories with their samples, the categories are on the X axis
+    /// - **returns** The newly created series
+    pub fn vertical<T: Into<f64>, S: IntoIterator<Item = T>, I: IntoIterator<Item = (K, S)>>(
+        data: I,
+    ) -> Self {
+        Self::new(data)
+    }
+}
+
+impl<K> ViolinSeries<K, BoxplotOrientH<K, f32>> {
+    /// Create a new horizontal violin series
+    ///
+    /// - `data`: The categories with their samples, the categories are on the Y axis
+    /// - **returns** The newly created series
+    pub fn horizontal<T: Into<f64>, S: IntoIterator<Item = T>, I: IntoIterator<Item = (K, S)>>(
+        data: I,
+    ) -> Self {
+        Self::new(data)
+    }
+}
+
+impl<K, O: BoxplotOrient<K, f32>> ViolinSeries<K, O> {
+    fn new<T: Into<f64>, S: IntoIterator<Item = T>, I: IntoIterator<Item = (K, S)>>(
+        data: I,
+    ) -> Self {
+        let data: Vec<_> = data
+            .into_iter()
+            .map(|(key, samples)| {
+                let samples = samples
+                    .into_iter()
+                    .map(Into::into)
+                    .filter(|v: &f64| v.is_finite())
+                    .collect();
+                (key, samples)
+            })
+            .collect();
+        Self {
+            iter: data.into_iter(),
+            bandwidth: None,
+            resolution: 50,
+            style: BLUE.mix(0.5).filled(),
+            width: 40,
+            inner_box: false,
+            inner_style: BLACK.filled(),
+            _p: PhantomData,
+        }
+    }
+
+    /// Set the bandwidth of the Gaussian kernel, in the unit of the samples
+    pub fn bandwidth(mut self, bandwidth: f64) -> Self {
+        self.bandwidth = Some(bandwidth).filter(|h| *h > 0.0);
+        self
+    }
+
+    /// Set the number of the values at which the density is sampled, which is at least 2
+    pub fn resolution(mut self, resolution: usize) -> Self {
+        self.resolution = resolution.max(2);
+        self
+    }
+
+    /// Set the style of the violins
+    pub fn style<S: Into<ShapeStyle>>(mut self, style: S) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Set the width of the widest part of the violins in pixels
+    pub fn width(mut self, width: u32) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Set if an inner box plot of the quartiles and the Tukey whiskers is overlaid on the violins
+    pub fn inner_box(mut self, inner_box: bool) -> Self {
+        self.inner_box = inner_box;
+        self
+    }
+
+    /// Set the style of the inner box plots
+    pub fn inner_style<S: Into<ShapeStyle>>(mut self, style: S) -> Self {
+        self.inner_style = style.into();
+        self
+    }
+}
+
+/// Sample the density estimate evenly between the smallest and the largest samples
+fn sample_density(kde: &KernelDensity, resolution: usize) -> Vec<(f32, f64)> {
+    let (min, max) = kde
+        .samples()
+        .iter()
+        .fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), v| {
+            (min.min(*v), max.max(*v))
+        });
+    kde.sample(min..max, resolution)
+        .into_iter()
+        .map(|(x, density)| (x as f32, density))
+        .collect()
+}
+
+impl<K: Clone, O: BoxplotOrient<K, f32>> Iterator for ViolinSeries<K, O> {
+    type Item = Violin<K, O>;
+    fn next(&mut self) -> Option<Self::Item> {
+        for (key, samples) in self.iter.by_ref() {
+            if samples.is_empty() {
+                continue;
+            }
+            let mut kde = KernelDensity::new(samples);
+            if let Some(bandwidth) = self.bandwidth {
+                kde = kde.bandwidth(bandwidth);
+            }
+            let density = sample_density(&kde, self.resolution);
+            let mut violin = Violin::<K, O>::with_density(key, density)
+                .style(self.style)
+                .width(self.width)
+                .inner_style(self.inner_style);
+            if self.inner_box {
+                violin = violin.inner_box(&Quartiles::with_tukey_fences(kde.samples(), 1.5));
+            }
+            return Some(violin);
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_violin_series() {
+        let kde = KernelDensity::new([2.0, 0.0, 1.0]);
+        let density = sample_density(&kde, 5);
+        assert_eq!(density[0].0, 0.0);
+        assert_eq!(density[4].0, 2.0);
+
+        let series: Vec<_> = ViolinSeries::horizontal(vec![
+            (0, vec![1.0, 2.0, 3.0]),
+            (1, vec![]),
+            (2, vec![f64::NAN, 5.0]),
+        ])
+        .resolution(10)
+        .inner_box(true)
+        .collect();
+        assert_eq!(series.len(), 2);
+    }
+}
@@ -0,0 +1,361 @@
+use std::ops::Add;
+
+use crate::chart::ChartContext;
+use crate::coord::cartesian::Cartesian2d;
+use crate::coord::ranged1d::{DiscreteRanged, Ranged};
+use crate::element::{Drawable, PointCollection};
+use crate::style::{Color, ShapeStyle, BLACK, BLUE, GREEN, RED};
+use plotters_backend::{BackendCoord, DrawingBackend, DrawingErrorKind};
+
+/// A step of a [`WaterfallSeries`]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum WaterfallStep<A> {
+    /// A signed change of the running total, drawn as a floating bar from the previous total
+    Delta(A),
+    /// An intermediate total, drawn as a bar from the baseline to the running total
+    Subtotal,
+    /// The final total, drawn as a bar from the baseline to the running total
+    Total,
+}
+
+/**
+An element of a [`WaterfallSeries`], which is either a bar or a connector line between two
+bars. The bar is a rectangle with the margin on the left and the right, and the connector is a
+horizontal line from the right edge of a bar to the left edge of the next bar.
+*/
+pub struct WaterfallElement<X, Y> {
+    points: [(X, Y); 2],
+    style: ShapeStyle,
+    margin: u32,
+    connector: bool,
+}
+
+impl<'a, X, Y> PointCollection<'a, (X, Y)> for &'a WaterfallElement<X, Y> {
+    type Point = &'a (X, Y);
+    type IntoIter = &'a [(X, Y)];
+    fn point_iter(self) -> &'a [(X, Y)] {
+        &self.points
+    }
+}
+
+impl<X, Y, DB: DrawingBackend> Drawable<DB> for WaterfallElement<X, Y> {
+    fn draw<I: Iterator<Item = BackendCoord>>(
+        &self,
+        mut points: I,
+        backend: &mut DB,
+        _: (u32, u32),
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let (a, b) = match (points.next(), points.next()) {
+            (Some(a), Some(b)) => (a, b),
+            _ => return Ok(()),
+        };
+        let margin = self.margin as i32;
+        if self.connector {
+            let from = (a.0 - margin, a.1);
+            let to = (b.0 + margin, a.1);
+            return backend.draw_line(from, to, &self.style);
+        }
+        let from = (a.0.min(b.0) + margin, a.1.min(b.1));
+        let to = (a.0.max(b.0) - margin, a.1.max(b.1));
+        backend.draw_rect(from, to, &self.style, self.style.filled)
+    }
+}
+
+/**
+Presents the signed changes of a running total as a waterfall chart, i.e. the bridge charts of
+the finance reports.
+
+Each data item is a category with a [`WaterfallStep`]. A delta is drawn as a floating bar from the
+running total before it to the running total after it, so the bars of the increases go upward and
+the bars of the decreases go downward, and they are styled differently. A subtotal or a total is
+drawn as a bar from the baseline to the running total. The consecutive bars are joined by the
+connector lines at the running total between them, which can be disabled with
+[`WaterfallSeries::connector_style()`].
+
+# Examples
+
+```
+use plotters::prelude::*;
+let drawing_area = SVGBackend::new("waterfall.svg", (300, 200)).into_drawing_area();
+drawing_area.fill(&WHITE).unwrap();
+let mut chart = ChartBuilder::on(&drawing_area)
+    .margin(5)
+    .set_left_and_bottom_label_area_size(20)
+    .build_cartesian_2d((0..5).into_segmented(), 0..140)
+    .unwrap();
+chart.configure_mesh().draw().unwrap();
+chart
+    .draw_series(WaterfallSeries::new(&chart).margin(8).data([
+        (0, WaterfallStep::Delta(100)),
+        (1, WaterfallStep::Delta(30)),
+        (2, WaterfallStep::Subtotal),
+        (3, WaterfallStep::Delta(-45)),
+        (4, WaterfallStep::Total),
+    ]))
+    .unwrap();
+```
+*/
+pub struct WaterfallSeries<BR: DiscreteRanged, A> {
+    br: BR,
+    margin: u32,
+    baseline: A,
+    increase_style: ShapeStyle,
+    decrease_style: ShapeStyle,
+    subtotal_style: ShapeStyle,
+    total_style: ShapeStyle,
+    connector_style: Option<ShapeStyle>,
+    iter: std::vec::IntoIter<(usize, WaterfallStep<A>)>,
+    level: Option<A>,
+    last: Option<usize>,
+    pending: Option<WaterfallElement<BR::ValueType, A>>,
+}
+
+impl<BR, A> WaterfallSeries<BR, A>
+where
+    BR: DiscreteRanged + Clone,
+    A: Add<A, Output = A> + Default + Clone + PartialOrd,
+{
+    /**
+    Creates a waterfall series with the categories on the X axis.
+
+    See [`WaterfallSeries`] for more information and examples.
+    */
+    pub fn new<ACoord, DB: DrawingBackend>(
+        parent: &ChartContext<DB, Cartesian2d<BR, ACoord>>,
+    ) -> Self
+    where
+        ACoord: Ranged<ValueType = A>,
+    {
+        Self {
+            br: parent.as_coord_spec().x_spec().clone(),
+            margin: 5,
+            baseline: A::default(),
+            increase_style: GREEN.filled(),
+            decrease_style: RED.filled(),
+            subtotal_style: BLUE.mix(0.6).filled(),
+            total_style: BLUE.filled(),
+            connector_style: Some(BLACK.into()),
+            iter: vec![].into_iter(),
+            level: None,
+            last: None,
+            pending: None,
+        }
+    }
+
+    /**
+    Sets the styles of the bars of the increases and the decreases.
+    */
+    pub fn delta_styles<S: Into<ShapeStyle>, T: Into<ShapeStyle>>(
+        mut self,
+        increase: S,
+        decrease: T,
+    ) -> Self {
+        self.increase_style = increase.into();
+        self.decrease_style = decrease.into();
+        self
+    }
+
+    /**
+    Sets the styles of the bars of the subtotals and the totals.
+    */
+    pub fn total_styles<S: Into<ShapeStyle>, T: Into<ShapeStyle>>(
+        mut self,
+        subtotal: S,
+        total: T,
+    ) -> Self {
+        self.subtotal_style = subtotal.into();
+        self.total_style = total.into();
+        self
+    }
+
+    /**
+    Sets the style of the connector lines between the bars, `None` removes the connector lines.
+    */
+    pub fn connector_style<S: Into<ShapeStyle>>(mut self, style: Option<S>) -> Self {
+        self.connector_style = style.map(Into::into);
+        self
+    }
+
+    /**
+    Sets the baseline, which is the running total before the first step and the bottom of the
+    bars of the subtotals and the totals.
+    */
+    pub fn baseline(mut self, baseline: A) -> Self {
+        self.baseline = baseline;
+        self
+    }
+
+    /**
+    Sets the margin for each bar, in backend pixels.
+    */
+    pub fn margin(mut self, value: u32) -> Self {
+        self.margin = value;
+        self
+    }
+
+    /**
+    Specifies the input data for the waterfall.
+
+    Each item is a category with a step, and the steps are accumulated in the order of the items.
+    The items with a category out of the range of the axis are ignored.
+    */
+    pub fn data<TB, I>(mut self, iter: I) -> Self
+    where
+        TB: Into<BR::ValueType>,
+        I: IntoIterator<Item = (TB, WaterfallStep<A>)>,
+    {
+        let br = &self.br;
+        let steps: Vec<_> = iter
+            .into_iter()
+            .filter_map(|(x, step)| Some((br.index_of(&x.into())?, step)))
+            .filter(|(x, _)| *x < br.size())
+            .collect();
+        self.iter = steps.into_iter();
+        self
+    }
+
+    /// Get the bar of a step, and advance the running total
+    fn next_bar(
+        &mut self,
+        x: usize,
+        step: WaterfallStep<A>,
+    ) -> Option<WaterfallElement<BR::ValueType, A>> {
+        let level = self.level.take().unwrap_or_else(|| self.baseline.clone());
+        let (from, to, style) = match step {
+            WaterfallStep::Delta(delta) => {
+                let style = if delta < A::default() {
+                    self.decrease_style
+                } else {
+                    self.increase_style
+                };
+                (level.clone(), level + delta, style)
+            }
+            WaterfallStep::Subtotal => (self.baseline.clone(), level, self.subtotal_style),
+            WaterfallStep::Total => (self.baseline.clone(), level, self.total_style),
+        };
+        self.level = Some(to.clone());
+        Some(WaterfallElement {
+            points: [
+                (self.br.from_index(x)?, to),
+                (self.br.from_index(x + 1)?, from),
+            ],
+            style,
+            margin: self.margin,
+            connector: false,
+        })
+    }
+}
+
+impl<BR, A> Iterator for WaterfallSeries<BR, A>
+where
+    BR: DiscreteRanged + Clone,
+    A: Add<A, Output = A> + Default + Clone + PartialOrd,
+{
+    type Item = WaterfallElement<BR::ValueType, A>;
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(bar) = self.pending.take() {
+            return Some(bar);
+        }
+        while let Some((x, step)) = self.iter.next() {
+            let level = self.level.clone().unwrap_or_else(|| self.baseline.clone());
+            let bar = match self.next_bar(x, step) {
+                Some(bar) => bar,
+                None => continue,
+            };
+            let last = self.last.replace(x);
+            let connector = match (last, self.connector_style) {
+                (Some(last), Some(style)) => self
+                    .br
+                    .from_index(last + 1)
+                    .zip(self.br.from_index(x))
+                    .map(|(from, to)| WaterfallElement {
+                        points: [(from, level.clone()), (to, level)],
+                        style,
+                        margin: self.margin,
+                        connector: true,
+                    }),
+                _ => None,
+            };
+            if connector.is_some() {
+                self.pending = Some(bar);
+                return connector;
+            }
+            return Some(bar);
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_waterfall_series() {
+        let drawing_area = create_mocked_drawing_area(100, 100, |_| {});
+        let chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d((0..4).into_segmented(), 0..20)
+            .unwrap();
+
+        let elements: Vec<_> = WaterfallSeries::new(&chart)
+            .data([
+                (0, WaterfallStep::Delta(10)),
+                (1, WaterfallStep::Delta(-4)),
+                (9, WaterfallStep::Delta(100)),
+                (2, WaterfallStep::Subtotal),
+                (3, WaterfallStep::Total),
+            ])
+            .collect();
+        let bars: Vec<_> = elements
+            .iter()
+            .filter(|e| !e.connector)
+            .map(|e| (e.points[0].1, e.points[1].1, e.style.color))
+            .collect();
+        assert_eq!(
+            bars,
+            [
+                (10, 0, GREEN.to_rgba()),
+                (6, 10, RED.to_rgba()),
+                (6, 0, BLUE.mix(0.6).to_rgba()),
+                (6, 0, BLUE.to_rgba()),
+            ]
+        );
+        let connectors: Vec<_> = elements
+            .iter()
+            .filter(|e| e.connector)
+            .map(|e| e.points[0].1)
+            .collect();
+        assert_eq!(connectors, [10, 6, 6]);
+    }
+
+    #[test]
+    fn test_draw_waterfall_series() {
+        let drawing_area = create_mocked_drawing_area(101, 101, |m| {
+            m.check_draw_line(|_, _, from, to| {
+                assert_eq!(from, (45, 50));
+                assert_eq!(to, (55, 50));
+            });
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_rect_call, 4);
+                assert_eq!(b.num_draw_line_call, 1);
+            });
+        });
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d((0..1).into_segmented(), 0..100)
+            .unwrap();
+        chart
+            .draw_series(
+                WaterfallSeries::new(&chart)
+                    .data([(0, WaterfallStep::Delta(50)), (1, WaterfallStep::Total)]),
+            )
+            .unwrap();
+        chart
+            .draw_series(
+                WaterfallSeries::new(&chart)
+                    .connector_style(None::<ShapeStyle>)
+                    .data([(0, WaterfallStep::Delta(50)), (1, WaterfallStep::Total)]),
+            )
+            .unwrap();
+    }
+}
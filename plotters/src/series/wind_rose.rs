@@ -0,0 +1,283 @@
+use std::ops::Range;
+
+use crate::data::float::pretty_print_float;
+use crate::element::Polygon;
+use crate::style::{Color, Palette, Palette99, ShapeStyle};
+
+/**
+The wind rose series, which is a polar histogram of directional data binned into angular sectors
+and stacked by the classes of the magnitudes, i.e. the frequencies of the wind directions split by
+the wind speeds.
+
+It's drawn on a chart with the polar coordinate, see
+[`crate::chart::ChartBuilder::build_polar()`], of which the angular axis is the direction and the
+radial axis is the count, or the fraction of the data when [`WindRoseSeries::normalized()`] is
+set. The sectors are centered on the directions `start + k * width`, so the first sector is
+centered on the north with the default angular range `0..360`. The class of a magnitude is the
+number of the class bounds which are less than or equal to it, and the classes are stacked from
+the center outwards in the colors of [`Palette99`] by default. Each class is a [`Polygon`], so
+the series works with [`crate::chart::ChartContext::draw_series()`] and the series labels.
+
+# Examples
+
+```
+use plotters::prelude::*;
+let wind = [(10.0, 3.0), (20.0, 7.0), (350.0, 12.0), (90.0, 4.0), (100.0, 9.0), (180.0, 2.0)];
+let series = WindRoseSeries::new(wind).sectors(8).classes([5.0, 10.0]);
+let drawing_area = SVGBackend::new("wind_rose_series.svg", (300, 300)).into_drawing_area();
+drawing_area.fill(&WHITE).unwrap();
+let mut chart = ChartBuilder::on(&drawing_area)
+    .margin(10)
+    .build_polar(0.0..360.0, 0.0..series.max_total())
+    .unwrap();
+chart.configure_mesh().draw().unwrap();
+chart.draw_series(series).unwrap();
+```
+*/
+pub struct WindRoseSeries {
+    data: Vec<(f64, f64)>,
+    angle_range: Range<f64>,
+    sectors: usize,
+    bounds: Vec<f64>,
+    styles: Vec<ShapeStyle>,
+    normalized: bool,
+    gap: f64,
+    resolution: usize,
+    elements: Option<std::vec::IntoIter<Polygon<(f64, f64)>>>,
+}
+
+impl WindRoseSeries {
+    /**
+    Creates a wind rose series with 16 sectors and a single class.
+
+    - `iter`: The `(direction, magnitude)` pairs, the pairs with a non-finite value are ignored
+    - **returns** The newly created series
+
+    See [`WindRoseSeries`] for more information and examples.
+    */
+    pub fn new<I: IntoIterator<Item = (f64, f64)>>(iter: I) -> Self {
+        Self {
+            data: iter
+                .into_iter()
+                .filter(|(d, m)| d.is_finite() && m.is_finite())
+                .collect(),
+            angle_range: 0.0..360.0,
+            sectors: 16,
+            bounds: vec![],
+            styles: vec![],
+            normalized: false,
+            gap: 0.1,
+            resolution: 16,
+            elements: None,
+        }
+    }
+
+    /// Set the range of the angular axis which makes a full turn, which is `0..360` by default
+    pub fn angle_range(mut self, range: Range<f64>) -> Self {
+        self.angle_range = range;
+        self
+    }
+
+    /// Set the number of the sectors, which is at least 1
+    pub fn sectors(mut self, sectors: usize) -> Self {
+        self.sectors = sectors.max(1);
+        self
+    }
+
+    /// Set the bounds between the classes of the magnitudes, so `n` bounds make `n + 1` classes
+    pub fn classes<I: IntoIterator<Item = f64>>(mut self, bounds: I) -> Self {
+        self.bounds = bounds.into_iter().filter(|b| b.is_finite()).collect();
+        self.bounds
+            .sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+        self
+    }
+
+    /// Set the styles of the classes from the center outwards, the classes without a style use
+    /// the colors of [`Palette99`]
+    pub fn styles<S: Into<ShapeStyle>, I: IntoIterator<Item = S>>(mut self, styles: I) -> Self {
+        self.styles = styles.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Set if the radial values are the fractions of the data instead of the counts
+    pub fn normalized(mut self, normalized: bool) -> Self {
+        self.normalized = normalized;
+        self
+    }
+
+    /// Set the fraction of the width of each sector left empty between the sectors, which is 0.1
+    /// by default
+    pub fn gap(mut self, gap: f64) -> Self {
+        self.gap = gap.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Set the number of the segments of each arc, which is at least 1
+    pub fn resolution(mut self, resolution: usize) -> Self {
+        self.resolution = resolution.max(1);
+        self
+    }
+
+    /// Get the angular width of each sector
+    pub fn sector_width(&self) -> f64 {
+        (self.angle_range.end - self.angle_range.start) / self.sectors as f64
+    }
+
+    /// Get the index of the sector of a direction
+    pub fn sector_of(&self, direction: f64) -> usize {
+        let pos = ((direction - self.angle_range.start) / self.sector_width()).round();
+        (pos as i64).rem_euclid(self.sectors as i64) as usize
+    }
+
+    /// Get the index of the class of a magnitude
+    pub fn class_of(&self, magnitude: f64) -> usize {
+        self.bounds.partition_point(|b| *b <= magnitude)
+    }
+
+    /// Get the labels of the classes, i.e. `< 5`, `5 - 10` and `>= 10` for the bounds 5 and 10
+    pub fn class_labels(&self) -> Vec<String> {
+        let format = |v: f64| pretty_print_float(v, false);
+        let (first, last) = match (self.bounds.first(), self.bounds.last()) {
+            (Some(first), Some(last)) => (*first, *last),
+            _ => return vec!["all".to_string()],
+        };
+        let mut labels = vec![format!("< {}", format(first))];
+        labels.extend(
+            self.bounds
+                .windows(2)
+                .map(|pair| format!("{} - {}", format(pair[0]), format(pair[1]))),
+        );
+        labels.push(format!(">= {}", format(last)));
+        labels
+    }
+
+    /// Get the radial values of the classes of each sector, i.e. the counts or the fractions
+    pub fn bins(&self) -> Vec<Vec<f64>> {
+        let mut bins = vec![vec![0.0; self.bounds.len() + 1]; self.sectors];
+        for (direction, magnitude) in &self.data {
+            bins[self.sector_of(*direction)][self.class_of(*magnitude)] += 1.0;
+        }
+        if self.normalized && !self.data.is_empty() {
+            let total = self.data.len() as f64;
+            bins.iter_mut().flatten().for_each(|v| *v /= total);
+        }
+        bins
+    }
+
+    /// Get the largest radial value of the stacked sectors, which is the end of the radial axis
+    /// fitting the series
+    pub fn max_total(&self) -> f64 {
+        self.bins()
+            .iter()
+            .map(|classes| classes.iter().sum::<f64>())
+            .fold(0.0, f64::max)
+    }
+
+    fn elements(&self) -> Vec<Polygon<(f64, f64)>> {
+        let width = self.sector_width();
+        let half = width * (1.0 - self.gap) / 2.0;
+        let mut elements = vec![];
+        for (sector, classes) in self.bins().into_iter().enumerate() {
+            let center = self.angle_range.start + width * sector as f64;
+            let arc: Vec<_> = (0..=self.resolution)
+                .map(|idx| center - half + 2.0 * half * idx as f64 / self.resolution as f64)
+                .collect();
+            let mut inner = 0.0;
+            for (class, value) in classes.into_iter().enumerate() {
+                if value <= 0.0 {
+                    continue;
+                }
+                let outer = inner + value;
+                let outline: Vec<_> = arc
+                    .iter()
+                    .map(|a| (*a, outer))
+                    .chain(arc.iter().rev().map(|a| (*a, inner)))
+                    .collect();
+                let style = self
+                    .styles
+                    .get(class)
+                    .copied()
+                    .unwrap_or_else(|| Palette99::pick(class).filled());
+                elements.push(Polygon::new(outline, style));
+                inner = outer;
+            }
+        }
+        elements
+    }
+}
+
+impl Iterator for WindRoseSeries {
+    type Item = Polygon<(f64, f64)>;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.elements.is_none() {
+            self.elements = Some(self.elements().into_iter());
+        }
+        self.elements.as_mut()?.next()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::element::PointCollection;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_wind_rose_series() {
+        let wind = [
+            (0.0, 1.0),
+            (44.0, 6.0),
+            (46.0, 5.0),
+            (350.0, 12.0),
+            (-90.0, 3.0),
+            (f64::NAN, 1.0),
+        ];
+        let series = WindRoseSeries::new(wind).sectors(4).classes([10.0, 5.0]);
+        assert_eq!(series.sector_width(), 90.0);
+        assert_eq!(series.sector_of(44.0), 0);
+        assert_eq!(series.sector_of(46.0), 1);
+        assert_eq!(series.sector_of(-90.0), 3);
+        assert_eq!(series.class_of(5.0), 1);
+        assert_eq!(series.class_labels(), ["< 5", "5 - 10", ">= 10"]);
+        assert_eq!(
+            series.bins(),
+            [
+                [1.0, 1.0, 1.0],
+                [0.0, 1.0, 0.0],
+                [0.0, 0.0, 0.0],
+                [1.0, 0.0, 0.0]
+            ]
+        );
+        assert_eq!(series.max_total(), 3.0);
+        assert_eq!(WindRoseSeries::new(wind).normalized(true).max_total(), 0.4);
+        assert_eq!(WindRoseSeries::new(wind).class_labels(), ["all"]);
+
+        let lengths: Vec<_> = series
+            .resolution(2)
+            .map(|p| (&p).point_iter().len())
+            .collect();
+        assert_eq!(lengths, [6, 6, 6, 6, 6]);
+
+        let drawing_area = create_mocked_drawing_area(201, 201, |m| {
+            m.check_fill_polygon(|c, points| {
+                assert_eq!(c, RED.to_rgba());
+                assert_eq!(points[0], (100, 200));
+                assert_eq!(points[1], (100, 0));
+                assert_eq!(points[4], (100, 100));
+            });
+            m.drop_check(|b| assert_eq!(b.num_fill_polygon_call, 1));
+        });
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_polar(0.0..360.0, 0.0..1.0)
+            .unwrap();
+        chart
+            .draw_series(
+                WindRoseSeries::new([(0.0, 1.0)])
+                    .sectors(1)
+                    .gap(0.0)
+                    .resolution(2)
+                    .styles([RED.filled()]),
+            )
+            .unwrap();
+    }
+}
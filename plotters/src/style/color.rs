@@ -32,6 +32,18 @@ pub trait Color {
         RGBAColor(r, g, b, a)
     }
 
+    /// Blend the color linearly with another color, `t` is the weight of the other color
+    fn blend<C: Color>(&self, other: &C, t: f64) -> RGBAColor {
+        let (a, b) = (self.to_backend_color(), other.to_backend_color());
+        let channel = |a: u8, b: u8| (f64::from(a) * (1.0 - t) + f64::from(b) * t).round() as u8;
+        RGBAColor(
+            channel(a.rgb.0, b.rgb.0),
+            channel(a.rgb.1, b.rgb.1),
+            channel(a.rgb.2, b.rgb.2),
+            a.alpha * (1.0 - t) + b.alpha * t,
+        )
+    }
+
     /// Convert the color into the RGBA color which is internally used by Plotters
     fn to_rgba(&self) -> RGBAColor {
         let (r, g, b) = self.rgb();
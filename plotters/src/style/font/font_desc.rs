@@ -153,7 +153,17 @@ impl<'a> FontDesc<'a> {
     /// and estimate the overall size of the font
     pub fn box_size(&self, text: &str) -> FontResult<(u32, u32)> {
         let ((min_x, min_y), (max_x, max_y)) = self.layout_box(text)?;
-        let (w, h) = self.get_transform().transform(max_x - min_x, max_y - min_y);
+        let (w, h) = (max_x - min_x, max_y - min_y);
+        if let FontTransform::RotateAngle(angle) = self.get_transform() {
+            // The bounding box of a rotated rectangle is not the rotated corner
+            let (sin, cos) = f64::from(angle).to_radians().sin_cos();
+            let (w, h) = (f64::from(w), f64::from(h));
+            return Ok((
+                (w * cos.abs() + h * sin.abs()).ceil() as u32,
+                (w * sin.abs() + h * cos.abs()).ceil() as u32,
+            ));
+        }
+        let (w, h) = self.get_transform().transform(w, h);
         Ok((w.unsigned_abs(), h.unsigned_abs()))
     }
 
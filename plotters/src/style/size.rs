@@ -61,6 +61,7 @@ impl SizeDesc for f64 {
 ///     1. portion of height
 ///     2. portion of width
 ///     3. portion of the minimal of height and weight
+#[derive(Clone, Copy, Debug)]
 pub enum RelativeSize {
     /// Percentage height
     Height(f64),
@@ -127,6 +128,7 @@ pub trait AsRelative: Into<f64> {
 impl<T: Into<f64>> AsRelative for T {}
 
 /// The struct describes a relative size with upper bound and lower bound
+#[derive(Clone, Copy, Debug)]
 pub struct RelativeSizeWithBound {
     size: RelativeSize,
     min: Option<i32>,